@@ -1,46 +1,158 @@
-use std::collections::{HashMap, HashSet};
-use std::path::PathBuf;
-use std::process::{Child, Command};
-use std::sync::mpsc;
-use std::time::Instant;
-
-use crate::config::{self, ProjectConfig};
+use std::cmp::Ordering;
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::path::{Path, PathBuf};
+use std::ffi::OsString;
+use std::process::{Command, Stdio};
+use std::sync::{mpsc, Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use crate::config::{self, ClaudeHome, NotificationSink, ProjectConfig};
 use crate::data::{
-    cli_detect, filebrowser, git, github, inboxes, jira, linear, path_encoding, plans,
+    activity_calendar, agent_history, azure, bitbucket, claude_settings,
+    cli_detect::{self, GitRemote},
+    cli_fixtures, command_import, commands, deps, drafts, filebrowser, git, github, grep,
+    hooks::{self, HookDraft},
+    inboxes, issue_templates, jira, jira_filter_choice, launch_args,
+    linear::{self, IssuePage, IssueQuery},
+    link_preview, merge, notes, notifications, path_encoding, plan_reviews, plans, power,
+    process_group::{self, ProcessGroup},
+    process_registry,
     process_runner::{self, ProcessOutput},
-    prompt_builder, sessions, subagents, tasks, teams, todos, transcripts,
+    process_usage::{self, UsageSample, UsageSampler},
+    prompt_builder, prompt_library, remote_choice, run_report, session_export, session_links,
+    session_stats, session_titles, sessions,
+    share::ShareServer,
+    snapshots, subagents, tasks, team_resources, teams, ticket_notes,
+    todo_tickets::{self, TicketTarget},
+    todos, transcripts, translate, trash,
 };
 use crate::event::AppEvent;
 use crate::event::FileChange;
-use crate::model::agent_status::{self, AgentStatus};
-use crate::model::filebrowser::{FileBrowserEntry, FileContent};
-use crate::model::git::{DiffLine, FlatGitItem, GitStatus};
-use crate::model::github::{FlatIssueItem, FlatPrItem, GitHubIssue, PullRequest};
-use crate::model::inbox::InboxMessage;
-use crate::model::jira::{FlatJiraItem, JiraIssue, JiraTransition};
+use crate::model::activity::{
+    ActivityItem, ActivitySource, ActivityTracker, ItemState, MAX_ACTIVITY,
+};
+use crate::model::agent_status::{self, AgentStatus, StatusHistory};
+use crate::model::artifact::{self, TicketNote, TicketNotes};
+use crate::model::azure::{FlatAzureItem, WorkItem, WorkItemComment};
+use crate::model::backoff::{Backoff, PollHealth};
+use crate::model::claude_settings::{ClaudeSettings, FlatSettingsItem};
+use crate::model::command::{CommandFile, CommandKind, CommandScope, ImportCandidate};
+use crate::model::deps::OutdatedDep;
+use crate::model::file_ref::{self, FileRef};
+use crate::model::filebrowser::{FileBrowserEntry, FileContent, FileInfo};
+use crate::model::filter::{self, Filter, FilterContext, Filterable};
+use crate::model::git::{
+    DiffAnchor, DiffLine, FlatGitItem, GitBranch, GitStash, GitStatus, GitWorktree,
+};
+use crate::model::github::{
+    FlatIssueItem, FlatPrItem, GitHubIssue, IssueComment, IssueMetaEdit, IssueMetaField,
+    IssueTemplate, PullRequest, ReviewThread,
+};
+use crate::model::grep::{FlatGrepItem, GrepMatch, GrepResults};
+use crate::model::hook::{FlatHookItem, Hook, SettingsFile, SettingsScope, HOOK_EVENTS};
+use crate::model::inbox::{InboxMessage, BROADCAST_TEMPLATES};
+use crate::model::jira::{
+    FlatJiraItem, JiraAttachment, JiraIssue, JiraResource, JiraTransition, BOARD_COLUMNS,
+};
 use crate::model::linear::{FlatLinearItem, LinearIssue};
-use crate::model::plan::{MarkdownLine, PlanFile as PlanFileModel};
-use crate::model::process::{ProcessStatus, SpawnedProcess, TicketInfo};
-use crate::model::session::SessionEntry;
-use crate::model::task::Task;
-use crate::model::team::{Team, TeamMember};
+use crate::model::link_preview::{find_urls, LinkPreview};
+use crate::model::list_table::{self, TableView};
+use crate::model::merge::{self as merge_model, MergeChunk, MergeSession};
+use crate::model::notification::{Notification, NotificationEvent, DEFAULT_TEMPLATE};
+use crate::model::overview;
+use crate::model::plan::{MarkdownLine, OutlineEntry, PlanDecision, PlanFile as PlanFileModel};
+use crate::model::process::{
+    ProcessRecord, ProcessStatus, ProcessUsage, SpawnedProcess, TicketInfo, TicketSource,
+};
+use crate::model::prompt::{LaunchDefaults, PromptPreview, PromptTemplate, MODEL_CHOICES};
+use crate::model::search::{SearchEntry, SearchRegistry, SearchSource, SearchTarget};
+use crate::model::session::{ActivityCalendar, SessionEntry, SessionLink, SessionStats};
+use crate::model::snapshot::{self, SnapshotStore};
+use crate::model::status_bar::StatusBarLayout;
+use crate::model::task::{propose_tasks, PlanTask, Task, TaskDraft};
+use crate::model::team::{Team, TeamMember, TeamResources};
 use crate::model::todo::{TodoFile, TodoItem};
-use crate::model::transcript::TranscriptItem;
+use crate::model::transcript::{self, format_tokens, TranscriptItem, TranscriptItemKind};
+use crate::model::trash::{TrashEntry, TrashSource};
+use crate::pane_send::{PaneRegistry, PaneRoute, TerminalBackend, CLAUDE_PANE};
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum ActiveTab {
+    Overview,
     Sessions,
     Teams,
     Todos,
     Git,
     Plans,
+    Commands,
+    Hooks,
+    Settings,
+    Notes,
     GitHubPRs,
     GitHubIssues,
     Jira,
     Linear,
+    Azure,
+    Deps,
     Processes,
 }
 
+impl ActiveTab {
+    /// Name shown in the tab bar and the help overlay.
+    pub fn title(&self) -> &'static str {
+        match self {
+            ActiveTab::Overview => "Overview",
+            ActiveTab::Sessions => "Sessions",
+            ActiveTab::Teams => "Teams",
+            ActiveTab::Todos => "Todos",
+            ActiveTab::Git => "Git",
+            ActiveTab::Plans => "Plans",
+            ActiveTab::Commands => "Commands",
+            ActiveTab::Hooks => "Hooks",
+            ActiveTab::Settings => "Settings",
+            ActiveTab::Notes => "Notes",
+            ActiveTab::GitHubPRs => "PRs",
+            ActiveTab::GitHubIssues => "Issues",
+            ActiveTab::Jira => "Jira",
+            ActiveTab::Linear => "Linear",
+            ActiveTab::Azure => "Work Items",
+            ActiveTab::Deps => "Deps",
+            ActiveTab::Processes => "Procs",
+        }
+    }
+}
+
+/// A summary card on the Overview tab. Enter jumps to `target`.
+#[derive(Debug, Clone)]
+pub struct OverviewCard {
+    pub title: &'static str,
+    pub target: ActiveTab,
+    pub headline: String,
+    pub details: Vec<String>,
+}
+
+/// Cards per row on the Overview tab.
+pub const OVERVIEW_COLUMNS: usize = 3;
+
+/// Tabs with an initial load, in the order they load at startup.
+const LOAD_ORDER: [ActiveTab; 15] = [
+    ActiveTab::Sessions,
+    ActiveTab::Overview,
+    ActiveTab::Teams,
+    ActiveTab::Todos,
+    ActiveTab::Git,
+    ActiveTab::Plans,
+    ActiveTab::Commands,
+    ActiveTab::Hooks,
+    ActiveTab::Settings,
+    ActiveTab::Notes,
+    ActiveTab::GitHubPRs,
+    ActiveTab::GitHubIssues,
+    ActiveTab::Jira,
+    ActiveTab::Linear,
+    ActiveTab::Azure,
+];
+
 #[derive(Debug, Clone, PartialEq)]
 pub enum ProcessesPane {
     List,
@@ -59,24 +171,211 @@ pub enum GitMode {
     Browse,
 }
 
+/// What to do with the selected stash, once confirmed.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum StashAction {
+    Apply,
+    Pop,
+    Drop,
+}
+
+impl StashAction {
+    /// The `git stash` subcommand.
+    pub fn command(&self) -> &'static str {
+        match self {
+            StashAction::Apply => "apply",
+            StashAction::Pop => "pop",
+            StashAction::Drop => "drop",
+        }
+    }
+
+    pub fn past_tense(&self) -> &'static str {
+        match self {
+            StashAction::Apply => "Applied",
+            StashAction::Pop => "Popped",
+            StashAction::Drop => "Dropped",
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub enum FileBrowserPane {
     Tree,
     Content,
 }
 
+/// What the path typed in the file browser is for.
+#[derive(Debug, Clone, PartialEq)]
+pub enum FileOp {
+    NewFile,
+    NewDir,
+    /// Rename or move this file or folder.
+    Rename(PathBuf),
+}
+
+/// A path being typed for a file browser operation, relative to the
+/// project root.
+pub struct FileOpInput {
+    pub op: FileOp,
+    pub input: String,
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub enum PlansPane {
+    List,
+    Outline,
+    Content,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum CommandsPane {
     List,
     Content,
 }
 
+#[derive(Debug, Clone, PartialEq)]
+pub enum HooksPane {
+    List,
+    Detail,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum SettingsPane {
+    List,
+    Detail,
+}
+
+/// A field of the hook form.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum HookField {
+    Scope,
+    Event,
+    Matcher,
+    Command,
+    Timeout,
+}
+
+impl HookField {
+    pub const ALL: [HookField; 5] = [
+        HookField::Scope,
+        HookField::Event,
+        HookField::Matcher,
+        HookField::Command,
+        HookField::Timeout,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            HookField::Scope => "Scope",
+            HookField::Event => "Event",
+            HookField::Matcher => "Matcher",
+            HookField::Command => "Command",
+            HookField::Timeout => "Timeout",
+        }
+    }
+}
+
+/// The guided form for adding or editing a hook.
+#[derive(Debug, Clone)]
+pub struct HookForm {
+    pub scope: SettingsScope,
+    pub event: String,
+    pub matcher: String,
+    /// `command` or `prompt`; kept from the hook being edited.
+    pub kind: String,
+    pub command: String,
+    /// Seconds, as typed.
+    pub timeout: String,
+    pub field: HookField,
+    /// The hook being edited; None adds a new one.
+    pub editing: Option<Hook>,
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub enum SessionsPane {
     List,
     Transcript,
 }
 
+/// Session awaiting a "resume it?" confirmation.
+#[derive(Debug, Clone)]
+pub struct ResumeTarget {
+    pub session_id: String,
+    pub title: String,
+    pub branch: String,
+    pub cwd: PathBuf,
+}
+
+/// Lines shown above a search match in the file preview.
+const GREP_PREVIEW_CONTEXT: usize = 5;
+
+/// How long the last deleted item can be restored with `z`.
+const UNDO_WINDOW: Duration = Duration::from_secs(10);
+
+/// Most results goto anything lists.
+const GOTO_RESULTS: usize = 50;
+
+/// The last item moved to the trash, restorable with `z` for a moment.
+#[derive(Debug, Clone)]
+pub struct DeletedFile {
+    pub name: String,
+    /// The entry's id in the trash.
+    pub trash_id: String,
+    /// Tab to reload after a restore.
+    pub tab: ActiveTab,
+    pub at: Instant,
+}
+
+/// What the trash view asks to delete for good.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TrashPurge {
+    Selected,
+    All,
+}
+
+/// The trash view: deleted items that can be restored or purged.
+#[derive(Debug, Clone, Default)]
+pub struct TrashView {
+    pub entries: Vec<TrashEntry>,
+    pub index: usize,
+    /// Waiting for `y` to purge.
+    pub confirm: Option<TrashPurge>,
+}
+
+/// Full content of one transcript item, shown in a popup.
+#[derive(Debug, Clone)]
+pub struct TranscriptDetail {
+    pub title: String,
+    pub text: String,
+    pub scroll: usize,
+}
+
+/// Links in the selected PR, issue or Jira item, shown in a popup with a
+/// preview of the highlighted one.
+#[derive(Debug, Clone)]
+pub struct LinkPopup {
+    pub urls: Vec<String>,
+    pub index: usize,
+}
+
+/// Agent and command definitions loaded for import, reviewed one at a time.
+#[derive(Debug, Clone)]
+pub struct CommandImport {
+    pub candidates: Vec<ImportCandidate>,
+    pub index: usize,
+    pub scroll: usize,
+}
+
+/// Plan proposal open in the review overlay.
+#[derive(Debug, Clone)]
+pub struct PlanReview {
+    pub session_id: String,
+    /// Timestamp of the transcript entry that proposed the plan.
+    pub proposed: Option<chrono::DateTime<chrono::Utc>>,
+    pub lines: Vec<MarkdownLine>,
+    pub scroll: usize,
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub enum TeamsPane {
     Teams,
@@ -85,6 +384,97 @@ pub enum TeamsPane {
     Detail,
 }
 
+/// Which field is focused in the task editor.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TaskEditField {
+    Subject,
+    Description,
+    Owner,
+    BlockedBy,
+}
+
+impl TaskEditField {
+    const ORDER: [TaskEditField; 4] = [
+        TaskEditField::Subject,
+        TaskEditField::Description,
+        TaskEditField::Owner,
+        TaskEditField::BlockedBy,
+    ];
+
+    fn cycle(self, forward: bool) -> Self {
+        let i = Self::ORDER.iter().position(|f| *f == self).unwrap_or(0);
+        let n = Self::ORDER.len();
+        Self::ORDER[if forward {
+            (i + 1) % n
+        } else {
+            (i + n - 1) % n
+        }]
+    }
+}
+
+/// Overlay for creating or editing a team task.
+pub struct TaskEditor {
+    /// Task being edited; None when creating a new one.
+    pub task_id: Option<String>,
+    pub field: TaskEditField,
+    pub subject: tui_textarea::TextArea<'static>,
+    pub description: tui_textarea::TextArea<'static>,
+    /// Member name the task is assigned to.
+    pub owner: Option<String>,
+    /// Ids of the other tasks, offered in the blocked-by list.
+    pub candidates: Vec<String>,
+    pub blocked_by: Vec<String>,
+    pub blocked_index: usize,
+}
+
+/// Tasks proposed from a plan (`t` on the Plans tab), picked before they're
+/// created under a team.
+pub struct PlanTasksPreview {
+    /// Filename of the plan.
+    pub plan: String,
+    /// Index of the team in `teams`.
+    pub team: usize,
+    pub tasks: Vec<PlanTask>,
+    pub index: usize,
+}
+
+/// A team member's transcript, shown over the Teams tab and read as it grows.
+pub struct MemberTranscript {
+    pub member: String,
+    pub path: PathBuf,
+    pub reader: transcripts::TranscriptReader,
+    /// Last item shown when not following.
+    pub scroll: usize,
+    /// Keep the newest items in view.
+    pub follow: bool,
+}
+
+/// Where a drafted PR goes: `head` into `base` on `repo`.
+#[derive(Debug, Clone)]
+pub struct PrTarget {
+    pub repo: String,
+    pub base: String,
+    pub head: String,
+}
+
+/// A PR title and description drafted by Claude Code, edited before the PR
+/// is created.
+pub struct PrDraft {
+    pub target: PrTarget,
+    pub field: IssueEditField,
+    pub title: tui_textarea::TextArea<'static>,
+    pub body: tui_textarea::TextArea<'static>,
+}
+
+/// A message being composed for every member of the selected team.
+pub struct BroadcastComposer {
+    pub editor: tui_textarea::TextArea<'static>,
+    /// Index into `BROADCAST_TEMPLATES` of the template last filled in.
+    pub template: Option<usize>,
+    /// Showing the recipients and waiting for `y` to send.
+    pub confirming: bool,
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub enum GitHubPane {
     List,
@@ -105,6 +495,101 @@ pub enum IssueEditMode {
     Comment(u64),
 }
 
+/// Popup for editing an issue's labels, assignees or milestone.
+#[derive(Debug, Clone)]
+pub struct IssueMetaPicker {
+    pub field: IssueMetaField,
+    pub number: u64,
+    /// None while the options are loading.
+    pub options: Option<Vec<String>>,
+    /// Checked options; at most one for the milestone.
+    pub checked: Vec<String>,
+    pub index: usize,
+}
+
+/// An issue that changed on GitHub while it was being edited, found when
+/// saving. The editor stays open until the user picks how to go on.
+#[derive(Debug, Clone)]
+pub struct IssueConflict {
+    pub number: u64,
+    /// The issue as it is on GitHub now.
+    pub upstream: GitHubIssue,
+    /// The edited title and body.
+    pub title: String,
+    pub body: String,
+    /// The body as loaded, against the edited and the upstream body.
+    pub chunks: Vec<MergeChunk>,
+    /// Whether the title changed on both sides.
+    pub title_conflict: bool,
+    pub scroll: usize,
+}
+
+/// The goto-anything overlay (Ctrl+K).
+#[derive(Debug, Clone, Default)]
+pub struct GotoSearch {
+    pub query: String,
+    /// Index into the results for `query`.
+    pub index: usize,
+}
+
+/// Popup for linking the selected session to a loaded PR, issue or ticket.
+#[derive(Debug, Clone)]
+pub struct SessionLinkPicker {
+    pub session_id: String,
+    /// Tickets from the PRs, Issues, Jira and Linear tabs.
+    pub candidates: Vec<SessionLink>,
+    /// Typed text narrowing the candidates by key or title.
+    pub query: String,
+    /// Index into `matches()`.
+    pub index: usize,
+}
+
+impl SessionLinkPicker {
+    /// Candidates whose key or title contains the query, ignoring case.
+    pub fn matches(&self) -> Vec<&SessionLink> {
+        let query = self.query.to_lowercase();
+        self.candidates
+            .iter()
+            .filter(|link| {
+                query.is_empty()
+                    || link.key.to_lowercase().contains(&query)
+                    || link.title.to_lowercase().contains(&query)
+            })
+            .collect()
+    }
+}
+
+/// Which name the branch picker's input line is collecting.
+#[derive(Debug, Clone, PartialEq)]
+pub enum BranchInputMode {
+    NewBranch,
+    NewWorktree,
+}
+
+/// Mode for the todo item input line (edit existing or add new).
+#[derive(Debug, Clone, PartialEq)]
+pub enum TodoEditMode {
+    Edit(usize), // item index
+    New,
+}
+
+/// A todo being turned into a ticket (`t` on the Todos tab): the trackers
+/// to pick from and the title, prefilled from the todo.
+#[derive(Debug, Clone)]
+pub struct TodoPromote {
+    pub targets: Vec<TicketTarget>,
+    pub index: usize,
+    pub title: String,
+    pub body: String,
+    /// Claude home, file name and content of the todo, to find it again
+    /// when the ticket has been created.
+    home: usize,
+    filename: String,
+    content: String,
+    /// Set while the ticket is being created.
+    pub creating: bool,
+}
+
 /// Which field is focused in the issue editor.
 #[derive(Debug, Clone, PartialEq)]
 pub enum IssueEditField {
@@ -124,6 +609,40 @@ pub enum LinearPane {
     Detail,
 }
 
+#[derive(Debug, Clone, PartialEq)]
+pub enum AzurePane {
+    List,
+    Detail,
+}
+
+/// What to draw again on the next frame. Clean panes are copied from the
+/// last frame; the tab bar, status bar and overlays are drawn on every frame.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Dirty {
+    pub session_list: bool,
+    pub transcript: bool,
+    /// The body of any other tab.
+    pub content: bool,
+    /// Only the status bar changed, e.g. a countdown ticked.
+    pub status_bar: bool,
+}
+
+impl Dirty {
+    pub fn all() -> Self {
+        Dirty {
+            session_list: true,
+            transcript: true,
+            content: true,
+            status_bar: true,
+        }
+    }
+
+    /// Whether anything needs a new frame.
+    pub fn any(&self) -> bool {
+        *self != Dirty::default()
+    }
+}
+
 pub struct App {
     pub should_quit: bool,
     pub active_tab: ActiveTab,
@@ -134,18 +653,66 @@ pub struct App {
 
     // Paths
     pub project_cwd: PathBuf,
+    /// Primary Claude home; plans, commands and prompts live here.
     pub claude_home: PathBuf,
+    /// Every Claude home whose sessions, teams and todos are merged in,
+    /// primary first.
+    pub claude_homes: Vec<ClaudeHome>,
     pub encoded_project: String,
 
+    /// Active list filter per tab (Sessions, PRs, Issues, Jira, Linear).
+    pub filters: HashMap<ActiveTab, Filter>,
+    /// Filter being typed for the active tab; `Some` while the input is open.
+    pub filter_input: Option<String>,
+    /// Failing background polls per integration tab (PRs, Issues, Jira,
+    /// Linear, Work Items).
+    poll_backoff: HashMap<ActiveTab, Backoff>,
+    /// Changes noticed between polls of PRs, issues, Jira and Linear,
+    /// newest first.
+    pub activity: Vec<ActivityItem>,
+    pub activity_tracker: ActivityTracker,
+    pub show_activity: bool,
+    pub activity_index: usize,
+    /// What every tab has loaded, for goto anything.
+    pub search_registry: SearchRegistry,
+    pub goto: Option<GotoSearch>,
+
+    // Overview tab
+    pub overview_index: usize,
+    /// Agent statuses for every team, refreshed while the Overview is shown.
+    pub overview_teams: Vec<(String, Vec<AgentStatus>)>,
+
     // Sessions tab
+    /// Sessions shown in the list, i.e. `all_sessions` narrowed by the filter.
     pub sessions: Vec<SessionEntry>,
+    pub all_sessions: Vec<SessionEntry>,
+    /// Whether the session scan index was checked against the transcripts
+    /// on disk; until the Sessions tab is reloaded, the watcher keeps it
+    /// current.
+    session_index_synced: bool,
     pub session_list_index: usize,
     pub sessions_pane: SessionsPane,
     pub transcript_reader: transcripts::TranscriptReader,
     pub transcript_items: Vec<TranscriptItem>,
     pub transcript_scroll: usize,
     pub follow_mode: bool,
+    /// Index of the first transcript item that arrived while follow mode
+    /// was off — drawn as a "new" divider until follow mode resumes.
+    pub transcript_unread_from: Option<usize>,
     pub loaded_session_id: Option<String>,
+    /// Claude home of the loaded session.
+    loaded_session_home: usize,
+    /// Titles given to sessions with `r`, by session id.
+    session_titles: HashMap<String, String>,
+    session_titles_path: PathBuf,
+    /// Title being typed for the selected session.
+    pub session_rename_input: Option<String>,
+    /// Tickets linked to sessions with `L`, by session id.
+    session_links: HashMap<String, SessionLink>,
+    session_links_path: PathBuf,
+    pub session_link_picker: Option<SessionLinkPicker>,
+    /// Group the session list under linked tickets (`b`).
+    pub sessions_by_ticket: bool,
 
     // Subagent transcripts
     pub subagents: Vec<subagents::SubagentInfo>,
@@ -154,6 +721,31 @@ pub struct App {
     pub subagent_reader: transcripts::TranscriptReader,
     pub viewing_subagent: bool,
     pub subagent_scroll: usize,
+    /// Show the per-turn tokens/elapsed column in the transcript.
+    pub show_turn_usage: bool,
+    /// Spread long messages over several lines instead of truncating them
+    /// (`w`). The transcript then scrolls by line: the top item is still
+    /// `transcript_scroll` / `subagent_scroll`, and this is the row of it
+    /// shown first.
+    pub transcript_wrap: bool,
+    pub transcript_wrap_row: usize,
+    /// Width inside the transcript pane's borders, for wrapping.
+    pub transcript_width: u16,
+    /// Show the selected session's stats in place of its transcript.
+    pub show_session_stats: bool,
+    /// Stats per session id, computed the first time the pane shows one.
+    pub session_stats: HashMap<String, SessionStats>,
+    session_stats_loading: HashSet<String>,
+    /// Messages and sessions per day across the project, for the heatmap
+    /// above the stats.
+    pub activity_calendar: Option<ActivityCalendar>,
+    activity_calendar_loading: bool,
+
+    // Transcript translation
+    pub translate_mode: bool,
+    /// Cache of original message text -> translated text.
+    pub translations: HashMap<String, String>,
+    translations_pending: HashSet<String>,
 
     // Teams tab
     pub teams: Vec<Team>,
@@ -164,19 +756,86 @@ pub struct App {
     pub tasks: Vec<Task>,
     pub inbox_messages: Vec<InboxMessage>,
     pub agent_statuses: HashMap<String, AgentStatus>,
+    /// Recorded status changes per team, keyed by (home, dir name).
+    pub agent_histories: HashMap<(usize, String), StatusHistory>,
     pub detail_scroll: usize,
+    /// Show the team's dependency graph instead of the selected task.
+    pub task_graph: bool,
+    pub task_editor: Option<TaskEditor>,
+    pub broadcast: Option<BroadcastComposer>,
+    pub member_transcript: Option<MemberTranscript>,
+    /// Aggregated transcript usage per team, keyed by (home, dir name).
+    pub team_resources: HashMap<(usize, String), TeamResources>,
+    team_resources_loading: bool,
+    /// Teams already reported as over `notifications.token_budget` this run.
+    budget_notified: HashSet<(usize, String)>,
 
     // Todos tab
     pub todo_files: Vec<TodoFile>,
     pub todo_file_index: usize,
     pub todo_item_index: usize,
     pub todos_pane_left: bool,
+    pub todo_edit_mode: Option<TodoEditMode>,
+    pub todo_edit_input: String,
+    pub todo_promote: Option<TodoPromote>,
 
     // Plans tab
     pub plan_files: Vec<PlanFileModel>,
     pub plan_file_index: usize,
     pub plans_pane: PlansPane,
     pub plan_content_scroll: usize,
+    pub plan_outline_index: usize,
+    pub plan_editing: bool,
+    pub plan_editor: Option<tui_textarea::TextArea<'static>>,
+    /// Filename of the plan open in the editor.
+    plan_edit_filename: Option<String>,
+    /// Disk content the editor's text was last reconciled with; the base
+    /// of the three-way merge when an agent rewrites the file.
+    plan_edit_base: String,
+    pub plan_merge: Option<MergeSession>,
+    pub plan_tasks: Option<PlanTasksPreview>,
+
+    // Commands tab
+    pub command_files: Vec<CommandFile>,
+    pub command_index: usize,
+    pub commands_pane: CommandsPane,
+    pub command_content_scroll: usize,
+    pub command_editing: bool,
+    pub command_editor: Option<tui_textarea::TextArea<'static>>,
+    /// Scope and kind of the file being created while its name is typed.
+    pub command_new_target: Option<(CommandScope, CommandKind)>,
+    pub command_new_name: String,
+    /// Import source (URL or path) being typed.
+    pub command_import_input: Option<String>,
+    pub command_import: Option<CommandImport>,
+    command_import_loading: bool,
+
+    // Hooks tab
+    pub hook_files: Vec<SettingsFile>,
+    pub hook_flat_list: Vec<FlatHookItem>,
+    pub hook_index: usize,
+    pub hooks_pane: HooksPane,
+    pub hook_detail_scroll: usize,
+    pub hook_form: Option<HookForm>,
+
+    // Settings tab
+    pub claude_settings: ClaudeSettings,
+    pub settings_flat_list: Vec<FlatSettingsItem>,
+    pub settings_index: usize,
+    pub settings_pane: SettingsPane,
+    pub settings_detail_scroll: usize,
+
+    // Notes tab
+    /// `.associate/notes.md` as last read.
+    pub notes_text: String,
+    pub notes_lines: Vec<MarkdownLine>,
+    /// Show the notes as typed rather than rendered.
+    pub notes_raw: bool,
+    pub notes_scroll: usize,
+    pub notes_editing: bool,
+    pub notes_editor: Option<tui_textarea::TextArea<'static>>,
+    /// Note being jotted with `Ctrl+N`, from any tab.
+    pub note_input: Option<String>,
 
     // Git tab
     pub git_status: GitStatus,
@@ -185,6 +844,16 @@ pub struct App {
     pub git_pane: GitPane,
     pub git_diff_lines: Vec<DiffLine>,
     pub diff_scroll: usize,
+    /// Show the diff side by side; narrow panes fall back to unified.
+    pub git_diff_split: bool,
+    /// Where to put the diff back to when the reload in flight arrives.
+    diff_anchor: Option<DiffAnchor>,
+    /// When to reload git status, once git and working-tree changes settle.
+    git_refresh_due: Option<Instant>,
+
+    /// External editor command for the main loop to run with the TUI
+    /// suspended.
+    pub pending_editor: Option<Vec<String>>,
 
     // File browser (Git tab browse mode)
     pub git_mode: GitMode,
@@ -192,31 +861,98 @@ pub struct App {
     pub fb_index: usize,
     pub fb_expanded: HashSet<PathBuf>,
     pub fb_content: Option<FileContent>,
+    pub fb_content_info: Option<FileInfo>,
     pub fb_content_path: Option<PathBuf>,
     pub fb_content_scroll: usize,
+    /// Follow the end of the open file as it grows (`f`).
+    pub fb_tail: bool,
     pub fb_pane: FileBrowserPane,
     pub fb_editing: bool,
     pub fb_editor: Option<tui_textarea::TextArea<'static>>,
+    /// New file / folder or rename prompt (`n` / `N` / `r`).
+    pub fb_op: Option<FileOpInput>,
+
+    // Project search (Git tab browse mode)
+    /// Search pattern, while it is being typed.
+    pub grep_input: Option<String>,
+    /// The searched pattern; its results replace the file tree while set.
+    pub grep_query: Option<String>,
+    pub grep_results: GrepResults,
+    pub grep_items: Vec<FlatGrepItem>,
+    pub grep_index: usize,
+    pub grep_loading: bool,
+
+    // Branch picker (Git tab popup)
+    pub show_branch_picker: bool,
+    pub branch_picker_branches: Vec<GitBranch>,
+    pub branch_picker_worktrees: Vec<GitWorktree>,
+    pub branch_picker_index: usize,
+    pub branch_picker_input: Option<BranchInputMode>,
+    pub branch_picker_input_text: String,
+    /// Worktree awaiting a "launch Claude here?" confirmation.
+    pub branch_picker_launch_path: Option<PathBuf>,
+
+    // Stashes (Git tab)
+    /// Message for a new stash, while it is being typed.
+    pub git_stash_input: Option<String>,
+    /// Action on the selected stash awaiting confirmation.
+    pub git_stash_confirm: Option<StashAction>,
 
     // GitHub PRs tab
     pub has_gh: bool,
+    /// GitHub remotes of the project's git repo, `origin` first.
+    pub gh_remotes: Vec<GitRemote>,
+    /// Remote picked for the GitHub tabs, remembered across runs.
+    pub gh_remote: Option<String>,
+    remote_choice_path: PathBuf,
+    pub show_remote_picker: bool,
+    pub remote_picker_index: usize,
     pub gh_repo: Option<String>,
     pub gh_user: Option<String>,
+    /// The PRs tab reads from Bitbucket Cloud (`prs.provider`) instead of GitHub.
+    pub prs_from_bitbucket: bool,
+    /// Bitbucket `workspace/repo` for the PRs tab.
+    pub bb_repo: Option<String>,
+    /// Nickname of the Bitbucket user the app password belongs to.
+    pub bb_user: Option<String>,
     pub gh_prs: Vec<PullRequest>,
+    /// Open PRs in the repo, when gh could count them.
+    pub gh_pr_total: Option<usize>,
+    /// Pages of PRs loaded so far, which a refresh fetches again.
+    gh_pr_pages: usize,
+    gh_prs_loading_more: bool,
     pub gh_flat_list: Vec<FlatPrItem>,
     pub gh_pr_index: usize,
+    pub gh_pr_table: TableView,
     pub gh_pane: GitHubPane,
     pub gh_detail_scroll: usize,
+    pub gh_check_index: usize,
     pub gh_last_poll: Instant,
     pub gh_prev_updated: HashMap<u64, String>,
-    pub gh_new_activity: bool,
+    /// Review threads per PR number, with the PR's `updated_at` when fetched.
+    pub gh_review_threads: HashMap<u64, (String, Vec<ReviewThread>)>,
+    gh_review_threads_loading: HashSet<u64>,
+    /// Expand resolved review threads in the PR detail pane.
+    pub gh_show_resolved_threads: bool,
+    /// Last CLI auth status check: (cli, authenticated, output).
+    pub auth_status: Option<(String, bool, String)>,
 
     // GitHub Issues tab
     pub gh_issues_enabled: bool,
     pub gh_issues_repo: Option<String>,
     pub gh_issues: Vec<GitHubIssue>,
+    /// Issues in the repo matching `github.issues.state`, when gh could
+    /// count them.
+    pub gh_issues_total: Option<usize>,
+    /// Pages of issues loaded so far, which a refresh fetches again.
+    gh_issues_pages: usize,
+    gh_issues_loading_more: bool,
     pub gh_issues_flat_list: Vec<FlatIssueItem>,
     pub gh_issues_index: usize,
+    pub gh_issues_table: TableView,
+    /// Width of the PRs / Issues table, from the terminal size, so `<` and
+    /// `>` stop where the columns fit.
+    pub list_table_width: u16,
     pub gh_issues_pane: IssuesPane,
     pub gh_issues_detail_scroll: usize,
     pub gh_issues_last_poll: Instant,
@@ -225,6 +961,17 @@ pub struct App {
     pub gh_issues_edit_field: IssueEditField,
     pub gh_issues_title_editor: Option<tui_textarea::TextArea<'static>>,
     pub gh_issues_body_editor: Option<tui_textarea::TextArea<'static>>,
+    pub gh_issues_picker: Option<IssueMetaPicker>,
+    /// Templates from `.github/ISSUE_TEMPLATE/`, read when creating an issue.
+    pub gh_issue_templates: Vec<IssueTemplate>,
+    /// Highlighted row of the template picker; the row past the last
+    /// template is a blank issue.
+    pub gh_issues_template_picker: Option<usize>,
+    /// Labels the chosen template puts on the new issue.
+    pub gh_issues_create_labels: Vec<String>,
+    /// The issue as it was when editing started, to spot upstream changes.
+    gh_issues_edit_base: Option<GitHubIssue>,
+    pub gh_issues_conflict: Option<IssueConflict>,
 
     // Jira tab
     pub has_jira: bool,
@@ -233,12 +980,24 @@ pub struct App {
     pub jira_index: usize,
     pub jira_pane: JiraPane,
     pub jira_detail_scroll: usize,
+    /// Selected attachment or web link in the detail pane.
+    pub jira_resource_index: usize,
     pub jira_detail: Option<JiraIssue>,
-    pub jira_search_mode: bool,
-    pub jira_search_input: String,
     pub jira_show_transitions: bool,
     pub jira_transitions: Vec<JiraTransition>,
     pub jira_last_poll: Instant,
+    /// Board layout: one column per status category.
+    pub jira_board_mode: bool,
+    pub jira_board_columns: Vec<Vec<JiraIssue>>,
+    pub jira_board_column: usize,
+    /// Selected card per column.
+    pub jira_board_rows: Vec<usize>,
+    pub jira_board_loading: bool,
+    /// `[jira.filters]` query shown in the list; `None` for `jira.jql`.
+    pub jira_filter: Option<String>,
+    jira_filter_path: PathBuf,
+    pub show_jira_filter_picker: bool,
+    pub jira_filter_picker_index: usize,
 
     // Linear tab
     pub has_linear: bool,
@@ -248,92 +1007,274 @@ pub struct App {
     pub linear_pane: LinearPane,
     pub linear_detail_scroll: usize,
     pub linear_last_poll: Instant,
+    /// Server-side filters from `[linear]` in `.assoc.toml`.
+    pub linear_query: IssueQuery,
+    /// Cursor of the next Linear page; None once everything is loaded.
+    pub linear_next_cursor: Option<String>,
+    /// Pages loaded so far, which a refresh fetches again.
+    linear_pages: usize,
+    linear_loading_more: bool,
+
+    // Azure DevOps Work Items tab
+    pub has_azure: bool,
+    pub azure_items: Vec<WorkItem>,
+    pub azure_flat_list: Vec<FlatAzureItem>,
+    pub azure_index: usize,
+    pub azure_pane: AzurePane,
+    pub azure_detail_scroll: usize,
+    pub azure_detail: Option<WorkItem>,
+    pub azure_comments: Vec<WorkItemComment>,
+    pub azure_detail_loading: bool,
+    pub azure_show_transitions: bool,
+    pub azure_transitions: Vec<String>,
+    pub azure_last_poll: Instant,
+
+    // Deps tab
+    pub outdated_deps: Vec<OutdatedDep>,
+    pub deps_index: usize,
+    pub deps_loading: bool,
+    pub deps_checked: bool,
+    pub deps_errors: Vec<String>,
 
     // Delete confirmation
     pub confirm_delete: bool,
     pub delete_target_name: String,
+    /// Why the delete asks first, e.g. "Team directory".
+    pub delete_target_detail: String,
+    /// Last item moved to the trash, restorable with `z` for `UNDO_WINDOW`.
+    pub undo_delete: Option<DeletedFile>,
+    pub trash: Option<TrashView>,
+
+    // Resume confirmation (Sessions tab)
+    pub resume_target: Option<ResumeTarget>,
+
+    // Transcript item popup (Sessions tab)
+    pub transcript_detail: Option<TranscriptDetail>,
+
+    // Link preview popup (PRs / Issues / Jira)
+    pub link_popup: Option<LinkPopup>,
+    /// Fetched previews by URL, kept for the rest of the run.
+    pub link_previews: HashMap<String, Result<LinkPreview, String>>,
+    link_previews_loading: HashSet<String>,
+
+    // Plan review overlay (Sessions tab)
+    pub plan_review: Option<PlanReview>,
+    /// Accept/reject decisions on proposed plans, persisted per project.
+    pub plan_decisions: Vec<PlanDecision>,
+    plan_decisions_path: PathBuf,
 
     // Processes tab
     pub has_claude: bool,
     pub processes: Vec<SpawnedProcess>,
-    pub process_children: Vec<(usize, Child)>,
+    pub process_children: Vec<(usize, ProcessGroup)>,
+    /// Force-kill deadlines for processes that were asked to stop.
+    pub process_terminating: Vec<(usize, Instant)>,
+    /// Queued processes whose working tree is being snapshotted before
+    /// they start; they count against `processes.max_concurrent`.
+    process_starting: HashSet<usize>,
+    /// Process groups left running by an earlier assoc that exited uncleanly.
+    /// A cleanup prompt is shown while this is non-empty.
+    pub orphan_processes: Vec<ProcessRecord>,
+    process_records: Vec<ProcessRecord>,
+    process_registry_path: PathBuf,
+    /// Where open editors are saved when a signal ends the app.
+    drafts_dir: PathBuf,
     pub process_index: usize,
+    /// Lines the output pane is scrolled up from the bottom.
     pub process_output_scroll: usize,
+    /// Output line a search or `e` pinned to the top of the pane. Scrolling
+    /// moves it; G and follow let go of it.
+    pub process_output_top: Option<usize>,
+    /// Output line found by the last search or `e`, highlighted.
+    pub process_output_mark: Option<usize>,
+    /// The output search being typed (`/`).
+    pub process_search_input: Option<String>,
+    /// The last output search; `n` / `N` step through its matches.
+    pub process_search: Option<String>,
+    /// Selected artifact of the selected process (`c` cycles, `o` opens).
+    pub process_artifact_index: usize,
+    /// Asking where to export the selected run's report (`E`).
+    pub process_report_prompt: bool,
+    /// Artifacts recorded against the tickets processes were launched from.
+    pub ticket_notes: TicketNotes,
+    ticket_notes_path: PathBuf,
     pub processes_pane: ProcessesPane,
-    pub process_follow: bool,
     pub process_tx: Option<mpsc::Sender<ProcessOutput>>,
     pub process_rx: Option<mpsc::Receiver<ProcessOutput>>,
+    /// CPU and memory of running processes, keyed by process id.
+    pub process_usage: HashMap<usize, ProcessUsage>,
+    /// Claude Code running in the project outside assoc, e.g. the Claude
+    /// Code pane.
+    pub pane_usage: Option<ProcessUsage>,
+    /// Shared with the thread taking a sample, which holds the lock
+    /// meanwhile.
+    usage_sampler: Arc<Mutex<UsageSampler>>,
+    usage_sampled: Option<Instant>,
     pub next_process_id: usize,
+    /// The process drafting a PR description (`P` on the Git tab), and the
+    /// PR it is for.
+    pub pr_draft_run: Option<(usize, PrTarget)>,
+    /// The drafted PR, open in the editor.
+    pub pr_draft: Option<PrDraft>,
 
     // Prompt picker (custom prompts selection)
     pub show_prompt_picker: bool,
     pub prompt_picker_index: usize,
+    /// Templates from `.associate/prompts/` and `~/.claude/prompts/`.
+    pub prompt_library: Vec<PromptTemplate>,
+    /// Extra `claude` arguments: the chosen template's, else those last
+    /// launched with from the ticket's source.
+    pub prompt_launch_args: Vec<String>,
+    /// Whether the launch passes `--dangerously-skip-permissions`.
+    pub prompt_skip_permissions: bool,
+    /// Extra arguments being typed in the dry run (`a`).
+    pub prompt_args_input: Option<String>,
+    /// `--model` for the launch; None uses Claude Code's default.
+    pub prompt_model: Option<String>,
+    /// Agents from `.claude/agents/` the launch can run as.
+    pub prompt_agents: Vec<CommandFile>,
+    /// The chosen agent's name and its `--agents` definition.
+    pub prompt_agent: Option<(String, String)>,
+    /// `--max-turns` for the launch; None leaves the run unlimited.
+    pub prompt_max_turns: Option<u32>,
+    /// Max turns being typed in the dry run (`t`).
+    pub prompt_turns_input: Option<String>,
+    /// Last launch settings by ticket source.
+    launch_defaults: HashMap<String, LaunchDefaults>,
+    launch_defaults_path: PathBuf,
 
     // Prompt modal
     pub show_prompt_modal: bool,
     pub prompt_editor: Option<tui_textarea::TextArea<'static>>,
     pub prompt_ticket_info: Option<TicketInfo>,
+    /// Dry run of the edited prompt, shown in place of the editor.
+    pub prompt_preview: Option<PromptPreview>,
 
     // Pane send
     pub two_pane: bool,
+    /// Terminal panes are opened and typed into with (`pane.backend`).
+    pub terminal: TerminalBackend,
+    /// Named panes from `--panes`. Without it, pane send reaches the
+    /// Claude Code pane with `move-focus` toward `pane.direction`.
+    pub pane_registry: Option<PaneRegistry>,
+    /// Pane the send bar types into.
+    pub send_target: String,
     pub send_mode: bool,
     pub send_input: String,
+    /// Quick-send snippets from `pane.snippets`, by key.
+    pub send_snippets: Vec<String>,
     pub send_pending: bool,
+    /// Whether the ratio toggle (=) has widened this pane.
+    pub pane_widened: bool,
     pub send_status: Option<(String, Instant)>,
     pub event_tx: Option<mpsc::Sender<AppEvent>>,
+    /// Serves the open transcript to `assoc mirror` (`--share`).
+    pub share: Option<ShareServer>,
+    /// Stretch polling, slow the tick and skip stats rescans to save battery.
+    pub low_power: bool,
+    /// `--low-power`: stay in low-power mode whatever the battery says.
+    pub low_power_flag: bool,
+    /// `display.low_power`, read once per config load: None follows the battery.
+    low_power_setting: Option<bool>,
+    power_checked: Option<Instant>,
+    power_checking: bool,
+    /// Segments of the status bar, from `[status_bar]`.
+    pub status_bar: StatusBarLayout,
 
     // Current issue detection
+    /// The project's git branch, None outside a repo or on a detached HEAD.
+    pub git_branch: Option<String>,
     /// Issue identifiers extracted from the current branch or directory name.
     /// Used to highlight and pin the "current" issue to the top of issue lists.
     pub current_issue_ids: Vec<String>,
 
+    // Tracker trend snapshots
+    /// Daily open/closed counts per tracker, drawn as mini-charts in the
+    /// Issues/Jira/Linear headers.
+    pub snapshots: SnapshotStore,
+    snapshot_path: PathBuf,
+
+    // Lazy tab loading
+    /// Tabs whose first load finished, successfully or not.
+    pub loaded_tabs: HashSet<ActiveTab>,
+    /// Tabs not loaded yet, in the order they are loaded while idle.
+    pub pending_tabs: VecDeque<ActiveTab>,
+    /// The GitHub login is still being looked up in the background.
+    pub gh_user_detecting: bool,
+
     // Status
     pub last_update: Instant,
     pub last_error: Option<String>,
 
-    // Dirty flag for redraw optimization
-    pub dirty: bool,
+    // Dirty flags for redraw optimization
+    pub dirty: Dirty,
 }
 
 impl App {
     pub fn new(project_cwd: PathBuf) -> Self {
-        let claude_home = config::claude_home();
         let encoded_project = path_encoding::encode_project_path(&project_cwd);
         let project_config = config::load_project_config(&project_cwd);
+        let claude_homes = project_config.claude_homes();
+        let claude_home = claude_homes[0].path.clone();
 
-        // Skip CLI detection entirely when associated tabs are disabled
-        let gh_tabs_wanted =
-            project_config.tabs.github_prs() || project_config.tabs.github_issues();
-        let has_gh = gh_tabs_wanted && cli_detect::is_available("gh");
-        let has_jira = project_config.tabs.jira() && cli_detect::is_available("acli");
-        let has_linear = project_config.tabs.linear() && project_config.linear_api_key().is_some();
+        let remote_choice_path =
+            remote_choice::choice_path(&config::assoc_home(), &encoded_project);
+        let gh_remote = remote_choice::load_choice(&remote_choice_path);
+        let Integrations {
+            has_gh,
+            has_jira,
+            has_linear,
+            has_azure,
+            gh_remotes,
+            gh_repo,
+            gh_user,
+            gh_issues_repo,
+            gh_issues_enabled,
+            prs_from_bitbucket,
+            bb_repo,
+        } = detect_integrations(&project_config, &project_cwd, None, gh_remote.as_deref());
         let has_claude = cli_detect::is_available("claude");
-        // Config github.repo overrides git remote detection
-        let gh_repo = project_config.github_repo().map(String::from).or_else(|| {
-            if has_gh {
-                cli_detect::detect_gh_repo(&project_cwd)
-            } else {
-                None
-            }
-        });
-        let gh_user = if has_gh {
-            cli_detect::detect_gh_user()
-        } else {
-            None
-        };
-
-        // Determine issues repo: config issues.repo > config github.repo > git remote
-        let gh_issues_repo = project_config
-            .github_issues_repo()
-            .map(String::from)
-            .or_else(|| gh_repo.clone());
-
-        // Show Issues tab if gh is available, repo is known, and config doesn't disable it.
-        // We don't pre-check hasIssuesEnabled — if issues can't be fetched, the tab shows an error.
-        let gh_issues_enabled =
-            has_gh && gh_issues_repo.is_some() && project_config.github_issues_enabled();
 
         let tail_lines = project_config.tail_lines();
+        let low_power_setting = project_config.low_power_setting();
+        let status_bar = project_config.status_bar_layout();
+        let send_snippets = project_config.send_snippets();
+        let terminal = project_config.terminal_backend();
+        let linear_query = project_config.linear_query();
+        let gh_pr_table = TableView::new(project_config.pr_columns());
+        let gh_issues_table = TableView::new(project_config.issue_columns());
+        let jira_filter_path =
+            jira_filter_choice::choice_path(&config::assoc_home(), &encoded_project);
+        let jira_filter = jira_filter_choice::load_choice(&jira_filter_path)
+            .filter(|name| project_config.jira_filter_jql(Some(name)).is_some());
+        let snapshot_path = snapshots::snapshot_path(&config::assoc_home(), &encoded_project);
+        let snapshot_store = snapshots::load_snapshots(&snapshot_path);
+        let ticket_notes_path = ticket_notes::notes_path(&config::assoc_home(), &encoded_project);
+        let ticket_notes = ticket_notes::load_notes(&ticket_notes_path);
+
+        let session_titles_path =
+            session_titles::titles_path(&config::assoc_home(), &encoded_project);
+        let session_titles = session_titles::load_titles(&session_titles_path);
+        let session_links_path = session_links::links_path(&config::assoc_home(), &encoded_project);
+        let session_links = session_links::load_links(&session_links_path);
+
+        let launch_defaults_path =
+            launch_args::defaults_path(&config::assoc_home(), &encoded_project);
+        let launch_defaults = launch_args::load_defaults(&launch_defaults_path);
+
+        let plan_decisions_path =
+            plan_reviews::decisions_path(&config::assoc_home(), &encoded_project);
+        let plan_decisions = plan_reviews::load_decisions(&plan_decisions_path);
+
+        let process_registry_path =
+            process_registry::registry_path(&config::assoc_home(), &encoded_project);
+        let (process_records, orphan_processes) = process_registry::find_orphans(
+            process_registry::load_registry(&process_registry_path),
+            process_group::process_alive,
+            process_group::group_alive,
+            process_group::process_started,
+        );
+        let drafts_dir = drafts::drafts_dir(&config::assoc_home(), &encoded_project);
 
         let mut app = App {
             should_quit: false,
@@ -343,16 +1284,41 @@ impl App {
             project_config,
             project_cwd,
             claude_home,
+            claude_homes,
             encoded_project,
 
+            filters: HashMap::new(),
+            filter_input: None,
+            poll_backoff: HashMap::new(),
+            activity: Vec::new(),
+            activity_tracker: ActivityTracker::default(),
+            show_activity: false,
+            activity_index: 0,
+            search_registry: SearchRegistry::default(),
+            goto: None,
+
+            overview_index: 0,
+            overview_teams: Vec::new(),
+
             sessions: Vec::new(),
+            all_sessions: Vec::new(),
+            session_index_synced: false,
             session_list_index: 0,
             sessions_pane: SessionsPane::List,
             transcript_reader: transcripts::TranscriptReader::with_tail_lines(tail_lines),
             transcript_items: Vec::new(),
             transcript_scroll: 0,
             follow_mode: true,
+            transcript_unread_from: None,
             loaded_session_id: None,
+            loaded_session_home: 0,
+            session_titles,
+            session_titles_path,
+            session_rename_input: None,
+            session_links,
+            session_links_path,
+            session_link_picker: None,
+            sessions_by_ticket: false,
 
             subagents: Vec::new(),
             subagent_index: 0,
@@ -360,6 +1326,19 @@ impl App {
             subagent_reader: transcripts::TranscriptReader::with_tail_lines(tail_lines),
             viewing_subagent: false,
             subagent_scroll: 0,
+            show_turn_usage: false,
+            transcript_wrap: false,
+            transcript_wrap_row: 0,
+            transcript_width: u16::MAX,
+            show_session_stats: false,
+            session_stats: HashMap::new(),
+            session_stats_loading: HashSet::new(),
+            activity_calendar: None,
+            activity_calendar_loading: false,
+
+            translate_mode: false,
+            translations: HashMap::new(),
+            translations_pending: HashSet::new(),
 
             teams: Vec::new(),
             team_list_index: 0,
@@ -369,17 +1348,66 @@ impl App {
             tasks: Vec::new(),
             inbox_messages: Vec::new(),
             agent_statuses: HashMap::new(),
+            agent_histories: HashMap::new(),
             detail_scroll: 0,
+            task_graph: false,
+            task_editor: None,
+            broadcast: None,
+            member_transcript: None,
+            team_resources: HashMap::new(),
+            team_resources_loading: false,
+            budget_notified: HashSet::new(),
 
             todo_files: Vec::new(),
             todo_file_index: 0,
             todo_item_index: 0,
             todos_pane_left: true,
+            todo_edit_mode: None,
+            todo_edit_input: String::new(),
+            todo_promote: None,
 
             plan_files: Vec::new(),
             plan_file_index: 0,
             plans_pane: PlansPane::List,
             plan_content_scroll: 0,
+            plan_outline_index: 0,
+            plan_editing: false,
+            plan_editor: None,
+            plan_edit_filename: None,
+            plan_edit_base: String::new(),
+            plan_merge: None,
+            plan_tasks: None,
+
+            command_files: Vec::new(),
+            command_index: 0,
+            commands_pane: CommandsPane::List,
+            hook_files: Vec::new(),
+            hook_flat_list: Vec::new(),
+            hook_index: 0,
+            hooks_pane: HooksPane::List,
+            hook_detail_scroll: 0,
+            hook_form: None,
+            claude_settings: ClaudeSettings::default(),
+            settings_flat_list: Vec::new(),
+            settings_index: 0,
+            settings_pane: SettingsPane::List,
+            settings_detail_scroll: 0,
+
+            notes_text: String::new(),
+            notes_lines: Vec::new(),
+            notes_raw: false,
+            notes_scroll: 0,
+            notes_editing: false,
+            notes_editor: None,
+            note_input: None,
+            command_content_scroll: 0,
+            command_editing: false,
+            command_editor: None,
+            command_new_target: None,
+            command_new_name: String::new(),
+            command_import_input: None,
+            command_import: None,
+            command_import_loading: false,
 
             git_status: GitStatus::default(),
             git_flat_list: Vec::new(),
@@ -387,42 +1415,92 @@ impl App {
             git_pane: GitPane::Files,
             git_diff_lines: Vec::new(),
             diff_scroll: 0,
+            git_diff_split: false,
+            diff_anchor: None,
+            git_refresh_due: None,
+            pending_editor: None,
 
             git_mode: GitMode::Status,
             fb_entries: Vec::new(),
             fb_index: 0,
             fb_expanded: HashSet::new(),
             fb_content: None,
+            fb_content_info: None,
             fb_content_path: None,
             fb_content_scroll: 0,
+            fb_tail: false,
             fb_pane: FileBrowserPane::Tree,
             fb_editing: false,
             fb_editor: None,
+            fb_op: None,
+
+            grep_input: None,
+            grep_query: None,
+            grep_results: GrepResults::default(),
+            grep_items: Vec::new(),
+            grep_index: 0,
+            grep_loading: false,
+
+            show_branch_picker: false,
+            branch_picker_branches: Vec::new(),
+            branch_picker_worktrees: Vec::new(),
+            branch_picker_index: 0,
+            branch_picker_input: None,
+            branch_picker_input_text: String::new(),
+            branch_picker_launch_path: None,
+            git_stash_input: None,
+            git_stash_confirm: None,
 
             two_pane: false,
+            terminal,
+            pane_registry: None,
+            send_target: CLAUDE_PANE.to_string(),
             send_mode: false,
             send_input: String::new(),
+            send_snippets,
             send_pending: false,
+            pane_widened: false,
             send_status: None,
             event_tx: None,
 
             has_gh,
+            gh_remotes,
+            gh_remote,
+            remote_choice_path,
+            show_remote_picker: false,
+            remote_picker_index: 0,
             gh_repo,
             gh_user,
+            prs_from_bitbucket,
+            bb_repo,
+            bb_user: None,
             gh_prs: Vec::new(),
+            gh_pr_total: None,
+            gh_pr_pages: 1,
+            gh_prs_loading_more: false,
             gh_flat_list: Vec::new(),
             gh_pr_index: 0,
+            gh_pr_table,
             gh_pane: GitHubPane::List,
             gh_detail_scroll: 0,
+            gh_check_index: 0,
             gh_last_poll: Instant::now(),
             gh_prev_updated: HashMap::new(),
-            gh_new_activity: false,
+            gh_review_threads: HashMap::new(),
+            gh_review_threads_loading: HashSet::new(),
+            gh_show_resolved_threads: false,
+            auth_status: None,
 
             gh_issues_enabled,
             gh_issues_repo,
             gh_issues: Vec::new(),
+            gh_issues_total: None,
+            gh_issues_pages: 1,
+            gh_issues_loading_more: false,
             gh_issues_flat_list: Vec::new(),
             gh_issues_index: 0,
+            gh_issues_table,
+            list_table_width: u16::MAX,
             gh_issues_pane: IssuesPane::List,
             gh_issues_detail_scroll: 0,
             gh_issues_last_poll: Instant::now(),
@@ -431,6 +1509,12 @@ impl App {
             gh_issues_edit_field: IssueEditField::Title,
             gh_issues_title_editor: None,
             gh_issues_body_editor: None,
+            gh_issues_picker: None,
+            gh_issue_templates: Vec::new(),
+            gh_issues_template_picker: None,
+            gh_issues_create_labels: Vec::new(),
+            gh_issues_edit_base: None,
+            gh_issues_conflict: None,
 
             has_jira,
             jira_issues: Vec::new(),
@@ -438,12 +1522,20 @@ impl App {
             jira_index: 0,
             jira_pane: JiraPane::List,
             jira_detail_scroll: 0,
+            jira_resource_index: 0,
             jira_detail: None,
-            jira_search_mode: false,
-            jira_search_input: String::new(),
             jira_show_transitions: false,
             jira_transitions: Vec::new(),
             jira_last_poll: Instant::now(),
+            jira_board_mode: false,
+            jira_board_columns: vec![Vec::new(); BOARD_COLUMNS.len()],
+            jira_board_column: 0,
+            jira_board_rows: vec![0; BOARD_COLUMNS.len()],
+            jira_board_loading: false,
+            jira_filter,
+            jira_filter_path,
+            show_jira_filter_picker: false,
+            jira_filter_picker_index: 0,
 
             has_linear,
             linear_issues: Vec::new(),
@@ -452,34 +1544,116 @@ impl App {
             linear_pane: LinearPane::List,
             linear_detail_scroll: 0,
             linear_last_poll: Instant::now(),
+            linear_query,
+            linear_next_cursor: None,
+            linear_pages: 1,
+            linear_loading_more: false,
+            has_azure,
+            azure_items: Vec::new(),
+            azure_flat_list: Vec::new(),
+            azure_index: 0,
+            azure_pane: AzurePane::List,
+            azure_detail_scroll: 0,
+            azure_detail: None,
+            azure_comments: Vec::new(),
+            azure_detail_loading: false,
+            azure_show_transitions: false,
+            azure_transitions: Vec::new(),
+            azure_last_poll: Instant::now(),
+
+            outdated_deps: Vec::new(),
+            deps_index: 0,
+            deps_loading: false,
+            deps_checked: false,
+            deps_errors: Vec::new(),
 
             confirm_delete: false,
             delete_target_name: String::new(),
+            delete_target_detail: String::new(),
+            undo_delete: None,
+            trash: None,
+
+            resume_target: None,
+
+            transcript_detail: None,
+
+            link_popup: None,
+            link_previews: HashMap::new(),
+            link_previews_loading: HashSet::new(),
+
+            plan_review: None,
+            plan_decisions,
+            plan_decisions_path,
 
             has_claude,
             processes: Vec::new(),
             process_children: Vec::new(),
+            process_terminating: Vec::new(),
+            process_starting: HashSet::new(),
+            orphan_processes,
+            process_records,
+            process_registry_path,
+            drafts_dir,
+            share: None,
+            low_power: false,
+            low_power_flag: false,
+            low_power_setting,
+            power_checked: None,
+            power_checking: false,
+            status_bar,
             process_index: 0,
             process_output_scroll: 0,
+            process_output_top: None,
+            process_output_mark: None,
+            process_search_input: None,
+            process_search: None,
+            process_artifact_index: 0,
+            process_report_prompt: false,
+            ticket_notes,
+            ticket_notes_path,
             processes_pane: ProcessesPane::List,
-            process_follow: true,
             process_tx: None,
             process_rx: None,
+            process_usage: HashMap::new(),
+            pane_usage: None,
+            usage_sampler: Arc::new(Mutex::new(UsageSampler::default())),
+            usage_sampled: None,
             next_process_id: 1,
+            pr_draft_run: None,
+            pr_draft: None,
 
             show_prompt_picker: false,
             prompt_picker_index: 0,
+            prompt_library: Vec::new(),
+            prompt_launch_args: Vec::new(),
+            prompt_skip_permissions: true,
+            prompt_args_input: None,
+            prompt_model: None,
+            prompt_agents: Vec::new(),
+            prompt_agent: None,
+            prompt_max_turns: None,
+            prompt_turns_input: None,
+            launch_defaults,
+            launch_defaults_path,
 
             show_prompt_modal: false,
             prompt_editor: None,
             prompt_ticket_info: None,
+            prompt_preview: None,
 
+            git_branch: None,
             current_issue_ids: Vec::new(),
 
+            snapshots: snapshot_store,
+            snapshot_path,
+
+            loaded_tabs: HashSet::new(),
+            pending_tabs: VecDeque::new(),
+            gh_user_detecting: false,
             last_update: Instant::now(),
             last_error: None,
 
-            dirty: true,
+            dirty: Dirty::all(),
         };
 
         // Detect current issue from branch name or directory name
@@ -491,13 +1665,26 @@ impl App {
             app.active_tab = first.clone();
         }
 
+        let saved_drafts = drafts::count_drafts(&app.drafts_dir);
+        if saved_drafts > 0 {
+            app.send_status = Some((
+                format!(
+                    "{} unsaved draft(s) kept from an earlier exit in {}",
+                    saved_drafts,
+                    app.drafts_dir.display()
+                ),
+                Instant::now(),
+            ));
+        }
+
         app
     }
 
     /// Detect the current issue from the git branch name or directory name.
     fn detect_current_issue(&mut self) {
         // Try branch name first
-        let mut source = cli_detect::detect_git_branch(&self.project_cwd);
+        self.git_branch = cli_detect::detect_git_branch(&self.project_cwd);
+        let mut source = self.git_branch.clone();
 
         // Fall back to directory name (last component)
         if source.is_none() {
@@ -513,10 +1700,14 @@ impl App {
         }
     }
 
-    /// Check if a GitHub issue number matches the current issue.
+    /// Check if a GitHub issue number matches the current issue: one named
+    /// by the branch, or the one the loaded session is linked to.
     pub fn is_current_github_issue(&self, number: u64) -> bool {
         let num_str = format!("#{}", number);
         self.current_issue_ids.contains(&num_str)
+            || self
+                .loaded_session_link()
+                .is_some_and(|l| l.is_to(&TicketSource::GitHubIssue, &num_str))
     }
 
     /// Check if a Jira issue key matches the current issue.
@@ -524,6 +1715,9 @@ impl App {
         self.current_issue_ids
             .iter()
             .any(|id| id.eq_ignore_ascii_case(key))
+            || self
+                .loaded_session_link()
+                .is_some_and(|l| l.is_to(&TicketSource::Jira, key))
     }
 
     /// Check if a Linear issue identifier matches the current issue.
@@ -531,35 +1725,49 @@ impl App {
         self.current_issue_ids
             .iter()
             .any(|id| id.eq_ignore_ascii_case(identifier))
+            || self
+                .loaded_session_link()
+                .is_some_and(|l| l.is_to(&TicketSource::Linear, identifier))
     }
 
     /// Check whether a tab is enabled via the `[tabs]` config section.
     pub fn is_tab_enabled(&self, tab: &ActiveTab) -> bool {
         let tc = &self.project_config.tabs;
         match tab {
+            ActiveTab::Overview => tc.overview(),
             ActiveTab::Sessions => tc.sessions(),
             ActiveTab::Teams => tc.teams(),
             ActiveTab::Todos => tc.todos(),
             ActiveTab::Git => tc.git(),
             ActiveTab::Plans => tc.plans(),
+            ActiveTab::Commands => tc.commands(),
+            ActiveTab::Hooks => tc.hooks(),
+            ActiveTab::Settings => tc.settings(),
+            ActiveTab::Notes => tc.notes(),
             ActiveTab::GitHubPRs => tc.github_prs(),
             ActiveTab::GitHubIssues => tc.github_issues(),
             ActiveTab::Jira => tc.jira(),
             ActiveTab::Linear => tc.linear(),
-            ActiveTab::Processes => true,
+            ActiveTab::Azure => tc.azure(),
+            ActiveTab::Deps | ActiveTab::Processes => true,
         }
     }
 
     /// Return the list of tabs that should be visible based on CLI availability and config.
     pub fn visible_tabs(&self) -> Vec<ActiveTab> {
         let mut tabs = vec![
+            ActiveTab::Overview,
             ActiveTab::Sessions,
             ActiveTab::Teams,
             ActiveTab::Todos,
             ActiveTab::Git,
             ActiveTab::Plans,
+            ActiveTab::Commands,
+            ActiveTab::Hooks,
+            ActiveTab::Settings,
+            ActiveTab::Notes,
         ];
-        if self.has_gh && self.gh_repo.is_some() {
+        if self.prs_available() {
             tabs.push(ActiveTab::GitHubPRs);
         }
         if self.gh_issues_enabled {
@@ -571,6 +1779,12 @@ impl App {
         if self.has_linear {
             tabs.push(ActiveTab::Linear);
         }
+        if self.has_azure {
+            tabs.push(ActiveTab::Azure);
+        }
+        if !self.project_config.deps_checkers().is_empty() {
+            tabs.push(ActiveTab::Deps);
+        }
         if !self.processes.is_empty() {
             tabs.push(ActiveTab::Processes);
         }
@@ -581,45 +1795,225 @@ impl App {
 
     /// Load all data from disk, skipping disabled tabs.
     pub fn load_all(&mut self) {
-        if self.is_tab_enabled(&ActiveTab::Sessions) {
-            self.load_sessions();
-        }
-        if self.is_tab_enabled(&ActiveTab::Teams) {
-            self.load_teams();
-        }
-        if self.is_tab_enabled(&ActiveTab::Todos) {
-            self.load_todos();
-        }
-        if self.is_tab_enabled(&ActiveTab::Git) {
-            self.load_git_data();
+        self.pending_tabs.clear();
+        for tab in LOAD_ORDER {
+            if self.is_tab_enabled(&tab) {
+                self.load_tab(&tab);
+            }
         }
-        if self.is_tab_enabled(&ActiveTab::Plans) {
-            self.load_plans();
+        self.last_update = Instant::now();
+    }
+
+    /// First load at startup. Sessions and the active tab load right away;
+    /// the other visible tabs are queued for `load_next_pending` so the
+    /// first frame isn't held up by tabs that may never be opened.
+    pub fn start_loading(&mut self) {
+        self.detect_gh_user();
+        let visible = self.visible_tabs();
+        self.pending_tabs = LOAD_ORDER
+            .into_iter()
+            .filter(|t| visible.contains(t))
+            .collect();
+        for tab in [ActiveTab::Sessions, self.active_tab.clone()] {
+            if self.pending_tabs.contains(&tab) {
+                self.load_tab(&tab);
+            }
         }
-        if self.is_tab_enabled(&ActiveTab::GitHubPRs) {
-            self.load_github_prs();
+        self.last_update = Instant::now();
+    }
+
+    /// Load the next queued tab. Called once per idle tick.
+    pub fn load_next_pending(&mut self) {
+        if let Some(tab) = self.pending_tabs.pop_front() {
+            self.load_tab(&tab);
         }
-        if self.is_tab_enabled(&ActiveTab::GitHubIssues) {
-            self.load_github_issues();
+    }
+
+    /// Load one tab's data. Tabs that fetch in the background are marked
+    /// loaded when their result arrives.
+    fn load_tab(&mut self, tab: &ActiveTab) {
+        self.pending_tabs.retain(|t| t != tab);
+        let background = match tab {
+            ActiveTab::Overview => {
+                self.load_overview_teams();
+                false
+            }
+            ActiveTab::Sessions => {
+                self.session_index_synced = false;
+                self.load_sessions();
+                false
+            }
+            ActiveTab::Teams => {
+                self.load_teams();
+                false
+            }
+            ActiveTab::Todos => {
+                self.load_todos();
+                false
+            }
+            ActiveTab::Plans => {
+                self.load_plans();
+                false
+            }
+            ActiveTab::Commands => {
+                self.load_commands();
+                false
+            }
+            ActiveTab::Hooks => {
+                self.load_hooks();
+                false
+            }
+            ActiveTab::Settings => {
+                self.load_settings();
+                false
+            }
+            ActiveTab::Notes => {
+                self.load_notes();
+                false
+            }
+            ActiveTab::Git => {
+                self.load_git_data();
+                if self.git_mode == GitMode::Browse {
+                    self.load_file_tree();
+                }
+                true
+            }
+            ActiveTab::GitHubPRs => {
+                self.load_github_prs();
+                true
+            }
+            ActiveTab::GitHubIssues => {
+                self.load_github_issues();
+                true
+            }
+            ActiveTab::Jira => {
+                self.load_jira_issues();
+                true
+            }
+            ActiveTab::Linear => {
+                self.load_linear_issues();
+                true
+            }
+            ActiveTab::Azure => {
+                self.load_azure_items();
+                true
+            }
+            ActiveTab::Deps | ActiveTab::Processes => false,
+        };
+        if !background {
+            self.loaded_tabs.insert(tab.clone());
         }
-        if self.is_tab_enabled(&ActiveTab::Jira) {
-            self.load_jira_issues();
+    }
+
+    /// Whether `tab`'s first load hasn't finished yet. Deps and Processes
+    /// have no initial load.
+    pub fn tab_loading(&self, tab: &ActiveTab) -> bool {
+        !matches!(tab, ActiveTab::Deps | ActiveTab::Processes) && !self.loaded_tabs.contains(tab)
+    }
+
+    /// Look up the GitHub login in the background; `gh api user` is a
+    /// network call.
+    fn detect_gh_user(&mut self) {
+        if !self.has_gh || self.gh_user.is_some() || self.gh_user_detecting {
+            return;
         }
-        if self.is_tab_enabled(&ActiveTab::Linear) {
-            self.load_linear_issues();
+        let tx = match self.event_tx.clone() {
+            Some(tx) => tx,
+            None => return,
+        };
+        self.gh_user_detecting = true;
+        std::thread::spawn(move || {
+            let _ = tx.send(AppEvent::GhUserDetected(cli_detect::detect_gh_user()));
+        });
+    }
+
+    pub fn handle_gh_user_detected(&mut self, user: Option<String>) {
+        self.gh_user_detecting = false;
+        self.gh_user = user;
+        self.rebuild_pr_list();
+        self.rebuild_issue_list();
+        // GitHub tabs that were asked to load while the login was unknown
+        for tab in [ActiveTab::GitHubPRs, ActiveTab::GitHubIssues] {
+            if self.is_tab_enabled(&tab)
+                && !self.loaded_tabs.contains(&tab)
+                && !self.pending_tabs.contains(&tab)
+            {
+                self.load_tab(&tab);
+            }
         }
-        self.last_update = Instant::now();
     }
 
-    pub fn load_sessions(&mut self) {
-        let project_dir = self
-            .claude_home
+    /// Path of the Claude home at `index` in `claude_homes`.
+    fn home_path(&self, index: usize) -> &Path {
+        self.claude_homes
+            .get(index)
+            .map(|h| h.path.as_path())
+            .unwrap_or(&self.claude_home)
+    }
+
+    /// Source label for items from the Claude home at `index`. Empty for the
+    /// primary home.
+    pub fn home_label(&self, index: usize) -> &str {
+        self.claude_homes
+            .get(index)
+            .map(|h| h.label.as_str())
+            .unwrap_or("")
+    }
+
+    /// This project's session directory within a Claude home.
+    fn project_dir(&self, home: usize) -> PathBuf {
+        self.home_path(home)
             .join("projects")
-            .join(&self.encoded_project);
+            .join(&self.encoded_project)
+    }
 
-        match sessions::load_sessions(&project_dir) {
-            Ok(entries) => {
-                self.sessions = entries;
+    /// Run `load` against every Claude home, tagging results with the home's
+    /// index. Stops at the first error.
+    fn load_from_homes<T>(
+        &self,
+        mut load: impl FnMut(&Path) -> anyhow::Result<Vec<T>>,
+        mut tag: impl FnMut(&mut T, usize),
+    ) -> anyhow::Result<Vec<T>> {
+        let mut merged = Vec::new();
+        for (index, home) in self.claude_homes.iter().enumerate() {
+            let mut items = load(&home.path).map_err(|e| match home.label.as_str() {
+                "" => e,
+                label => anyhow::anyhow!("[{}] {}", label, e),
+            })?;
+            for item in &mut items {
+                tag(item, index);
+            }
+            merged.extend(items);
+        }
+        Ok(merged)
+    }
+
+    pub fn load_sessions(&mut self) {
+        let encoded = self.encoded_project.clone();
+        let assoc_home = config::assoc_home();
+        let sync = !self.session_index_synced;
+        let loaded = self.load_from_homes(
+            |home| {
+                let project_dir = home.join("projects").join(&encoded);
+                sessions::load_sessions(
+                    &project_dir,
+                    &sessions::index_path(&assoc_home, &project_dir),
+                    sync,
+                )
+            },
+            |s, home| s.home = home,
+        );
+
+        match loaded {
+            Ok(mut entries) => {
+                self.session_index_synced = true;
+                if self.claude_homes.len() > 1 {
+                    entries.sort_by_key(|e| std::cmp::Reverse(e.modified));
+                }
+                session_titles::apply_titles(&self.session_titles, &mut entries);
+                session_links::apply_links(&self.session_links, &mut entries);
+                self.all_sessions = entries;
+                self.rebuild_session_list();
                 if !self.sessions.is_empty() {
                     if self.loaded_session_id.is_none() {
                         // First load — show most recent session
@@ -641,35 +2035,379 @@ impl App {
         }
     }
 
-    pub fn load_selected_transcript(&mut self) {
-        if self.sessions.is_empty() {
+    /// Update the session scan index for a transcript the watcher saw
+    /// change, reloading the list if a session was added, changed or removed.
+    fn update_session_index(&mut self, transcript: &Path) {
+        let Some(name) = transcript.file_name() else {
+            return;
+        };
+        // The watcher may report the path by another spelling (e.g. through
+        // a symlink), so the transcript is found again under each home
+        let parent = transcript.parent();
+        let canonical = parent.and_then(|p| p.canonicalize().ok());
+        let same = |dir: &Path| {
+            parent == Some(dir) || canonical.is_some() && canonical == dir.canonicalize().ok()
+        };
+        let Some(project_dir) = (0..self.claude_homes.len())
+            .map(|home| self.project_dir(home))
+            .find(|dir| same(dir))
+        else {
             return;
+        };
+        let index = sessions::index_path(&config::assoc_home(), &project_dir);
+        match sessions::update_index(&project_dir, &index, &project_dir.join(name)) {
+            Ok(true) => self.load_sessions(),
+            Ok(false) => {}
+            Err(e) => self.last_error = Some(format!("Session index: {}", e)),
         }
+    }
 
-        let idx = self.session_list_index.min(self.sessions.len() - 1);
-        let session = &self.sessions[idx];
-        let session_id = session.session_id.clone();
+    /// Ask for a title for the selected session, starting from the one it
+    /// was given before.
+    pub fn start_session_rename(&mut self) {
+        let Some(session) = self.sessions.get(self.session_list_index) else {
+            return;
+        };
+        self.session_rename_input = Some(session.custom_title.clone().unwrap_or_default());
+    }
 
-        // Don't reload if same session
-        if self.loaded_session_id.as_ref() == Some(&session_id) {
+    /// Save the typed title for the selected session. An empty title goes
+    /// back to the generated one.
+    pub fn submit_session_rename(&mut self) {
+        let Some(title) = self.session_rename_input.take() else {
             return;
+        };
+        let Some(id) = self
+            .sessions
+            .get(self.session_list_index)
+            .map(|s| s.session_id.clone())
+        else {
+            return;
+        };
+        let title = title.trim();
+        let msg = if title.is_empty() {
+            self.session_titles.remove(&id);
+            "Restored the generated title"
+        } else {
+            self.session_titles.insert(id, title.to_string());
+            "Renamed the session"
+        };
+        if let Err(e) = session_titles::save_titles(&self.session_titles_path, &self.session_titles)
+        {
+            self.last_error = Some(format!("Session title: {}", e));
+        } else {
+            self.send_status = Some((msg.to_string(), Instant::now()));
         }
+        session_titles::apply_titles(&self.session_titles, &mut self.all_sessions);
+        self.rebuild_session_list();
+    }
 
-        let project_dir = self
-            .claude_home
-            .join("projects")
-            .join(&self.encoded_project);
-        let transcript_path = project_dir.join(format!("{}.jsonl", session_id));
-
-        self.transcript_reader =
-            transcripts::TranscriptReader::with_tail_lines(self.project_config.tail_lines());
-        match self.transcript_reader.load_initial(&transcript_path) {
+    /// Offer the tickets loaded on the PRs, Issues, Jira and Linear tabs
+    /// for linking to the selected session.
+    pub fn open_session_link_picker(&mut self) {
+        let Some(session) = self.sessions.get(self.session_list_index) else {
+            return;
+        };
+        let candidates: Vec<SessionLink> = self
+            .gh_prs
+            .iter()
+            .map(prompt_builder::ticket_from_github_pr)
+            .chain(
+                self.gh_issues
+                    .iter()
+                    .map(prompt_builder::ticket_from_github_issue),
+            )
+            .chain(
+                self.jira_issues
+                    .iter()
+                    .map(prompt_builder::ticket_from_jira),
+            )
+            .chain(
+                self.linear_issues
+                    .iter()
+                    .map(prompt_builder::ticket_from_linear),
+            )
+            .map(|ticket| SessionLink::from_ticket(&ticket))
+            .collect();
+        if candidates.is_empty() && session.link.is_none() {
+            self.last_error = Some(
+                "No tickets loaded: open the PRs, Issues, Jira or Linear tab first".to_string(),
+            );
+            return;
+        }
+        self.session_link_picker = Some(SessionLinkPicker {
+            session_id: session.session_id.clone(),
+            candidates,
+            query: String::new(),
+            index: 0,
+        });
+    }
+
+    pub fn session_link_picker_move(&mut self, down: bool) {
+        if let Some(picker) = self.session_link_picker.as_mut() {
+            let len = picker.matches().len();
+            picker.index = if down {
+                (picker.index + 1).min(len.saturating_sub(1))
+            } else {
+                picker.index.saturating_sub(1)
+            };
+        }
+    }
+
+    /// Narrow the picker as the query is typed.
+    pub fn session_link_picker_edit(&mut self, c: Option<char>) {
+        if let Some(picker) = self.session_link_picker.as_mut() {
+            match c {
+                Some(c) => picker.query.push(c),
+                None => {
+                    picker.query.pop();
+                }
+            }
+            picker.index = 0;
+        }
+    }
+
+    /// Link the picker's session to the highlighted ticket.
+    pub fn submit_session_link(&mut self) {
+        let Some(picker) = self.session_link_picker.take() else {
+            return;
+        };
+        let Some(link) = picker.matches().get(picker.index).map(|l| (*l).clone()) else {
+            return;
+        };
+        let msg = format!("Linked the session to {}", link.label());
+        self.session_links.insert(picker.session_id, link);
+        self.save_session_links(msg);
+    }
+
+    /// Remove the picker's session's link.
+    pub fn remove_session_link(&mut self) {
+        let Some(picker) = self.session_link_picker.take() else {
+            return;
+        };
+        if self.session_links.remove(&picker.session_id).is_some() {
+            self.save_session_links("Removed the session's link".to_string());
+        }
+    }
+
+    fn save_session_links(&mut self, msg: String) {
+        if let Err(e) = session_links::save_links(&self.session_links_path, &self.session_links) {
+            self.last_error = Some(format!("Session link: {}", e));
+        } else {
+            self.send_status = Some((msg, Instant::now()));
+        }
+        session_links::apply_links(&self.session_links, &mut self.all_sessions);
+        self.rebuild_session_list();
+    }
+
+    /// Switch the session list between newest first and grouped under the
+    /// tickets sessions are linked to.
+    pub fn toggle_sessions_by_ticket(&mut self) {
+        self.sessions_by_ticket = !self.sessions_by_ticket;
+        self.rebuild_session_list();
+        self.session_list_index = 0;
+    }
+
+    /// Sessions linked to `key` from `source`, newest first.
+    pub fn sessions_linked_to(&self, source: &TicketSource, key: &str) -> Vec<&SessionEntry> {
+        self.all_sessions
+            .iter()
+            .filter(|s| s.link.as_ref().is_some_and(|l| l.is_to(source, key)))
+            .collect()
+    }
+
+    /// The ticket the session in the transcript pane is linked to.
+    fn loaded_session_link(&self) -> Option<&SessionLink> {
+        let id = self.loaded_session_id.as_deref()?;
+        self.all_sessions
+            .iter()
+            .find(|s| s.session_id == id)?
+            .link
+            .as_ref()
+    }
+
+    fn rebuild_session_list(&mut self) {
+        self.sessions = self.filtered(ActiveTab::Sessions, &self.all_sessions, None);
+        if self.sessions_by_ticket {
+            // Stable, so each ticket's sessions stay newest first
+            self.sessions
+                .sort_by_key(|s| (s.link.is_none(), s.link.as_ref().map(SessionLink::label)));
+        }
+        if self.session_list_index >= self.sessions.len() {
+            self.session_list_index = self.sessions.len().saturating_sub(1);
+        }
+        self.index_for_search(SearchSource::Sessions);
+    }
+
+    /// `items` narrowed by `tab`'s filter, with `me` standing for the user.
+    fn filtered<T: Filterable + Clone>(
+        &self,
+        tab: ActiveTab,
+        items: &[T],
+        me: Option<&str>,
+    ) -> Vec<T> {
+        let Some(filter) = self.filters.get(&tab) else {
+            return items.to_vec();
+        };
+        let ctx = FilterContext {
+            me,
+            now: chrono::Utc::now(),
+        };
+        items
+            .iter()
+            .filter(|item| filter.matches(*item, &ctx))
+            .cloned()
+            .collect()
+    }
+
+    /// Filter fields of the active tab's items, or `None` if its list
+    /// can't be filtered.
+    pub fn filter_keys(&self) -> Option<&'static [&'static str]> {
+        match self.active_tab {
+            ActiveTab::Sessions => Some(SessionEntry::FILTER_KEYS),
+            ActiveTab::GitHubPRs => Some(PullRequest::FILTER_KEYS),
+            ActiveTab::GitHubIssues => Some(GitHubIssue::FILTER_KEYS),
+            ActiveTab::Jira if !self.jira_board_mode => Some(JiraIssue::FILTER_KEYS),
+            ActiveTab::Linear => Some(LinearIssue::FILTER_KEYS),
+            _ => None,
+        }
+    }
+
+    /// The active tab's filter expression, if one is applied.
+    pub fn active_filter(&self) -> Option<&str> {
+        self.filters
+            .get(&self.active_tab)
+            .map(|f| f.source.as_str())
+    }
+
+    /// Open the filter input, prefilled with the tab's current filter.
+    pub fn filter_start(&mut self) {
+        if self.filter_keys().is_some() {
+            self.filter_input = Some(self.active_filter().unwrap_or_default().to_string());
+        }
+    }
+
+    /// Complete the field name or value under the cursor.
+    pub fn filter_complete(&mut self) {
+        let Some(input) = self.filter_input.as_deref() else {
+            return;
+        };
+        let completed = match self.active_tab {
+            ActiveTab::Sessions => filter::complete(input, &self.all_sessions),
+            ActiveTab::GitHubPRs => filter::complete(input, &self.gh_prs),
+            ActiveTab::GitHubIssues => filter::complete(input, &self.gh_issues),
+            ActiveTab::Jira => filter::complete(input, &self.jira_issues),
+            ActiveTab::Linear => filter::complete(input, &self.linear_issues),
+            _ => None,
+        };
+        if completed.is_some() {
+            self.filter_input = completed;
+        }
+    }
+
+    /// Parse the typed filter and apply it to the active tab's list. An
+    /// empty input clears the filter. On Jira, `key:` and `label:` terms also
+    /// search Jira so issues outside the assigned list can be found.
+    pub fn filter_apply(&mut self) {
+        let (Some(input), Some(keys)) = (self.filter_input.as_deref(), self.filter_keys()) else {
+            return;
+        };
+        let filter = match Filter::parse(input) {
+            Ok(filter) => filter,
+            Err(e) => {
+                self.last_error = Some(format!("Filter: {}", e));
+                return;
+            }
+        };
+        if let Some(key) = filter.unknown_key(keys) {
+            self.last_error = Some(format!(
+                "Filter: unknown field '{}' (fields: {})",
+                key,
+                keys.join(", ")
+            ));
+            return;
+        }
+        self.filter_input = None;
+        self.last_error = None;
+
+        let tab = self.active_tab.clone();
+        let jira_query = |f: &Filter| {
+            f.values_of("key")
+                .into_iter()
+                .chain(f.values_of("label"))
+                .find(|v| !v.ends_with('*'))
+                .map(str::to_string)
+        };
+        let searched_before = self.filters.get(&tab).and_then(jira_query).is_some();
+        let search = jira_query(&filter);
+        if filter.is_empty() {
+            self.filters.remove(&tab);
+        } else {
+            self.filters.insert(tab.clone(), filter);
+        }
+
+        match tab {
+            ActiveTab::Sessions => {
+                self.rebuild_session_list();
+                self.load_selected_transcript();
+            }
+            ActiveTab::GitHubPRs => self.rebuild_pr_list(),
+            ActiveTab::GitHubIssues => self.rebuild_issue_list(),
+            ActiveTab::Jira => match search {
+                Some(query) => self.jira_search(&query),
+                None => {
+                    self.rebuild_jira_list();
+                    if searched_before {
+                        // Back from search results to the assigned issues
+                        self.load_jira_issues();
+                    }
+                }
+            },
+            ActiveTab::Linear => self.rebuild_linear_list(),
+            _ => {}
+        }
+    }
+
+    pub fn filter_cancel(&mut self) {
+        self.filter_input = None;
+    }
+
+    pub fn load_selected_transcript(&mut self) {
+        if self.sessions.is_empty() {
+            return;
+        }
+        if self.show_session_stats {
+            self.load_session_stats();
+        }
+
+        let idx = self.session_list_index.min(self.sessions.len() - 1);
+        let session = &self.sessions[idx];
+        let session_id = session.session_id.clone();
+        let home = session.home;
+
+        // Don't reload if same session
+        if self.loaded_session_id.as_ref() == Some(&session_id) {
+            return;
+        }
+
+        let project_dir = self.project_dir(home);
+        let transcript_path = project_dir.join(format!("{}.jsonl", session_id));
+
+        self.transcript_reader = self.new_transcript_reader();
+        self.transcript_unread_from = None;
+        match self.transcript_reader.load_initial(&transcript_path) {
             Ok(()) => {
                 self.transcript_items = self.transcript_reader.items.clone();
                 self.loaded_session_id = Some(session_id.clone());
+                self.loaded_session_home = home;
                 if self.follow_mode {
                     self.transcript_scroll = self.transcript_items.len();
                 }
+                self.request_translations();
+                if let Some(share) = &self.share {
+                    let title = self.sessions[idx].display_title();
+                    share.publish_session(&session_id, &title, &self.transcript_items);
+                }
             }
             Err(e) => {
                 self.last_error = Some(format!("Transcript: {}", e));
@@ -680,31 +2418,52 @@ impl App {
         self.subagents = subagents::find_subagents(&project_dir, &session_id);
         self.subagent_index = 0;
         self.subagent_transcript.clear();
-        self.subagent_reader =
-            transcripts::TranscriptReader::with_tail_lines(self.project_config.tail_lines());
+        self.subagent_reader = self.new_transcript_reader();
         self.viewing_subagent = false;
         self.subagent_scroll = 0;
     }
 
+    /// Reader for a session or subagent transcript, with its line index
+    /// cached under `.associate/cache/transcripts/`.
+    fn new_transcript_reader(&self) -> transcripts::TranscriptReader {
+        let index_dir = self
+            .project_cwd
+            .join(".associate")
+            .join("cache")
+            .join("transcripts");
+        transcripts::TranscriptReader::with_tail_lines(self.project_config.tail_lines())
+            .with_index_cache(index_dir)
+    }
+
     pub fn refresh_transcript(&mut self) {
         if let Some(ref session_id) = self.loaded_session_id.clone() {
-            let project_dir = self
-                .claude_home
-                .join("projects")
-                .join(&self.encoded_project);
+            let project_dir = self.project_dir(self.loaded_session_home);
             let transcript_path = project_dir.join(format!("{}.jsonl", session_id));
 
             match self.transcript_reader.read_new(&transcript_path) {
                 Ok((true, drained)) => {
+                    let seen = self.transcript_items.len().saturating_sub(drained);
                     self.transcript_items = self.transcript_reader.items.clone();
                     if self.follow_mode {
                         self.transcript_scroll = self.transcript_items.len();
-                    } else if drained > 0 {
-                        // Adjust scroll position so the user stays at the same content
+                    } else {
+                        // Adjust positions so the user stays at the same content
                         self.transcript_scroll =
                             self.transcript_scroll.saturating_sub(drained);
+                        self.transcript_unread_from = match self.transcript_unread_from {
+                            Some(idx) => Some(idx.saturating_sub(drained)),
+                            None if self.transcript_items.len() > seen => Some(seen),
+                            None => None,
+                        };
                     }
                     self.last_update = Instant::now();
+                    if let Some(share) = &self.share {
+                        share.publish_items(
+                            &self.transcript_items[seen.min(self.transcript_items.len())..],
+                        );
+                    }
+                    self.request_translations();
+                    self.review_new_plan(seen);
                 }
                 Ok((false, _)) => {}
                 Err(e) => {
@@ -715,9 +2474,14 @@ impl App {
     }
 
     pub fn load_teams(&mut self) {
-        match teams::load_teams(&self.claude_home, Some(&self.project_cwd)) {
+        let loaded = self.load_from_homes(
+            |home| teams::load_teams(home, Some(&self.project_cwd)),
+            |t, home| t.home = home,
+        );
+        match loaded {
             Ok(t) => {
                 self.teams = t;
+                self.index_for_search(SearchSource::Teams);
                 // Clamp indices after reload in case the list shrunk
                 if self.team_list_index >= self.teams.len() {
                     self.team_list_index = self.teams.len().saturating_sub(1);
@@ -734,6 +2498,11 @@ impl App {
                     self.task_list_index = self.tasks.len().saturating_sub(1);
                 }
                 self.compute_agent_statuses();
+                // Rescanning every team's transcripts is the costly part; in
+                // low-power mode the figures from the first scan stay up
+                if !self.low_power || self.team_resources.is_empty() {
+                    self.load_team_resources();
+                }
                 self.last_error = None;
             }
             Err(e) => {
@@ -743,6 +2512,124 @@ impl App {
     }
 
     /// Compute agent statuses for the currently selected team.
+    /// Aggregate every team's transcript usage in the background.
+    pub fn load_team_resources(&mut self) {
+        if self.team_resources_loading || self.teams.is_empty() {
+            return;
+        }
+        let tx = match self.event_tx.clone() {
+            Some(tx) => tx,
+            None => return,
+        };
+        let teams: Vec<(PathBuf, Team)> = self
+            .teams
+            .iter()
+            .map(|t| (self.home_path(t.home).to_path_buf(), t.clone()))
+            .collect();
+        let encoded = self.encoded_project.clone();
+        self.team_resources_loading = true;
+        std::thread::spawn(move || {
+            let resources = teams
+                .iter()
+                .map(|(home, team)| {
+                    let key = (team.home, team.dir_name.clone());
+                    (
+                        key,
+                        team_resources::scan_team_resources(home, &encoded, team),
+                    )
+                })
+                .collect();
+            let _ = tx.send(AppEvent::TeamResourcesLoaded(resources));
+        });
+    }
+
+    pub fn handle_team_resources_loaded(
+        &mut self,
+        resources: HashMap<(usize, String), TeamResources>,
+    ) {
+        self.team_resources_loading = false;
+        self.team_resources = resources;
+
+        let Some(budget) = self.project_config.token_budget() else {
+            return;
+        };
+        let mut over: Vec<((usize, String), u64)> = self
+            .team_resources
+            .iter()
+            .filter(|(key, r)| r.tokens >= budget && !self.budget_notified.contains(*key))
+            .map(|(key, r)| (key.clone(), r.tokens))
+            .collect();
+        over.sort();
+        for (key, tokens) in over {
+            let name = self
+                .teams
+                .iter()
+                .find(|t| t.home == key.0 && t.dir_name == key.1)
+                .map(|t| t.display_name().to_string())
+                .unwrap_or_else(|| key.1.clone());
+            self.notify(Notification {
+                event: NotificationEvent::BudgetExceeded,
+                title: format!("Team {} passed its token budget", name),
+                detail: format!(
+                    "{} tokens used (budget {})",
+                    format_tokens(tokens),
+                    format_tokens(budget)
+                ),
+            });
+            self.budget_notified.insert(key);
+        }
+    }
+
+    // --- Notification helpers ---
+
+    /// Post `notification` to every configured sink that wants its event.
+    /// Sending happens in the background; failures come back as an error.
+    pub fn notify(&mut self, notification: Notification) {
+        let sinks: Vec<NotificationSink> = self
+            .project_config
+            .notification_sinks()
+            .iter()
+            .filter(|s| s.wants(notification.event.key()))
+            .cloned()
+            .collect();
+        if sinks.is_empty() {
+            return;
+        }
+        let tx = match self.event_tx.clone() {
+            Some(tx) => tx,
+            None => return,
+        };
+        let project = self
+            .project_cwd
+            .file_name()
+            .map(|n| n.to_string_lossy().to_string())
+            .unwrap_or_default();
+        std::thread::spawn(move || {
+            let errors = sinks
+                .iter()
+                .filter_map(|sink| {
+                    let template = sink.template.as_deref().unwrap_or(DEFAULT_TEMPLATE);
+                    let message = notification.render(template, &project);
+                    notifications::send(sink, &notification.title, &message)
+                        .err()
+                        .map(|e| format!("{}: {}", sink.kind, e))
+                })
+                .collect();
+            let _ = tx.send(AppEvent::NotificationsSent(errors));
+        });
+    }
+
+    pub fn handle_notifications_sent(&mut self, errors: Vec<String>) {
+        if let Some(first) = errors.first() {
+            self.last_error = Some(format!("Notify: {}", first));
+        }
+    }
+
+    /// Aggregated usage for `team`, once the background scan has finished.
+    pub fn resources_for(&self, team: &Team) -> Option<&TeamResources> {
+        self.team_resources.get(&(team.home, team.dir_name.clone()))
+    }
+
     fn compute_agent_statuses(&mut self) {
         self.agent_statuses.clear();
 
@@ -751,188 +2638,1924 @@ impl App {
         }
 
         let idx = self.team_list_index.min(self.teams.len() - 1);
-        let team = &self.teams[idx];
-
-        // Find the lead agent name to load their inbox
-        let lead_name = team.config.members.iter().find_map(|m| {
-            if m.is_lead(&team.config) {
-                Some(m.name.clone())
-            } else {
-                None
-            }
-        });
+        self.agent_statuses = self.team_agent_statuses(&self.teams[idx], &self.tasks);
+        let statuses = self.agent_statuses.clone();
+        self.record_agent_history(idx, &statuses);
+    }
 
-        // Load the lead's inbox (or first member if no lead identified)
-        let lead_inbox_name = lead_name
-            .as_deref()
-            .or_else(|| team.config.members.first().map(|m| m.name.as_str()));
+    /// Status history for `team`, if any has been recorded.
+    pub fn history_for(&self, team: &Team) -> Option<&StatusHistory> {
+        self.agent_histories
+            .get(&(team.home, team.dir_name.clone()))
+    }
 
-        let lead_inbox = if let Some(name) = lead_inbox_name {
-            inboxes::load_inbox(&self.claude_home, &team.dir_name, name).unwrap_or_default()
-        } else {
-            Vec::new()
-        };
+    /// Add any status transitions for the team at `idx` to its history,
+    /// loading it from disk first and saving it when something changed.
+    fn record_agent_history(&mut self, idx: usize, statuses: &HashMap<String, AgentStatus>) {
+        let team = &self.teams[idx];
+        let path = agent_history::history_path(
+            &config::assoc_home(),
+            &self.encoded_project,
+            &team.dir_name,
+        );
+        let history = self
+            .agent_histories
+            .entry((team.home, team.dir_name.clone()))
+            .or_insert_with(|| agent_history::load_history(&path));
+        if !history.record(statuses, chrono::Utc::now()) {
+            return;
+        }
+        if let Err(e) = agent_history::save_history(&path, history) {
+            self.last_error = Some(format!("Status history: {}", e));
+        }
+    }
 
+    fn team_agent_statuses(&self, team: &Team, tasks: &[Task]) -> HashMap<String, AgentStatus> {
+        let lead_inbox = inboxes::load_lead_inbox(self.home_path(team.home), team);
         let member_names: Vec<&str> = team
             .config
             .members
             .iter()
             .map(|m| m.name.as_str())
             .collect();
-        self.agent_statuses =
-            agent_status::derive_all_statuses(&member_names, &lead_inbox, &self.tasks);
+        agent_status::derive_all_statuses(&member_names, &lead_inbox, tasks)
     }
 
-    pub fn load_tasks_for_selected_team(&mut self) {
-        if self.teams.is_empty() {
-            self.tasks = Vec::new();
-            return;
+    /// Every team's agent statuses once the Overview has loaded them, else
+    /// the selected team's.
+    pub fn all_agent_statuses(&self) -> Vec<AgentStatus> {
+        if self.overview_teams.is_empty() {
+            self.agent_statuses.values().cloned().collect()
+        } else {
+            self.overview_teams
+                .iter()
+                .flat_map(|(_, statuses)| statuses.iter().cloned())
+                .collect()
         }
-        let idx = self.team_list_index.min(self.teams.len() - 1);
-        let team_name = &self.teams[idx].dir_name;
+    }
 
-        match tasks::load_tasks(&self.claude_home, team_name) {
-            Ok(t) => self.tasks = t,
-            Err(e) => {
-                self.last_error = Some(format!("Tasks: {}", e));
-            }
+    /// Derive agent statuses for every team, not just the selected one.
+    pub fn load_overview_teams(&mut self) {
+        let all_statuses: Vec<HashMap<String, AgentStatus>> = self
+            .teams
+            .iter()
+            .map(|team| {
+                let tasks = tasks::load_tasks(self.home_path(team.home), &team.dir_name)
+                    .unwrap_or_default();
+                self.team_agent_statuses(team, &tasks)
+            })
+            .collect();
+        for (idx, statuses) in all_statuses.iter().enumerate() {
+            self.record_agent_history(idx, statuses);
         }
+        self.overview_teams = self
+            .teams
+            .iter()
+            .zip(&all_statuses)
+            .map(|(team, statuses)| {
+                let ordered = team
+                    .config
+                    .members
+                    .iter()
+                    .filter_map(|m| statuses.get(&m.name).cloned())
+                    .collect();
+                (team.display_name().to_string(), ordered)
+            })
+            .collect();
     }
 
-    pub fn load_inbox_for_selected_member(&mut self) {
-        if self.teams.is_empty() {
-            self.inbox_messages = Vec::new();
-            return;
+    /// Summary cards for the Overview tab, one per visible tab they link to.
+    pub fn overview_cards(&self) -> Vec<OverviewCard> {
+        let visible = self.visible_tabs();
+        let now = chrono::Utc::now();
+        let mut cards = Vec::new();
+
+        if visible.contains(&ActiveTab::Sessions) {
+            let active = overview::active_session_count(&self.all_sessions, now);
+            let mut details = vec![format!("{} sessions total", self.all_sessions.len())];
+            if let Some(latest) = self.all_sessions.first() {
+                details.push(format!("Latest: {}", latest.display_title()));
+                if let Some(modified) = latest.modified {
+                    details.push(format!("Updated {}", overview::relative_age(modified, now)));
+                }
+            }
+            cards.push(OverviewCard {
+                title: "Sessions",
+                target: ActiveTab::Sessions,
+                headline: format!("{} active", active),
+                details,
+            });
         }
-        let team_idx = self.team_list_index.min(self.teams.len() - 1);
-        let team_name = self.teams[team_idx].dir_name.clone();
 
-        let members = self.current_team_members();
-        if members.is_empty() {
-            self.inbox_messages = Vec::new();
-            return;
+        if visible.contains(&ActiveTab::Processes) {
+            let running: Vec<&SpawnedProcess> = self
+                .processes
+                .iter()
+                .filter(|p| p.status == ProcessStatus::Running)
+                .collect();
+            let mut details: Vec<String> = running
+                .iter()
+                .map(|p| format!("{} {}", p.label, p.title))
+                .collect();
+            let queued = self
+                .processes
+                .iter()
+                .filter(|p| p.status == ProcessStatus::Queued)
+                .count();
+            if queued > 0 {
+                details.push(format!("{} queued", queued));
+            }
+            let finished = self.processes.len() - running.len() - queued;
+            if finished > 0 {
+                details.push(format!("{} finished", finished));
+            }
+            cards.push(OverviewCard {
+                title: "Processes",
+                target: ActiveTab::Processes,
+                headline: format!("{} running", running.len()),
+                details,
+            });
         }
-        let member_idx = self.member_list_index.min(members.len() - 1);
-        let agent_name = members[member_idx].name.clone();
 
-        match inboxes::load_inbox(&self.claude_home, &team_name, &agent_name) {
-            Ok(msgs) => self.inbox_messages = msgs,
-            Err(_) => self.inbox_messages = Vec::new(),
+        if visible.contains(&ActiveTab::Teams) {
+            cards.push(OverviewCard {
+                title: "Teams",
+                target: ActiveTab::Teams,
+                headline: format!("{} teams", self.overview_teams.len()),
+                details: self
+                    .overview_teams
+                    .iter()
+                    .map(|(name, statuses)| {
+                        format!("{}: {}", name, overview::status_summary(statuses))
+                    })
+                    .collect(),
+            });
         }
-    }
 
-    pub fn load_plans(&mut self) {
-        match plans::load_plans(&self.claude_home) {
-            Ok(p) => {
-                self.plan_files = p;
-                if !self.plan_files.is_empty() && self.plan_file_index >= self.plan_files.len() {
-                    self.plan_file_index = self.plan_files.len() - 1;
-                }
-                // Clamp scroll position to the new content's line count
-                let line_count = self.current_plan_lines().len();
-                if self.plan_content_scroll > line_count {
-                    self.plan_content_scroll = line_count.saturating_sub(1);
-                }
-                self.last_error = None;
-            }
-            Err(e) => {
-                self.last_error = Some(format!("Plans: {}", e));
-            }
+        if visible.contains(&ActiveTab::Git) {
+            let gs = &self.git_status;
+            let dirty = gs.staged.len() + gs.unstaged.len() + gs.untracked.len();
+            cards.push(OverviewCard {
+                title: "Git",
+                target: ActiveTab::Git,
+                headline: format!("{} dirty files", dirty),
+                details: vec![
+                    format!("{} staged", gs.staged.len()),
+                    format!("{} unstaged", gs.unstaged.len()),
+                    format!("{} untracked", gs.untracked.len()),
+                ],
+            });
         }
-    }
 
-    pub fn current_plan_lines(&self) -> &[MarkdownLine] {
-        if self.plan_files.is_empty() {
-            return &[];
+        if visible.contains(&ActiveTab::GitHubPRs) {
+            let user = self.pr_user().unwrap_or("");
+            let to_review: Vec<&PullRequest> = self
+                .gh_prs
+                .iter()
+                .filter(|pr| pr.review_requested_from(user))
+                .collect();
+            let mut details: Vec<String> = to_review
+                .iter()
+                .map(|pr| format!("#{} {}", pr.number, pr.title))
+                .collect();
+            details.push(format!("{} open PRs", self.gh_prs.len()));
+            cards.push(OverviewCard {
+                title: "Pull Requests",
+                target: ActiveTab::GitHubPRs,
+                headline: format!("{} need my review", to_review.len()),
+                details,
+            });
+        }
+
+        if visible.contains(&ActiveTab::GitHubIssues) {
+            let user = self.gh_user.as_deref().unwrap_or("");
+            let assigned: Vec<String> = self
+                .gh_issues
+                .iter()
+                .filter(|i| {
+                    i.assignees
+                        .iter()
+                        .any(|a| a.login.eq_ignore_ascii_case(user))
+                })
+                .map(|i| format!("#{} {}", i.number, i.title))
+                .collect();
+            cards.push(OverviewCard {
+                title: "Issues",
+                target: ActiveTab::GitHubIssues,
+                headline: format!("{} assigned to me", assigned.len()),
+                details: assigned,
+            });
+        }
+
+        if visible.contains(&ActiveTab::Jira) {
+            // The default JQL only returns my unresolved issues
+            let assigned: Vec<String> = self
+                .jira_issues
+                .iter()
+                .filter(|i| BOARD_COLUMNS[i.board_column()] != "Done")
+                .map(|i| format!("{} {}", i.key, i.summary))
+                .collect();
+            cards.push(OverviewCard {
+                title: "Jira",
+                target: ActiveTab::Jira,
+                headline: format!("{} assigned to me", assigned.len()),
+                details: assigned,
+            });
+        }
+
+        if visible.contains(&ActiveTab::Linear) {
+            let username = self.project_config.linear_username();
+            let assigned: Vec<String> = self
+                .linear_issues
+                .iter()
+                .filter(|i| match (&i.assignee, username) {
+                    (Some(a), Some(user)) => a
+                        .email
+                        .as_deref()
+                        .is_some_and(|e| e.eq_ignore_ascii_case(user)),
+                    (Some(_), None) => true,
+                    (None, _) => false,
+                })
+                .map(|i| format!("{} {}", i.identifier, i.title))
+                .collect();
+            cards.push(OverviewCard {
+                title: "Linear",
+                target: ActiveTab::Linear,
+                headline: format!("{} assigned to me", assigned.len()),
+                details: assigned,
+            });
+        }
+
+        if visible.contains(&ActiveTab::Azure) {
+            // The default WIQL only returns my open work items
+            let assigned: Vec<String> = self
+                .azure_items
+                .iter()
+                .filter(|i| !i.is_closed())
+                .map(|i| format!("{} {}", i.key(), i.title))
+                .collect();
+            cards.push(OverviewCard {
+                title: "Work Items",
+                target: ActiveTab::Azure,
+                headline: format!("{} assigned to me", assigned.len()),
+                details: assigned,
+            });
+        }
+
+        for card in &mut cards {
+            if self.tab_loading(&card.target) {
+                card.headline = "Loading...".to_string();
+                card.details.clear();
+            }
+        }
+
+        cards
+    }
+
+    /// Open the task editor for a new task in the selected team.
+    pub fn task_start_new(&mut self) {
+        if self.teams.is_empty() {
+            return;
+        }
+        self.open_task_editor(None);
+    }
+
+    /// Open the task editor on the selected task.
+    pub fn task_start_edit(&mut self) {
+        if let Some(task) = self.tasks.get(self.task_list_index).cloned() {
+            self.open_task_editor(Some(task));
+        }
+    }
+
+    pub fn toggle_task_graph(&mut self) {
+        self.task_graph = !self.task_graph;
+        self.detail_scroll = 0;
+    }
+
+    fn open_task_editor(&mut self, task: Option<Task>) {
+        let editor = |text: &str| {
+            let mut ed = tui_textarea::TextArea::default();
+            ed.set_cursor_line_style(ratatui::style::Style::default());
+            if !text.is_empty() {
+                ed.insert_str(text);
+                ed.move_cursor(tui_textarea::CursorMove::Top);
+                ed.move_cursor(tui_textarea::CursorMove::Head);
+            }
+            ed
+        };
+        let task_id = task.as_ref().map(|t| t.id.clone());
+        let candidates = self
+            .tasks
+            .iter()
+            .filter(|t| Some(&t.id) != task_id.as_ref())
+            .map(|t| t.id.clone())
+            .collect();
+        self.task_editor = Some(TaskEditor {
+            task_id,
+            field: TaskEditField::Subject,
+            subject: editor(
+                task.as_ref()
+                    .and_then(|t| t.subject.as_deref())
+                    .unwrap_or(""),
+            ),
+            description: editor(
+                task.as_ref()
+                    .and_then(|t| t.description.as_deref())
+                    .unwrap_or(""),
+            ),
+            owner: task.as_ref().and_then(|t| t.owner.clone()),
+            candidates,
+            blocked_by: task.map(|t| t.blocked_by).unwrap_or_default(),
+            blocked_index: 0,
+        });
+    }
+
+    pub fn task_editor_cycle_field(&mut self, forward: bool) {
+        if let Some(editor) = self.task_editor.as_mut() {
+            editor.field = editor.field.cycle(forward);
+        }
+    }
+
+    /// Step the owner through "unassigned" and the team's members.
+    pub fn task_editor_cycle_owner(&mut self, forward: bool) {
+        let mut names: Vec<Option<String>> = vec![None];
+        names.extend(
+            self.current_team_members()
+                .iter()
+                .map(|m| Some(m.name.clone())),
+        );
+        let Some(editor) = self.task_editor.as_mut() else {
+            return;
+        };
+        let n = names.len();
+        let i = names.iter().position(|o| *o == editor.owner).unwrap_or(0);
+        editor.owner = names[if forward {
+            (i + 1) % n
+        } else {
+            (i + n - 1) % n
+        }]
+        .clone();
+    }
+
+    pub fn task_editor_move_blocker(&mut self, down: bool) {
+        if let Some(editor) = self.task_editor.as_mut() {
+            if down {
+                if editor.blocked_index + 1 < editor.candidates.len() {
+                    editor.blocked_index += 1;
+                }
+            } else {
+                editor.blocked_index = editor.blocked_index.saturating_sub(1);
+            }
+        }
+    }
+
+    pub fn task_editor_toggle_blocker(&mut self) {
+        let Some(editor) = self.task_editor.as_mut() else {
+            return;
+        };
+        let Some(id) = editor.candidates.get(editor.blocked_index).cloned() else {
+            return;
+        };
+        if let Some(pos) = editor.blocked_by.iter().position(|b| *b == id) {
+            editor.blocked_by.remove(pos);
+        } else {
+            editor.blocked_by.push(id);
+        }
+    }
+
+    /// Write the edited task to `tasks/<team>/` and reload the task list.
+    pub fn task_save_edit(&mut self) {
+        let Some(editor) = self.task_editor.as_ref() else {
+            return;
+        };
+        if self.teams.is_empty() {
+            return;
+        }
+        let draft = TaskDraft {
+            subject: editor.subject.lines().join(" ").trim().to_string(),
+            description: editor.description.lines().join("\n").trim_end().to_string(),
+            owner: editor.owner.clone(),
+            blocked_by: editor.blocked_by.clone(),
+        };
+        if draft.subject.is_empty() {
+            self.last_error = Some("Subject cannot be empty".to_string());
+            return;
+        }
+        let idx = self.team_list_index.min(self.teams.len() - 1);
+        let team = &self.teams[idx];
+        let result = tasks::save_task(
+            self.home_path(team.home),
+            &team.dir_name,
+            editor.task_id.as_deref(),
+            &draft,
+        );
+        match result {
+            Ok(id) => {
+                self.task_editor = None;
+                self.load_tasks_for_selected_team();
+                if let Some(pos) = self.tasks.iter().position(|t| t.id == id) {
+                    self.task_list_index = pos;
+                }
+                self.compute_agent_statuses();
+                self.send_status = Some((format!("Saved task #{}", id), Instant::now()));
+            }
+            Err(e) => self.last_error = Some(format!("Task save: {}", e)),
+        }
+    }
+
+    pub fn task_cancel_edit(&mut self) {
+        self.task_editor = None;
+    }
+
+    /// Open the composer for a message to every member of the selected team.
+    pub fn broadcast_start(&mut self) {
+        if self.broadcast_recipients().is_empty() {
+            self.last_error = Some("Broadcast: the team has no members".to_string());
+            return;
+        }
+        let mut editor = tui_textarea::TextArea::default();
+        editor.set_cursor_line_style(ratatui::style::Style::default());
+        self.broadcast = Some(BroadcastComposer {
+            editor,
+            template: None,
+            confirming: false,
+        });
+    }
+
+    /// Members of the selected team a broadcast goes to.
+    pub fn broadcast_recipients(&self) -> Vec<String> {
+        self.current_team_members()
+            .iter()
+            .map(|m| m.name.clone())
+            .collect()
+    }
+
+    /// Replace the message with the next (or previous) built-in template.
+    pub fn broadcast_cycle_template(&mut self, forward: bool) {
+        let Some(composer) = self.broadcast.as_mut() else {
+            return;
+        };
+        let count = BROADCAST_TEMPLATES.len();
+        let next = match (composer.template, forward) {
+            (None, true) => 0,
+            (None, false) => count - 1,
+            (Some(i), true) => (i + 1) % count,
+            (Some(i), false) => (i + count - 1) % count,
+        };
+        let mut editor = tui_textarea::TextArea::default();
+        editor.set_cursor_line_style(ratatui::style::Style::default());
+        editor.insert_str(BROADCAST_TEMPLATES[next].1);
+        composer.editor = editor;
+        composer.template = Some(next);
+    }
+
+    /// Ask for confirmation before sending, unless the message is empty.
+    pub fn broadcast_request_send(&mut self) {
+        let Some(composer) = self.broadcast.as_mut() else {
+            return;
+        };
+        if composer.editor.lines().join("\n").trim().is_empty() {
+            self.last_error = Some("Message cannot be empty".to_string());
+            return;
+        }
+        composer.confirming = true;
+    }
+
+    /// Leave the confirmation for the editor, or close the composer.
+    pub fn broadcast_cancel(&mut self) {
+        match self.broadcast.as_mut() {
+            Some(composer) if composer.confirming => composer.confirming = false,
+            _ => self.broadcast = None,
+        }
+    }
+
+    /// Write the message to every recipient's inbox.
+    pub fn broadcast_send(&mut self) {
+        let Some(composer) = self.broadcast.take() else {
+            return;
+        };
+        if self.teams.is_empty() {
+            return;
+        }
+        let text = composer.editor.lines().join("\n").trim().to_string();
+        let idx = self.team_list_index.min(self.teams.len() - 1);
+        let team = &self.teams[idx];
+        let home = self.home_path(team.home);
+        let now = chrono::Utc::now();
+        let recipients = self.broadcast_recipients();
+        let failed: Vec<String> = recipients
+            .iter()
+            .filter_map(|name| {
+                inboxes::append_message(home, &team.dir_name, name, &text, now)
+                    .err()
+                    .map(|e| format!("{}: {}", name, e))
+            })
+            .collect();
+
+        let sent = recipients.len() - failed.len();
+        if let Some(first) = failed.first() {
+            self.last_error = Some(format!("Broadcast: {}", first));
+        }
+        if sent > 0 {
+            let noun = if sent == 1 { "member" } else { "members" };
+            self.send_status = Some((format!("Sent to {} {}", sent, noun), Instant::now()));
+        }
+        self.load_inbox_for_selected_member();
+    }
+
+    pub fn load_tasks_for_selected_team(&mut self) {
+        if self.teams.is_empty() {
+            self.tasks = Vec::new();
+            return;
+        }
+        let idx = self.team_list_index.min(self.teams.len() - 1);
+        let team = &self.teams[idx];
+
+        match tasks::load_tasks(self.home_path(team.home), &team.dir_name) {
+            Ok(t) => self.tasks = t,
+            Err(e) => {
+                self.last_error = Some(format!("Tasks: {}", e));
+            }
+        }
+    }
+
+    /// Open the selected member's transcript over the Teams tab.
+    pub fn open_member_transcript(&mut self) {
+        if self.teams.is_empty() {
+            return;
+        }
+        let team = &self.teams[self.team_list_index.min(self.teams.len() - 1)];
+        let members = self.current_team_members();
+        let Some(member) = members.get(self.member_list_index.min(members.len().saturating_sub(1)))
+        else {
+            return;
+        };
+        let found = team_resources::member_transcript(
+            self.home_path(team.home),
+            &self.encoded_project,
+            team,
+            member,
+        );
+        let member = member.name.clone();
+        let Some(path) = found else {
+            self.send_status = Some((
+                format!("No transcript found for {}", member),
+                Instant::now(),
+            ));
+            return;
+        };
+        let mut reader = self.new_transcript_reader();
+        if let Err(e) = reader.load_initial(&path) {
+            self.last_error = Some(format!("Transcript: {}", e));
+            return;
+        }
+        self.member_transcript = Some(MemberTranscript {
+            member,
+            path,
+            reader,
+            scroll: 0,
+            follow: true,
+        });
+    }
+
+    /// Read what the open member transcript gained since the last read.
+    pub fn refresh_member_transcript(&mut self) {
+        let Some(view) = self.member_transcript.as_mut() else {
+            return;
+        };
+        match view.reader.read_new(&view.path) {
+            Ok((_, drained)) => view.scroll = view.scroll.saturating_sub(drained),
+            Err(e) => self.last_error = Some(format!("Transcript: {}", e)),
+        }
+    }
+
+    /// Scroll the member transcript by `delta` items; scrolling up stops
+    /// following, and reaching the end again resumes it.
+    pub fn member_transcript_scroll(&mut self, delta: isize) {
+        let Some(view) = self.member_transcript.as_mut() else {
+            return;
+        };
+        let last = view.reader.items.len().saturating_sub(1);
+        let from = if view.follow { last } else { view.scroll };
+        view.scroll = from.saturating_add_signed(delta).min(last);
+        view.follow = view.scroll == last;
+    }
+
+    /// Jump to the first items, or back to following the newest.
+    pub fn member_transcript_jump(&mut self, bottom: bool) {
+        if let Some(view) = self.member_transcript.as_mut() {
+            view.scroll = 0;
+            view.follow = bottom;
+        }
+    }
+
+    pub fn close_member_transcript(&mut self) {
+        self.member_transcript = None;
+    }
+
+    pub fn load_inbox_for_selected_member(&mut self) {
+        if self.teams.is_empty() {
+            self.inbox_messages = Vec::new();
+            return;
+        }
+        let team_idx = self.team_list_index.min(self.teams.len() - 1);
+        let team_name = self.teams[team_idx].dir_name.clone();
+        let team_home = self.home_path(self.teams[team_idx].home).to_path_buf();
+
+        let members = self.current_team_members();
+        if members.is_empty() {
+            self.inbox_messages = Vec::new();
+            return;
+        }
+        let member_idx = self.member_list_index.min(members.len() - 1);
+        let agent_name = members[member_idx].name.clone();
+
+        match inboxes::load_inbox(&team_home, &team_name, &agent_name) {
+            Ok(msgs) => self.inbox_messages = msgs,
+            Err(_) => self.inbox_messages = Vec::new(),
+        }
+    }
+
+    pub fn load_plans(&mut self) {
+        match plans::load_plans(&self.claude_home) {
+            Ok(p) => {
+                self.plan_files = p;
+                self.index_for_search(SearchSource::Plans);
+                if !self.plan_files.is_empty() && self.plan_file_index >= self.plan_files.len() {
+                    self.plan_file_index = self.plan_files.len() - 1;
+                }
+                // Clamp scroll position to the new content's line count
+                let line_count = self.current_plan_lines().len();
+                if self.plan_content_scroll > line_count {
+                    self.plan_content_scroll = line_count.saturating_sub(1);
+                }
+                let headings = self.current_plan_outline().len();
+                self.plan_outline_index = self.plan_outline_index.min(headings.saturating_sub(1));
+                self.last_error = None;
+            }
+            Err(e) => {
+                self.last_error = Some(format!("Plans: {}", e));
+            }
+        }
+    }
+
+    pub fn current_plan_lines(&self) -> &[MarkdownLine] {
+        if self.plan_files.is_empty() {
+            return &[];
+        }
+        let idx = self.plan_file_index.min(self.plan_files.len() - 1);
+        &self.plan_files[idx].lines
+    }
+
+    /// Headings of the selected plan, for the outline sidebar.
+    pub fn current_plan_outline(&self) -> Vec<OutlineEntry> {
+        plans::outline(self.current_plan_lines())
+    }
+
+    /// Propose tasks from the selected plan for the team selected on the
+    /// Teams tab.
+    pub fn plan_tasks_open(&mut self) {
+        let Some(plan) = self.plan_files.get(self.plan_file_index) else {
+            return;
+        };
+        if self.teams.is_empty() {
+            self.last_error = Some("No team to add tasks to".to_string());
+            return;
+        }
+        let drafts = plans::plan_tasks(&plan.filename, &plan.lines);
+        if drafts.is_empty() {
+            self.last_error = Some(format!(
+                "No unchecked checklist items or headings in {}",
+                plan.filename
+            ));
+            return;
+        }
+        let team = self.team_list_index.min(self.teams.len() - 1);
+        self.plan_tasks = Some(PlanTasksPreview {
+            plan: plan.filename.clone(),
+            team,
+            tasks: self.propose_plan_tasks(team, drafts),
+            index: 0,
+        });
+    }
+
+    /// `drafts` checked for duplicates against team `team`'s tasks.
+    fn propose_plan_tasks(&self, team: usize, drafts: Vec<TaskDraft>) -> Vec<PlanTask> {
+        let existing = self
+            .teams
+            .get(team)
+            .and_then(|t| tasks::load_tasks(self.home_path(t.home), &t.dir_name).ok())
+            .unwrap_or_default();
+        propose_tasks(drafts, &existing)
+    }
+
+    /// Propose the tasks to the next team instead.
+    pub fn plan_tasks_next_team(&mut self) {
+        let Some(preview) = self.plan_tasks.as_ref() else {
+            return;
+        };
+        let team = (preview.team + 1) % self.teams.len().max(1);
+        let drafts = preview.tasks.iter().map(|t| t.draft.clone()).collect();
+        let tasks = self.propose_plan_tasks(team, drafts);
+        if let Some(preview) = self.plan_tasks.as_mut() {
+            preview.team = team;
+            preview.tasks = tasks;
+        }
+    }
+
+    pub fn plan_tasks_move(&mut self, down: bool) {
+        if let Some(preview) = self.plan_tasks.as_mut() {
+            preview.index = if down {
+                (preview.index + 1).min(preview.tasks.len().saturating_sub(1))
+            } else {
+                preview.index.saturating_sub(1)
+            };
+        }
+    }
+
+    pub fn plan_tasks_toggle(&mut self) {
+        if let Some(preview) = self.plan_tasks.as_mut() {
+            if let Some(task) = preview.tasks.get_mut(preview.index) {
+                task.selected = !task.selected;
+            }
+        }
+    }
+
+    /// Create the selected tasks, unowned, under the preview's team.
+    pub fn plan_tasks_create(&mut self) {
+        let Some(preview) = self.plan_tasks.as_ref() else {
+            return;
+        };
+        let Some(team) = self.teams.get(preview.team) else {
+            return;
+        };
+        let selected: Vec<&TaskDraft> = preview
+            .tasks
+            .iter()
+            .filter(|t| t.selected)
+            .map(|t| &t.draft)
+            .collect();
+        if selected.is_empty() {
+            self.last_error = Some("No tasks selected".to_string());
+            return;
+        }
+        let home = self.home_path(team.home).to_path_buf();
+        let mut created = 0;
+        let mut error = None;
+        for draft in &selected {
+            match tasks::save_task(&home, &team.dir_name, None, draft) {
+                Ok(_) => created += 1,
+                Err(e) => {
+                    error = Some(format!("Task save: {}", e));
+                    break;
+                }
+            }
+        }
+        let status = format!(
+            "Created {} task(s) in {} from {}",
+            created,
+            team.display_name(),
+            preview.plan
+        );
+        let team = preview.team;
+        self.plan_tasks = None;
+        if created > 0 {
+            self.send_status = Some((status, Instant::now()));
+        }
+        if let Some(error) = error {
+            self.last_error = Some(error);
+        }
+        if team == self.team_list_index.min(self.teams.len() - 1) {
+            self.load_tasks_for_selected_team();
+            self.compute_agent_statuses();
+        }
+    }
+
+    /// Select an outline entry and scroll the content to its heading.
+    fn plan_select_section(&mut self, index: usize) {
+        if let Some(entry) = self.current_plan_outline().get(index) {
+            self.plan_outline_index = index;
+            self.plan_content_scroll = entry.line;
+        }
+    }
+
+    // --- Plan editing ---
+
+    pub fn plan_start_edit(&mut self) {
+        if self.plan_files.is_empty() {
+            return;
+        }
+        let idx = self.plan_file_index.min(self.plan_files.len() - 1);
+        let filename = self.plan_files[idx].filename.clone();
+        let path = self.claude_home.join("plans").join(&filename);
+        match std::fs::read_to_string(&path) {
+            Ok(text) => {
+                let mut editor = tui_textarea::TextArea::default();
+                editor.insert_str(&text);
+                editor.move_cursor(tui_textarea::CursorMove::Top);
+                editor.move_cursor(tui_textarea::CursorMove::Head);
+                self.plan_editor = Some(editor);
+                self.plan_editing = true;
+                self.plan_edit_filename = Some(filename);
+                self.plan_edit_base = text;
+            }
+            Err(e) => self.last_error = Some(format!("Plans: {}", e)),
+        }
+    }
+
+    /// Save the editor's text, unless the file changed on disk since it was
+    /// opened — then offer a merge first so the agent's edits aren't lost.
+    pub fn plan_save_edit(&mut self) {
+        let Some(filename) = self.plan_edit_filename.clone() else {
+            return;
+        };
+        let path = self.claude_home.join("plans").join(&filename);
+        if self.plan_open_merge(&path) {
+            self.send_status = Some((
+                "Plan changed on disk — review the merge, then save again".to_string(),
+                Instant::now(),
+            ));
+            return;
+        }
+        let content = self.plan_editor_text() + "\n";
+        if let Err(e) = plans::save_plan(&self.claude_home, &filename, &content) {
+            self.last_error = Some(format!("Save: {}", e));
+            return;
+        }
+        self.plan_cancel_edit();
+        self.load_plans();
+    }
+
+    pub fn plan_cancel_edit(&mut self) {
+        self.plan_editing = false;
+        self.plan_editor = None;
+        self.plan_edit_filename = None;
+        self.plan_edit_base.clear();
+        self.plan_merge = None;
+    }
+
+    fn plan_editor_text(&self) -> String {
+        self.plan_editor
+            .as_ref()
+            .map(|e| e.lines().join("\n"))
+            .unwrap_or_default()
+    }
+
+    fn plan_replace_editor_text(&mut self, text: &str) {
+        let mut editor = tui_textarea::TextArea::default();
+        editor.insert_str(text);
+        editor.move_cursor(tui_textarea::CursorMove::Top);
+        editor.move_cursor(tui_textarea::CursorMove::Head);
+        self.plan_editor = Some(editor);
+    }
+
+    /// Called from the watcher when a plan file changes.
+    fn plan_check_external_change(&mut self, path: &std::path::Path) {
+        if !self.plan_editing || self.plan_merge.is_some() {
+            return;
+        }
+        let is_open = path
+            .file_name()
+            .map(|n| Some(n.to_string_lossy().as_ref()) == self.plan_edit_filename.as_deref())
+            .unwrap_or(false);
+        if is_open && self.plan_open_merge(path) {
+            self.send_status = Some((
+                "Plan changed on disk — review incoming edits".to_string(),
+                Instant::now(),
+            ));
+        }
+    }
+
+    /// Reconcile the editor with the file at `path`. Returns true when a
+    /// merge session was opened for the user to resolve.
+    fn plan_open_merge(&mut self, path: &std::path::Path) -> bool {
+        let Ok(disk) = std::fs::read_to_string(path) else {
+            return false;
+        };
+        if same_lines(&disk, &self.plan_edit_base) {
+            return false;
+        }
+        let mine = self.plan_editor_text();
+        if same_lines(&mine, &self.plan_edit_base) {
+            // No local edits yet: just follow the file.
+            self.plan_replace_editor_text(&disk);
+            self.plan_edit_base = disk;
+            return false;
+        }
+        let chunks = merge::three_way_merge(&self.plan_edit_base, &mine, &disk);
+        let session = MergeSession::new(chunks, disk);
+        if session.hunk_indices().is_empty() {
+            self.plan_edit_base = session.theirs_text;
+            return false;
+        }
+        self.plan_merge = Some(session);
+        true
+    }
+
+    pub fn plan_merge_next(&mut self) {
+        if let Some(ref mut m) = self.plan_merge {
+            let count = m.hunk_indices().len();
+            if m.selected + 1 < count {
+                m.selected += 1;
+            }
+        }
+    }
+
+    pub fn plan_merge_prev(&mut self) {
+        if let Some(ref mut m) = self.plan_merge {
+            m.selected = m.selected.saturating_sub(1);
+        }
+    }
+
+    pub fn plan_merge_decide(&mut self, accept: bool) {
+        if let Some(ref mut m) = self.plan_merge {
+            m.set_selected(accept);
+        }
+    }
+
+    /// Apply the hunk decisions to the editor. With `keep_mine` every hunk
+    /// is rejected. Either way the disk version becomes the new base, so a
+    /// later save is a deliberate overwrite.
+    pub fn plan_merge_finish(&mut self, keep_mine: bool) {
+        let Some(mut session) = self.plan_merge.take() else {
+            return;
+        };
+        if keep_mine {
+            for accepted in session.accepted.iter_mut() {
+                *accepted = false;
+            }
+        }
+        let merged = session.merged_text();
+        if merged != self.plan_editor_text() {
+            self.plan_replace_editor_text(&merged);
+        }
+        self.plan_edit_base = session.theirs_text;
+    }
+
+    // --- Notes tab ---
+
+    pub fn load_notes(&mut self) {
+        self.notes_text = notes::load_notes(&notes::notes_path(&self.project_cwd));
+        self.notes_lines = plans::parse_markdown_lines(&self.notes_text);
+    }
+
+    pub fn notes_start_edit(&mut self) {
+        self.load_notes();
+        let mut editor = tui_textarea::TextArea::default();
+        editor.insert_str(&self.notes_text);
+        // Notes grow at the bottom, so start there
+        editor.move_cursor(tui_textarea::CursorMove::Bottom);
+        editor.move_cursor(tui_textarea::CursorMove::End);
+        self.notes_editor = Some(editor);
+        self.notes_editing = true;
+    }
+
+    pub fn notes_save_edit(&mut self) {
+        let content = self
+            .notes_editor
+            .as_ref()
+            .map(|e| e.lines().join("\n"))
+            .unwrap_or_default();
+        let content = format!("{}\n", content.trim_end());
+        if let Err(e) = notes::save_notes(&notes::notes_path(&self.project_cwd), &content) {
+            self.last_error = Some(format!("Save: {}", e));
+            return;
+        }
+        self.notes_cancel_edit();
+        self.load_notes();
+    }
+
+    pub fn notes_cancel_edit(&mut self) {
+        self.notes_editing = false;
+        self.notes_editor = None;
+    }
+
+    pub fn toggle_notes_raw(&mut self) {
+        self.notes_raw = !self.notes_raw;
+        self.notes_scroll = 0;
+    }
+
+    /// Start jotting a note, from whichever tab is showing.
+    pub fn start_note_input(&mut self) {
+        self.note_input = Some(String::new());
+    }
+
+    /// Append the jotted note to the notes file as a timestamped bullet.
+    pub fn submit_note_input(&mut self) {
+        let Some(text) = self.note_input.take() else {
+            return;
+        };
+        if text.trim().is_empty() {
+            return;
+        }
+        let path = notes::notes_path(&self.project_cwd);
+        match notes::append_note(&path, &text, chrono::Local::now()) {
+            Ok(()) => {
+                self.send_status = Some(("Added to notes".to_string(), Instant::now()));
+                self.load_notes();
+            }
+            Err(e) => self.last_error = Some(format!("Notes: {}", e)),
+        }
+    }
+
+    // --- Hooks tab ---
+
+    pub fn load_hooks(&mut self) {
+        self.hook_files = hooks::load_hooks(&self.project_cwd, &self.claude_home);
+        self.hook_flat_list = hooks::flatten(&self.hook_files);
+        self.hook_index = self
+            .hook_index
+            .min(self.hook_flat_list.len().saturating_sub(1));
+        self.hook_settle();
+    }
+
+    /// Select the next (or previous) hook or problem, skipping headers.
+    fn hook_move(&mut self, forward: bool) {
+        let list = &self.hook_flat_list;
+        let found = if forward {
+            (self.hook_index + 1..list.len()).find(|&i| list[i].is_selectable())
+        } else {
+            (0..self.hook_index.min(list.len()))
+                .rev()
+                .find(|&i| list[i].is_selectable())
+        };
+        if let Some(i) = found {
+            self.hook_index = i;
+            self.hook_detail_scroll = 0;
+        }
+    }
+
+    /// Move off a header onto the nearest row below it, or above it at
+    /// the end of the list.
+    fn hook_settle(&mut self) {
+        let list = &self.hook_flat_list;
+        if list
+            .get(self.hook_index)
+            .is_some_and(FlatHookItem::is_selectable)
+        {
+            return;
+        }
+        let below = (self.hook_index..list.len()).find(|&i| list[i].is_selectable());
+        let above = (0..self.hook_index.min(list.len()))
+            .rev()
+            .find(|&i| list[i].is_selectable());
+        self.hook_index = below.or(above).unwrap_or(0);
+    }
+
+    pub fn selected_hook_item(&self) -> Option<&FlatHookItem> {
+        self.hook_flat_list.get(self.hook_index)
+    }
+
+    pub fn selected_hook(&self) -> Option<&Hook> {
+        match self.selected_hook_item()? {
+            FlatHookItem::Hook(hook) => Some(hook),
+            _ => None,
+        }
+    }
+
+    /// Path of the settings file for `scope`.
+    pub fn hook_settings_path(&self, scope: SettingsScope) -> PathBuf {
+        hooks::settings_path(scope, &self.project_cwd, &self.claude_home)
+    }
+
+    /// Open the form for a new hook: a project-scope PreToolUse hook, or
+    /// under the selected hook's event.
+    pub fn hook_start_new(&mut self) {
+        let event = self
+            .selected_hook()
+            .map(|h| h.event.clone())
+            .unwrap_or_else(|| HOOK_EVENTS[0].to_string());
+        self.hook_form = Some(HookForm {
+            scope: SettingsScope::Project,
+            event,
+            matcher: String::new(),
+            kind: "command".to_string(),
+            command: String::new(),
+            timeout: String::new(),
+            field: HookField::Scope,
+            editing: None,
+        });
+    }
+
+    /// Open the form on the selected hook.
+    pub fn hook_start_edit(&mut self) {
+        let Some(hook) = self.selected_hook().cloned() else {
+            return;
+        };
+        self.hook_form = Some(HookForm {
+            scope: hook.scope,
+            event: hook.event.clone(),
+            matcher: hook.matcher.clone().unwrap_or_default(),
+            kind: hook.kind.clone(),
+            command: hook.command.clone(),
+            timeout: hook.timeout.map(|t| t.to_string()).unwrap_or_default(),
+            field: HookField::Command,
+            editing: Some(hook),
+        });
+    }
+
+    pub fn hook_cancel_form(&mut self) {
+        self.hook_form = None;
+    }
+
+    /// Move to the next (or previous) field of the form.
+    pub fn hook_form_next_field(&mut self, forward: bool) {
+        let Some(form) = self.hook_form.as_mut() else {
+            return;
+        };
+        let fields = HookField::ALL;
+        let i = fields.iter().position(|f| *f == form.field).unwrap_or(0);
+        let len = fields.len();
+        form.field = fields[if forward {
+            (i + 1) % len
+        } else {
+            (i + len - 1) % len
+        }];
+    }
+
+    /// Step the scope or event field through its choices.
+    pub fn hook_form_cycle(&mut self, forward: bool) {
+        let Some(form) = self.hook_form.as_mut() else {
+            return;
+        };
+        let step = |i: usize, len: usize| {
+            if forward {
+                (i + 1) % len
+            } else {
+                (i + len - 1) % len
+            }
+        };
+        match form.field {
+            HookField::Scope => {
+                let scopes = SettingsScope::ALL;
+                let i = scopes.iter().position(|s| *s == form.scope).unwrap_or(0);
+                form.scope = scopes[step(i, scopes.len())];
+            }
+            HookField::Event => {
+                // An unknown event being edited steps onto the known ones
+                let i = HOOK_EVENTS
+                    .iter()
+                    .position(|e| *e == form.event)
+                    .unwrap_or(HOOK_EVENTS.len() - 1);
+                form.event = HOOK_EVENTS[step(i, HOOK_EVENTS.len())].to_string();
+            }
+            _ => {}
+        }
+    }
+
+    pub fn hook_form_input(&mut self, c: char) {
+        let Some(form) = self.hook_form.as_mut() else {
+            return;
+        };
+        match form.field {
+            HookField::Matcher => form.matcher.push(c),
+            HookField::Command => form.command.push(c),
+            HookField::Timeout if c.is_ascii_digit() => form.timeout.push(c),
+            HookField::Scope | HookField::Event if c == ' ' => self.hook_form_cycle(true),
+            _ => {}
+        }
+    }
+
+    pub fn hook_form_backspace(&mut self) {
+        let Some(form) = self.hook_form.as_mut() else {
+            return;
+        };
+        match form.field {
+            HookField::Matcher => {
+                form.matcher.pop();
+            }
+            HookField::Command => {
+                form.command.pop();
+            }
+            HookField::Timeout => {
+                form.timeout.pop();
+            }
+            HookField::Scope | HookField::Event => {}
+        }
+    }
+
+    /// Write the form's hook. Editing a hook into another scope moves it
+    /// to that scope's file.
+    pub fn hook_submit_form(&mut self) {
+        let Some(form) = self.hook_form.clone() else {
+            return;
+        };
+        let command = form.command.trim();
+        if command.is_empty() {
+            self.last_error = Some(format!("Hooks: the {} is empty", form.kind));
+            return;
+        }
+        let matcher = form.matcher.trim();
+        let draft = HookDraft {
+            event: form.event.clone(),
+            matcher: (!matcher.is_empty() && matcher != "*").then(|| matcher.to_string()),
+            kind: form.kind.clone(),
+            command: command.to_string(),
+            timeout: form.timeout.parse().ok().filter(|&t| t > 0),
+        };
+        let path = self.hook_settings_path(form.scope);
+        let result = match &form.editing {
+            Some(old) if old.scope == form.scope => hooks::save_hook(&path, Some(old), &draft),
+            Some(old) => hooks::save_hook(&path, None, &draft)
+                .and_then(|()| hooks::remove_hook(&self.hook_settings_path(old.scope), old)),
+            None => hooks::save_hook(&path, None, &draft),
+        };
+        if let Err(e) = result {
+            self.last_error = Some(format!("Hooks: {:#}", e));
+            return;
+        }
+        self.hook_form = None;
+        self.load_hooks();
+        // Select the saved hook
+        let saved = self.hook_flat_list.iter().position(|item| {
+            matches!(item, FlatHookItem::Hook(h) if h.scope == form.scope
+                && h.event == draft.event
+                && h.matcher == draft.matcher
+                && h.command == draft.command)
+        });
+        if let Some(i) = saved {
+            self.hook_index = i;
+            self.hook_detail_scroll = 0;
+        }
+        let verb = if form.editing.is_some() {
+            "Saved"
+        } else {
+            "Added"
+        };
+        self.send_status = Some((
+            format!("{} {} hook in {}", verb, draft.event, path.display()),
+            Instant::now(),
+        ));
+    }
+
+    // --- Settings tab ---
+
+    pub fn load_settings(&mut self) {
+        self.claude_settings = claude_settings::load_settings(&self.project_cwd, &self.claude_home);
+        self.settings_flat_list = claude_settings::flatten(&self.claude_settings);
+        self.settings_index = self
+            .settings_index
+            .min(self.settings_flat_list.len().saturating_sub(1));
+        self.settings_settle();
+    }
+
+    /// Select the next (or previous) value or problem, skipping headers.
+    fn settings_move(&mut self, forward: bool) {
+        let list = &self.settings_flat_list;
+        let found = if forward {
+            (self.settings_index + 1..list.len()).find(|&i| list[i].is_selectable())
+        } else {
+            (0..self.settings_index.min(list.len()))
+                .rev()
+                .find(|&i| list[i].is_selectable())
+        };
+        if let Some(i) = found {
+            self.settings_index = i;
+            self.settings_detail_scroll = 0;
+        }
+    }
+
+    /// Move off a header onto the nearest row below it, or above it at
+    /// the end of the list.
+    fn settings_settle(&mut self) {
+        let list = &self.settings_flat_list;
+        if list
+            .get(self.settings_index)
+            .is_some_and(FlatSettingsItem::is_selectable)
+        {
+            return;
+        }
+        let below = (self.settings_index..list.len()).find(|&i| list[i].is_selectable());
+        let above = (0..self.settings_index.min(list.len()))
+            .rev()
+            .find(|&i| list[i].is_selectable());
+        self.settings_index = below.or(above).unwrap_or(0);
+    }
+
+    pub fn selected_settings_item(&self) -> Option<&FlatSettingsItem> {
+        self.settings_flat_list.get(self.settings_index)
+    }
+
+    // --- Commands tab ---
+
+    pub fn load_commands(&mut self) {
+        self.command_files = commands::load_commands(&self.project_cwd, &self.claude_home);
+        if self.command_index >= self.command_files.len() {
+            self.command_index = self.command_files.len().saturating_sub(1);
+        }
+        let line_count = self.current_command_lines().len();
+        if self.command_content_scroll > line_count {
+            self.command_content_scroll = line_count.saturating_sub(1);
+        }
+    }
+
+    pub fn selected_command(&self) -> Option<&CommandFile> {
+        if self.command_files.is_empty() {
+            return None;
+        }
+        let idx = self.command_index.min(self.command_files.len() - 1);
+        Some(&self.command_files[idx])
+    }
+
+    pub fn current_command_lines(&self) -> &[MarkdownLine] {
+        self.selected_command()
+            .map(|c| c.lines.as_slice())
+            .unwrap_or(&[])
+    }
+
+    pub fn command_start_edit(&mut self) {
+        let Some(path) = self.selected_command().map(|c| c.path.clone()) else {
+            return;
+        };
+        match std::fs::read_to_string(&path) {
+            Ok(text) => {
+                let mut editor = tui_textarea::TextArea::default();
+                editor.insert_str(&text);
+                editor.move_cursor(tui_textarea::CursorMove::Top);
+                editor.move_cursor(tui_textarea::CursorMove::Head);
+                self.command_editor = Some(editor);
+                self.command_editing = true;
+            }
+            Err(e) => self.last_error = Some(format!("Commands: {}", e)),
+        }
+    }
+
+    pub fn command_save_edit(&mut self) {
+        let Some(path) = self.selected_command().map(|c| c.path.clone()) else {
+            return;
+        };
+        let content = self
+            .command_editor
+            .as_ref()
+            .map(|e| e.lines().join("\n"))
+            .unwrap_or_default()
+            + "\n";
+        if let Err(e) = std::fs::write(&path, content) {
+            self.last_error = Some(format!("Save: {}", e));
+            return;
+        }
+        self.command_cancel_edit();
+        self.load_commands();
+    }
+
+    pub fn command_cancel_edit(&mut self) {
+        self.command_editing = false;
+        self.command_editor = None;
+    }
+
+    /// Start naming a new file, defaulting to a project-scope command.
+    pub fn command_start_new(&mut self) {
+        self.command_new_target = Some((CommandScope::Project, CommandKind::Command));
+        self.command_new_name.clear();
+    }
+
+    /// Cycle where the new file goes: project/user command, then project/user agent.
+    pub fn command_cycle_new_target(&mut self) {
+        self.command_new_target = match self.command_new_target {
+            Some((CommandScope::Project, CommandKind::Command)) => {
+                Some((CommandScope::User, CommandKind::Command))
+            }
+            Some((CommandScope::User, CommandKind::Command)) => {
+                Some((CommandScope::Project, CommandKind::Agent))
+            }
+            Some((CommandScope::Project, CommandKind::Agent)) => {
+                Some((CommandScope::User, CommandKind::Agent))
+            }
+            Some((CommandScope::User, CommandKind::Agent)) | None => {
+                Some((CommandScope::Project, CommandKind::Command))
+            }
+        };
+    }
+
+    pub fn command_cancel_new(&mut self) {
+        self.command_new_target = None;
+        self.command_new_name.clear();
+    }
+
+    /// Create the file from its template, select it and open the editor.
+    pub fn command_submit_new(&mut self) {
+        let Some((scope, kind)) = self.command_new_target else {
+            return;
+        };
+        match commands::create_command(
+            scope,
+            kind,
+            &self.command_new_name,
+            &self.project_cwd,
+            &self.claude_home,
+        ) {
+            Ok(path) => {
+                self.command_cancel_new();
+                self.load_commands();
+                if let Some(idx) = self.command_files.iter().position(|c| c.path == path) {
+                    self.command_index = idx;
+                    self.command_content_scroll = 0;
+                }
+                self.commands_pane = CommandsPane::Content;
+                self.command_start_edit();
+            }
+            Err(e) => self.last_error = Some(format!("New command: {}", e)),
+        }
+    }
+
+    /// Start typing an import source, prefilled with `commands.shared_repo`.
+    pub fn command_start_import(&mut self) {
+        self.command_import_input = Some(
+            self.project_config
+                .commands_shared_repo()
+                .unwrap_or_default(),
+        );
+    }
+
+    pub fn command_cancel_import_input(&mut self) {
+        self.command_import_input = None;
+    }
+
+    /// Load the typed source's definitions in the background for review.
+    pub fn command_submit_import_source(&mut self) {
+        let Some(source) = self.command_import_input.take() else {
+            return;
+        };
+        if self.command_import_loading {
+            return;
+        }
+        let Some(tx) = self.event_tx.clone() else {
+            return;
+        };
+        self.command_import_loading = true;
+        self.send_status = Some((format!("Loading {}...", source.trim()), Instant::now()));
+        std::thread::spawn(move || {
+            let result = command_import::load_source(&source).map_err(|e| e.to_string());
+            let _ = tx.send(AppEvent::CommandImportLoaded(result));
+        });
+    }
+
+    pub fn handle_command_import_loaded(&mut self, result: Result<Vec<ImportCandidate>, String>) {
+        self.command_import_loading = false;
+        match result {
+            Ok(candidates) => {
+                self.command_import = Some(CommandImport {
+                    candidates,
+                    index: 0,
+                    scroll: 0,
+                });
+            }
+            Err(e) => self.last_error = Some(format!("Import: {}", e)),
+        }
+    }
+
+    pub fn selected_import_candidate(&self) -> Option<&ImportCandidate> {
+        let import = self.command_import.as_ref()?;
+        import.candidates.get(import.index)
+    }
+
+    /// Where the reviewed definition would be written, and whether that
+    /// file already exists.
+    pub fn import_target(&self) -> Option<(PathBuf, bool)> {
+        let candidate = self.selected_import_candidate()?;
+        let path =
+            command_import::target_path(candidate, &self.project_cwd, &self.claude_home).ok()?;
+        let exists = path.exists();
+        Some((path, exists))
+    }
+
+    /// Review the next (or previous) loaded definition.
+    pub fn command_import_move(&mut self, forward: bool) {
+        if let Some(import) = self.command_import.as_mut() {
+            let len = import.candidates.len();
+            if len > 0 {
+                import.index = if forward {
+                    (import.index + 1) % len
+                } else {
+                    (import.index + len - 1) % len
+                };
+                import.scroll = 0;
+            }
+        }
+    }
+
+    pub fn command_import_scroll(&mut self, down: bool) {
+        if let Some(import) = self.command_import.as_mut() {
+            import.scroll = if down {
+                import.scroll + 1
+            } else {
+                import.scroll.saturating_sub(1)
+            };
+        }
+    }
+
+    pub fn close_command_import(&mut self) {
+        self.command_import = None;
+    }
+
+    /// Write the reviewed definition into the project's `.claude/`. An
+    /// existing file is only replaced when `overwrite` is set.
+    pub fn command_import_confirm(&mut self, overwrite: bool) {
+        let Some(candidate) = self.selected_import_candidate().cloned() else {
+            return;
+        };
+        if let Some((path, true)) = self.import_target() {
+            if !overwrite {
+                self.last_error = Some(format!(
+                    "Import: {} already exists; press o to overwrite",
+                    path.display()
+                ));
+                return;
+            }
+        }
+        match command_import::import(&candidate, &self.project_cwd, &self.claude_home, overwrite) {
+            Ok(path) => {
+                self.send_status = Some((
+                    format!("Imported {} to {}", candidate.name, path.display()),
+                    Instant::now(),
+                ));
+                if let Some(import) = self.command_import.as_mut() {
+                    import.candidates.remove(import.index);
+                    import.index = import.index.min(import.candidates.len().saturating_sub(1));
+                    import.scroll = 0;
+                    if import.candidates.is_empty() {
+                        self.command_import = None;
+                    }
+                }
+                self.load_commands();
+                if let Some(idx) = self.command_files.iter().position(|c| c.path == path) {
+                    self.command_index = idx;
+                    self.command_content_scroll = 0;
+                }
+            }
+            Err(e) => self.last_error = Some(format!("Import: {}", e)),
+        }
+    }
+
+    pub fn load_todos(&mut self) {
+        let loaded = self.load_from_homes(todos::load_todos, |t, home| t.home = home);
+        match loaded {
+            Ok(t) => {
+                self.todo_files = t;
+                self.index_for_search(SearchSource::Todos);
+                if self.todo_file_index >= self.todo_files.len() {
+                    self.todo_file_index = self.todo_files.len().saturating_sub(1);
+                    self.todo_item_index = 0;
+                }
+                self.last_error = None;
+            }
+            Err(e) => {
+                self.todo_files.clear();
+                self.todo_file_index = 0;
+                self.todo_item_index = 0;
+                self.last_error = Some(format!("Todos: {}", e));
+            }
+        }
+    }
+
+    /// Cycle to the next subagent transcript (or back to main).
+    pub fn cycle_subagent(&mut self) {
+        if self.subagents.is_empty() {
+            return;
+        }
+
+        if !self.viewing_subagent {
+            // Switch to first subagent
+            self.viewing_subagent = true;
+            self.subagent_index = 0;
+        } else {
+            // Cycle through subagents, then back to main
+            self.subagent_index += 1;
+            if self.subagent_index >= self.subagents.len() {
+                self.viewing_subagent = false;
+                self.subagent_index = 0;
+                return;
+            }
+        }
+
+        self.subagent_scroll = 0;
+        self.transcript_wrap_row = 0;
+        self.load_subagent_transcript();
+    }
+
+    /// Load the transcript for the currently selected subagent.
+    fn load_subagent_transcript(&mut self) {
+        if self.subagent_index >= self.subagents.len() {
+            return;
+        }
+        let path = self.subagents[self.subagent_index].path.clone();
+        self.subagent_reader = self.new_transcript_reader();
+        match self.subagent_reader.load_initial(&path) {
+            Ok(()) => {
+                self.subagent_transcript = self.subagent_reader.items.clone();
+                self.request_translations();
+            }
+            Err(e) => {
+                self.last_error = Some(format!("Subagent transcript: {}", e));
+            }
+        }
+    }
+
+    /// Refresh the subagent transcript if viewing one.
+    fn refresh_subagent_transcript(&mut self) {
+        if !self.viewing_subagent || self.subagent_index >= self.subagents.len() {
+            return;
+        }
+        let path = self.subagents[self.subagent_index].path.clone();
+        match self.subagent_reader.read_new(&path) {
+            Ok((true, drained)) => {
+                self.subagent_transcript = self.subagent_reader.items.clone();
+                if drained > 0 {
+                    self.subagent_scroll = self.subagent_scroll.saturating_sub(drained);
+                }
+                self.request_translations();
+            }
+            Ok((false, _)) => {}
+            Err(e) => {
+                self.last_error = Some(format!("Subagent update: {}", e));
+            }
+        }
+    }
+
+    /// Toggle the tokens/elapsed column on assistant transcript items.
+    pub fn toggle_turn_usage(&mut self) {
+        self.show_turn_usage = !self.show_turn_usage;
+    }
+
+    /// Switch the transcript between one line per item and wrapped
+    /// messages, keeping the top item in view.
+    pub fn toggle_transcript_wrap(&mut self) {
+        self.transcript_wrap = !self.transcript_wrap;
+        self.transcript_wrap_row = 0;
+    }
+
+    /// The text of `item` as the transcript pane lays it out: one row per
+    /// line in wrapped mode, else a single row.
+    pub fn transcript_rows(&self, item: &TranscriptItem) -> Vec<String> {
+        let text = self.transcript_display_text(item);
+        if self.transcript_wrap && item.kind.wraps() {
+            let prefix = transcript::prefix_width(self.show_turn_usage);
+            let width = (self.transcript_width as usize).saturating_sub(prefix);
+            transcript::wrap_text(text, width)
+        } else {
+            vec![text.replace('\n', " ").replace('\r', "")]
+        }
+    }
+
+    /// Scroll the viewed transcript one line: a row within a wrapped item,
+    /// or a whole item otherwise.
+    fn transcript_scroll_line(&mut self, down: bool) {
+        let (items, scroll) = if self.viewing_subagent {
+            (&self.subagent_transcript, self.subagent_scroll)
+        } else {
+            (&self.transcript_items, self.transcript_scroll)
+        };
+        let Some(last) = items.len().checked_sub(1) else {
+            return;
+        };
+        let scroll = scroll.min(last);
+        let rows = self.transcript_rows(&items[scroll]).len();
+        let row = self.transcript_wrap_row.min(rows - 1);
+        let (scroll, row) = if down {
+            if row + 1 < rows {
+                (scroll, row + 1)
+            } else {
+                ((scroll + 1).min(last), if scroll < last { 0 } else { row })
+            }
+        } else if row > 0 {
+            (scroll, row - 1)
+        } else if scroll > 0 {
+            let above = self.transcript_rows(&items[scroll - 1]).len();
+            (scroll - 1, above - 1)
+        } else {
+            (0, 0)
+        };
+        self.transcript_wrap_row = row;
+        if self.viewing_subagent {
+            self.subagent_scroll = scroll;
+        } else {
+            self.follow_mode = false;
+            self.transcript_scroll = scroll;
+        }
+    }
+
+    // --- Session stats ---
+
+    /// Toggle the stats pane in place of the transcript.
+    pub fn toggle_session_stats(&mut self) {
+        self.show_session_stats = !self.show_session_stats;
+        if self.show_session_stats {
+            self.load_session_stats();
+            self.load_activity_calendar();
+        }
+    }
+
+    /// Rescan the project's recent transcripts for the activity heatmap in
+    /// the background.
+    pub fn load_activity_calendar(&mut self) {
+        if self.activity_calendar_loading {
+            return;
+        }
+        let Some(tx) = self.event_tx.clone() else {
+            return;
+        };
+        let project_dirs: Vec<PathBuf> = (0..self.claude_homes.len())
+            .map(|home| self.project_dir(home))
+            .collect();
+        let since = activity_calendar::calendar_start(chrono::Local::now().date_naive());
+        self.activity_calendar_loading = true;
+        std::thread::spawn(move || {
+            let calendar = activity_calendar::scan_activity(&project_dirs, since);
+            let _ = tx.send(AppEvent::ActivityCalendarLoaded(calendar));
+        });
+    }
+
+    pub fn handle_activity_calendar_loaded(&mut self, calendar: ActivityCalendar) {
+        self.activity_calendar_loading = false;
+        self.activity_calendar = Some(calendar);
+    }
+
+    /// Stats of the selected session, once computed.
+    pub fn selected_session_stats(&self) -> Option<&SessionStats> {
+        let session = self.sessions.get(self.session_list_index)?;
+        self.session_stats.get(&session.session_id)
+    }
+
+    /// Scan the selected session's transcript in the background, unless its
+    /// cached stats were computed from a transcript of the current size.
+    pub fn load_session_stats(&mut self) {
+        let Some(session) = self.sessions.get(self.session_list_index) else {
+            return;
+        };
+        let session_id = session.session_id.clone();
+        let project_dir = self.project_dir(session.home);
+        if self.session_stats_loading.contains(&session_id) {
+            return;
+        }
+        let size = std::fs::metadata(project_dir.join(format!("{}.jsonl", session_id)))
+            .map(|m| m.len())
+            .ok();
+        if let Some(stats) = self.session_stats.get(&session_id) {
+            if size.is_none_or(|size| size == stats.bytes) {
+                return;
+            }
+        }
+        let Some(tx) = self.event_tx.clone() else {
+            return;
+        };
+        self.session_stats_loading.insert(session_id.clone());
+        std::thread::spawn(move || {
+            let result = session_stats::scan_session_stats(&project_dir, &session_id)
+                .map_err(|e| e.to_string());
+            let _ = tx.send(AppEvent::SessionStatsLoaded(session_id, result));
+        });
+    }
+
+    /// Write the selected session's redacted JSONL export under
+    /// `~/.assoc/exports/` in the background.
+    pub fn export_selected_session(&mut self) {
+        let Some(session) = self.sessions.get(self.session_list_index) else {
+            return;
+        };
+        let transcript = self
+            .project_dir(session.home)
+            .join(format!("{}.jsonl", session.session_id));
+        let path = session_export::export_path(&config::assoc_home(), &session.session_id);
+        let Some(tx) = self.event_tx.clone() else {
+            return;
+        };
+        std::thread::spawn(move || {
+            let result = session_export::export_to_file(&transcript, &path)
+                .map(|count| (path, count))
+                .map_err(|e| e.to_string());
+            let _ = tx.send(AppEvent::SessionExported(result));
+        });
+    }
+
+    pub fn handle_session_exported(&mut self, result: Result<(PathBuf, usize), String>) {
+        match result {
+            Ok((path, count)) => {
+                self.send_status = Some((
+                    format!("Exported {} record(s) to {}", count, path.display()),
+                    Instant::now(),
+                ));
+            }
+            Err(e) => self.last_error = Some(format!("Export: {}", e)),
         }
-        let idx = self.plan_file_index.min(self.plan_files.len() - 1);
-        &self.plan_files[idx].lines
     }
 
-    pub fn load_todos(&mut self) {
-        match todos::load_todos(&self.claude_home) {
-            Ok(t) => {
-                self.todo_files = t;
-                if self.todo_file_index >= self.todo_files.len() {
-                    self.todo_file_index = self.todo_files.len().saturating_sub(1);
-                    self.todo_item_index = 0;
-                }
-                self.last_error = None;
-            }
-            Err(e) => {
-                self.todo_files.clear();
-                self.todo_file_index = 0;
-                self.todo_item_index = 0;
-                self.last_error = Some(format!("Todos: {}", e));
+    pub fn handle_session_stats_loaded(
+        &mut self,
+        session_id: String,
+        result: Result<SessionStats, String>,
+    ) {
+        self.session_stats_loading.remove(&session_id);
+        match result {
+            Ok(stats) => {
+                self.session_stats.insert(session_id, stats);
             }
+            Err(e) => self.last_error = Some(format!("Session stats: {}", e)),
         }
     }
 
-    /// Cycle to the next subagent transcript (or back to main).
-    pub fn cycle_subagent(&mut self) {
-        if self.subagents.is_empty() {
+    // --- Transcript translation ---
+
+    /// Toggle between translated and original transcript text.
+    pub fn toggle_translation(&mut self) {
+        if self.project_config.translation_command().is_none() {
+            self.last_error =
+                Some("Translation: set [translation] command in .assoc.toml".to_string());
             return;
         }
+        self.translate_mode = !self.translate_mode;
+        self.request_translations();
+    }
 
-        if !self.viewing_subagent {
-            // Switch to first subagent
-            self.viewing_subagent = true;
-            self.subagent_index = 0;
+    /// Translate user/assistant messages of the displayed transcript that
+    /// aren't cached yet. Runs one background thread per batch.
+    fn request_translations(&mut self) {
+        if !self.translate_mode {
+            return;
+        }
+        let (command, tx) = match (
+            self.project_config.translation_command(),
+            self.event_tx.clone(),
+        ) {
+            (Some(c), Some(tx)) => (c.to_string(), tx),
+            _ => return,
+        };
+
+        let items = if self.viewing_subagent {
+            &self.subagent_transcript
         } else {
-            // Cycle through subagents, then back to main
-            self.subagent_index += 1;
-            if self.subagent_index >= self.subagents.len() {
-                self.viewing_subagent = false;
-                self.subagent_index = 0;
-                return;
+            &self.transcript_items
+        };
+        let mut batch: Vec<String> = Vec::new();
+        for item in items {
+            if matches!(
+                item.kind,
+                TranscriptItemKind::User | TranscriptItemKind::Assistant
+            ) && !item.text.trim().is_empty()
+                && !self.translations.contains_key(&item.text)
+                && !self.translations_pending.contains(&item.text)
+                && !batch.contains(&item.text)
+            {
+                batch.push(item.text.clone());
             }
         }
+        if batch.is_empty() {
+            return;
+        }
+        self.translations_pending.extend(batch.iter().cloned());
 
-        self.subagent_scroll = 0;
-        self.load_subagent_transcript();
+        std::thread::spawn(move || {
+            for text in batch {
+                let result = translate::translate(&command, &text).map_err(|e| e.to_string());
+                let failed = result.is_err();
+                if tx.send(AppEvent::TranslationLoaded(text, result)).is_err() || failed {
+                    break;
+                }
+            }
+        });
     }
 
-    /// Load the transcript for the currently selected subagent.
-    fn load_subagent_transcript(&mut self) {
-        if self.subagent_index >= self.subagents.len() {
-            return;
-        }
-        let path = self.subagents[self.subagent_index].path.clone();
-        self.subagent_reader =
-            transcripts::TranscriptReader::with_tail_lines(self.project_config.tail_lines());
-        match self.subagent_reader.load_initial(&path) {
-            Ok(()) => {
-                self.subagent_transcript = self.subagent_reader.items.clone();
+    pub fn handle_translation_loaded(&mut self, original: String, result: Result<String, String>) {
+        self.translations_pending.remove(&original);
+        match result {
+            Ok(translated) => {
+                self.translations.insert(original, translated);
             }
             Err(e) => {
-                self.last_error = Some(format!("Subagent transcript: {}", e));
+                // Stop translating so a broken command doesn't fail on every message
+                self.translate_mode = false;
+                self.translations_pending.clear();
+                self.last_error = Some(format!("Translation: {}", e));
             }
         }
     }
 
-    /// Refresh the subagent transcript if viewing one.
-    fn refresh_subagent_transcript(&mut self) {
-        if !self.viewing_subagent || self.subagent_index >= self.subagents.len() {
-            return;
-        }
-        let path = self.subagents[self.subagent_index].path.clone();
-        match self.subagent_reader.read_new(&path) {
-            Ok((true, drained)) => {
-                self.subagent_transcript = self.subagent_reader.items.clone();
-                if drained > 0 {
-                    self.subagent_scroll = self.subagent_scroll.saturating_sub(drained);
-                }
-            }
-            Ok((false, _)) => {}
-            Err(e) => {
-                self.last_error = Some(format!("Subagent update: {}", e));
+    /// Text to display for a transcript item, honouring translate mode.
+    pub fn transcript_display_text<'a>(&'a self, item: &'a TranscriptItem) -> &'a str {
+        if self.translate_mode {
+            if let Some(t) = self.translations.get(&item.text) {
+                return t;
             }
         }
+        &item.text
     }
 
     /// Handle a file change event from the watcher.
     /// Skips processing if the associated tab is disabled.
     pub fn handle_file_change(&mut self, change: FileChange) {
+        // Other tabs' bodies can summarize anything, but the Sessions panes
+        // only show sessions and transcripts
+        self.dirty.content = true;
+        self.dirty.status_bar = true;
+        if matches!(
+            change,
+            FileChange::SessionIndex
+                | FileChange::Transcript(_)
+                | FileChange::SubagentTranscript(_)
+        ) {
+            self.dirty.session_list = true;
+            self.dirty.transcript = true;
+        }
+
         let handled = match change {
             FileChange::SessionIndex => {
                 if self.is_tab_enabled(&ActiveTab::Sessions) {
@@ -942,9 +4565,14 @@ impl App {
                     false
                 }
             }
-            FileChange::Transcript(_) => {
+            FileChange::Transcript(path) => {
                 if self.is_tab_enabled(&ActiveTab::Sessions) {
+                    self.update_session_index(&path);
                     self.refresh_transcript();
+                    // Low-power mode keeps the stats pane as last computed
+                    if self.show_session_stats && !self.low_power {
+                        self.load_session_stats();
+                    }
                     true
                 } else {
                     false
@@ -961,6 +4589,7 @@ impl App {
             FileChange::TeamConfig(_) => {
                 if self.is_tab_enabled(&ActiveTab::Teams) {
                     self.load_teams();
+                    self.refresh_overview_teams();
                     true
                 } else {
                     false
@@ -970,6 +4599,7 @@ impl App {
                 if self.is_tab_enabled(&ActiveTab::Teams) {
                     self.load_inbox_for_selected_member();
                     self.compute_agent_statuses();
+                    self.refresh_overview_teams();
                     true
                 } else {
                     false
@@ -979,6 +4609,7 @@ impl App {
                 if self.is_tab_enabled(&ActiveTab::Teams) {
                     self.load_tasks_for_selected_team();
                     self.compute_agent_statuses();
+                    self.refresh_overview_teams();
                     true
                 } else {
                     false
@@ -994,14 +4625,32 @@ impl App {
             }
             FileChange::GitChange => {
                 if self.is_tab_enabled(&ActiveTab::Git) {
-                    self.load_git_data();
+                    self.schedule_git_refresh();
+                }
+                false
+            }
+            FileChange::Worktree => {
+                // Only while following, so Claude's edits show up in the diff
+                if self.follow_mode && self.is_tab_enabled(&ActiveTab::Git) {
+                    self.schedule_git_refresh();
+                }
+                false
+            }
+            FileChange::ClaudeSettings => {
+                // The Hooks tab reads the same files
+                if self.is_tab_enabled(&ActiveTab::Hooks) {
+                    self.load_hooks();
+                }
+                if self.is_tab_enabled(&ActiveTab::Settings) {
+                    self.load_settings();
                     true
                 } else {
                     false
                 }
             }
-            FileChange::PlanFile(_) => {
+            FileChange::PlanFile(ref path) => {
                 if self.is_tab_enabled(&ActiveTab::Plans) {
+                    self.plan_check_external_change(path);
                     self.load_plans();
                     true
                 } else {
@@ -1048,14 +4697,459 @@ impl App {
     }
 
     fn on_tab_switch(&mut self, target: &ActiveTab) {
-        // Clear new-activity badge when switching to that tab
-        if *target == ActiveTab::GitHubPRs {
-            self.gh_new_activity = false;
+        // Clear the tab's new-activity badge
+        for item in &mut self.activity {
+            if Self::activity_tab(item.source) == *target {
+                item.read = true;
+            }
+        }
+        // Tabs still queued load now rather than waiting their turn
+        if self.pending_tabs.contains(target) {
+            self.load_tab(target);
+            return;
+        }
+        // Commands, hooks and notes aren't watched; pick up edits made
+        // outside assoc
+        if *target == ActiveTab::Commands {
+            self.load_commands();
+        }
+        if *target == ActiveTab::Hooks {
+            self.load_hooks();
+        }
+        if *target == ActiveTab::Notes {
+            self.load_notes();
+        }
+        if *target == ActiveTab::Overview {
+            self.load_overview_teams();
+        }
+    }
+
+    // --- Activity feed ---
+
+    /// Tab that lists items from `source`.
+    pub fn activity_tab(source: ActivitySource) -> ActiveTab {
+        match source {
+            ActivitySource::GitHubPr => ActiveTab::GitHubPRs,
+            ActivitySource::GitHubIssue => ActiveTab::GitHubIssues,
+            ActivitySource::Jira => ActiveTab::Jira,
+            ActivitySource::Linear => ActiveTab::Linear,
+        }
+    }
+
+    /// Diff a poll of `source` against the previous one and add the changes
+    /// to the feed.
+    fn record_activity(
+        &mut self,
+        source: ActivitySource,
+        states: Vec<(String, ItemState)>,
+        me: Option<String>,
+    ) {
+        let mut changes = self.activity_tracker.update(source, states, me.as_deref());
+        if changes.is_empty() {
+            return;
+        }
+        changes.append(&mut self.activity);
+        changes.truncate(MAX_ACTIVITY);
+        self.activity = changes;
+    }
+
+    pub fn unread_activity(&self) -> usize {
+        self.activity.iter().filter(|a| !a.read).count()
+    }
+
+    /// Whether `tab` has activity not yet seen, for its tab bar badge.
+    pub fn tab_has_activity(&self, tab: &ActiveTab) -> bool {
+        self.activity
+            .iter()
+            .any(|a| !a.read && Self::activity_tab(a.source) == *tab)
+    }
+
+    pub fn toggle_activity(&mut self) {
+        if self.show_activity {
+            self.close_activity();
+        } else {
+            self.show_activity = true;
+            self.activity_index = 0;
+        }
+    }
+
+    /// Close the feed; everything it showed counts as seen.
+    pub fn close_activity(&mut self) {
+        self.show_activity = false;
+        for item in &mut self.activity {
+            item.read = true;
+        }
+    }
+
+    pub fn activity_move(&mut self, down: bool) {
+        if down {
+            if self.activity_index + 1 < self.activity.len() {
+                self.activity_index += 1;
+            }
+        } else {
+            self.activity_index = self.activity_index.saturating_sub(1);
+        }
+    }
+
+    /// Switch to the selected entry's tab and select its item there.
+    pub fn activity_jump(&mut self) {
+        let Some(item) = self.activity.get(self.activity_index).cloned() else {
+            return;
+        };
+        self.close_activity();
+        let tab = Self::activity_tab(item.source);
+        if !self.is_tab_enabled(&tab) {
+            return;
+        }
+        self.switch_to_tab(tab);
+        let id = item.item_id.as_str();
+        if !self.select_tracker_item(item.source, id) {
+            self.last_error = Some(format!(
+                "{} {} is no longer in the list",
+                item.source.label(),
+                id
+            ));
+        }
+    }
+
+    /// Select the PR, issue or ticket `id` from `source` in its tab's list.
+    fn select_tracker_item(&mut self, source: ActivitySource, id: &str) -> bool {
+        match source {
+            ActivitySource::GitHubPr => {
+                let pos = self.gh_flat_list.iter().position(
+                    |entry| matches!(entry, FlatPrItem::Pr(pr) if pr.number.to_string() == id),
+                );
+                if let Some(i) = pos {
+                    self.gh_pr_index = i;
+                    self.gh_pane = GitHubPane::List;
+                    self.gh_detail_scroll = 0;
+                    self.gh_check_index = 0;
+                    self.gh_load_review_threads();
+                }
+                pos.is_some()
+            }
+            ActivitySource::GitHubIssue => {
+                let pos = self.gh_issues_flat_list.iter().position(
+                    |entry| matches!(entry, FlatIssueItem::Issue(i) if i.number.to_string() == id),
+                );
+                if let Some(i) = pos {
+                    self.gh_issues_index = i;
+                    self.gh_issues_pane = IssuesPane::List;
+                }
+                pos.is_some()
+            }
+            ActivitySource::Jira => {
+                let pos = self
+                    .jira_flat_list
+                    .iter()
+                    .position(|entry| matches!(entry, FlatJiraItem::Issue(i) if i.key == id));
+                if let Some(i) = pos {
+                    self.jira_index = i;
+                    self.jira_pane = JiraPane::List;
+                    self.jira_detail = None;
+                }
+                pos.is_some()
+            }
+            ActivitySource::Linear => {
+                let pos = self.linear_flat_list.iter().position(
+                    |entry| matches!(entry, FlatLinearItem::Issue(i) if i.identifier == id),
+                );
+                if let Some(i) = pos {
+                    self.linear_index = i;
+                    self.linear_pane = LinearPane::List;
+                }
+                pos.is_some()
+            }
+        }
+    }
+
+    // --- Goto anything ---
+
+    /// Replace `source`'s goto-anything entries with what its tab has loaded.
+    fn index_for_search(&mut self, source: SearchSource) {
+        let entry = |text: String, detail: String, target| SearchEntry {
+            text,
+            detail,
+            target,
+        };
+        let entries: Vec<SearchEntry> = match source {
+            SearchSource::Sessions => self
+                .all_sessions
+                .iter()
+                .map(|s| {
+                    entry(
+                        s.display_title(),
+                        s.git_branch.clone().unwrap_or_default(),
+                        SearchTarget::Session(s.session_id.clone()),
+                    )
+                })
+                .collect(),
+            SearchSource::Plans => self
+                .plan_files
+                .iter()
+                .map(|p| {
+                    entry(
+                        p.filename.clone(),
+                        p.title.clone(),
+                        SearchTarget::Plan(p.filename.clone()),
+                    )
+                })
+                .collect(),
+            SearchSource::Todos => self
+                .todo_files
+                .iter()
+                .flat_map(|file| {
+                    file.items
+                        .iter()
+                        .enumerate()
+                        .filter_map(move |(item, todo)| {
+                            let target = SearchTarget::Todo {
+                                file: file.filename.clone(),
+                                item,
+                            };
+                            Some(entry(todo.content.clone()?, file.display_name(), target))
+                        })
+                })
+                .collect(),
+            SearchSource::Prs => self
+                .gh_prs
+                .iter()
+                .map(|pr| {
+                    entry(
+                        format!("#{} {}", pr.number, pr.title),
+                        pr.author.login.clone(),
+                        SearchTarget::Pr(pr.number),
+                    )
+                })
+                .collect(),
+            SearchSource::Issues => self
+                .gh_issues
+                .iter()
+                .map(|issue| {
+                    entry(
+                        format!("#{} {}", issue.number, issue.title),
+                        issue.author.login.clone(),
+                        SearchTarget::Issue(issue.number),
+                    )
+                })
+                .collect(),
+            SearchSource::Jira => self
+                .jira_issues
+                .iter()
+                .map(|issue| {
+                    entry(
+                        format!("{} {}", issue.key, issue.summary),
+                        issue.status_name.clone(),
+                        SearchTarget::Jira(issue.key.clone()),
+                    )
+                })
+                .collect(),
+            SearchSource::Linear => self
+                .linear_issues
+                .iter()
+                .map(|issue| {
+                    entry(
+                        format!("{} {}", issue.identifier, issue.title),
+                        issue.state.name.clone(),
+                        SearchTarget::Linear(issue.identifier.clone()),
+                    )
+                })
+                .collect(),
+            SearchSource::Teams => self
+                .teams
+                .iter()
+                .flat_map(|team| {
+                    team.config.members.iter().map(move |member| {
+                        let target = SearchTarget::Member {
+                            team: team.dir_name.clone(),
+                            member: member.name.clone(),
+                        };
+                        entry(member.name.clone(), team.display_name().to_string(), target)
+                    })
+                })
+                .collect(),
+            // Not a tab's list: read when goto anything opens
+            SearchSource::Files => git::project_files(&self.project_cwd)
+                .unwrap_or_default()
+                .into_iter()
+                .map(|file| entry(file.clone(), String::new(), SearchTarget::File(file)))
+                .collect(),
+        };
+        self.search_registry.set(source, entries);
+    }
+
+    /// Open goto anything; the project's files are listed afresh each time.
+    pub fn goto_start(&mut self) {
+        if self.is_tab_enabled(&ActiveTab::Git) {
+            self.index_for_search(SearchSource::Files);
+        }
+        self.goto = Some(GotoSearch::default());
+    }
+
+    /// The best matches for the typed query.
+    pub fn goto_results(&self) -> Vec<(SearchSource, &SearchEntry)> {
+        match self.goto {
+            Some(ref goto) => self.search_registry.search(&goto.query, GOTO_RESULTS),
+            None => Vec::new(),
+        }
+    }
+
+    pub fn goto_type(&mut self, c: char) {
+        if let Some(goto) = self.goto.as_mut() {
+            goto.query.push(c);
+            goto.index = 0;
+        }
+    }
+
+    pub fn goto_backspace(&mut self) {
+        if let Some(goto) = self.goto.as_mut() {
+            goto.query.pop();
+            goto.index = 0;
+        }
+    }
+
+    pub fn goto_move(&mut self, down: bool) {
+        let count = self.goto_results().len();
+        let Some(goto) = self.goto.as_mut() else {
+            return;
+        };
+        if down {
+            goto.index = (goto.index + 1).min(count.saturating_sub(1));
+        } else {
+            goto.index = goto.index.saturating_sub(1);
+        }
+    }
+
+    /// Close goto anything and select the highlighted result in its tab.
+    pub fn goto_select(&mut self) {
+        let index = self.goto.as_ref().map_or(0, |g| g.index);
+        let Some((source, entry)) = self
+            .goto_results()
+            .get(index)
+            .map(|(source, entry)| (*source, (*entry).clone()))
+        else {
+            return;
+        };
+        self.goto = None;
+        if !self.goto_target(&entry.target) {
+            self.last_error = Some(format!(
+                "{} {} is no longer in the list",
+                source.label(),
+                entry.text
+            ));
+        }
+    }
+
+    /// Switch to the tab that lists `target` and select it there, clearing
+    /// the tab's filter if it hides the item.
+    fn goto_target(&mut self, target: &SearchTarget) -> bool {
+        let tracker = match target {
+            SearchTarget::Pr(n) => Some((ActivitySource::GitHubPr, n.to_string())),
+            SearchTarget::Issue(n) => Some((ActivitySource::GitHubIssue, n.to_string())),
+            SearchTarget::Jira(key) => Some((ActivitySource::Jira, key.clone())),
+            SearchTarget::Linear(id) => Some((ActivitySource::Linear, id.clone())),
+            _ => None,
+        };
+        if let Some((source, id)) = tracker {
+            let tab = Self::activity_tab(source);
+            self.switch_to_tab(tab.clone());
+            return self.select_tracker_item(source, &id)
+                || (self.clear_tab_filter(tab) && self.select_tracker_item(source, &id));
+        }
+
+        match target {
+            SearchTarget::Session(id) => {
+                self.switch_to_tab(ActiveTab::Sessions);
+                let find = |app: &App| app.sessions.iter().position(|s| s.session_id == *id);
+                let pos = find(self).or_else(|| {
+                    self.clear_tab_filter(ActiveTab::Sessions);
+                    find(self)
+                });
+                let Some(i) = pos else {
+                    return false;
+                };
+                self.session_list_index = i;
+                self.sessions_pane = SessionsPane::List;
+                self.load_selected_transcript();
+            }
+            SearchTarget::Plan(name) => {
+                self.switch_to_tab(ActiveTab::Plans);
+                let Some(i) = self.plan_files.iter().position(|p| p.filename == *name) else {
+                    return false;
+                };
+                self.plan_file_index = i;
+                self.plan_content_scroll = 0;
+                self.plan_outline_index = 0;
+                self.plans_pane = PlansPane::List;
+            }
+            SearchTarget::Todo { file, item } => {
+                self.switch_to_tab(ActiveTab::Todos);
+                let Some(i) = self.todo_files.iter().position(|t| t.filename == *file) else {
+                    return false;
+                };
+                self.todo_file_index = i;
+                self.todo_item_index = *item;
+                self.todos_pane_left = false;
+            }
+            SearchTarget::Member { team, member } => {
+                self.switch_to_tab(ActiveTab::Teams);
+                let Some(t) = self.teams.iter().position(|t| t.dir_name == *team) else {
+                    return false;
+                };
+                self.team_list_index = t;
+                self.member_list_index = self.teams[t]
+                    .config
+                    .members
+                    .iter()
+                    .position(|m| m.name == *member)
+                    .unwrap_or(0);
+                self.task_list_index = 0;
+                self.detail_scroll = 0;
+                self.teams_pane = TeamsPane::Members;
+                self.load_tasks_for_selected_team();
+                self.compute_agent_statuses();
+                self.load_inbox_for_selected_member();
+            }
+            SearchTarget::File(path) => {
+                let path = self.project_cwd.join(path);
+                return self.browse_file_at(path, 1);
+            }
+            _ => {}
+        }
+        true
+    }
+
+    /// Drop `tab`'s filter and rebuild its list. False if it had none.
+    fn clear_tab_filter(&mut self, tab: ActiveTab) -> bool {
+        if self.filters.remove(&tab).is_none() {
+            return false;
+        }
+        match tab {
+            ActiveTab::Sessions => self.rebuild_session_list(),
+            ActiveTab::GitHubPRs => self.rebuild_pr_list(),
+            ActiveTab::GitHubIssues => self.rebuild_issue_list(),
+            ActiveTab::Jira => self.rebuild_jira_list(),
+            ActiveTab::Linear => self.rebuild_linear_list(),
+            _ => {}
+        }
+        true
+    }
+
+    /// Re-derive every team's statuses, but only while the Overview shows them.
+    fn refresh_overview_teams(&mut self) {
+        if self.active_tab == ActiveTab::Overview {
+            self.load_overview_teams();
         }
     }
 
     pub fn navigate_down(&mut self) {
         match self.active_tab {
+            ActiveTab::Overview => {
+                let len = self.overview_cards().len();
+                if self.overview_index + OVERVIEW_COLUMNS < len {
+                    self.overview_index += OVERVIEW_COLUMNS;
+                }
+            }
             ActiveTab::Sessions => match self.sessions_pane {
                 SessionsPane::List => {
                     if !self.sessions.is_empty() {
@@ -1064,7 +5158,9 @@ impl App {
                     }
                 }
                 SessionsPane::Transcript => {
-                    if self.viewing_subagent {
+                    if self.transcript_wrap {
+                        self.transcript_scroll_line(true);
+                    } else if self.viewing_subagent {
                         self.subagent_scroll = self
                             .subagent_scroll
                             .saturating_add(1)
@@ -1143,12 +5239,44 @@ impl App {
                         self.plan_file_index =
                             (self.plan_file_index + 1).min(self.plan_files.len() - 1);
                         self.plan_content_scroll = 0;
+                        self.plan_outline_index = 0;
                     }
                 }
+                PlansPane::Outline => {
+                    let last = self.current_plan_outline().len().saturating_sub(1);
+                    self.plan_select_section((self.plan_outline_index + 1).min(last));
+                }
                 PlansPane::Content => {
                     self.plan_content_scroll = self.plan_content_scroll.saturating_add(1);
                 }
             },
+            ActiveTab::Commands => match self.commands_pane {
+                CommandsPane::List => {
+                    if !self.command_files.is_empty() {
+                        self.command_index =
+                            (self.command_index + 1).min(self.command_files.len() - 1);
+                        self.command_content_scroll = 0;
+                    }
+                }
+                CommandsPane::Content => {
+                    self.command_content_scroll = self.command_content_scroll.saturating_add(1);
+                }
+            },
+            ActiveTab::Hooks => match self.hooks_pane {
+                HooksPane::List => self.hook_move(true),
+                HooksPane::Detail => {
+                    self.hook_detail_scroll = self.hook_detail_scroll.saturating_add(1);
+                }
+            },
+            ActiveTab::Settings => match self.settings_pane {
+                SettingsPane::List => self.settings_move(true),
+                SettingsPane::Detail => {
+                    self.settings_detail_scroll = self.settings_detail_scroll.saturating_add(1);
+                }
+            },
+            ActiveTab::Notes => {
+                self.notes_scroll = self.notes_scroll.saturating_add(1);
+            }
             ActiveTab::GitHubPRs => match self.gh_pane {
                 GitHubPane::List => {
                     self.gh_skip_to_next_pr();
@@ -1166,6 +5294,7 @@ impl App {
                 }
             },
             ActiveTab::Jira => match self.jira_pane {
+                JiraPane::List if self.jira_board_mode => self.jira_board_move_cursor(1),
                 JiraPane::List => {
                     self.jira_skip_to_next_issue();
                 }
@@ -1181,28 +5310,57 @@ impl App {
                     self.linear_detail_scroll = self.linear_detail_scroll.saturating_add(1);
                 }
             },
+            ActiveTab::Azure => match self.azure_pane {
+                AzurePane::List => {
+                    self.azure_skip_to_next_item();
+                }
+                AzurePane::Detail => {
+                    self.azure_detail_scroll = self.azure_detail_scroll.saturating_add(1);
+                }
+            },
+            ActiveTab::Deps => {
+                if !self.outdated_deps.is_empty() {
+                    self.deps_index = (self.deps_index + 1).min(self.outdated_deps.len() - 1);
+                }
+            }
             ActiveTab::Processes => match self.processes_pane {
                 ProcessesPane::List => {
                     if !self.processes.is_empty() {
                         self.process_index = (self.process_index + 1).min(self.processes.len() - 1);
-                        self.process_output_scroll = 0;
+                        self.reset_process_output();
+                        self.process_artifact_index = 0;
                     }
                 }
-                ProcessesPane::Output => {
-                    self.process_output_scroll = self.process_output_scroll.saturating_add(1);
-                }
+                ProcessesPane::Output => match self.process_output_top {
+                    Some(top) => {
+                        let last = self
+                            .selected_process()
+                            .map_or(0, |p| p.output_line_count() - 1);
+                        self.process_output_top = Some((top + 1).min(last));
+                    }
+                    None => {
+                        self.process_output_scroll = self.process_output_scroll.saturating_sub(1);
+                    }
+                },
             },
         }
     }
 
     pub fn navigate_up(&mut self) {
         match self.active_tab {
+            ActiveTab::Overview => {
+                if self.overview_index >= OVERVIEW_COLUMNS {
+                    self.overview_index -= OVERVIEW_COLUMNS;
+                }
+            }
             ActiveTab::Sessions => match self.sessions_pane {
                 SessionsPane::List => {
                     self.session_list_index = self.session_list_index.saturating_sub(1);
                 }
                 SessionsPane::Transcript => {
-                    if self.viewing_subagent {
+                    if self.transcript_wrap {
+                        self.transcript_scroll_line(false);
+                    } else if self.viewing_subagent {
                         self.subagent_scroll = self.subagent_scroll.saturating_sub(1);
                     } else {
                         self.follow_mode = false;
@@ -1268,12 +5426,42 @@ impl App {
                     if self.plan_file_index > 0 {
                         self.plan_file_index -= 1;
                         self.plan_content_scroll = 0;
+                        self.plan_outline_index = 0;
                     }
                 }
+                PlansPane::Outline => {
+                    self.plan_select_section(self.plan_outline_index.saturating_sub(1));
+                }
                 PlansPane::Content => {
                     self.plan_content_scroll = self.plan_content_scroll.saturating_sub(1);
                 }
             },
+            ActiveTab::Commands => match self.commands_pane {
+                CommandsPane::List => {
+                    if self.command_index > 0 {
+                        self.command_index -= 1;
+                        self.command_content_scroll = 0;
+                    }
+                }
+                CommandsPane::Content => {
+                    self.command_content_scroll = self.command_content_scroll.saturating_sub(1);
+                }
+            },
+            ActiveTab::Hooks => match self.hooks_pane {
+                HooksPane::List => self.hook_move(false),
+                HooksPane::Detail => {
+                    self.hook_detail_scroll = self.hook_detail_scroll.saturating_sub(1);
+                }
+            },
+            ActiveTab::Settings => match self.settings_pane {
+                SettingsPane::List => self.settings_move(false),
+                SettingsPane::Detail => {
+                    self.settings_detail_scroll = self.settings_detail_scroll.saturating_sub(1);
+                }
+            },
+            ActiveTab::Notes => {
+                self.notes_scroll = self.notes_scroll.saturating_sub(1);
+            }
             ActiveTab::GitHubPRs => match self.gh_pane {
                 GitHubPane::List => {
                     self.gh_skip_to_prev_pr();
@@ -1291,6 +5479,7 @@ impl App {
                 }
             },
             ActiveTab::Jira => match self.jira_pane {
+                JiraPane::List if self.jira_board_mode => self.jira_board_move_cursor(-1),
                 JiraPane::List => {
                     self.jira_skip_to_prev_issue();
                 }
@@ -1306,14 +5495,32 @@ impl App {
                     self.linear_detail_scroll = self.linear_detail_scroll.saturating_sub(1);
                 }
             },
+            ActiveTab::Azure => match self.azure_pane {
+                AzurePane::List => {
+                    self.azure_skip_to_prev_item();
+                }
+                AzurePane::Detail => {
+                    self.azure_detail_scroll = self.azure_detail_scroll.saturating_sub(1);
+                }
+            },
+            ActiveTab::Deps => {
+                self.deps_index = self.deps_index.saturating_sub(1);
+            }
             ActiveTab::Processes => match self.processes_pane {
                 ProcessesPane::List => {
                     self.process_index = self.process_index.saturating_sub(1);
-                    self.process_output_scroll = 0;
+                    self.reset_process_output();
+                    self.process_artifact_index = 0;
                 }
                 ProcessesPane::Output => {
-                    self.process_follow = false;
-                    self.process_output_scroll = self.process_output_scroll.saturating_sub(1);
+                    // Scrolling up breaks follow until G or f
+                    self.set_process_follow(false);
+                    if let Some(top) = self.process_output_top {
+                        self.process_output_top = Some(top.saturating_sub(1));
+                    } else {
+                        let max = self.selected_process().map_or(0, |p| p.output_line_count());
+                        self.process_output_scroll = (self.process_output_scroll + 1).min(max);
+                    }
                 }
             },
         }
@@ -1321,6 +5528,11 @@ impl App {
 
     pub fn navigate_left(&mut self) {
         match self.active_tab {
+            ActiveTab::Overview => {
+                if !self.overview_index.is_multiple_of(OVERVIEW_COLUMNS) {
+                    self.overview_index -= 1;
+                }
+            }
             ActiveTab::Sessions => {
                 self.sessions_pane = SessionsPane::List;
             }
@@ -1347,7 +5559,19 @@ impl App {
                 }
             }
             ActiveTab::Plans => {
-                self.plans_pane = PlansPane::List;
+                self.plans_pane = match self.plans_pane {
+                    PlansPane::Content => PlansPane::Outline,
+                    _ => PlansPane::List,
+                };
+            }
+            ActiveTab::Commands => {
+                self.commands_pane = CommandsPane::List;
+            }
+            ActiveTab::Hooks => {
+                self.hooks_pane = HooksPane::List;
+            }
+            ActiveTab::Settings => {
+                self.settings_pane = SettingsPane::List;
             }
             ActiveTab::GitHubPRs => {
                 self.gh_pane = GitHubPane::List;
@@ -1356,11 +5580,18 @@ impl App {
                 self.gh_issues_pane = IssuesPane::List;
             }
             ActiveTab::Jira => {
+                if self.jira_board_mode && self.jira_pane == JiraPane::List {
+                    self.jira_board_move_column(-1);
+                }
                 self.jira_pane = JiraPane::List;
             }
             ActiveTab::Linear => {
                 self.linear_pane = LinearPane::List;
             }
+            ActiveTab::Azure => {
+                self.azure_pane = AzurePane::List;
+            }
+            ActiveTab::Notes | ActiveTab::Deps => {}
             ActiveTab::Processes => {
                 self.processes_pane = ProcessesPane::List;
             }
@@ -1369,6 +5600,14 @@ impl App {
 
     pub fn navigate_right(&mut self) {
         match self.active_tab {
+            ActiveTab::Overview => {
+                let len = self.overview_cards().len();
+                if self.overview_index % OVERVIEW_COLUMNS < OVERVIEW_COLUMNS - 1
+                    && self.overview_index + 1 < len
+                {
+                    self.overview_index += 1;
+                }
+            }
             ActiveTab::Sessions => {
                 self.sessions_pane = SessionsPane::Transcript;
             }
@@ -1396,7 +5635,19 @@ impl App {
                 }
             }
             ActiveTab::Plans => {
-                self.plans_pane = PlansPane::Content;
+                self.plans_pane = match self.plans_pane {
+                    PlansPane::List => PlansPane::Outline,
+                    _ => PlansPane::Content,
+                };
+            }
+            ActiveTab::Commands => {
+                self.commands_pane = CommandsPane::Content;
+            }
+            ActiveTab::Hooks => {
+                self.hooks_pane = HooksPane::Detail;
+            }
+            ActiveTab::Settings => {
+                self.settings_pane = SettingsPane::Detail;
             }
             ActiveTab::GitHubPRs => {
                 self.gh_pane = GitHubPane::Detail;
@@ -1405,11 +5656,21 @@ impl App {
                 self.gh_issues_pane = IssuesPane::Detail;
             }
             ActiveTab::Jira => {
-                self.jira_pane = JiraPane::Detail;
+                if self.jira_board_mode {
+                    if self.jira_pane == JiraPane::List {
+                        self.jira_board_move_column(1);
+                    }
+                } else {
+                    self.jira_pane = JiraPane::Detail;
+                }
             }
             ActiveTab::Linear => {
                 self.linear_pane = LinearPane::Detail;
             }
+            ActiveTab::Azure => {
+                self.azure_pane = AzurePane::Detail;
+            }
+            ActiveTab::Notes | ActiveTab::Deps => {}
             ActiveTab::Processes => {
                 self.processes_pane = ProcessesPane::Output;
             }
@@ -1418,14 +5679,26 @@ impl App {
 
     pub fn select_item(&mut self) {
         match self.active_tab {
+            ActiveTab::Overview => {
+                if let Some(card) = self.overview_cards().get(self.overview_index) {
+                    self.switch_to_tab(card.target.clone());
+                }
+            }
             ActiveTab::Sessions => {
                 if self.sessions_pane == SessionsPane::List {
                     // Force reload of selected transcript
                     self.loaded_session_id = None;
                     self.load_selected_transcript();
                     self.sessions_pane = SessionsPane::Transcript;
+                } else {
+                    self.open_transcript_detail();
                 }
             }
+            ActiveTab::Teams => match self.teams_pane {
+                TeamsPane::Teams => self.navigate_right(),
+                TeamsPane::Members => self.open_member_transcript(),
+                _ => {}
+            },
             ActiveTab::Git => {
                 if self.git_mode == GitMode::Browse {
                     self.fb_select_item();
@@ -1434,10 +5707,22 @@ impl App {
                     self.git_pane = GitPane::Diff;
                 }
             }
-            ActiveTab::Plans => {
-                if self.plans_pane == PlansPane::List {
+            ActiveTab::Plans => match self.plans_pane {
+                PlansPane::List => self.plans_pane = PlansPane::Content,
+                PlansPane::Outline => {
+                    self.plan_select_section(self.plan_outline_index);
                     self.plans_pane = PlansPane::Content;
                 }
+                PlansPane::Content => {}
+            },
+            ActiveTab::Commands if self.commands_pane == CommandsPane::List => {
+                self.commands_pane = CommandsPane::Content;
+            }
+            ActiveTab::Hooks if self.hooks_pane == HooksPane::List => {
+                self.hooks_pane = HooksPane::Detail;
+            }
+            ActiveTab::Settings if self.settings_pane == SettingsPane::List => {
+                self.settings_pane = SettingsPane::Detail;
             }
             ActiveTab::GitHubPRs => {
                 if self.gh_pane == GitHubPane::List {
@@ -1460,6 +5745,10 @@ impl App {
                     self.linear_open_selected();
                 }
             }
+            ActiveTab::Azure if self.azure_pane == AzurePane::List => {
+                self.azure_load_detail();
+                self.azure_pane = AzurePane::Detail;
+            }
             ActiveTab::Processes => {
                 if self.processes_pane == ProcessesPane::List {
                     self.processes_pane = ProcessesPane::Output;
@@ -1471,9 +5760,11 @@ impl App {
 
     pub fn jump_top(&mut self) {
         match self.active_tab {
+            ActiveTab::Overview => self.overview_index = 0,
             ActiveTab::Sessions => match self.sessions_pane {
                 SessionsPane::List => self.session_list_index = 0,
                 SessionsPane::Transcript => {
+                    self.transcript_wrap_row = 0;
                     if self.viewing_subagent {
                         self.subagent_scroll = 0;
                     } else {
@@ -1511,9 +5802,13 @@ impl App {
                 }
             }
             ActiveTab::Git => {
-                if self.git_mode == GitMode::Browse {
+                if self.git_mode == GitMode::Browse && self.grep_query.is_some() {
+                    self.grep_index = 0;
+                    self.grep_step(1);
+                } else if self.git_mode == GitMode::Browse {
                     self.fb_index = 0;
                     self.fb_content_scroll = 0;
+                    self.fb_page_in();
                 } else {
                     match self.git_pane {
                         GitPane::Files => {
@@ -1531,11 +5826,45 @@ impl App {
                 PlansPane::List => {
                     self.plan_file_index = 0;
                     self.plan_content_scroll = 0;
+                    self.plan_outline_index = 0;
                 }
+                PlansPane::Outline => self.plan_select_section(0),
                 PlansPane::Content => {
                     self.plan_content_scroll = 0;
                 }
             },
+            ActiveTab::Commands => match self.commands_pane {
+                CommandsPane::List => {
+                    self.command_index = 0;
+                    self.command_content_scroll = 0;
+                }
+                CommandsPane::Content => {
+                    self.command_content_scroll = 0;
+                }
+            },
+            ActiveTab::Hooks => match self.hooks_pane {
+                HooksPane::List => {
+                    self.hook_index = 0;
+                    self.hook_settle();
+                    self.hook_detail_scroll = 0;
+                }
+                HooksPane::Detail => {
+                    self.hook_detail_scroll = 0;
+                }
+            },
+            ActiveTab::Settings => match self.settings_pane {
+                SettingsPane::List => {
+                    self.settings_index = 0;
+                    self.settings_settle();
+                    self.settings_detail_scroll = 0;
+                }
+                SettingsPane::Detail => {
+                    self.settings_detail_scroll = 0;
+                }
+            },
+            ActiveTab::Notes => {
+                self.notes_scroll = 0;
+            }
             ActiveTab::GitHubPRs => match self.gh_pane {
                 GitHubPane::List => {
                     self.gh_pr_index = 0;
@@ -1572,13 +5901,27 @@ impl App {
                     self.linear_detail_scroll = 0;
                 }
             },
+            ActiveTab::Azure => match self.azure_pane {
+                AzurePane::List => {
+                    self.azure_index = 0;
+                    self.azure_skip_to_item_entry();
+                }
+                AzurePane::Detail => {
+                    self.azure_detail_scroll = 0;
+                }
+            },
+            ActiveTab::Deps => self.deps_index = 0,
             ActiveTab::Processes => match self.processes_pane {
                 ProcessesPane::List => {
                     self.process_index = 0;
-                    self.process_output_scroll = 0;
+                    self.reset_process_output();
+                    self.process_artifact_index = 0;
                 }
                 ProcessesPane::Output => {
-                    self.process_output_scroll = 0;
+                    self.set_process_follow(false);
+                    self.process_output_top = None;
+                    self.process_output_scroll =
+                        self.selected_process().map_or(0, |p| p.output_line_count());
                 }
             },
         }
@@ -1586,6 +5929,9 @@ impl App {
 
     pub fn jump_bottom(&mut self) {
         match self.active_tab {
+            ActiveTab::Overview => {
+                self.overview_index = self.overview_cards().len().saturating_sub(1)
+            }
             ActiveTab::Sessions => match self.sessions_pane {
                 SessionsPane::List => {
                     if !self.sessions.is_empty() {
@@ -1593,11 +5939,13 @@ impl App {
                     }
                 }
                 SessionsPane::Transcript => {
+                    self.transcript_wrap_row = 0;
                     if self.viewing_subagent {
                         self.subagent_scroll = self.subagent_transcript.len();
                     } else {
                         self.follow_mode = true;
                         self.transcript_scroll = self.transcript_items.len();
+                        self.transcript_unread_from = None;
                     }
                 }
             },
@@ -1644,11 +5992,15 @@ impl App {
                 }
             }
             ActiveTab::Git => {
-                if self.git_mode == GitMode::Browse {
+                if self.git_mode == GitMode::Browse && self.grep_query.is_some() {
+                    self.grep_index = self.grep_items.len();
+                    self.grep_step(-1);
+                } else if self.git_mode == GitMode::Browse {
                     if !self.fb_entries.is_empty() {
                         self.fb_index = self.fb_entries.len() - 1;
                     }
                     self.fb_content_scroll = usize::MAX;
+                    self.fb_page_in();
                 } else {
                     match self.git_pane {
                         GitPane::Files => {
@@ -1656,7 +6008,7 @@ impl App {
                                 self.git_file_index = self.git_flat_list.len() - 1;
                                 while self.git_file_index > 0 {
                                     match self.git_flat_list.get(self.git_file_index) {
-                                        Some(item) if !item.is_file() => {
+                                        Some(item) if !item.is_entry() => {
                                             self.git_file_index -= 1
                                         }
                                         _ => break,
@@ -1676,12 +6028,51 @@ impl App {
                     if !self.plan_files.is_empty() {
                         self.plan_file_index = self.plan_files.len() - 1;
                         self.plan_content_scroll = 0;
+                        self.plan_outline_index = 0;
                     }
                 }
+                PlansPane::Outline => {
+                    let last = self.current_plan_outline().len().saturating_sub(1);
+                    self.plan_select_section(last);
+                }
                 PlansPane::Content => {
                     self.plan_content_scroll = usize::MAX;
                 }
             },
+            ActiveTab::Commands => match self.commands_pane {
+                CommandsPane::List => {
+                    if !self.command_files.is_empty() {
+                        self.command_index = self.command_files.len() - 1;
+                        self.command_content_scroll = 0;
+                    }
+                }
+                CommandsPane::Content => {
+                    self.command_content_scroll = usize::MAX;
+                }
+            },
+            ActiveTab::Hooks => match self.hooks_pane {
+                HooksPane::List => {
+                    self.hook_index = self.hook_flat_list.len().saturating_sub(1);
+                    self.hook_settle();
+                    self.hook_detail_scroll = 0;
+                }
+                HooksPane::Detail => {
+                    self.hook_detail_scroll = usize::MAX;
+                }
+            },
+            ActiveTab::Settings => match self.settings_pane {
+                SettingsPane::List => {
+                    self.settings_index = self.settings_flat_list.len().saturating_sub(1);
+                    self.settings_settle();
+                    self.settings_detail_scroll = 0;
+                }
+                SettingsPane::Detail => {
+                    self.settings_detail_scroll = usize::MAX;
+                }
+            },
+            ActiveTab::Notes => {
+                self.notes_scroll = usize::MAX;
+            }
             ActiveTab::GitHubPRs => match self.gh_pane {
                 GitHubPane::List => {
                     if !self.gh_flat_list.is_empty() {
@@ -1698,6 +6089,7 @@ impl App {
                             }
                         }
                     }
+                    self.load_more_github_prs();
                 }
                 GitHubPane::Detail => {
                     self.gh_detail_scroll = usize::MAX;
@@ -1718,6 +6110,7 @@ impl App {
                             }
                         }
                     }
+                    self.load_more_github_issues();
                 }
                 IssuesPane::Detail => {
                     self.gh_issues_detail_scroll = usize::MAX;
@@ -1758,21 +6151,40 @@ impl App {
                             }
                         }
                     }
+                    self.load_more_linear_issues();
                 }
                 LinearPane::Detail => {
                     self.linear_detail_scroll = usize::MAX;
                 }
             },
+            ActiveTab::Azure => match self.azure_pane {
+                AzurePane::List => {
+                    if !self.azure_flat_list.is_empty() {
+                        self.azure_index = self.azure_flat_list.len() - 1;
+                        while self.azure_index > 0 {
+                            match self.azure_flat_list.get(self.azure_index) {
+                                Some(FlatAzureItem::StateHeader(_)) => self.azure_index -= 1,
+                                _ => break,
+                            }
+                        }
+                    }
+                }
+                AzurePane::Detail => {
+                    self.azure_detail_scroll = usize::MAX;
+                }
+            },
+            ActiveTab::Deps => self.deps_index = self.outdated_deps.len().saturating_sub(1),
             ActiveTab::Processes => match self.processes_pane {
                 ProcessesPane::List => {
                     if !self.processes.is_empty() {
                         self.process_index = self.processes.len() - 1;
-                        self.process_output_scroll = 0;
+                        self.reset_process_output();
+                        self.process_artifact_index = 0;
                     }
                 }
                 ProcessesPane::Output => {
-                    self.process_follow = true;
-                    self.process_output_scroll = usize::MAX;
+                    self.set_process_follow(true);
+                    self.process_output_scroll = 0;
                 }
             },
         }
@@ -1782,20 +6194,435 @@ impl App {
         self.follow_mode = !self.follow_mode;
         if self.follow_mode {
             self.transcript_scroll = self.transcript_items.len();
+            self.transcript_unread_from = None;
+        }
+    }
+
+    // --- Transcript item popup ---
+
+    /// Index of the transcript item under the cursor: the scroll position,
+    /// clamped to the last item.
+    pub fn selected_transcript_index(&self) -> Option<usize> {
+        let (len, scroll) = if self.viewing_subagent {
+            (self.subagent_transcript.len(), self.subagent_scroll)
+        } else {
+            (self.transcript_items.len(), self.transcript_scroll)
+        };
+        len.checked_sub(1).map(|last| scroll.min(last))
+    }
+
+    /// Show the selected transcript item in full.
+    pub fn open_transcript_detail(&mut self) {
+        self.transcript_detail = self.selected_transcript_detail();
+    }
+
+    /// The selected transcript item in full. Tool calls and results are
+    /// truncated in the list, so their JSONL line is re-read from disk.
+    fn selected_transcript_detail(&mut self) -> Option<TranscriptDetail> {
+        let idx = self.selected_transcript_index()?;
+        let (item, path) = if self.viewing_subagent {
+            let agent = self.subagents.get(self.subagent_index)?;
+            (&self.subagent_transcript[idx], agent.path.clone())
+        } else {
+            let session_id = self.loaded_session_id.as_ref()?;
+            let path = self
+                .project_dir(self.loaded_session_home)
+                .join(format!("{}.jsonl", session_id));
+            (&self.transcript_items[idx], path)
+        };
+
+        let full = match item.kind {
+            TranscriptItemKind::ToolUse | TranscriptItemKind::ToolResult => {
+                match transcripts::read_item_detail(&path, item) {
+                    Ok(detail) => detail,
+                    Err(e) => {
+                        self.last_error = Some(format!("Transcript detail: {}", e));
+                        None
+                    }
+                }
+            }
+            _ => None,
+        };
+        let text = full.unwrap_or_else(|| self.transcript_display_text(item).to_string());
+        let time = item
+            .timestamp
+            .map(|ts| ts.format(" %H:%M:%S").to_string())
+            .unwrap_or_default();
+        Some(TranscriptDetail {
+            title: format!("{}{}", item.kind.label().trim(), time),
+            text,
+            scroll: 0,
+        })
+    }
+
+    pub fn transcript_detail_scroll(&mut self, down: bool) {
+        if let Some(detail) = self.transcript_detail.as_mut() {
+            detail.scroll = if down {
+                (detail.scroll + 1).min(detail.text.lines().count().saturating_sub(1))
+            } else {
+                detail.scroll.saturating_sub(1)
+            };
+        }
+    }
+
+    pub fn close_transcript_detail(&mut self) {
+        self.transcript_detail = None;
+    }
+
+    // --- Plan review ---
+
+    /// Recorded decision on a proposed plan, if any.
+    pub fn plan_decision(
+        &self,
+        session_id: &str,
+        proposed: Option<chrono::DateTime<chrono::Utc>>,
+    ) -> Option<&PlanDecision> {
+        self.plan_decisions
+            .iter()
+            .find(|d| d.is_for(session_id, proposed))
+    }
+
+    fn start_plan_review(&mut self, session_id: String, item: &TranscriptItem) {
+        self.plan_review = Some(PlanReview {
+            session_id,
+            proposed: item.timestamp,
+            lines: plans::parse_markdown_lines(&item.text),
+            scroll: 0,
+        });
+    }
+
+    /// Open the review overlay for an undecided plan among the transcript
+    /// items from `first_new` on, while following the session.
+    fn review_new_plan(&mut self, first_new: usize) {
+        if !self.follow_mode || self.plan_review.is_some() {
+            return;
+        }
+        let Some(session_id) = self.loaded_session_id.clone() else {
+            return;
+        };
+        let plan = self
+            .transcript_items
+            .iter()
+            .skip(first_new)
+            .rfind(|i| i.kind == TranscriptItemKind::Plan)
+            .filter(|i| self.plan_decision(&session_id, i.timestamp).is_none())
+            .cloned();
+        if let Some(item) = plan {
+            self.start_plan_review(session_id, &item);
+        }
+    }
+
+    /// Review the most recent plan proposed in the loaded session.
+    pub fn open_latest_plan_review(&mut self) {
+        let Some(session_id) = self.loaded_session_id.clone() else {
+            return;
+        };
+        let plan = self
+            .transcript_items
+            .iter()
+            .rfind(|i| i.kind == TranscriptItemKind::Plan)
+            .cloned();
+        match plan {
+            Some(item) => self.start_plan_review(session_id, &item),
+            None => {
+                self.send_status = Some((
+                    "No plan proposals in this session".to_string(),
+                    Instant::now(),
+                ));
+            }
+        }
+    }
+
+    pub fn plan_review_scroll(&mut self, down: bool) {
+        if let Some(review) = self.plan_review.as_mut() {
+            review.scroll = if down {
+                (review.scroll + 1).min(review.lines.len().saturating_sub(1))
+            } else {
+                review.scroll.saturating_sub(1)
+            };
+        }
+    }
+
+    pub fn close_plan_review(&mut self) {
+        self.plan_review = None;
+    }
+
+    /// Record the decision on the reviewed plan. In two-pane mode the answer
+    /// is also typed into the Claude Code pane's approval prompt.
+    pub fn decide_plan_review(&mut self, accepted: bool) {
+        let Some(review) = self.plan_review.take() else {
+            return;
+        };
+        self.plan_decisions
+            .retain(|d| !d.is_for(&review.session_id, review.proposed));
+        self.plan_decisions.push(PlanDecision {
+            session_id: review.session_id,
+            proposed: review.proposed,
+            accepted,
+            decided: chrono::Utc::now(),
+        });
+        if let Err(e) =
+            plan_reviews::save_decisions(&self.plan_decisions_path, &self.plan_decisions)
+        {
+            self.last_error = Some(format!("Plan review: {}", e));
+        }
+
+        let verdict = if accepted { "accepted" } else { "rejected" };
+        if self.two_pane {
+            if let Some(ref tx) = self.event_tx {
+                self.send_pending = true;
+                let keys = self.project_config.plan_review_keys(accepted).to_string();
+                let route = self.pane_route(CLAUDE_PANE);
+                crate::pane_send::send_keys_to_pane(self.terminal, keys, route, tx.clone());
+            }
+        } else {
+            self.send_status = Some((format!("Plan {} (recorded)", verdict), Instant::now()));
         }
     }
 
-    /// Toggle follow mode for process output.
+    /// Scroll the transcript so the "new" divider is near the top.
+    pub fn jump_to_unread(&mut self) {
+        match self.transcript_unread_from {
+            Some(idx) => {
+                self.viewing_subagent = false;
+                self.transcript_scroll = idx.saturating_sub(1);
+                self.transcript_wrap_row = 0;
+            }
+            None => {
+                self.send_status = Some(("No new transcript lines".to_string(), Instant::now()));
+            }
+        }
+    }
+
+    /// Number of transcript items behind the "new" divider.
+    pub fn transcript_unread_count(&self) -> usize {
+        self.transcript_unread_from
+            .map_or(0, |idx| self.transcript_items.len().saturating_sub(idx))
+    }
+
+    /// Toggle follow mode for the selected process's output.
     pub fn toggle_process_follow(&mut self) {
-        self.process_follow = !self.process_follow;
-        if self.process_follow {
-            self.process_output_scroll = usize::MAX;
+        let follow = !self.selected_process().is_some_and(|p| p.follow);
+        self.set_process_follow(follow);
+        if follow {
+            self.process_output_scroll = 0;
+        }
+    }
+
+    // --- Process output search ---
+
+    /// Open the output search input, prefilled with the last search.
+    pub fn process_search_start(&mut self) {
+        if self.selected_process().is_some() {
+            self.process_search_input = Some(self.process_search.clone().unwrap_or_default());
+        }
+    }
+
+    /// Search the selected process's output for the typed text and jump to
+    /// the first match; empty text clears the search.
+    pub fn process_search_submit(&mut self) {
+        let Some(query) = self.process_search_input.take() else {
+            return;
+        };
+        self.process_output_mark = None;
+        if query.is_empty() {
+            self.process_search = None;
+            return;
+        }
+        self.process_search = Some(query);
+        self.process_search_step(true);
+    }
+
+    /// Jump to the next (or previous) line matching the last search,
+    /// wrapping around the output.
+    pub fn process_search_step(&mut self, forward: bool) {
+        let Some(query) = self.process_search.clone() else {
+            return;
+        };
+        let Some(proc) = self.selected_process() else {
+            return;
+        };
+        let matches = proc.output_matches(&query);
+        let found = match (self.process_output_mark, forward) {
+            (None, true) => matches.first(),
+            (None, false) => matches.last(),
+            (Some(at), true) => matches.iter().find(|&&i| i > at).or(matches.first()),
+            (Some(at), false) => matches.iter().rev().find(|&&i| i < at).or(matches.last()),
+        };
+        let Some(&line) = found else {
+            self.send_status = Some((format!("No match for \"{}\"", query), Instant::now()));
+            return;
+        };
+        let nth = matches.iter().position(|&i| i == line).unwrap_or(0) + 1;
+        self.send_status = Some((
+            format!("Match {} of {}", nth, matches.len()),
+            Instant::now(),
+        ));
+        self.process_output_jump(line);
+    }
+
+    /// Jump to the selected run's first stderr line, or else its first line
+    /// that reads like an error.
+    pub fn process_jump_to_error(&mut self) {
+        let Some(proc) = self.selected_process() else {
+            return;
+        };
+        match proc.first_error_line() {
+            Some(line) => self.process_output_jump(line),
+            None => {
+                self.send_status = Some(("No errors in the output".to_string(), Instant::now()))
+            }
+        }
+    }
+
+    /// Stop following and show `line` near the top of the output pane, with
+    /// the two lines before it for context.
+    fn process_output_jump(&mut self, line: usize) {
+        self.set_process_follow(false);
+        self.process_output_top = Some(line.saturating_sub(2));
+        self.process_output_mark = Some(line);
+        self.processes_pane = ProcessesPane::Output;
+    }
+
+    /// Decide whether low-power mode applies: fixed by `--low-power` or
+    /// `display.low_power`, otherwise checked against the battery in the
+    /// background every `POWER_CHECK_SECS`. Called every tick.
+    pub fn check_power(&mut self) {
+        if let Some(low_power) = self.fixed_low_power() {
+            self.set_low_power(low_power);
+            return;
+        }
+        let due = self
+            .power_checked
+            .is_none_or(|t| t.elapsed() >= Duration::from_secs(config::POWER_CHECK_SECS));
+        if self.power_checking || !due {
+            return;
+        }
+        let Some(tx) = self.event_tx.clone() else {
+            return;
+        };
+        self.power_checking = true;
+        self.power_checked = Some(Instant::now());
+        std::thread::spawn(move || {
+            let _ = tx.send(AppEvent::PowerStatusLoaded(power::on_battery()));
+        });
+    }
+
+    pub fn handle_power_status_loaded(&mut self, on_battery: bool) {
+        self.power_checking = false;
+        if self.fixed_low_power().is_none() {
+            self.set_low_power(on_battery);
+        }
+    }
+
+    /// Low-power mode as fixed by `--low-power` or `display.low_power`.
+    fn fixed_low_power(&self) -> Option<bool> {
+        if self.low_power_flag {
+            Some(true)
+        } else {
+            self.low_power_setting
+        }
+    }
+
+    fn set_low_power(&mut self, low_power: bool) {
+        if self.low_power == low_power {
+            return;
         }
+        self.low_power = low_power;
+        let status = if low_power {
+            "Low-power mode on: polling less often"
+        } else {
+            "Low-power mode off"
+        };
+        self.send_status = Some((status.to_string(), Instant::now()));
     }
 
-    /// Mark the app as needing a redraw.
+    /// Mark the whole screen as needing a redraw.
     pub fn mark_dirty(&mut self) {
-        self.dirty = true;
+        self.dirty = Dirty::all();
+    }
+
+    /// Mark what a tick may have changed: the status bar's countdown and
+    /// clock, and the tab body only while it shows something that moves
+    /// with time on its own.
+    pub fn mark_tick_dirty(&mut self) {
+        self.dirty.status_bar = true;
+        if self.tab_ticks() {
+            self.dirty.content = true;
+        }
+    }
+
+    /// Whether the active tab's body changes with time alone: Overview
+    /// ages, a member's time in their status on Teams, running processes
+    /// and a tab still loading.
+    fn tab_ticks(&self) -> bool {
+        match self.active_tab {
+            ActiveTab::Overview | ActiveTab::Teams => true,
+            ActiveTab::Processes => self.processes.iter().any(|p| {
+                matches!(p.status, ProcessStatus::Running | ProcessStatus::Terminating)
+            }),
+            ref tab => self.tab_loading(tab),
+        }
+    }
+
+    /// Status-bar text for the active tab's next background refresh, if it polls.
+    pub fn poll_countdown(&self) -> Option<String> {
+        let cfg = &self.project_config;
+        let (interval, last_poll) = match self.active_tab {
+            ActiveTab::GitHubPRs if self.prs_from_bitbucket => {
+                (cfg.bitbucket_poll_interval(), self.gh_last_poll)
+            }
+            ActiveTab::GitHubPRs => (cfg.github_poll_interval(), self.gh_last_poll),
+            ActiveTab::GitHubIssues => (cfg.github_poll_interval(), self.gh_issues_last_poll),
+            ActiveTab::Jira => (cfg.jira_poll_interval(), self.jira_last_poll),
+            ActiveTab::Linear => (cfg.linear_poll_interval(), self.linear_last_poll),
+            ActiveTab::Azure => (cfg.azure_poll_interval(), self.azure_last_poll),
+            _ => return None,
+        };
+        let tab = self.active_tab.clone();
+        match config::effective_poll(interval, self.low_power) {
+            Some(interval) => {
+                let delay = self.poll_delay(&tab, interval);
+                let remaining = delay.saturating_sub(last_poll.elapsed()).as_secs();
+                if self.poll_health(&tab) == PollHealth::Ok {
+                    Some(format!("refresh in {}s", remaining))
+                } else {
+                    Some(format!("failing, retry in {}s", remaining))
+                }
+            }
+            None => Some("polling off".to_string()),
+        }
+    }
+
+    /// Time between background polls of `tab`'s integration, stretched
+    /// while they fail.
+    pub fn poll_delay(&self, tab: &ActiveTab, interval: Duration) -> Duration {
+        self.poll_backoff
+            .get(tab)
+            .map_or(interval, |b| b.delay(interval))
+    }
+
+    pub fn poll_health(&self, tab: &ActiveTab) -> PollHealth {
+        self.poll_backoff
+            .get(tab)
+            .map_or(PollHealth::Ok, Backoff::health)
+    }
+
+    /// A refresh of the active tab by hand (`r`): it runs whatever the
+    /// backoff, and its error is shown.
+    pub fn note_manual_refresh(&mut self) {
+        if let Some(backoff) = self.poll_backoff.get_mut(&self.active_tab) {
+            backoff.refresh_requested();
+        }
+    }
+
+    fn poll_succeeded(&mut self, tab: ActiveTab) {
+        self.poll_backoff.remove(&tab);
+    }
+
+    /// Record a failed load of `tab`; whether to show its error.
+    fn poll_failed(&mut self, tab: ActiveTab, error: &str) -> bool {
+        self.poll_backoff.entry(tab).or_default().failed(error)
     }
 
     // --- Git helpers ---
@@ -1812,11 +6639,43 @@ impl App {
         });
     }
 
+    /// Reload git status once changes have been quiet for
+    /// `GIT_REFRESH_DEBOUNCE_MS`; each new change pushes the reload back.
+    fn schedule_git_refresh(&mut self) {
+        let delay = Duration::from_millis(config::GIT_REFRESH_DEBOUNCE_MS);
+        self.git_refresh_due = Some(Instant::now() + delay);
+    }
+
+    /// Called every tick: run the reload `schedule_git_refresh` put off.
+    pub fn flush_git_refresh(&mut self) {
+        if self
+            .git_refresh_due
+            .is_some_and(|due| Instant::now() >= due)
+        {
+            self.git_refresh_due = None;
+            self.load_git_data();
+            self.last_update = Instant::now();
+        }
+    }
+
     pub fn handle_git_status_loaded(&mut self, result: Result<GitStatus, String>) {
+        self.loaded_tabs.insert(ActiveTab::Git);
         match result {
             Ok(status) => {
+                let selected = self.git_flat_list.get(self.git_file_index).cloned();
                 self.git_status = status;
                 self.git_flat_list = self.git_status.flat_list();
+                // Stay on the selected file when rows above it come and go
+                let same = selected.as_ref().and_then(|selected| {
+                    self.git_flat_list
+                        .iter()
+                        .position(|item| item.same_entry(selected))
+                });
+                if let Some(idx) = same {
+                    self.git_file_index = idx;
+                    self.refresh_selected_diff();
+                    return;
+                }
                 // Clamp index
                 if self.git_flat_list.is_empty() {
                     self.git_file_index = 0;
@@ -1834,29 +6693,112 @@ impl App {
 
     pub fn load_selected_diff(&mut self) {
         self.diff_scroll = 0;
+        self.diff_anchor = None;
         if self.git_flat_list.is_empty() {
             self.git_diff_lines.clear();
             return;
         }
+        self.spawn_diff_load();
+    }
+
+    /// Reload the selected diff, keeping the view on the hunk it shows.
+    fn refresh_selected_diff(&mut self) {
+        let hunks = git::hunk_starts(&self.git_diff_lines, self.git_diff_split);
+        self.diff_anchor = Some(git::diff_anchor(&hunks, self.diff_scroll));
+        self.spawn_diff_load();
+    }
+
+    fn spawn_diff_load(&mut self) {
         let idx = self.git_file_index.min(self.git_flat_list.len() - 1);
-        if let FlatGitItem::File(ref entry) = self.git_flat_list[idx] {
-            let tx = match self.event_tx.clone() {
-                Some(tx) => tx,
-                None => return,
+        let item = self.git_flat_list[idx].clone();
+        if !item.is_entry() {
+            return;
+        }
+        let tx = match self.event_tx.clone() {
+            Some(tx) => tx,
+            None => return,
+        };
+        let cwd = self.project_cwd.clone();
+        std::thread::spawn(move || {
+            let result = match item {
+                FlatGitItem::Stash(stash) => git::load_stash_diff(&cwd, &stash),
+                FlatGitItem::File(entry) => git::load_diff(&cwd, &entry),
+                _ => return,
             };
-            let cwd = self.project_cwd.clone();
-            let entry = entry.clone();
-            std::thread::spawn(move || {
-                let result = git::load_diff(&cwd, &entry).map_err(|e| e.to_string());
-                let _ = tx.send(AppEvent::GitDiffLoaded(result));
-            });
+            let _ = tx.send(AppEvent::GitDiffLoaded(result.map_err(|e| e.to_string())));
+        });
+    }
+
+    /// The stash selected in the Files pane, if a stash row is selected.
+    pub fn selected_stash(&self) -> Option<&GitStash> {
+        match self.git_flat_list.get(self.git_file_index) {
+            Some(FlatGitItem::Stash(stash)) => Some(stash),
+            _ => None,
+        }
+    }
+
+    /// Ask for a message, then stash the working tree.
+    pub fn git_start_stash(&mut self) {
+        if self.git_status.total_files() == 0 {
+            self.last_error = Some("Git: no changes to stash".to_string());
+            return;
+        }
+        self.git_stash_input = Some(String::new());
+    }
+
+    pub fn git_submit_stash(&mut self) {
+        let Some(message) = self.git_stash_input.take() else {
+            return;
+        };
+        match git::stash_push(&self.project_cwd, message.trim()) {
+            Ok(()) => {
+                self.send_status = Some(("Stashed changes".to_string(), Instant::now()));
+                self.load_git_data();
+            }
+            Err(e) => self.last_error = Some(format!("Git: {}", e)),
+        }
+    }
+
+    pub fn git_request_stash_action(&mut self, action: StashAction) {
+        if self.selected_stash().is_some() {
+            self.git_stash_confirm = Some(action);
+        }
+    }
+
+    /// Answer the apply / pop / drop confirmation.
+    pub fn git_confirm_stash_action(&mut self, confirmed: bool) {
+        let Some(action) = self.git_stash_confirm.take() else {
+            return;
+        };
+        let Some(stash) = self.selected_stash().cloned().filter(|_| confirmed) else {
+            return;
+        };
+        match git::stash_command(&self.project_cwd, action.command(), &stash) {
+            Ok(()) => {
+                let msg = format!("{} {}", action.past_tense(), stash.name());
+                self.send_status = Some((msg, Instant::now()));
+            }
+            Err(e) => self.last_error = Some(format!("Git: {}", e)),
         }
+        // A conflicting apply still changes the tree, so reload either way
+        self.load_git_data();
+    }
+
+    pub fn toggle_diff_split(&mut self) {
+        self.git_diff_split = !self.git_diff_split;
     }
 
     pub fn handle_git_diff_loaded(&mut self, result: Result<Vec<DiffLine>, String>) {
         match result {
-            Ok(lines) => self.git_diff_lines = lines,
+            Ok(lines) => {
+                self.git_diff_lines = lines;
+                if let Some(anchor) = self.diff_anchor.take() {
+                    let hunks = git::hunk_starts(&self.git_diff_lines, self.git_diff_split);
+                    self.diff_scroll = git::restore_anchor(&hunks, anchor);
+                }
+            }
             Err(e) => {
+                self.diff_anchor = None;
                 self.last_error = Some(format!("Diff: {}", e));
                 self.git_diff_lines.clear();
             }
@@ -1869,10 +6811,10 @@ impl App {
             return;
         }
         let idx = self.git_file_index.min(self.git_flat_list.len() - 1);
-        if !self.git_flat_list[idx].is_file() {
+        if !self.git_flat_list[idx].is_entry() {
             // Scan forward
             for i in (idx + 1)..self.git_flat_list.len() {
-                if self.git_flat_list[i].is_file() {
+                if self.git_flat_list[i].is_entry() {
                     self.git_file_index = i;
                     return;
                 }
@@ -1886,7 +6828,7 @@ impl App {
         }
         let start = self.git_file_index + 1;
         for i in start..self.git_flat_list.len() {
-            if self.git_flat_list[i].is_file() {
+            if self.git_flat_list[i].is_entry() {
                 self.git_file_index = i;
                 return;
             }
@@ -1899,7 +6841,7 @@ impl App {
         }
         let start = self.git_file_index - 1;
         for i in (0..=start).rev() {
-            if self.git_flat_list[i].is_file() {
+            if self.git_flat_list[i].is_entry() {
                 self.git_file_index = i;
                 return;
             }
@@ -1924,6 +6866,453 @@ impl App {
         &self.todo_files[idx].items
     }
 
+    // --- Branch picker ---
+
+    pub fn open_branch_picker(&mut self) {
+        if self.reload_branch_picker() {
+            self.branch_picker_index = 0;
+            self.branch_picker_input = None;
+            self.branch_picker_launch_path = None;
+            self.show_branch_picker = true;
+        }
+    }
+
+    pub fn close_branch_picker(&mut self) {
+        self.show_branch_picker = false;
+        self.branch_picker_input = None;
+        self.branch_picker_input_text.clear();
+        self.branch_picker_launch_path = None;
+    }
+
+    /// Reload branches and worktrees. Returns false (and sets an error) on failure.
+    fn reload_branch_picker(&mut self) -> bool {
+        let loaded = git::list_branches(&self.project_cwd).and_then(|branches| {
+            git::list_worktrees(&self.project_cwd).map(|worktrees| (branches, worktrees))
+        });
+        match loaded {
+            Ok((branches, worktrees)) => {
+                self.branch_picker_branches = branches;
+                self.branch_picker_worktrees = worktrees;
+                let len = self.branch_picker_len();
+                if self.branch_picker_index >= len {
+                    self.branch_picker_index = len.saturating_sub(1);
+                }
+                true
+            }
+            Err(e) => {
+                self.last_error = Some(format!("Git: {}", e));
+                false
+            }
+        }
+    }
+
+    pub fn branch_picker_len(&self) -> usize {
+        self.branch_picker_branches.len() + self.branch_picker_worktrees.len()
+    }
+
+    pub fn branch_picker_down(&mut self) {
+        if self.branch_picker_index + 1 < self.branch_picker_len() {
+            self.branch_picker_index += 1;
+        }
+    }
+
+    pub fn branch_picker_up(&mut self) {
+        self.branch_picker_index = self.branch_picker_index.saturating_sub(1);
+    }
+
+    /// Whether a worktree path from git refers to the project directory.
+    pub fn is_current_worktree(&self, path: &str) -> bool {
+        let norm = |s: &str| s.replace('\\', "/").trim_end_matches('/').to_lowercase();
+        norm(path) == norm(&self.project_cwd.to_string_lossy())
+    }
+
+    /// Enter on the selected row: switch to a branch, or offer to launch
+    /// Claude in a worktree.
+    pub fn branch_picker_select(&mut self) {
+        let idx = self.branch_picker_index;
+        if let Some(branch) = self.branch_picker_branches.get(idx).cloned() {
+            if branch.is_current {
+                self.close_branch_picker();
+                return;
+            }
+            if let Some(ref path) = branch.worktree_path {
+                self.last_error = Some(format!(
+                    "Git: {} is checked out in worktree {}",
+                    branch.name, path
+                ));
+                return;
+            }
+            match git::load_git_status(&self.project_cwd) {
+                Ok(status) if status.has_uncommitted_changes() => {
+                    self.last_error =
+                        Some("Git: commit or stash your changes before switching".to_string());
+                    return;
+                }
+                Ok(_) => {}
+                Err(e) => {
+                    self.last_error = Some(format!("Git: {}", e));
+                    return;
+                }
+            }
+            match git::switch_branch(&self.project_cwd, &branch.name) {
+                Ok(()) => {
+                    self.close_branch_picker();
+                    self.send_status =
+                        Some((format!("Switched to {}", branch.name), Instant::now()));
+                    self.detect_current_issue();
+                    self.load_git_data();
+                    if self.git_mode == GitMode::Browse {
+                        self.load_file_tree();
+                    }
+                }
+                Err(e) => self.last_error = Some(format!("Git: {}", e)),
+            }
+        } else if let Some(wt) = self
+            .branch_picker_worktrees
+            .get(idx - self.branch_picker_branches.len())
+        {
+            if !self.is_current_worktree(&wt.path) {
+                self.branch_picker_launch_path = Some(PathBuf::from(&wt.path));
+            }
+        }
+    }
+
+    pub fn branch_picker_start_input(&mut self, mode: BranchInputMode) {
+        self.branch_picker_input_text.clear();
+        self.branch_picker_input = Some(mode);
+    }
+
+    pub fn branch_picker_cancel_input(&mut self) {
+        self.branch_picker_input = None;
+        self.branch_picker_input_text.clear();
+    }
+
+    pub fn branch_picker_submit_input(&mut self) {
+        let name = self.branch_picker_input_text.trim().to_string();
+        let mode = self.branch_picker_input.take();
+        self.branch_picker_input_text.clear();
+        if name.is_empty() {
+            return;
+        }
+        if name.starts_with('-') || name.contains(char::is_whitespace) {
+            self.last_error = Some(format!("Git: invalid branch name '{}'", name));
+            return;
+        }
+
+        match mode {
+            Some(BranchInputMode::NewBranch) => {
+                match git::create_branch(&self.project_cwd, &name) {
+                    Ok(()) => {
+                        self.send_status = Some((format!("Created {}", name), Instant::now()));
+                        self.reload_branch_picker();
+                    }
+                    Err(e) => self.last_error = Some(format!("Git: {}", e)),
+                }
+            }
+            Some(BranchInputMode::NewWorktree) => {
+                let path = self.worktree_path_for(&name);
+                match git::add_worktree(&self.project_cwd, &path, &name) {
+                    Ok(()) => {
+                        self.reload_branch_picker();
+                        self.branch_picker_launch_path = Some(path);
+                    }
+                    Err(e) => self.last_error = Some(format!("Git: {}", e)),
+                }
+            }
+            None => {}
+        }
+    }
+
+    /// Sibling directory for a new worktree: `<project>-<branch>`.
+    fn worktree_path_for(&self, branch: &str) -> PathBuf {
+        let dir_name = self
+            .project_cwd
+            .file_name()
+            .map(|n| n.to_string_lossy().to_string())
+            .unwrap_or_else(|| "worktree".to_string());
+        let name = format!("{}-{}", dir_name, branch.replace(['/', '\\'], "-"));
+        match self.project_cwd.parent() {
+            Some(parent) => parent.join(name),
+            None => self.project_cwd.join(name),
+        }
+    }
+
+    /// Answer the "launch Claude in this worktree?" prompt.
+    pub fn branch_picker_confirm_launch(&mut self, launch: bool) {
+        let Some(path) = self.branch_picker_launch_path.take() else {
+            return;
+        };
+        if !launch {
+            return;
+        }
+
+        // Reuse `assoc launch` so the new tab gets Claude + dashboard side by side
+        let status = format!("Launched Claude in {}", path.display());
+        self.run_assoc_launch(vec!["--cwd".into(), path.into()], status);
+        self.close_branch_picker();
+    }
+
+    /// Run `assoc launch` with `args` in the background, as opening a tab
+    /// or pane can take a while; `status` is shown once it succeeds. Its
+    /// output would land on the dashboard's screen, so only the error is
+    /// kept.
+    fn run_assoc_launch(&mut self, args: Vec<OsString>, status: String) {
+        let Some(tx) = self.event_tx.clone() else {
+            return;
+        };
+        std::thread::spawn(move || {
+            let result = std::env::current_exe()
+                .and_then(|exe| {
+                    Command::new(exe)
+                        .arg("launch")
+                        .args(args)
+                        .stdin(Stdio::null())
+                        .stdout(Stdio::null())
+                        .stderr(Stdio::piped())
+                        .output()
+                })
+                .map_err(|e| format!("Failed to run assoc launch: {}", e))
+                .and_then(|output| {
+                    if output.status.success() {
+                        return Ok(status);
+                    }
+                    let stderr = String::from_utf8_lossy(&output.stderr);
+                    match stderr.lines().map(str::trim).find(|l| !l.is_empty()) {
+                        Some(msg) => Err(format!("assoc launch: {}", msg)),
+                        None => Err(format!("assoc launch exited with {}", output.status)),
+                    }
+                });
+            let _ = tx.send(AppEvent::AssocLaunched(result));
+        });
+    }
+
+    pub fn handle_assoc_launched(&mut self, result: Result<String, String>) {
+        match result {
+            Ok(status) => self.send_status = Some((status, Instant::now())),
+            Err(e) => self.last_error = Some(e),
+        }
+    }
+
+    // --- Todo editing ---
+
+    /// Cycle the selected item's status and write the file back.
+    pub fn todo_toggle_status(&mut self) {
+        let item_idx = self.todo_item_index;
+        let Some(file) = self.todo_files.get_mut(self.todo_file_index) else {
+            return;
+        };
+        let Some(item) = file.items.get_mut(item_idx) else {
+            return;
+        };
+        item.cycle_status();
+        self.save_current_todo_file();
+    }
+
+    pub fn todo_start_edit(&mut self) {
+        if let Some(item) = self.current_todo_items().get(self.todo_item_index) {
+            self.todo_edit_input = item.content.clone().unwrap_or_default();
+            self.todo_edit_mode = Some(TodoEditMode::Edit(self.todo_item_index));
+        }
+    }
+
+    pub fn todo_start_new(&mut self) {
+        if self.todo_files.is_empty() {
+            return;
+        }
+        self.todo_edit_input.clear();
+        self.todo_edit_mode = Some(TodoEditMode::New);
+    }
+
+    pub fn todo_save_edit(&mut self) {
+        let text = self.todo_edit_input.trim().to_string();
+        let mode = self.todo_edit_mode.take();
+        self.todo_edit_input.clear();
+        if text.is_empty() {
+            return;
+        }
+        let Some(file) = self.todo_files.get_mut(self.todo_file_index) else {
+            return;
+        };
+        match mode {
+            Some(TodoEditMode::Edit(idx)) => {
+                if let Some(item) = file.items.get_mut(idx) {
+                    item.content = Some(text);
+                }
+            }
+            Some(TodoEditMode::New) => {
+                file.items.push(TodoItem::new(text));
+                self.todo_item_index = file.items.len() - 1;
+                self.todos_pane_left = false;
+            }
+            None => return,
+        }
+        self.save_current_todo_file();
+    }
+
+    pub fn todo_cancel_edit(&mut self) {
+        self.todo_edit_mode = None;
+        self.todo_edit_input.clear();
+    }
+
+    /// Trackers a todo can be turned into a ticket in: GitHub Issues,
+    /// `jira.project` and `linear.team`.
+    fn todo_ticket_targets(&self) -> Vec<TicketTarget> {
+        let mut targets = Vec::new();
+        if self.gh_issues_enabled {
+            if let Some(ref repo) = self.gh_issues_repo {
+                targets.push(TicketTarget::GitHub(repo.clone()));
+            }
+        }
+        if self.has_jira {
+            if let Some(project) = self.project_config.jira_project() {
+                targets.push(TicketTarget::Jira(project.to_string()));
+            }
+        }
+        if self.has_linear {
+            let team = self.project_config.linear_query().team;
+            if let (Some(team), Some(api_key)) = (team, self.project_config.linear_api_key()) {
+                targets.push(TicketTarget::Linear {
+                    team,
+                    api_key: api_key.to_string(),
+                });
+            }
+        }
+        targets
+    }
+
+    /// Open the tracker picker for the selected todo, with the ticket's
+    /// title and body drafted from it.
+    pub fn open_todo_promote(&mut self) {
+        let Some(file) = self.todo_files.get(self.todo_file_index) else {
+            return;
+        };
+        let Some(item) = file.items.get(self.todo_item_index) else {
+            return;
+        };
+        if let Some(key) = item.ticket() {
+            self.last_error = Some(format!("Todo is already tracked as {}", key));
+            return;
+        }
+        let targets = self.todo_ticket_targets();
+        if targets.is_empty() {
+            self.last_error = Some(
+                "No tracker to create the ticket in: needs GitHub Issues, jira.project or linear.team"
+                    .to_string(),
+            );
+            return;
+        }
+        let (title, body) = todo_tickets::draft(file, item);
+        self.todo_promote = Some(TodoPromote {
+            targets,
+            index: 0,
+            title,
+            body,
+            home: file.home,
+            filename: file.filename.clone(),
+            content: item.content.clone().unwrap_or_default(),
+            creating: false,
+        });
+    }
+
+    pub fn todo_promote_move(&mut self, down: bool) {
+        if let Some(ref mut promote) = self.todo_promote {
+            let len = promote.targets.len();
+            promote.index = if down {
+                (promote.index + 1) % len
+            } else {
+                (promote.index + len - 1) % len
+            };
+        }
+    }
+
+    /// Create the ticket in the picked tracker in the background.
+    pub fn submit_todo_promote(&mut self) {
+        let Some(ref mut promote) = self.todo_promote else {
+            return;
+        };
+        if promote.creating {
+            return;
+        }
+        let title = promote.title.trim().to_string();
+        if title.is_empty() {
+            self.last_error = Some("Title cannot be empty".to_string());
+            return;
+        }
+        let Some(tx) = self.event_tx.clone() else {
+            return;
+        };
+        promote.creating = true;
+        let target = promote.targets[promote.index].clone();
+        let body = promote.body.clone();
+        let (home, filename, content) = (
+            promote.home,
+            promote.filename.clone(),
+            promote.content.clone(),
+        );
+        self.send_status = Some((format!("Creating {}...", target.label()), Instant::now()));
+        std::thread::spawn(move || {
+            let result = target.create(&title, &body).map_err(|e| e.to_string());
+            let _ = tx.send(AppEvent::TodoTicketCreated(home, filename, content, result));
+        });
+    }
+
+    /// Write the new ticket's key back into the todo it was made from.
+    pub fn handle_todo_ticket_created(
+        &mut self,
+        home: usize,
+        filename: String,
+        content: String,
+        result: Result<String, String>,
+    ) {
+        let key = match result {
+            Ok(key) => key,
+            Err(e) => {
+                if let Some(ref mut promote) = self.todo_promote {
+                    promote.creating = false;
+                }
+                self.last_error = Some(format!("Ticket: {}", e));
+                return;
+            }
+        };
+        self.todo_promote = None;
+        let file = self
+            .todo_files
+            .iter_mut()
+            .find(|f| f.home == home && f.filename == filename);
+        let item = file.and_then(|f| {
+            f.items
+                .iter_mut()
+                .find(|item| item.content.as_deref() == Some(content.as_str()))
+        });
+        let Some(item) = item else {
+            self.send_status = Some((
+                format!("Created {}; the todo changed before it could be noted", key),
+                Instant::now(),
+            ));
+            return;
+        };
+        item.set_ticket(&key);
+        if let Some(file) = self
+            .todo_files
+            .iter()
+            .find(|f| f.home == home && f.filename == filename)
+        {
+            if let Err(e) = todos::save_todo_file(self.home_path(file.home), file) {
+                self.last_error = Some(format!("Todos: {}", e));
+                return;
+            }
+        }
+        self.send_status = Some((format!("Created {}", key), Instant::now()));
+    }
+
+    fn save_current_todo_file(&mut self) {
+        if let Some(file) = self.todo_files.get(self.todo_file_index) {
+            if let Err(e) = todos::save_todo_file(self.home_path(file.home), file) {
+                self.last_error = Some(format!("Todos: {}", e));
+            }
+        }
+    }
+
     // --- File browser helpers ---
 
     pub fn toggle_git_mode(&mut self) {
@@ -1956,6 +7345,7 @@ impl App {
 
     fn fb_navigate_down(&mut self) {
         match self.fb_pane {
+            FileBrowserPane::Tree if self.grep_query.is_some() => self.grep_step(1),
             FileBrowserPane::Tree => {
                 if !self.fb_entries.is_empty() {
                     self.fb_index = (self.fb_index + 1).min(self.fb_entries.len() - 1);
@@ -1963,22 +7353,32 @@ impl App {
             }
             FileBrowserPane::Content => {
                 self.fb_content_scroll = self.fb_content_scroll.saturating_add(1);
+                self.fb_page_in();
             }
         }
     }
 
     fn fb_navigate_up(&mut self) {
         match self.fb_pane {
+            FileBrowserPane::Tree if self.grep_query.is_some() => self.grep_step(-1),
             FileBrowserPane::Tree => {
                 self.fb_index = self.fb_index.saturating_sub(1);
             }
             FileBrowserPane::Content => {
                 self.fb_content_scroll = self.fb_content_scroll.saturating_sub(1);
+                self.fb_tail = false;
+                self.fb_page_in();
             }
         }
     }
 
     fn fb_select_item(&mut self) {
+        if self.grep_query.is_some() {
+            if self.grep_selected().is_some() {
+                self.fb_pane = FileBrowserPane::Content;
+            }
+            return;
+        }
         if self.fb_entries.is_empty() {
             return;
         }
@@ -1999,18 +7399,225 @@ impl App {
             EntryKind::File => {
                 // Load file content
                 match filebrowser::read_file_content(&entry.path) {
-                    Ok(content) => {
+                    Ok((content, info)) => {
                         self.fb_content = Some(content);
+                        self.fb_content_info = Some(info);
                         self.fb_content_path = Some(entry.path);
                         self.fb_content_scroll = 0;
+                        self.fb_tail = false;
                         self.fb_pane = FileBrowserPane::Content;
                     }
-                    Err(e) => {
-                        self.last_error = Some(format!("Read file: {}", e));
+                    Err(e) => {
+                        self.last_error = Some(format!("Read file: {}", e));
+                    }
+                }
+            }
+        }
+    }
+
+    /// Load the part of a large file the content pane has scrolled to.
+    fn fb_page_in(&mut self) {
+        let (Some(FileContent::Large(file)), Some(path)) =
+            (self.fb_content.as_mut(), self.fb_content_path.as_ref())
+        else {
+            return;
+        };
+        if let Err(e) = filebrowser::page_large_file(path, file, self.fb_content_scroll) {
+            self.last_error = Some(format!("Read file: {}", e));
+        }
+        self.fb_content_scroll = self
+            .fb_content_scroll
+            .min(file.line_count().saturating_sub(1));
+    }
+
+    /// Toggle following the end of the open file (`f`).
+    pub fn fb_toggle_tail(&mut self) {
+        if self.fb_content_path.is_none() || self.fb_editing {
+            return;
+        }
+        self.fb_tail = !self.fb_tail;
+        if self.fb_tail {
+            self.fb_content_scroll = usize::MAX;
+            self.fb_page_in();
+        }
+    }
+
+    /// Show what the followed file gained since the last tick.
+    pub fn refresh_file_tail(&mut self) {
+        if !self.fb_tail || self.fb_editing {
+            return;
+        }
+        let Some(path) = self.fb_content_path.clone() else {
+            return;
+        };
+        let grown = match self.fb_content.as_mut() {
+            Some(FileContent::Large(file)) => filebrowser::refresh_large_file(&path, file),
+            _ => std::fs::metadata(&path)
+                .map(|m| self.fb_content_info.as_ref().map(|i| i.size) != Some(m.len()))
+                .map_err(anyhow::Error::from),
+        };
+        match grown {
+            Ok(false) => return,
+            Ok(true) => {}
+            Err(e) => {
+                self.last_error = Some(format!("Follow file: {}", e));
+                self.fb_tail = false;
+                return;
+            }
+        }
+        if let Some(FileContent::Large(file)) = self.fb_content.as_ref() {
+            if let Some(info) = self.fb_content_info.as_mut() {
+                info.size = file.size;
+            }
+        } else {
+            // Small files are read again, and become large ones past the limit
+            match filebrowser::read_file_content(&path) {
+                Ok((content, info)) => {
+                    self.fb_content = Some(content);
+                    self.fb_content_info = Some(info);
+                }
+                Err(e) => {
+                    self.last_error = Some(format!("Follow file: {}", e));
+                    self.fb_tail = false;
+                    return;
+                }
+            }
+        }
+        self.fb_content_scroll = usize::MAX;
+        self.fb_page_in();
+        self.dirty.content = true;
+    }
+
+    /// The entry highlighted in the file tree, outside search results.
+    fn selected_fb_entry(&self) -> Option<&FileBrowserEntry> {
+        if self.git_mode != GitMode::Browse
+            || self.fb_pane != FileBrowserPane::Tree
+            || self.grep_query.is_some()
+        {
+            return None;
+        }
+        self.fb_entries.get(self.fb_index)
+    }
+
+    /// `path` relative to the project root, with `/` separators.
+    fn fb_relative(&self, path: &Path) -> String {
+        let relative = path.strip_prefix(&self.project_cwd).unwrap_or(path);
+        let parts: Vec<String> = relative
+            .components()
+            .map(|c| c.as_os_str().to_string_lossy().into_owned())
+            .collect();
+        parts.join("/")
+    }
+
+    fn fb_close_content(&mut self) {
+        self.fb_content = None;
+        self.fb_content_info = None;
+        self.fb_content_path = None;
+        self.fb_content_scroll = 0;
+        self.fb_tail = false;
+    }
+
+    /// Ask for the path of a new file or folder (`n` / `N`), starting in
+    /// the highlighted folder.
+    pub fn fb_start_new(&mut self, dir: bool) {
+        if self.git_mode != GitMode::Browse || self.grep_query.is_some() {
+            return;
+        }
+        use crate::model::filebrowser::EntryKind;
+        let folder = match self.fb_entries.get(self.fb_index) {
+            Some(e) if e.kind == EntryKind::Directory => Some(e.path.clone()),
+            Some(e) => e.path.parent().map(Path::to_path_buf),
+            None => None,
+        };
+        let input = match folder.filter(|f| *f != self.project_cwd) {
+            Some(folder) => format!("{}/", self.fb_relative(&folder)),
+            None => String::new(),
+        };
+        let op = if dir { FileOp::NewDir } else { FileOp::NewFile };
+        self.fb_op = Some(FileOpInput { op, input });
+    }
+
+    /// Ask where to rename or move the highlighted entry (`r`).
+    pub fn fb_start_rename(&mut self) {
+        let Some(path) = self.selected_fb_entry().map(|e| e.path.clone()) else {
+            return;
+        };
+        if trash::trash_dir(&self.project_cwd).starts_with(&path) {
+            self.last_error = Some(format!(
+                "Rename: {} holds the trash",
+                self.fb_relative(&path)
+            ));
+            return;
+        }
+        let input = self.fb_relative(&path);
+        self.fb_op = Some(FileOpInput {
+            op: FileOp::Rename(path),
+            input,
+        });
+    }
+
+    /// Carry out the typed file operation and select its result in the tree.
+    pub fn fb_submit_op(&mut self) {
+        let Some(FileOpInput { op, input }) = self.fb_op.take() else {
+            return;
+        };
+        let target = match filebrowser::project_path(&self.project_cwd, &input) {
+            Ok(target) => target,
+            Err(e) => {
+                self.last_error = Some(format!("File: {}", e));
+                return;
+            }
+        };
+        let done = match &op {
+            FileOp::NewFile => filebrowser::create_file(&target),
+            FileOp::NewDir => filebrowser::create_dir(&target),
+            FileOp::Rename(from) => filebrowser::rename_path(from, &target),
+        };
+        if let Err(e) = done {
+            self.last_error = Some(format!("File: {}", e));
+            return;
+        }
+
+        let status = match &op {
+            FileOp::NewFile => format!("Created {}", self.fb_relative(&target)),
+            FileOp::NewDir => format!("Created {}/", self.fb_relative(&target)),
+            FileOp::Rename(from) => {
+                // Follow the moved entry: open file, expanded folders
+                if let Some(open) = self.fb_content_path.as_ref() {
+                    if let Ok(rest) = open.strip_prefix(from) {
+                        self.fb_content_path = Some(target.join(rest));
+                    }
+                }
+                let moved: Vec<PathBuf> = self
+                    .fb_expanded
+                    .iter()
+                    .filter(|dir| dir.starts_with(from))
+                    .cloned()
+                    .collect();
+                for dir in moved {
+                    self.fb_expanded.remove(&dir);
+                    if let Ok(rest) = dir.strip_prefix(from) {
+                        self.fb_expanded.insert(target.join(rest));
                     }
                 }
+                format!(
+                    "Moved {} to {}",
+                    self.fb_relative(from),
+                    self.fb_relative(&target)
+                )
+            }
+        };
+        for dir in target.ancestors().skip(1) {
+            if dir.starts_with(&self.project_cwd) && dir != self.project_cwd {
+                self.fb_expanded.insert(dir.to_path_buf());
             }
         }
+        self.load_file_tree();
+        if let Some(i) = self.fb_entries.iter().position(|e| e.path == target) {
+            self.fb_index = i;
+        }
+        self.fb_pane = FileBrowserPane::Tree;
+        self.send_status = Some((status, Instant::now()));
     }
 
     pub fn fb_backspace(&mut self) {
@@ -2077,7 +7684,10 @@ impl App {
             self.fb_editing = false;
             self.fb_editor = None;
             match filebrowser::read_file_content(&path) {
-                Ok(c) => self.fb_content = Some(c),
+                Ok((content, info)) => {
+                    self.fb_content = Some(content);
+                    self.fb_content_info = Some(info);
+                }
                 Err(e) => self.last_error = Some(format!("Reload: {}", e)),
             }
         }
@@ -2088,9 +7698,367 @@ impl App {
         self.fb_editor = None;
     }
 
+    // --- Opening referenced files ---
+
+    /// Open the file the selected transcript item mentions, or the file in
+    /// the diff pane, at the referenced line (`e`).
+    pub fn open_file_reference(&mut self) {
+        let refs = match self.active_tab {
+            ActiveTab::Sessions => self
+                .selected_transcript_detail()
+                .map(|d| file_ref::transcript_refs(&d.text))
+                .unwrap_or_default(),
+            ActiveTab::Git => self.diff_file_ref().into_iter().collect(),
+            _ => return,
+        };
+        // Mentions can be relative to the project or made up; take the first real file
+        let found = refs
+            .into_iter()
+            .find_map(|r| Some((self.resolve_ref_path(&r.path)?, r)));
+        let Some((path, file_ref)) = found else {
+            self.last_error = Some("Open: no existing file referenced here".to_string());
+            return;
+        };
+        let line = file_ref
+            .line
+            .or_else(|| {
+                let anchor = file_ref.anchor.as_deref()?;
+                let contents = std::fs::read_to_string(&path).ok()?;
+                file_ref::locate(&contents, anchor)
+            })
+            .unwrap_or(1);
+        self.open_file_at(path, line);
+    }
+
+    /// The selected diff's file, at the line shown at the top of the diff pane.
+    fn diff_file_ref(&self) -> Option<FileRef> {
+        let idx = self
+            .git_file_index
+            .min(self.git_flat_list.len().checked_sub(1)?);
+        let FlatGitItem::File(ref entry) = self.git_flat_list[idx] else {
+            return None;
+        };
+        let top = self
+            .diff_scroll
+            .min(self.git_diff_lines.len().saturating_sub(1));
+        Some(FileRef {
+            path: entry.path.clone(),
+            line: git::new_file_line(&self.git_diff_lines, top),
+            anchor: None,
+        })
+    }
+
+    /// `path` as an existing file, taking relative paths from the project root.
+    fn resolve_ref_path(&self, path: &str) -> Option<PathBuf> {
+        let path = self.project_cwd.join(path);
+        path.is_file().then_some(path)
+    }
+
+    /// Open `path` at `line`: in `editor.command` when configured, otherwise
+    /// in the Git tab's file browser editor.
+    fn open_file_at(&mut self, path: PathBuf, line: usize) {
+        if let Some(template) = self.project_config.editor_command() {
+            self.pending_editor = Some(file_ref::editor_command(
+                template,
+                &path.to_string_lossy(),
+                line,
+            ));
+            return;
+        }
+        if !self.is_tab_enabled(&ActiveTab::Git) {
+            self.last_error = Some(
+                "Open: the Git tab is disabled; set editor.command to use an external editor"
+                    .to_string(),
+            );
+            return;
+        }
+        if !self.browse_file_at(path, line) {
+            return;
+        }
+
+        self.fb_start_edit();
+        if let Some(editor) = self.fb_editor.as_mut() {
+            let row = u16::try_from(line - 1).unwrap_or(u16::MAX);
+            editor.move_cursor(tui_textarea::CursorMove::Jump(row, 0));
+        }
+    }
+
+    /// Show `path` in the Git tab's file browser, scrolled to `line`.
+    fn browse_file_at(&mut self, path: PathBuf, line: usize) -> bool {
+        let (content, info) = match filebrowser::read_file_content(&path) {
+            Ok(read) => read,
+            Err(e) => {
+                self.last_error = Some(format!("Read file: {}", e));
+                return false;
+            }
+        };
+
+        self.switch_to_tab(ActiveTab::Git);
+        self.git_mode = GitMode::Browse;
+        // Expand the file's folders so the tree shows it selected
+        if let Ok(relative) = path.strip_prefix(&self.project_cwd) {
+            for dir in relative.ancestors().skip(1) {
+                if !dir.as_os_str().is_empty() {
+                    self.fb_expanded.insert(self.project_cwd.join(dir));
+                }
+            }
+        }
+        self.load_file_tree();
+        if let Some(i) = self.fb_entries.iter().position(|e| e.path == path) {
+            self.fb_index = i;
+        }
+        self.fb_content = Some(content);
+        self.fb_content_info = Some(info);
+        self.fb_content_path = Some(path);
+        self.fb_content_scroll = line - 1;
+        self.fb_tail = false;
+        self.fb_page_in();
+        self.fb_pane = FileBrowserPane::Content;
+        true
+    }
+
+    // --- Project search ---
+
+    /// Open the search input, prefilled with the last pattern.
+    pub fn grep_start(&mut self) {
+        self.grep_input = Some(self.grep_query.clone().unwrap_or_default());
+    }
+
+    /// Search for the typed pattern in the background; an empty pattern
+    /// closes the results.
+    pub fn grep_submit(&mut self) {
+        let Some(pattern) = self.grep_input.take() else {
+            return;
+        };
+        if pattern.is_empty() {
+            self.grep_close();
+            return;
+        }
+        let Some(tx) = self.event_tx.clone() else {
+            return;
+        };
+        self.grep_query = Some(pattern.clone());
+        self.grep_loading = true;
+        self.fb_pane = FileBrowserPane::Tree;
+        let cwd = self.project_cwd.clone();
+        std::thread::spawn(move || {
+            let result = grep::search(&cwd, &pattern).map_err(|e| e.to_string());
+            let _ = tx.send(AppEvent::GrepLoaded(pattern, result));
+        });
+    }
+
+    pub fn handle_grep_loaded(&mut self, pattern: String, result: Result<GrepResults, String>) {
+        // A newer search was started, or the results were closed
+        if self.grep_query.as_ref() != Some(&pattern) {
+            return;
+        }
+        self.grep_loading = false;
+        match result {
+            Ok(results) => {
+                self.grep_items = results.flat_list();
+                self.grep_results = results;
+                self.grep_index = 0;
+                self.grep_step(1);
+            }
+            Err(e) => self.last_error = Some(format!("Search: {}", e)),
+        }
+    }
+
+    /// Put the file tree back in place of the results.
+    pub fn grep_close(&mut self) {
+        self.grep_query = None;
+        self.grep_loading = false;
+        self.grep_results = GrepResults::default();
+        self.grep_items.clear();
+        self.grep_index = 0;
+        self.fb_pane = FileBrowserPane::Tree;
+    }
+
+    pub fn grep_selected(&self) -> Option<&GrepMatch> {
+        match self.grep_items.get(self.grep_index)? {
+            FlatGrepItem::Match(m) => Some(m),
+            FlatGrepItem::File(..) => None,
+        }
+    }
+
+    /// Move to the next (`1`) or previous (`-1`) matched line, skipping the
+    /// file headers, and preview it.
+    fn grep_step(&mut self, step: isize) {
+        let mut i = self.grep_index;
+        while let Some(next) = i.checked_add_signed(step) {
+            if next >= self.grep_items.len() {
+                break;
+            }
+            i = next;
+            if matches!(self.grep_items[i], FlatGrepItem::Match(_)) {
+                self.grep_index = i;
+                break;
+            }
+        }
+        self.grep_preview();
+    }
+
+    /// Show the selected match's file in the content pane, scrolled to it.
+    fn grep_preview(&mut self) {
+        let Some(m) = self.grep_selected() else {
+            return;
+        };
+        let path = self.project_cwd.join(&m.path);
+        let line = m.line;
+        // Editing at a match leaves the file rendered as markdown
+        let rendered = matches!(self.fb_content, Some(FileContent::Markdown(_)));
+        if rendered || self.fb_content_path.as_ref() != Some(&path) {
+            match filebrowser::read_file_content(&path) {
+                Ok((content, info)) => {
+                    // Line numbers have to match the file's, so no rendering
+                    let content = match content {
+                        FileContent::Markdown(_) => std::fs::read_to_string(&path)
+                            .map(|raw| FileContent::Text(raw.lines().map(String::from).collect()))
+                            .unwrap_or(content),
+                        other => other,
+                    };
+                    self.fb_content = Some(content);
+                    self.fb_content_info = Some(info);
+                    self.fb_content_path = Some(path);
+                    self.fb_tail = false;
+                }
+                Err(e) => {
+                    self.last_error = Some(format!("Read file: {}", e));
+                    return;
+                }
+            }
+        }
+        self.fb_content_scroll = line.saturating_sub(1 + GREP_PREVIEW_CONTEXT);
+        self.fb_page_in();
+    }
+
+    /// Matched lines of the file in the content pane, when searching.
+    pub fn grep_content_matches(&self) -> Vec<&GrepMatch> {
+        let Some(path) = self.fb_content_path.as_ref() else {
+            return Vec::new();
+        };
+        self.grep_results
+            .matches
+            .iter()
+            .filter(|m| self.project_cwd.join(&m.path) == *path)
+            .collect()
+    }
+
+    /// Edit the selected match's file at its line (`e`).
+    pub fn grep_open_match(&mut self) {
+        let Some(m) = self.grep_selected() else {
+            return;
+        };
+        let (path, line) = (self.project_cwd.join(&m.path), m.line);
+        self.open_file_at(path, line);
+    }
+
+    // --- Link previews ---
+
+    /// Text of the selected PR, issue or Jira item that may contain links.
+    fn selected_item_text(&self) -> Option<String> {
+        let comments = |comments: &[IssueComment]| {
+            comments
+                .iter()
+                .map(|c| c.body.as_str())
+                .collect::<Vec<_>>()
+                .join("\n")
+        };
+        match self.active_tab {
+            ActiveTab::GitHubPRs => self.gh_selected_pr().map(|pr| {
+                format!(
+                    "{}\n{}",
+                    pr.body.as_deref().unwrap_or(""),
+                    comments(&pr.comments)
+                )
+            }),
+            ActiveTab::GitHubIssues => self.issues_selected().map(|issue| {
+                format!(
+                    "{}\n{}",
+                    issue.body.as_deref().unwrap_or(""),
+                    comments(&issue.comments)
+                )
+            }),
+            ActiveTab::Jira => self
+                .jira_selected_issue()
+                .map(|issue| issue.description.clone().unwrap_or_default()),
+            _ => None,
+        }
+    }
+
+    /// List the links in the selected item and preview the first one.
+    pub fn open_link_popup(&mut self) {
+        let Some(text) = self.selected_item_text() else {
+            return;
+        };
+        let urls = find_urls(&text);
+        if urls.is_empty() {
+            self.last_error = Some("Links: none in this item".to_string());
+            return;
+        }
+        self.link_popup = Some(LinkPopup { urls, index: 0 });
+        self.fetch_link_preview();
+    }
+
+    pub fn close_link_popup(&mut self) {
+        self.link_popup = None;
+    }
+
+    pub fn link_popup_move(&mut self, down: bool) {
+        let Some(popup) = self.link_popup.as_mut() else {
+            return;
+        };
+        if down {
+            if popup.index + 1 < popup.urls.len() {
+                popup.index += 1;
+            }
+        } else {
+            popup.index = popup.index.saturating_sub(1);
+        }
+        self.fetch_link_preview();
+    }
+
+    pub fn selected_link(&self) -> Option<&str> {
+        let popup = self.link_popup.as_ref()?;
+        popup.urls.get(popup.index).map(String::as_str)
+    }
+
+    pub fn open_selected_link(&self) {
+        if let Some(url) = self.selected_link() {
+            cli_detect::open_url(url);
+        }
+    }
+
+    /// Fetch the highlighted link's preview in the background unless it is
+    /// cached or already on its way.
+    fn fetch_link_preview(&mut self) {
+        let Some(url) = self.selected_link().map(String::from) else {
+            return;
+        };
+        if self.link_previews.contains_key(&url) || self.link_previews_loading.contains(&url) {
+            return;
+        }
+        let Some(tx) = self.event_tx.clone() else {
+            return;
+        };
+        self.link_previews_loading.insert(url.clone());
+        std::thread::spawn(move || {
+            let result = link_preview::fetch_preview(&url).map_err(|e| e.to_string());
+            let _ = tx.send(AppEvent::LinkPreviewLoaded(url, result));
+        });
+    }
+
+    pub fn handle_link_preview_loaded(&mut self, url: String, result: Result<LinkPreview, String>) {
+        self.link_previews_loading.remove(&url);
+        self.link_previews.insert(url, result);
+    }
+
     // --- Delete helpers ---
 
-    /// Show the delete confirmation dialog for the currently selected item.
+    /// Move the currently selected item to the trash. Small files go at once;
+    /// directories and large files ask first, as set by `[confirm]` in the
+    /// config. Either way `z` restores it for a moment, and the trash view
+    /// after that.
     pub fn request_delete(&mut self) {
         let name = match self.active_tab {
             ActiveTab::Todos => {
@@ -2107,6 +8075,15 @@ impl App {
                 let idx = self.plan_file_index.min(self.plan_files.len() - 1);
                 self.plan_files[idx].filename.clone()
             }
+            ActiveTab::Commands => {
+                if self.commands_pane != CommandsPane::List {
+                    return;
+                }
+                match self.selected_command() {
+                    Some(c) => c.display_name(),
+                    None => return,
+                }
+            }
             ActiveTab::Sessions => {
                 if self.sessions_pane != SessionsPane::List || self.sessions.is_empty() {
                     return;
@@ -2122,20 +8099,235 @@ impl App {
                 let idx = self.team_list_index.min(self.teams.len() - 1);
                 self.teams[idx].display_name().to_string()
             }
+            ActiveTab::Git => {
+                let Some(entry) = self.selected_fb_entry() else {
+                    return;
+                };
+                if trash::trash_dir(&self.project_cwd).starts_with(&entry.path) {
+                    self.last_error = Some(format!("Delete: {} holds the trash", entry.name));
+                    return;
+                }
+                self.fb_relative(&entry.path)
+            }
             _ => return,
         };
+        let path = self.selected_delete_path();
+        let size = path
+            .as_ref()
+            .and_then(|p| std::fs::metadata(p).ok())
+            .filter(|m| m.is_file())
+            .map(|m| m.len());
+        if path.is_some() && !self.project_config.delete_needs_confirm(size) {
+            self.execute_delete();
+            return;
+        }
+        self.delete_target_detail = match size {
+            Some(size) => format!("{} KB", size.div_ceil(1024)),
+            None if self.active_tab == ActiveTab::Teams => "Team directory".to_string(),
+            None if self.active_tab == ActiveTab::Git => "Folder".to_string(),
+            None => String::new(),
+        };
         self.delete_target_name = name;
         self.confirm_delete = true;
     }
 
-    /// Execute the delete after confirmation.
+    /// Path of the file or directory `request_delete` would remove.
+    fn selected_delete_path(&self) -> Option<PathBuf> {
+        match self.active_tab {
+            ActiveTab::Todos => {
+                let file = self.todo_files.get(self.todo_file_index)?;
+                Some(self.home_path(file.home).join("todos").join(&file.filename))
+            }
+            ActiveTab::Plans => {
+                let file = self.plan_files.get(self.plan_file_index)?;
+                Some(self.claude_home.join("plans").join(&file.filename))
+            }
+            ActiveTab::Commands => self.selected_command().map(|c| c.path.clone()),
+            ActiveTab::Sessions => {
+                let session = self.sessions.get(self.session_list_index)?;
+                Some(
+                    self.project_dir(session.home)
+                        .join(format!("{}.jsonl", session.session_id)),
+                )
+            }
+            ActiveTab::Teams => {
+                let team = self.teams.get(self.team_list_index)?;
+                Some(self.home_path(team.home).join("teams").join(&team.dir_name))
+            }
+            ActiveTab::Git => self.selected_fb_entry().map(|e| e.path.clone()),
+            _ => None,
+        }
+    }
+
+    /// Move `path` to the project's trash, remembering it for `z`. False,
+    /// with the error shown, when it could not be moved.
+    fn move_to_trash(&mut self, name: String, source: TrashSource, path: &Path) -> bool {
+        match trash::move_to_trash(&self.project_cwd, &name, source, path) {
+            Ok(entry) => {
+                self.send_status = Some((
+                    format!("Moved {} to the trash (z to undo)", name),
+                    Instant::now(),
+                ));
+                self.undo_delete = Some(DeletedFile {
+                    name,
+                    trash_id: entry.id,
+                    tab: self.active_tab.clone(),
+                    at: Instant::now(),
+                });
+                true
+            }
+            Err(e) => {
+                self.last_error = Some(format!("Delete {}: {}", source.label(), e));
+                false
+            }
+        }
+    }
+
+    /// Whether `z` would restore the last deleted item.
+    pub fn can_undo_delete(&self) -> bool {
+        self.undo_delete
+            .as_ref()
+            .is_some_and(|d| d.at.elapsed() <= UNDO_WINDOW)
+    }
+
+    /// Restore the last deleted item from the trash, if it is still within
+    /// the undo window.
+    pub fn undo_last_delete(&mut self) {
+        if !self.can_undo_delete() {
+            return;
+        }
+        let Some(deleted) = self.undo_delete.take() else {
+            return;
+        };
+        if let Err(e) = trash::restore(&self.project_cwd, &deleted.trash_id) {
+            self.last_error = Some(format!("Undo delete: {}", e));
+            return;
+        }
+        self.load_tab(&deleted.tab);
+        self.send_status = Some((format!("Restored {}", deleted.name), Instant::now()));
+    }
+
+    // --- Trash ---
+
+    pub fn toggle_trash(&mut self) {
+        if self.trash.is_some() {
+            self.trash = None;
+        } else {
+            self.trash = Some(TrashView {
+                entries: trash::load_trash(&self.project_cwd),
+                ..TrashView::default()
+            });
+        }
+    }
+
+    pub fn close_trash(&mut self) {
+        self.trash = None;
+    }
+
+    pub fn trash_move(&mut self, down: bool) {
+        let Some(view) = self.trash.as_mut() else {
+            return;
+        };
+        if down {
+            if view.index + 1 < view.entries.len() {
+                view.index += 1;
+            }
+        } else {
+            view.index = view.index.saturating_sub(1);
+        }
+    }
+
+    /// Put the selected item back where it was deleted from.
+    pub fn trash_restore(&mut self) {
+        let Some(id) = self.selected_trash_entry().map(|e| e.id.clone()) else {
+            return;
+        };
+        match trash::restore(&self.project_cwd, &id) {
+            Ok(entry) => {
+                if self.undo_delete.as_ref().is_some_and(|d| d.trash_id == id) {
+                    self.undo_delete = None;
+                }
+                self.load_tab(&Self::trash_tab(entry.source));
+                self.send_status = Some((format!("Restored {}", entry.name), Instant::now()));
+            }
+            Err(e) => self.last_error = Some(format!("Restore: {}", e)),
+        }
+        self.reload_trash();
+    }
+
+    /// Ask before purging the selected item, or everything.
+    pub fn trash_request_purge(&mut self, scope: TrashPurge) {
+        if let Some(view) = self.trash.as_mut().filter(|v| !v.entries.is_empty()) {
+            view.confirm = Some(scope);
+        }
+    }
+
+    pub fn trash_cancel_purge(&mut self) {
+        if let Some(view) = self.trash.as_mut() {
+            view.confirm = None;
+        }
+    }
+
+    /// Delete what `trash_request_purge` asked about for good.
+    pub fn trash_confirm_purge(&mut self) {
+        let Some(scope) = self.trash.as_mut().and_then(|v| v.confirm.take()) else {
+            return;
+        };
+        let purged = match scope {
+            TrashPurge::Selected => match self.selected_trash_entry().map(|e| e.id.clone()) {
+                Some(id) => trash::purge(&self.project_cwd, &id),
+                None => return,
+            },
+            TrashPurge::All => trash::purge_all(&self.project_cwd),
+        };
+        if let Err(e) = purged {
+            self.last_error = Some(format!("Purge: {}", e));
+        }
+        // Nothing left for `z` to restore
+        let trashed = trash::load_trash(&self.project_cwd);
+        if let Some(deleted) = &self.undo_delete {
+            if !trashed.iter().any(|e| e.id == deleted.trash_id) {
+                self.undo_delete = None;
+            }
+        }
+        self.reload_trash();
+    }
+
+    pub fn selected_trash_entry(&self) -> Option<&TrashEntry> {
+        let view = self.trash.as_ref()?;
+        view.entries.get(view.index)
+    }
+
+    fn reload_trash(&mut self) {
+        if let Some(view) = self.trash.as_mut() {
+            view.entries = trash::load_trash(&self.project_cwd);
+            view.index = view.index.min(view.entries.len().saturating_sub(1));
+        }
+    }
+
+    /// Tab that lists items of `source`.
+    fn trash_tab(source: TrashSource) -> ActiveTab {
+        match source {
+            TrashSource::Session => ActiveTab::Sessions,
+            TrashSource::Plan => ActiveTab::Plans,
+            TrashSource::Todo => ActiveTab::Todos,
+            TrashSource::Command => ActiveTab::Commands,
+            TrashSource::Team => ActiveTab::Teams,
+            TrashSource::File => ActiveTab::Git,
+        }
+    }
+
+    /// Move the selected item to the trash, after confirmation if it needed
+    /// one.
     pub fn execute_delete(&mut self) {
         self.confirm_delete = false;
         match self.active_tab {
             ActiveTab::Todos => self.delete_selected_todo(),
             ActiveTab::Plans => self.delete_selected_plan(),
+            ActiveTab::Commands => self.delete_selected_command(),
             ActiveTab::Sessions => self.delete_selected_session(),
             ActiveTab::Teams => self.delete_selected_team(),
+            ActiveTab::Git => self.delete_selected_fb_entry(),
             _ => {}
         }
     }
@@ -2144,6 +8336,7 @@ impl App {
     pub fn cancel_delete(&mut self) {
         self.confirm_delete = false;
         self.delete_target_name.clear();
+        self.delete_target_detail.clear();
     }
 
     fn delete_selected_todo(&mut self) {
@@ -2151,10 +8344,9 @@ impl App {
             return;
         }
         let idx = self.todo_file_index.min(self.todo_files.len() - 1);
-        let filename = &self.todo_files[idx].filename;
-        let path = self.claude_home.join("todos").join(filename);
-        if let Err(e) = std::fs::remove_file(&path) {
-            self.last_error = Some(format!("Delete todo: {}", e));
+        let file = &self.todo_files[idx];
+        let path = self.home_path(file.home).join("todos").join(&file.filename);
+        if !self.move_to_trash(file.filename.clone(), TrashSource::Todo, &path) {
             return;
         }
         self.load_todos();
@@ -2169,10 +8361,9 @@ impl App {
             return;
         }
         let idx = self.plan_file_index.min(self.plan_files.len() - 1);
-        let filename = &self.plan_files[idx].filename;
-        let path = self.claude_home.join("plans").join(filename);
-        if let Err(e) = std::fs::remove_file(&path) {
-            self.last_error = Some(format!("Delete plan: {}", e));
+        let filename = self.plan_files[idx].filename.clone();
+        let path = self.claude_home.join("plans").join(&filename);
+        if !self.move_to_trash(filename, TrashSource::Plan, &path) {
             return;
         }
         self.load_plans();
@@ -2180,6 +8371,21 @@ impl App {
             self.plan_file_index = self.plan_files.len().saturating_sub(1);
         }
         self.plan_content_scroll = 0;
+        self.plan_outline_index = 0;
+    }
+
+    fn delete_selected_command(&mut self) {
+        let Some((name, path)) = self
+            .selected_command()
+            .map(|c| (c.display_name(), c.path.clone()))
+        else {
+            return;
+        };
+        if !self.move_to_trash(name, TrashSource::Command, &path) {
+            return;
+        }
+        self.load_commands();
+        self.command_content_scroll = 0;
     }
 
     fn delete_selected_session(&mut self) {
@@ -2188,15 +8394,17 @@ impl App {
         }
         let idx = self.session_list_index.min(self.sessions.len() - 1);
         let session_id = self.sessions[idx].session_id.clone();
-        let project_dir = self
-            .claude_home
-            .join("projects")
-            .join(&self.encoded_project);
-        let path = project_dir.join(format!("{}.jsonl", session_id));
-        if let Err(e) = std::fs::remove_file(&path) {
-            self.last_error = Some(format!("Delete session: {}", e));
+        let project_dir = self.project_dir(self.sessions[idx].home);
+        let name = format!("{}.jsonl", session_id);
+        let path = project_dir.join(&name);
+        if !self.move_to_trash(name, TrashSource::Session, &path) {
             return;
         }
+        let _ = sessions::update_index(
+            &project_dir,
+            &sessions::index_path(&config::assoc_home(), &project_dir),
+            &path,
+        );
         // Clear loaded transcript if it was the deleted session
         if self.loaded_session_id.as_deref() == Some(&session_id) {
             self.loaded_session_id = None;
@@ -2217,10 +8425,12 @@ impl App {
             return;
         }
         let idx = self.team_list_index.min(self.teams.len() - 1);
-        let dir_name = self.teams[idx].dir_name.clone();
-        let team_dir = self.claude_home.join("teams").join(&dir_name);
-        if let Err(e) = std::fs::remove_dir_all(&team_dir) {
-            self.last_error = Some(format!("Delete team: {}", e));
+        let name = self.teams[idx].display_name().to_string();
+        let team_dir = self
+            .home_path(self.teams[idx].home)
+            .join("teams")
+            .join(&self.teams[idx].dir_name);
+        if !self.move_to_trash(name, TrashSource::Team, &team_dir) {
             return;
         }
         self.load_teams();
@@ -2234,15 +8444,60 @@ impl App {
         self.compute_agent_statuses();
     }
 
+    fn delete_selected_fb_entry(&mut self) {
+        let Some(path) = self.selected_fb_entry().map(|e| e.path.clone()) else {
+            return;
+        };
+        let name = self.fb_relative(&path);
+        if !self.move_to_trash(name, TrashSource::File, &path) {
+            return;
+        }
+        if self
+            .fb_content_path
+            .as_ref()
+            .is_some_and(|open| open.starts_with(&path))
+        {
+            self.fb_close_content();
+        }
+        self.fb_expanded.retain(|dir| !dir.starts_with(&path));
+        self.load_file_tree();
+        self.fb_index = self.fb_index.min(self.fb_entries.len().saturating_sub(1));
+    }
+
     // --- GitHub PR helpers ---
 
+    /// Whether the PRs tab has a repo to read from on its provider.
+    pub fn prs_available(&self) -> bool {
+        if self.prs_from_bitbucket {
+            self.bb_repo.is_some()
+        } else {
+            self.has_gh && self.gh_repo.is_some()
+        }
+    }
+
+    /// Login the PR list is grouped by on the active provider.
+    fn pr_user(&self) -> Option<&str> {
+        if self.prs_from_bitbucket {
+            self.bb_user.as_deref()
+        } else {
+            self.gh_user.as_deref()
+        }
+    }
+
     pub fn load_github_prs(&mut self) {
+        if self.prs_from_bitbucket {
+            self.load_bitbucket_prs();
+            return;
+        }
         let (repo, _user) = match (&self.gh_repo, &self.gh_user) {
             (Some(r), Some(u)) => (r.clone(), u.clone()),
+            // Retried once the background login lookup finishes
+            (Some(_), None) if self.gh_user_detecting => return,
             (Some(_), None) => {
                 self.last_error = Some(
                     "GitHub: not authenticated. Run 'gh auth login' to sign in.".to_string(),
                 );
+                self.loaded_tabs.insert(ActiveTab::GitHubPRs);
                 return;
             }
             _ => return,
@@ -2252,28 +8507,103 @@ impl App {
             Some(tx) => tx,
             None => return,
         };
+        // A refresh reloads every page loaded so far
+        let limit = self.gh_pr_pages * self.project_config.github_page_size();
         std::thread::spawn(move || {
-            let result = github::list_open_prs(&repo).map_err(|e| e.to_string());
-            let _ = tx.send(AppEvent::GitHubPrsLoaded(result));
+            let result = github::list_open_prs(&repo, limit).map_err(|e| e.to_string());
+            // Only a full list may have been cut short
+            let total = match result {
+                Ok(ref prs) if prs.len() >= limit => github::count_open_prs(&repo).ok(),
+                Ok(ref prs) => Some(prs.len()),
+                Err(_) => None,
+            };
+            let _ = tx.send(AppEvent::GitHubPrsLoaded(result, total));
         });
     }
 
-    pub fn handle_github_prs_loaded(&mut self, result: Result<Vec<PullRequest>, String>) {
+    /// Whether the repo has open PRs past the ones loaded.
+    pub fn gh_prs_have_more(&self) -> bool {
+        !self.prs_from_bitbucket
+            && github::has_more_pages(
+                self.gh_prs.len(),
+                self.gh_pr_total,
+                self.gh_pr_pages * self.project_config.github_page_size(),
+            )
+    }
+
+    /// Fetch the next page of PRs, if there is one.
+    pub fn load_more_github_prs(&mut self) {
+        if self.gh_prs_loading_more || !self.gh_prs_have_more() {
+            return;
+        }
+        self.gh_prs_loading_more = true;
+        self.gh_pr_pages += 1;
+        self.send_status = Some(("Loading more PRs...".to_string(), Instant::now()));
+        self.load_github_prs();
+    }
+
+    /// PRs from gh, with the open PR count when it was fetched.
+    pub fn handle_github_pr_page(
+        &mut self,
+        result: Result<Vec<PullRequest>, String>,
+        total: Option<usize>,
+    ) {
+        let loading_more = std::mem::take(&mut self.gh_prs_loading_more);
+        match result {
+            Ok(ref prs) => {
+                if loading_more {
+                    let added = prs.len().saturating_sub(self.gh_prs.len());
+                    self.send_status = Some((format!("Loaded {} more PRs", added), Instant::now()));
+                }
+                self.gh_pr_total = total;
+            }
+            Err(_) if loading_more => self.gh_pr_pages -= 1,
+            Err(_) => {}
+        }
+        self.handle_github_prs_loaded(result, loading_more);
+    }
+
+    /// A polled PR list. With `loading_more` it only grew by a page, so PRs
+    /// not seen before are added to the baselines instead of announced.
+    pub fn handle_github_prs_loaded(
+        &mut self,
+        result: Result<Vec<PullRequest>, String>,
+        loading_more: bool,
+    ) {
+        self.loaded_tabs.insert(ActiveTab::GitHubPRs);
         match result {
             Ok(prs) => {
-                // Check for new activity
-                for pr in &prs {
-                    if let Some(prev) = self.gh_prev_updated.get(&pr.number) {
-                        if *prev != pr.updated_at {
-                            self.gh_new_activity = true;
-                        }
-                    } else {
-                        // New PR appeared
-                        if !self.gh_prev_updated.is_empty() {
-                            self.gh_new_activity = true;
-                        }
+                // Skip the first load so existing PRs aren't announced
+                if !loading_more && !self.gh_prev_updated.is_empty() {
+                    let agent_branches: HashSet<&str> = self
+                        .all_sessions
+                        .iter()
+                        .filter_map(|s| s.git_branch.as_deref())
+                        .collect();
+                    let opened: Vec<Notification> = prs
+                        .iter()
+                        .filter(|pr| !self.gh_prev_updated.contains_key(&pr.number))
+                        .filter(|pr| github::is_agent_pr(pr, &agent_branches))
+                        .map(|pr| Notification {
+                            event: NotificationEvent::PrOpened,
+                            title: format!("PR #{} opened: {}", pr.number, pr.title),
+                            detail: pr.url.clone(),
+                        })
+                        .collect();
+                    for notification in opened {
+                        self.notify(notification);
                     }
                 }
+
+                let states = prs.iter().map(ItemState::from_pr).collect();
+                if loading_more {
+                    self.activity_tracker
+                        .extend(ActivitySource::GitHubPr, states);
+                } else {
+                    let me = self.pr_user().map(String::from);
+                    self.record_activity(ActivitySource::GitHubPr, states, me);
+                }
+
                 // Update prev timestamps
                 self.gh_prev_updated.clear();
                 for pr in &prs {
@@ -2281,21 +8611,98 @@ impl App {
                         .insert(pr.number, pr.updated_at.clone());
                 }
 
-                let user = self.gh_user.as_deref().unwrap_or("");
-                self.gh_flat_list = github::categorize_prs(&prs, user);
                 self.gh_prs = prs;
-                if self.gh_pr_index >= self.gh_flat_list.len() {
-                    self.gh_pr_index = 0;
-                    self.gh_skip_to_pr_entry();
-                }
+                self.rebuild_pr_list();
+                self.gh_load_review_threads();
+                self.poll_succeeded(ActiveTab::GitHubPRs);
             }
             Err(e) => {
-                self.last_error = Some(format!("GitHub: {}", e));
+                let provider = if self.prs_from_bitbucket {
+                    "Bitbucket"
+                } else {
+                    "GitHub"
+                };
+                if self.poll_failed(ActiveTab::GitHubPRs, &e) {
+                    self.last_error = Some(format!("{}: {}", provider, e));
+                }
             }
         }
     }
 
+    fn load_bitbucket_prs(&mut self) {
+        let Some(repo) = self.bb_repo.clone() else {
+            return;
+        };
+        // Replayed fixtures need no credentials
+        let credentials = self
+            .project_config
+            .bitbucket_credentials()
+            .or_else(|| cli_fixtures::replaying().then_some(("", "")));
+        let Some((username, app_password)) = credentials else {
+            self.last_error = Some(
+                "Bitbucket: set bitbucket.username and bitbucket.app_password in .assoc.toml"
+                    .to_string(),
+            );
+            self.loaded_tabs.insert(ActiveTab::GitHubPRs);
+            return;
+        };
+        let (username, app_password) = (username.to_string(), app_password.to_string());
+        self.gh_last_poll = Instant::now();
+        let tx = match self.event_tx.clone() {
+            Some(tx) => tx,
+            None => return,
+        };
+        std::thread::spawn(move || {
+            let result = bitbucket::list_open_prs(&repo, &username, &app_password)
+                .map_err(|e| e.to_string());
+            let _ = tx.send(AppEvent::BitbucketPrsLoaded(result));
+        });
+    }
+
+    pub fn handle_bitbucket_prs_loaded(
+        &mut self,
+        result: Result<(String, Vec<PullRequest>), String>,
+    ) {
+        let result = result.map(|(user, prs)| {
+            self.bb_user = Some(user);
+            prs
+        });
+        self.handle_github_prs_loaded(result, false);
+    }
+
+    fn rebuild_pr_list(&mut self) {
+        let user = self.pr_user().unwrap_or("").to_string();
+        let prs = self.filtered(ActiveTab::GitHubPRs, &self.gh_prs, Some(&user));
+        self.gh_flat_list = if self.prs_from_bitbucket {
+            bitbucket::categorize_prs(&prs, &user)
+        } else {
+            github::categorize_prs(&prs, &user)
+        };
+        self.gh_pr_table.sort_sections(
+            &mut self.gh_flat_list,
+            |item| matches!(item, FlatPrItem::SectionHeader(_)),
+            |a, b, column| match (a, b) {
+                (FlatPrItem::Pr(a), FlatPrItem::Pr(b)) => list_table::compare_prs(a, b, column),
+                _ => Ordering::Equal,
+            },
+        );
+        if self.gh_pr_index >= self.gh_flat_list.len() {
+            self.gh_pr_index = 0;
+            self.gh_skip_to_pr_entry();
+        }
+        self.index_for_search(SearchSource::Prs);
+    }
+
+    /// Move to the next PR; past the last, load the next page.
     fn gh_skip_to_next_pr(&mut self) {
+        let at_end = !self
+            .gh_flat_list
+            .iter()
+            .skip(self.gh_pr_index + 1)
+            .any(|item| matches!(item, FlatPrItem::Pr(_)));
+        if at_end {
+            self.load_more_github_prs();
+        }
         if self.gh_flat_list.is_empty() {
             return;
         }
@@ -2304,6 +8711,8 @@ impl App {
             if matches!(self.gh_flat_list[i], FlatPrItem::Pr(_)) {
                 self.gh_pr_index = i;
                 self.gh_detail_scroll = 0;
+                self.gh_check_index = 0;
+                self.gh_load_review_threads();
                 return;
             }
         }
@@ -2317,6 +8726,8 @@ impl App {
             if matches!(self.gh_flat_list[i], FlatPrItem::Pr(_)) {
                 self.gh_pr_index = i;
                 self.gh_detail_scroll = 0;
+                self.gh_check_index = 0;
+                self.gh_load_review_threads();
                 return;
             }
         }
@@ -2337,6 +8748,60 @@ impl App {
         }
     }
 
+    /// `s` sorts the PR or issue table by the next column, `S` reverses
+    /// the order. The selection stays on the same item.
+    pub fn table_sort(&mut self, reverse: bool) {
+        let table = match self.active_tab {
+            ActiveTab::GitHubPRs => &mut self.gh_pr_table,
+            ActiveTab::GitHubIssues => &mut self.gh_issues_table,
+            _ => return,
+        };
+        if reverse {
+            table.descending = !table.descending;
+        } else {
+            table.cycle_sort();
+        }
+        let label = table.sort_label();
+
+        if self.active_tab == ActiveTab::GitHubPRs {
+            let selected = self.gh_selected_pr().map(|pr| pr.number);
+            self.rebuild_pr_list();
+            if let Some(i) = self
+                .gh_flat_list
+                .iter()
+                .position(|item| matches!(item, FlatPrItem::Pr(pr) if Some(pr.number) == selected))
+            {
+                self.gh_pr_index = i;
+            }
+        } else {
+            let selected = self.issues_selected().map(|issue| issue.number);
+            self.rebuild_issue_list();
+            if let Some(i) = self.gh_issues_flat_list.iter().position(|item| {
+                matches!(item, FlatIssueItem::Issue(issue) if Some(issue.number) == selected)
+            }) {
+                self.gh_issues_index = i;
+            }
+        }
+        let tab = if self.active_tab == ActiveTab::GitHubPRs {
+            "PRs"
+        } else {
+            "Issues"
+        };
+        self.send_status = Some((format!("{}: {}", tab, label), Instant::now()));
+    }
+
+    /// `<` / `>` scroll the PR or issue table's columns on narrow terminals.
+    pub fn table_scroll(&mut self, step: isize) {
+        let width = self.list_table_width;
+        let table = match self.active_tab {
+            ActiveTab::GitHubPRs => &mut self.gh_pr_table,
+            ActiveTab::GitHubIssues => &mut self.gh_issues_table,
+            _ => return,
+        };
+        table.scroll = table.first_column(width).saturating_add_signed(step);
+        table.scroll = table.first_column(width);
+    }
+
     pub fn gh_selected_pr(&self) -> Option<&PullRequest> {
         if self.gh_flat_list.is_empty() {
             return None;
@@ -2348,12 +8813,343 @@ impl App {
         }
     }
 
+    /// Open the selected PR in the browser. From the detail pane, if the PR
+    /// has failing checks, open the selected check's log instead.
     pub fn gh_open_selected(&self) {
         if let Some(pr) = self.gh_selected_pr() {
+            if self.gh_pane == GitHubPane::Detail {
+                let failing = pr.failing_checks();
+                if let Some(check) =
+                    failing.get(self.gh_check_index.min(failing.len().saturating_sub(1)))
+                {
+                    if let Some(url) = check.url() {
+                        cli_detect::open_url(url);
+                        return;
+                    }
+                }
+            }
             cli_detect::open_url(&pr.url);
         }
     }
 
+    /// Move the failing-check selection in the PR detail pane, wrapping around.
+    pub fn gh_cycle_check(&mut self) {
+        let count = match self.gh_selected_pr() {
+            Some(pr) => pr.failing_checks().len(),
+            None => return,
+        };
+        if count == 0 {
+            return;
+        }
+        self.gh_check_index = (self.gh_check_index + 1) % count;
+    }
+
+    /// Fetch the selected PR's review threads in the background, unless they
+    /// were fetched since the PR last changed. GitHub only.
+    fn gh_load_review_threads(&mut self) {
+        if self.prs_from_bitbucket {
+            return;
+        }
+        let Some(repo) = self.gh_repo.clone() else {
+            return;
+        };
+        let Some(pr) = self.gh_selected_pr() else {
+            return;
+        };
+        let (number, updated_at) = (pr.number, pr.updated_at.clone());
+        let fresh = self
+            .gh_review_threads
+            .get(&number)
+            .is_some_and(|(fetched_at, _)| *fetched_at == updated_at);
+        if fresh || self.gh_review_threads_loading.contains(&number) {
+            return;
+        }
+        let Some(tx) = self.event_tx.clone() else {
+            return;
+        };
+        self.gh_review_threads_loading.insert(number);
+        std::thread::spawn(move || {
+            let result = github::list_review_threads(&repo, number).map_err(|e| e.to_string());
+            let _ = tx.send(AppEvent::ReviewThreadsLoaded(number, updated_at, result));
+        });
+    }
+
+    pub fn handle_review_threads_loaded(
+        &mut self,
+        number: u64,
+        updated_at: String,
+        result: Result<Vec<ReviewThread>, String>,
+    ) {
+        self.gh_review_threads_loading.remove(&number);
+        match result {
+            Ok(threads) => {
+                self.gh_review_threads.insert(number, (updated_at, threads));
+            }
+            Err(e) => self.last_error = Some(format!("Review threads: {}", e)),
+        }
+    }
+
+    /// Review threads of the selected PR, once fetched.
+    pub fn gh_selected_review_threads(&self) -> Option<&[ReviewThread]> {
+        let pr = self.gh_selected_pr()?;
+        self.gh_review_threads
+            .get(&pr.number)
+            .map(|(_, threads)| threads.as_slice())
+    }
+
+    /// Expand or collapse resolved review threads in the PR detail pane.
+    pub fn gh_toggle_resolved_threads(&mut self) {
+        self.gh_show_resolved_threads = !self.gh_show_resolved_threads;
+    }
+
+    // --- Empty-state actions ---
+
+    /// Whether the list on the active tab has nothing in it, so the
+    /// empty-state shortcuts (auth check, repo settings, setup) apply.
+    pub fn active_list_is_empty(&self) -> bool {
+        match self.active_tab {
+            ActiveTab::Teams => self.teams.is_empty(),
+            ActiveTab::GitHubPRs => self.gh_flat_list.is_empty(),
+            ActiveTab::GitHubIssues => self.gh_issues_flat_list.is_empty(),
+            ActiveTab::Jira => self.jira_flat_list.is_empty(),
+            ActiveTab::Linear => self.linear_flat_list.is_empty(),
+            ActiveTab::Azure => self.azure_flat_list.is_empty(),
+            _ => false,
+        }
+    }
+
+    /// CLI whose auth status is relevant to the active tab.
+    pub fn active_tab_cli(&self) -> Option<&'static str> {
+        match self.active_tab {
+            ActiveTab::GitHubPRs if self.prs_from_bitbucket => None,
+            ActiveTab::GitHubPRs | ActiveTab::GitHubIssues => Some("gh"),
+            ActiveTab::Jira => Some("acli"),
+            ActiveTab::Azure => Some(azure::AZ),
+            _ => None,
+        }
+    }
+
+    /// Run the active tab's CLI auth status command in the background.
+    pub fn check_auth_status(&mut self) {
+        let Some(cli) = self.active_tab_cli() else {
+            return;
+        };
+        let tx = match self.event_tx.clone() {
+            Some(tx) => tx,
+            None => return,
+        };
+        self.auth_status = Some((cli.to_string(), false, "Checking...".to_string()));
+        std::thread::spawn(move || {
+            let result = cli_detect::auth_status(cli).map_err(|e| e.to_string());
+            let _ = tx.send(AppEvent::AuthStatusLoaded(cli.to_string(), result));
+        });
+    }
+
+    pub fn handle_auth_status_loaded(
+        &mut self,
+        cli: String,
+        result: Result<(bool, String), String>,
+    ) {
+        self.auth_status = Some(match result {
+            Ok((ok, output)) => (cli, ok, output),
+            Err(e) => (cli.clone(), false, format!("Failed to run {}: {}", cli, e)),
+        });
+    }
+
+    /// Open the GitHub repo's settings page (where issues can be enabled).
+    pub fn open_repo_settings(&self) {
+        let repo = match self.active_tab {
+            ActiveTab::GitHubPRs if !self.prs_from_bitbucket => self.gh_repo.as_deref(),
+            ActiveTab::GitHubIssues => self.gh_issues_repo.as_deref(),
+            _ => None,
+        };
+        if let Some(repo) = repo {
+            cli_detect::open_url(&format!("https://github.com/{}/settings", repo));
+        }
+    }
+
+    // --- Remote picker (PRs / Issues tabs) ---
+
+    pub fn open_remote_picker(&mut self) {
+        if self.active_tab == ActiveTab::GitHubPRs && self.prs_from_bitbucket {
+            self.last_error = Some("Remotes: the PRs tab reads from Bitbucket".to_string());
+            return;
+        }
+        if self.project_config.github_repo().is_some() {
+            self.last_error = Some("Remotes: github.repo in .assoc.toml sets the repo".to_string());
+            return;
+        }
+        if self.gh_remotes.is_empty() {
+            self.last_error = Some("Remotes: no GitHub remotes found".to_string());
+            return;
+        }
+        self.remote_picker_index = self
+            .gh_remotes
+            .iter()
+            .position(|r| Some(&r.repo) == self.gh_repo.as_ref())
+            .unwrap_or(0);
+        self.show_remote_picker = true;
+    }
+
+    /// Name of the remote `repo` comes from, when there are several to pick.
+    pub fn remote_label(&self, repo: Option<&String>) -> Option<&str> {
+        if self.gh_remotes.len() < 2 {
+            return None;
+        }
+        self.gh_remotes
+            .iter()
+            .find(|r| Some(&r.repo) == repo)
+            .map(|r| r.name.as_str())
+    }
+
+    pub fn close_remote_picker(&mut self) {
+        self.show_remote_picker = false;
+    }
+
+    pub fn remote_picker_move(&mut self, down: bool) {
+        if down {
+            if self.remote_picker_index + 1 < self.gh_remotes.len() {
+                self.remote_picker_index += 1;
+            }
+        } else {
+            self.remote_picker_index = self.remote_picker_index.saturating_sub(1);
+        }
+    }
+
+    /// Point the GitHub tabs at the highlighted remote and remember it for
+    /// this project.
+    pub fn remote_picker_select(&mut self) {
+        self.show_remote_picker = false;
+        let Some(remote) = self.gh_remotes.get(self.remote_picker_index).cloned() else {
+            return;
+        };
+        if let Err(e) = remote_choice::save_choice(&self.remote_choice_path, &remote.name) {
+            self.last_error = Some(format!("Remotes: {}", e));
+        }
+        self.gh_remote = Some(remote.name.clone());
+        if self.gh_repo.as_ref() == Some(&remote.repo) {
+            return;
+        }
+        self.gh_repo = Some(remote.repo.clone());
+        // Issues follow the remote unless issues.repo pins them
+        if self.project_config.github_issues_repo().is_none() {
+            self.gh_issues_repo = Some(remote.repo.clone());
+            self.gh_issues_enabled = self.has_gh && self.project_config.github_issues_enabled();
+        }
+
+        // Start both tabs over so the old repo's items aren't shown or
+        // reported as changes
+        self.gh_prs.clear();
+        self.gh_prev_updated.clear();
+        self.gh_pr_index = 0;
+        self.gh_detail_scroll = 0;
+        self.rebuild_pr_list();
+        self.gh_issues.clear();
+        self.gh_issues_index = 0;
+        self.gh_issues_detail_scroll = 0;
+        self.rebuild_issue_list();
+        self.activity_tracker.forget(ActivitySource::GitHubPr);
+        self.activity_tracker.forget(ActivitySource::GitHubIssue);
+        self.loaded_tabs.remove(&ActiveTab::GitHubPRs);
+        self.loaded_tabs.remove(&ActiveTab::GitHubIssues);
+        self.load_tab(&ActiveTab::GitHubPRs);
+        self.load_tab(&ActiveTab::GitHubIssues);
+        self.send_status = Some((
+            format!("GitHub tabs now use {} ({})", remote.name, remote.repo),
+            Instant::now(),
+        ));
+    }
+
+    pub fn has_config_file(&self) -> bool {
+        self.project_cwd.join(".assoc.toml").exists()
+    }
+
+    /// Re-read `.assoc.toml` and apply it without restarting: integration
+    /// tabs, Claude homes and tab visibility are recomputed and every tab is
+    /// reloaded, while selections and scroll positions are kept. A config
+    /// that no longer parses is reported and the current one stays active.
+    pub fn reload_config(&mut self) -> bool {
+        let project_config = match config::try_load_project_config(&self.project_cwd) {
+            Ok(project_config) => project_config,
+            Err(e) => {
+                self.last_error = Some(format!("Config: {}", e));
+                return false;
+            }
+        };
+
+        let integrations = detect_integrations(
+            &project_config,
+            &self.project_cwd,
+            self.gh_user.take(),
+            self.gh_remote.as_deref(),
+        );
+        self.has_gh = integrations.has_gh;
+        self.has_jira = integrations.has_jira;
+        self.has_linear = integrations.has_linear;
+        self.has_azure = integrations.has_azure;
+        self.gh_remotes = integrations.gh_remotes;
+        self.gh_repo = integrations.gh_repo;
+        self.gh_user = integrations.gh_user;
+        self.gh_issues_repo = integrations.gh_issues_repo;
+        self.gh_issues_enabled = integrations.gh_issues_enabled;
+        self.prs_from_bitbucket = integrations.prs_from_bitbucket;
+        self.bb_repo = integrations.bb_repo;
+        self.detect_gh_user();
+
+        let claude_homes = project_config.claude_homes();
+        if claude_homes != self.claude_homes {
+            self.claude_home = claude_homes[0].path.clone();
+            self.claude_homes = claude_homes;
+            // Home indices may have shifted; reload the transcript from its new home
+            self.loaded_session_id = None;
+        }
+        self.low_power_setting = project_config.low_power_setting();
+        self.status_bar = project_config.status_bar_layout();
+        self.send_snippets = project_config.send_snippets();
+        self.terminal = project_config.terminal_backend();
+        let linear_query = project_config.linear_query();
+        if linear_query != self.linear_query {
+            self.linear_query = linear_query;
+            self.linear_pages = 1;
+            self.linear_next_cursor = None;
+        }
+        // A filter dropped from [jira.filters] falls back to the default
+        if let Some(name) = self.jira_filter.as_deref() {
+            if project_config.jira_filter_jql(Some(name)).is_none() {
+                self.jira_filter = None;
+            }
+        }
+        self.gh_pr_table.columns = project_config.pr_columns();
+        self.gh_issues_table.columns = project_config.issue_columns();
+        self.project_config = project_config;
+
+        let tabs = self.visible_tabs();
+        if !tabs.contains(&self.active_tab) {
+            if let Some(first) = tabs.first() {
+                self.on_tab_switch(first);
+                self.active_tab = first.clone();
+            }
+        }
+
+        self.load_all();
+        self.last_error = None;
+        self.send_status = Some(("Config reloaded".to_string(), Instant::now()));
+        true
+    }
+
+    /// Write a commented-out `.assoc.toml` to the project root to get started.
+    pub fn write_starter_config(&mut self) {
+        if self.has_config_file() {
+            return;
+        }
+        match crate::config::write_starter_config(&self.project_cwd) {
+            Ok(_) => {
+                self.send_status = Some(("Wrote .assoc.toml".to_string(), Instant::now()));
+            }
+            Err(e) => self.last_error = Some(format!("Config: {}", e)),
+        }
+    }
+
     // --- GitHub Issues helpers ---
 
     pub fn load_github_issues(&mut self) {
@@ -2362,10 +9158,13 @@ impl App {
         }
         let (repo, _user) = match (&self.gh_issues_repo, &self.gh_user) {
             (Some(r), Some(u)) => (r.clone(), u.clone()),
+            // Retried once the background login lookup finishes
+            (Some(_), None) if self.gh_user_detecting => return,
             (Some(_), None) => {
                 self.last_error = Some(
                     "GitHub: not authenticated. Run 'gh auth login' to sign in.".to_string(),
                 );
+                self.loaded_tabs.insert(ActiveTab::GitHubIssues);
                 return;
             }
             _ => return,
@@ -2376,31 +9175,106 @@ impl App {
             None => return,
         };
         let state = self.project_config.github_issues_state().to_string();
+        let limit = self.gh_issues_pages * self.project_config.github_page_size();
         std::thread::spawn(move || {
-            let result = github::list_issues(&repo, &state).map_err(|e| e.to_string());
-            let _ = tx.send(AppEvent::GitHubIssuesLoaded(result));
+            let result = github::list_issues(&repo, &state, limit).map_err(|e| e.to_string());
+            let total = match result {
+                Ok(ref issues) if issues.len() >= limit => github::count_issues(&repo, &state).ok(),
+                Ok(ref issues) => Some(issues.len()),
+                Err(_) => None,
+            };
+            let _ = tx.send(AppEvent::GitHubIssuesLoaded(result, total));
         });
     }
 
-    pub fn handle_github_issues_loaded(&mut self, result: Result<Vec<GitHubIssue>, String>) {
+    /// Whether the repo has matching issues past the ones loaded.
+    pub fn gh_issues_have_more(&self) -> bool {
+        github::has_more_pages(
+            self.gh_issues.len(),
+            self.gh_issues_total,
+            self.gh_issues_pages * self.project_config.github_page_size(),
+        )
+    }
+
+    /// Fetch the next page of issues, if there is one.
+    pub fn load_more_github_issues(&mut self) {
+        if self.gh_issues_loading_more || !self.gh_issues_have_more() {
+            return;
+        }
+        self.gh_issues_loading_more = true;
+        self.gh_issues_pages += 1;
+        self.send_status = Some(("Loading more issues...".to_string(), Instant::now()));
+        self.load_github_issues();
+    }
+
+    pub fn handle_github_issues_loaded(
+        &mut self,
+        result: Result<Vec<GitHubIssue>, String>,
+        total: Option<usize>,
+    ) {
+        self.loaded_tabs.insert(ActiveTab::GitHubIssues);
+        let loading_more = std::mem::take(&mut self.gh_issues_loading_more);
         match result {
             Ok(issues) => {
-                let user = self.gh_user.as_deref().unwrap_or("");
-                let mut flat = github::categorize_issues(&issues, user);
-                self.pin_current_github_issue(&mut flat);
-                self.gh_issues_flat_list = flat;
-                self.gh_issues = issues;
-                if self.gh_issues_index >= self.gh_issues_flat_list.len() {
-                    self.gh_issues_index = 0;
-                    self.issues_skip_to_entry();
+                if loading_more {
+                    let added = issues.len().saturating_sub(self.gh_issues.len());
+                    self.send_status =
+                        Some((format!("Loaded {} more issues", added), Instant::now()));
+                }
+                self.gh_issues_total = total;
+                let open_ids = issues
+                    .iter()
+                    .filter(|i| i.state.eq_ignore_ascii_case("open"))
+                    .map(|i| i.number.to_string())
+                    .collect();
+                self.record_snapshot(snapshot::TRACKER_GITHUB_ISSUES, open_ids);
+                let states = issues.iter().map(ItemState::from_github_issue).collect();
+                // A further page adds to the baseline rather than announcing
+                // the issues it brought in
+                if loading_more {
+                    self.activity_tracker
+                        .extend(ActivitySource::GitHubIssue, states);
+                } else {
+                    self.record_activity(ActivitySource::GitHubIssue, states, self.gh_user.clone());
                 }
+                self.gh_issues = issues;
+                self.rebuild_issue_list();
+                self.poll_succeeded(ActiveTab::GitHubIssues);
             }
             Err(e) => {
-                self.last_error = Some(format!("Issues: {}", e));
+                if loading_more {
+                    self.gh_issues_pages -= 1;
+                }
+                if self.poll_failed(ActiveTab::GitHubIssues, &e) {
+                    self.last_error = Some(format!("Issues: {}", e));
+                }
             }
         }
     }
 
+    fn rebuild_issue_list(&mut self) {
+        let user = self.gh_user.as_deref().unwrap_or("");
+        let issues = self.filtered(ActiveTab::GitHubIssues, &self.gh_issues, Some(user));
+        let mut flat = github::categorize_issues(&issues, user);
+        self.gh_issues_table.sort_sections(
+            &mut flat,
+            |item| matches!(item, FlatIssueItem::SectionHeader(_)),
+            |a, b, column| match (a, b) {
+                (FlatIssueItem::Issue(a), FlatIssueItem::Issue(b)) => {
+                    list_table::compare_issues(a, b, column)
+                }
+                _ => Ordering::Equal,
+            },
+        );
+        self.pin_current_github_issue(&mut flat);
+        self.gh_issues_flat_list = flat;
+        if self.gh_issues_index >= self.gh_issues_flat_list.len() {
+            self.gh_issues_index = 0;
+            self.issues_skip_to_entry();
+        }
+        self.index_for_search(SearchSource::Issues);
+    }
+
     /// If any GitHub issue matches current_issue_ids, move it to the top
     /// under a "Current Issue" header.
     fn pin_current_github_issue(&self, flat: &mut Vec<FlatIssueItem>) {
@@ -2421,7 +9295,16 @@ impl App {
         }
     }
 
+    /// Move to the next issue; past the last, load the next page.
     fn issues_skip_to_next(&mut self) {
+        let at_end = !self
+            .gh_issues_flat_list
+            .iter()
+            .skip(self.gh_issues_index + 1)
+            .any(|item| matches!(item, FlatIssueItem::Issue(_)));
+        if at_end {
+            self.load_more_github_issues();
+        }
         if self.gh_issues_flat_list.is_empty() {
             return;
         }
@@ -2481,11 +9364,50 @@ impl App {
         }
     }
 
+    /// Offer the repo's issue templates, or open a blank editor when it
+    /// has none.
     pub fn issues_start_create(&mut self) {
+        self.gh_issue_templates = issue_templates::load_templates(&self.project_cwd);
+        if self.gh_issue_templates.is_empty() {
+            self.issues_open_create(None);
+        } else {
+            self.gh_issues_template_picker = Some(0);
+        }
+    }
+
+    pub fn issues_template_picker_move(&mut self, down: bool) {
+        let Some(index) = self.gh_issues_template_picker.as_mut() else {
+            return;
+        };
+        // One row per template, then the blank issue
+        if down {
+            *index = (*index + 1).min(self.gh_issue_templates.len());
+        } else {
+            *index = index.saturating_sub(1);
+        }
+    }
+
+    pub fn issues_template_picker_select(&mut self) {
+        let Some(index) = self.gh_issues_template_picker.take() else {
+            return;
+        };
+        let template = self.gh_issue_templates.get(index).cloned();
+        self.issues_open_create(template.as_ref());
+    }
+
+    /// Open the new-issue editor, filled in from `template`.
+    fn issues_open_create(&mut self, template: Option<&IssueTemplate>) {
         let mut title_ed = tui_textarea::TextArea::default();
         title_ed.set_cursor_line_style(ratatui::style::Style::default());
         let mut body_ed = tui_textarea::TextArea::default();
         body_ed.set_cursor_line_style(ratatui::style::Style::default());
+        if let Some(template) = template {
+            title_ed.insert_str(&template.title);
+            body_ed.insert_str(&template.body);
+            body_ed.move_cursor(tui_textarea::CursorMove::Top);
+            body_ed.move_cursor(tui_textarea::CursorMove::Head);
+        }
+        self.gh_issues_create_labels = template.map(|t| t.labels.clone()).unwrap_or_default();
         self.gh_issues_title_editor = Some(title_ed);
         self.gh_issues_body_editor = Some(body_ed);
         self.gh_issues_edit_mode = Some(IssueEditMode::Create);
@@ -2513,6 +9435,7 @@ impl App {
             self.gh_issues_edit_mode = Some(IssueEditMode::Edit(issue.number));
             self.gh_issues_edit_field = IssueEditField::Title;
             self.gh_issues_editing = true;
+            self.gh_issues_edit_base = Some(issue);
         }
     }
 
@@ -2554,13 +9477,17 @@ impl App {
                     self.last_error = Some("Title cannot be empty".to_string());
                     return;
                 }
-                github::create_issue(&repo, &title, &body)
+                github::create_issue(&repo, &title, &body, &self.gh_issues_create_labels)
+                    .map(|_| ())
             }
             IssueEditMode::Edit(number) => {
                 if title.trim().is_empty() {
                     self.last_error = Some("Title cannot be empty".to_string());
                     return;
                 }
+                if self.issues_find_conflict(&repo, *number, &title, &body) {
+                    return;
+                }
                 github::edit_issue(&repo, *number, &title, &body)
             }
             IssueEditMode::Comment(number) => {
@@ -2572,22 +9499,154 @@ impl App {
             }
         };
 
-        match result {
+        match result {
+            Ok(()) => {
+                self.issues_cancel_edit();
+                self.load_github_issues();
+            }
+            Err(e) => {
+                self.last_error = Some(format!("Issue save: {}", e));
+            }
+        }
+    }
+
+    pub fn issues_cancel_edit(&mut self) {
+        self.gh_issues_editing = false;
+        self.gh_issues_edit_mode = None;
+        self.gh_issues_title_editor = None;
+        self.gh_issues_body_editor = None;
+        self.gh_issues_create_labels.clear();
+        self.gh_issues_edit_base = None;
+        self.gh_issues_conflict = None;
+    }
+
+    /// Check whether the issue's title or body changed on GitHub since
+    /// editing started. Returns true when the save has to wait: either the
+    /// conflict dialog is open, or the issue couldn't be fetched.
+    fn issues_find_conflict(&mut self, repo: &str, number: u64, title: &str, body: &str) -> bool {
+        let Some(base) = self.gh_issues_edit_base.clone() else {
+            return false;
+        };
+        let upstream = match github::view_issue(repo, number) {
+            Ok(issue) => issue,
+            Err(e) => {
+                self.last_error = Some(format!("Issue save: {}", e));
+                return true;
+            }
+        };
+        if upstream.updated_at == base.updated_at {
+            return false;
+        }
+        let base_body = issue_body(&base);
+        let upstream_body = issue_body(&upstream);
+        let upstream_edited = upstream.title != base.title || upstream_body != base_body;
+        let same_as_mine = upstream.title == title && upstream_body == body;
+        if !upstream_edited || same_as_mine {
+            return false;
+        }
+        self.gh_issues_conflict = Some(IssueConflict {
+            number,
+            chunks: merge::three_way_merge(&base_body, body, &upstream_body),
+            title_conflict: title != base.title && upstream.title != base.title,
+            upstream,
+            title: title.to_string(),
+            body: body.to_string(),
+            scroll: 0,
+        });
+        true
+    }
+
+    /// Save my version over the upstream changes.
+    pub fn issues_conflict_overwrite(&mut self) {
+        let (Some(conflict), Some(repo)) = (&self.gh_issues_conflict, &self.gh_issues_repo) else {
+            return;
+        };
+        let number = conflict.number;
+        match github::edit_issue(repo, number, &conflict.title, &conflict.body) {
+            Ok(()) => {
+                self.issues_cancel_edit();
+                self.load_github_issues();
+                self.send_status = Some((format!("Overwrote issue #{}", number), Instant::now()));
+            }
+            Err(e) => self.last_error = Some(format!("Issue save: {}", e)),
+        }
+    }
+
+    /// Back to the editor with the upstream changes merged in. Lines both
+    /// sides changed are left between conflict markers to sort out by hand.
+    pub fn issues_conflict_merge(&mut self) {
+        let Some(conflict) = self.gh_issues_conflict.take() else {
+            return;
+        };
+        let base_title = self
+            .gh_issues_edit_base
+            .as_ref()
+            .map(|b| b.title.clone())
+            .unwrap_or_default();
+        let title = if conflict.title == base_title {
+            &conflict.upstream.title
+        } else {
+            &conflict.title
+        };
+        let conflicts = conflict.chunks.iter().filter(|c| c.is_conflict()).count();
+
+        let mut title_ed = tui_textarea::TextArea::default();
+        title_ed.set_cursor_line_style(ratatui::style::Style::default());
+        title_ed.insert_str(title);
+        title_ed.move_cursor(tui_textarea::CursorMove::Head);
+        let mut body_ed = tui_textarea::TextArea::default();
+        body_ed.set_cursor_line_style(ratatui::style::Style::default());
+        body_ed.insert_str(merge_model::marked_merge(&conflict.chunks));
+        body_ed.move_cursor(tui_textarea::CursorMove::Top);
+        body_ed.move_cursor(tui_textarea::CursorMove::Head);
+        self.gh_issues_title_editor = Some(title_ed);
+        self.gh_issues_body_editor = Some(body_ed);
+        self.gh_issues_edit_field = IssueEditField::Body;
+        self.gh_issues_edit_base = Some(conflict.upstream);
+
+        let msg = match conflicts {
+            0 => "Merged upstream changes; review and save".to_string(),
+            n => format!("Merged upstream changes; resolve {} marked conflict(s)", n),
+        };
+        self.send_status = Some((msg, Instant::now()));
+    }
+
+    /// Leave the issue as it is upstream and post my version as a comment.
+    pub fn issues_conflict_comment(&mut self) {
+        let (Some(conflict), Some(repo)) = (&self.gh_issues_conflict, &self.gh_issues_repo) else {
+            return;
+        };
+        let number = conflict.number;
+        let base_title = self.gh_issues_edit_base.as_ref().map(|b| b.title.as_str());
+        let comment = if base_title == Some(conflict.title.as_str()) {
+            conflict.body.clone()
+        } else {
+            format!("**Title:** {}\n\n{}", conflict.title, conflict.body)
+        };
+        match github::comment_issue(repo, number, &comment) {
             Ok(()) => {
                 self.issues_cancel_edit();
                 self.load_github_issues();
+                let msg = format!("Posted my version as a comment on #{}", number);
+                self.send_status = Some((msg, Instant::now()));
             }
-            Err(e) => {
-                self.last_error = Some(format!("Issue save: {}", e));
-            }
+            Err(e) => self.last_error = Some(format!("Issue comment: {}", e)),
         }
     }
 
-    pub fn issues_cancel_edit(&mut self) {
-        self.gh_issues_editing = false;
-        self.gh_issues_edit_mode = None;
-        self.gh_issues_title_editor = None;
-        self.gh_issues_body_editor = None;
+    /// Close the conflict dialog and keep editing.
+    pub fn issues_conflict_cancel(&mut self) {
+        self.gh_issues_conflict = None;
+    }
+
+    pub fn issues_conflict_scroll(&mut self, down: bool) {
+        if let Some(conflict) = self.gh_issues_conflict.as_mut() {
+            conflict.scroll = if down {
+                conflict.scroll.saturating_add(1)
+            } else {
+                conflict.scroll.saturating_sub(1)
+            };
+        }
     }
 
     pub fn issues_toggle_state(&mut self) {
@@ -2608,6 +9667,178 @@ impl App {
         }
     }
 
+    /// Open the label / assignee / milestone picker for the selected issue
+    /// and load its options in the background.
+    pub fn issues_open_picker(&mut self, field: IssueMetaField) {
+        let Some(issue) = self.issues_selected() else {
+            return;
+        };
+        let Some(repo) = self.gh_issues_repo.clone() else {
+            return;
+        };
+        let tx = match self.event_tx.clone() {
+            Some(tx) => tx,
+            None => return,
+        };
+        self.gh_issues_picker = Some(IssueMetaPicker {
+            field,
+            number: issue.number,
+            options: None,
+            checked: issue.meta_values(field),
+            index: 0,
+        });
+        std::thread::spawn(move || {
+            let result = github::list_meta_options(&repo, field).map_err(|e| e.to_string());
+            let _ = tx.send(AppEvent::IssueMetaOptionsLoaded(field, result));
+        });
+    }
+
+    pub fn handle_issue_meta_options_loaded(
+        &mut self,
+        field: IssueMetaField,
+        result: Result<Vec<String>, String>,
+    ) {
+        let Some(picker) = self.gh_issues_picker.as_mut().filter(|p| p.field == field) else {
+            return;
+        };
+        match result {
+            Ok(mut options) => {
+                // Keep current values that the listing left out, e.g. a
+                // closed milestone or a user who lost access
+                for value in &picker.checked {
+                    if !options.contains(value) {
+                        options.push(value.clone());
+                    }
+                }
+                if options.is_empty() {
+                    self.gh_issues_picker = None;
+                    self.last_error =
+                        Some(format!("No {} in this repo", field.title().to_lowercase()));
+                    return;
+                }
+                picker.index = options
+                    .iter()
+                    .position(|o| picker.checked.contains(o))
+                    .unwrap_or(0);
+                picker.options = Some(options);
+            }
+            Err(e) => {
+                self.gh_issues_picker = None;
+                self.last_error = Some(format!("{}: {}", field.title(), e));
+            }
+        }
+    }
+
+    pub fn issues_picker_move(&mut self, down: bool) {
+        let Some(picker) = self.gh_issues_picker.as_mut() else {
+            return;
+        };
+        let len = picker.options.as_ref().map_or(0, |o| o.len());
+        if down {
+            picker.index = (picker.index + 1).min(len.saturating_sub(1));
+        } else {
+            picker.index = picker.index.saturating_sub(1);
+        }
+    }
+
+    /// Check or uncheck the highlighted option. The milestone holds one
+    /// value, so checking it replaces the previous one.
+    pub fn issues_picker_toggle(&mut self) {
+        let Some(picker) = self.gh_issues_picker.as_mut() else {
+            return;
+        };
+        let Some(option) = picker
+            .options
+            .as_ref()
+            .and_then(|o| o.get(picker.index))
+            .cloned()
+        else {
+            return;
+        };
+        if let Some(pos) = picker.checked.iter().position(|c| *c == option) {
+            picker.checked.remove(pos);
+        } else {
+            if picker.field == IssueMetaField::Milestone {
+                picker.checked.clear();
+            }
+            picker.checked.push(option);
+        }
+    }
+
+    /// Apply the picker's selection: update the issue locally right away,
+    /// then run `gh issue edit` in the background.
+    pub fn issues_picker_apply(&mut self) {
+        let Some(picker) = self.gh_issues_picker.take() else {
+            return;
+        };
+        if picker.options.is_none() {
+            return;
+        }
+        let Some(repo) = self.gh_issues_repo.clone() else {
+            return;
+        };
+        let Some(current) = self
+            .gh_issues
+            .iter()
+            .find(|i| i.number == picker.number)
+            .map(|i| i.meta_values(picker.field))
+        else {
+            return;
+        };
+        let add: Vec<String> = picker
+            .checked
+            .iter()
+            .filter(|c| !current.contains(c))
+            .cloned()
+            .collect();
+        let remove: Vec<String> = current
+            .iter()
+            .filter(|c| !picker.checked.contains(c))
+            .cloned()
+            .collect();
+        if add.is_empty() && remove.is_empty() {
+            return;
+        }
+        let edit = match picker.field {
+            IssueMetaField::Labels => IssueMetaEdit::Labels { add, remove },
+            IssueMetaField::Assignees => IssueMetaEdit::Assignees { add, remove },
+            IssueMetaField::Milestone => IssueMetaEdit::Milestone(picker.checked.first().cloned()),
+        };
+
+        for issue in self
+            .gh_issues
+            .iter_mut()
+            .filter(|i| i.number == picker.number)
+        {
+            issue.apply_meta_edit(&edit);
+        }
+        for item in self.gh_issues_flat_list.iter_mut() {
+            if let FlatIssueItem::Issue(issue) = item {
+                if issue.number == picker.number {
+                    issue.apply_meta_edit(&edit);
+                }
+            }
+        }
+
+        let tx = match self.event_tx.clone() {
+            Some(tx) => tx,
+            None => return,
+        };
+        let number = picker.number;
+        std::thread::spawn(move || {
+            let result = github::edit_issue_meta(&repo, number, &edit).map_err(|e| e.to_string());
+            let _ = tx.send(AppEvent::IssueMetaUpdated(result));
+        });
+    }
+
+    pub fn handle_issue_meta_updated(&mut self, result: Result<(), String>) {
+        if let Err(e) = result {
+            self.last_error = Some(format!("Issue update: {}", e));
+            // Drop the optimistic change
+            self.load_github_issues();
+        }
+    }
+
     // --- Jira helpers ---
 
     pub fn load_jira_issues(&mut self) {
@@ -2620,48 +9851,213 @@ impl App {
             None => return,
         };
         let project_key = self.project_config.jira_project().map(|s| s.to_string());
-        let custom_jql = self.project_config.jira_jql().map(|s| s.to_string());
+        let filter = self.jira_filter.clone();
+        let custom_jql = self
+            .project_config
+            .jira_filter_jql(filter.as_deref())
+            .map(|s| s.to_string());
         std::thread::spawn(move || {
             let result = jira::search_my_issues(
                 project_key.as_deref(),
                 custom_jql.as_deref(),
             )
             .map_err(|e| e.to_string());
-            let _ = tx.send(AppEvent::JiraIssuesLoaded(result));
+            let _ = tx.send(AppEvent::JiraIssuesLoaded(filter, result));
+        });
+        if self.jira_board_mode {
+            self.load_jira_board();
+        }
+    }
+
+    /// Open the `[jira.filters]` switcher (`f`), at the filter in use.
+    pub fn open_jira_filter_picker(&mut self) {
+        let names = self.project_config.jira_filter_names();
+        // Entry 0 is the default query
+        self.jira_filter_picker_index = self
+            .jira_filter
+            .as_deref()
+            .and_then(|current| names.iter().position(|n| *n == current))
+            .map_or(0, |i| i + 1);
+        self.show_jira_filter_picker = true;
+    }
+
+    pub fn close_jira_filter_picker(&mut self) {
+        self.show_jira_filter_picker = false;
+    }
+
+    pub fn jira_filter_picker_move(&mut self, down: bool) {
+        let entries = self.project_config.jira_filter_names().len() + 1;
+        if down {
+            if self.jira_filter_picker_index + 1 < entries {
+                self.jira_filter_picker_index += 1;
+            }
+        } else {
+            self.jira_filter_picker_index = self.jira_filter_picker_index.saturating_sub(1);
+        }
+    }
+
+    /// Show the highlighted filter's issues and remember it for this project.
+    pub fn jira_filter_picker_select(&mut self) {
+        self.show_jira_filter_picker = false;
+        let filter = match self.jira_filter_picker_index {
+            0 => None,
+            i => match self.project_config.jira_filter_names().get(i - 1) {
+                Some(name) => Some(name.to_string()),
+                None => return,
+            },
+        };
+        if let Err(e) = jira_filter_choice::save_choice(&self.jira_filter_path, filter.as_deref()) {
+            self.last_error = Some(format!("Jira filter: {}", e));
+        }
+        if filter == self.jira_filter {
+            return;
+        }
+        self.jira_filter = filter;
+
+        // Start the list over so the old filter's issues aren't shown or
+        // reported as changes
+        self.jira_issues.clear();
+        self.jira_index = 0;
+        self.jira_detail = None;
+        self.jira_detail_scroll = 0;
+        self.rebuild_jira_list();
+        self.activity_tracker.forget(ActivitySource::Jira);
+        self.loaded_tabs.remove(&ActiveTab::Jira);
+        self.load_jira_issues();
+    }
+
+    /// Toggle between the grouped list and the sprint board.
+    pub fn jira_toggle_board(&mut self) {
+        self.jira_board_mode = !self.jira_board_mode;
+        self.jira_pane = JiraPane::List;
+        self.jira_show_transitions = false;
+        if self.jira_board_mode {
+            self.load_jira_board();
+        }
+    }
+
+    pub fn load_jira_board(&mut self) {
+        if !self.has_jira || self.jira_board_loading {
+            return;
+        }
+        let tx = match self.event_tx.clone() {
+            Some(tx) => tx,
+            None => return,
+        };
+        self.jira_board_loading = true;
+        let board_id = self.project_config.jira_board_id();
+        let project_key = self.project_config.jira_project().map(|s| s.to_string());
+        let board_jql = self.project_config.jira_board_jql().map(|s| s.to_string());
+        std::thread::spawn(move || {
+            let result =
+                jira::search_board_issues(board_id, project_key.as_deref(), board_jql.as_deref())
+                    .map_err(|e| e.to_string());
+            let _ = tx.send(AppEvent::JiraBoardLoaded(result));
         });
     }
 
-    pub fn handle_jira_issues_loaded(&mut self, result: Result<Vec<JiraIssue>, String>) {
+    pub fn handle_jira_board_loaded(&mut self, result: Result<Vec<JiraIssue>, String>) {
+        self.jira_board_loading = false;
         match result {
             Ok(issues) => {
-                let mut flat = jira::categorize_issues(&issues);
-                self.pin_current_jira_issue(&mut flat);
-                self.jira_flat_list = flat;
-                self.jira_issues = issues;
-                if self.jira_index >= self.jira_flat_list.len() {
-                    self.jira_index = 0;
-                    self.jira_skip_to_issue_entry();
+                self.jira_board_columns = jira::group_board_columns(&issues);
+                for (row, cards) in self
+                    .jira_board_rows
+                    .iter_mut()
+                    .zip(&self.jira_board_columns)
+                {
+                    *row = (*row).min(cards.len().saturating_sub(1));
                 }
             }
             Err(e) => {
-                self.last_error = Some(format!("Jira: {}", e));
+                self.last_error = Some(format!("Jira board: {}", e));
             }
         }
     }
 
-    pub fn jira_search(&mut self) {
-        let query = self.jira_search_input.trim().to_string();
-        if query.is_empty() {
+    fn jira_board_move_cursor(&mut self, delta: isize) {
+        let col = self.jira_board_column;
+        let len = self.jira_board_columns[col].len();
+        let row = &mut self.jira_board_rows[col];
+        *row = row.saturating_add_signed(delta).min(len.saturating_sub(1));
+    }
+
+    fn jira_board_move_column(&mut self, delta: isize) {
+        self.jira_board_column = self
+            .jira_board_column
+            .saturating_add_signed(delta)
+            .min(BOARD_COLUMNS.len() - 1);
+    }
+
+    /// Transition the selected card into the next (`1`) or previous (`-1`)
+    /// column.
+    pub fn jira_board_transition(&mut self, delta: isize) {
+        let Some(issue) = self.jira_selected_issue().cloned() else {
+            return;
+        };
+        let from = self.jira_board_column;
+        let to = from.saturating_add_signed(delta);
+        if to == from || to >= BOARD_COLUMNS.len() {
+            return;
+        }
+        let status = jira::board_column_status(&self.jira_board_columns, to);
+        match jira::transition_issue(&issue.key, &status) {
+            Ok(()) => {
+                self.send_status = Some((format!("{} → {}", issue.key, status), Instant::now()));
+                self.load_jira_issues();
+                if !self.jira_board_mode {
+                    self.load_jira_board();
+                }
+            }
+            Err(e) => {
+                self.last_error = Some(format!("Transition: {}", e));
+            }
+        }
+    }
+
+    pub fn handle_jira_issues_loaded(
+        &mut self,
+        filter: Option<String>,
+        result: Result<Vec<JiraIssue>, String>,
+    ) {
+        // Loaded for a filter since switched away from
+        if filter != self.jira_filter {
             return;
         }
-        self.jira_search_mode = false;
-        match jira::search_issues(&query) {
+        self.loaded_tabs.insert(ActiveTab::Jira);
+        match result {
+            Ok(issues) => {
+                // The snapshot history follows the default query only
+                if filter.is_none() {
+                    let open_ids = issues
+                        .iter()
+                        .filter(|i| !i.status_category.eq_ignore_ascii_case("done"))
+                        .map(|i| i.key.clone())
+                        .collect();
+                    self.record_snapshot(snapshot::TRACKER_JIRA, open_ids);
+                }
+                let states = issues.iter().map(ItemState::from_jira).collect();
+                self.record_activity(ActivitySource::Jira, states, None);
+                self.jira_issues = issues;
+                self.rebuild_jira_list();
+                self.poll_succeeded(ActiveTab::Jira);
+            }
+            Err(e) => {
+                if self.poll_failed(ActiveTab::Jira, &e) {
+                    self.last_error = Some(format!("Jira: {}", e));
+                }
+            }
+        }
+    }
+
+    /// Fetch issues matching `query` (a key or a label) from Jira, beyond
+    /// the assigned ones, and show them in the list.
+    fn jira_search(&mut self, query: &str) {
+        match jira::search_issues(query) {
             Ok(issues) => {
-                let mut flat = jira::categorize_issues(&issues);
-                self.pin_current_jira_issue(&mut flat);
-                self.jira_flat_list = flat;
                 self.jira_issues = issues;
                 self.jira_index = 0;
+                self.rebuild_jira_list();
                 self.jira_skip_to_issue_entry();
             }
             Err(e) => {
@@ -2670,14 +10066,35 @@ impl App {
         }
     }
 
+    fn rebuild_jira_list(&mut self) {
+        let issues = self.filtered(ActiveTab::Jira, &self.jira_issues, None);
+        let mut flat = jira::categorize_issues(&issues);
+        self.pin_current_jira_issue(&mut flat);
+        self.jira_flat_list = flat;
+        if self.jira_index >= self.jira_flat_list.len() {
+            self.jira_index = 0;
+            self.jira_skip_to_issue_entry();
+        }
+        self.index_for_search(SearchSource::Jira);
+    }
+
     fn jira_load_detail(&mut self) {
         let issue = self.jira_selected_issue();
         if let Some(issue) = issue {
             let key = issue.key.clone();
             match jira::view_issue(&key) {
-                Ok(detail) => {
+                Ok(mut detail) => {
+                    if let Some((email, token)) = self.project_config.jira_credentials() {
+                        match jira::web_links(&detail, (email, &token)) {
+                            Ok(links) => detail.web_links = links,
+                            Err(e) => {
+                                self.last_error = Some(format!("Jira web links: {}", e));
+                            }
+                        }
+                    }
                     self.jira_detail = Some(detail);
                     self.jira_detail_scroll = 0;
+                    self.jira_resource_index = 0;
                 }
                 Err(e) => {
                     self.last_error = Some(format!("Jira detail: {}", e));
@@ -2779,6 +10196,10 @@ impl App {
     }
 
     pub fn jira_selected_issue(&self) -> Option<&JiraIssue> {
+        if self.jira_board_mode {
+            let col = self.jira_board_column;
+            return self.jira_board_columns[col].get(self.jira_board_rows[col]);
+        }
         if self.jira_flat_list.is_empty() {
             return None;
         }
@@ -2789,7 +10210,36 @@ impl App {
         }
     }
 
-    pub fn jira_open_selected(&self) {
+    /// Select the next attachment or web link in the detail pane.
+    pub fn jira_cycle_resource(&mut self) {
+        let count = self.jira_detail.as_ref().map_or(0, |d| d.resources().len());
+        if count == 0 {
+            self.send_status = Some(("No attachments or web links".to_string(), Instant::now()));
+            return;
+        }
+        self.jira_resource_index = (self.jira_resource_index + 1) % count;
+    }
+
+    /// Open the selected attachment or web link from the detail pane, or the
+    /// issue itself. Attachments download in the background first.
+    pub fn jira_open_selected(&mut self) {
+        if self.jira_pane == JiraPane::Detail {
+            let attachment = match &self.jira_detail {
+                Some(detail) => match detail.resources().get(self.jira_resource_index) {
+                    Some(JiraResource::WebLink(link)) => {
+                        cli_detect::open_url(&link.url);
+                        return;
+                    }
+                    Some(JiraResource::Attachment(a)) => Some((detail.key.clone(), (*a).clone())),
+                    None => None,
+                },
+                None => None,
+            };
+            if let Some((key, attachment)) = attachment {
+                self.jira_download_attachment(key, attachment);
+                return;
+            }
+        }
         if let Some(issue) = self.jira_selected_issue() {
             if !issue.url.is_empty() {
                 cli_detect::open_url(&issue.url);
@@ -2797,14 +10247,46 @@ impl App {
         }
     }
 
+    fn jira_download_attachment(&mut self, key: String, attachment: JiraAttachment) {
+        let Some(tx) = self.event_tx.clone() else {
+            return;
+        };
+        let credentials = self
+            .project_config
+            .jira_credentials()
+            .map(|(email, token)| (email.to_string(), token));
+        self.send_status = Some((
+            format!("Downloading {}...", attachment.filename),
+            Instant::now(),
+        ));
+        std::thread::spawn(move || {
+            let credentials = credentials.as_ref().map(|(e, t)| (e.as_str(), t.as_str()));
+            let result = jira::download_attachment(&key, &attachment, credentials)
+                .map_err(|e| format!("{}: {}", attachment.filename, e));
+            let _ = tx.send(AppEvent::JiraAttachmentDownloaded(result));
+        });
+    }
+
+    pub fn handle_jira_attachment_downloaded(&mut self, result: Result<PathBuf, String>) {
+        match result {
+            Ok(path) => {
+                cli_detect::open_path(&path);
+                self.send_status = Some((format!("Opened {}", path.display()), Instant::now()));
+            }
+            Err(e) => self.last_error = Some(format!("Jira attachment: {}", e)),
+        }
+    }
+
     // --- Linear helpers ---
 
     pub fn load_linear_issues(&mut self) {
         if !self.has_linear {
             return;
         }
+        // Replayed responses need no key
         let api_key = match self.project_config.linear_api_key() {
             Some(k) => k.to_string(),
+            None if cli_fixtures::replaying() => String::new(),
             None => return,
         };
         self.linear_last_poll = Instant::now();
@@ -2812,33 +10294,177 @@ impl App {
             Some(tx) => tx,
             None => return,
         };
-        let username = self.project_config.linear_username().map(|s| s.to_string());
-        let team = self.project_config.linear_team().map(|s| s.to_string());
+        let query = self.linear_query.clone();
+        // A refresh reloads every page loaded so far, in one request
+        let first = (self.linear_pages * linear::PAGE_SIZE).min(linear::MAX_PAGE_SIZE);
         std::thread::spawn(move || {
             let result =
-                linear::fetch_my_issues(&api_key, username.as_deref(), team.as_deref())
-                    .map_err(|e| e.to_string());
+                linear::fetch_my_issues(&api_key, &query, first, None).map_err(|e| e.to_string());
             let _ = tx.send(AppEvent::LinearIssuesLoaded(result));
         });
     }
 
-    pub fn handle_linear_issues_loaded(&mut self, result: Result<Vec<LinearIssue>, String>) {
-        let username = self.project_config.linear_username().map(|s| s.to_string());
-        match result {
-            Ok(issues) => {
-                let mut flat = linear::categorize_issues(&issues, username.as_deref());
-                self.pin_current_linear_issue(&mut flat);
-                self.linear_flat_list = flat;
-                self.linear_issues = issues;
-                if self.linear_index >= self.linear_flat_list.len() {
-                    self.linear_index = 0;
-                    self.linear_skip_to_issue_entry();
-                }
-            }
-            Err(e) => {
-                self.last_error = Some(format!("Linear: {}", e));
-            }
+    /// Fetch the next page of Linear issues, if there is one.
+    pub fn load_more_linear_issues(&mut self) {
+        if self.linear_loading_more {
+            return;
+        }
+        let Some(after) = self.linear_next_cursor.clone() else {
+            return;
+        };
+        let api_key = match self.project_config.linear_api_key() {
+            Some(k) => k.to_string(),
+            None if cli_fixtures::replaying() => String::new(),
+            None => return,
+        };
+        let Some(tx) = self.event_tx.clone() else {
+            return;
+        };
+        self.linear_loading_more = true;
+        self.send_status = Some(("Loading more Linear issues...".to_string(), Instant::now()));
+        let query = self.linear_query.clone();
+        std::thread::spawn(move || {
+            let result = linear::fetch_my_issues(&api_key, &query, linear::PAGE_SIZE, Some(&after))
+                .map_err(|e| e.to_string());
+            let _ = tx.send(AppEvent::LinearMoreLoaded(after, result));
+        });
+    }
+
+    pub fn handle_linear_issues_loaded(&mut self, result: Result<IssuePage, String>) {
+        self.loaded_tabs.insert(ActiveTab::Linear);
+        match result {
+            Ok(page) => {
+                let states = page.issues.iter().map(ItemState::from_linear).collect();
+                let me = self.project_config.linear_username().map(|s| s.to_string());
+                self.record_activity(ActivitySource::Linear, states, me);
+                self.linear_pages = page.issues.len().div_ceil(linear::PAGE_SIZE).max(1);
+                self.linear_next_cursor = page.next_cursor;
+                self.linear_issues = page.issues;
+                self.record_linear_snapshot();
+                self.rebuild_linear_list();
+                self.poll_succeeded(ActiveTab::Linear);
+            }
+            Err(e) => {
+                if self.poll_failed(ActiveTab::Linear, &e) {
+                    self.last_error = Some(format!("Linear: {}", e));
+                }
+            }
+        }
+    }
+
+    /// Append a page fetched by `load_more_linear_issues`. Dropped when a
+    /// refresh has replaced the list since it was requested.
+    pub fn handle_linear_more_loaded(&mut self, after: String, result: Result<IssuePage, String>) {
+        self.linear_loading_more = false;
+        if self.linear_next_cursor.as_deref() != Some(after.as_str()) {
+            return;
+        }
+        match result {
+            Ok(mut page) => {
+                // Issues updated since the first page was fetched can show up again
+                page.issues.retain(|new| {
+                    !self
+                        .linear_issues
+                        .iter()
+                        .any(|i| i.identifier == new.identifier)
+                });
+                let states = page.issues.iter().map(ItemState::from_linear).collect();
+                self.activity_tracker.extend(ActivitySource::Linear, states);
+                self.send_status = Some((
+                    format!("Loaded {} more Linear issues", page.issues.len()),
+                    Instant::now(),
+                ));
+                self.linear_pages += 1;
+                self.linear_next_cursor = page.next_cursor;
+                self.linear_issues.extend(page.issues);
+                self.record_linear_snapshot();
+                self.rebuild_linear_list();
+            }
+            Err(e) => {
+                self.last_error = Some(format!("Linear: {}", e));
+            }
+        }
+    }
+
+    fn record_linear_snapshot(&mut self) {
+        let open_ids = self
+            .linear_issues
+            .iter()
+            .filter(|i| !matches!(i.state.state_type.as_str(), "completed" | "canceled"))
+            .map(|i| i.identifier.clone())
+            .collect();
+        self.record_snapshot(snapshot::TRACKER_LINEAR, open_ids);
+    }
+
+    /// The configured Linear filters, for the list title, e.g.
+    /// `project:Mobile cycle:current`.
+    pub fn linear_scope(&self) -> Option<String> {
+        let query = &self.linear_query;
+        let mut parts = Vec::new();
+        if let Some(project) = &query.project {
+            parts.push(format!("project:{}", project));
+        }
+        if let Some(cycle) = &query.cycle {
+            parts.push(format!("cycle:{}", cycle));
+        }
+        if !query.states.is_empty() {
+            parts.push(format!("state:{}", query.states.join(",")));
+        }
+        (!parts.is_empty()).then(|| parts.join(" "))
+    }
+
+    /// Add today's open issue ids for `tracker` to the snapshot history and
+    /// persist it.
+    fn record_snapshot(&mut self, tracker: &str, open_ids: Vec<String>) {
+        let today = chrono::Local::now().format("%Y-%m-%d").to_string();
+        self.snapshots.record(tracker, &today, open_ids);
+        if let Err(e) = snapshots::save_snapshots(&self.snapshot_path, &self.snapshots) {
+            self.last_error = Some(format!("Snapshots: {}", e));
+        }
+    }
+
+    // --- Deps helpers ---
+
+    /// Run the configured dependency checkers in the background.
+    pub fn load_outdated_deps(&mut self) {
+        if self.deps_loading {
+            return;
+        }
+        let tx = match self.event_tx.clone() {
+            Some(tx) => tx,
+            None => return,
+        };
+        let checkers = self.project_config.deps_checkers().to_vec();
+        let cwd = self.project_cwd.clone();
+        self.deps_loading = true;
+        std::thread::spawn(move || {
+            let mut found = Vec::new();
+            let mut errors = Vec::new();
+            for checker in &checkers {
+                match deps::run_checker(&cwd, checker) {
+                    Ok(d) => found.extend(d),
+                    Err(e) => errors.push(format!("{}: {}", checker, e)),
+                }
+            }
+            let _ = tx.send(AppEvent::DepsLoaded(found, errors));
+        });
+    }
+
+    pub fn handle_deps_loaded(&mut self, found: Vec<OutdatedDep>, errors: Vec<String>) {
+        self.deps_loading = false;
+        self.deps_checked = true;
+        self.outdated_deps = found;
+        if self.deps_index >= self.outdated_deps.len() {
+            self.deps_index = self.outdated_deps.len().saturating_sub(1);
         }
+        if let Some(first) = errors.first() {
+            self.last_error = Some(format!("Deps: {}", first));
+        }
+        self.deps_errors = errors;
+    }
+
+    pub fn selected_outdated_dep(&self) -> Option<&OutdatedDep> {
+        self.outdated_deps.get(self.deps_index)
     }
 
     // --- Prompt modal helpers ---
@@ -2858,6 +10484,12 @@ impl App {
             ActiveTab::Jira => self
                 .jira_selected_issue()
                 .map(prompt_builder::ticket_from_jira),
+            ActiveTab::Azure => self
+                .azure_selected_item()
+                .map(prompt_builder::ticket_from_azure),
+            ActiveTab::Deps => self
+                .selected_outdated_dep()
+                .map(prompt_builder::ticket_from_outdated_dep),
             _ => None,
         }
     }
@@ -2871,10 +10503,22 @@ impl App {
         }
 
         let ticket = self.resolve_current_ticket();
+        self.prompt_library =
+            prompt_library::load_prompt_library(&self.project_cwd, &self.claude_home);
+        self.prompt_agents = commands::load_commands(&self.project_cwd, &self.claude_home)
+            .into_iter()
+            .filter(|file| file.kind == CommandKind::Agent)
+            .collect();
+        self.prompt_launch_args.clear();
+        self.prompt_model = None;
 
         if let Some(ticket) = ticket {
-            // If custom prompts are configured, show the picker first
-            if !self.project_config.prompts.is_empty() {
+            if ticket.source == TicketSource::Dependency {
+                // Dependency updates have their own prompt; custom prompts are ticket-oriented
+                let prompt = prompt_builder::build_dependency_prompt(&ticket);
+                self.open_prompt_editor_with(ticket, &prompt);
+            } else if self.prompt_picker_len() > 1 {
+                // If custom prompts or templates exist, show the picker first
                 self.prompt_ticket_info = Some(ticket);
                 self.prompt_picker_index = 0;
                 self.show_prompt_picker = true;
@@ -2893,6 +10537,25 @@ impl App {
         editor.move_cursor(tui_textarea::CursorMove::Top);
         editor.move_cursor(tui_textarea::CursorMove::Head);
 
+        let defaults = launch_args::defaults_for(&self.launch_defaults, &ticket.source);
+        if self.prompt_launch_args.is_empty() {
+            self.prompt_launch_args = defaults.args;
+        }
+        if self.prompt_model.is_none() {
+            self.prompt_model = defaults.model;
+        }
+        self.prompt_skip_permissions = defaults.skip_permissions;
+        self.prompt_max_turns = defaults.max_turns;
+        // An agent that was since deleted is dropped
+        self.prompt_agent = defaults.agent.and_then(|name| {
+            self.prompt_agents
+                .iter()
+                .filter_map(|file| commands::agent_definition(file).ok())
+                .find(|(agent, _)| *agent == name)
+        });
+        self.prompt_args_input = None;
+        self.prompt_turns_input = None;
+
         self.prompt_editor = Some(editor);
         self.prompt_ticket_info = Some(ticket);
         self.show_prompt_modal = true;
@@ -2907,13 +10570,23 @@ impl App {
 
         self.show_prompt_picker = false;
 
-        // Index 0 = "Default (from ticket)", rest map to custom prompts
+        // Index 0 = "Default (from ticket)", then config prompts, then library templates
+        let custom_count = self.project_config.prompts.len();
         let prompt_text = if self.prompt_picker_index == 0 {
             prompt_builder::build_default_prompt(&ticket)
+        } else if self.prompt_picker_index <= custom_count {
+            let cp = &self.project_config.prompts[self.prompt_picker_index - 1];
+            self.fill_prompt_template(&cp.prompt, &ticket)
         } else {
-            let custom_idx = self.prompt_picker_index - 1;
-            match self.project_config.prompts.get(custom_idx) {
-                Some(cp) => cp.prompt.clone(),
+            match self
+                .prompt_library
+                .get(self.prompt_picker_index - 1 - custom_count)
+            {
+                Some(template) => {
+                    self.prompt_model = template.model.clone();
+                    self.prompt_launch_args = template.args.clone();
+                    self.fill_prompt_template(&template.body, &ticket)
+                }
                 None => prompt_builder::build_default_prompt(&ticket),
             }
         };
@@ -2927,16 +10600,177 @@ impl App {
         self.prompt_ticket_info = None;
     }
 
-    /// Total number of items in the prompt picker (default + custom prompts).
+    /// Total number of items in the prompt picker (default + custom prompts + templates).
     pub fn prompt_picker_len(&self) -> usize {
-        1 + self.project_config.prompts.len()
+        1 + self.project_config.prompts.len() + self.prompt_library.len()
     }
 
-    /// Confirm and launch the process from the prompt modal.
-    pub fn confirm_prompt_modal(&mut self) {
-        let prompt = if let Some(ref editor) = self.prompt_editor {
-            editor.lines().join("\n")
+    /// Fill `template` from `ticket`, the current branch and, only when the
+    /// template asks for `{{diff}}`, the uncommitted changes.
+    fn fill_prompt_template(&self, template: &str, ticket: &TicketInfo) -> String {
+        let branch = cli_detect::detect_git_branch(&self.project_cwd).unwrap_or_default();
+        let diff = if prompt_library::placeholders(template)
+            .iter()
+            .any(|name| name == "diff")
+        {
+            git::working_diff(&self.project_cwd).unwrap_or_default()
         } else {
+            String::new()
+        };
+        prompt_builder::build_template_prompt(template, ticket, &branch, &diff)
+    }
+
+    /// The edited prompt with any variables typed into it filled, as it
+    /// would be launched.
+    fn final_prompt(&self) -> Option<String> {
+        let editor = self.prompt_editor.as_ref()?;
+        let ticket = self.prompt_ticket_info.as_ref()?;
+        Some(self.fill_prompt_template(&editor.lines().join("\n"), ticket))
+    }
+
+    /// Show the prompt a launch would send, with its size, instead of the
+    /// editor; or go back to editing.
+    pub fn toggle_prompt_preview(&mut self) {
+        if self.prompt_preview.take().is_some() {
+            return;
+        }
+        let Some(text) = self.final_prompt() else {
+            return;
+        };
+        self.prompt_args_input = None;
+        self.prompt_turns_input = None;
+        self.prompt_preview = Some(PromptPreview {
+            tokens: prompt_builder::estimate_tokens(&text),
+            unresolved: prompt_library::placeholders(&text),
+            text,
+            scroll: 0,
+        });
+    }
+
+    pub fn prompt_preview_scroll(&mut self, down: bool, lines: usize) {
+        if let Some(preview) = self.prompt_preview.as_mut() {
+            preview.scroll = if down {
+                (preview.scroll + lines).min(preview.text.lines().count())
+            } else {
+                preview.scroll.saturating_sub(lines)
+            };
+        }
+    }
+
+    /// Arguments following `-p "<prompt>"` in the launch being prepared.
+    pub fn prompt_command_args(&self) -> Vec<String> {
+        let mut extra = process_runner::choice_args(
+            self.prompt_model.as_deref(),
+            self.prompt_agent
+                .as_ref()
+                .map(|(name, definition)| (name.as_str(), definition.as_str())),
+            self.prompt_max_turns,
+        );
+        extra.extend(self.prompt_launch_args.iter().cloned());
+        process_runner::headless_args(self.prompt_skip_permissions, &extra)
+    }
+
+    /// Step the launch's model through Claude Code's default and
+    /// [`MODEL_CHOICES`]. A template's model that isn't one of them steps
+    /// to the default.
+    pub fn cycle_prompt_model(&mut self) {
+        let current = self
+            .prompt_model
+            .as_deref()
+            .and_then(|model| MODEL_CHOICES.iter().position(|m| *m == model));
+        self.prompt_model = match (self.prompt_model.is_some(), current) {
+            (false, _) => Some(MODEL_CHOICES[0].to_string()),
+            (true, Some(i)) => MODEL_CHOICES.get(i + 1).map(|m| m.to_string()),
+            (true, None) => None,
+        };
+    }
+
+    /// Step the launch's agent through none and the agents in
+    /// `.claude/agents/`.
+    pub fn cycle_prompt_agent(&mut self) {
+        if self.prompt_agents.is_empty() {
+            self.last_error = Some("No agents in .claude/agents/".to_string());
+            return;
+        }
+        let current = self.prompt_agent.as_ref().and_then(|(name, _)| {
+            self.prompt_agents.iter().position(|file| {
+                commands::agent_definition(file).is_ok_and(|(agent, _)| agent == *name)
+            })
+        });
+        let next = match current {
+            None => 0,
+            Some(i) if i + 1 < self.prompt_agents.len() => i + 1,
+            Some(_) => {
+                self.prompt_agent = None;
+                return;
+            }
+        };
+        match commands::agent_definition(&self.prompt_agents[next]) {
+            Ok(agent) => self.prompt_agent = Some(agent),
+            Err(e) => self.last_error = Some(format!("Agent: {}", e)),
+        }
+    }
+
+    /// Start typing the launch's max turns, seeded with the current value.
+    pub fn start_prompt_turns_edit(&mut self) {
+        self.prompt_turns_input = Some(
+            self.prompt_max_turns
+                .map(|turns| turns.to_string())
+                .unwrap_or_default(),
+        );
+    }
+
+    /// Use the typed max turns; empty or zero leaves the run unlimited.
+    pub fn apply_prompt_turns_edit(&mut self) {
+        if let Some(input) = self.prompt_turns_input.take() {
+            self.prompt_max_turns = input.trim().parse().ok().filter(|&turns| turns > 0);
+        }
+    }
+
+    /// Toggle `--dangerously-skip-permissions` for the launch.
+    pub fn toggle_prompt_skip_permissions(&mut self) {
+        self.prompt_skip_permissions = !self.prompt_skip_permissions;
+    }
+
+    /// Start editing the extra arguments, seeded with the current ones.
+    pub fn start_prompt_args_edit(&mut self) {
+        self.prompt_args_input = Some(self.prompt_launch_args.join(" "));
+    }
+
+    /// Use the typed extra arguments, split on whitespace like a
+    /// template's `args:` header.
+    pub fn apply_prompt_args_edit(&mut self) {
+        if let Some(input) = self.prompt_args_input.take() {
+            self.prompt_launch_args = input.split_whitespace().map(String::from).collect();
+        }
+    }
+
+    /// Remember how `source` was launched for the next launch from it.
+    fn remember_launch_defaults(&mut self, source: &TicketSource) {
+        let defaults = LaunchDefaults {
+            args: self.prompt_launch_args.clone(),
+            skip_permissions: self.prompt_skip_permissions,
+            model: self.prompt_model.clone(),
+            agent: self.prompt_agent.as_ref().map(|(name, _)| name.clone()),
+            max_turns: self.prompt_max_turns,
+        };
+        self.launch_defaults
+            .insert(source.key().to_string(), defaults);
+        if let Err(e) =
+            launch_args::save_defaults(&self.launch_defaults_path, &self.launch_defaults)
+        {
+            self.last_error = Some(format!("Launch args: {}", e));
+        }
+    }
+
+    /// Launch the process from the prompt modal. From the editor this first
+    /// shows the dry run, so the command line is always confirmed.
+    pub fn confirm_prompt_modal(&mut self) {
+        if self.prompt_preview.is_none() {
+            self.toggle_prompt_preview();
+            return;
+        }
+        let Some(prompt) = self.final_prompt() else {
             return;
         };
 
@@ -2947,15 +10781,29 @@ impl App {
 
         self.show_prompt_modal = false;
         self.prompt_editor = None;
+        self.prompt_preview = None;
+        self.prompt_args_input = None;
+        self.prompt_turns_input = None;
 
-        self.spawn_claude_process(&ticket, &prompt);
+        self.remember_launch_defaults(&ticket.source);
+        let args = self.prompt_command_args();
+        self.prompt_launch_args.clear();
+        self.prompt_model = None;
+        self.prompt_agent = None;
+        self.spawn_claude_process(&ticket, &prompt, &args);
     }
 
     /// Cancel and close the prompt modal.
     pub fn cancel_prompt_modal(&mut self) {
         self.show_prompt_modal = false;
         self.prompt_editor = None;
+        self.prompt_preview = None;
+        self.prompt_args_input = None;
+        self.prompt_turns_input = None;
         self.prompt_ticket_info = None;
+        self.prompt_launch_args.clear();
+        self.prompt_model = None;
+        self.prompt_agent = None;
     }
 
     // --- Process management ---
@@ -2969,51 +10817,286 @@ impl App {
         }
     }
 
-    /// Spawn a new Claude Code process with the given prompt.
-    fn spawn_claude_process(&mut self, ticket: &TicketInfo, prompt: &str) {
-        self.ensure_process_channel();
-
+    /// Add a Claude Code process for `ticket`. It starts right away, or waits
+    /// as Queued when `processes.max_concurrent` are already running.
+    fn spawn_claude_process(&mut self, ticket: &TicketInfo, prompt: &str, args: &[String]) {
         let id = self.next_process_id;
         self.next_process_id += 1;
+        self.processes.push(SpawnedProcess {
+            id,
+            label: ticket.key.clone(),
+            title: ticket.title.clone(),
+            source: ticket.source.clone(),
+            ticket_url: ticket.url.clone(),
+            status: ProcessStatus::Queued,
+            prompt: prompt.to_string(),
+            model: args
+                .iter()
+                .position(|arg| arg == "--model")
+                .and_then(|i| args.get(i + 1))
+                .cloned(),
+            args: args.to_vec(),
+            cwd: self.project_cwd.clone(),
+            output_lines: std::collections::VecDeque::new(),
+            error_lines: std::collections::VecDeque::new(),
+            session_id: None,
+            progress_lines: Vec::new(),
+            follow: true,
+            artifacts: Vec::new(),
+            started_at: None,
+            finished_at: None,
+            exit_code: None,
+            tree_before: None,
+            tree_after: None,
+        });
+
+        // Auto-switch to Processes tab
+        self.active_tab = ActiveTab::Processes;
+        self.process_index = self.processes.len() - 1;
+        self.reset_process_output();
+
+        self.start_queued_processes();
+        if self
+            .processes
+            .last()
+            .is_some_and(|p| p.status == ProcessStatus::Queued)
+        {
+            self.send_status = Some((
+                format!("Queued {}: waiting for a free slot", ticket.key),
+                Instant::now(),
+            ));
+        }
+    }
+
+    /// Start queued processes, oldest first, while there are free slots.
+    fn start_queued_processes(&mut self) {
+        let max = self.project_config.max_concurrent_processes();
+        while max.is_none_or(|max| self.process_children.len() + self.process_starting.len() < max)
+        {
+            let Some(idx) = self.processes.iter().position(|p| {
+                p.status == ProcessStatus::Queued && !self.process_starting.contains(&p.id)
+            }) else {
+                return;
+            };
+            self.snapshot_before_start(idx);
+        }
+    }
+
+    /// Snapshot the working tree in the background, then start the queued
+    /// process at `idx` once it is taken, so the report's diff covers the
+    /// whole run.
+    fn snapshot_before_start(&mut self, idx: usize) {
+        let Some(tx) = self.event_tx.clone() else {
+            self.start_process(idx, None);
+            return;
+        };
+        let (id, cwd) = (self.processes[idx].id, self.processes[idx].cwd.clone());
+        self.process_starting.insert(id);
+        std::thread::spawn(move || {
+            let tree = git::snapshot_tree(&cwd).ok();
+            let _ = tx.send(AppEvent::ProcessTreeBefore(id, tree));
+        });
+    }
 
+    /// The tree was snapshotted for process `id`; start it unless it was
+    /// cancelled meanwhile.
+    pub fn handle_process_tree_before(&mut self, id: usize, tree: Option<String>) {
+        self.process_starting.remove(&id);
+        let queued = self
+            .processes
+            .iter()
+            .position(|p| p.id == id && p.status == ProcessStatus::Queued);
+        if let Some(idx) = queued {
+            self.start_process(idx, tree);
+        }
+    }
+
+    /// A finished process's tree was snapshotted, for its report's diff.
+    pub fn handle_process_tree_after(&mut self, id: usize, tree: Option<String>) {
+        if let Some(proc) = self.processes.iter_mut().find(|p| p.id == id) {
+            proc.tree_after = tree;
+        }
+    }
+
+    /// Launch the queued process at `idx`, with the tree snapshotted just
+    /// before. A failed launch marks it Failed.
+    fn start_process(&mut self, idx: usize, tree_before: Option<String>) {
+        self.ensure_process_channel();
         let tx = match self.process_tx.as_ref() {
             Some(tx) => tx.clone(),
             None => {
                 self.last_error =
                     Some("Internal error: process channel not initialized".to_string());
+                self.processes[idx].status = ProcessStatus::Failed;
                 return;
             }
         };
-        match process_runner::spawn_claude_headless(id, prompt, &self.project_cwd, tx) {
+        let process = &self.processes[idx];
+        match process_runner::spawn_claude_headless(
+            process.id,
+            &process.prompt,
+            &process.args,
+            &process.cwd,
+            tx,
+        ) {
             Ok(child) => {
-                let process = SpawnedProcess {
-                    id,
-                    label: ticket.key.clone(),
-                    title: ticket.title.clone(),
-                    source: ticket.source.clone(),
-                    status: ProcessStatus::Running,
-                    prompt: prompt.to_string(),
-                    cwd: self.project_cwd.clone(),
-                    output_lines: std::collections::VecDeque::new(),
-                    error_lines: std::collections::VecDeque::new(),
-                    session_id: None,
-                    progress_lines: Vec::new(),
-                };
-                self.processes.push(process);
-                self.process_children.push((id, child));
-
-                // Auto-switch to Processes tab
-                self.active_tab = ActiveTab::Processes;
-                self.process_index = self.processes.len() - 1;
-                self.process_output_scroll = 0;
+                self.process_records.push(ProcessRecord {
+                    pid: child.id(),
+                    owner: std::process::id(),
+                    label: process.label.clone(),
+                    started: chrono::Utc::now(),
+                });
+                self.process_children.push((process.id, child));
+                self.processes[idx].status = ProcessStatus::Running;
+                self.processes[idx].started_at = Some(chrono::Utc::now());
+                self.processes[idx].tree_before = tree_before;
+                self.save_process_registry();
             }
             Err(e) => {
+                self.processes[idx].status = ProcessStatus::Failed;
                 self.last_error = Some(format!("Failed to spawn claude: {}", e));
             }
         }
     }
 
+    // --- PR drafts ---
+
+    /// Have Claude Code draft a PR description from the current branch's
+    /// commits; the editor opens when the run finishes.
+    pub fn draft_pr_description(&mut self) {
+        let repo = match &self.gh_repo {
+            Some(repo) if self.has_gh && !self.prs_from_bitbucket => repo.clone(),
+            _ => {
+                self.last_error = Some("PR draft: needs gh and a GitHub repo".to_string());
+                return;
+            }
+        };
+        if let Some((run, _)) = &self.pr_draft_run {
+            let label = self
+                .processes
+                .iter()
+                .find(|p| p.id == *run)
+                .map(|p| p.title.clone())
+                .unwrap_or_default();
+            self.send_status = Some((
+                format!("Already drafting a PR for {}", label),
+                Instant::now(),
+            ));
+            return;
+        }
+        let changes = match git::branch_changes(&self.project_cwd) {
+            Ok(changes) => changes,
+            Err(e) => {
+                self.last_error = Some(format!("PR draft: {}", e));
+                return;
+            }
+        };
+        let ticket = prompt_builder::ticket_from_branch(&changes);
+        let prompt = prompt_builder::build_pr_description_prompt(&changes);
+        let target = PrTarget {
+            repo,
+            base: changes.base,
+            head: changes.branch,
+        };
+        self.pr_draft_run = Some((self.next_process_id, target));
+        let args = process_runner::headless_args(false, &[]);
+        self.spawn_claude_process(&ticket, &prompt, &args);
+    }
+
+    /// Open the editor on the description drafted by process `id`.
+    fn open_pr_draft(&mut self, id: usize) {
+        let Some((_, target)) = self.pr_draft_run.take() else {
+            return;
+        };
+        let reply = self
+            .processes
+            .iter()
+            .find(|p| p.id == id && p.status == ProcessStatus::Completed)
+            .and_then(|p| stream_json_result(&p.output_lines));
+        let Some(reply) = reply else {
+            self.last_error = Some(format!(
+                "PR draft for {}: Claude Code gave no description; see the Processes tab",
+                target.head
+            ));
+            return;
+        };
+        let (title, body) = prompt_builder::parse_pr_description(&reply);
+        let mut title_ed = tui_textarea::TextArea::default();
+        title_ed.set_cursor_line_style(ratatui::style::Style::default());
+        title_ed.insert_str(&title);
+        let mut body_ed = tui_textarea::TextArea::default();
+        body_ed.set_cursor_line_style(ratatui::style::Style::default());
+        body_ed.insert_str(&body);
+        body_ed.move_cursor(tui_textarea::CursorMove::Top);
+        body_ed.move_cursor(tui_textarea::CursorMove::Head);
+        self.pr_draft = Some(PrDraft {
+            target,
+            field: IssueEditField::Title,
+            title: title_ed,
+            body: body_ed,
+        });
+    }
+
+    pub fn pr_draft_toggle_field(&mut self) {
+        if let Some(draft) = self.pr_draft.as_mut() {
+            draft.field = match draft.field {
+                IssueEditField::Title => IssueEditField::Body,
+                IssueEditField::Body => IssueEditField::Title,
+            };
+        }
+    }
+
+    pub fn pr_draft_input(&mut self, key: crossterm::event::KeyEvent) {
+        if let Some(draft) = self.pr_draft.as_mut() {
+            match draft.field {
+                IssueEditField::Title => draft.title.input(key),
+                IssueEditField::Body => draft.body.input(key),
+            };
+        }
+    }
+
+    pub fn pr_draft_cancel(&mut self) {
+        self.pr_draft = None;
+    }
+
+    /// Create the PR with `gh pr create`. The editor stays open if it fails.
+    pub fn pr_draft_create(&mut self) {
+        let Some(draft) = self.pr_draft.as_ref() else {
+            return;
+        };
+        let title = draft.title.lines().join(" ");
+        if title.trim().is_empty() {
+            self.last_error = Some("Title cannot be empty".to_string());
+            return;
+        }
+        let body = draft.body.lines().join("\n");
+        let target = &draft.target;
+        match github::create_pr(
+            &target.repo,
+            &target.base,
+            &target.head,
+            title.trim(),
+            &body,
+        ) {
+            Ok(url) => {
+                self.pr_draft = None;
+                self.send_status = Some((format!("Opened {}", url), Instant::now()));
+                self.load_github_prs();
+            }
+            Err(e) => self.last_error = Some(format!("Create PR: {}", e)),
+        }
+    }
+
+    /// Move to the next issue; past the last, load the next page.
     fn linear_skip_to_next_issue(&mut self) {
+        let at_end = !self
+            .linear_flat_list
+            .iter()
+            .skip(self.linear_index + 1)
+            .any(|item| matches!(item, FlatLinearItem::Issue(_)));
+        if at_end {
+            self.load_more_linear_issues();
+        }
         if self.linear_flat_list.is_empty() {
             return;
         }
@@ -3058,40 +11141,241 @@ impl App {
         }
     }
 
+    fn rebuild_linear_list(&mut self) {
+        let username = self.project_config.linear_username();
+        let issues = self.filtered(ActiveTab::Linear, &self.linear_issues, username);
+        let mut flat = linear::categorize_issues(&issues, username);
+        self.pin_current_linear_issue(&mut flat);
+        self.linear_flat_list = flat;
+        if self.linear_index >= self.linear_flat_list.len() {
+            self.linear_index = 0;
+            self.linear_skip_to_issue_entry();
+        }
+        self.index_for_search(SearchSource::Linear);
+    }
+
     /// If any Linear issue matches current_issue_ids, move it to the top
     /// under a "Current Issue" header.
     fn pin_current_linear_issue(&self, flat: &mut Vec<FlatLinearItem>) {
         if self.current_issue_ids.is_empty() {
             return;
         }
-        let pos = flat.iter().position(|item| {
-            matches!(item, FlatLinearItem::Issue(issue) if self.is_current_linear_issue(&issue.identifier))
-        });
-        if let Some(idx) = pos {
-            let item = flat.remove(idx);
-            flat.insert(
-                0,
-                FlatLinearItem::AssignmentHeader(">>> Current Issue <<<".to_string()),
-            );
-            flat.insert(1, item);
+        let pos = flat.iter().position(|item| {
+            matches!(item, FlatLinearItem::Issue(issue) if self.is_current_linear_issue(&issue.identifier))
+        });
+        if let Some(idx) = pos {
+            let item = flat.remove(idx);
+            flat.insert(
+                0,
+                FlatLinearItem::AssignmentHeader(">>> Current Issue <<<".to_string()),
+            );
+            flat.insert(1, item);
+        }
+    }
+
+    pub fn linear_selected_issue(&self) -> Option<&LinearIssue> {
+        if self.linear_flat_list.is_empty() {
+            return None;
+        }
+        let idx = self.linear_index.min(self.linear_flat_list.len() - 1);
+        match &self.linear_flat_list[idx] {
+            FlatLinearItem::Issue(issue) => Some(issue),
+            _ => None,
+        }
+    }
+
+    pub fn linear_open_selected(&self) {
+        if let Some(issue) = self.linear_selected_issue() {
+            if !issue.url.is_empty() {
+                cli_detect::open_url(&issue.url);
+            }
+        }
+    }
+
+    // --- Azure DevOps helpers ---
+
+    pub fn load_azure_items(&mut self) {
+        if !self.has_azure {
+            return;
+        }
+        self.azure_last_poll = Instant::now();
+        let tx = match self.event_tx.clone() {
+            Some(tx) => tx,
+            None => return,
+        };
+        let organization = self
+            .project_config
+            .azure_organization()
+            .map(|s| s.to_string());
+        let project = self.project_config.azure_project().map(|s| s.to_string());
+        let custom_wiql = self.project_config.azure_wiql().map(|s| s.to_string());
+        std::thread::spawn(move || {
+            let result = azure::search_my_work_items(
+                organization.as_deref(),
+                project.as_deref(),
+                custom_wiql.as_deref(),
+            )
+            .map_err(|e| e.to_string());
+            let _ = tx.send(AppEvent::AzureItemsLoaded(result));
+        });
+    }
+
+    pub fn handle_azure_items_loaded(&mut self, result: Result<Vec<WorkItem>, String>) {
+        self.loaded_tabs.insert(ActiveTab::Azure);
+        match result {
+            Ok(items) => {
+                self.azure_flat_list = azure::categorize_work_items(&items);
+                let open_ids = items
+                    .iter()
+                    .filter(|i| !i.is_closed())
+                    .map(|i| i.key())
+                    .collect();
+                self.record_snapshot(snapshot::TRACKER_AZURE, open_ids);
+                self.azure_items = items;
+                if self.azure_index >= self.azure_flat_list.len() {
+                    self.azure_index = 0;
+                }
+                self.azure_skip_to_item_entry();
+                self.poll_succeeded(ActiveTab::Azure);
+            }
+            Err(e) => {
+                if self.poll_failed(ActiveTab::Azure, &e) {
+                    self.last_error = Some(format!("Azure: {}", e));
+                }
+            }
+        }
+    }
+
+    /// Fetch the selected work item's description and comments in the background.
+    fn azure_load_detail(&mut self) {
+        let Some(item) = self.azure_selected_item() else {
+            return;
+        };
+        let tx = match self.event_tx.clone() {
+            Some(tx) => tx,
+            None => return,
+        };
+        let id = item.id;
+        let project = item.project.clone();
+        let organization = self
+            .project_config
+            .azure_organization()
+            .map(|s| s.to_string());
+        self.azure_detail_loading = true;
+        std::thread::spawn(move || {
+            let result = azure::view_work_item(organization.as_deref(), id)
+                .map(|detail| {
+                    // Comments are a preview API; show the item without them on failure
+                    let comments = azure::list_comments(organization.as_deref(), &project, id)
+                        .unwrap_or_default();
+                    (detail, comments)
+                })
+                .map_err(|e| e.to_string());
+            let _ = tx.send(AppEvent::AzureDetailLoaded(result));
+        });
+    }
+
+    pub fn handle_azure_detail_loaded(
+        &mut self,
+        result: Result<(WorkItem, Vec<WorkItemComment>), String>,
+    ) {
+        self.azure_detail_loading = false;
+        match result {
+            Ok((detail, comments)) => {
+                self.azure_detail = Some(detail);
+                self.azure_comments = comments;
+                self.azure_detail_scroll = 0;
+            }
+            Err(e) => {
+                self.last_error = Some(format!("Azure detail: {}", e));
+            }
+        }
+    }
+
+    pub fn azure_load_transitions(&mut self) {
+        if let Some(item) = self.azure_selected_item() {
+            self.azure_transitions = azure::get_state_options(&item.state);
+            self.azure_show_transitions = true;
+        }
+    }
+
+    pub fn azure_do_transition(&mut self, idx: usize) {
+        if idx >= self.azure_transitions.len() {
+            return;
+        }
+        let state = self.azure_transitions[idx].clone();
+        self.azure_show_transitions = false;
+        if let Some(item) = self.azure_selected_item() {
+            let organization = self.project_config.azure_organization();
+            match azure::update_state(organization, item.id, &state) {
+                Ok(()) => {
+                    self.azure_detail = None;
+                    self.load_azure_items();
+                }
+                Err(e) => {
+                    self.last_error = Some(format!("Transition: {}", e));
+                }
+            }
+        }
+    }
+
+    fn azure_skip_to_next_item(&mut self) {
+        if self.azure_flat_list.is_empty() {
+            return;
+        }
+        let start = self.azure_index + 1;
+        for i in start..self.azure_flat_list.len() {
+            if matches!(self.azure_flat_list[i], FlatAzureItem::WorkItem(_)) {
+                self.azure_index = i;
+                self.azure_detail_scroll = 0;
+                return;
+            }
+        }
+    }
+
+    fn azure_skip_to_prev_item(&mut self) {
+        if self.azure_index == 0 || self.azure_flat_list.is_empty() {
+            return;
+        }
+        for i in (0..self.azure_index).rev() {
+            if matches!(self.azure_flat_list[i], FlatAzureItem::WorkItem(_)) {
+                self.azure_index = i;
+                self.azure_detail_scroll = 0;
+                return;
+            }
+        }
+    }
+
+    fn azure_skip_to_item_entry(&mut self) {
+        if self.azure_flat_list.is_empty() {
+            return;
+        }
+        let idx = self.azure_index.min(self.azure_flat_list.len() - 1);
+        if matches!(self.azure_flat_list[idx], FlatAzureItem::StateHeader(_)) {
+            for i in (idx + 1)..self.azure_flat_list.len() {
+                if matches!(self.azure_flat_list[i], FlatAzureItem::WorkItem(_)) {
+                    self.azure_index = i;
+                    return;
+                }
+            }
         }
     }
 
-    pub fn linear_selected_issue(&self) -> Option<&LinearIssue> {
-        if self.linear_flat_list.is_empty() {
+    pub fn azure_selected_item(&self) -> Option<&WorkItem> {
+        if self.azure_flat_list.is_empty() {
             return None;
         }
-        let idx = self.linear_index.min(self.linear_flat_list.len() - 1);
-        match &self.linear_flat_list[idx] {
-            FlatLinearItem::Issue(issue) => Some(issue),
+        let idx = self.azure_index.min(self.azure_flat_list.len() - 1);
+        match &self.azure_flat_list[idx] {
+            FlatAzureItem::WorkItem(item) => Some(item),
             _ => None,
         }
     }
 
-    pub fn linear_open_selected(&self) {
-        if let Some(issue) = self.linear_selected_issue() {
-            if !issue.url.is_empty() {
-                cli_detect::open_url(&issue.url);
+    pub fn azure_open_selected(&self) {
+        if let Some(item) = self.azure_selected_item() {
+            if !item.url.is_empty() {
+                cli_detect::open_url(&item.url);
             }
         }
     }
@@ -3105,13 +11389,19 @@ impl App {
             None => return,
         };
 
-        let selected_id = self.selected_process().map(|p| p.id);
+        let selected = self
+            .selected_process()
+            .map(|p| (p.id, p.follow, p.output_line_count()));
+        let selected_id = selected.map(|(id, _, _)| id);
         let mut got_output_for_selected = false;
+        let mut linked = Vec::new();
 
         while let Ok(msg) = rx.try_recv() {
+            self.dirty.content = true;
             match msg {
                 ProcessOutput::Stdout(id, line) => {
                     if let Some(proc) = self.processes.iter_mut().find(|p| p.id == id) {
+                        linked.extend(link_artifacts(proc, &line));
                         proc.output_lines.push_back(line.clone());
                         if proc.output_lines.len() > MAX_PROCESS_OUTPUT_LINES {
                             proc.output_lines.pop_front();
@@ -3131,6 +11421,7 @@ impl App {
                 }
                 ProcessOutput::Stderr(id, line) => {
                     if let Some(proc) = self.processes.iter_mut().find(|p| p.id == id) {
+                        linked.extend(link_artifacts(proc, &line));
                         proc.error_lines.push_back(line);
                         if proc.error_lines.len() > MAX_PROCESS_OUTPUT_LINES {
                             proc.error_lines.pop_front();
@@ -3143,9 +11434,16 @@ impl App {
             }
         }
 
-        // Auto-scroll to bottom if follow mode is on and new output arrived
-        if self.process_follow && got_output_for_selected {
-            self.process_output_scroll = usize::MAX;
+        self.record_ticket_notes(linked);
+
+        // Following stays at the bottom; otherwise keep the same lines in view
+        if let Some((_, follow, before)) = selected.filter(|_| got_output_for_selected) {
+            if follow {
+                self.process_output_scroll = 0;
+            } else if let Some(proc) = self.selected_process() {
+                let added = proc.output_line_count().saturating_sub(before);
+                self.process_output_scroll += added;
+            }
         }
 
         // Check for exited children
@@ -3153,29 +11451,243 @@ impl App {
         for (id, child) in &mut self.process_children {
             match child.try_wait() {
                 Ok(Some(status)) => {
-                    exited.push((*id, status.success()));
+                    exited.push((*id, child.id(), status.success(), status.code()));
                 }
                 Ok(None) => {} // still running
                 Err(_) => {
-                    exited.push((*id, false));
+                    exited.push((*id, child.id(), false, None));
                 }
             }
         }
-        for (id, success) in exited {
+        if !exited.is_empty() {
+            self.dirty.content = true;
+            self.process_records
+                .retain(|r| !exited.iter().any(|(_, pid, _, _)| *pid == r.pid));
+            self.save_process_registry();
+        }
+        let mut drafted = None;
+        for (id, _, success, code) in exited {
+            if self
+                .pr_draft_run
+                .as_ref()
+                .is_some_and(|(run, _)| *run == id)
+            {
+                drafted = Some(id);
+            }
             if let Some(proc) = self.processes.iter_mut().find(|p| p.id == id) {
-                if proc.status == ProcessStatus::Running {
-                    proc.status = if success {
-                        ProcessStatus::Completed
-                    } else {
-                        ProcessStatus::Failed
-                    };
+                proc.finished_at = Some(chrono::Utc::now());
+                proc.exit_code = code;
+                let tx = self.event_tx.clone();
+                if let Some(tx) = tx.filter(|_| proc.tree_before.is_some()) {
+                    let cwd = proc.cwd.clone();
+                    std::thread::spawn(move || {
+                        let tree = git::snapshot_tree(&cwd).ok();
+                        let _ = tx.send(AppEvent::ProcessTreeAfter(id, tree));
+                    });
+                }
+                match proc.status {
+                    ProcessStatus::Running if success => proc.status = ProcessStatus::Completed,
+                    ProcessStatus::Running | ProcessStatus::Terminating => {
+                        proc.status = ProcessStatus::Failed
+                    }
+                    _ => {}
                 }
+                let outcome = if proc.status == ProcessStatus::Completed {
+                    "finished"
+                } else {
+                    "failed"
+                };
+                let notification = Notification {
+                    event: NotificationEvent::ProcessFinished,
+                    title: format!("{} {}", proc.label, outcome),
+                    detail: proc.title.clone(),
+                };
+                self.notify(notification);
             }
             self.process_children.retain(|(pid, _)| *pid != id);
+            self.process_terminating.retain(|(pid, _)| *pid != id);
+        }
+        if let Some(id) = drafted {
+            self.open_pr_draft(id);
+        }
+        self.start_queued_processes();
+
+        // Escalate stops that outlived their grace period
+        let now = Instant::now();
+        for (id, deadline) in &self.process_terminating {
+            if now < *deadline {
+                continue;
+            }
+            if let Some((_, group)) = self.process_children.iter_mut().find(|(pid, _)| pid == id) {
+                group.kill();
+            }
+        }
+    }
+
+    /// Kill the orphaned process groups found at startup.
+    pub fn kill_orphan_processes(&mut self) {
+        let orphans = std::mem::take(&mut self.orphan_processes);
+        // Checked again: a group may have exited, and its pid been reused,
+        // while the prompt was open
+        for record in &orphans {
+            if process_registry::is_same_group(
+                record,
+                process_group::group_alive,
+                process_group::process_started,
+            ) {
+                process_group::kill_group(record.pid);
+            }
+        }
+        self.save_process_registry();
+        self.send_status = Some((
+            format!("Killed {} orphaned process group(s)", orphans.len()),
+            Instant::now(),
+        ));
+    }
+
+    /// Leave the orphaned process groups running and stop tracking them.
+    pub fn dismiss_orphan_processes(&mut self) {
+        self.orphan_processes.clear();
+        self.save_process_registry();
+    }
+
+    /// Persist live and orphaned process groups so a later run can find
+    /// anything this one fails to clean up.
+    fn save_process_registry(&mut self) {
+        let records: Vec<ProcessRecord> = self
+            .process_records
+            .iter()
+            .chain(&self.orphan_processes)
+            .cloned()
+            .collect();
+        if let Err(e) = process_registry::save_registry(&self.process_registry_path, &records) {
+            self.last_error = Some(format!("Process registry: {}", e));
         }
     }
 
     /// Get the currently selected process.
+    /// Add artifacts processes just linked to the notes of the tickets they
+    /// were launched from, and say what was linked.
+    fn record_ticket_notes(&mut self, linked: Vec<(String, TicketNote)>) {
+        let mut added = Vec::new();
+        for (ticket_url, note) in linked {
+            let summary = format!("{} {}", note.artifact.kind.label(), note.artifact.reference);
+            if !ticket_url.is_empty() && self.ticket_notes.add(&ticket_url, note) {
+                added.push(summary);
+            }
+        }
+        if added.is_empty() {
+            return;
+        }
+        if let Err(e) = ticket_notes::save_notes(&self.ticket_notes_path, &self.ticket_notes) {
+            self.last_error = Some(format!("Ticket notes: {}", e));
+        }
+        self.send_status = Some((format!("Linked {}", added.join(", ")), Instant::now()));
+    }
+
+    /// Artifacts of the selected process, newest last.
+    pub fn selected_artifacts(&self) -> &[artifact::Artifact] {
+        self.selected_process()
+            .map(|p| p.artifacts.as_slice())
+            .unwrap_or(&[])
+    }
+
+    /// Move the artifact selection of the selected process, wrapping around.
+    pub fn process_cycle_artifact(&mut self) {
+        let count = self.selected_artifacts().len();
+        if count > 0 {
+            self.process_artifact_index = (self.process_artifact_index + 1) % count;
+        }
+    }
+
+    /// Open the selected artifact of the selected process in the browser.
+    pub fn process_open_artifact(&mut self) {
+        let artifacts = self.selected_artifacts();
+        match artifacts.get(
+            self.process_artifact_index
+                .min(artifacts.len().saturating_sub(1)),
+        ) {
+            Some(artifact) => cli_detect::open_url(&artifact.url),
+            None => {
+                self.send_status = Some(("No artifacts linked yet".to_string(), Instant::now()));
+            }
+        }
+    }
+
+    /// Start sampling CPU and memory of running processes and the Claude
+    /// Code pane when a sample is due. Nothing is sampled while no process
+    /// runs outside two-pane mode.
+    pub fn sample_process_usage(&mut self) {
+        let spawned: Vec<(usize, u32)> = self
+            .process_children
+            .iter()
+            .map(|(id, group)| (*id, group.id()))
+            .collect();
+        if spawned.is_empty() && !self.two_pane {
+            self.process_usage.clear();
+            self.pane_usage = None;
+            return;
+        }
+        let interval = if self.low_power {
+            process_usage::USAGE_SAMPLE_SECS * 4
+        } else {
+            process_usage::USAGE_SAMPLE_SECS
+        };
+        if self
+            .usage_sampled
+            .is_some_and(|t| t.elapsed() < Duration::from_secs(interval))
+        {
+            return;
+        }
+        let Some(tx) = self.event_tx.clone() else {
+            return;
+        };
+        // The last sample is still being taken
+        if self.usage_sampler.try_lock().is_err() {
+            return;
+        }
+        self.usage_sampled = Some(Instant::now());
+        let sampler = Arc::clone(&self.usage_sampler);
+        let project = self.project_cwd.clone();
+        std::thread::spawn(move || {
+            let Ok(mut sampler) = sampler.lock() else {
+                return;
+            };
+            let sample = sampler.sample(&spawned, &project);
+            let _ = tx.send(AppEvent::ProcessUsageSampled(sample));
+        });
+    }
+
+    pub fn handle_process_usage_sampled(&mut self, sample: UsageSample) {
+        self.process_usage = sample.processes;
+        // Processes that exited while the sample was taken
+        self.process_usage
+            .retain(|id, _| self.process_children.iter().any(|(pid, _)| pid == id));
+        self.pane_usage = sample.pane;
+    }
+
+    /// Combined usage of running processes and the Claude Code pane.
+    pub fn total_process_usage(&self) -> ProcessUsage {
+        let mut total = self.pane_usage.unwrap_or_default();
+        for usage in self.process_usage.values() {
+            total.add(*usage);
+        }
+        total
+    }
+
+    /// Whether combined usage crosses `processes.cpu_warn_percent` or
+    /// `processes.memory_warn_mb`.
+    pub fn process_usage_high(&self) -> bool {
+        let total = self.total_process_usage();
+        self.project_config
+            .process_cpu_warn()
+            .is_some_and(|percent| total.cpu >= percent as f32)
+            || self
+                .project_config
+                .process_memory_warn_mb()
+                .is_some_and(|mb| total.memory >= mb * 1024 * 1024)
+    }
+
     pub fn selected_process(&self) -> Option<&SpawnedProcess> {
         if self.processes.is_empty() {
             return None;
@@ -3184,33 +11696,165 @@ impl App {
         Some(&self.processes[idx])
     }
 
-    /// Kill the currently selected process.
-    pub fn kill_selected_process(&mut self) {
+    /// Ask where to export the selected run's report, once it has finished.
+    pub fn start_process_report(&mut self) {
+        match self.selected_process() {
+            Some(proc) if proc.is_finished() => self.process_report_prompt = true,
+            Some(_) => {
+                self.last_error = Some("Report: the run hasn't finished yet".to_string());
+            }
+            None => {}
+        }
+    }
+
+    /// Render the selected run's report, cut to `max_chars` when given.
+    fn selected_process_report(&self, max_chars: Option<usize>) -> Option<String> {
+        let proc = self.selected_process()?;
+        let diff = match (&proc.tree_before, &proc.tree_after) {
+            (Some(before), Some(after)) => git::diff_snapshots(&proc.cwd, before, after).ok(),
+            _ => None,
+        };
+        Some(run_report::render(proc, diff.as_deref(), max_chars))
+    }
+
+    /// Save the selected run's report as Markdown under `processes.report_dir`.
+    pub fn save_process_report(&mut self) {
+        self.process_report_prompt = false;
+        let (Some(proc), Some(report)) =
+            (self.selected_process(), self.selected_process_report(None))
+        else {
+            return;
+        };
+        let dir = self
+            .project_cwd
+            .join(self.project_config.process_report_dir());
+        let path = run_report::report_path(&dir, proc);
+        match run_report::write_report(&path, &report) {
+            Ok(()) => {
+                let shown = path.strip_prefix(&self.project_cwd).unwrap_or(&path);
+                self.send_status = Some((
+                    format!("Saved run report to {}", shown.display()),
+                    Instant::now(),
+                ));
+            }
+            Err(e) => self.last_error = Some(format!("Report: {}", e)),
+        }
+    }
+
+    /// Post the selected run's report as a comment on its ticket.
+    pub fn post_process_report(&mut self) {
+        self.process_report_prompt = false;
+        let Some(proc) = self.selected_process() else {
+            return;
+        };
+        if run_report::comment_target(&proc.source).is_none() {
+            return;
+        }
+        let Some(report) = self.selected_process_report(Some(run_report::COMMENT_MAX_CHARS)) else {
+            return;
+        };
+        let Some(tx) = self.event_tx.clone() else {
+            return;
+        };
+        let source = proc.source.clone();
+        let label = proc.label.clone();
+        let url = proc.ticket_url.clone();
+        let api_key = match self.project_config.linear_api_key() {
+            Some(k) => Some(k.to_string()),
+            None if cli_fixtures::replaying() => Some(String::new()),
+            None => None,
+        };
+        self.send_status = Some((
+            format!("Posting run report on {}...", label),
+            Instant::now(),
+        ));
+        std::thread::spawn(move || {
+            let result =
+                run_report::post_comment(&source, &label, &url, api_key.as_deref(), &report)
+                    .map_err(|e| e.to_string());
+            let _ = tx.send(AppEvent::ProcessReportPosted(label, result));
+        });
+    }
+
+    pub fn handle_process_report_posted(&mut self, label: String, result: Result<(), String>) {
+        match result {
+            Ok(()) => {
+                self.send_status =
+                    Some((format!("Posted run report on {}", label), Instant::now()));
+            }
+            Err(e) => self.last_error = Some(format!("Report: {}", e)),
+        }
+    }
+
+    fn set_process_follow(&mut self, follow: bool) {
         if self.processes.is_empty() {
             return;
         }
         let idx = self.process_index.min(self.processes.len() - 1);
-        let id = self.processes[idx].id;
+        self.processes[idx].follow = follow;
+        if follow {
+            self.process_output_top = None;
+        }
+    }
 
-        if self.processes[idx].status != ProcessStatus::Running {
+    /// Show a newly selected process's output from the bottom, without the
+    /// previous one's search position.
+    fn reset_process_output(&mut self) {
+        self.process_output_scroll = 0;
+        self.process_output_top = None;
+        self.process_output_mark = None;
+    }
+
+    /// Stop the currently selected process and everything it spawned.
+    ///
+    /// The first press asks the process group to exit and marks it
+    /// Terminating; it is force-killed once the configured timeout passes,
+    /// or immediately if stopped again. A queued process is removed.
+    pub fn kill_selected_process(&mut self) {
+        if self.processes.is_empty() {
             return;
         }
+        let idx = self.process_index.min(self.processes.len() - 1);
+        if self.processes[idx].status == ProcessStatus::Queued {
+            let cancelled = self.processes.remove(idx);
+            self.process_index = self
+                .process_index
+                .min(self.processes.len().saturating_sub(1));
+            self.reset_process_output();
+            self.send_status = Some((format!("Cancelled {}", cancelled.label), Instant::now()));
+            return;
+        }
+        let id = self.processes[idx].id;
+        let Some((_, group)) = self.process_children.iter_mut().find(|(pid, _)| *pid == id) else {
+            return;
+        };
 
-        if let Some(pos) = self
-            .process_children
-            .iter_mut()
-            .position(|(pid, _)| *pid == id)
-        {
-            kill_process_tree(self.process_children[pos].1.id());
-            self.process_children.remove(pos);
+        match self.processes[idx].status {
+            ProcessStatus::Running => {
+                group.terminate();
+                let deadline = Instant::now() + self.project_config.terminate_timeout();
+                self.process_terminating.push((id, deadline));
+                self.processes[idx].status = ProcessStatus::Terminating;
+                self.send_status = Some((
+                    format!("Terminating {}...", self.processes[idx].label),
+                    Instant::now(),
+                ));
+            }
+            ProcessStatus::Terminating => {
+                group.kill();
+                self.send_status = Some((
+                    format!("Killed {}", self.processes[idx].label),
+                    Instant::now(),
+                ));
+            }
+            _ => {}
         }
-        self.processes[idx].status = ProcessStatus::Failed;
     }
 
     /// Jump to the Sessions tab and load the transcript for the selected process's session.
-    /// Open the currently selected session in a new Windows Terminal pane
-    /// running `claude --resume <session_id>`.
-    pub fn open_session_in_wt(&mut self) {
+    /// Open the currently selected session in a new terminal pane running
+    /// `claude --resume <session_id>`.
+    pub fn open_session_in_pane(&mut self) {
         if self.sessions.is_empty() {
             return;
         }
@@ -3225,32 +11869,76 @@ impl App {
             .unwrap_or_else(|| self.project_cwd.to_str().unwrap_or("."))
             .to_string();
 
-        let result = Command::new("wt.exe")
-            .args([
-                "split-pane",
-                "-d",
-                &cwd,
-                "--",
-                "claude",
-                "--resume",
-                &session_id,
-            ])
+        let result = self
+            .terminal
+            .new_pane_command(&cwd, &["claude", "--resume", &session_id])
             .status();
 
+        let program = self.terminal.program();
         match result {
             Ok(s) if s.success() => {}
             Ok(s) => {
-                self.last_error = Some(format!("wt.exe exited with {}", s));
+                self.last_error = Some(format!("{} exited with {}", program, s));
             }
             Err(e) => {
                 self.last_error = Some(format!(
-                    "Failed to run wt.exe: {}. Is Windows Terminal installed?",
-                    e
+                    "Failed to run {}: {}. Is {} installed?",
+                    program,
+                    e,
+                    self.terminal.label()
                 ));
             }
         }
     }
 
+    /// Ask to resume the selected session with `claude --resume`.
+    pub fn request_resume_session(&mut self) {
+        let Some(session) = self.sessions.get(self.session_list_index) else {
+            return;
+        };
+        let cwd = session
+            .project_path
+            .as_ref()
+            .map(PathBuf::from)
+            .unwrap_or_else(|| self.project_cwd.clone());
+        self.resume_target = Some(ResumeTarget {
+            session_id: session.session_id.clone(),
+            title: session.display_title(),
+            branch: session.branch().to_string(),
+            cwd,
+        });
+    }
+
+    /// Answer the resume prompt. In two-pane mode the command is typed into
+    /// the Claude Code pane; otherwise `assoc launch` opens it in a new tab.
+    pub fn confirm_resume_session(&mut self, resume: bool) {
+        let Some(target) = self.resume_target.take() else {
+            return;
+        };
+        if !resume {
+            return;
+        }
+
+        if self.two_pane {
+            let Some(ref tx) = self.event_tx else {
+                return;
+            };
+            self.send_pending = true;
+            let text = format!("claude --resume {}", target.session_id);
+            let route = self.pane_route(CLAUDE_PANE);
+            crate::pane_send::send_to_pane(self.terminal, text, route, tx.clone());
+            return;
+        }
+
+        let args = vec![
+            "--cwd".into(),
+            target.cwd.into(),
+            "--resume".into(),
+            target.session_id.into(),
+        ];
+        self.run_assoc_launch(args, format!("Resuming {}", target.title));
+    }
+
     pub fn jump_to_process_session(&mut self) {
         let sid = match self
             .selected_process()
@@ -3278,6 +11966,138 @@ impl App {
     }
 }
 
+/// Integration availability derived from the project config and installed CLIs.
+struct Integrations {
+    has_gh: bool,
+    has_jira: bool,
+    has_linear: bool,
+    has_azure: bool,
+    gh_remotes: Vec<GitRemote>,
+    gh_repo: Option<String>,
+    gh_user: Option<String>,
+    gh_issues_repo: Option<String>,
+    gh_issues_enabled: bool,
+    prs_from_bitbucket: bool,
+    bb_repo: Option<String>,
+}
+
+/// Work out which integration tabs can be shown for `project_config`.
+/// `gh_remote` is the git remote picked for the GitHub tabs, if any.
+fn detect_integrations(
+    project_config: &ProjectConfig,
+    project_cwd: &Path,
+    known_gh_user: Option<String>,
+    gh_remote: Option<&str>,
+) -> Integrations {
+    // Skip CLI detection entirely when associated tabs are disabled
+    let prs_from_bitbucket = project_config.prs_from_bitbucket();
+    let gh_tabs_wanted = (project_config.tabs.github_prs() && !prs_from_bitbucket)
+        || project_config.tabs.github_issues();
+    let has_gh = gh_tabs_wanted && cli_detect::is_available("gh");
+    let has_jira = project_config.tabs.jira() && cli_detect::is_available("acli");
+    let has_linear = project_config.tabs.linear()
+        && (project_config.linear_api_key().is_some()
+            || cli_fixtures::has_replay_fixtures("linear"));
+    // az is common for unrelated Azure work, so the tab also needs an [azure] section
+    let has_azure = project_config.tabs.azure()
+        && project_config.azure.is_some()
+        && cli_detect::is_available(azure::AZ);
+    let gh_remotes = if has_gh {
+        cli_detect::list_gh_remotes(project_cwd)
+    } else {
+        Vec::new()
+    };
+    // Config github.repo overrides the picked or detected git remote
+    let gh_repo = project_config
+        .github_repo()
+        .map(String::from)
+        .or_else(|| cli_detect::pick_repo(&gh_remotes, gh_remote));
+    // gh_user needs a network call, so it is looked up in the background by
+    // `App::detect_gh_user`; keep a known value across reloads
+    let gh_user = known_gh_user.filter(|_| has_gh);
+
+    // Determine issues repo: config issues.repo > config github.repo > git remote
+    let gh_issues_repo = project_config
+        .github_issues_repo()
+        .map(String::from)
+        .or_else(|| gh_repo.clone());
+
+    // Show Issues tab if gh is available, repo is known, and config doesn't disable it.
+    // We don't pre-check hasIssuesEnabled — if issues can't be fetched, the tab shows an error.
+    let gh_issues_enabled =
+        has_gh && gh_issues_repo.is_some() && project_config.github_issues_enabled();
+
+    // Bitbucket PRs go through its REST API, so only the repo is needed here;
+    // missing credentials are reported when the tab loads
+    let bb_repo = if prs_from_bitbucket && project_config.tabs.github_prs() {
+        project_config
+            .bitbucket_repo()
+            .map(String::from)
+            .or_else(|| {
+                cli_detect::pick_repo(&cli_detect::list_bitbucket_remotes(project_cwd), None)
+            })
+    } else {
+        None
+    };
+
+    Integrations {
+        has_gh,
+        has_jira,
+        has_linear,
+        has_azure,
+        gh_remotes,
+        gh_repo,
+        gh_user,
+        gh_issues_repo,
+        gh_issues_enabled,
+        prs_from_bitbucket,
+        bb_repo,
+    }
+}
+
+/// Record artifacts linked in a line of `proc`'s output that it did not
+/// already have. Links that were in the prompt, such as the ticket itself,
+/// are left out. Returns notes for the originating ticket.
+/// An issue's body with GitHub's CRLF line endings made plain, as the
+/// editor holds it.
+fn issue_body(issue: &GitHubIssue) -> String {
+    issue
+        .body
+        .as_deref()
+        .unwrap_or_default()
+        .replace("\r\n", "\n")
+}
+
+fn link_artifacts(proc: &mut SpawnedProcess, line: &str) -> Vec<(String, TicketNote)> {
+    let mut notes = Vec::new();
+    for found in artifact::find_artifacts(line) {
+        if proc.prompt.contains(&found.url) || proc.artifacts.iter().any(|a| a.url == found.url) {
+            continue;
+        }
+        notes.push((
+            proc.ticket_url.clone(),
+            TicketNote {
+                at: chrono::Utc::now(),
+                process: format!("{} {}", proc.label, proc.title),
+                artifact: found.clone(),
+            },
+        ));
+        proc.artifacts.push(found);
+    }
+    notes
+}
+
+/// The final reply of a successful headless run, from its `result` event.
+fn stream_json_result(lines: &std::collections::VecDeque<String>) -> Option<String> {
+    lines.iter().rev().find_map(|line| {
+        let v: serde_json::Value = serde_json::from_str(line).ok()?;
+        if v.get("type")?.as_str()? != "result" || v.get("subtype")?.as_str()? != "success" {
+            return None;
+        }
+        Some(v.get("result")?.as_str()?.to_string())
+    })
+}
+
 /// Parse one line of `--output-format stream-json` output.
 ///
 /// Returns `Some((progress_lines, session_id))` if the event produced displayable
@@ -3469,7 +12289,7 @@ impl App {
     pub fn start_send_mode(&mut self) {
         if !self.two_pane {
             self.last_error =
-                Some("Pane send requires exactly 2 WT panes (use 'assoc launch')".to_string());
+                Some("Pane send requires two-pane mode (use 'assoc launch')".to_string());
             return;
         }
         self.send_mode = true;
@@ -3494,6 +12314,13 @@ impl App {
                 let issue = self.linear_selected_issue()?;
                 Some(format!("{} {}: ", issue.identifier, issue.title))
             }
+            ActiveTab::Azure => {
+                let item = self.azure_selected_item()?;
+                Some(format!("{} {}: ", item.key(), item.title))
+            }
+            ActiveTab::Teams if self.teams.is_empty() => {
+                Some("Create an agent team to ".to_string())
+            }
             _ => None,
         }
     }
@@ -3514,9 +12341,47 @@ impl App {
         self.send_input.clear();
 
         if let Some(ref tx) = self.event_tx {
-            let direction = self.project_config.send_direction();
-            crate::pane_send::send_to_claude_pane(text, direction, tx.clone());
+            let route = self.pane_route(&self.send_target);
+            crate::pane_send::send_to_pane(self.terminal, text, route, tx.clone());
+        }
+    }
+
+    /// How to reach the pane called `name`: by index when launched with a
+    /// pane registry, otherwise toward `pane.direction`.
+    pub fn pane_route(&self, name: &str) -> PaneRoute {
+        self.pane_registry
+            .as_ref()
+            .and_then(|registry| registry.route(name))
+            .unwrap_or_else(|| {
+                PaneRoute::Direction(self.project_config.send_direction().to_string())
+            })
+    }
+
+    /// Point the send bar at the next pane in the registry.
+    pub fn cycle_send_target(&mut self) {
+        let Some(ref registry) = self.pane_registry else {
+            return;
+        };
+        let targets = registry.targets();
+        if targets.len() < 2 {
+            return;
         }
+        let next = targets
+            .iter()
+            .position(|t| *t == self.send_target)
+            .map_or(0, |i| (i + 1) % targets.len());
+        self.send_target = targets[next].to_string();
+    }
+
+    /// Send the snippet at `index` instead of the typed text. False when
+    /// there is no such snippet.
+    pub fn send_snippet(&mut self, index: usize) -> bool {
+        let Some(snippet) = self.send_snippets.get(index) else {
+            return false;
+        };
+        self.send_input = snippet.clone();
+        self.execute_send();
+        true
     }
 
     pub fn handle_send_complete(&mut self, error: Option<String>) {
@@ -3528,6 +12393,161 @@ impl App {
         }
     }
 
+    /// Toggle the split between widening this pane and giving the width back
+    /// to the Claude Code pane.
+    pub fn toggle_pane_ratio(&mut self) {
+        if !self.two_pane {
+            self.last_error =
+                Some("Pane resize requires two-pane mode (use 'assoc launch')".to_string());
+            return;
+        }
+        let Some(ref tx) = self.event_tx else {
+            return;
+        };
+        self.pane_widened = !self.pane_widened;
+        crate::pane_send::resize_panes(
+            self.terminal,
+            self.pane_widened,
+            self.project_config.send_direction(),
+            self.project_config.pane_resize_steps(),
+            tx.clone(),
+        );
+    }
+
+    /// The terminal changed size: re-measure the list tables and the
+    /// transcript, and pull scroll offsets that point past their content
+    /// back in.
+    pub fn handle_resize(&mut self, list_table_width: u16, transcript_width: u16) {
+        self.list_table_width = list_table_width;
+        self.transcript_width = transcript_width;
+        self.transcript_scroll = self.transcript_scroll.min(self.transcript_items.len());
+        self.subagent_scroll = self.subagent_scroll.min(self.subagent_transcript.len());
+        self.diff_scroll = self
+            .diff_scroll
+            .min(self.git_diff_lines.len().saturating_sub(1));
+        let output_lines = self.selected_process().map_or(0, |p| p.output_line_count());
+        self.process_output_scroll = self.process_output_scroll.min(output_lines);
+        self.mark_dirty();
+    }
+
+    pub fn handle_resize_complete(&mut self, error: Option<String>) {
+        if let Some(e) = error {
+            // The split did not move, so the next toggle should retry
+            self.pane_widened = !self.pane_widened;
+            self.last_error = Some(format!("Resize: {}", e));
+        } else {
+            let msg = if self.pane_widened {
+                "Dashboard widened"
+            } else {
+                "Claude pane widened"
+            };
+            self.send_status = Some((msg.to_string(), Instant::now()));
+        }
+    }
+
+    /// Clean up before a signal or console close ends the app: keep the
+    /// text of any open editor as a draft and give owned process groups a
+    /// chance to exit before `Drop` kills what is left.
+    pub fn shutdown(&mut self) {
+        let unsaved = self.unsaved_drafts();
+        if let Err(e) = drafts::save_drafts(&self.drafts_dir, &unsaved, chrono::Local::now()) {
+            eprintln!("Failed to save drafts: {}", e);
+        }
+
+        let pids: Vec<u32> = self.process_children.iter().map(|(_, g)| g.id()).collect();
+        for (_, group) in &self.process_children {
+            group.terminate();
+        }
+        // Windows allows about five seconds after a console close
+        let wait = self
+            .project_config
+            .terminate_timeout()
+            .min(std::time::Duration::from_secs(3));
+        let deadline = Instant::now() + wait;
+        while Instant::now() < deadline {
+            self.process_children
+                .retain_mut(|(_, group)| matches!(group.try_wait(), Ok(None)));
+            if self.process_children.is_empty() {
+                break;
+            }
+            std::thread::sleep(std::time::Duration::from_millis(50));
+        }
+        let live: Vec<u32> = self.process_children.iter().map(|(_, g)| g.id()).collect();
+        self.process_records
+            .retain(|r| !pids.contains(&r.pid) || live.contains(&r.pid));
+        self.save_process_registry();
+    }
+
+    /// `(name, text)` of each open editor or input with something typed in
+    /// it; file editors are skipped when they match the file on disk.
+    fn unsaved_drafts(&self) -> Vec<(String, String)> {
+        let text = |editor: &Option<tui_textarea::TextArea<'static>>| {
+            editor.as_ref().map(|e| e.lines().join("\n"))
+        };
+        let on_disk = |path: &Path| std::fs::read_to_string(path).unwrap_or_default();
+        let mut drafts = Vec::new();
+
+        if let (true, Some(content), Some(path)) = (
+            self.fb_editing,
+            text(&self.fb_editor),
+            &self.fb_content_path,
+        ) {
+            if !same_lines(&content, &on_disk(path)) {
+                let name = path.file_name().unwrap_or_default().to_string_lossy();
+                drafts.push((format!("file-{}", name), content));
+            }
+        }
+        if let (true, Some(content)) = (self.plan_editing, text(&self.plan_editor)) {
+            if !same_lines(&content, &self.plan_edit_base) {
+                let name = self.plan_edit_filename.as_deref().unwrap_or("new");
+                drafts.push((format!("plan-{}", name), content));
+            }
+        }
+        if let (true, Some(content), Some(command)) = (
+            self.command_editing,
+            text(&self.command_editor),
+            self.selected_command(),
+        ) {
+            if !same_lines(&content, &on_disk(&command.path)) {
+                drafts.push((format!("command-{}", command.name), content));
+            }
+        }
+        if let (true, Some(content)) = (self.notes_editing, text(&self.notes_editor)) {
+            let path = notes::notes_path(&self.project_cwd);
+            if !same_lines(&content, &on_disk(&path)) {
+                drafts.push(("notes".to_string(), content));
+            }
+        }
+        if let Some(editor) = &self.task_editor {
+            let subject = editor.subject.lines().join(" ");
+            let description = editor.description.lines().join("\n");
+            drafts.push((
+                "task".to_string(),
+                format!("{}\n\n{}", subject.trim(), description.trim_end()),
+            ));
+        }
+        if self.gh_issues_editing {
+            let title = text(&self.gh_issues_title_editor).unwrap_or_default();
+            let body = text(&self.gh_issues_body_editor).unwrap_or_default();
+            drafts.push((
+                "issue".to_string(),
+                format!("{}\n\n{}", title.trim(), body.trim_end()),
+            ));
+        }
+        if let (true, Some(content)) = (self.show_prompt_modal, text(&self.prompt_editor)) {
+            drafts.push(("prompt".to_string(), content));
+        }
+        if self.todo_edit_mode.is_some() {
+            drafts.push(("todo".to_string(), self.todo_edit_input.clone()));
+        }
+        if self.send_mode {
+            drafts.push(("send".to_string(), self.send_input.clone()));
+        }
+
+        drafts.retain(|(_, content)| !content.trim().is_empty());
+        drafts
+    }
+
     /// Clear stale send status (after 3 seconds).
     pub fn clear_stale_send_status(&mut self) {
         if let Some((_, ts)) = &self.send_status {
@@ -3540,19 +12560,18 @@ impl App {
 
 impl Drop for App {
     fn drop(&mut self) {
-        for (_, child) in &mut self.process_children {
-            kill_process_tree(child.id());
-            let _ = child.wait();
+        for (_, group) in &mut self.process_children {
+            group.kill_and_wait();
+        }
+        if !self.process_children.is_empty() {
+            let pids: Vec<u32> = self.process_children.iter().map(|(_, g)| g.id()).collect();
+            self.process_records.retain(|r| !pids.contains(&r.pid));
+            self.save_process_registry();
         }
     }
 }
 
-/// Kill a process and its entire process tree on Windows using `taskkill /F /T`.
-/// Falls back silently if taskkill is not available.
-fn kill_process_tree(pid: u32) {
-    let _ = Command::new("taskkill")
-        .args(["/F", "/T", "/PID", &pid.to_string()])
-        .stdout(std::process::Stdio::null())
-        .stderr(std::process::Stdio::null())
-        .output();
+/// Compare two texts line by line, ignoring a trailing newline.
+fn same_lines(a: &str, b: &str) -> bool {
+    a.lines().eq(b.lines())
 }
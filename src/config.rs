@@ -1,10 +1,58 @@
+use std::collections::BTreeMap;
 use std::path::{Path, PathBuf};
+use std::time::Duration;
 
 use serde::Deserialize;
 
-/// Base path for all Claude Code data.
+use crate::data::linear::IssueQuery;
+use crate::model::list_table::{self, ListColumn};
+use crate::model::status_bar::{self, StatusBarLayout};
+use crate::pane_send::{is_pane_name, TerminalBackend, ASSOC_PANE, CLAUDE_PANE};
+
+/// Base path for all Claude Code data: `CLAUDE_CONFIG_DIR` when set,
+/// otherwise `~/.claude`.
 pub fn claude_home() -> PathBuf {
-    dirs_base().join(".claude")
+    match std::env::var("CLAUDE_CONFIG_DIR") {
+        Ok(dir) if !dir.trim().is_empty() => expand_home(dir.trim()),
+        _ => dirs_base().join(".claude"),
+    }
+}
+
+/// Expand a leading `~` to the user's home directory.
+fn expand_home(path: &str) -> PathBuf {
+    match path.strip_prefix('~') {
+        Some(rest) if rest.is_empty() || rest.starts_with(['/', '\\']) => {
+            dirs_base().join(rest.trim_start_matches(['/', '\\']))
+        }
+        _ => PathBuf::from(path),
+    }
+}
+
+/// A Claude home directory whose sessions, teams and todos are shown.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ClaudeHome {
+    pub path: PathBuf,
+    /// Source label shown next to items from this home. Empty for the
+    /// primary home.
+    pub label: String,
+}
+
+impl ClaudeHome {
+    fn extra(path: PathBuf) -> Self {
+        // "~/box/.claude" reads better as "box" than ".claude"
+        let name = |p: &Path| p.file_name().map(|n| n.to_string_lossy().to_string());
+        let label = match name(&path) {
+            Some(n) if n.starts_with('.') => path.parent().and_then(name).unwrap_or(n),
+            Some(n) => n,
+            None => path.display().to_string(),
+        };
+        Self { path, label }
+    }
+}
+
+/// Base path for data that assoc keeps for itself.
+pub fn assoc_home() -> PathBuf {
+    dirs_base().join(".assoc")
 }
 
 fn dirs_base() -> PathBuf {
@@ -14,30 +62,114 @@ fn dirs_base() -> PathBuf {
         .unwrap_or_else(|_| PathBuf::from("."))
 }
 
+/// A poll interval as used: stretched in low-power mode.
+pub fn effective_poll(interval: Option<Duration>, low_power: bool) -> Option<Duration> {
+    if low_power {
+        interval.map(|i| i * LOW_POWER_POLL_FACTOR)
+    } else {
+        interval
+    }
+}
+
 /// How often the tick event fires (ms).
 pub const TICK_RATE_MS: u64 = 250;
 
+/// Most frames drawn per second, however fast keys and file changes arrive.
+pub const MAX_FPS: u32 = 30;
+
+/// Tick rate in low-power mode, unless `display.tick_rate` is slower (ms).
+const LOW_POWER_TICK_RATE_MS: u64 = 1_000;
+
+/// Poll intervals are multiplied by this in low-power mode.
+const LOW_POWER_POLL_FACTOR: u32 = 4;
+
+/// How often the battery is checked when `display.low_power` is "auto" (seconds).
+pub const POWER_CHECK_SECS: u64 = 60;
+
 /// File watcher debounce interval (ms).
 pub const DEBOUNCE_MS: u64 = 200;
 
+/// Quiet time after the last git or working-tree change before the Git tab
+/// reloads, so a burst of edits costs one `git status` (ms).
+pub const GIT_REFRESH_DEBOUNCE_MS: u64 = 500;
+
+/// How often `assoc serve` rebuilds its dashboard without a file change,
+/// for processes exiting and agents going idle (seconds).
+pub const SERVE_REFRESH_SECS: u64 = 10;
+
+/// Least time between `assoc serve` rebuilds while files change (ms).
+pub const SERVE_MIN_GAP_MS: u64 = 2_000;
+
+/// Most working-tree directories watched for diff follow mode.
+pub const WORKTREE_WATCH_MAX_DIRS: usize = 2_000;
+
 /// How many lines to load from end of JSONL on initial read.
 pub const JSONL_TAIL_LINES: usize = 200;
 
+/// Default poll interval for the PRs / Issues / Jira / Linear / Work Items tabs (seconds).
+pub const POLL_SECONDS: u64 = 60;
+
+/// Shortest poll interval allowed, to avoid hammering remote APIs (seconds).
+const MIN_POLL_SECONDS: u64 = 10;
+
+/// PRs and issues fetched per page when `github.page_size` is unset.
+pub const GITHUB_PAGE_SIZE: usize = 100;
+
+/// How long a stopped process gets to exit before it is force-killed (seconds).
+pub const TERMINATE_TIMEOUT_SECS: u64 = 5;
+
+/// Directory run reports are saved in when `processes.report_dir` is unset.
+const DEFAULT_REPORT_DIR: &str = "run-reports";
+
+/// Largest file deleted with undo instead of a confirmation (KB).
+const UNDO_MAX_KB: u64 = 1024;
+
+/// WT resize steps the pane ratio toggle moves the split by.
+const PANE_RESIZE_STEPS: u32 = 5;
+
+/// Claude Code's plan approval menu: "Yes, and manually approve edits".
+const PLAN_ACCEPT_KEYS: &str = "2";
+
+/// Claude Code's plan approval menu: "No, keep planning".
+const PLAN_REJECT_KEYS: &str = "3";
+
+/// Quick-send snippets in the pane send bar when `pane.snippets` is unset.
+const DEFAULT_SEND_SNIPPETS: &[&str] = &["continue", "run the tests", "/compact"];
+
+/// Snippets get the keys 1-9, so later ones are dropped.
+const MAX_SEND_SNIPPETS: usize = 9;
+
 // ---------------------------------------------------------------------------
 // Project config (.assoc.toml)
 // ---------------------------------------------------------------------------
 
 #[derive(Debug, Default, Deserialize)]
 pub struct ProjectConfig {
+    pub prs: Option<PrsConfig>,
     pub github: Option<GithubConfig>,
+    pub bitbucket: Option<BitbucketConfig>,
     pub jira: Option<JiraConfig>,
     pub linear: Option<LinearConfig>,
+    pub azure: Option<AzureConfig>,
     pub display: Option<DisplayConfig>,
+    pub status_bar: Option<StatusBarConfig>,
+    pub polling: Option<PollingConfig>,
     #[serde(default)]
     pub tabs: TabsConfig,
     pub pane: Option<PaneConfig>,
+    pub translation: Option<TranslationConfig>,
+    pub deps: Option<DepsConfig>,
+    pub processes: Option<ProcessesConfig>,
+    pub claude: Option<ClaudeConfig>,
+    pub notifications: Option<NotificationsConfig>,
+    pub confirm: Option<ConfirmConfig>,
+    pub editor: Option<EditorConfig>,
+    pub commands: Option<CommandsConfig>,
     #[serde(default)]
     pub prompts: Vec<CustomPrompt>,
+    /// Named `assoc launch --profile` setups, from `[launch.<name>]`.
+    #[serde(default)]
+    pub launch: BTreeMap<String, LaunchProfile>,
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -51,18 +183,27 @@ pub struct CustomPrompt {
 /// entirely — its data won't be loaded, watched, or polled.
 #[derive(Debug, Clone, Default, Deserialize)]
 pub struct TabsConfig {
+    overview: Option<bool>,
     sessions: Option<bool>,
     teams: Option<bool>,
     todos: Option<bool>,
     git: Option<bool>,
     plans: Option<bool>,
+    commands: Option<bool>,
+    hooks: Option<bool>,
+    settings: Option<bool>,
+    notes: Option<bool>,
     github_prs: Option<bool>,
     github_issues: Option<bool>,
     jira: Option<bool>,
     linear: Option<bool>,
+    azure: Option<bool>,
 }
 
 impl TabsConfig {
+    pub fn overview(&self) -> bool {
+        self.overview.unwrap_or(true)
+    }
     pub fn sessions(&self) -> bool {
         self.sessions.unwrap_or(true)
     }
@@ -78,6 +219,18 @@ impl TabsConfig {
     pub fn plans(&self) -> bool {
         self.plans.unwrap_or(true)
     }
+    pub fn commands(&self) -> bool {
+        self.commands.unwrap_or(true)
+    }
+    pub fn hooks(&self) -> bool {
+        self.hooks.unwrap_or(true)
+    }
+    pub fn settings(&self) -> bool {
+        self.settings.unwrap_or(true)
+    }
+    pub fn notes(&self) -> bool {
+        self.notes.unwrap_or(true)
+    }
     pub fn github_prs(&self) -> bool {
         self.github_prs.unwrap_or(true)
     }
@@ -90,11 +243,27 @@ impl TabsConfig {
     pub fn linear(&self) -> bool {
         self.linear.unwrap_or(true)
     }
+    pub fn azure(&self) -> bool {
+        self.azure.unwrap_or(true)
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct PrsConfig {
+    /// Where the PRs tab reads pull requests from: "github" (default) or
+    /// "bitbucket".
+    pub provider: Option<String>,
+    /// Columns of the PR table, in order, e.g. ["number", "title", "ci"].
+    pub columns: Option<Vec<String>>,
 }
 
 #[derive(Debug, Deserialize)]
 pub struct GithubConfig {
     pub repo: Option<String>,
+    /// Poll interval for the PRs and Issues tabs. 0 disables polling.
+    pub poll_seconds: Option<u64>,
+    /// PRs and issues fetched per page; the next page loads past the end.
+    pub page_size: Option<usize>,
     pub issues: Option<GithubIssuesConfig>,
 }
 
@@ -106,12 +275,42 @@ pub struct GithubIssuesConfig {
     pub repo: Option<String>,
     /// Issue state filter: "open", "closed", or "all". Default: "open".
     pub state: Option<String>,
+    /// Columns of the issue table, in order.
+    pub columns: Option<Vec<String>>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct BitbucketConfig {
+    /// Repo as "workspace/repo". Detected from a bitbucket.org git remote
+    /// when unset.
+    pub repo: Option<String>,
+    /// Bitbucket username the app password belongs to.
+    pub username: Option<String>,
+    pub app_password: Option<String>,
+    /// Poll interval for the PRs tab. 0 disables polling.
+    pub poll_seconds: Option<u64>,
 }
 
 #[derive(Debug, Deserialize)]
 pub struct JiraConfig {
     pub project: Option<String>,
     pub jql: Option<String>,
+    /// Named JQL queries for the issue list, from `[jira.filters]`,
+    /// switched between with `f`.
+    #[serde(default)]
+    pub filters: BTreeMap<String, String>,
+    /// Board whose active sprint populates the board view.
+    pub board_id: Option<u64>,
+    /// JQL for the board view when no `board_id` is set.
+    pub board_jql: Option<String>,
+    /// Poll interval for the Jira tab. 0 disables polling.
+    pub poll_seconds: Option<u64>,
+    /// Atlassian account email the API token belongs to.
+    pub email: Option<String>,
+    /// Environment variable holding the API token for attachment downloads
+    /// and web links, which acli doesn't provide. The token stays out of
+    /// the file, which is committed and exported.
+    pub api_token_env: Option<String>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -119,18 +318,283 @@ pub struct LinearConfig {
     pub api_key: Option<String>,
     pub username: Option<String>,
     pub team: Option<String>,
+    /// Only issues in this project.
+    pub project: Option<String>,
+    /// Only issues in this cycle: `current`, or a cycle number.
+    pub cycle: Option<String>,
+    /// Only issues in these workflow states, by name. Unset shows every
+    /// issue not completed or cancelled.
+    pub states: Option<Vec<String>>,
+    /// Poll interval for the Linear tab. 0 disables polling.
+    pub poll_seconds: Option<u64>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct AzureConfig {
+    /// Organization URL (e.g. "https://dev.azure.com/myorg"). Falls back to
+    /// the `az devops configure` default.
+    pub organization: Option<String>,
+    pub project: Option<String>,
+    pub wiql: Option<String>,
+    /// Poll interval for the Work Items tab. 0 disables polling.
+    pub poll_seconds: Option<u64>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ClaudeConfig {
+    /// Claude home to use instead of `CLAUDE_CONFIG_DIR` / `~/.claude`.
+    pub home: Option<String>,
+    /// Further homes whose sessions, teams and todos are merged in.
+    #[serde(default)]
+    pub extra_homes: Vec<String>,
 }
 
 #[derive(Debug, Deserialize)]
 pub struct DisplayConfig {
     pub tick_rate: Option<u64>,
+    /// Cap on frames drawn per second; bursts of changes share a frame.
+    pub max_fps: Option<u32>,
     pub tail_lines: Option<usize>,
+    /// "auto" (default): low-power mode while on battery. "always" or "never".
+    pub low_power: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct StatusBarConfig {
+    /// Segment names, in order, for each side of the status bar.
+    pub left: Option<Vec<String>>,
+    pub center: Option<Vec<String>>,
+    pub right: Option<Vec<String>>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct PollingConfig {
+    /// Set to false to turn off all background polling (manual `r` refresh still works).
+    pub enabled: Option<bool>,
 }
 
 #[derive(Debug, Deserialize)]
 pub struct PaneConfig {
+    /// Terminal to launch in and send to: "auto" (default), "wt", "zellij"
+    /// or "wezterm".
+    pub backend: Option<String>,
     /// Direction to move-focus to reach the Claude Code pane (right, left, up, down).
     pub direction: Option<String>,
+    /// Number of WT resize steps the ratio toggle (=) moves the split by.
+    pub resize_steps: Option<u32>,
+    /// Keys typed into the Claude Code pane to accept a reviewed plan.
+    pub plan_accept: Option<String>,
+    /// Keys typed into the Claude Code pane to reject a reviewed plan.
+    pub plan_reject: Option<String>,
+    /// Texts the send bar sends with one key: `1`-`9` on an empty bar,
+    /// `Alt+1`-`Alt+9` anytime.
+    pub snippets: Option<Vec<String>>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct TranslationConfig {
+    /// Shell command that reads text on stdin and writes the translation to stdout.
+    pub command: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct DepsConfig {
+    /// Dependency checkers to run on the Deps tab ("cargo", "npm").
+    #[serde(default)]
+    pub checkers: Vec<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ProcessesConfig {
+    /// Seconds to wait after a graceful stop before force-killing the process tree.
+    pub terminate_timeout_secs: Option<u64>,
+    /// Most processes running at once; further launches wait in a queue.
+    pub max_concurrent: Option<usize>,
+    /// Directory, relative to the project root, that run reports are saved in.
+    pub report_dir: Option<String>,
+    /// Combined CPU of running processes and the Claude Code pane, in
+    /// percent of one core, that turns the status bar warning on.
+    pub cpu_warn_percent: Option<u32>,
+    /// Combined resident memory (MB) that turns the status bar warning on.
+    pub memory_warn_mb: Option<u64>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct NotificationsConfig {
+    /// Team token total that triggers a `budget_exceeded` notification.
+    pub token_budget: Option<u64>,
+    #[serde(default)]
+    pub sinks: Vec<NotificationSink>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct NotificationSink {
+    /// "slack", "discord" or "email".
+    pub kind: String,
+    /// Webhook URL (slack, discord) or SMTP URL such as
+    /// `smtps://smtp.example.com:465` (email).
+    pub url: String,
+    /// Events to post ("process_finished", "pr_opened", "budget_exceeded").
+    /// All events when empty.
+    #[serde(default)]
+    pub events: Vec<String>,
+    /// Message template with `{event}`, `{title}`, `{detail}` and `{project}` placeholders.
+    pub template: Option<String>,
+    /// Email sender address.
+    pub from: Option<String>,
+    /// Email recipients.
+    #[serde(default)]
+    pub to: Vec<String>,
+    /// SMTP login user.
+    pub username: Option<String>,
+    /// Environment variable holding the SMTP password, so it stays out of the file.
+    pub password_env: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ConfirmConfig {
+    /// "auto" (small files are deleted at once with undo, directories and
+    /// large files ask first) or "always" (every delete asks first).
+    pub deletes: Option<String>,
+    /// Largest file, in KB, that "auto" deletes without asking.
+    pub undo_max_kb: Option<u64>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct EditorConfig {
+    /// External editor for `e` on file references, e.g. "code -g {path}:{line}"
+    /// or "$EDITOR". Unset opens the built-in editor on the Git tab.
+    pub command: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CommandsConfig {
+    /// Checkout of a shared repo of agents/commands, offered as the default
+    /// source when importing on the Commands tab.
+    pub shared_repo: Option<String>,
+}
+
+/// A named `assoc launch` setup. Command-line flags override its values.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct LaunchProfile {
+    /// Extra arguments passed to claude; flags after `--` are appended.
+    #[serde(default)]
+    pub claude_args: Vec<String>,
+    /// Claude pane width ratio (0.01-0.99).
+    pub claude_ratio: Option<f64>,
+    pub cols: Option<u32>,
+    pub rows: Option<u32>,
+    /// Session ID to resume, or "latest" to continue the most recent one.
+    pub resume: Option<String>,
+    /// Further panes, such as a dev server or test watcher.
+    #[serde(default)]
+    pub panes: Vec<LaunchPane>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct LaunchPane {
+    /// Name pane send targets it by, e.g. "dev".
+    pub name: Option<String>,
+    pub title: Option<String>,
+    /// Command line run in the pane. Defaults to the WT profile's shell.
+    pub command: Option<String>,
+    /// Pane it splits: "claude" (default), "assoc" or an earlier pane's name.
+    pub from: Option<String>,
+    /// "down" (default) splits the pane below it, "right" beside it.
+    pub split: Option<String>,
+    /// Share of the split pane the new pane takes (0.01-0.99).
+    pub size: Option<f64>,
+    /// Working directory, relative to the project. Defaults to the project.
+    pub cwd: Option<String>,
+}
+
+impl LaunchProfile {
+    /// The claude arguments for the `resume` setting.
+    pub fn resume_args(&self) -> Vec<String> {
+        match self.resume.as_deref().map(str::trim) {
+            None | Some("") => Vec::new(),
+            Some("latest") => vec!["--continue".to_string()],
+            Some(id) => vec!["--resume".to_string(), id.to_string()],
+        }
+    }
+
+    fn validate(&self) -> Result<(), String> {
+        let in_range = |v: f64| (0.01..=0.99).contains(&v);
+        if let Some(ratio) = self.claude_ratio.filter(|r| !in_range(*r)) {
+            return Err(format!(
+                "claude_ratio must be between 0.01 and 0.99, got {}",
+                ratio
+            ));
+        }
+        let mut names = vec![ASSOC_PANE, CLAUDE_PANE];
+        for pane in &self.panes {
+            if pane.command.as_deref().is_some_and(|c| c.trim().is_empty()) {
+                return Err("a pane has an empty command".to_string());
+            }
+            // Panes split ones opened before them, so the layout is a tree
+            if let Some(from) = pane.from.as_deref().filter(|f| !names.contains(f)) {
+                return Err(format!(
+                    "pane from must be one of {}, got {:?}",
+                    names
+                        .iter()
+                        .map(|n| format!("{:?}", n))
+                        .collect::<Vec<_>>()
+                        .join(", "),
+                    from
+                ));
+            }
+            if let Some(name) = pane.name.as_deref() {
+                if !is_pane_name(name) {
+                    return Err(format!(
+                        "pane name must be letters, digits, - or _, got {:?}",
+                        name
+                    ));
+                }
+                if names.contains(&name) {
+                    return Err(format!("pane name {:?} is used twice", name));
+                }
+                names.push(name);
+            }
+            if let Some(split) = pane
+                .split
+                .as_deref()
+                .filter(|s| !matches!(*s, "down" | "right"))
+            {
+                return Err(format!(
+                    "pane split must be \"down\" or \"right\", got {:?}",
+                    split
+                ));
+            }
+            if let Some(size) = pane.size.filter(|s| !in_range(*s)) {
+                return Err(format!(
+                    "pane size must be between 0.01 and 0.99, got {}",
+                    size
+                ));
+            }
+        }
+        Ok(())
+    }
+}
+
+impl LaunchPane {
+    /// Whether the pane goes beside the pane it splits rather than below it.
+    pub fn splits_right(&self) -> bool {
+        self.split.as_deref() == Some("right")
+    }
+
+    pub fn display_title(&self) -> &str {
+        self.title
+            .as_deref()
+            .or(self.name.as_deref())
+            .or(self.command.as_deref())
+            .unwrap_or("Shell")
+    }
+}
+
+impl NotificationSink {
+    pub fn wants(&self, event: &str) -> bool {
+        self.events.is_empty() || self.events.iter().any(|e| e == event)
+    }
 }
 
 impl ProjectConfig {
@@ -142,6 +606,42 @@ impl ProjectConfig {
             .max(50)
     }
 
+    /// Shortest time between two frames.
+    pub fn frame_interval(&self) -> Duration {
+        let fps = self
+            .display
+            .as_ref()
+            .and_then(|d| d.max_fps)
+            .unwrap_or(MAX_FPS)
+            .clamp(1, 120);
+        Duration::from_millis(1_000 / fps as u64)
+    }
+
+    /// Tick rate in ms, slowed down in low-power mode.
+    pub fn effective_tick_rate(&self, low_power: bool) -> u64 {
+        if low_power {
+            self.tick_rate().max(LOW_POWER_TICK_RATE_MS)
+        } else {
+            self.tick_rate()
+        }
+    }
+
+    /// `display.low_power` as a fixed choice, or None to follow the battery.
+    pub fn low_power_setting(&self) -> Option<bool> {
+        match self.display.as_ref().and_then(|d| d.low_power.as_deref()) {
+            None | Some("auto") => None,
+            Some("always") => Some(true),
+            Some("never") => Some(false),
+            Some(other) => {
+                eprintln!(
+                    "Warning: invalid display.low_power {:?}, defaulting to \"auto\"",
+                    other
+                );
+                None
+            }
+        }
+    }
+
     pub fn tail_lines(&self) -> usize {
         self.display
             .as_ref()
@@ -149,6 +649,98 @@ impl ProjectConfig {
             .unwrap_or(JSONL_TAIL_LINES)
     }
 
+    /// Segments of the status bar and the side each is on.
+    pub fn status_bar_layout(&self) -> StatusBarLayout {
+        let bar = self.status_bar.as_ref();
+        status_bar::parse_layout(
+            bar.and_then(|b| b.left.as_deref()),
+            bar.and_then(|b| b.center.as_deref()),
+            bar.and_then(|b| b.right.as_deref()),
+        )
+    }
+
+    pub fn polling_enabled(&self) -> bool {
+        self.polling
+            .as_ref()
+            .and_then(|p| p.enabled)
+            .unwrap_or(true)
+    }
+
+    /// Resolve a per-tab poll setting. None means polling is off for that tab.
+    fn poll_interval(&self, seconds: Option<u64>) -> Option<Duration> {
+        if !self.polling_enabled() {
+            return None;
+        }
+        match seconds.unwrap_or(POLL_SECONDS) {
+            0 => None,
+            s => Some(Duration::from_secs(s.max(MIN_POLL_SECONDS))),
+        }
+    }
+
+    pub fn github_poll_interval(&self) -> Option<Duration> {
+        self.poll_interval(self.github.as_ref().and_then(|g| g.poll_seconds))
+    }
+
+    pub fn jira_poll_interval(&self) -> Option<Duration> {
+        self.poll_interval(self.jira.as_ref().and_then(|j| j.poll_seconds))
+    }
+
+    pub fn linear_poll_interval(&self) -> Option<Duration> {
+        self.poll_interval(self.linear.as_ref().and_then(|l| l.poll_seconds))
+    }
+
+    pub fn azure_poll_interval(&self) -> Option<Duration> {
+        self.poll_interval(self.azure.as_ref().and_then(|a| a.poll_seconds))
+    }
+
+    pub fn bitbucket_poll_interval(&self) -> Option<Duration> {
+        self.poll_interval(self.bitbucket.as_ref().and_then(|b| b.poll_seconds))
+    }
+
+    /// Whether the PRs tab reads from Bitbucket Cloud instead of GitHub.
+    pub fn prs_from_bitbucket(&self) -> bool {
+        match self.prs.as_ref().and_then(|p| p.provider.as_deref()) {
+            None | Some("github") => false,
+            Some("bitbucket") => true,
+            Some(other) => {
+                eprintln!(
+                    "Warning: invalid prs.provider {:?}, defaulting to \"github\"",
+                    other
+                );
+                false
+            }
+        }
+    }
+
+    /// Columns of the PRs tab table.
+    pub fn pr_columns(&self) -> Vec<ListColumn> {
+        let names = self.prs.as_ref().and_then(|p| p.columns.as_deref());
+        list_table::parse_columns(names, "prs.columns", list_table::PR_COLUMNS)
+    }
+
+    /// Columns of the Issues tab table.
+    pub fn issue_columns(&self) -> Vec<ListColumn> {
+        let names = self
+            .github
+            .as_ref()
+            .and_then(|g| g.issues.as_ref())
+            .and_then(|i| i.columns.as_deref());
+        list_table::parse_columns(names, "github.issues.columns", list_table::ISSUE_COLUMNS)
+    }
+
+    pub fn bitbucket_repo(&self) -> Option<&str> {
+        self.bitbucket.as_ref().and_then(|b| b.repo.as_deref())
+    }
+
+    /// Username and app password for the Bitbucket API, when both are set.
+    pub fn bitbucket_credentials(&self) -> Option<(&str, &str)> {
+        let bitbucket = self.bitbucket.as_ref()?;
+        Some((
+            bitbucket.username.as_deref()?,
+            bitbucket.app_password.as_deref()?,
+        ))
+    }
+
     pub fn github_repo(&self) -> Option<&str> {
         self.github.as_ref().and_then(|g| g.repo.as_deref())
     }
@@ -162,6 +754,14 @@ impl ProjectConfig {
             .unwrap_or(true)
     }
 
+    pub fn github_page_size(&self) -> usize {
+        self.github
+            .as_ref()
+            .and_then(|g| g.page_size)
+            .filter(|size| *size > 0)
+            .unwrap_or(GITHUB_PAGE_SIZE)
+    }
+
     /// Override repo for issues (falls back to github.repo / git remote).
     pub fn github_issues_repo(&self) -> Option<&str> {
         self.github
@@ -199,6 +799,43 @@ impl ProjectConfig {
         self.jira.as_ref().and_then(|j| j.jql.as_deref())
     }
 
+    /// Names of the `[jira.filters]` queries, in name order.
+    pub fn jira_filter_names(&self) -> Vec<&str> {
+        self.jira
+            .iter()
+            .flat_map(|j| j.filters.keys())
+            .map(String::as_str)
+            .collect()
+    }
+
+    /// JQL for the issue list: the named filter's, or `jira.jql` for none.
+    pub fn jira_filter_jql(&self, filter: Option<&str>) -> Option<&str> {
+        match filter {
+            Some(name) => self
+                .jira
+                .as_ref()
+                .and_then(|j| j.filters.get(name))
+                .map(String::as_str),
+            None => self.jira_jql(),
+        }
+    }
+
+    pub fn jira_board_id(&self) -> Option<u64> {
+        self.jira.as_ref().and_then(|j| j.board_id)
+    }
+
+    pub fn jira_board_jql(&self) -> Option<&str> {
+        self.jira.as_ref().and_then(|j| j.board_jql.as_deref())
+    }
+
+    /// Email and API token for Jira's REST API, when the email is set and
+    /// the `api_token_env` variable holds a token.
+    pub fn jira_credentials(&self) -> Option<(&str, String)> {
+        let jira = self.jira.as_ref()?;
+        let token = std::env::var(jira.api_token_env.as_deref()?).ok()?;
+        Some((jira.email.as_deref()?, token))
+    }
+
     pub fn linear_api_key(&self) -> Option<&str> {
         self.linear.as_ref().and_then(|l| l.api_key.as_deref())
     }
@@ -207,8 +844,191 @@ impl ProjectConfig {
         self.linear.as_ref().and_then(|l| l.username.as_deref())
     }
 
-    pub fn linear_team(&self) -> Option<&str> {
-        self.linear.as_ref().and_then(|l| l.team.as_deref())
+    /// Server-side filters for the Linear tab. An unparseable cycle is
+    /// ignored with a warning.
+    pub fn linear_query(&self) -> IssueQuery {
+        let Some(linear) = self.linear.as_ref() else {
+            return IssueQuery::default();
+        };
+        let non_empty = |value: &Option<String>| {
+            value
+                .as_deref()
+                .map(str::trim)
+                .filter(|v| !v.is_empty())
+                .map(String::from)
+        };
+        let cycle = non_empty(&linear.cycle).filter(|cycle| {
+            let valid = cycle.eq_ignore_ascii_case("current") || cycle.parse::<u32>().is_ok();
+            if !valid {
+                eprintln!(
+                    "Warning: linear.cycle {:?} is neither \"current\" nor a cycle number, \
+                     ignoring it",
+                    cycle
+                );
+            }
+            valid
+        });
+        IssueQuery {
+            username: non_empty(&linear.username),
+            team: non_empty(&linear.team),
+            project: non_empty(&linear.project),
+            cycle,
+            states: linear
+                .states
+                .iter()
+                .flatten()
+                .map(|s| s.trim())
+                .filter(|s| !s.is_empty())
+                .map(String::from)
+                .collect(),
+        }
+    }
+
+    pub fn azure_organization(&self) -> Option<&str> {
+        self.azure.as_ref().and_then(|a| a.organization.as_deref())
+    }
+
+    pub fn azure_project(&self) -> Option<&str> {
+        self.azure.as_ref().and_then(|a| a.project.as_deref())
+    }
+
+    pub fn azure_wiql(&self) -> Option<&str> {
+        self.azure.as_ref().and_then(|a| a.wiql.as_deref())
+    }
+
+    pub fn translation_command(&self) -> Option<&str> {
+        self.translation
+            .as_ref()
+            .and_then(|t| t.command.as_deref())
+            .filter(|c| !c.trim().is_empty())
+    }
+
+    pub fn notification_sinks(&self) -> &[NotificationSink] {
+        self.notifications
+            .as_ref()
+            .map(|n| n.sinks.as_slice())
+            .unwrap_or(&[])
+    }
+
+    /// Team token total that triggers a budget notification, if set.
+    pub fn token_budget(&self) -> Option<u64> {
+        self.notifications
+            .as_ref()
+            .and_then(|n| n.token_budget)
+            .filter(|b| *b > 0)
+    }
+
+    /// Configured dependency checkers. The Deps tab is hidden when empty.
+    pub fn deps_checkers(&self) -> &[String] {
+        self.deps
+            .as_ref()
+            .map(|d| d.checkers.as_slice())
+            .unwrap_or(&[])
+    }
+
+    pub fn terminate_timeout(&self) -> Duration {
+        Duration::from_secs(
+            self.processes
+                .as_ref()
+                .and_then(|p| p.terminate_timeout_secs)
+                .unwrap_or(TERMINATE_TIMEOUT_SECS),
+        )
+    }
+
+    /// Whether deleting an item of `size` bytes asks first instead of
+    /// offering undo. `None` is a directory, which always asks.
+    pub fn delete_needs_confirm(&self, size: Option<u64>) -> bool {
+        let confirm = self.confirm.as_ref();
+        let always = match confirm.and_then(|c| c.deletes.as_deref()) {
+            None | Some("auto") => false,
+            Some("always") => true,
+            Some(other) => {
+                eprintln!(
+                    "Warning: invalid confirm.deletes {:?}, defaulting to \"auto\"",
+                    other
+                );
+                false
+            }
+        };
+        let max_kb = confirm.and_then(|c| c.undo_max_kb).unwrap_or(UNDO_MAX_KB);
+        always || size.is_none_or(|size| size > max_kb * 1024)
+    }
+
+    pub fn editor_command(&self) -> Option<&str> {
+        self.editor
+            .as_ref()
+            .and_then(|e| e.command.as_deref())
+            .filter(|c| !c.trim().is_empty())
+    }
+
+    /// Default import source on the Commands tab, with `~` expanded.
+    pub fn commands_shared_repo(&self) -> Option<String> {
+        self.commands
+            .as_ref()
+            .and_then(|c| c.shared_repo.as_deref())
+            .map(str::trim)
+            .filter(|r| !r.is_empty())
+            .map(|r| expand_home(r).display().to_string())
+    }
+
+    /// Most spawned processes allowed to run at once. Unlimited when unset or 0.
+    pub fn max_concurrent_processes(&self) -> Option<usize> {
+        self.processes
+            .as_ref()
+            .and_then(|p| p.max_concurrent)
+            .filter(|n| *n > 0)
+    }
+
+    /// CPU percent that raises the usage warning. Off when unset or 0.
+    pub fn process_cpu_warn(&self) -> Option<u32> {
+        self.processes
+            .as_ref()
+            .and_then(|p| p.cpu_warn_percent)
+            .filter(|n| *n > 0)
+    }
+
+    /// Memory (MB) that raises the usage warning. Off when unset or 0.
+    pub fn process_memory_warn_mb(&self) -> Option<u64> {
+        self.processes
+            .as_ref()
+            .and_then(|p| p.memory_warn_mb)
+            .filter(|n| *n > 0)
+    }
+
+    /// Where run reports are saved, relative to the project root unless
+    /// absolute; `~` is expanded.
+    pub fn process_report_dir(&self) -> PathBuf {
+        self.processes
+            .as_ref()
+            .and_then(|p| p.report_dir.as_deref())
+            .map(str::trim)
+            .filter(|dir| !dir.is_empty())
+            .map(expand_home)
+            .unwrap_or_else(|| PathBuf::from(DEFAULT_REPORT_DIR))
+    }
+
+    /// Quick-send snippets for the pane send bar, blank ones skipped.
+    pub fn send_snippets(&self) -> Vec<String> {
+        let Some(snippets) = self.pane.as_ref().and_then(|p| p.snippets.as_ref()) else {
+            return DEFAULT_SEND_SNIPPETS
+                .iter()
+                .map(|s| s.to_string())
+                .collect();
+        };
+        let snippets: Vec<String> = snippets
+            .iter()
+            .map(|s| s.trim())
+            .filter(|s| !s.is_empty())
+            .map(String::from)
+            .collect();
+        if snippets.len() > MAX_SEND_SNIPPETS {
+            eprintln!(
+                "Warning: pane.snippets has {} entries, only the first {} get a key",
+                snippets.len(),
+                MAX_SEND_SNIPPETS
+            );
+        }
+        snippets.into_iter().take(MAX_SEND_SNIPPETS).collect()
     }
 
     pub fn send_direction(&self) -> &str {
@@ -225,22 +1045,251 @@ impl ProjectConfig {
             None => "right",
         }
     }
+
+    /// The `pane.backend` terminal, detected from the environment unless set.
+    pub fn terminal_backend(&self) -> TerminalBackend {
+        match self.pane.as_ref().and_then(|p| p.backend.as_deref()) {
+            None | Some("auto") => TerminalBackend::detect(),
+            Some(name) => TerminalBackend::from_name(name).unwrap_or_else(|| {
+                eprintln!(
+                    "Warning: invalid pane.backend {:?}, detecting the terminal",
+                    name
+                );
+                TerminalBackend::detect()
+            }),
+        }
+    }
+
+    /// Claude homes to read, primary first. Extra homes that resolve to the
+    /// primary (or to each other) are dropped.
+    pub fn claude_homes(&self) -> Vec<ClaudeHome> {
+        let claude = self.claude.as_ref();
+        let primary = claude
+            .and_then(|c| c.home.as_deref())
+            .filter(|h| !h.trim().is_empty())
+            .map(|h| expand_home(h.trim()))
+            .unwrap_or_else(claude_home);
+        let mut homes = vec![ClaudeHome {
+            path: primary,
+            label: String::new(),
+        }];
+        for extra in claude.map(|c| c.extra_homes.as_slice()).unwrap_or(&[]) {
+            if extra.trim().is_empty() {
+                continue;
+            }
+            let path = expand_home(extra.trim());
+            if homes.iter().all(|h| h.path != path) {
+                homes.push(ClaudeHome::extra(path));
+            }
+        }
+        homes
+    }
+
+    /// Keys sent to the Claude Code pane when a plan is accepted or rejected.
+    pub fn plan_review_keys(&self, accepted: bool) -> &str {
+        let pane = self.pane.as_ref();
+        let configured = if accepted {
+            pane.and_then(|p| p.plan_accept.as_deref())
+        } else {
+            pane.and_then(|p| p.plan_reject.as_deref())
+        };
+        match configured.filter(|k| !k.is_empty()) {
+            Some(keys) => keys,
+            None if accepted => PLAN_ACCEPT_KEYS,
+            None => PLAN_REJECT_KEYS,
+        }
+    }
+
+    /// The `[launch.<name>]` profile, checked for out-of-range values.
+    pub fn launch_profile(&self, name: &str) -> Result<&LaunchProfile, String> {
+        let Some(profile) = self.launch.get(name) else {
+            let names: Vec<&str> = self.launch.keys().map(String::as_str).collect();
+            return Err(if names.is_empty() {
+                format!("no [launch.{}] profile in .assoc.toml", name)
+            } else {
+                format!(
+                    "no [launch.{}] profile in .assoc.toml (profiles: {})",
+                    name,
+                    names.join(", ")
+                )
+            });
+        };
+        profile
+            .validate()
+            .map_err(|e| format!("[launch.{}]: {}", name, e))?;
+        Ok(profile)
+    }
+
+    pub fn pane_resize_steps(&self) -> u32 {
+        self.pane
+            .as_ref()
+            .and_then(|p| p.resize_steps)
+            .unwrap_or(PANE_RESIZE_STEPS)
+            .clamp(1, 20)
+    }
+}
+
+/// Commented-out starter config written by the empty-state setup action.
+const STARTER_CONFIG: &str = include_str!("../.assoc.toml.example");
+
+/// Write a starter `.assoc.toml` into `cwd`. Fails if one already exists.
+pub fn write_starter_config(cwd: &Path) -> std::io::Result<PathBuf> {
+    let path = cwd.join(".assoc.toml");
+    let mut file = std::fs::OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(&path)?;
+    std::io::Write::write_all(&mut file, STARTER_CONFIG.as_bytes())?;
+    Ok(path)
 }
 
 /// Load project config from `.assoc.toml` in the given directory.
-/// Returns default config if the file doesn't exist or can't be parsed.
 pub fn load_project_config(cwd: &Path) -> ProjectConfig {
-    let path = cwd.join(".assoc.toml");
-    if path.exists() {
-        let content = std::fs::read_to_string(&path).unwrap_or_default();
-        match toml::from_str::<ProjectConfig>(&content) {
-            Ok(config) => config,
-            Err(e) => {
-                eprintln!("Warning: failed to parse .assoc.toml: {e}");
-                ProjectConfig::default()
-            }
-        }
-    } else {
+    try_load_project_config(cwd).unwrap_or_else(|e| {
+        eprintln!("Warning: failed to parse .assoc.toml: {e}");
         ProjectConfig::default()
+    })
+}
+
+/// Like [`load_project_config`], but reports a file that fails to read or
+/// parse instead of falling back to the defaults. Used for hot-reload.
+pub fn try_load_project_config(cwd: &Path) -> Result<ProjectConfig, String> {
+    let path = cwd.join(".assoc.toml");
+    if !path.exists() {
+        return Ok(ProjectConfig::default());
+    }
+    let content = std::fs::read_to_string(&path).map_err(|e| e.to_string())?;
+    toml::from_str::<ProjectConfig>(&content).map_err(|e| e.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn merges_configured_claude_homes() {
+        let config: ProjectConfig = toml::from_str(
+            r#"
+            [claude]
+            home = "/data/primary/.claude"
+            extra_homes = ["/mnt/devbox/.claude", "/srv/claude-state", "/data/primary/.claude", ""]
+            "#,
+        )
+        .unwrap();
+        let homes = config.claude_homes();
+        let labels: Vec<&str> = homes.iter().map(|h| h.label.as_str()).collect();
+        assert_eq!(labels, ["", "devbox", "claude-state"]);
+        assert_eq!(homes[0].path, PathBuf::from("/data/primary/.claude"));
+        assert_eq!(expand_home("~/x"), dirs_base().join("x"));
+    }
+
+    #[test]
+    fn deletes_ask_for_directories_and_large_files() {
+        let config = ProjectConfig::default();
+        assert!(!config.delete_needs_confirm(Some(4 * 1024)));
+        assert!(config.delete_needs_confirm(Some(2 * 1024 * 1024)));
+        assert!(config.delete_needs_confirm(None));
+
+        let config: ProjectConfig = toml::from_str("[confirm]\ndeletes = \"always\"\n").unwrap();
+        assert!(config.delete_needs_confirm(Some(0)));
+    }
+
+    #[test]
+    fn reads_send_snippets() {
+        let config = ProjectConfig::default();
+        assert_eq!(config.send_snippets(), DEFAULT_SEND_SNIPPETS);
+
+        let config: ProjectConfig =
+            toml::from_str("[pane]\nsnippets = [\"yes\", \" \", \" /clear \"]\n").unwrap();
+        assert_eq!(config.send_snippets(), ["yes", "/clear"]);
+
+        let config: ProjectConfig = toml::from_str("[pane]\nsnippets = []\n").unwrap();
+        assert!(config.send_snippets().is_empty());
+    }
+
+    #[test]
+    fn reads_jira_filters() {
+        let config: ProjectConfig = toml::from_str(
+            r#"
+            [jira]
+            jql = "assignee = currentUser()"
+
+            [jira.filters]
+            team = "project = PROJ"
+            bugs = "type = Bug"
+            "#,
+        )
+        .unwrap();
+        assert_eq!(config.jira_filter_names(), ["bugs", "team"]);
+        assert_eq!(config.jira_filter_jql(Some("bugs")), Some("type = Bug"));
+        assert_eq!(
+            config.jira_filter_jql(None),
+            Some("assignee = currentUser()")
+        );
+        assert_eq!(config.jira_filter_jql(Some("gone")), None);
+        assert!(ProjectConfig::default().jira_filter_names().is_empty());
+    }
+
+    #[test]
+    fn reads_launch_profiles() {
+        let config: ProjectConfig = toml::from_str(
+            r#"
+            [launch.dev]
+            claude_args = ["--model", "opus"]
+            claude_ratio = 0.6
+            resume = "latest"
+
+            [[launch.dev.panes]]
+            name = "server"
+            command = "cmd /k npm run dev"
+            split = "right"
+
+            [[launch.dev.panes]]
+            from = "server"
+
+            [launch.bad]
+            claude_ratio = 1.5
+            "#,
+        )
+        .unwrap();
+        let dev = config.launch_profile("dev").unwrap();
+        assert_eq!(dev.claude_args, ["--model", "opus"]);
+        assert_eq!(dev.resume_args(), ["--continue"]);
+        assert!(dev.panes[0].splits_right());
+        assert_eq!(dev.panes[1].display_title(), "Shell");
+        let unknown = LaunchProfile {
+            panes: vec![dev.panes[1].clone(), dev.panes[0].clone()],
+            ..Default::default()
+        };
+        assert!(unknown.validate().unwrap_err().contains("\"server\""));
+        assert!(config
+            .launch_profile("bad")
+            .unwrap_err()
+            .contains("claude_ratio"));
+        assert_eq!(
+            config.launch_profile("ci").unwrap_err(),
+            "no [launch.ci] profile in .assoc.toml (profiles: bad, dev)"
+        );
+        let resume = LaunchProfile {
+            resume: Some("abc123".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(resume.resume_args(), ["--resume", "abc123"]);
+    }
+
+    #[test]
+    fn reload_reports_broken_config() {
+        let dir = std::env::temp_dir().join(format!("assoc-config-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        assert!(try_load_project_config(&dir).is_ok());
+
+        std::fs::write(dir.join(".assoc.toml"), "[display]\ntick_rate = 500\n").unwrap();
+        let config = try_load_project_config(&dir).unwrap();
+        assert_eq!(config.tick_rate(), 500);
+
+        std::fs::write(dir.join(".assoc.toml"), "[display\n").unwrap();
+        assert!(try_load_project_config(&dir).is_err());
+
+        let _ = std::fs::remove_dir_all(&dir);
     }
 }
@@ -0,0 +1,123 @@
+use std::collections::{BTreeSet, HashSet};
+use std::path::PathBuf;
+
+use chrono::{DateTime, Datelike, Days, Local, NaiveDate};
+
+use crate::model::session::ActivityCalendar;
+use crate::model::transcript::{ContentBlock, MessageContent, TranscriptEnvelope};
+
+/// Weeks of activity covered by the heatmap.
+pub const CALENDAR_WEEKS: u64 = 26;
+
+/// The Sunday starting the first week of a calendar ending in the week of
+/// `today`.
+pub fn calendar_start(today: NaiveDate) -> NaiveDate {
+    let this_week = today - Days::new(u64::from(today.weekday().num_days_from_sunday()));
+    this_week - Days::new((CALENDAR_WEEKS - 1) * 7)
+}
+
+/// Count messages and active sessions per local day since `since` across
+/// the session transcripts in `project_dirs`. Transcripts last modified
+/// before `since` are skipped without being read.
+pub fn scan_activity(project_dirs: &[PathBuf], since: NaiveDate) -> ActivityCalendar {
+    let mut calendar = ActivityCalendar::default();
+    let transcripts = project_dirs
+        .iter()
+        .filter_map(|dir| std::fs::read_dir(dir).ok())
+        .flat_map(|entries| entries.flatten().map(|e| e.path()))
+        .filter(|path| path.extension().is_some_and(|e| e == "jsonl"));
+    for path in transcripts {
+        let modified = std::fs::metadata(&path).and_then(|m| m.modified()).ok();
+        if modified.is_some_and(|m| DateTime::<Local>::from(m).date_naive() < since) {
+            continue;
+        }
+        if let Ok(content) = std::fs::read_to_string(&path) {
+            add_transcript(&mut calendar, &content, since);
+        }
+    }
+    calendar
+}
+
+/// Add the messages of one JSONL transcript on or after `since` to
+/// `calendar`, counting the session once on each day it was active.
+pub fn add_transcript(calendar: &mut ActivityCalendar, content: &str, since: NaiveDate) {
+    let mut active_days = BTreeSet::new();
+    let mut seen_messages = HashSet::new();
+    for line in content.lines() {
+        let Ok(envelope) = serde_json::from_str::<TranscriptEnvelope>(line) else {
+            continue;
+        };
+        let (Some(ts), Some(message)) = (envelope.timestamp, &envelope.message) else {
+            continue;
+        };
+        let date = ts.with_timezone(&Local).date_naive();
+        if date < since {
+            continue;
+        }
+        // Counted like the stats pane: prompts and assistant messages once
+        // per id, not tool results
+        let counted = match message.role.as_deref().unwrap_or(envelope.kind.as_str()) {
+            "assistant" => message
+                .id
+                .as_ref()
+                .is_none_or(|id| seen_messages.insert(id.clone())),
+            "user" => match &message.content {
+                MessageContent::Blocks(blocks) => !blocks
+                    .iter()
+                    .any(|b| matches!(b, ContentBlock::ToolResult { .. })),
+                MessageContent::Text(_) => true,
+            },
+            _ => false,
+        };
+        if counted {
+            calendar.days.entry(date).or_default().messages += 1;
+            active_days.insert(date);
+        }
+    }
+    for date in active_days {
+        calendar.days.entry(date).or_default().sessions += 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::session::DayActivity;
+
+    #[test]
+    fn counts_messages_and_sessions_per_day() {
+        let day = |d: u32| NaiveDate::from_ymd_opt(2025, 3, d).unwrap();
+        let first = [
+            r#"{"type":"user","timestamp":"2025-03-01T12:00:00Z","message":{"role":"user","content":"old"}}"#,
+            r#"{"type":"user","timestamp":"2025-03-03T12:00:00Z","message":{"role":"user","content":"fix it"}}"#,
+            r#"{"type":"assistant","timestamp":"2025-03-03T12:00:05Z","message":{"id":"m1","role":"assistant","content":[{"type":"text","text":"ok"}]}}"#,
+            r#"{"type":"assistant","timestamp":"2025-03-03T12:00:06Z","message":{"id":"m1","role":"assistant","content":[{"type":"tool_use","name":"Edit"}]}}"#,
+            r#"{"type":"user","timestamp":"2025-03-03T12:00:07Z","message":{"role":"user","content":[{"type":"tool_result","content":"done"}]}}"#,
+            r#"{"type":"user","timestamp":"2025-03-05T12:00:00Z","message":{"role":"user","content":"again"}}"#,
+        ];
+        let second = [
+            r#"{"type":"user","timestamp":"2025-03-03T12:30:00Z","message":{"role":"user","content":"hi"}}"#,
+            r#"{"type":"summary","summary":"Greeting"}"#,
+        ];
+
+        let mut calendar = ActivityCalendar::default();
+        add_transcript(&mut calendar, &first.join("\n"), day(2));
+        add_transcript(&mut calendar, &second.join("\n"), day(2));
+
+        assert_eq!(calendar.day(day(1)), DayActivity::default());
+        assert_eq!(
+            calendar.day(day(3)),
+            DayActivity {
+                messages: 3,
+                sessions: 2
+            }
+        );
+        assert_eq!(calendar.day(day(5)).messages, 1);
+        assert_eq!(calendar.range(day(1), day(4)).count(), 1);
+
+        // 2025-03-05 is a Wednesday; its week starts on Sunday 2025-03-02
+        let start = calendar_start(day(5));
+        assert_eq!(start, day(2) - Days::new((CALENDAR_WEEKS - 1) * 7));
+        assert_eq!(start.weekday(), chrono::Weekday::Sun);
+    }
+}
@@ -0,0 +1,36 @@
+use std::path::{Path, PathBuf};
+
+use anyhow::Result;
+
+use crate::model::agent_status::StatusHistory;
+
+/// Location of a team's status history for a project.
+pub fn history_path(assoc_home: &Path, encoded_project: &str, team_dir: &str) -> PathBuf {
+    assoc_home
+        .join("agent-history")
+        .join(encoded_project)
+        .join(format!("{}.json", team_dir))
+}
+
+/// Load a team's status history, returning an empty one if it is missing
+/// or unreadable.
+pub fn load_history(path: &Path) -> StatusHistory {
+    std::fs::read_to_string(path)
+        .ok()
+        .and_then(|data| serde_json::from_str(&data).ok())
+        .unwrap_or_default()
+}
+
+/// Write a team's status history to disk via a temporary file.
+pub fn save_history(path: &Path, history: &StatusHistory) -> Result<()> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    let tmp = path.with_extension("json.tmp");
+    std::fs::write(&tmp, serde_json::to_string(history)?)?;
+    if let Err(e) = std::fs::rename(&tmp, path) {
+        let _ = std::fs::remove_file(&tmp);
+        return Err(e.into());
+    }
+    Ok(())
+}
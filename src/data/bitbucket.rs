@@ -0,0 +1,264 @@
+use anyhow::Result;
+use serde::Deserialize;
+
+use super::{cli_fixtures, curl};
+use crate::model::github::{FlatPrItem, PrAuthor, PrReviewRequest, PullRequest};
+
+const API_URL: &str = "https://api.bitbucket.org/2.0";
+
+/// PR fields the list endpoint leaves out by default.
+const PR_FIELDS: &str = "+values.participants,+values.description";
+
+#[derive(Debug, Deserialize)]
+struct Page {
+    values: Vec<BbPullRequest>,
+}
+
+#[derive(Debug, Deserialize)]
+struct BbPullRequest {
+    id: u64,
+    title: String,
+    #[serde(default)]
+    description: Option<String>,
+    state: String,
+    #[serde(default)]
+    draft: bool,
+    author: BbUser,
+    source: BbEnd,
+    destination: BbEnd,
+    links: BbLinks,
+    created_on: String,
+    updated_on: String,
+    #[serde(default)]
+    participants: Vec<BbParticipant>,
+}
+
+#[derive(Debug, Deserialize)]
+struct BbUser {
+    display_name: String,
+    #[serde(default)]
+    nickname: Option<String>,
+}
+
+impl BbUser {
+    fn login(&self) -> String {
+        self.nickname
+            .clone()
+            .unwrap_or_else(|| self.display_name.clone())
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct BbEnd {
+    branch: BbBranch,
+}
+
+#[derive(Debug, Deserialize)]
+struct BbBranch {
+    name: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct BbLinks {
+    html: BbLink,
+}
+
+#[derive(Debug, Deserialize)]
+struct BbLink {
+    href: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct BbParticipant {
+    user: BbUser,
+    role: String,
+    #[serde(default)]
+    approved: bool,
+    /// "approved", "changes_requested" or null.
+    #[serde(default)]
+    state: Option<String>,
+}
+
+/// Fetch the open PRs of `repo` (`workspace/repo`) along with the nickname
+/// of the signed-in user, which the list is grouped by. Authenticates with
+/// a Bitbucket username and app password.
+pub fn list_open_prs(
+    repo: &str,
+    username: &str,
+    app_password: &str,
+) -> Result<(String, Vec<PullRequest>)> {
+    let me: BbUser = serde_json::from_slice(&api_get("/user", username, app_password)?)?;
+    let path = format!(
+        "/repositories/{}/pullrequests?state=OPEN&pagelen=50&fields={}",
+        repo, PR_FIELDS
+    );
+    let page: Page = serde_json::from_slice(&api_get(&path, username, app_password)?)?;
+    let prs = page.values.into_iter().map(to_pull_request).collect();
+    Ok((me.login(), prs))
+}
+
+/// GET `path` from the Bitbucket API. The credentials go to curl on stdin
+/// so they don't show up in the process list.
+fn api_get(path: &str, username: &str, app_password: &str) -> Result<Vec<u8>> {
+    cli_fixtures::capture("bitbucket", &["get", path], || {
+        let url = format!("{}{}", API_URL, path);
+        let user = format!("{}:{}", username, app_password);
+        curl::run(&[("user", &user)], &["--max-time", "30", &url])
+    })
+}
+
+/// Map a Bitbucket PR onto the PRs tab's model. Reviewers who haven't
+/// approved yet count as pending review requests.
+fn to_pull_request(pr: BbPullRequest) -> PullRequest {
+    let reviewers: Vec<&BbParticipant> = pr
+        .participants
+        .iter()
+        .filter(|p| p.role == "REVIEWER")
+        .collect();
+    let approved_by: Vec<String> = pr
+        .participants
+        .iter()
+        .filter(|p| p.approved)
+        .map(|p| p.user.login())
+        .collect();
+    let changes_requested = pr
+        .participants
+        .iter()
+        .any(|p| p.state.as_deref() == Some("changes_requested"));
+    let review_decision = if changes_requested {
+        Some("CHANGES_REQUESTED")
+    } else if !approved_by.is_empty() {
+        Some("APPROVED")
+    } else if !reviewers.is_empty() {
+        Some("REVIEW_REQUIRED")
+    } else {
+        None
+    };
+    let review_requests = reviewers
+        .iter()
+        .filter(|p| !p.approved)
+        .map(|p| PrReviewRequest {
+            login: Some(p.user.login()),
+        })
+        .collect();
+
+    PullRequest {
+        number: pr.id,
+        title: pr.title,
+        state: pr.state,
+        author: PrAuthor {
+            login: pr.author.login(),
+            name: Some(pr.author.display_name),
+        },
+        url: pr.links.html.href,
+        created_at: pr.created_on,
+        updated_at: pr.updated_on,
+        head_ref_name: pr.source.branch.name,
+        base_ref_name: pr.destination.branch.name,
+        is_draft: pr.draft,
+        additions: 0,
+        deletions: 0,
+        review_decision: review_decision.map(str::to_string),
+        assignees: Vec::new(),
+        review_requests,
+        labels: Vec::new(),
+        body: pr.description.filter(|d| !d.is_empty()),
+        status_check_rollup: Vec::new(),
+        comments: Vec::new(),
+        approved_by,
+    }
+}
+
+/// Categorize PRs into sections: My PRs, Reviewing, Other Open.
+/// Returns a flat list with section headers interleaved.
+pub fn categorize_prs(prs: &[PullRequest], current_user: &str) -> Vec<FlatPrItem> {
+    let mut my_prs: Vec<&PullRequest> = Vec::new();
+    let mut reviewing: Vec<&PullRequest> = Vec::new();
+    let mut other: Vec<&PullRequest> = Vec::new();
+
+    for pr in prs {
+        if pr.author.login.eq_ignore_ascii_case(current_user) {
+            my_prs.push(pr);
+        } else if pr.review_requested_from(current_user)
+            || pr
+                .approved_by
+                .iter()
+                .any(|a| a.eq_ignore_ascii_case(current_user))
+        {
+            reviewing.push(pr);
+        } else {
+            other.push(pr);
+        }
+    }
+
+    let mut result = Vec::new();
+    for (label, mut group) in [
+        ("My PRs", my_prs),
+        ("Reviewing", reviewing),
+        ("Other Open", other),
+    ] {
+        if group.is_empty() {
+            continue;
+        }
+        // Most recently updated first
+        group.sort_by(|a, b| b.updated_at.cmp(&a.updated_at));
+        result.push(FlatPrItem::SectionHeader(format!(
+            "{} ({})",
+            label,
+            group.len()
+        )));
+        result.extend(
+            group
+                .into_iter()
+                .map(|pr| FlatPrItem::Pr(Box::new(pr.clone()))),
+        );
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn maps_prs_and_groups_by_reviewer() {
+        let page = r#"{"values": [
+            {"id": 7, "title": "Add login", "state": "OPEN", "draft": false,
+             "author": {"display_name": "Ann Lee", "nickname": "ann"},
+             "source": {"branch": {"name": "feature/login"}},
+             "destination": {"branch": {"name": "main"}},
+             "links": {"html": {"href": "https://bitbucket.org/ws/app/pull-requests/7"}},
+             "created_on": "2026-01-01T00:00:00Z", "updated_on": "2026-01-02T00:00:00Z",
+             "participants": [
+                {"user": {"display_name": "Bo", "nickname": "bo"}, "role": "REVIEWER",
+                 "approved": true, "state": "approved"},
+                {"user": {"display_name": "Me", "nickname": "me"}, "role": "REVIEWER",
+                 "approved": false, "state": null}
+             ]},
+            {"id": 8, "title": "Tidy", "state": "OPEN",
+             "author": {"display_name": "Me", "nickname": "me"},
+             "source": {"branch": {"name": "tidy"}},
+             "destination": {"branch": {"name": "main"}},
+             "links": {"html": {"href": "https://bitbucket.org/ws/app/pull-requests/8"}},
+             "created_on": "2026-01-01T00:00:00Z", "updated_on": "2026-01-01T00:00:00Z"}
+        ]}"#;
+        let page: Page = serde_json::from_str(page).unwrap();
+        let prs: Vec<PullRequest> = page.values.into_iter().map(to_pull_request).collect();
+
+        assert_eq!(prs[0].head_ref_name, "feature/login");
+        assert_eq!(prs[0].approved_by, ["bo"]);
+        assert_eq!(prs[0].review_decision.as_deref(), Some("APPROVED"));
+        assert!(prs[0].review_requested_from("me"));
+        assert!(!prs[0].review_requested_from("bo"));
+        assert_eq!(prs[1].review_decision, None);
+
+        let headers: Vec<String> = categorize_prs(&prs, "me")
+            .into_iter()
+            .filter_map(|item| match item {
+                FlatPrItem::SectionHeader(h) => Some(h),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(headers, ["My PRs (1)", "Reviewing (1)"]);
+    }
+}
@@ -0,0 +1,232 @@
+use std::path::{Path, PathBuf};
+
+use serde_json::Value;
+
+use crate::data::hooks;
+use crate::model::claude_settings::{
+    ClaudeSettings, FlatSettingsItem, SettingsEntry, SettingsLayer, SettingsSection, PRECEDENCE,
+};
+use crate::model::hook::SettingsScope;
+
+/// Read every scope's settings file, most specific first. Missing files
+/// have no values.
+pub fn load_settings(project_cwd: &Path, claude_home: &Path) -> ClaudeSettings {
+    let mut settings = ClaudeSettings::default();
+    for scope in PRECEDENCE {
+        let path = hooks::settings_path(scope, project_cwd, claude_home);
+        let (layer, entries) = match std::fs::read_to_string(&path) {
+            Ok(text) => parse_layer(scope, path, &text),
+            Err(e) => {
+                let missing = e.kind() == std::io::ErrorKind::NotFound;
+                let layer = SettingsLayer {
+                    scope,
+                    path,
+                    exists: !missing,
+                    problems: if missing {
+                        Vec::new()
+                    } else {
+                        vec![e.to_string()]
+                    },
+                };
+                (layer, Vec::new())
+            }
+        };
+        settings.layers.push(layer);
+        settings.entries.extend(entries);
+    }
+    mark_overridden(&mut settings.entries);
+    settings
+}
+
+/// Parse the model, permission rules and hooks of one settings file,
+/// noting values of the wrong type. Well-formed values are kept even when
+/// others in the file are broken.
+pub fn parse_layer(
+    scope: SettingsScope,
+    path: PathBuf,
+    text: &str,
+) -> (SettingsLayer, Vec<SettingsEntry>) {
+    // Hooks are validated the way the Hooks tab does, which also reports
+    // files that aren't JSON objects
+    let hook_file = hooks::parse_settings(scope, path.clone(), text);
+    let mut layer = SettingsLayer {
+        scope,
+        path,
+        exists: true,
+        problems: hook_file.problems,
+    };
+    let mut entries = Vec::new();
+    let entry = |section, value: &str, detail: &str| SettingsEntry {
+        section,
+        scope,
+        value: value.to_string(),
+        detail: detail.to_string(),
+        overridden_by: None,
+    };
+
+    let value: Option<Value> = serde_json::from_str(text).ok();
+    let Some(root) = value.as_ref().and_then(Value::as_object) else {
+        return (layer, entries);
+    };
+
+    match root.get("model") {
+        None => {}
+        Some(Value::String(model)) => entries.push(entry(SettingsSection::Model, model, "")),
+        Some(_) => layer.problems.push("model must be a string".to_string()),
+    }
+
+    match root.get("permissions") {
+        None => {}
+        Some(Value::Object(permissions)) => {
+            let lists = [
+                (SettingsSection::Allow, "allow"),
+                (SettingsSection::Ask, "ask"),
+                (SettingsSection::Deny, "deny"),
+            ];
+            for (section, key) in lists {
+                let Some(rules) = permissions.get(key) else {
+                    continue;
+                };
+                let Some(rules) = rules.as_array() else {
+                    layer
+                        .problems
+                        .push(format!("permissions.{} must be an array", key));
+                    continue;
+                };
+                for (i, rule) in rules.iter().enumerate() {
+                    match rule.as_str() {
+                        Some(rule) => entries.push(entry(section, rule, "")),
+                        None => layer
+                            .problems
+                            .push(format!("permissions.{}[{}] must be a string", key, i)),
+                    }
+                }
+            }
+        }
+        Some(_) => layer
+            .problems
+            .push("permissions must be an object".to_string()),
+    }
+
+    for hook in &hook_file.hooks {
+        let value = match hook.matcher {
+            Some(ref matcher) => format!("{} {}", hook.event, matcher),
+            None => hook.event.clone(),
+        };
+        entries.push(entry(SettingsSection::Hooks, &value, &hook.command));
+    }
+    (layer, entries)
+}
+
+/// Mark every model but the most specific one as overridden. `entries` are
+/// in precedence order.
+fn mark_overridden(entries: &mut [SettingsEntry]) {
+    let winner = entries
+        .iter()
+        .find(|e| e.section == SettingsSection::Model)
+        .map(|e| e.scope);
+    for entry in entries.iter_mut() {
+        if entry.section == SettingsSection::Model && Some(entry.scope) != winner {
+            entry.overridden_by = winner;
+        }
+    }
+}
+
+/// Files' problems first, then the values grouped by section.
+pub fn flatten(settings: &ClaudeSettings) -> Vec<FlatSettingsItem> {
+    let mut flat = Vec::new();
+    let problems: Vec<FlatSettingsItem> = settings
+        .layers
+        .iter()
+        .flat_map(|l| {
+            l.problems
+                .iter()
+                .map(|p| FlatSettingsItem::Problem(l.scope, p.clone()))
+        })
+        .collect();
+    if !problems.is_empty() {
+        flat.push(FlatSettingsItem::ProblemHeader);
+        flat.extend(problems);
+    }
+
+    for section in SettingsSection::ALL {
+        let entries: Vec<&SettingsEntry> = settings
+            .entries
+            .iter()
+            .filter(|e| e.section == section)
+            .collect();
+        if entries.is_empty() {
+            continue;
+        }
+        flat.push(FlatSettingsItem::SectionHeader(section));
+        flat.extend(entries.into_iter().cloned().map(FlatSettingsItem::Entry));
+    }
+    flat
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn merges_scopes_and_reports_problems() {
+        let dir = std::env::temp_dir().join(format!("assoc-settings-{}", std::process::id()));
+        let project = dir.join("project");
+        let home = dir.join("home");
+        std::fs::create_dir_all(project.join(".claude")).unwrap();
+        std::fs::create_dir_all(&home).unwrap();
+        std::fs::write(
+            home.join("settings.json"),
+            r#"{ "model": "opus", "permissions": { "allow": ["Bash(ls:*)"], "deny": "Read" } }"#,
+        )
+        .unwrap();
+        std::fs::write(
+            project.join(".claude").join("settings.json"),
+            r#"{ "model": "sonnet", "hooks": { "Stop": [{ "hooks": [{ "type": "command", "command": "./done.sh" }] }] } }"#,
+        )
+        .unwrap();
+        std::fs::write(
+            project.join(".claude").join("settings.local.json"),
+            "{ \"model\": ",
+        )
+        .unwrap();
+
+        let settings = load_settings(&project, &home);
+        let models: Vec<(SettingsScope, Option<SettingsScope>)> = settings
+            .entries
+            .iter()
+            .filter(|e| e.section == SettingsSection::Model)
+            .map(|e| (e.scope, e.overridden_by))
+            .collect();
+        assert_eq!(
+            models,
+            vec![
+                (SettingsScope::Project, None),
+                (SettingsScope::User, Some(SettingsScope::Project)),
+            ]
+        );
+        let hook = settings
+            .entries
+            .iter()
+            .find(|e| e.section == SettingsSection::Hooks)
+            .unwrap();
+        assert_eq!(
+            (hook.value.as_str(), hook.detail.as_str()),
+            ("Stop", "./done.sh")
+        );
+
+        let local = settings.layer(SettingsScope::Local).unwrap();
+        assert!(local.problems[0].starts_with("invalid JSON"));
+        let user = settings.layer(SettingsScope::User).unwrap();
+        assert_eq!(user.problems, vec!["permissions.deny must be an array"]);
+
+        let flat = flatten(&settings);
+        assert!(matches!(flat[0], FlatSettingsItem::ProblemHeader));
+        assert!(flat.iter().any(|item| matches!(
+            item,
+            FlatSettingsItem::SectionHeader(SettingsSection::Allow)
+        )));
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}
@@ -1,8 +1,14 @@
 use std::path::Path;
 use std::process::Command;
 
-/// Check if a CLI tool is available on PATH.
+use super::cli_fixtures;
+
+/// Check if a CLI tool is available on PATH. In replay mode, whether there
+/// are fixtures for it instead.
 pub fn is_available(cmd: &str) -> bool {
+    if cli_fixtures::replaying() {
+        return cli_fixtures::has_replay_fixtures(cmd);
+    }
     Command::new(cmd)
         .arg("--version")
         .stdout(std::process::Stdio::null())
@@ -11,6 +17,31 @@ pub fn is_available(cmd: &str) -> bool {
         .is_ok()
 }
 
+/// Run a CLI's auth status command (`gh auth status`, `acli jira auth status`,
+/// `az account show`) and return its combined stdout/stderr. The bool is
+/// whether the command reported success, i.e. whether the CLI is authenticated.
+pub fn auth_status(cmd: &str) -> std::io::Result<(bool, String)> {
+    let args: &[&str] = match cmd {
+        "acli" => &["jira", "auth", "status"],
+        "az" | "az.cmd" => &["account", "show"],
+        _ => &["auth", "status"],
+    };
+    let output = Command::new(cmd)
+        .args(args)
+        .stdout(std::process::Stdio::piped())
+        .stderr(std::process::Stdio::piped())
+        .output()?;
+    let mut text = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    if !stderr.trim().is_empty() {
+        if !text.is_empty() {
+            text.push('\n');
+        }
+        text.push_str(stderr.trim());
+    }
+    Ok((output.status.success(), text))
+}
+
 /// Open a URL in the default browser (Windows).
 pub fn open_url(url: &str) {
     if !url.starts_with("http://") && !url.starts_with("https://") {
@@ -19,10 +50,26 @@ pub fn open_url(url: &str) {
     let _ = Command::new("cmd").args(["/C", "start", "", url]).spawn();
 }
 
-/// Try to get `owner/repo` from `git remote get-url origin` in the given directory.
-fn try_git_remote(dir: &Path) -> Option<String> {
+/// Open a local file with its default program (Windows).
+pub fn open_path(path: &std::path::Path) {
+    let _ = Command::new("cmd")
+        .args(["/C", "start", ""])
+        .arg(path)
+        .spawn();
+}
+
+/// A git remote that points at a GitHub or Bitbucket repo.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GitRemote {
+    pub name: String,
+    /// `owner/repo` (`workspace/repo` on Bitbucket)
+    pub repo: String,
+}
+
+/// Run `git remote -v` in `dir` and return the remotes `parse_url` accepts.
+fn try_git_remotes(dir: &Path, parse_url: fn(&str) -> Option<String>) -> Option<Vec<GitRemote>> {
     let output = Command::new("git")
-        .args(["remote", "get-url", "origin"])
+        .args(["remote", "-v"])
         .current_dir(dir)
         .stdout(std::process::Stdio::piped())
         .stderr(std::process::Stdio::null())
@@ -31,26 +78,66 @@ fn try_git_remote(dir: &Path) -> Option<String> {
     if !output.status.success() {
         return None;
     }
-    let url = String::from_utf8_lossy(&output.stdout).trim().to_string();
-    parse_gh_repo_url(&url)
+    Some(parse_remotes(
+        &String::from_utf8_lossy(&output.stdout),
+        parse_url,
+    ))
+}
+
+/// Parse `git remote -v` output into the remotes whose fetch URL
+/// `parse_url` turns into a repo, `origin` first and the rest in git's order.
+fn parse_remotes(output: &str, parse_url: fn(&str) -> Option<String>) -> Vec<GitRemote> {
+    let mut remotes: Vec<GitRemote> = output
+        .lines()
+        .filter(|line| line.ends_with("(fetch)"))
+        .filter_map(|line| {
+            let mut parts = line.split_whitespace();
+            let name = parts.next()?;
+            let repo = parse_url(parts.next()?)?;
+            Some(GitRemote {
+                name: name.to_string(),
+                repo,
+            })
+        })
+        .collect();
+    remotes.sort_by_key(|r| r.name != "origin");
+    remotes
+}
+
+/// List the GitHub remotes of the repo containing `cwd`, `origin` first.
+pub fn list_gh_remotes(cwd: &Path) -> Vec<GitRemote> {
+    list_remotes(cwd, parse_gh_repo_url)
+}
+
+/// List the Bitbucket Cloud remotes of the repo containing `cwd`, `origin` first.
+pub fn list_bitbucket_remotes(cwd: &Path) -> Vec<GitRemote> {
+    list_remotes(cwd, parse_bitbucket_repo_url)
 }
 
-/// Parse `git remote get-url origin` into `owner/repo`.
 /// Walks up parent directories to find a `.git` dir if cwd itself isn't a repo.
-pub fn detect_gh_repo(cwd: &Path) -> Option<String> {
+fn list_remotes(cwd: &Path, parse_url: fn(&str) -> Option<String>) -> Vec<GitRemote> {
     // Try cwd first
-    if let Some(repo) = try_git_remote(cwd) {
-        return Some(repo);
+    if let Some(remotes) = try_git_remotes(cwd, parse_url) {
+        return remotes;
     }
     // Walk up parent dirs looking for a .git directory
     let mut dir = cwd.parent();
     while let Some(parent) = dir {
         if parent.join(".git").exists() {
-            return try_git_remote(parent);
+            return try_git_remotes(parent, parse_url).unwrap_or_default();
         }
         dir = parent.parent();
     }
-    None
+    Vec::new()
+}
+
+/// Pick the repo to target: the remote named `preferred` when it exists,
+/// otherwise `origin`, otherwise the first one.
+pub fn pick_repo(remotes: &[GitRemote], preferred: Option<&str>) -> Option<String> {
+    preferred
+        .and_then(|name| remotes.iter().find(|r| r.name == name))
+        .or_else(|| remotes.first())
+        .map(|r| r.repo.clone())
 }
 
 fn parse_gh_repo_url(url: &str) -> Option<String> {
@@ -67,6 +154,17 @@ fn parse_gh_repo_url(url: &str) -> Option<String> {
     None
 }
 
+fn parse_bitbucket_repo_url(url: &str) -> Option<String> {
+    // SSH: git@bitbucket.org:workspace/repo.git
+    // HTTPS: https://[user@]bitbucket.org/workspace/repo.git
+    let rest = url.strip_prefix("git@bitbucket.org:").or_else(|| {
+        let host_path = url.strip_prefix("https://")?;
+        let host_path = host_path.split_once('@').map_or(host_path, |(_, h)| h);
+        host_path.strip_prefix("bitbucket.org/")
+    })?;
+    Some(rest.strip_suffix(".git").unwrap_or(rest).to_string())
+}
+
 /// Get the current git branch name.
 pub fn detect_git_branch(cwd: &Path) -> Option<String> {
     let output = Command::new("git")
@@ -162,19 +260,57 @@ pub fn extract_issue_ids(input: &str) -> Vec<String> {
 
 /// Get the current GitHub user via `gh api user --jq .login`.
 pub fn detect_gh_user() -> Option<String> {
-    let output = Command::new("gh")
-        .args(["api", "user", "--jq", ".login"])
-        .stdout(std::process::Stdio::piped())
-        .stderr(std::process::Stdio::null())
-        .output()
-        .ok()?;
-    if !output.status.success() {
-        return None;
-    }
-    let user = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    let args = ["api", "user", "--jq", ".login"];
+    let stdout = cli_fixtures::capture("gh", &args, || {
+        let output = Command::new("gh")
+            .args(args)
+            .stdout(std::process::Stdio::piped())
+            .stderr(std::process::Stdio::null())
+            .output()?;
+        if !output.status.success() {
+            anyhow::bail!("gh api user failed");
+        }
+        Ok(output.stdout)
+    })
+    .ok()?;
+    let user = String::from_utf8_lossy(&stdout).trim().to_string();
     if user.is_empty() {
         None
     } else {
         Some(user)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lists_remotes_origin_first() {
+        let output = "\
+fork\tgit@github.com:me/app.git (fetch)
+fork\tgit@github.com:me/app.git (push)
+gitlab\thttps://gitlab.com/team/app.git (fetch)
+origin\thttps://github.com/team/app.git (fetch)
+origin\thttps://github.com/team/app.git (push)
+";
+        let remotes = parse_remotes(output, parse_gh_repo_url);
+        let names: Vec<(&str, &str)> = remotes
+            .iter()
+            .map(|r| (r.name.as_str(), r.repo.as_str()))
+            .collect();
+        assert_eq!(names, [("origin", "team/app"), ("fork", "me/app")]);
+        assert_eq!(pick_repo(&remotes, Some("fork")).as_deref(), Some("me/app"));
+        assert_eq!(
+            pick_repo(&remotes, Some("gone")).as_deref(),
+            Some("team/app")
+        );
+        assert_eq!(pick_repo(&[], None), None);
+
+        let remotes = parse_remotes(
+            "origin\thttps://ann@bitbucket.org/team/app.git (fetch)\n",
+            parse_bitbucket_repo_url,
+        );
+        assert_eq!(pick_repo(&remotes, None).as_deref(), Some("team/app"));
+    }
+}
@@ -0,0 +1,168 @@
+//! Record and replay the output of external CLIs (gh, acli, az, Linear's API).
+//!
+//! With `ASSOC_RECORD_DIR=<dir>` set, the stdout of every successful call is
+//! saved as a fixture file in `<dir>`. With `ASSOC_REPLAY_DIR=<dir>` set, calls
+//! are answered from those files and the tools are never run, so the
+//! dashboard works offline with canned data and no credentials.
+//!
+//! A fixture is named after the program and its leading subcommand words,
+//! plus a hash of the full arguments: `gh-issue-list-1a2b3c4d.json`. Replay
+//! falls back to the name without the hash (`gh-issue-list.json`), so one
+//! hand-written file can answer every variant of a call.
+
+use std::path::{Path, PathBuf};
+
+use anyhow::Result;
+
+pub const RECORD_ENV: &str = "ASSOC_RECORD_DIR";
+pub const REPLAY_ENV: &str = "ASSOC_REPLAY_DIR";
+
+/// Run `run` for `program args`, recording or replaying its stdout depending
+/// on the environment. `args` identify the call; leave out secrets such as
+/// API keys, since they go into the fixture name's hash.
+pub fn capture(
+    program: &str,
+    args: &[&str],
+    run: impl FnOnce() -> Result<Vec<u8>>,
+) -> Result<Vec<u8>> {
+    capture_in(
+        env_dir(REPLAY_ENV).as_deref(),
+        env_dir(RECORD_ENV).as_deref(),
+        program,
+        args,
+        run,
+    )
+}
+
+/// Whether replay mode has fixtures for `program`, so its tab can be shown
+/// without the tool installed.
+pub fn has_replay_fixtures(program: &str) -> bool {
+    let Some(dir) = env_dir(REPLAY_ENV) else {
+        return false;
+    };
+    let prefix = format!("{}-", program_name(program));
+    std::fs::read_dir(dir)
+        .map(|entries| {
+            entries
+                .flatten()
+                .any(|e| e.file_name().to_string_lossy().starts_with(&prefix))
+        })
+        .unwrap_or(false)
+}
+
+/// Whether replay mode is on.
+pub fn replaying() -> bool {
+    env_dir(REPLAY_ENV).is_some()
+}
+
+fn capture_in(
+    replay: Option<&Path>,
+    record: Option<&Path>,
+    program: &str,
+    args: &[&str],
+    run: impl FnOnce() -> Result<Vec<u8>>,
+) -> Result<Vec<u8>> {
+    let (base, hashed) = fixture_names(program, args);
+    if let Some(dir) = replay {
+        let exact = dir.join(&hashed);
+        let fallback = dir.join(&base);
+        return std::fs::read(&exact)
+            .or_else(|_| std::fs::read(&fallback))
+            .map_err(|_| {
+                anyhow::anyhow!(
+                    "no replay fixture {} or {}",
+                    exact.display(),
+                    fallback.display()
+                )
+            });
+    }
+    let stdout = run()?;
+    if let Some(dir) = record {
+        // Recording is best effort; a failed write must not break the load
+        if std::fs::create_dir_all(dir).is_ok() {
+            let _ = std::fs::write(dir.join(&hashed), &stdout);
+        }
+    }
+    Ok(stdout)
+}
+
+fn env_dir(var: &str) -> Option<PathBuf> {
+    std::env::var_os(var)
+        .filter(|v| !v.is_empty())
+        .map(PathBuf::from)
+}
+
+/// Program name without a Windows wrapper extension (`az.cmd` -> `az`).
+fn program_name(program: &str) -> &str {
+    program
+        .strip_suffix(".cmd")
+        .or_else(|| program.strip_suffix(".exe"))
+        .unwrap_or(program)
+}
+
+/// The fallback and exact fixture file names for a call.
+fn fixture_names(program: &str, args: &[&str]) -> (String, String) {
+    let mut stem = program_name(program).to_string();
+    // Leading subcommand words; stops at the first flag, id or free text
+    let is_word = |a: &&&str| {
+        !a.is_empty()
+            && !a.starts_with('-')
+            && a.chars().all(|c| c.is_ascii_lowercase() || c == '-')
+    };
+    for arg in args.iter().take_while(is_word) {
+        stem.push('-');
+        stem.push_str(arg);
+    }
+    let hash = fnv1a(args);
+    (
+        format!("{}.json", stem),
+        format!("{}-{:08x}.json", stem, hash),
+    )
+}
+
+/// 32-bit FNV-1a over the arguments, stable across runs and Rust versions.
+fn fnv1a(args: &[&str]) -> u32 {
+    let mut hash: u32 = 0x811c_9dc5;
+    for arg in args {
+        for byte in arg.bytes().chain(std::iter::once(0)) {
+            hash ^= byte as u32;
+            hash = hash.wrapping_mul(0x0100_0193);
+        }
+    }
+    hash
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn records_then_replays() {
+        let (base, hashed) = fixture_names("az.cmd", &["boards", "query", "--wiql", "SELECT"]);
+        assert_eq!(base, "az-boards-query.json");
+        assert!(hashed.starts_with("az-boards-query-") && hashed.ends_with(".json"));
+        let (base, _) = fixture_names("gh", &["issue", "close", "12", "--repo", "o/r"]);
+        assert_eq!(base, "gh-issue-close.json");
+
+        let dir = std::env::temp_dir().join(format!("assoc-fixtures-{}", std::process::id()));
+        let args = ["pr", "list", "--repo", "o/r"];
+        let out = capture_in(None, Some(&dir), "gh", &args, || Ok(b"[1]".to_vec())).unwrap();
+        assert_eq!(out, b"[1]");
+
+        // Replay never runs the command
+        let replayed = capture_in(Some(&dir), None, "gh", &args, || {
+            anyhow::bail!("should not run")
+        })
+        .unwrap();
+        assert_eq!(replayed, b"[1]");
+
+        // Other arguments fall back to the unhashed name
+        std::fs::write(dir.join("gh-pr-list.json"), b"[2]").unwrap();
+        let other = ["pr", "list", "--repo", "x/y"];
+        let fallback = capture_in(Some(&dir), None, "gh", &other, || Ok(Vec::new())).unwrap();
+        assert_eq!(fallback, b"[2]");
+        assert!(capture_in(Some(&dir), None, "acli", &[], || Ok(Vec::new())).is_err());
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}
@@ -0,0 +1,242 @@
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+
+use anyhow::{bail, Result};
+
+use super::commands;
+use crate::model::command::{CommandKind, CommandScope, ImportCandidate};
+
+/// Directories of a shared repo searched for definitions, with their kind.
+const REPO_DIRS: &[(&str, CommandKind)] = &[
+    (".claude/agents", CommandKind::Agent),
+    (".claude/commands", CommandKind::Command),
+    ("agents", CommandKind::Agent),
+    ("commands", CommandKind::Command),
+];
+
+/// Load definitions to import from `source`: an `http(s)://` URL of one
+/// markdown file, a local `.md` file, or a checkout of a shared repo whose
+/// `agents/` and `commands/` (or `.claude/agents/` ...) hold definitions.
+pub fn load_source(source: &str) -> Result<Vec<ImportCandidate>> {
+    let source = source.trim().trim_matches('"');
+    if source.is_empty() {
+        bail!("enter a URL or path");
+    }
+    if source.starts_with("http://") || source.starts_with("https://") {
+        let content = fetch_url(&raw_url(source))?;
+        let stem = source
+            .split(['?', '#'])
+            .next()
+            .unwrap_or(source)
+            .trim_end_matches('/')
+            .rsplit('/')
+            .next()
+            .unwrap_or(source);
+        return Ok(vec![candidate(
+            source,
+            stem.trim_end_matches(".md"),
+            content,
+        )]);
+    }
+
+    let path = Path::new(source);
+    if path.is_file() {
+        let stem = path
+            .file_stem()
+            .map(|s| s.to_string_lossy().to_string())
+            .unwrap_or_default();
+        let content = std::fs::read_to_string(path)?;
+        return Ok(vec![candidate(source, &stem, content)]);
+    }
+    if !path.is_dir() {
+        bail!("{} not found", source);
+    }
+
+    let mut candidates = Vec::new();
+    for (sub, kind) in REPO_DIRS {
+        let dir = path.join(sub);
+        let mut files = Vec::new();
+        commands::collect(&dir, &dir, CommandScope::Project, *kind, &mut files);
+        files.sort_by(|a, b| a.name.cmp(&b.name));
+        for file in files {
+            candidates.push(ImportCandidate {
+                kind: file.kind,
+                name: file.name,
+                content: std::fs::read_to_string(&file.path)?,
+                source: file.path.display().to_string(),
+            });
+        }
+    }
+    if candidates.is_empty() {
+        bail!("no agents/ or commands/ definitions in {}", source);
+    }
+    Ok(candidates)
+}
+
+/// Build a candidate from a single file, guessing its kind from the path and
+/// its name from an agent's `name:` or the file name.
+fn candidate(source: &str, stem: &str, content: String) -> ImportCandidate {
+    let kind = infer_kind(source, &content);
+    let name = match kind {
+        CommandKind::Agent => frontmatter_field(&content, "name"),
+        CommandKind::Command => None,
+    }
+    .unwrap_or_else(|| stem.to_string());
+    ImportCandidate {
+        kind,
+        name,
+        content,
+        source: source.to_string(),
+    }
+}
+
+/// `commands/` in the path makes a command and `agents/` an agent.
+/// Otherwise frontmatter with a `name:` (required for agents) decides.
+pub fn infer_kind(source: &str, content: &str) -> CommandKind {
+    let source = source.replace('\\', "/");
+    if source.contains("/commands/") {
+        CommandKind::Command
+    } else if source.contains("/agents/") || frontmatter_field(content, "name").is_some() {
+        CommandKind::Agent
+    } else {
+        CommandKind::Command
+    }
+}
+
+fn frontmatter_field(content: &str, field: &str) -> Option<String> {
+    let rest = content.strip_prefix("---")?;
+    let (header, _) = rest.split_once("\n---")?;
+    header.lines().find_map(|line| {
+        let value = line.strip_prefix(field)?.strip_prefix(':')?.trim();
+        let value = value.trim_matches('"');
+        (!value.is_empty()).then(|| value.to_string())
+    })
+}
+
+/// GitHub `blob` page URLs point at the raw file instead.
+pub fn raw_url(url: &str) -> String {
+    let Some(rest) = url.strip_prefix("https://github.com/") else {
+        return url.to_string();
+    };
+    match rest.split_once("/blob/") {
+        Some((repo, path)) => format!("https://raw.githubusercontent.com/{}/{}", repo, path),
+        None => url.to_string(),
+    }
+}
+
+fn fetch_url(url: &str) -> Result<String> {
+    let output = Command::new("curl")
+        .args([
+            "-fsSL",
+            "--max-time",
+            "20",
+            "--max-filesize",
+            "1000000",
+            url,
+        ])
+        .stdin(Stdio::null())
+        .output()?;
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        match stderr.lines().next().map(str::trim) {
+            Some(msg) if !msg.is_empty() => bail!("{}", msg),
+            _ => bail!("curl exited with {}", output.status),
+        }
+    }
+    Ok(String::from_utf8(output.stdout)?)
+}
+
+/// Write `candidate` into the project's `.claude/`. An existing file is only
+/// replaced with `overwrite`.
+pub fn import(
+    candidate: &ImportCandidate,
+    project_cwd: &Path,
+    claude_home: &Path,
+    overwrite: bool,
+) -> Result<PathBuf> {
+    let path = target_path(candidate, project_cwd, claude_home)?;
+    if path.exists() && !overwrite {
+        bail!("{} already exists", path.display());
+    }
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    std::fs::write(&path, &candidate.content)?;
+    Ok(path)
+}
+
+/// Where `candidate` is written in the project.
+pub fn target_path(
+    candidate: &ImportCandidate,
+    project_cwd: &Path,
+    claude_home: &Path,
+) -> Result<PathBuf> {
+    commands::command_path(
+        CommandScope::Project,
+        candidate.kind,
+        &candidate.name,
+        project_cwd,
+        claude_home,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn loads_repo_definitions_and_protects_existing_files() {
+        let root = std::env::temp_dir().join(format!("assoc-cmd-import-{}", std::process::id()));
+        let repo = root.join("team-repo");
+        let project = root.join("project");
+        std::fs::create_dir_all(repo.join("agents").join("review")).unwrap();
+        std::fs::create_dir_all(repo.join(".claude").join("commands")).unwrap();
+        std::fs::write(
+            repo.join("agents").join("review").join("security.md"),
+            "---\nname: security\n---\nAudit",
+        )
+        .unwrap();
+        std::fs::write(
+            repo.join(".claude").join("commands").join("ship.md"),
+            "Ship it",
+        )
+        .unwrap();
+
+        let candidates = load_source(&repo.display().to_string()).unwrap();
+        let found: Vec<(CommandKind, &str)> = candidates
+            .iter()
+            .map(|c| (c.kind, c.name.as_str()))
+            .collect();
+        assert_eq!(
+            found,
+            [
+                (CommandKind::Command, "ship"),
+                (CommandKind::Agent, "review:security")
+            ]
+        );
+
+        let agent = &candidates[1];
+        let path = import(agent, &project, &root, false).unwrap();
+        assert_eq!(
+            path,
+            project
+                .join(".claude")
+                .join("agents")
+                .join("review")
+                .join("security.md")
+        );
+        assert!(import(agent, &project, &root, false).is_err());
+        assert!(import(agent, &project, &root, true).is_ok());
+
+        assert_eq!(
+            infer_kind("https://x.io/a.md", "---\nname: a\n---\n"),
+            CommandKind::Agent
+        );
+        assert_eq!(
+            raw_url("https://github.com/o/r/blob/main/agents/a.md"),
+            "https://raw.githubusercontent.com/o/r/main/agents/a.md"
+        );
+
+        let _ = std::fs::remove_dir_all(&root);
+    }
+}
@@ -0,0 +1,244 @@
+use std::path::{Path, PathBuf};
+
+use anyhow::Result;
+
+use super::plans::parse_markdown_lines;
+use crate::model::command::{CommandFile, CommandKind, CommandScope};
+
+const COMMAND_TEMPLATE: &str = "---
+description: {{name}}
+argument-hint: [args]
+---
+
+Describe what Claude should do when `/{{name}}` is run.
+
+Use $ARGUMENTS to refer to anything typed after the command.
+";
+
+const AGENT_TEMPLATE: &str = "---
+name: {{name}}
+description: When Claude should hand work to this agent
+tools: Read, Grep, Glob
+---
+
+You are a specialist for ... Describe the agent's role, what it should
+focus on, and how it should report back.
+";
+
+/// The `.claude/` directory for a scope.
+pub fn scope_dir(scope: CommandScope, project_cwd: &Path, claude_home: &Path) -> PathBuf {
+    match scope {
+        CommandScope::Project => project_cwd.join(".claude"),
+        CommandScope::User => claude_home.to_path_buf(),
+    }
+}
+
+/// Load commands and agents from the project's `.claude/` and from
+/// `~/.claude/`, project scope first, each group sorted by name.
+pub fn load_commands(project_cwd: &Path, claude_home: &Path) -> Vec<CommandFile> {
+    let mut files = Vec::new();
+    for scope in [CommandScope::Project, CommandScope::User] {
+        for kind in [CommandKind::Command, CommandKind::Agent] {
+            let dir = scope_dir(scope, project_cwd, claude_home).join(kind.dir_name());
+            let mut group = Vec::new();
+            collect(&dir, &dir, scope, kind, &mut group);
+            group.sort_by(|a: &CommandFile, b| a.name.cmp(&b.name));
+            files.extend(group);
+        }
+    }
+    files
+}
+
+/// File for a command or agent called `name`. `name` may contain `:` or `/`
+/// to place it in a namespace subdirectory.
+pub fn command_path(
+    scope: CommandScope,
+    kind: CommandKind,
+    name: &str,
+    project_cwd: &Path,
+    claude_home: &Path,
+) -> Result<PathBuf> {
+    let name = name.trim().trim_start_matches('/');
+    let parts: Vec<&str> = name.split([':', '/']).collect();
+    if parts
+        .iter()
+        .any(|p| p.is_empty() || *p == "." || *p == ".." || p.contains('\\'))
+    {
+        anyhow::bail!("invalid name {:?}", name);
+    }
+
+    let mut path = scope_dir(scope, project_cwd, claude_home).join(kind.dir_name());
+    let (file, dirs) = parts.split_last().expect("split yields at least one part");
+    for dir in dirs {
+        path.push(dir);
+    }
+    path.push(format!("{}.md", file));
+    Ok(path)
+}
+
+/// Create a new command or agent file from a template.
+pub fn create_command(
+    scope: CommandScope,
+    kind: CommandKind,
+    name: &str,
+    project_cwd: &Path,
+    claude_home: &Path,
+) -> Result<PathBuf> {
+    let path = command_path(scope, kind, name, project_cwd, claude_home)?;
+    if path.exists() {
+        anyhow::bail!("{} already exists", path.display());
+    }
+
+    let template = match kind {
+        CommandKind::Command => COMMAND_TEMPLATE,
+        CommandKind::Agent => AGENT_TEMPLATE,
+    };
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    let name = name.trim().trim_start_matches('/').replace('/', ":");
+    std::fs::write(&path, template.replace("{{name}}", &name))?;
+    Ok(path)
+}
+
+/// Add every `.md` file under `dir` to `out`, named relative to `root`.
+pub fn collect(
+    root: &Path,
+    dir: &Path,
+    scope: CommandScope,
+    kind: CommandKind,
+    out: &mut Vec<CommandFile>,
+) {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return;
+    };
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if path.is_dir() {
+            collect(root, &path, scope, kind, out);
+            continue;
+        }
+        if path.extension().and_then(|e| e.to_str()) != Some("md") {
+            continue;
+        }
+        let content = std::fs::read_to_string(&path).unwrap_or_default();
+        let name = path
+            .strip_prefix(root)
+            .unwrap_or(&path)
+            .with_extension("")
+            .components()
+            .map(|c| c.as_os_str().to_string_lossy().to_string())
+            .collect::<Vec<_>>()
+            .join(":");
+        out.push(CommandFile {
+            scope,
+            kind,
+            name,
+            description: frontmatter_description(&content),
+            lines: parse_markdown_lines(&content),
+            path,
+        });
+    }
+}
+
+/// Read `description:` from a `---` frontmatter block.
+fn frontmatter_description(content: &str) -> Option<String> {
+    frontmatter_field(content, "description")
+}
+
+/// Read `key:` from a `---` frontmatter block.
+pub fn frontmatter_field(content: &str, key: &str) -> Option<String> {
+    let rest = content.strip_prefix("---")?;
+    let (header, _) = rest.split_once("\n---")?;
+    header.lines().find_map(|line| {
+        let value = line
+            .strip_prefix(key)?
+            .strip_prefix(':')?
+            .trim()
+            .trim_matches('"');
+        (!value.is_empty()).then(|| value.to_string())
+    })
+}
+
+/// The agent an agent file defines, as its name and the JSON `claude
+/// --agents` takes: `{"name": {"description", "prompt", "tools", "model"}}`.
+/// The name is the frontmatter's `name:`, else the file's name.
+pub fn agent_definition(file: &CommandFile) -> Result<(String, String)> {
+    let content = std::fs::read_to_string(&file.path)?;
+    let name = frontmatter_field(&content, "name").unwrap_or_else(|| file.name.clone());
+    let prompt = match content
+        .strip_prefix("---")
+        .and_then(|rest| rest.split_once("\n---"))
+    {
+        Some((_, body)) => body.trim_start_matches('-').trim(),
+        None => content.trim(),
+    };
+
+    let mut agent = serde_json::Map::new();
+    agent.insert(
+        "description".to_string(),
+        frontmatter_field(&content, "description")
+            .unwrap_or_else(|| name.clone())
+            .into(),
+    );
+    agent.insert("prompt".to_string(), prompt.into());
+    if let Some(tools) = frontmatter_field(&content, "tools") {
+        let tools: Vec<serde_json::Value> = tools
+            .split(',')
+            .map(str::trim)
+            .filter(|t| !t.is_empty())
+            .map(|t| t.into())
+            .collect();
+        agent.insert("tools".to_string(), tools.into());
+    }
+    if let Some(model) = frontmatter_field(&content, "model") {
+        agent.insert("model".to_string(), model.into());
+    }
+    let mut agents = serde_json::Map::new();
+    agents.insert(name.clone(), agent.into());
+    Ok((name, serde_json::Value::Object(agents).to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn description_comes_from_frontmatter() {
+        let content = "---\ndescription: \"Review the diff\"\nmodel: sonnet\n---\nBody";
+        assert_eq!(
+            frontmatter_description(content).as_deref(),
+            Some("Review the diff")
+        );
+        assert_eq!(frontmatter_description("Just a prompt"), None);
+        assert_eq!(frontmatter_description("---\ndescription:\n---\n"), None);
+    }
+
+    #[test]
+    fn agent_definition_from_frontmatter() {
+        let dir = std::env::temp_dir().join(format!("assoc-agent-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("reviewer.md");
+        std::fs::write(
+            &path,
+            "---\nname: code-reviewer\ndescription: Reviews diffs\ntools: Read, Grep\n---\n\nYou review code.\n",
+        )
+        .unwrap();
+        let file = CommandFile {
+            scope: CommandScope::Project,
+            kind: CommandKind::Agent,
+            name: "reviewer".to_string(),
+            path,
+            description: None,
+            lines: Vec::new(),
+        };
+        let (name, json) = agent_definition(&file).unwrap();
+        assert_eq!(name, "code-reviewer");
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+        let agent = &value["code-reviewer"];
+        assert_eq!(agent["prompt"], "You review code.");
+        assert_eq!(agent["tools"], serde_json::json!(["Read", "Grep"]));
+        assert!(agent.get("model").is_none());
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}
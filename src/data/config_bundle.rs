@@ -0,0 +1,251 @@
+use std::collections::BTreeMap;
+use std::path::{Component, Path, PathBuf};
+
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+
+/// Bundle format version written by `assoc config export`.
+const BUNDLE_VERSION: u32 = 1;
+
+/// Project config file name, relative to the project root.
+const CONFIG_FILE: &str = ".assoc.toml";
+
+/// Directory holding prompt templates and other shareable project data.
+const DATA_DIR: &str = ".associate";
+
+/// `.assoc.toml` keys holding credentials, left out of an export so a
+/// bundle can be shared.
+const SECRET_KEYS: [&str; 2] = ["linear.api_key", "bitbucket.app_password"];
+
+/// Secret keys a sink needs to parse, which are blanked instead.
+const BLANKED_KEYS: [&str; 1] = ["notifications.sinks.url"];
+
+/// Machine-local data under `DATA_DIR` that is never bundled: caches,
+/// trashed transcripts and team directories, unsent drafts and the
+/// registry of spawned processes.
+const LOCAL_DIRS: [&str; 4] = ["cache", "trash", "drafts", "processes"];
+
+/// A shareable snapshot of a project's Associate setup: `.assoc.toml`
+/// without its secrets, plus everything under `.associate/` (prompt
+/// templates, saved filters, ...).
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ConfigBundle {
+    pub version: u32,
+    /// File contents keyed by `/`-separated path relative to the project.
+    pub files: BTreeMap<String, String>,
+}
+
+/// Collect the project's config files into a bundle.
+pub fn export_bundle(project_cwd: &Path) -> Result<ConfigBundle> {
+    let mut files = BTreeMap::new();
+
+    let config = project_cwd.join(CONFIG_FILE);
+    if config.is_file() {
+        let content = std::fs::read_to_string(&config)?;
+        files.insert(CONFIG_FILE.to_string(), strip_secrets(&content));
+    }
+    let data_dir = project_cwd.join(DATA_DIR);
+    let skip: Vec<PathBuf> = LOCAL_DIRS.iter().map(|d| data_dir.join(d)).collect();
+    collect_dir(project_cwd, &data_dir, &skip, &mut files)?;
+
+    if files.is_empty() {
+        anyhow::bail!(
+            "nothing to export: no {} or {}/ in {}",
+            CONFIG_FILE,
+            DATA_DIR,
+            project_cwd.display()
+        );
+    }
+    Ok(ConfigBundle {
+        version: BUNDLE_VERSION,
+        files,
+    })
+}
+
+/// Write a bundle's files into the project. Existing files are only
+/// replaced with `force`; otherwise the import fails before writing anything.
+pub fn import_bundle(
+    project_cwd: &Path,
+    bundle: &ConfigBundle,
+    force: bool,
+) -> Result<Vec<PathBuf>> {
+    if bundle.version > BUNDLE_VERSION {
+        anyhow::bail!(
+            "bundle version {} is newer than this assoc supports ({})",
+            bundle.version,
+            BUNDLE_VERSION
+        );
+    }
+
+    let mut targets = Vec::new();
+    for (rel, content) in &bundle.files {
+        let path = project_cwd.join(validate_bundle_path(rel)?);
+        if path.exists() && !force {
+            anyhow::bail!(
+                "{} already exists (use --force to overwrite)",
+                path.display()
+            );
+        }
+        targets.push((path, content));
+    }
+
+    let mut written = Vec::new();
+    for (path, content) in targets {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(&path, content)?;
+        written.push(path);
+    }
+    Ok(written)
+}
+
+/// `.assoc.toml` without API keys, passwords and webhook URLs. Other
+/// lines, comments included, are kept as written.
+fn strip_secrets(config: &str) -> String {
+    let mut table = String::new();
+    let mut out = String::new();
+    for line in config.lines() {
+        let trimmed = line.trim_start();
+        if trimmed.starts_with('[') {
+            table = trimmed
+                .trim_start_matches('[')
+                .split(']')
+                .next()
+                .unwrap_or("")
+                .trim()
+                .to_string();
+        } else if let Some((key, _)) = trimmed.split_once('=') {
+            let key = key.trim().trim_matches('"');
+            let full = if table.is_empty() {
+                key.to_string()
+            } else {
+                format!("{}.{}", table, key)
+            };
+            let indent = &line[..line.len() - trimmed.len()];
+            if SECRET_KEYS.contains(&full.as_str()) {
+                out.push_str(&format!(
+                    "{}# {} = \"...\"  # left out of the export; set your own\n",
+                    indent, key
+                ));
+                continue;
+            }
+            if BLANKED_KEYS.contains(&full.as_str()) {
+                out.push_str(&format!(
+                    "{}{} = \"\"  # left out of the export; set your own\n",
+                    indent, key
+                ));
+                continue;
+            }
+        }
+        out.push_str(line);
+        out.push('\n');
+    }
+    out
+}
+
+/// Only `.assoc.toml` and relative paths inside `.associate/` may be
+/// written by an import.
+fn validate_bundle_path(rel: &str) -> Result<PathBuf> {
+    let path = PathBuf::from(rel);
+    let safe = path.components().all(|c| matches!(c, Component::Normal(_)));
+    let allowed = rel == CONFIG_FILE || path.starts_with(DATA_DIR);
+    if !safe || !allowed || path == Path::new(DATA_DIR) {
+        anyhow::bail!("refusing to write {:?} from bundle", rel);
+    }
+    Ok(path)
+}
+
+fn collect_dir(
+    root: &Path,
+    dir: &Path,
+    skip: &[PathBuf],
+    files: &mut BTreeMap<String, String>,
+) -> Result<()> {
+    if !dir.is_dir() || skip.iter().any(|s| s == dir) {
+        return Ok(());
+    }
+    for entry in std::fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.is_dir() {
+            collect_dir(root, &path, skip, files)?;
+        } else if let Ok(content) = std::fs::read_to_string(&path) {
+            let rel = path
+                .strip_prefix(root)
+                .unwrap_or(&path)
+                .components()
+                .map(|c| c.as_os_str().to_string_lossy())
+                .collect::<Vec<_>>()
+                .join("/");
+            files.insert(rel, content);
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bundle_paths_are_confined_to_project_config() {
+        assert!(validate_bundle_path(".assoc.toml").is_ok());
+        assert!(validate_bundle_path(".associate/prompts/fix.md").is_ok());
+        assert!(validate_bundle_path("src/main.rs").is_err());
+        assert!(validate_bundle_path(".associate/../src/main.rs").is_err());
+        assert!(validate_bundle_path("/etc/passwd").is_err());
+    }
+
+    #[test]
+    fn strips_secrets_from_the_config() {
+        let config = r#"# Linear
+[linear]
+team = "ENG"
+api_key = "lin_api_123"
+
+[bitbucket]
+username = "me"
+app_password = "bb-secret"
+
+[jira]
+project = "PROJ"
+
+[[notifications.sinks]]
+kind = "slack"
+url = "https://hooks.slack.com/services/T000/B000/XXX"
+"#;
+        let stripped = strip_secrets(config);
+        for secret in ["lin_api_123", "bb-secret", "hooks.slack.com"] {
+            assert!(!stripped.contains(secret), "{} exported", secret);
+        }
+        assert!(stripped.contains("# Linear\n[linear]\nteam = \"ENG\""));
+        assert!(stripped.contains("project = \"PROJ\""));
+
+        let parsed: crate::config::ProjectConfig = toml::from_str(&stripped).unwrap();
+        assert_eq!(parsed.linear_api_key(), None);
+        assert_eq!(parsed.notification_sinks()[0].url, "");
+    }
+
+    #[test]
+    fn export_leaves_out_machine_local_data() {
+        let project = std::env::temp_dir().join(format!("assoc-bundle-{}", std::process::id()));
+        let data = project.join(DATA_DIR);
+        std::fs::create_dir_all(data.join("prompts")).unwrap();
+        std::fs::create_dir_all(data.join("trash").join("20250101-session")).unwrap();
+        std::fs::create_dir_all(data.join("cache")).unwrap();
+        std::fs::write(data.join("prompts").join("fix.md"), "Fix it").unwrap();
+        std::fs::write(
+            data.join("trash")
+                .join("20250101-session")
+                .join("abc.jsonl"),
+            "{}",
+        )
+        .unwrap();
+        std::fs::write(data.join("cache").join("index.json"), "{}").unwrap();
+
+        let bundle = export_bundle(&project).unwrap();
+        std::fs::remove_dir_all(&project).unwrap();
+        let paths: Vec<&str> = bundle.files.keys().map(|k| k.as_str()).collect();
+        assert_eq!(paths, vec![".associate/prompts/fix.md"]);
+    }
+}
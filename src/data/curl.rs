@@ -0,0 +1,61 @@
+//! curl with its options fed on stdin (`-K -`), so credentials, webhook
+//! URLs and request bodies don't show up in the process list.
+
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+use anyhow::{bail, Result};
+
+/// Run `curl -sS --fail` with `config` as `name = "value"` options read
+/// from stdin, then `args`. Returns stdout; on failure, curl's first line
+/// of stderr.
+pub fn run(config: &[(&str, &str)], args: &[&str]) -> Result<Vec<u8>> {
+    let mut child = Command::new("curl")
+        .args(["-sS", "--fail", "-K", "-"])
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()?;
+    if let Some(mut pipe) = child.stdin.take() {
+        pipe.write_all(config_text(config).as_bytes())?;
+        // Dropping stdin closes the pipe so curl sees EOF
+    }
+    let output = child.wait_with_output()?;
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        match stderr.lines().next().map(str::trim) {
+            Some(msg) if !msg.is_empty() => bail!("{}", msg),
+            _ => bail!("curl exited with {}", output.status),
+        }
+    }
+    Ok(output.stdout)
+}
+
+/// `config` in curl's config file syntax, one quoted option per line.
+fn config_text(config: &[(&str, &str)]) -> String {
+    config
+        .iter()
+        .map(|(name, value)| {
+            let value = value
+                .replace('\\', "\\\\")
+                .replace('"', "\\\"")
+                .replace('\n', "\\n")
+                .replace('\r', "\\r");
+            format!("{} = \"{}\"\n", name, value)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn quotes_config_values() {
+        assert_eq!(
+            config_text(&[("user", "me@x.com:to\"k\\en"), ("data-binary", "a\nb")]),
+            "user = \"me@x.com:to\\\"k\\\\en\"\ndata-binary = \"a\\nb\"\n"
+        );
+    }
+}
@@ -0,0 +1,162 @@
+use std::path::Path;
+use std::process::Command;
+
+use anyhow::{bail, Result};
+use serde::Deserialize;
+
+use crate::model::deps::OutdatedDep;
+
+/// Checker names understood by `run_checker`.
+pub const KNOWN_CHECKERS: &[&str] = &["cargo", "npm"];
+
+/// Run one dependency checker in `cwd` and return the outdated dependencies it reports.
+pub fn run_checker(cwd: &Path, checker: &str) -> Result<Vec<OutdatedDep>> {
+    match checker {
+        "cargo" => {
+            let output = Command::new("cargo")
+                .args(["outdated", "--root-deps-only", "--format", "json"])
+                .current_dir(cwd)
+                .output()?;
+            if !output.status.success() {
+                bail!(
+                    "{}",
+                    first_stderr_line(&output.stderr, "cargo outdated failed")
+                );
+            }
+            parse_cargo_outdated(&String::from_utf8_lossy(&output.stdout))
+        }
+        "npm" => {
+            // npm is a .cmd shim on Windows, so it has to go through cmd
+            let output = Command::new("cmd")
+                .args(["/C", "npm", "outdated", "--json"])
+                .current_dir(cwd)
+                .output()?;
+            // npm exits with 1 when anything is outdated, so only trust stdout
+            let stdout = String::from_utf8_lossy(&output.stdout);
+            if stdout.trim().is_empty() && !output.status.success() {
+                bail!(
+                    "{}",
+                    first_stderr_line(&output.stderr, "npm outdated failed")
+                );
+            }
+            parse_npm_outdated(&stdout)
+        }
+        other => bail!(
+            "unknown checker '{}' (expected one of: {})",
+            other,
+            KNOWN_CHECKERS.join(", ")
+        ),
+    }
+}
+
+fn first_stderr_line(stderr: &[u8], fallback: &str) -> String {
+    String::from_utf8_lossy(stderr)
+        .lines()
+        .map(str::trim)
+        .find(|l| !l.is_empty())
+        .unwrap_or(fallback)
+        .to_string()
+}
+
+#[derive(Deserialize)]
+struct CargoOutdatedCrate {
+    #[serde(default)]
+    dependencies: Vec<CargoOutdatedDep>,
+}
+
+#[derive(Deserialize)]
+struct CargoOutdatedDep {
+    name: String,
+    project: String,
+    compat: String,
+    latest: String,
+    #[serde(default)]
+    kind: Option<String>,
+}
+
+/// Parse `cargo outdated --format json`, which prints one JSON object per workspace member.
+fn parse_cargo_outdated(output: &str) -> Result<Vec<OutdatedDep>> {
+    let mut deps = Vec::new();
+    for line in output.lines().filter(|l| l.trim_start().starts_with('{')) {
+        let krate: CargoOutdatedCrate = serde_json::from_str(line)?;
+        for d in krate.dependencies {
+            // "---" means no newer version; "Removed" means the crate was yanked
+            if d.latest == "---" || d.latest == "Removed" {
+                continue;
+            }
+            if deps
+                .iter()
+                .any(|e: &OutdatedDep| e.name == d.name && e.current.as_deref() == Some(&d.project))
+            {
+                continue;
+            }
+            deps.push(OutdatedDep {
+                ecosystem: "cargo".to_string(),
+                name: d.name,
+                current: Some(d.project),
+                wanted: (d.compat != "---").then_some(d.compat),
+                latest: d.latest,
+                kind: d.kind,
+            });
+        }
+    }
+    Ok(deps)
+}
+
+#[derive(Deserialize)]
+struct NpmOutdatedEntry {
+    #[serde(default)]
+    current: Option<String>,
+    #[serde(default)]
+    wanted: Option<String>,
+    latest: String,
+    #[serde(default)]
+    dependent: Option<String>,
+}
+
+/// Parse `npm outdated --json`: an object keyed by package name.
+fn parse_npm_outdated(output: &str) -> Result<Vec<OutdatedDep>> {
+    if output.trim().is_empty() {
+        return Ok(vec![]);
+    }
+    let map: std::collections::BTreeMap<String, NpmOutdatedEntry> = serde_json::from_str(output)?;
+    Ok(map
+        .into_iter()
+        .map(|(name, e)| OutdatedDep {
+            ecosystem: "npm".to_string(),
+            name,
+            current: e.current,
+            wanted: e.wanted,
+            latest: e.latest,
+            kind: e.dependent,
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_cargo_outdated() {
+        let output = r#"{"crate_name":"assoc","dependencies":[{"name":"ratatui","project":"0.28.1","compat":"---","latest":"0.29.0","kind":"Normal","platform":null},{"name":"serde","project":"1.0.200","compat":"1.0.210","latest":"1.0.210","kind":"Normal","platform":null},{"name":"old","project":"0.1.0","compat":"---","latest":"Removed","kind":"Normal","platform":null}]}"#;
+        let deps = parse_cargo_outdated(output).unwrap();
+        assert_eq!(deps.len(), 2);
+        assert_eq!(deps[0].name, "ratatui");
+        assert_eq!(deps[0].wanted, None);
+        assert!(deps[0].is_breaking());
+        assert_eq!(deps[1].wanted.as_deref(), Some("1.0.210"));
+        assert!(!deps[1].is_breaking());
+    }
+
+    #[test]
+    fn test_parse_npm_outdated() {
+        let output = r#"{"react":{"current":"17.0.2","wanted":"17.0.2","latest":"18.3.1","dependent":"web"},"left-pad":{"wanted":"1.3.0","latest":"1.3.0"}}"#;
+        let deps = parse_npm_outdated(output).unwrap();
+        assert_eq!(deps.len(), 2);
+        assert_eq!(deps[0].name, "left-pad");
+        assert_eq!(deps[0].current_display(), "(missing)");
+        assert!(deps[1].is_breaking());
+        assert!(parse_npm_outdated("").unwrap().is_empty());
+    }
+}
@@ -0,0 +1,90 @@
+use std::path::{Path, PathBuf};
+
+use anyhow::Result;
+use chrono::{DateTime, Local};
+
+/// Where unsaved edits are kept when the app shuts down with an editor open.
+pub fn drafts_dir(assoc_home: &Path, encoded_project: &str) -> PathBuf {
+    assoc_home.join("drafts").join(encoded_project)
+}
+
+/// Write each `(name, text)` draft to `dir` as `<time>-<name>.md` and
+/// return the files written.
+pub fn save_drafts(
+    dir: &Path,
+    drafts: &[(String, String)],
+    now: DateTime<Local>,
+) -> Result<Vec<PathBuf>> {
+    if drafts.is_empty() {
+        return Ok(Vec::new());
+    }
+    std::fs::create_dir_all(dir)?;
+    let stamp = now.format("%Y%m%d-%H%M%S");
+    let mut written = Vec::new();
+    for (name, text) in drafts {
+        let path = dir.join(format!("{}-{}.md", stamp, file_safe(name)));
+        std::fs::write(&path, text)?;
+        written.push(path);
+    }
+    Ok(written)
+}
+
+/// Number of drafts waiting in `dir`.
+pub fn count_drafts(dir: &Path) -> usize {
+    std::fs::read_dir(dir)
+        .map(|entries| {
+            entries
+                .flatten()
+                .filter(|e| e.path().extension().is_some_and(|x| x == "md"))
+                .count()
+        })
+        .unwrap_or(0)
+}
+
+/// `name` with anything but letters, digits, `-`, `_` and `.` replaced.
+fn file_safe(name: &str) -> String {
+    name.chars()
+        .map(|c| {
+            if c.is_alphanumeric() || matches!(c, '-' | '_' | '.') {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn saves_drafts_with_safe_names() {
+        let dir = std::env::temp_dir().join(format!("assoc-drafts-{}", std::process::id()));
+        let now = "2025-04-01T09:30:00+00:00"
+            .parse::<DateTime<chrono::FixedOffset>>()
+            .unwrap()
+            .with_timezone(&Local);
+        let drafts = vec![
+            ("plan-roadmap.md".to_string(), "# Roadmap".to_string()),
+            ("issue new/title".to_string(), "Crash".to_string()),
+        ];
+        let written = save_drafts(&dir, &drafts, now).unwrap();
+        let stamp = now.format("%Y%m%d-%H%M%S").to_string();
+        let names: Vec<String> = written
+            .iter()
+            .map(|p| p.file_name().unwrap().to_string_lossy().to_string())
+            .collect();
+        assert_eq!(
+            names,
+            [
+                format!("{}-plan-roadmap.md.md", stamp),
+                format!("{}-issue_new_title.md", stamp)
+            ]
+        );
+        assert_eq!(std::fs::read_to_string(&written[1]).unwrap(), "Crash");
+        assert_eq!(count_drafts(&dir), 2);
+        assert!(save_drafts(&dir, &[], now).unwrap().is_empty());
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}
@@ -1,11 +1,15 @@
 use std::collections::HashSet;
-use std::path::{Path, PathBuf};
+use std::fs::File;
+use std::io::{BufRead, BufReader, Read, Seek, SeekFrom};
+use std::path::{Component, Path, PathBuf};
 use std::process::Command;
 
-use anyhow::Result;
+use anyhow::{bail, Result};
 
 use crate::data::plans;
-use crate::model::filebrowser::{EntryKind, FileBrowserEntry, FileContent};
+use crate::model::filebrowser::{
+    EntryKind, FileBrowserEntry, FileContent, FileInfo, ImagePreview, LargeFile,
+};
 
 const MAX_DEPTH: usize = 20;
 
@@ -142,37 +146,362 @@ fn is_ignored(root: &Path, path: &Path, ignored: &HashSet<PathBuf>) -> bool {
     false
 }
 
-/// Read file content for display.
-pub fn read_file_content(path: &Path) -> Result<FileContent> {
-    let metadata = std::fs::metadata(path)?;
+/// Largest file shown as text or a hexdump.
+const MAX_CONTENT_BYTES: u64 = 1_048_576;
+/// Largest image file decoded for a preview.
+const MAX_IMAGE_BYTES: u64 = 16 * 1_048_576;
+/// Images with more pixels than this are not decoded.
+const MAX_IMAGE_PIXELS: u64 = 40_000_000;
+/// Binary files up to this size are shown as a hexdump.
+const MAX_HEXDUMP_BYTES: usize = 64 * 1024;
+/// Longest side of the pixels kept for an image preview.
+pub const PREVIEW_MAX_SIDE: u32 = 320;
+/// Lines between the offsets kept in a large file's line index.
+pub const LINE_CHECKPOINT: usize = 1024;
+/// Lines of a large file held in memory at once.
+const PAGE_LINES: usize = 1000;
+/// Longest line kept from a large file; the rest of it is dropped.
+pub const MAX_LINE_BYTES: usize = 4096;
+/// Bytes read at a time when counting a large file's lines.
+const SCAN_CHUNK: usize = 256 * 1024;
 
-    // If file > 1MB, return TooLarge
-    if metadata.len() > 1_048_576 {
-        return Ok(FileContent::TooLarge);
+/// Read file content for display, with its size and detected type.
+pub fn read_file_content(path: &Path) -> Result<(FileContent, FileInfo)> {
+    let size = std::fs::metadata(path)?.len();
+    let by_extension = mime_from_extension(path);
+    let is_image = matches!(by_extension, Some("image/png" | "image/jpeg"));
+    let limit = if is_image {
+        MAX_IMAGE_BYTES
+    } else {
+        MAX_CONTENT_BYTES
+    };
+    if size > limit {
+        if !is_image && starts_as_text(path)? {
+            let mime = by_extension.unwrap_or("text/plain");
+            let mut file = LargeFile {
+                size,
+                checkpoints: vec![0],
+                ..LargeFile::default()
+            };
+            page_large_file(path, &mut file, 0)?;
+            return Ok((FileContent::Large(file), FileInfo { size, mime }));
+        }
+        let mime = by_extension.unwrap_or("application/octet-stream");
+        return Ok((FileContent::TooLarge, FileInfo { size, mime }));
     }
 
-    // Try to read as raw bytes first
     let bytes = std::fs::read(path)?;
-
-    // Try to interpret as UTF-8
-    let text = match String::from_utf8(bytes) {
-        Ok(s) => s,
-        Err(_) => return Ok(FileContent::Binary),
+    let binary = is_binary(&bytes);
+    // Magic bytes only mean something in binary files ("BM..." is text too)
+    let mime = if binary {
+        sniff_mime(&bytes)
+            .or(by_extension)
+            .unwrap_or("application/octet-stream")
+    } else {
+        by_extension.unwrap_or("text/plain")
     };
+    let info = FileInfo { size, mime };
+
+    if matches!(mime, "image/png" | "image/jpeg") {
+        if let Some(preview) = decode_image(&bytes, mime) {
+            return Ok((FileContent::Image(preview), info));
+        }
+    }
+    if binary {
+        let content = if bytes.len() <= MAX_HEXDUMP_BYTES {
+            FileContent::Hexdump(hexdump(&bytes))
+        } else if size > MAX_CONTENT_BYTES {
+            FileContent::TooLarge
+        } else {
+            FileContent::Binary
+        };
+        return Ok((content, info));
+    }
+    // Not binary, so valid UTF-8
+    let text = String::from_utf8(bytes).unwrap_or_default();
 
     // If .md extension, parse with markdown parser
-    let is_markdown = path
-        .extension()
-        .and_then(|e| e.to_str())
-        .map(|e| e.eq_ignore_ascii_case("md"))
-        .unwrap_or(false);
-
-    if is_markdown {
-        let lines = plans::parse_markdown_lines(&text);
-        Ok(FileContent::Markdown(lines))
+    let content = if mime == "text/markdown" {
+        FileContent::Markdown(plans::parse_markdown_lines(&text))
     } else {
-        let lines: Vec<String> = text.lines().map(|l| l.to_string()).collect();
-        Ok(FileContent::Text(lines))
+        FileContent::Text(text.lines().map(|l| l.to_string()).collect())
+    };
+    Ok((content, info))
+}
+
+/// Whether `bytes` should be shown as binary: it is not UTF-8 or has a NUL
+/// near the start, the way git decides.
+fn is_binary(bytes: &[u8]) -> bool {
+    bytes[..bytes.len().min(8000)].contains(&0) || std::str::from_utf8(bytes).is_err()
+}
+
+/// Whether the start of the file looks like text, for files too large to
+/// check whole.
+fn starts_as_text(path: &Path) -> Result<bool> {
+    let mut head = Vec::with_capacity(8000);
+    File::open(path)?.take(8000).read_to_end(&mut head)?;
+    // The 8000 bytes may end partway through a character
+    let utf8 = match std::str::from_utf8(&head) {
+        Ok(_) => true,
+        Err(e) => e.error_len().is_none(),
+    };
+    Ok(utf8 && !head.contains(&0))
+}
+
+/// Load the page of `file` around `line`, counting its lines only as far as
+/// that needs. `usize::MAX` loads the end of the file.
+pub fn page_large_file(path: &Path, file: &mut LargeFile, line: usize) -> Result<()> {
+    count_lines(path, file, line.saturating_add(PAGE_LINES))?;
+    let count = file.line_count();
+    let line = line.min(count.saturating_sub(1));
+
+    // Keep the page while it still reaches a screen past `line`
+    let end = file.start + file.lines.len();
+    let at_end = file.fully_scanned() && end >= count;
+    if !file.lines.is_empty() && line >= file.start && (line + PAGE_LINES / 4 <= end || at_end) {
+        return Ok(());
+    }
+
+    let mut start = line.saturating_sub(PAGE_LINES / 2);
+    if file.fully_scanned() {
+        start = start.min(count.saturating_sub(PAGE_LINES));
+    }
+    let checkpoint = (start / LINE_CHECKPOINT).min(file.checkpoints.len() - 1);
+    let offset = file.checkpoints[checkpoint];
+    let mut reader = File::open(path)?;
+    reader.seek(SeekFrom::Start(offset))?;
+    let mut reader = BufReader::new(reader).take(file.size.saturating_sub(offset));
+
+    let mut lines = Vec::with_capacity(PAGE_LINES);
+    let mut buf = Vec::new();
+    let mut at = checkpoint * LINE_CHECKPOINT;
+    while lines.len() < PAGE_LINES {
+        buf.clear();
+        if read_capped_line(&mut reader, &mut buf)? == 0 {
+            break;
+        }
+        if at >= start {
+            lines.push(String::from_utf8_lossy(&buf).into_owned());
+        }
+        at += 1;
+    }
+    file.start = start;
+    file.lines = lines;
+    Ok(())
+}
+
+/// Count `file`'s lines until `until` of them are known or the file ends,
+/// noting an offset every `LINE_CHECKPOINT` lines.
+fn count_lines(path: &Path, file: &mut LargeFile, until: usize) -> Result<()> {
+    if file.lines_scanned >= until || file.fully_scanned() {
+        return Ok(());
+    }
+    let mut reader = File::open(path)?;
+    reader.seek(SeekFrom::Start(file.bytes_scanned))?;
+    let mut reader = reader.take(file.size - file.bytes_scanned);
+    let mut chunk = vec![0; SCAN_CHUNK];
+    while file.lines_scanned < until {
+        let read = reader.read(&mut chunk)?;
+        if read == 0 {
+            // Truncated since it was opened
+            file.size = file.bytes_scanned;
+            break;
+        }
+        for (i, _) in chunk[..read]
+            .iter()
+            .enumerate()
+            .filter(|(_, &b)| b == b'\n')
+        {
+            file.lines_scanned += 1;
+            file.last_line_start = file.bytes_scanned + i as u64 + 1;
+            if file.lines_scanned.is_multiple_of(LINE_CHECKPOINT) {
+                file.checkpoints.push(file.last_line_start);
+            }
+        }
+        file.bytes_scanned += read as u64;
+    }
+    Ok(())
+}
+
+/// Read one line into `line`, without its line ending and cut to
+/// `MAX_LINE_BYTES`. Returns the bytes consumed, 0 at the end of the file.
+fn read_capped_line(reader: &mut impl BufRead, line: &mut Vec<u8>) -> std::io::Result<usize> {
+    let mut consumed = 0;
+    loop {
+        let available = reader.fill_buf()?;
+        if available.is_empty() {
+            break;
+        }
+        let newline = available.iter().position(|&b| b == b'\n');
+        let text = &available[..newline.unwrap_or(available.len())];
+        let room = MAX_LINE_BYTES.saturating_sub(line.len());
+        line.extend_from_slice(&text[..text.len().min(room)]);
+        let used = newline.map_or(available.len(), |i| i + 1);
+        reader.consume(used);
+        consumed += used;
+        if newline.is_some() {
+            break;
+        }
+    }
+    if line.last() == Some(&b'\r') {
+        line.pop();
+    }
+    Ok(consumed)
+}
+
+/// Pick up lines appended to `file` since it was read. Returns whether it
+/// changed; a file that shrank is counted again from the start.
+pub fn refresh_large_file(path: &Path, file: &mut LargeFile) -> Result<bool> {
+    let size = std::fs::metadata(path)?.len();
+    if size == file.size {
+        return Ok(false);
+    }
+    if size < file.size {
+        *file = LargeFile {
+            checkpoints: vec![0],
+            ..LargeFile::default()
+        };
+    }
+    file.size = size;
+    Ok(true)
+}
+
+/// MIME type from the file's leading magic bytes.
+fn sniff_mime(bytes: &[u8]) -> Option<&'static str> {
+    const MAGIC: &[(&[u8], &str)] = &[
+        (b"\x89PNG\r\n\x1a\n", "image/png"),
+        (b"\xff\xd8\xff", "image/jpeg"),
+        (b"GIF87a", "image/gif"),
+        (b"GIF89a", "image/gif"),
+        (b"BM", "image/bmp"),
+        (b"\0\0\x01\0", "image/x-icon"),
+        (b"%PDF-", "application/pdf"),
+        (b"PK\x03\x04", "application/zip"),
+        (b"\x1f\x8b", "application/gzip"),
+        (b"\x7fELF", "application/x-elf"),
+        (b"MZ", "application/x-msdownload"),
+        (b"\0asm", "application/wasm"),
+        (b"SQLite format 3\0", "application/vnd.sqlite3"),
+    ];
+    if bytes.len() >= 12 && &bytes[..4] == b"RIFF" && &bytes[8..12] == b"WEBP" {
+        return Some("image/webp");
+    }
+    MAGIC
+        .iter()
+        .find(|(magic, _)| bytes.starts_with(magic))
+        .map(|(_, mime)| *mime)
+}
+
+/// MIME type from the file extension, for the types worth naming.
+fn mime_from_extension(path: &Path) -> Option<&'static str> {
+    let ext = path.extension()?.to_str()?.to_ascii_lowercase();
+    Some(match ext.as_str() {
+        "png" => "image/png",
+        "jpg" | "jpeg" => "image/jpeg",
+        "gif" => "image/gif",
+        "webp" => "image/webp",
+        "svg" => "image/svg+xml",
+        "md" => "text/markdown",
+        "json" => "application/json",
+        "toml" => "application/toml",
+        "html" | "htm" => "text/html",
+        "pdf" => "application/pdf",
+        "zip" => "application/zip",
+        _ => return None,
+    })
+}
+
+/// `xxd`-style rows: offset, 16 bytes in hex and their printable ASCII.
+fn hexdump(bytes: &[u8]) -> Vec<String> {
+    bytes
+        .chunks(16)
+        .enumerate()
+        .map(|(row, chunk)| {
+            let mut line = format!("{:08x} ", row * 16);
+            for i in 0..16 {
+                if i == 8 {
+                    line.push(' ');
+                }
+                match chunk.get(i) {
+                    Some(b) => line.push_str(&format!(" {:02x}", b)),
+                    None => line.push_str("   "),
+                }
+            }
+            line.push_str("  |");
+            line.extend(chunk.iter().map(|&b| {
+                if b.is_ascii_graphic() || b == b' ' {
+                    b as char
+                } else {
+                    '.'
+                }
+            }));
+            line.push('|');
+            line
+        })
+        .collect()
+}
+
+/// Decode a PNG or JPEG and scale it down for the preview. `None` when
+/// the image is corrupt or too large to decode.
+fn decode_image(bytes: &[u8], mime: &str) -> Option<ImagePreview> {
+    let (width, height, channels, data) = if mime == "image/png" {
+        let mut decoder = png::Decoder::new(std::io::Cursor::new(bytes));
+        decoder.set_transformations(png::Transformations::normalize_to_color8());
+        let mut reader = decoder.read_info().ok()?;
+        let (width, height) = (reader.info().width, reader.info().height);
+        if u64::from(width) * u64::from(height) > MAX_IMAGE_PIXELS {
+            return None;
+        }
+        let mut data = vec![0; reader.output_buffer_size()];
+        let frame = reader.next_frame(&mut data).ok()?;
+        let channels = frame.color_type.samples();
+        data.truncate(frame.buffer_size());
+        (width, height, channels, data)
+    } else {
+        let mut decoder = zune_jpeg::JpegDecoder::new(bytes);
+        decoder.decode_headers().ok()?;
+        let (width, height) = decoder.dimensions()?;
+        if width as u64 * height as u64 > MAX_IMAGE_PIXELS {
+            return None;
+        }
+        let data = decoder.decode().ok()?;
+        let channels = data.len() / (width * height).max(1);
+        (width as u32, height as u32, channels, data)
+    };
+    if width == 0 || height == 0 || data.len() < (width * height) as usize * channels {
+        return None;
+    }
+
+    let scale = (f64::from(PREVIEW_MAX_SIDE) / f64::from(width.max(height))).min(1.0);
+    let preview_width = ((f64::from(width) * scale).round() as u32).max(1);
+    let preview_height = ((f64::from(height) * scale).round() as u32).max(1);
+    let mut pixels = Vec::with_capacity((preview_width * preview_height) as usize);
+    for y in 0..preview_height {
+        let source_y = (u64::from(y) * u64::from(height) / u64::from(preview_height)) as usize;
+        for x in 0..preview_width {
+            let source_x = (u64::from(x) * u64::from(width) / u64::from(preview_width)) as usize;
+            let at = (source_y * width as usize + source_x) * channels;
+            pixels.push(to_rgb(&data[at..at + channels]));
+        }
+    }
+    Some(ImagePreview {
+        original_width: width,
+        original_height: height,
+        width: preview_width,
+        height: preview_height,
+        pixels,
+    })
+}
+
+/// One gray, gray+alpha, RGB or RGBA pixel as RGB on a black background.
+fn to_rgb(sample: &[u8]) -> [u8; 3] {
+    let blend = |c: u8, a: u8| (u16::from(c) * u16::from(a) / 255) as u8;
+    match *sample {
+        [g] => [g, g, g],
+        [g, a] => [blend(g, a); 3],
+        [r, g, b] => [r, g, b],
+        [r, g, b, a, ..] => [blend(r, a), blend(g, a), blend(b, a)],
+        [] => [0, 0, 0],
     }
 }
 
@@ -181,3 +510,200 @@ pub fn save_file(path: &Path, content: &str) -> Result<()> {
     std::fs::write(path, content)?;
     Ok(())
 }
+
+/// `relative` as a path inside the project at `root`. Refuses absolute
+/// paths, `..`, `.git`, and folders a symlink takes outside the project.
+pub fn project_path(root: &Path, relative: &str) -> Result<PathBuf> {
+    let relative = relative.trim().trim_end_matches(['/', '\\']);
+    let mut path = root.to_path_buf();
+    for component in Path::new(relative).components() {
+        match component {
+            Component::Normal(name) if name == ".git" => bail!("{} is inside .git", relative),
+            Component::Normal(name) => path.push(name),
+            Component::CurDir => {}
+            _ => bail!("{} is outside the project", relative),
+        }
+    }
+    if path == root {
+        bail!("No path given");
+    }
+    let root = root.canonicalize()?;
+    let inside = path
+        .ancestors()
+        .skip(1)
+        .find(|dir| dir.exists())
+        .and_then(|dir| dir.canonicalize().ok())
+        .is_some_and(|dir| dir.starts_with(&root));
+    if !inside {
+        bail!("{} is outside the project", relative);
+    }
+    Ok(path)
+}
+
+/// Create an empty file, and any folders it needs.
+pub fn create_file(path: &Path) -> Result<()> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    match std::fs::OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(path)
+    {
+        Ok(_) => Ok(()),
+        Err(e) if e.kind() == std::io::ErrorKind::AlreadyExists => {
+            bail!("{} already exists", path.display())
+        }
+        Err(e) => Err(e.into()),
+    }
+}
+
+/// Create a folder, and any folders above it.
+pub fn create_dir(path: &Path) -> Result<()> {
+    if path.exists() {
+        bail!("{} already exists", path.display());
+    }
+    std::fs::create_dir_all(path)?;
+    Ok(())
+}
+
+/// Rename or move `from` to `to`, creating folders for it. Never replaces
+/// an existing file.
+pub fn rename_path(from: &Path, to: &Path) -> Result<()> {
+    if to.exists() {
+        bail!("{} already exists", to.display());
+    }
+    if to.starts_with(from) {
+        bail!("Can't move a folder into itself");
+    }
+    if let Some(parent) = to.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    std::fs::rename(from, to)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn previews_binaries_and_images() {
+        let dir = std::env::temp_dir().join(format!("assoc-fb-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+
+        let blob = dir.join("blob.bin");
+        std::fs::write(&blob, b"\x7fELF\x02\x01\x01\0\0\0\0\0\0\0\0\0AB").unwrap();
+        let (content, info) = read_file_content(&blob).unwrap();
+        assert_eq!(info.mime, "application/x-elf");
+        let FileContent::Hexdump(rows) = content else {
+            panic!("expected a hexdump");
+        };
+        assert_eq!(
+            rows,
+            [
+                "00000000  7f 45 4c 46 02 01 01 00  00 00 00 00 00 00 00 00  |.ELF............|",
+                "00000010  41 42                                             |AB|",
+            ]
+        );
+
+        // 2x2 RGBA: red, half-transparent white, blue, fully transparent
+        let png_path = dir.join("pixels.png");
+        let mut png_bytes = Vec::new();
+        {
+            let mut encoder = png::Encoder::new(&mut png_bytes, 2, 2);
+            encoder.set_color(png::ColorType::Rgba);
+            let mut writer = encoder.write_header().unwrap();
+            writer
+                .write_image_data(&[
+                    255, 0, 0, 255, 255, 255, 255, 128, 0, 0, 255, 255, 9, 9, 9, 0,
+                ])
+                .unwrap();
+        }
+        std::fs::write(&png_path, &png_bytes).unwrap();
+        let (content, info) = read_file_content(&png_path).unwrap();
+        assert_eq!(info.mime, "image/png");
+        assert_eq!(info.size, png_bytes.len() as u64);
+        let FileContent::Image(image) = content else {
+            panic!("expected an image");
+        };
+        assert_eq!((image.width, image.height), (2, 2));
+        assert_eq!(
+            image.pixels,
+            [[255, 0, 0], [128, 128, 128], [0, 0, 255], [0, 0, 0]]
+        );
+
+        let text = dir.join("notes.md");
+        std::fs::write(&text, "BMW notes\n").unwrap();
+        let (content, info) = read_file_content(&text).unwrap();
+        assert!(matches!(content, FileContent::Markdown(_)));
+        assert_eq!(info.mime, "text/markdown");
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn creates_and_renames_inside_the_project() {
+        let root = std::env::temp_dir().join(format!("assoc-fb-ops-{}", std::process::id()));
+        std::fs::create_dir_all(&root).unwrap();
+
+        for outside in ["", "../x", "/etc/passwd", "src/../../x", ".git/config"] {
+            assert!(project_path(&root, outside).is_err(), "{}", outside);
+        }
+        let file = project_path(&root, "src/new/lib.rs").unwrap();
+        assert_eq!(file, root.join("src").join("new").join("lib.rs"));
+        create_file(&file).unwrap();
+        assert!(create_file(&file).is_err());
+
+        let dir = project_path(&root, "docs/").unwrap();
+        create_dir(&dir).unwrap();
+        assert!(dir.is_dir());
+
+        let moved = project_path(&root, "docs/guide/lib.rs").unwrap();
+        rename_path(&file, &moved).unwrap();
+        assert!(moved.is_file() && !file.exists());
+        assert!(rename_path(&dir, &dir.join("inner")).is_err());
+
+        let _ = std::fs::remove_dir_all(&root);
+    }
+
+    #[test]
+    fn pages_large_files() {
+        let dir = std::env::temp_dir().join(format!("assoc-fb-large-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let log = dir.join("app.log");
+        let mut text: String = (0..60_000)
+            .map(|n| format!("line {:05} of the log\n", n))
+            .collect();
+        text.push_str(&"x".repeat(10_000));
+        std::fs::write(&log, &text).unwrap();
+
+        let (content, info) = read_file_content(&log).unwrap();
+        assert_eq!(info.mime, "text/plain");
+        let FileContent::Large(mut file) = content else {
+            panic!("expected a large file");
+        };
+        assert_eq!(file.start, 0);
+        assert_eq!(file.lines.len(), PAGE_LINES);
+        assert!(!file.fully_scanned());
+
+        page_large_file(&log, &mut file, 30_000).unwrap();
+        assert_eq!(file.lines[30_000 - file.start], "line 30000 of the log");
+
+        // The end: an unterminated last line, cut short
+        page_large_file(&log, &mut file, usize::MAX).unwrap();
+        assert!(file.fully_scanned());
+        assert_eq!(file.line_count(), 60_001);
+        assert_eq!(file.lines.last().unwrap().len(), MAX_LINE_BYTES);
+
+        // Appended lines are picked up
+        let mut appended = std::fs::OpenOptions::new().append(true).open(&log).unwrap();
+        std::io::Write::write_all(&mut appended, b"\nlast\n").unwrap();
+        assert!(refresh_large_file(&log, &mut file).unwrap());
+        page_large_file(&log, &mut file, usize::MAX).unwrap();
+        assert_eq!(file.line_count(), 60_002);
+        assert_eq!(file.lines.last().unwrap(), "last");
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}
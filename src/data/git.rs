@@ -1,9 +1,13 @@
 use std::path::Path;
 use std::process::Command;
+use std::sync::atomic::{AtomicUsize, Ordering};
 
-use anyhow::Result;
+use anyhow::{bail, Result};
 
-use crate::model::git::{DiffLine, DiffLineKind, GitFileEntry, GitFileSection, GitStatus};
+use crate::model::git::{
+    BranchChanges, DiffAnchor, DiffLine, DiffLineKind, GitBranch, GitFileEntry, GitFileSection,
+    GitStash, GitStatus, GitWorktree, SplitDiffRow, WordSpan,
+};
 
 /// Load git status by running `git status --porcelain` in the given directory.
 /// Returns an empty GitStatus if git is not available or cwd is not a repo.
@@ -72,9 +76,291 @@ pub fn load_git_status(cwd: &Path) -> Result<GitStatus> {
         }
     }
 
+    status.stashes = list_stashes(cwd).unwrap_or_default();
     Ok(status)
 }
 
+/// Run a git command and return its stdout, or bail with its stderr.
+fn run_git(cwd: &Path, args: &[&str]) -> Result<String> {
+    run_git_command(Command::new("git"), cwd, args)
+}
+
+/// `run_git` against another index file, leaving the real one alone.
+fn run_git_with_index(cwd: &Path, index: &Path, args: &[&str]) -> Result<String> {
+    let mut cmd = Command::new("git");
+    cmd.env("GIT_INDEX_FILE", index);
+    run_git_command(cmd, cwd, args)
+}
+
+fn run_git_command(mut cmd: Command, cwd: &Path, args: &[&str]) -> Result<String> {
+    let output = cmd.args(args).current_dir(cwd).output()?;
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        let msg = stderr.lines().next().unwrap_or("").trim();
+        if msg.is_empty() {
+            bail!("git {} exited with {}", args[0], output.status);
+        }
+        bail!("{}", msg);
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).to_string())
+}
+
+/// List local branches, current branch first.
+pub fn list_branches(cwd: &Path) -> Result<Vec<GitBranch>> {
+    let stdout = run_git(
+        cwd,
+        &[
+            "branch",
+            "--format=%(HEAD)%00%(refname:short)%00%(upstream:short)%00%(worktreepath)",
+        ],
+    )?;
+
+    let mut branches: Vec<GitBranch> = stdout
+        .lines()
+        .filter_map(|line| {
+            let mut parts = line.split('\0');
+            let head = parts.next()?;
+            let name = parts.next()?.to_string();
+            // Skip "(HEAD detached at ...)" pseudo-entries
+            if name.starts_with('(') {
+                return None;
+            }
+            let non_empty = |s: Option<&str>| s.filter(|s| !s.is_empty()).map(String::from);
+            Some(GitBranch {
+                name,
+                is_current: head == "*",
+                upstream: non_empty(parts.next()),
+                worktree_path: non_empty(parts.next()),
+            })
+        })
+        .collect();
+
+    branches.sort_by_key(|b| !b.is_current);
+    Ok(branches)
+}
+
+/// List all worktrees of the repository, main worktree first.
+pub fn list_worktrees(cwd: &Path) -> Result<Vec<GitWorktree>> {
+    let stdout = run_git(cwd, &["worktree", "list", "--porcelain"])?;
+    Ok(parse_worktree_list(&stdout))
+}
+
+fn parse_worktree_list(output: &str) -> Vec<GitWorktree> {
+    let mut worktrees = Vec::new();
+    let mut current: Option<GitWorktree> = None;
+
+    for line in output.lines() {
+        if let Some(path) = line.strip_prefix("worktree ") {
+            if let Some(wt) = current.take() {
+                worktrees.push(wt);
+            }
+            current = Some(GitWorktree {
+                path: path.to_string(),
+                ..Default::default()
+            });
+        } else if let Some(wt) = current.as_mut() {
+            if let Some(head) = line.strip_prefix("HEAD ") {
+                wt.head = head.to_string();
+            } else if let Some(branch) = line.strip_prefix("branch ") {
+                let short = branch.strip_prefix("refs/heads/").unwrap_or(branch);
+                wt.branch = Some(short.to_string());
+            } else if line == "bare" {
+                wt.is_bare = true;
+            }
+        }
+    }
+    if let Some(wt) = current {
+        worktrees.push(wt);
+    }
+
+    worktrees
+}
+
+/// Switch the working tree to an existing local branch.
+pub fn switch_branch(cwd: &Path, name: &str) -> Result<()> {
+    run_git(cwd, &["switch", name])?;
+    Ok(())
+}
+
+/// Create a new branch from the current HEAD without switching to it.
+pub fn create_branch(cwd: &Path, name: &str) -> Result<()> {
+    run_git(cwd, &["branch", name])?;
+    Ok(())
+}
+
+/// Create a linked worktree at `path` on a new branch from the current HEAD.
+pub fn add_worktree(cwd: &Path, path: &Path, branch: &str) -> Result<()> {
+    let path = path.to_string_lossy();
+    run_git(cwd, &["worktree", "add", "-b", branch, &path])?;
+    Ok(())
+}
+
+/// List stashes, newest first, with how much each one changes.
+pub fn list_stashes(cwd: &Path) -> Result<Vec<GitStash>> {
+    let stdout = run_git(cwd, &["stash", "list", "--format=%gd%x00%s", "--shortstat"])?;
+    Ok(parse_stash_list(&stdout))
+}
+
+/// Parse `git stash list --format=%gd%x00%s --shortstat`: a ref and subject
+/// line per stash, followed by its shortstat line unless it is empty.
+fn parse_stash_list(output: &str) -> Vec<GitStash> {
+    let mut stashes: Vec<GitStash> = Vec::new();
+    for line in output.lines() {
+        if let Some((name, message)) = line.split_once('\0') {
+            let index = name
+                .trim_start_matches("stash@{")
+                .trim_end_matches('}')
+                .parse()
+                .unwrap_or(stashes.len());
+            stashes.push(GitStash {
+                index,
+                message: message.to_string(),
+                ..Default::default()
+            });
+        } else if let Some(stash) = stashes.last_mut() {
+            for part in line.split(',') {
+                let mut words = part.split_whitespace();
+                let count = words.next().and_then(|n| n.parse().ok()).unwrap_or(0);
+                match words.next() {
+                    Some(w) if w.starts_with("file") => stash.files = count,
+                    Some(w) if w.starts_with("insertion") => stash.insertions = count,
+                    Some(w) if w.starts_with("deletion") => stash.deletions = count,
+                    _ => {}
+                }
+            }
+        }
+    }
+    stashes
+}
+
+/// Stash all changes, untracked files included.
+pub fn stash_push(cwd: &Path, message: &str) -> Result<()> {
+    let mut args = vec!["stash", "push", "--include-untracked"];
+    if !message.is_empty() {
+        args.extend_from_slice(&["--message", message]);
+    }
+    run_git(cwd, &args)?;
+    Ok(())
+}
+
+/// Run `git stash <command> <stash>`: `apply`, `pop` or `drop`.
+pub fn stash_command(cwd: &Path, command: &str, stash: &GitStash) -> Result<()> {
+    run_git(cwd, &["stash", command, &stash.name()])?;
+    Ok(())
+}
+
+/// The changes a stash holds, as a diff against the commit it was made on.
+pub fn load_stash_diff(cwd: &Path, stash: &GitStash) -> Result<Vec<DiffLine>> {
+    let stdout = run_git(cwd, &["stash", "show", "--patch", &stash.name()])?;
+    Ok(parse_diff_output(&stdout))
+}
+
+/// The repo's default branch: what `origin/HEAD` points at, else `main` or
+/// `master`, whichever exists.
+pub fn default_branch(cwd: &Path) -> Option<String> {
+    if let Ok(head) = run_git(
+        cwd,
+        &["symbolic-ref", "--short", "refs/remotes/origin/HEAD"],
+    ) {
+        if let Some(branch) = head.trim().strip_prefix("origin/") {
+            return Some(branch.to_string());
+        }
+    }
+    ["main", "master"]
+        .into_iter()
+        .find(|b| run_git(cwd, &["rev-parse", "--verify", "--quiet", b]).is_ok())
+        .map(str::to_string)
+}
+
+/// The commits and changed files of the current branch since it left the
+/// default branch, for drafting a PR description.
+pub fn branch_changes(cwd: &Path) -> Result<BranchChanges> {
+    let branch = run_git(cwd, &["rev-parse", "--abbrev-ref", "HEAD"])?
+        .trim()
+        .to_string();
+    if branch == "HEAD" {
+        bail!("HEAD is detached; switch to a branch first");
+    }
+    let Some(base) = default_branch(cwd) else {
+        bail!("no default branch found (origin/HEAD, main or master)");
+    };
+    if branch == base {
+        bail!(
+            "{} is the default branch; switch to a feature branch first",
+            branch
+        );
+    }
+    // Compare with the remote's copy when there is one; the local one may be stale
+    let remote_base = format!("origin/{}", base);
+    let base_ref = if run_git(cwd, &["rev-parse", "--verify", "--quiet", &remote_base]).is_ok() {
+        remote_base
+    } else {
+        base.clone()
+    };
+    let range = format!("{}..HEAD", base_ref);
+    let commits = run_git(
+        cwd,
+        &[
+            "log",
+            "--reverse",
+            "--no-merges",
+            "--format=- %s%n%b",
+            &range,
+        ],
+    )?;
+    if commits.trim().is_empty() {
+        bail!("{} has no commits that {} lacks", branch, base_ref);
+    }
+    let diff_stat = run_git(
+        cwd,
+        &[
+            "diff",
+            "--no-color",
+            "--stat",
+            &format!("{}...HEAD", base_ref),
+        ],
+    )?;
+    Ok(BranchChanges {
+        branch,
+        base,
+        commits: commits.trim().to_string(),
+        diff_stat: diff_stat.trim_end().to_string(),
+    })
+}
+
+/// Uncommitted changes against `HEAD`, for the `{{diff}}` prompt variable.
+pub fn working_diff(cwd: &Path) -> Result<String> {
+    run_git(cwd, &["diff", "HEAD"])
+}
+
+/// Record the working tree, untracked files included, as a tree object
+/// without touching the index, so a later snapshot can be diffed against it.
+pub fn snapshot_tree(cwd: &Path) -> Result<String> {
+    let index = run_git(cwd, &["rev-parse", "--git-path", "index"])?;
+    let index = cwd.join(index.trim());
+    // Snapshots can run at once on several threads, each with its own index
+    static SNAPSHOTS: AtomicUsize = AtomicUsize::new(0);
+    let scratch = std::env::temp_dir().join(format!(
+        "assoc-snapshot-index-{}-{}",
+        std::process::id(),
+        SNAPSHOTS.fetch_add(1, Ordering::Relaxed)
+    ));
+    let _ = std::fs::remove_file(&scratch);
+    // Starting from the real index lets git skip rehashing unchanged files
+    if index.exists() {
+        std::fs::copy(&index, &scratch)?;
+    }
+    let tree = run_git_with_index(cwd, &scratch, &["add", "--all"])
+        .and_then(|_| run_git_with_index(cwd, &scratch, &["write-tree"]));
+    let _ = std::fs::remove_file(&scratch);
+    Ok(tree?.trim().to_string())
+}
+
+/// Files changed between two snapshots: a `--stat` summary, then the patch.
+pub fn diff_snapshots(cwd: &Path, from: &str, to: &str) -> Result<String> {
+    run_git(cwd, &["diff", "--no-color", "--stat", "--patch", from, to])
+}
+
 /// Load diff for a specific file entry.
 pub fn load_diff(cwd: &Path, entry: &GitFileEntry) -> Result<Vec<DiffLine>> {
     match entry.section {
@@ -182,3 +468,379 @@ fn parse_diff_output(output: &str) -> Vec<DiffLine> {
         })
         .collect()
 }
+
+/// Directories tracked at HEAD, relative to `cwd`.
+pub fn tracked_dirs(cwd: &Path) -> Result<Vec<String>> {
+    let output = run_git(cwd, &["ls-tree", "-r", "-d", "-z", "--name-only", "HEAD"])?;
+    Ok(output
+        .split('\0')
+        .filter(|dir| !dir.is_empty())
+        .map(str::to_string)
+        .collect())
+}
+
+/// Tracked and untracked files that aren't ignored, relative to `cwd`.
+pub fn project_files(cwd: &Path) -> Result<Vec<String>> {
+    let output = run_git(
+        cwd,
+        &[
+            "ls-files",
+            "-z",
+            "--cached",
+            "--others",
+            "--exclude-standard",
+        ],
+    )?;
+    Ok(output
+        .split('\0')
+        .filter(|file| !file.is_empty())
+        .map(str::to_string)
+        .collect())
+}
+
+/// Old-file start line of each hunk header among the rows the diff pane
+/// shows (side by side when `split`), None for other rows.
+pub fn hunk_starts(lines: &[DiffLine], split: bool) -> Vec<Option<usize>> {
+    // "@@ -12,7 +14,9 @@ context" starts at old line 12
+    let old_start = |line: &DiffLine| {
+        if line.kind != DiffLineKind::Hunk {
+            return None;
+        }
+        line.text
+            .split_whitespace()
+            .find_map(|w| w.strip_prefix('-'))
+            .and_then(|w| w.split(',').next()?.parse::<usize>().ok())
+    };
+    if split {
+        split_diff_rows(lines)
+            .iter()
+            .map(|row| match row {
+                SplitDiffRow::Full(line) => old_start(line),
+                SplitDiffRow::Pair { .. } => None,
+            })
+            .collect()
+    } else {
+        lines.iter().map(old_start).collect()
+    }
+}
+
+/// Anchor the row at `top` to the nearest hunk header above it.
+pub fn diff_anchor(hunks: &[Option<usize>], top: usize) -> DiffAnchor {
+    let header = hunks
+        .get(..=top)
+        .and_then(|rows| rows.iter().rposition(Option::is_some));
+    match header {
+        Some(row) => DiffAnchor {
+            hunk: hunks[row],
+            offset: top - row,
+        },
+        None => DiffAnchor {
+            hunk: None,
+            offset: top,
+        },
+    }
+}
+
+/// The top row that keeps `anchor` in view: the same offset into the last
+/// hunk starting at or before the anchored one, kept inside that hunk.
+pub fn restore_anchor(hunks: &[Option<usize>], anchor: DiffAnchor) -> usize {
+    let Some(start) = anchor.hunk else {
+        return anchor.offset;
+    };
+    let Some(row) = hunks
+        .iter()
+        .rposition(|hunk| hunk.is_some_and(|s| s <= start))
+    else {
+        return 0;
+    };
+    let end = hunks[row + 1..]
+        .iter()
+        .position(Option::is_some)
+        .map_or(hunks.len(), |next| row + 1 + next);
+    row + anchor.offset.min(end - row - 1)
+}
+
+/// Arrange unified diff lines into side-by-side rows. Each run of removed
+/// lines is paired line by line with the added lines that follow it.
+pub fn split_diff_rows(lines: &[DiffLine]) -> Vec<SplitDiffRow> {
+    let mut rows = Vec::new();
+    let mut i = 0;
+    while i < lines.len() {
+        match lines[i].kind {
+            DiffLineKind::Header | DiffLineKind::Hunk => {
+                rows.push(SplitDiffRow::Full(lines[i].clone()));
+                i += 1;
+            }
+            DiffLineKind::Context => {
+                rows.push(SplitDiffRow::Pair {
+                    old: Some(lines[i].clone()),
+                    new: Some(lines[i].clone()),
+                });
+                i += 1;
+            }
+            DiffLineKind::Remove | DiffLineKind::Add => {
+                let removed_end = lines[i..]
+                    .iter()
+                    .position(|l| l.kind != DiffLineKind::Remove)
+                    .map_or(lines.len(), |n| i + n);
+                let added_end = lines[removed_end..]
+                    .iter()
+                    .position(|l| l.kind != DiffLineKind::Add)
+                    .map_or(lines.len(), |n| removed_end + n);
+                let removed = &lines[i..removed_end];
+                let added = &lines[removed_end..added_end];
+                for n in 0..removed.len().max(added.len()) {
+                    rows.push(SplitDiffRow::Pair {
+                        old: removed.get(n).cloned(),
+                        new: added.get(n).cloned(),
+                    });
+                }
+                i = added_end;
+            }
+        }
+    }
+    rows
+}
+
+/// Line in the new version of the file that diff line `index` belongs to:
+/// a hunk's first line, an added or context line's own line, or the line
+/// after a removal. Lines before the first hunk map to that hunk's start;
+/// diffs without hunks (untracked files) count every line.
+pub fn new_file_line(lines: &[DiffLine], index: usize) -> Option<usize> {
+    // "@@ -12,7 +14,9 @@ context" starts at new line 14
+    let hunk_start = |line: &DiffLine| {
+        line.text
+            .split_whitespace()
+            .find_map(|w| w.strip_prefix('+'))
+            .and_then(|w| w.split(',').next()?.parse::<usize>().ok())
+    };
+    let mut next = None;
+    for line in lines.get(..=index)? {
+        match line.kind {
+            DiffLineKind::Hunk => next = hunk_start(line),
+            DiffLineKind::Add | DiffLineKind::Context => next = Some(next.unwrap_or(1) + 1),
+            DiffLineKind::Remove | DiffLineKind::Header => {}
+        }
+    }
+    match lines[index].kind {
+        DiffLineKind::Add | DiffLineKind::Context => next.map(|n| n - 1),
+        DiffLineKind::Hunk | DiffLineKind::Remove => next,
+        DiffLineKind::Header => lines[index..]
+            .iter()
+            .filter(|l| l.kind == DiffLineKind::Hunk)
+            .find_map(hunk_start)
+            .or(Some(1)),
+    }
+}
+
+/// Word-level diff of a changed line pair. Returns the old and new text as
+/// spans, marking the words that are not common to both.
+pub fn word_diff(old: &str, new: &str) -> (Vec<WordSpan>, Vec<WordSpan>) {
+    let old_words = split_words(old);
+    let new_words = split_words(new);
+
+    // Longest common subsequence of words; very long lines are shown as
+    // wholly changed rather than paying for the full table
+    if old_words.len() * new_words.len() > 40_000 {
+        return (
+            join_spans(old_words.iter().map(|w| (*w, true))),
+            join_spans(new_words.iter().map(|w| (*w, true))),
+        );
+    }
+    let (n, m) = (old_words.len(), new_words.len());
+    let mut lcs = vec![vec![0u16; m + 1]; n + 1];
+    for a in (0..n).rev() {
+        for b in (0..m).rev() {
+            lcs[a][b] = if old_words[a] == new_words[b] {
+                lcs[a + 1][b + 1] + 1
+            } else {
+                lcs[a + 1][b].max(lcs[a][b + 1])
+            };
+        }
+    }
+
+    let mut old_marks = vec![true; n];
+    let mut new_marks = vec![true; m];
+    let (mut a, mut b) = (0, 0);
+    while a < n && b < m {
+        if old_words[a] == new_words[b] {
+            old_marks[a] = false;
+            new_marks[b] = false;
+            a += 1;
+            b += 1;
+        } else if lcs[a + 1][b] >= lcs[a][b + 1] {
+            a += 1;
+        } else {
+            b += 1;
+        }
+    }
+
+    (
+        join_spans(old_words.iter().copied().zip(old_marks)),
+        join_spans(new_words.iter().copied().zip(new_marks)),
+    )
+}
+
+/// Split into runs of word characters, runs of whitespace, and single
+/// punctuation characters.
+fn split_words(s: &str) -> Vec<&str> {
+    let class = |c: char| {
+        if c.is_alphanumeric() || c == '_' {
+            0
+        } else if c.is_whitespace() {
+            1
+        } else {
+            2
+        }
+    };
+    let mut words = Vec::new();
+    let mut start = 0;
+    let mut prev: Option<u8> = None;
+    for (i, c) in s.char_indices() {
+        let k = class(c);
+        if let Some(p) = prev {
+            if p != k || k == 2 {
+                words.push(&s[start..i]);
+                start = i;
+            }
+        }
+        prev = Some(k);
+    }
+    if start < s.len() {
+        words.push(&s[start..]);
+    }
+    words
+}
+
+/// Merge adjacent words with the same mark into spans.
+fn join_spans<'a>(words: impl Iterator<Item = (&'a str, bool)>) -> Vec<WordSpan> {
+    let mut spans: Vec<WordSpan> = Vec::new();
+    for (word, changed) in words {
+        match spans.last_mut() {
+            Some(last) if last.changed == changed => last.text.push_str(word),
+            _ => spans.push(WordSpan {
+                text: word.to_string(),
+                changed,
+            }),
+        }
+    }
+    spans
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_worktree_list() {
+        let output = "worktree C:/dev/assoc\nHEAD 1234567890abcdef\nbranch refs/heads/main\n\n\
+                      worktree C:/dev/assoc-feature\nHEAD abcdef1234567890\nbranch refs/heads/feature/x\n\n\
+                      worktree C:/dev/assoc-old\nHEAD 0000000000000000\ndetached\n";
+        let wts = parse_worktree_list(output);
+        assert_eq!(wts.len(), 3);
+        assert_eq!(wts[0].path, "C:/dev/assoc");
+        assert_eq!(wts[0].branch.as_deref(), Some("main"));
+        assert_eq!(wts[1].branch.as_deref(), Some("feature/x"));
+        assert_eq!(wts[2].branch, None);
+        assert_eq!(wts[2].display_branch(), "(detached 0000000)");
+    }
+
+    #[test]
+    fn test_parse_stash_list() {
+        let output = "stash@{0}\0WIP on main: e8d9c2f init\n\n 1 file changed, 1 insertion(+)\n\
+                      stash@{1}\0On main: wip one\n\n\
+                      2 files changed, 3 insertions(+), 1 deletion(-)\n\
+                      stash@{2}\0On main: only untracked\n";
+        let stashes = parse_stash_list(output);
+        assert_eq!(stashes.len(), 3);
+        assert_eq!(stashes[0].message, "WIP on main: e8d9c2f init");
+        assert_eq!((stashes[0].files, stashes[0].insertions), (1, 1));
+        assert_eq!(
+            stashes[1],
+            GitStash {
+                index: 1,
+                message: "On main: wip one".to_string(),
+                files: 2,
+                insertions: 3,
+                deletions: 1,
+            }
+        );
+        assert_eq!(stashes[2].files, 0);
+        assert_eq!(stashes[2].name(), "stash@{2}");
+    }
+
+    #[test]
+    fn test_split_diff_rows_and_word_diff() {
+        let diff =
+            parse_diff_output("@@ -1,3 +1,3 @@\n ctx\n-let x = 1;\n-gone\n+let x = 2;\n tail");
+        let rows = split_diff_rows(&diff);
+        assert_eq!(rows.len(), 5);
+        assert!(matches!(&rows[0], SplitDiffRow::Full(l) if l.kind == DiffLineKind::Hunk));
+        assert!(matches!(
+            &rows[2],
+            SplitDiffRow::Pair { old: Some(o), new: Some(n) } if o.text == "-let x = 1;" && n.text == "+let x = 2;"
+        ));
+        assert!(matches!(
+            &rows[3],
+            SplitDiffRow::Pair {
+                old: Some(_),
+                new: None
+            }
+        ));
+
+        assert_eq!(new_file_line(&diff, 0), Some(1));
+        assert_eq!(new_file_line(&diff, 4), Some(2));
+        assert_eq!(new_file_line(&diff, 5), Some(3));
+
+        let (old, new) = word_diff("let x = 1;", "let x = 2;");
+        assert_eq!(old[0].text, "let x = ");
+        assert!(!old[0].changed);
+        assert_eq!(
+            old[1],
+            WordSpan {
+                text: "1".to_string(),
+                changed: true
+            }
+        );
+        assert_eq!(new[1].text, "2");
+        assert_eq!(
+            new[2],
+            WordSpan {
+                text: ";".to_string(),
+                changed: false
+            }
+        );
+    }
+
+    #[test]
+    fn test_diff_anchor_follows_hunk() {
+        let before = parse_diff_output(
+            "diff --git a/f b/f\n@@ -10,3 +10,3 @@\n a\n-b\n+c\n@@ -40,4 +40,4 @@\n d\n e\n-f\n+g",
+        );
+        let hunks = hunk_starts(&before, false);
+        assert_eq!(hunks[1], Some(10));
+        // Scrolled to "e" in the second hunk
+        let anchor = diff_anchor(&hunks, 7);
+        assert_eq!(
+            anchor,
+            DiffAnchor {
+                hunk: Some(40),
+                offset: 2
+            }
+        );
+
+        // A new hunk above pushes the second one down
+        let after = parse_diff_output(
+            "diff --git a/f b/f\n@@ -1,1 +1,2 @@\n x\n+y\n@@ -10,3 +11,3 @@\n a\n-b\n+c\n\
+             @@ -40,4 +41,4 @@\n d\n e\n-f\n+g",
+        );
+        let hunks = hunk_starts(&after, false);
+        assert_eq!(after[restore_anchor(&hunks, anchor)].text, " e");
+        assert_eq!(restore_anchor(&hunks, diff_anchor(&hunks, 0)), 0);
+
+        // The hunk shrank: stay on its last row
+        let shrunk = parse_diff_output("@@ -40,1 +40,1 @@\n+g");
+        assert_eq!(restore_anchor(&hunk_starts(&shrunk, false), anchor), 1);
+        assert_eq!(hunk_starts(&after, true).iter().flatten().count(), 3);
+    }
+}
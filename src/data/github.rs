@@ -1,36 +1,145 @@
+use std::collections::HashSet;
+
 use anyhow::Result;
+use serde::Deserialize;
 
-use crate::model::github::{FlatIssueItem, FlatPrItem, GitHubIssue, PullRequest};
-
-/// List open PRs for a repo using `gh pr list`.
-pub fn list_open_prs(repo: &str) -> Result<Vec<PullRequest>> {
-    let mut child = std::process::Command::new("gh")
-        .args([
-            "pr",
-            "list",
-            "--repo",
-            repo,
-            "--state",
-            "open",
-            "--limit",
-            "100",
-            "--json",
-            "number,title,state,author,url,createdAt,updatedAt,headRefName,baseRefName,isDraft,additions,deletions,reviewDecision,assignees,labels,body",
-        ])
-        .stdout(std::process::Stdio::piped())
-        .stderr(std::process::Stdio::piped())
-        .spawn()?;
-    let output = wait_with_output(&mut child)?;
-
-    if !output.status.success() {
-        let stderr = String::from_utf8_lossy(&output.stderr);
-        anyhow::bail!("gh pr list failed: {}", stderr.trim());
-    }
+use super::cli_fixtures;
+use crate::model::github::{
+    FlatIssueItem, FlatPrItem, GitHubIssue, IssueAuthor, IssueMetaEdit, IssueMetaField,
+    PullRequest, ReviewComment, ReviewThread,
+};
 
-    let prs: Vec<PullRequest> = serde_json::from_slice(&output.stdout)?;
+/// List the first `limit` open PRs for a repo using `gh pr list`.
+pub fn list_open_prs(repo: &str, limit: usize) -> Result<Vec<PullRequest>> {
+    let limit = limit.to_string();
+    let stdout = run_gh(&[
+        "pr",
+        "list",
+        "--repo",
+        repo,
+        "--state",
+        "open",
+        "--limit",
+        &limit,
+        "--json",
+        "number,title,state,author,url,createdAt,updatedAt,headRefName,baseRefName,isDraft,additions,deletions,reviewDecision,assignees,reviewRequests,labels,body,statusCheckRollup,comments",
+    ])?;
+    let prs: Vec<PullRequest> = serde_json::from_slice(&stdout)?;
     Ok(prs)
 }
 
+const REVIEW_THREADS_QUERY: &str = "\
+query($owner: String!, $name: String!, $number: Int!) {
+  repository(owner: $owner, name: $name) {
+    pullRequest(number: $number) {
+      reviewThreads(first: 100) {
+        nodes {
+          path line originalLine isResolved isOutdated
+          comments(first: 50) { nodes { author { login } body createdAt } }
+        }
+      }
+    }
+  }
+}";
+
+/// Fetch the inline review threads of PR `number` with `gh api graphql`,
+/// sorted by file and line.
+pub fn list_review_threads(repo: &str, number: u64) -> Result<Vec<ReviewThread>> {
+    let (owner, name) = repo
+        .split_once('/')
+        .ok_or_else(|| anyhow::anyhow!("expected owner/name, got '{}'", repo))?;
+    let stdout = run_gh(&[
+        "api",
+        "graphql",
+        "-f",
+        &format!("query={}", REVIEW_THREADS_QUERY),
+        "-f",
+        &format!("owner={}", owner),
+        "-f",
+        &format!("name={}", name),
+        "-F",
+        &format!("number={}", number),
+    ])?;
+    parse_review_threads(&stdout)
+}
+
+#[derive(Deserialize)]
+struct Nodes<T> {
+    nodes: Vec<T>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ThreadNode {
+    path: String,
+    line: Option<u64>,
+    original_line: Option<u64>,
+    is_resolved: bool,
+    is_outdated: bool,
+    comments: Nodes<CommentNode>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CommentNode {
+    /// `null` for deleted accounts.
+    author: Option<IssueAuthor>,
+    body: String,
+    created_at: String,
+}
+
+fn parse_review_threads(data: &[u8]) -> Result<Vec<ReviewThread>> {
+    let response: serde_json::Value = serde_json::from_slice(data)?;
+    if let Some(message) = response
+        .pointer("/errors/0/message")
+        .and_then(|m| m.as_str())
+    {
+        anyhow::bail!("{}", message);
+    }
+    let threads = response
+        .pointer("/data/repository/pullRequest/reviewThreads")
+        .cloned()
+        .ok_or_else(|| anyhow::anyhow!("pull request not found"))?;
+    let threads: Nodes<ThreadNode> = serde_json::from_value(threads)?;
+    let mut threads: Vec<ReviewThread> = threads
+        .nodes
+        .into_iter()
+        .map(|t| ReviewThread {
+            path: t.path,
+            line: t.line,
+            original_line: t.original_line,
+            is_resolved: t.is_resolved,
+            is_outdated: t.is_outdated,
+            comments: t
+                .comments
+                .nodes
+                .into_iter()
+                .map(|c| ReviewComment {
+                    author: c.author.map_or_else(|| "ghost".to_string(), |a| a.login),
+                    body: c.body,
+                    created_at: c.created_at,
+                })
+                .collect(),
+        })
+        .collect();
+    threads.sort_by(|a, b| {
+        a.path
+            .cmp(&b.path)
+            .then_with(|| a.display_line().cmp(&b.display_line()))
+    });
+    Ok(threads)
+}
+
+/// Whether an agent likely opened `pr`: its head branch is one a Claude
+/// session worked on, or its body carries Claude Code's generated footer.
+pub fn is_agent_pr(pr: &PullRequest, agent_branches: &HashSet<&str>) -> bool {
+    agent_branches.contains(pr.head_ref_name.as_str())
+        || pr
+            .body
+            .as_deref()
+            .is_some_and(|b| b.contains("Generated with [Claude Code]"))
+}
+
 /// Categorize PRs into sections: My PRs, Assigned to Me, Other Open.
 /// Returns a flat list with section headers interleaved.
 pub fn categorize_prs(prs: &[PullRequest], current_user: &str) -> Vec<FlatPrItem> {
@@ -102,19 +211,21 @@ pub fn categorize_prs(prs: &[PullRequest], current_user: &str) -> Vec<FlatPrItem
 /// pipe-buffer deadlock that occurs when the child writes more data than the
 /// OS pipe buffer can hold before the parent drains it.
 fn run_gh(args: &[&str]) -> Result<Vec<u8>> {
-    let mut child = std::process::Command::new("gh")
-        .args(args)
-        .stdout(std::process::Stdio::piped())
-        .stderr(std::process::Stdio::piped())
-        .spawn()?;
-
-    let output = wait_with_output(&mut child)?;
-
-    if !output.status.success() {
-        let stderr = String::from_utf8_lossy(&output.stderr);
-        anyhow::bail!("gh failed: {}", stderr.trim());
-    }
-    Ok(output.stdout)
+    cli_fixtures::capture("gh", args, || {
+        let mut child = std::process::Command::new("gh")
+            .args(args)
+            .stdout(std::process::Stdio::piped())
+            .stderr(std::process::Stdio::piped())
+            .spawn()?;
+
+        let output = wait_with_output(&mut child)?;
+
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            anyhow::bail!("gh {} failed: {}", args[..2].join(" "), stderr.trim());
+        }
+        Ok(output.stdout)
+    })
 }
 
 /// Wait for a child process while concurrently draining its stdout and stderr
@@ -172,8 +283,13 @@ pub fn repo_has_issues(repo: &str) -> bool {
     }
 }
 
-/// List issues for a repo using `gh issue list`.
-pub fn list_issues(repo: &str, state: &str) -> Result<Vec<GitHubIssue>> {
+/// Fields requested for issues, matching `GitHubIssue`.
+const ISSUE_FIELDS: &str =
+    "number,title,state,url,createdAt,updatedAt,author,labels,assignees,body,comments,milestone";
+
+/// List the first `limit` issues for a repo using `gh issue list`.
+pub fn list_issues(repo: &str, state: &str, limit: usize) -> Result<Vec<GitHubIssue>> {
+    let limit = limit.to_string();
     let stdout = run_gh(&[
         "issue",
         "list",
@@ -182,14 +298,82 @@ pub fn list_issues(repo: &str, state: &str) -> Result<Vec<GitHubIssue>> {
         "--state",
         state,
         "--limit",
-        "100",
+        &limit,
         "--json",
-        "number,title,state,url,createdAt,updatedAt,author,labels,assignees,body,comments,milestone",
+        ISSUE_FIELDS,
     ])?;
     let issues: Vec<GitHubIssue> = serde_json::from_slice(&stdout)?;
     Ok(issues)
 }
 
+/// How many open PRs the repo has, which `gh pr list` doesn't report.
+pub fn count_open_prs(repo: &str) -> Result<usize> {
+    count_connection(repo, "pullRequests(states: OPEN)")
+}
+
+/// How many issues in `state` ("open", "closed" or "all") the repo has.
+pub fn count_issues(repo: &str, state: &str) -> Result<usize> {
+    let connection = match state {
+        "open" => "issues(states: OPEN)",
+        "closed" => "issues(states: CLOSED)",
+        _ => "issues",
+    };
+    count_connection(repo, connection)
+}
+
+fn count_connection(repo: &str, connection: &str) -> Result<usize> {
+    let (owner, name) = repo
+        .split_once('/')
+        .ok_or_else(|| anyhow::anyhow!("expected owner/name, got '{}'", repo))?;
+    let query = format!(
+        "query($owner: String!, $name: String!) {{ \
+         repository(owner: $owner, name: $name) {{ {} {{ totalCount }} }} }}",
+        connection
+    );
+    let stdout = run_gh(&[
+        "api",
+        "graphql",
+        "-f",
+        &format!("query={}", query),
+        "-f",
+        &format!("owner={}", owner),
+        "-f",
+        &format!("name={}", name),
+        "--jq",
+        ".data.repository[].totalCount",
+    ])?;
+    let count = String::from_utf8_lossy(&stdout);
+    count
+        .trim()
+        .parse()
+        .map_err(|_| anyhow::anyhow!("unexpected count {:?}", count.trim()))
+}
+
+/// Whether a list fetched with `limit` has further pages: fewer items than
+/// the `total` count, or, without one, a full page.
+pub fn has_more_pages(loaded: usize, total: Option<usize>, limit: usize) -> bool {
+    match total {
+        Some(total) => loaded < total,
+        None => loaded >= limit,
+    }
+}
+
+/// Fetch one issue via `gh issue view`, to see whether it changed upstream
+/// while it was being edited.
+pub fn view_issue(repo: &str, number: u64) -> Result<GitHubIssue> {
+    let num_str = number.to_string();
+    let stdout = run_gh(&[
+        "issue",
+        "view",
+        &num_str,
+        "--repo",
+        repo,
+        "--json",
+        ISSUE_FIELDS,
+    ])?;
+    Ok(serde_json::from_slice(&stdout)?)
+}
+
 /// Categorize issues into sections: Assigned to Me, My Issues, Other.
 pub fn categorize_issues(issues: &[GitHubIssue], current_user: &str) -> Vec<FlatIssueItem> {
     let mut my_issues: Vec<&GitHubIssue> = Vec::new();
@@ -249,14 +433,29 @@ pub fn categorize_issues(issues: &[GitHubIssue], current_user: &str) -> Vec<Flat
     result
 }
 
-/// Create a new issue via `gh issue create`.
-pub fn create_issue(repo: &str, title: &str, body: &str) -> Result<()> {
+/// Create a new issue via `gh issue create` and return its URL.
+pub fn create_issue(repo: &str, title: &str, body: &str, labels: &[String]) -> Result<String> {
     let mut args = vec!["issue", "create", "--repo", repo, "--title", title];
     if !body.is_empty() {
         args.extend_from_slice(&["--body", body]);
     }
-    run_gh(&args)?;
-    Ok(())
+    for label in labels {
+        args.extend_from_slice(&["--label", label]);
+    }
+    let stdout = run_gh(&args)?;
+    let stdout = String::from_utf8_lossy(&stdout);
+    Ok(stdout.lines().last().unwrap_or("").trim().to_string())
+}
+
+/// Open a PR from `head` into `base` via `gh pr create` and return its URL.
+/// `head` must already be pushed.
+pub fn create_pr(repo: &str, base: &str, head: &str, title: &str, body: &str) -> Result<String> {
+    let stdout = run_gh(&[
+        "pr", "create", "--repo", repo, "--base", base, "--head", head, "--title", title, "--body",
+        body,
+    ])?;
+    let stdout = String::from_utf8_lossy(&stdout);
+    Ok(stdout.lines().last().unwrap_or("").trim().to_string())
 }
 
 /// Edit an existing issue's title and/or body via `gh issue edit`.
@@ -289,3 +488,139 @@ pub fn comment_issue(repo: &str, number: u64, body: &str) -> Result<()> {
     run_gh(&["issue", "comment", &num_str, "--repo", repo, "--body", body])?;
     Ok(())
 }
+
+/// Comment on the issue or PR at `url` via `gh issue comment` or
+/// `gh pr comment`.
+pub fn comment_on_url(url: &str, is_pr: bool, body: &str) -> Result<()> {
+    let kind = if is_pr { "pr" } else { "issue" };
+    run_gh(&[kind, "comment", url, "--body", body])?;
+    Ok(())
+}
+
+/// The values a picker offers for `field`: the repo's labels, the users
+/// issues can be assigned to, or its open milestones.
+pub fn list_meta_options(repo: &str, field: IssueMetaField) -> Result<Vec<String>> {
+    let stdout = match field {
+        IssueMetaField::Labels => run_gh(&[
+            "label", "list", "--repo", repo, "--limit", "200", "--json", "name", "--jq", ".[].name",
+        ])?,
+        IssueMetaField::Assignees => {
+            let path = format!("repos/{}/assignees", repo);
+            run_gh(&["api", &path, "--paginate", "--jq", ".[].login"])?
+        }
+        IssueMetaField::Milestone => {
+            let path = format!("repos/{}/milestones", repo);
+            run_gh(&["api", &path, "--paginate", "--jq", ".[].title"])?
+        }
+    };
+    Ok(String::from_utf8_lossy(&stdout)
+        .lines()
+        .map(str::trim)
+        .filter(|l| !l.is_empty())
+        .map(String::from)
+        .collect())
+}
+
+/// Change an issue's labels, assignees or milestone via `gh issue edit`.
+pub fn edit_issue_meta(repo: &str, number: u64, edit: &IssueMetaEdit) -> Result<()> {
+    let num_str = number.to_string();
+    let flags = meta_edit_args(edit);
+    let mut args = vec!["issue", "edit", &num_str, "--repo", repo];
+    args.extend(flags.iter().map(String::as_str));
+    run_gh(&args)?;
+    Ok(())
+}
+
+/// `gh issue edit` flags for an edit. Names are comma-joined, so a label
+/// containing a comma cannot be set this way.
+fn meta_edit_args(edit: &IssueMetaEdit) -> Vec<String> {
+    let mut args = Vec::new();
+    let mut push = |flag: &str, values: &[String]| {
+        if !values.is_empty() {
+            args.push(flag.to_string());
+            args.push(values.join(","));
+        }
+    };
+    match edit {
+        IssueMetaEdit::Labels { add, remove } => {
+            push("--add-label", add);
+            push("--remove-label", remove);
+        }
+        IssueMetaEdit::Assignees { add, remove } => {
+            push("--add-assignee", add);
+            push("--remove-assignee", remove);
+        }
+        IssueMetaEdit::Milestone(Some(title)) => push("--milestone", std::slice::from_ref(title)),
+        IssueMetaEdit::Milestone(None) => args.push("--remove-milestone".to_string()),
+    }
+    args
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_recorded_lists() {
+        let data = include_bytes!("../../tests/fixtures/gh-pr-list.json");
+        let prs: Vec<PullRequest> = serde_json::from_slice(data).unwrap();
+        let flat = categorize_prs(&prs, "octocat");
+        assert!(matches!(&flat[0], FlatPrItem::SectionHeader(h) if h == "My PRs (1)"));
+        assert!(matches!(&flat[2], FlatPrItem::SectionHeader(h) if h == "Assigned to Me (1)"));
+        assert_eq!(prs[0].failing_checks().len(), 1);
+
+        let data = include_bytes!("../../tests/fixtures/gh-issue-list.json");
+        let issues: Vec<GitHubIssue> = serde_json::from_slice(data).unwrap();
+        assert_eq!(issues[0].comments.len(), 1);
+        let flat = categorize_issues(&issues, "octocat");
+        assert!(matches!(&flat[1], FlatIssueItem::Issue(i) if i.number == 198));
+
+        let data = include_bytes!("../../tests/fixtures/gh-api-graphql.json");
+        let threads = parse_review_threads(data).unwrap();
+        let places: Vec<(&str, Option<u64>, bool)> = threads
+            .iter()
+            .map(|t| (t.path.as_str(), t.display_line(), t.is_resolved))
+            .collect();
+        assert_eq!(
+            places,
+            [
+                ("src/app.rs", Some(12), true),
+                ("src/app.rs", Some(40), false),
+                ("src/main.rs", Some(7), false)
+            ]
+        );
+        assert_eq!(threads[1].comments[1].author, "ghost");
+        assert!(parse_review_threads(br#"{"errors":[{"message":"Bad credentials"}]}"#).is_err());
+    }
+
+    #[test]
+    fn pages_until_the_count_is_reached() {
+        assert!(has_more_pages(100, Some(342), 100));
+        assert!(!has_more_pages(342, Some(342), 400));
+        assert!(has_more_pages(100, None, 100));
+        assert!(!has_more_pages(57, None, 100));
+    }
+
+    #[test]
+    fn meta_edits_map_to_gh_flags_and_apply_locally() {
+        let edit = IssueMetaEdit::Labels {
+            add: vec!["bug".to_string(), "p1".to_string()],
+            remove: Vec::new(),
+        };
+        assert_eq!(meta_edit_args(&edit), vec!["--add-label", "bug,p1"]);
+        assert_eq!(
+            meta_edit_args(&IssueMetaEdit::Milestone(None)),
+            vec!["--remove-milestone"]
+        );
+
+        let data = include_bytes!("../../tests/fixtures/gh-issue-list.json");
+        let mut issue = serde_json::from_slice::<Vec<GitHubIssue>>(data).unwrap()[0].clone();
+        issue.apply_meta_edit(&IssueMetaEdit::Assignees {
+            add: vec!["hubot".to_string()],
+            remove: vec!["octocat".to_string()],
+        });
+        assert_eq!(issue.meta_values(IssueMetaField::Assignees), vec!["hubot"]);
+        issue.apply_meta_edit(&IssueMetaEdit::Milestone(None));
+        assert!(issue.meta_values(IssueMetaField::Milestone).is_empty());
+    }
+}
@@ -0,0 +1,148 @@
+use std::io;
+use std::path::Path;
+use std::process::Command;
+
+use anyhow::{anyhow, bail, Result};
+
+use crate::model::grep::{GrepMatch, GrepResults};
+
+/// Most matched lines kept from one search.
+pub const MAX_MATCHES: usize = 1000;
+
+/// Search the project's files for `pattern`, a regular expression, with
+/// `rg`, or `git grep` when ripgrep isn't installed. Both skip ignored and
+/// binary files.
+pub fn search(cwd: &Path, pattern: &str) -> Result<GrepResults> {
+    let rg = Command::new("rg")
+        .args([
+            "--line-number",
+            "--column",
+            "--only-matching",
+            "--null",
+            "--no-heading",
+            "--color",
+            "never",
+            "-e",
+            pattern,
+        ])
+        .current_dir(cwd)
+        .output();
+    let output = match rg {
+        Ok(o) => o,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Command::new("git")
+            .args([
+                "grep",
+                "--untracked",
+                "-I",
+                "-n",
+                "--column",
+                "-o",
+                "-z",
+                "-E",
+                "-e",
+                pattern,
+            ])
+            .current_dir(cwd)
+            .output()
+            .map_err(|e| anyhow!("neither rg nor git could run: {}", e))?,
+        Err(e) => return Err(e.into()),
+    };
+
+    // Both exit with 1 when nothing matched
+    if !output.status.success() && output.status.code() != Some(1) {
+        bail!("{}", String::from_utf8_lossy(&output.stderr).trim());
+    }
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    Ok(group_hits(cwd, &parse_hits(&stdout)))
+}
+
+/// One matched piece of text: (path, line, 1-based byte column, text).
+type Hit<'a> = (&'a str, usize, usize, &'a str);
+
+/// Parse `--only-matching` output with NUL after the path: rg writes
+/// `path\0line:col:text`, git grep `path\0line\0col\0text`.
+fn parse_hits(output: &str) -> Vec<Hit<'_>> {
+    output
+        .lines()
+        .filter_map(|line| {
+            let (path, rest) = line.split_once('\0')?;
+            let mut fields = rest.splitn(3, [':', '\0']);
+            let line_no = fields.next()?.parse().ok()?;
+            let column = fields.next()?.parse().ok()?;
+            Some((path, line_no, column, fields.next()?))
+        })
+        .collect()
+}
+
+/// Merge the hits on each line into one match with the line's full text,
+/// read back from the file.
+fn group_hits(cwd: &Path, hits: &[Hit<'_>]) -> GrepResults {
+    let mut results = GrepResults::default();
+    let mut file: Option<(&str, Vec<String>)> = None;
+    for &(path, line, column, text) in hits {
+        let start = column.saturating_sub(1);
+        let range = (start, start + text.len());
+        if let Some(last) = results.matches.last_mut() {
+            if last.path == path && last.line == line {
+                last.ranges.push(range);
+                continue;
+            }
+        }
+        if results.matches.len() == MAX_MATCHES {
+            results.truncated = true;
+            break;
+        }
+        if file.as_ref().is_none_or(|(read, _)| *read != path) {
+            let lines = std::fs::read_to_string(cwd.join(path))
+                .map(|contents| contents.lines().map(String::from).collect())
+                .unwrap_or_default();
+            file = Some((path, lines));
+        }
+        let full = file
+            .as_ref()
+            .and_then(|(_, lines)| lines.get(line.checked_sub(1)?))
+            .filter(|full| full.get(range.0..range.1) == Some(text));
+        let (text, range) = match full {
+            Some(full) => (full.clone(), range),
+            // The file changed since the search; keep just the matched text
+            None => (text.to_string(), (0, text.len())),
+        };
+        results.matches.push(GrepMatch {
+            path: path.to_string(),
+            line,
+            text,
+            ranges: vec![range],
+        });
+    }
+    results
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::grep::FlatGrepItem;
+
+    #[test]
+    fn groups_rg_and_git_grep_hits_by_line() {
+        let dir = std::env::temp_dir().join(format!("assoc-grep-{}", std::process::id()));
+        std::fs::create_dir_all(dir.join("src")).unwrap();
+        std::fs::write(dir.join("src/a.rs"), "fn foo() {}\nlet foo = foo();\n").unwrap();
+        std::fs::write(dir.join("b:c.txt"), "food\n").unwrap();
+
+        let rg = "src/a.rs\x001:4:foo\nsrc/a.rs\x002:5:foo\nsrc/a.rs\x002:11:foo\n";
+        let git = "b:c.txt\x001\x001\x00foo\n";
+        let results = group_hits(&dir, &parse_hits(&format!("{}{}", rg, git)));
+
+        assert_eq!(results.matches.len(), 3);
+        assert_eq!(results.matches[1].text, "let foo = foo();");
+        assert_eq!(results.matches[1].ranges, [(4, 7), (10, 13)]);
+        assert_eq!(results.matches[2].path, "b:c.txt");
+        assert_eq!(results.matches[2].ranges, [(0, 3)]);
+        assert_eq!(results.file_count(), 2);
+        let flat = results.flat_list();
+        assert!(matches!(&flat[0], FlatGrepItem::File(path, 2) if path == "src/a.rs"));
+        assert!(matches!(&flat[3], FlatGrepItem::File(path, 1) if path == "b:c.txt"));
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+}
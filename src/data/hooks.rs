@@ -0,0 +1,439 @@
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use serde_json::{Map, Value};
+
+use crate::model::hook::{FlatHookItem, Hook, SettingsFile, SettingsScope, HOOK_EVENTS};
+
+/// A hook as entered in the form, before it is written.
+#[derive(Debug, Clone, PartialEq)]
+pub struct HookDraft {
+    pub event: String,
+    pub matcher: Option<String>,
+    /// `command` or `prompt`.
+    pub kind: String,
+    pub command: String,
+    pub timeout: Option<u64>,
+}
+
+/// The settings file for a scope.
+pub fn settings_path(scope: SettingsScope, project_cwd: &Path, claude_home: &Path) -> PathBuf {
+    match scope {
+        SettingsScope::Project => project_cwd.join(".claude").join("settings.json"),
+        SettingsScope::Local => project_cwd.join(".claude").join("settings.local.json"),
+        SettingsScope::User => claude_home.join("settings.json"),
+    }
+}
+
+/// Read every scope's settings file. Missing files have no hooks.
+pub fn load_hooks(project_cwd: &Path, claude_home: &Path) -> Vec<SettingsFile> {
+    SettingsScope::ALL
+        .iter()
+        .map(|&scope| {
+            let path = settings_path(scope, project_cwd, claude_home);
+            match std::fs::read_to_string(&path) {
+                Ok(text) => parse_settings(scope, path, &text),
+                Err(e) if e.kind() == std::io::ErrorKind::NotFound => SettingsFile {
+                    scope,
+                    path,
+                    hooks: Vec::new(),
+                    problems: Vec::new(),
+                },
+                Err(e) => SettingsFile {
+                    scope,
+                    path,
+                    hooks: Vec::new(),
+                    problems: vec![e.to_string()],
+                },
+            }
+        })
+        .collect()
+}
+
+/// Parse a settings file's `hooks`, noting everything that doesn't match
+/// the shape Claude Code expects. Well-formed hooks are kept even when
+/// others in the file are broken.
+pub fn parse_settings(scope: SettingsScope, path: PathBuf, text: &str) -> SettingsFile {
+    let mut file = SettingsFile {
+        scope,
+        path,
+        hooks: Vec::new(),
+        problems: Vec::new(),
+    };
+    if text.trim().is_empty() {
+        return file;
+    }
+    let value: Value = match serde_json::from_str(text) {
+        Ok(value) => value,
+        Err(e) => {
+            file.problems.push(format!("invalid JSON: {}", e));
+            return file;
+        }
+    };
+    let Some(root) = value.as_object() else {
+        file.problems
+            .push("settings must be a JSON object".to_string());
+        return file;
+    };
+    let Some(hooks) = root.get("hooks") else {
+        return file;
+    };
+    let Some(events) = hooks.as_object() else {
+        file.problems.push("hooks must be an object".to_string());
+        return file;
+    };
+
+    for (event, groups) in events {
+        if !HOOK_EVENTS.contains(&event.as_str()) {
+            file.problems
+                .push(format!("unknown hook event {:?}", event));
+        }
+        let Some(groups) = groups.as_array() else {
+            file.problems
+                .push(format!("hooks.{} must be an array", event));
+            continue;
+        };
+        for (group, entry) in groups.iter().enumerate() {
+            let at = format!("hooks.{}[{}]", event, group);
+            let Some(entry) = entry.as_object() else {
+                file.problems.push(format!("{} must be an object", at));
+                continue;
+            };
+            let matcher = match entry.get("matcher") {
+                None => None,
+                Some(Value::String(m)) if m.is_empty() || m == "*" => None,
+                Some(Value::String(m)) => Some(m.clone()),
+                Some(_) => {
+                    file.problems
+                        .push(format!("{}.matcher must be a string", at));
+                    continue;
+                }
+            };
+            let Some(list) = entry.get("hooks").and_then(Value::as_array) else {
+                file.problems.push(format!("{}.hooks must be an array", at));
+                continue;
+            };
+            for (index, hook) in list.iter().enumerate() {
+                let at = format!("{}.hooks[{}]", at, index);
+                match parse_hook(hook) {
+                    Ok((kind, command, timeout)) => file.hooks.push(Hook {
+                        scope,
+                        event: event.clone(),
+                        matcher: matcher.clone(),
+                        kind,
+                        command,
+                        timeout,
+                        group,
+                        index,
+                    }),
+                    Err(problem) => file.problems.push(format!("{}: {}", at, problem)),
+                }
+            }
+        }
+    }
+    file
+}
+
+/// Type, command (or prompt) and timeout of one hook object.
+fn parse_hook(hook: &Value) -> std::result::Result<(String, String, Option<u64>), String> {
+    let hook = hook.as_object().ok_or("must be an object")?;
+    let kind = match hook.get("type").and_then(Value::as_str) {
+        Some(kind @ ("command" | "prompt")) => kind.to_string(),
+        Some(other) => return Err(format!("unknown type {:?}", other)),
+        None => return Err("missing type".to_string()),
+    };
+    let command = hook
+        .get(kind.as_str())
+        .and_then(Value::as_str)
+        .filter(|c| !c.trim().is_empty())
+        .ok_or_else(|| format!("missing {}", kind))?
+        .to_string();
+    let timeout = match hook.get("timeout") {
+        None => None,
+        Some(t) => match t.as_u64() {
+            Some(secs) if secs > 0 => Some(secs),
+            _ => return Err("timeout must be a positive number of seconds".to_string()),
+        },
+    };
+    Ok((kind, command, timeout))
+}
+
+/// Files' problems first, then hooks grouped by event in the order Claude
+/// Code runs them; events it doesn't know go last.
+pub fn flatten(files: &[SettingsFile]) -> Vec<FlatHookItem> {
+    let mut flat = Vec::new();
+    let problems: Vec<FlatHookItem> = files
+        .iter()
+        .flat_map(|f| {
+            f.problems
+                .iter()
+                .map(|p| FlatHookItem::Problem(f.scope, p.clone()))
+        })
+        .collect();
+    if !problems.is_empty() {
+        flat.push(FlatHookItem::ProblemHeader);
+        flat.extend(problems);
+    }
+
+    let mut events: Vec<&str> = HOOK_EVENTS.to_vec();
+    for hook in files.iter().flat_map(|f| &f.hooks) {
+        if !events.contains(&hook.event.as_str()) {
+            events.push(&hook.event);
+        }
+    }
+    for event in events {
+        let hooks: Vec<&Hook> = files
+            .iter()
+            .flat_map(|f| &f.hooks)
+            .filter(|h| h.event == event)
+            .collect();
+        if hooks.is_empty() {
+            continue;
+        }
+        flat.push(FlatHookItem::EventHeader(event.to_string()));
+        flat.extend(hooks.into_iter().map(|h| FlatHookItem::Hook(h.clone())));
+    }
+    flat
+}
+
+/// Write `draft` into the settings file at `path`, creating it if needed.
+/// With `replacing`, that hook is updated: in place when its event and
+/// matcher are unchanged, otherwise moved to the draft's matcher group.
+/// Other settings and hook fields are left as they are.
+pub fn save_hook(path: &Path, replacing: Option<&Hook>, draft: &HookDraft) -> Result<()> {
+    let mut root = read_settings(path)?;
+    let events = hooks_object(&mut root)?;
+
+    if let Some(old) = replacing {
+        if old.event == draft.event && old.matcher == draft.matcher {
+            let hook = hook_at(events, old)
+                .with_context(|| format!("hook no longer in {}", path.display()))?;
+            fill_hook(hook, draft);
+            return write_settings(path, &root);
+        }
+        remove_from(events, old)?;
+    }
+
+    let groups = events
+        .entry(draft.event.clone())
+        .or_insert_with(|| Value::Array(Vec::new()))
+        .as_array_mut()
+        .with_context(|| format!("hooks.{} is not an array", draft.event))?;
+    let same_matcher = |group: &Value| {
+        let matcher = group
+            .get("matcher")
+            .and_then(Value::as_str)
+            .filter(|m| !m.is_empty() && *m != "*");
+        matcher == draft.matcher.as_deref() && group.get("hooks").is_some_and(Value::is_array)
+    };
+    let mut hook = Map::new();
+    fill_hook(&mut hook, draft);
+    match groups.iter_mut().find(|g| same_matcher(g)) {
+        Some(group) => group["hooks"]
+            .as_array_mut()
+            .expect("checked above")
+            .push(Value::Object(hook)),
+        None => {
+            let mut group = Map::new();
+            if let Some(matcher) = &draft.matcher {
+                group.insert("matcher".to_string(), Value::String(matcher.clone()));
+            }
+            group.insert("hooks".to_string(), Value::Array(vec![Value::Object(hook)]));
+            groups.push(Value::Object(group));
+        }
+    }
+    write_settings(path, &root)
+}
+
+/// Remove `hook` from the settings file at `path`, dropping its matcher
+/// group and event when they're left empty.
+pub fn remove_hook(path: &Path, hook: &Hook) -> Result<()> {
+    let mut root = read_settings(path)?;
+    remove_from(hooks_object(&mut root)?, hook)?;
+    write_settings(path, &root)
+}
+
+fn read_settings(path: &Path) -> Result<Value> {
+    let text = match std::fs::read_to_string(path) {
+        Ok(text) => text,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => String::new(),
+        Err(e) => return Err(e.into()),
+    };
+    if text.trim().is_empty() {
+        return Ok(Value::Object(Map::new()));
+    }
+    let root: Value = serde_json::from_str(&text)
+        .with_context(|| format!("{} is not valid JSON; fix it first", path.display()))?;
+    if !root.is_object() {
+        anyhow::bail!("{} is not a JSON object", path.display());
+    }
+    Ok(root)
+}
+
+fn hooks_object(root: &mut Value) -> Result<&mut Map<String, Value>> {
+    root.as_object_mut()
+        .expect("checked by read_settings")
+        .entry("hooks")
+        .or_insert_with(|| Value::Object(Map::new()))
+        .as_object_mut()
+        .context("hooks is not an object")
+}
+
+fn hook_at<'a>(
+    events: &'a mut Map<String, Value>,
+    hook: &Hook,
+) -> Option<&'a mut Map<String, Value>> {
+    events
+        .get_mut(&hook.event)?
+        .get_mut(hook.group)?
+        .get_mut("hooks")?
+        .get_mut(hook.index)?
+        .as_object_mut()
+}
+
+fn remove_from(events: &mut Map<String, Value>, hook: &Hook) -> Result<()> {
+    let missing = || anyhow::anyhow!("hook is no longer in the settings file");
+    let groups = events
+        .get_mut(&hook.event)
+        .and_then(Value::as_array_mut)
+        .ok_or_else(missing)?;
+    let list = groups
+        .get_mut(hook.group)
+        .and_then(|g| g.get_mut("hooks"))
+        .and_then(Value::as_array_mut)
+        .ok_or_else(missing)?;
+    if hook.index >= list.len() {
+        return Err(missing());
+    }
+    list.remove(hook.index);
+    if list.is_empty() {
+        groups.remove(hook.group);
+    }
+    if groups.is_empty() {
+        events.remove(&hook.event);
+    }
+    Ok(())
+}
+
+fn fill_hook(hook: &mut Map<String, Value>, draft: &HookDraft) {
+    hook.insert("type".to_string(), Value::String(draft.kind.clone()));
+    let other = if draft.kind == "prompt" {
+        "command"
+    } else {
+        "prompt"
+    };
+    hook.remove(other);
+    hook.insert(draft.kind.clone(), Value::String(draft.command.clone()));
+    match draft.timeout {
+        Some(secs) => hook.insert("timeout".to_string(), Value::from(secs)),
+        None => hook.remove("timeout"),
+    };
+}
+
+/// Pretty-print `root` to `path` through a temporary file.
+fn write_settings(path: &Path, root: &Value) -> Result<()> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    let tmp = path.with_extension("json.tmp");
+    std::fs::write(&tmp, serde_json::to_string_pretty(root)? + "\n")?;
+    std::fs::rename(&tmp, path)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_validates_and_edits_hooks() {
+        let text = r#"{
+  "model": "sonnet",
+  "hooks": {
+    "PreToolUse": [
+      {
+        "matcher": "Bash",
+        "hooks": [{ "type": "command", "command": "./check.sh", "timeout": 5 }]
+      },
+      { "matcher": 3, "hooks": [] }
+    ],
+    "Stop": [{ "hooks": [{ "type": "command" }, { "type": "command", "command": "say done" }] }],
+    "OnSave": []
+  }
+}"#;
+        let file = parse_settings(SettingsScope::Project, PathBuf::from("s.json"), text);
+        assert_eq!(file.hooks.len(), 2);
+        assert_eq!(file.hooks[0].matcher.as_deref(), Some("Bash"));
+        assert_eq!(file.hooks[0].timeout, Some(5));
+        assert_eq!((file.hooks[1].group, file.hooks[1].index), (0, 1));
+        assert_eq!(
+            file.problems,
+            [
+                "hooks.PreToolUse[1].matcher must be a string",
+                "hooks.Stop[0].hooks[0]: missing command",
+                "unknown hook event \"OnSave\"",
+            ]
+        );
+        let flat = flatten(std::slice::from_ref(&file));
+        assert!(matches!(flat[0], FlatHookItem::ProblemHeader));
+        assert!(matches!(&flat[4], FlatHookItem::EventHeader(e) if e == "PreToolUse"));
+
+        let bad = parse_settings(
+            SettingsScope::User,
+            PathBuf::from("s.json"),
+            "{ \"hooks\": ",
+        );
+        assert!(bad.problems[0].starts_with("invalid JSON"));
+
+        let dir = std::env::temp_dir().join(format!("assoc-hooks-{}", std::process::id()));
+        let path = dir.join(".claude").join("settings.json");
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(&path, text).unwrap();
+        let reload = || {
+            let text = std::fs::read_to_string(&path).unwrap();
+            parse_settings(SettingsScope::Project, path.clone(), &text)
+        };
+        let draft = HookDraft {
+            event: "PreToolUse".to_string(),
+            matcher: Some("Bash".to_string()),
+            kind: "command".to_string(),
+            command: "./lint.sh".to_string(),
+            timeout: None,
+        };
+
+        // Joins the existing Bash group; other settings stay in order
+        save_hook(&path, None, &draft).unwrap();
+        let saved = std::fs::read_to_string(&path).unwrap();
+        assert!(saved.find("\"model\"").unwrap() < saved.find("\"hooks\"").unwrap());
+        let hooks = reload().hooks;
+        assert_eq!(
+            (hooks[1].command.as_str(), hooks[1].index),
+            ("./lint.sh", 1)
+        );
+
+        // Same matcher edits in place, a new one moves the hook
+        let edited = HookDraft {
+            timeout: Some(30),
+            ..draft.clone()
+        };
+        save_hook(&path, Some(&hooks[1]), &edited).unwrap();
+        let hooks = reload().hooks;
+        assert_eq!(hooks[1].timeout, Some(30));
+        let moved = HookDraft {
+            matcher: Some("Edit|Write".to_string()),
+            ..edited
+        };
+        save_hook(&path, Some(&hooks[1]), &moved).unwrap();
+        let hooks = reload().hooks;
+        assert_eq!(hooks.len(), 3);
+        assert_eq!(hooks[1].matcher.as_deref(), Some("Edit|Write"));
+
+        remove_hook(&path, &hooks[1]).unwrap();
+        assert_eq!(reload().hooks.len(), 2);
+        assert!(!std::fs::read_to_string(&path)
+            .unwrap()
+            .contains("Edit|Write"));
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}
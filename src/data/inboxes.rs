@@ -1,8 +1,11 @@
 use std::path::Path;
 
 use anyhow::Result;
+use chrono::{DateTime, SecondsFormat, Utc};
+use serde_json::{json, Value};
 
 use crate::model::inbox::InboxMessage;
+use crate::model::team::Team;
 
 /// Load inbox messages for a specific agent in a team.
 pub fn load_inbox(
@@ -32,3 +35,86 @@ pub fn load_inbox(
 
     Ok(messages)
 }
+
+/// Load the inbox of `team`'s lead (or its first member if no lead is
+/// identified), where teammates report their status.
+pub fn load_lead_inbox(claude_home: &Path, team: &Team) -> Vec<InboxMessage> {
+    let lead_name = team
+        .config
+        .members
+        .iter()
+        .find(|m| m.is_lead(&team.config))
+        .or_else(|| team.config.members.first())
+        .map(|m| m.name.as_str());
+    match lead_name {
+        Some(name) => load_inbox(claude_home, &team.dir_name, name).unwrap_or_default(),
+        None => Vec::new(),
+    }
+}
+
+/// Sender name on messages broadcast from the dashboard.
+pub const BROADCAST_SENDER: &str = "user";
+
+/// Append an unread message to `agent_name`'s inbox in `team_name`,
+/// keeping the messages already there as they are.
+pub fn append_message(
+    claude_home: &Path,
+    team_name: &str,
+    agent_name: &str,
+    text: &str,
+    now: DateTime<Utc>,
+) -> Result<()> {
+    let inboxes_dir = claude_home.join("teams").join(team_name).join("inboxes");
+    std::fs::create_dir_all(&inboxes_dir)?;
+    let path = inboxes_dir.join(format!("{}.json", agent_name));
+
+    let mut messages: Vec<Value> = match std::fs::read_to_string(&path) {
+        Ok(data) => serde_json::from_str(&data)?,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Vec::new(),
+        Err(e) => return Err(e.into()),
+    };
+    messages.push(json!({
+        "from": BROADCAST_SENDER,
+        "text": text,
+        "timestamp": now.to_rfc3339_opts(SecondsFormat::Millis, true),
+        "read": false,
+    }));
+
+    let tmp = path.with_extension("json.tmp");
+    std::fs::write(&tmp, serde_json::to_string_pretty(&messages)?)?;
+    if let Err(e) = std::fs::rename(&tmp, &path) {
+        let _ = std::fs::remove_file(&tmp);
+        return Err(e.into());
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn appends_to_existing_inbox() {
+        let home = std::env::temp_dir().join(format!("assoc-inbox-{}", std::process::id()));
+        let dir = home.join("teams").join("alpha").join("inboxes");
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(
+            dir.join("tester.json"),
+            r#"[{"from":"team-lead","text":"hi","color":"blue"}]"#,
+        )
+        .unwrap();
+
+        let now = DateTime::from_timestamp(1_800_000_000, 0).unwrap();
+        append_message(&home, "alpha", "tester", "Wrap up", now).unwrap();
+        append_message(&home, "alpha", "writer", "Wrap up", now).unwrap();
+
+        let tester = load_inbox(&home, "alpha", "tester").unwrap();
+        assert_eq!(tester.len(), 2);
+        assert_eq!(tester[0].from, BROADCAST_SENDER);
+        assert_eq!(tester[0].read, Some(false));
+        assert_eq!(tester[1].color.as_deref(), Some("blue"));
+        assert_eq!(load_inbox(&home, "alpha", "writer").unwrap().len(), 1);
+
+        let _ = std::fs::remove_dir_all(&home);
+    }
+}
@@ -0,0 +1,107 @@
+use std::path::Path;
+
+use super::commands::frontmatter_field;
+use crate::model::github::IssueTemplate;
+
+/// Markdown templates in `.github/ISSUE_TEMPLATE/`, by file name. Issue
+/// forms (`.yml`) and `config.yml` aren't offered.
+pub fn load_templates(project_cwd: &Path) -> Vec<IssueTemplate> {
+    let dir = project_cwd.join(".github").join("ISSUE_TEMPLATE");
+    let Ok(entries) = std::fs::read_dir(&dir) else {
+        return Vec::new();
+    };
+    let mut files: Vec<_> = entries
+        .flatten()
+        .map(|e| e.path())
+        .filter(|p| p.extension().is_some_and(|ext| ext == "md"))
+        .collect();
+    files.sort();
+    files
+        .iter()
+        .filter_map(|path| {
+            let content = std::fs::read_to_string(path).ok()?;
+            let stem = path.file_stem()?.to_string_lossy();
+            Some(parse_template(&stem, &content))
+        })
+        .collect()
+}
+
+pub fn parse_template(file_name: &str, content: &str) -> IssueTemplate {
+    let content = content.replace("\r\n", "\n");
+    let (header, body) = match content
+        .strip_prefix("---")
+        .and_then(|rest| rest.split_once("\n---"))
+    {
+        Some((header, body)) => (header, body.trim_start_matches('-')),
+        None => ("", content.as_str()),
+    };
+    IssueTemplate {
+        name: frontmatter_field(&content, "name").unwrap_or_else(|| file_name.to_string()),
+        about: frontmatter_field(&content, "about"),
+        title: frontmatter_field(&content, "title").unwrap_or_default(),
+        labels: list_field(header, "labels"),
+        body: body.trim_start_matches('\n').to_string(),
+    }
+}
+
+/// A frontmatter list written as `key: a, b`, `key: ["a", "b"]` or as
+/// `- a` lines under `key:`.
+fn list_field(header: &str, key: &str) -> Vec<String> {
+    let mut lines = header.lines();
+    let Some(inline) = lines.find_map(|line| line.strip_prefix(key)?.strip_prefix(':')) else {
+        return Vec::new();
+    };
+    let items: Vec<&str> = if inline.trim().is_empty() {
+        lines
+            .map_while(|line| line.trim_start().strip_prefix('-'))
+            .collect()
+    } else {
+        inline
+            .trim()
+            .trim_start_matches('[')
+            .trim_end_matches(']')
+            .split(',')
+            .collect()
+    };
+    items
+        .into_iter()
+        .map(|item| {
+            item.trim()
+                .trim_matches(|c| c == '"' || c == '\'')
+                .to_string()
+        })
+        .filter(|item| !item.is_empty())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn strips_frontmatter_and_reads_labels() {
+        let template = parse_template(
+            "bug_report",
+            "---\nname: Bug report\nabout: Something broke\ntitle: \"[Bug] \"\nlabels: bug, \"needs triage\"\n---\n\n**Describe the bug**\n",
+        );
+        assert_eq!(template.name, "Bug report");
+        assert_eq!(template.title, "[Bug] ");
+        assert_eq!(template.labels, ["bug", "needs triage"]);
+        assert_eq!(template.body, "**Describe the bug**\n");
+
+        let listed = parse_template(
+            "feature",
+            "---\nlabels:\n  - enhancement\n  - 'ui'\n---\nIdea",
+        );
+        assert_eq!(listed.name, "feature");
+        assert_eq!(listed.labels, ["enhancement", "ui"]);
+        assert_eq!(
+            parse_template("x", "---\nlabels: [a, b]\n---\n").labels,
+            ["a", "b"]
+        );
+        assert_eq!(
+            parse_template("plain", "No frontmatter").body,
+            "No frontmatter"
+        );
+    }
+}
@@ -1,8 +1,10 @@
 use std::io::Read;
+use std::path::{Path, PathBuf};
 
-use anyhow::Result;
+use anyhow::{bail, Result};
 
-use crate::model::jira::{FlatJiraItem, JiraIssue};
+use super::{cli_fixtures, curl};
+use crate::model::jira::{FlatJiraItem, JiraAttachment, JiraIssue, JiraWebLink, BOARD_COLUMNS};
 
 /// Common statuses offered in the transition popup.
 const COMMON_STATUSES: &[&str] = &["To Do", "In Progress", "In Review", "Done"];
@@ -34,48 +36,9 @@ pub fn search_my_issues(
         q
     };
 
-    let mut child = std::process::Command::new("acli")
-        .args(["jira", "workitem", "search", "--jql", &jql, "--json"])
-        .stdout(std::process::Stdio::piped())
-        .stderr(std::process::Stdio::piped())
-        .spawn()?;
-    let output = {
-        let timeout = std::time::Duration::from_secs(30);
-        let start = std::time::Instant::now();
-        loop {
-            match child.try_wait()? {
-                Some(status) => {
-                    let mut stdout = Vec::new();
-                    let mut stderr = Vec::new();
-                    if let Some(mut s) = child.stdout.take() {
-                        s.read_to_end(&mut stdout).ok();
-                    }
-                    if let Some(mut s) = child.stderr.take() {
-                        s.read_to_end(&mut stderr).ok();
-                    }
-                    break std::process::Output {
-                        status,
-                        stdout,
-                        stderr,
-                    };
-                }
-                None => {
-                    if start.elapsed() > timeout {
-                        child.kill().ok();
-                        anyhow::bail!("command timed out after 30 seconds");
-                    }
-                    std::thread::sleep(std::time::Duration::from_millis(100));
-                }
-            }
-        }
-    };
+    let stdout = run_acli(&["jira", "workitem", "search", "--jql", &jql, "--json"])?;
 
-    if !output.status.success() {
-        let stderr = String::from_utf8_lossy(&output.stderr);
-        anyhow::bail!("acli search failed: {}", stderr.trim());
-    }
-
-    parse_issues_json(&output.stdout)
+    parse_issues_json(&stdout)
 }
 
 /// Search for issues by key or label.
@@ -89,48 +52,9 @@ pub fn search_issues(query: &str) -> Result<Vec<JiraIssue>> {
         format!("labels = \"{}\"", safe_query)
     };
 
-    let mut child = std::process::Command::new("acli")
-        .args(["jira", "workitem", "search", "--jql", &jql, "--json"])
-        .stdout(std::process::Stdio::piped())
-        .stderr(std::process::Stdio::piped())
-        .spawn()?;
-    let output = {
-        let timeout = std::time::Duration::from_secs(30);
-        let start = std::time::Instant::now();
-        loop {
-            match child.try_wait()? {
-                Some(status) => {
-                    let mut stdout = Vec::new();
-                    let mut stderr = Vec::new();
-                    if let Some(mut s) = child.stdout.take() {
-                        s.read_to_end(&mut stdout).ok();
-                    }
-                    if let Some(mut s) = child.stderr.take() {
-                        s.read_to_end(&mut stderr).ok();
-                    }
-                    break std::process::Output {
-                        status,
-                        stdout,
-                        stderr,
-                    };
-                }
-                None => {
-                    if start.elapsed() > timeout {
-                        child.kill().ok();
-                        anyhow::bail!("command timed out after 30 seconds");
-                    }
-                    std::thread::sleep(std::time::Duration::from_millis(100));
-                }
-            }
-        }
-    };
-
-    if !output.status.success() {
-        let stderr = String::from_utf8_lossy(&output.stderr);
-        anyhow::bail!("acli search failed: {}", stderr.trim());
-    }
+    let stdout = run_acli(&["jira", "workitem", "search", "--jql", &jql, "--json"])?;
 
-    parse_issues_json(&output.stdout)
+    parse_issues_json(&stdout)
 }
 
 /// Return common status names for the transition popup.
@@ -146,103 +70,78 @@ pub fn get_status_options(current_status: &str) -> Vec<String> {
 
 /// Transition an issue to a new status by name.
 pub fn transition_issue(key: &str, status_name: &str) -> Result<()> {
-    let mut child = std::process::Command::new("acli")
-        .args([
-            "jira",
-            "workitem",
-            "transition",
-            "--key",
-            key,
-            "--status",
-            status_name,
-            "--yes",
-        ])
-        .stdout(std::process::Stdio::piped())
-        .stderr(std::process::Stdio::piped())
-        .spawn()?;
-    let output = {
-        let timeout = std::time::Duration::from_secs(30);
-        let start = std::time::Instant::now();
-        loop {
-            match child.try_wait()? {
-                Some(status) => {
-                    let mut stdout = Vec::new();
-                    let mut stderr = Vec::new();
-                    if let Some(mut s) = child.stdout.take() {
-                        s.read_to_end(&mut stdout).ok();
-                    }
-                    if let Some(mut s) = child.stderr.take() {
-                        s.read_to_end(&mut stderr).ok();
-                    }
-                    break std::process::Output {
-                        status,
-                        stdout,
-                        stderr,
-                    };
-                }
-                None => {
-                    if start.elapsed() > timeout {
-                        child.kill().ok();
-                        anyhow::bail!("command timed out after 30 seconds");
-                    }
-                    std::thread::sleep(std::time::Duration::from_millis(100));
-                }
-            }
-        }
-    };
+    run_acli(&[
+        "jira",
+        "workitem",
+        "transition",
+        "--key",
+        key,
+        "--status",
+        status_name,
+        "--yes",
+    ])?;
 
-    if !output.status.success() {
-        let stderr = String::from_utf8_lossy(&output.stderr);
-        anyhow::bail!("acli transition failed: {}", stderr.trim());
-    }
+    Ok(())
+}
 
+/// Add a comment to an issue.
+pub fn add_comment(key: &str, body: &str) -> Result<()> {
+    run_acli(&[
+        "jira", "workitem", "comment", "create", "--key", key, "--body", body,
+    ])?;
     Ok(())
 }
 
-/// Get full details for a single issue including description.
-pub fn view_issue(key: &str) -> Result<JiraIssue> {
-    let mut child = std::process::Command::new("acli")
-        .args(["jira", "workitem", "view", key, "--json"])
-        .stdout(std::process::Stdio::piped())
-        .stderr(std::process::Stdio::piped())
-        .spawn()?;
-    let output = {
-        let timeout = std::time::Duration::from_secs(30);
-        let start = std::time::Instant::now();
-        loop {
-            match child.try_wait()? {
-                Some(status) => {
-                    let mut stdout = Vec::new();
-                    let mut stderr = Vec::new();
-                    if let Some(mut s) = child.stdout.take() {
-                        s.read_to_end(&mut stdout).ok();
-                    }
-                    if let Some(mut s) = child.stderr.take() {
-                        s.read_to_end(&mut stderr).ok();
-                    }
-                    break std::process::Output {
-                        status,
-                        stdout,
-                        stderr,
-                    };
-                }
-                None => {
-                    if start.elapsed() > timeout {
-                        child.kill().ok();
-                        anyhow::bail!("command timed out after 30 seconds");
-                    }
-                    std::thread::sleep(std::time::Duration::from_millis(100));
-                }
-            }
-        }
+/// Create a task in `project` and return its key.
+pub fn create_issue(project: &str, summary: &str, description: &str) -> Result<String> {
+    let mut args = vec![
+        "jira",
+        "workitem",
+        "create",
+        "--project",
+        project,
+        "--type",
+        "Task",
+        "--summary",
+        summary,
+        "--json",
+    ];
+    if !description.is_empty() {
+        args.extend_from_slice(&["--description", description]);
+    }
+    let stdout = run_acli(&args)?;
+    parse_created_key(&stdout).ok_or_else(|| anyhow::anyhow!("acli did not return the new key"))
+}
+
+/// The `key` of a created work item, as an object or the first of an array.
+fn parse_created_key(data: &[u8]) -> Option<String> {
+    let value: serde_json::Value = serde_json::from_slice(data).ok()?;
+    let obj = match value.as_array() {
+        Some(items) => items.first()?,
+        None => &value,
     };
+    obj.get("key")?.as_str().map(str::to_string)
+}
 
-    if !output.status.success() {
-        let stderr = String::from_utf8_lossy(&output.stderr);
-        anyhow::bail!("acli issue get failed: {}", stderr.trim());
-    }
+/// Fields `view_issue` asks acli for; its default set leaves out
+/// priority, labels and attachments.
+const VIEW_FIELDS: &str =
+    "key,issuetype,summary,status,priority,labels,assignee,description,attachment";
+
+/// Get full details for a single issue including description and
+/// attachments.
+pub fn view_issue(key: &str) -> Result<JiraIssue> {
+    let stdout = run_acli(&[
+        "jira",
+        "workitem",
+        "view",
+        key,
+        "--fields",
+        VIEW_FIELDS,
+        "--json",
+    ])?;
 
-    let value: serde_json::Value = serde_json::from_slice(&output.stdout)?;
+    let value: serde_json::Value = serde_json::from_slice(&stdout)?;
 
     // The output might be a single object or an array with one element
     let obj = if value.is_array() {
@@ -258,6 +157,49 @@ pub fn view_issue(key: &str) -> Result<JiraIssue> {
         .ok_or_else(|| anyhow::anyhow!("failed to parse issue from acli output"))
 }
 
+/// Web links (remote links) on `issue`, read from Jira's REST API since
+/// acli doesn't return them. `credentials` are the account email and an
+/// API token.
+pub fn web_links(issue: &JiraIssue, credentials: (&str, &str)) -> Result<Vec<JiraWebLink>> {
+    let Some(base) = issue.url.find("/browse/").map(|i| &issue.url[..i]) else {
+        bail!("no Jira site URL for {}", issue.key);
+    };
+    let data = cli_fixtures::capture("jira", &["remotelink", &issue.key], || {
+        let url = format!("{}/rest/api/3/issue/{}/remotelink", base, issue.key);
+        let user = format!("{}:{}", credentials.0, credentials.1);
+        curl::run(&[("user", &user)], &["--max-time", "30", &url])
+    })?;
+    Ok(parse_web_links(&serde_json::from_slice(&data)?))
+}
+
+/// Download `attachment` of issue `key` into the temp directory and return
+/// its path. Without `credentials` only attachments on public sites work.
+pub fn download_attachment(
+    key: &str,
+    attachment: &JiraAttachment,
+    credentials: Option<(&str, &str)>,
+) -> Result<PathBuf> {
+    if attachment.content.is_empty() {
+        bail!("{} has no download URL", attachment.filename);
+    }
+    // The name comes from Jira; keep only its last component
+    let file_name = Path::new(&attachment.filename)
+        .file_name()
+        .map(|n| n.to_os_string())
+        .unwrap_or_else(|| attachment.id.clone().into());
+    let dir = std::env::temp_dir()
+        .join("assoc-jira")
+        .join(key)
+        .join(&attachment.id);
+    std::fs::create_dir_all(&dir)?;
+    let path = dir.join(file_name);
+    let (out, url) = (path.to_string_lossy(), attachment.content.as_str());
+    let user = credentials.map(|(email, token)| format!("{}:{}", email, token));
+    let config: Vec<(&str, &str)> = user.iter().map(|u| ("user", u.as_str())).collect();
+    curl::run(&config, &["-L", "--max-time", "300", "-o", &out, url])?;
+    Ok(path)
+}
+
 /// Group issues by status_name into a flat list of headers and issues.
 /// Groups are ordered: "In Progress" statuses first, then "To Do", then anything else.
 pub fn categorize_issues(issues: &[JiraIssue]) -> Vec<FlatJiraItem> {
@@ -289,7 +231,7 @@ pub fn categorize_issues(issues: &[JiraIssue]) -> Vec<FlatJiraItem> {
 
         for issue in issues {
             if issue.status_name == *status_name {
-                result.push(FlatJiraItem::Issue(issue.clone()));
+                result.push(FlatJiraItem::Issue(Box::new(issue.clone())));
             }
         }
     }
@@ -297,8 +239,131 @@ pub fn categorize_issues(issues: &[JiraIssue]) -> Vec<FlatJiraItem> {
     result
 }
 
+/// Load the issues for the board view: the active sprint of `board_id` when
+/// set, otherwise `custom_jql` or open sprints (scoped to `project_key`).
+pub fn search_board_issues(
+    board_id: Option<u64>,
+    project_key: Option<&str>,
+    custom_jql: Option<&str>,
+) -> Result<Vec<JiraIssue>> {
+    if let Some(board_id) = board_id {
+        let board = board_id.to_string();
+        let sprints = run_acli(&[
+            "jira",
+            "board",
+            "list-sprints",
+            "--id",
+            &board,
+            "--state",
+            "active",
+            "--json",
+        ])?;
+        let sprint = parse_active_sprint_id(&sprints)
+            .ok_or_else(|| anyhow::anyhow!("no active sprint on board {}", board_id))?;
+        let items = run_acli(&[
+            "jira",
+            "sprint",
+            "list-workitems",
+            "--sprint",
+            &sprint.to_string(),
+            "--board",
+            &board,
+            "--json",
+        ])?;
+        return parse_issues_json(&items);
+    }
+
+    let jql = match custom_jql {
+        Some(jql) => jql.to_string(),
+        None => {
+            let mut q = "sprint in openSprints()".to_string();
+            if let Some(key) = project_key {
+                q.push_str(&format!(" AND project = \"{}\"", key.replace('"', "")));
+            }
+            q.push_str(" ORDER BY Rank ASC");
+            q
+        }
+    };
+    let data = run_acli(&["jira", "workitem", "search", "--jql", &jql, "--json"])?;
+    parse_issues_json(&data)
+}
+
+/// Split issues into one list per board column.
+pub fn group_board_columns(issues: &[JiraIssue]) -> Vec<Vec<JiraIssue>> {
+    let mut columns = vec![Vec::new(); BOARD_COLUMNS.len()];
+    for issue in issues {
+        columns[issue.board_column()].push(issue.clone());
+    }
+    columns
+}
+
+/// Status name to transition a card to when it moves into `column`: the
+/// status already used by cards there, or the category name itself.
+pub fn board_column_status(columns: &[Vec<JiraIssue>], column: usize) -> String {
+    columns
+        .get(column)
+        .and_then(|cards| cards.first())
+        .map(|issue| issue.status_name.clone())
+        .unwrap_or_else(|| BOARD_COLUMNS[column.min(BOARD_COLUMNS.len() - 1)].to_string())
+}
+
 // ---------------------------------------------------------------------------
 // Internal helpers
+
+/// Run acli with a 30 second timeout and return its stdout.
+fn run_acli(args: &[&str]) -> Result<Vec<u8>> {
+    cli_fixtures::capture("acli", args, || {
+        let mut child = std::process::Command::new("acli")
+            .args(args)
+            .stdout(std::process::Stdio::piped())
+            .stderr(std::process::Stdio::piped())
+            .spawn()?;
+        let timeout = std::time::Duration::from_secs(30);
+        let start = std::time::Instant::now();
+        let status = loop {
+            if let Some(status) = child.try_wait()? {
+                break status;
+            }
+            if start.elapsed() > timeout {
+                child.kill().ok();
+                anyhow::bail!("command timed out after 30 seconds");
+            }
+            std::thread::sleep(std::time::Duration::from_millis(100));
+        };
+        let mut stdout = Vec::new();
+        let mut stderr = Vec::new();
+        if let Some(mut s) = child.stdout.take() {
+            s.read_to_end(&mut stdout).ok();
+        }
+        if let Some(mut s) = child.stderr.take() {
+            s.read_to_end(&mut stderr).ok();
+        }
+        if !status.success() {
+            let stderr = String::from_utf8_lossy(&stderr);
+            anyhow::bail!("acli {} failed: {}", args[1..3].join(" "), stderr.trim());
+        }
+        Ok(stdout)
+    })
+}
+
+/// Pull the id of the first active sprint out of `board list-sprints` JSON,
+/// which is either a bare array or wrapped in a `sprints`/`values` key.
+fn parse_active_sprint_id(data: &[u8]) -> Option<u64> {
+    let value: serde_json::Value = serde_json::from_slice(data).ok()?;
+    let sprints = value
+        .as_array()
+        .or_else(|| value.get("sprints").and_then(|v| v.as_array()))
+        .or_else(|| value.get("values").and_then(|v| v.as_array()))?;
+    sprints
+        .iter()
+        .find(|s| {
+            s.get("state")
+                .and_then(|v| v.as_str())
+                .is_none_or(|st| st.eq_ignore_ascii_case("active"))
+        })
+        .and_then(|s| s.get("id"))
+        .and_then(|id| id.as_u64().or_else(|| id.as_str()?.parse().ok()))
+}
 // ---------------------------------------------------------------------------
 
 /// Check if a string looks like a Jira issue key (e.g. "PROJ-123").
@@ -456,6 +521,19 @@ fn parse_issue_from_value(v: &serde_json::Value) -> Option<JiraIssue> {
                 .unwrap_or_default()
         });
 
+    let attachments = v
+        .get("attachments")
+        .or_else(|| v.pointer("/fields/attachment"))
+        .and_then(|v| v.as_array())
+        .map(|arr| arr.iter().filter_map(parse_attachment).collect())
+        .unwrap_or_default();
+
+    let web_links = v
+        .get("webLinks")
+        .or_else(|| v.get("remotelinks"))
+        .map(parse_web_links)
+        .unwrap_or_default();
+
     Some(JiraIssue {
         key,
         summary,
@@ -466,9 +544,53 @@ fn parse_issue_from_value(v: &serde_json::Value) -> Option<JiraIssue> {
         labels,
         description,
         url,
+        attachments,
+        web_links,
+    })
+}
+
+/// Parse one entry of an issue's `attachment` field.
+fn parse_attachment(v: &serde_json::Value) -> Option<JiraAttachment> {
+    let text = |key: &str| {
+        v.get(key)
+            .and_then(|v| v.as_str())
+            .unwrap_or("")
+            .to_string()
+    };
+    let id = match v.get("id")? {
+        serde_json::Value::String(s) => s.clone(),
+        other => other.to_string(),
+    };
+    Some(JiraAttachment {
+        id,
+        filename: v.get("filename")?.as_str()?.to_string(),
+        size: v.get("size").and_then(|v| v.as_u64()).unwrap_or(0),
+        mime_type: text("mimeType"),
+        content: text("content"),
     })
 }
 
+/// Parse web links, either flat `{title, url}` objects or remote links as
+/// the REST API returns them, with both under `object`.
+fn parse_web_links(v: &serde_json::Value) -> Vec<JiraWebLink> {
+    let Some(arr) = v.as_array() else {
+        return Vec::new();
+    };
+    arr.iter()
+        .filter_map(|link| {
+            let link = link.get("object").unwrap_or(link);
+            let url = link.get("url")?.as_str()?.to_string();
+            let title = link
+                .get("title")
+                .and_then(|v| v.as_str())
+                .filter(|t| !t.is_empty())
+                .unwrap_or(&url)
+                .to_string();
+            Some(JiraWebLink { title, url })
+        })
+        .collect()
+}
+
 /// Recursively extract plain text from an Atlassian Document Format (ADF) value.
 fn extract_adf_text(v: &serde_json::Value) -> String {
     let mut buf = String::new();
@@ -495,3 +617,92 @@ fn extract_adf_text_inner(v: &serde_json::Value, buf: &mut String) {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::jira::JiraResource;
+
+    fn issue(key: &str, status: &str, category: &str) -> JiraIssue {
+        JiraIssue {
+            key: key.to_string(),
+            summary: String::new(),
+            status_name: status.to_string(),
+            status_category: category.to_string(),
+            issue_type: "Task".to_string(),
+            priority: String::new(),
+            labels: Vec::new(),
+            description: None,
+            url: String::new(),
+            attachments: Vec::new(),
+            web_links: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn board_columns_follow_status_category() {
+        let issues = vec![
+            issue("A-1", "Backlog", "To Do"),
+            issue("A-2", "In Review", "In Progress"),
+            issue("A-3", "Closed", "Done"),
+        ];
+        let columns = group_board_columns(&issues);
+        assert_eq!(columns[1][0].key, "A-2");
+        assert_eq!(board_column_status(&columns, 2), "Closed");
+        assert_eq!(
+            board_column_status(&group_board_columns(&[]), 1),
+            "In Progress"
+        );
+
+        let sprints = br#"{"sprints":[{"id":7,"state":"closed"},{"id":"12","state":"active"}]}"#;
+        assert_eq!(parse_active_sprint_id(sprints), Some(12));
+    }
+
+    #[test]
+    fn parses_created_key() {
+        assert_eq!(
+            parse_created_key(br#"{"id":"10042","key":"WID-150","self":"x"}"#).as_deref(),
+            Some("WID-150")
+        );
+        assert_eq!(
+            parse_created_key(br#"[{"key":"WID-151"}]"#).as_deref(),
+            Some("WID-151")
+        );
+        assert_eq!(parse_created_key(b"Created WID-152"), None);
+    }
+
+    #[test]
+    fn parses_recorded_search() {
+        let data = include_bytes!("../../tests/fixtures/acli-jira-workitem-search.json");
+        let issues = parse_issues_json(data).unwrap();
+        assert_eq!(issues.len(), 3);
+        assert_eq!(issues[0].issue_type, "Story");
+        assert_eq!(issues[0].url, "https://acme.atlassian.net/browse/WID-142");
+
+        let flat = categorize_issues(&issues);
+        assert!(matches!(&flat[0], FlatJiraItem::StatusHeader(s, _) if s == "In Progress"));
+        assert!(matches!(flat.last(), Some(FlatJiraItem::Issue(i)) if i.key == "WID-131"));
+    }
+
+    #[test]
+    fn parses_recorded_attachments_and_web_links() {
+        let data = include_bytes!("../../tests/fixtures/acli-jira-workitem-view.json");
+        let value: serde_json::Value = serde_json::from_slice(data).unwrap();
+        let mut issue = parse_issue_from_value(&value).unwrap();
+        assert_eq!(issue.url, "https://acme.atlassian.net/browse/WID-142");
+        assert_eq!(issue.attachments.len(), 2);
+        assert_eq!(issue.attachments[0].id, "10021");
+        assert_eq!(issue.attachments[0].size, 48213);
+        assert_eq!(issue.attachments[1].mime_type, "text/plain");
+
+        let data = include_bytes!("../../tests/fixtures/jira-remotelink.json");
+        issue.web_links = parse_web_links(&serde_json::from_slice(data).unwrap());
+        assert_eq!(issue.web_links[0].title, "Webhook outage 2024-04-30");
+        // Untitled links show their URL
+        assert_eq!(issue.web_links[1].title, issue.web_links[1].url);
+        assert!(matches!(
+            issue.resources()[2],
+            JiraResource::WebLink(l) if l.url.ends_with("/417")
+        ));
+    }
+}
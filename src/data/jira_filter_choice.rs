@@ -0,0 +1,34 @@
+use std::path::{Path, PathBuf};
+
+use anyhow::Result;
+
+/// Location of the remembered Jira filter for a project.
+pub fn choice_path(assoc_home: &Path, encoded_project: &str) -> PathBuf {
+    assoc_home
+        .join("jira-filters")
+        .join(format!("{}.txt", encoded_project))
+}
+
+/// Load the `[jira.filters]` name last used in a project, if any.
+pub fn load_choice(path: &Path) -> Option<String> {
+    let name = std::fs::read_to_string(path).ok()?;
+    let name = name.trim();
+    (!name.is_empty()).then(|| name.to_string())
+}
+
+/// Remember `name` as the project's filter; `None` goes back to `jira.jql`.
+pub fn save_choice(path: &Path, name: Option<&str>) -> Result<()> {
+    match name {
+        Some(name) => {
+            if let Some(parent) = path.parent() {
+                std::fs::create_dir_all(parent)?;
+            }
+            std::fs::write(path, name)?;
+        }
+        None => match std::fs::remove_file(path) {
+            Err(e) if e.kind() != std::io::ErrorKind::NotFound => return Err(e.into()),
+            _ => {}
+        },
+    }
+    Ok(())
+}
@@ -0,0 +1,46 @@
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use anyhow::Result;
+
+use crate::model::process::TicketSource;
+use crate::model::prompt::LaunchDefaults;
+
+/// Location of a project's last-used launch settings, by ticket source.
+pub fn defaults_path(assoc_home: &Path, encoded_project: &str) -> PathBuf {
+    assoc_home
+        .join("launch-args")
+        .join(format!("{}.json", encoded_project))
+}
+
+/// Load the remembered settings, returning an empty map if the file is
+/// missing or unreadable.
+pub fn load_defaults(path: &Path) -> HashMap<String, LaunchDefaults> {
+    std::fs::read_to_string(path)
+        .ok()
+        .and_then(|data| serde_json::from_str(&data).ok())
+        .unwrap_or_default()
+}
+
+/// The settings last launched with from `source`.
+pub fn defaults_for(
+    defaults: &HashMap<String, LaunchDefaults>,
+    source: &TicketSource,
+) -> LaunchDefaults {
+    defaults.get(source.key()).cloned().unwrap_or_default()
+}
+
+/// Write the remembered settings to disk via a temporary file.
+pub fn save_defaults(path: &Path, defaults: &HashMap<String, LaunchDefaults>) -> Result<()> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    let tmp = path.with_extension("json.tmp");
+    let json = serde_json::to_string_pretty(defaults)?;
+    std::fs::write(&tmp, json)?;
+    if let Err(e) = std::fs::rename(&tmp, path) {
+        let _ = std::fs::remove_file(&tmp);
+        return Err(e.into());
+    }
+    Ok(())
+}
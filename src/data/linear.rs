@@ -2,123 +2,254 @@ use std::io::Read;
 
 use anyhow::Result;
 
+use super::cli_fixtures;
 use crate::model::linear::{FlatLinearItem, LinearIssue};
 
-/// Fetch issues from Linear's GraphQL API.
-/// If `username` is provided, filter by assignee email.
-/// If `team_key` is provided, add a team filter.
+/// Issues per request when loading more. Linear caps `first` at 250.
+pub const PAGE_SIZE: usize = 50;
+pub const MAX_PAGE_SIZE: usize = 250;
+
+/// Server-side filters for the issue query, from `[linear]` in
+/// `.assoc.toml`.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct IssueQuery {
+    /// Assignee email; matching issues and unassigned ones are returned.
+    pub username: Option<String>,
+    pub team: Option<String>,
+    /// Project name, matched case-insensitively.
+    pub project: Option<String>,
+    /// `current` for the team's active cycle, or a cycle number.
+    pub cycle: Option<String>,
+    /// Workflow state names. Empty returns everything not completed or
+    /// cancelled.
+    pub states: Vec<String>,
+}
+
+/// One page of issues and the cursor of the next, if there is one.
+#[derive(Debug, Clone, Default)]
+pub struct IssuePage {
+    pub issues: Vec<LinearIssue>,
+    pub next_cursor: Option<String>,
+}
+
+/// Fetch up to `first` issues from Linear's GraphQL API, starting after
+/// `after` when given.
 pub fn fetch_my_issues(
     api_key: &str,
-    username: Option<&str>,
-    team_key: Option<&str>,
-) -> Result<Vec<LinearIssue>> {
-    let query = build_query(username, team_key);
+    query: &IssueQuery,
+    first: usize,
+    after: Option<&str>,
+) -> Result<IssuePage> {
+    let query = build_query(query, first, after);
 
     let body = serde_json::json!({ "query": query });
-    let body_str = serde_json::to_string(&body)?;
-
-    let mut child = std::process::Command::new("curl")
-        .args([
-            "-s",
-            "-X",
-            "POST",
-            "-H",
-            "Content-Type: application/json",
-            "-H",
-            &format!("Authorization: {}", api_key),
-            "-d",
-            &body_str,
-            "https://api.linear.app/graphql",
-        ])
-        .stdout(std::process::Stdio::piped())
-        .stderr(std::process::Stdio::piped())
-        .spawn()?;
-
-    let output = {
-        let timeout = std::time::Duration::from_secs(30);
-        let start = std::time::Instant::now();
-        loop {
-            match child.try_wait()? {
-                Some(status) => {
-                    let mut stdout = Vec::new();
-                    let mut stderr = Vec::new();
-                    if let Some(mut s) = child.stdout.take() {
-                        s.read_to_end(&mut stdout).ok();
-                    }
-                    if let Some(mut s) = child.stderr.take() {
-                        s.read_to_end(&mut stderr).ok();
+    let stdout = post_graphql(api_key, &serde_json::to_string(&body)?)?;
+    parse_response(&stdout)
+}
+
+/// Add a comment to the issue with `identifier`, e.g. `ENG-123`.
+pub fn add_comment(api_key: &str, identifier: &str, body: &str) -> Result<()> {
+    let request = serde_json::json!({
+        "query": "mutation($issue: String!, $body: String!) { \
+                  commentCreate(input: { issueId: $issue, body: $body }) { success } }",
+        "variables": { "issue": identifier, "body": body },
+    });
+    let data = post_graphql(api_key, &serde_json::to_string(&request)?)?;
+    let value: serde_json::Value = serde_json::from_slice(&data)?;
+    check_errors(&value)?;
+    match value.pointer("/data/commentCreate/success") {
+        Some(serde_json::Value::Bool(true)) => Ok(()),
+        _ => anyhow::bail!("Linear did not create the comment"),
+    }
+}
+
+/// Create an issue in the team with `team_key`, e.g. `ENG`, and return
+/// its identifier.
+pub fn create_issue(
+    api_key: &str,
+    team_key: &str,
+    title: &str,
+    description: &str,
+) -> Result<String> {
+    let request = serde_json::json!({
+        "query": "query($key: String!) { teams(filter: { key: { eq: $key } }) { nodes { id } } }",
+        "variables": { "key": team_key },
+    });
+    let data = post_graphql(api_key, &serde_json::to_string(&request)?)?;
+    let value: serde_json::Value = serde_json::from_slice(&data)?;
+    check_errors(&value)?;
+    let Some(team_id) = value
+        .pointer("/data/teams/nodes/0/id")
+        .and_then(|id| id.as_str())
+    else {
+        anyhow::bail!("no Linear team with key {}", team_key);
+    };
+
+    let request = serde_json::json!({
+        "query": "mutation($team: String!, $title: String!, $description: String) { \
+                  issueCreate(input: { teamId: $team, title: $title, description: $description }) \
+                  { success issue { identifier } } }",
+        "variables": { "team": team_id, "title": title, "description": description },
+    });
+    let data = post_graphql(api_key, &serde_json::to_string(&request)?)?;
+    let value: serde_json::Value = serde_json::from_slice(&data)?;
+    check_errors(&value)?;
+    match value
+        .pointer("/data/issueCreate/issue/identifier")
+        .and_then(|id| id.as_str())
+    {
+        Some(identifier) => Ok(identifier.to_string()),
+        None => anyhow::bail!("Linear did not create the issue"),
+    }
+}
+
+/// POST a GraphQL request body to Linear and return the response.
+fn post_graphql(api_key: &str, body_str: &str) -> Result<Vec<u8>> {
+    // The API key stays out of the fixture arguments
+    cli_fixtures::capture("linear", &["graphql", body_str], || {
+        let mut child = std::process::Command::new("curl")
+            .args([
+                "-s",
+                "-X",
+                "POST",
+                "-H",
+                "Content-Type: application/json",
+                "-H",
+                &format!("Authorization: {}", api_key),
+                "-d",
+                body_str,
+                "https://api.linear.app/graphql",
+            ])
+            .stdout(std::process::Stdio::piped())
+            .stderr(std::process::Stdio::piped())
+            .spawn()?;
+
+        let output = {
+            let timeout = std::time::Duration::from_secs(30);
+            let start = std::time::Instant::now();
+            loop {
+                match child.try_wait()? {
+                    Some(status) => {
+                        let mut stdout = Vec::new();
+                        let mut stderr = Vec::new();
+                        if let Some(mut s) = child.stdout.take() {
+                            s.read_to_end(&mut stdout).ok();
+                        }
+                        if let Some(mut s) = child.stderr.take() {
+                            s.read_to_end(&mut stderr).ok();
+                        }
+                        break std::process::Output {
+                            status,
+                            stdout,
+                            stderr,
+                        };
                     }
-                    break std::process::Output {
-                        status,
-                        stdout,
-                        stderr,
-                    };
-                }
-                None => {
-                    if start.elapsed() > timeout {
-                        child.kill().ok();
-                        anyhow::bail!("Linear API request timed out after 30s");
+                    None => {
+                        if start.elapsed() > timeout {
+                            child.kill().ok();
+                            anyhow::bail!("Linear API request timed out after 30s");
+                        }
+                        std::thread::sleep(std::time::Duration::from_millis(100));
                     }
-                    std::thread::sleep(std::time::Duration::from_millis(100));
                 }
             }
+        };
+
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            anyhow::bail!("curl failed: {}", stderr.trim());
         }
-    };
+        Ok(output.stdout)
+    })
+}
 
-    if !output.status.success() {
-        let stderr = String::from_utf8_lossy(&output.stderr);
-        anyhow::bail!("curl failed: {}", stderr.trim());
+/// Fail with the first GraphQL error in a response, if any.
+fn check_errors(value: &serde_json::Value) -> Result<()> {
+    if let Some(errors) = value.get("errors").and_then(|e| e.as_array()) {
+        if let Some(first) = errors.first() {
+            let msg = first
+                .get("message")
+                .and_then(|m| m.as_str())
+                .unwrap_or("unknown error");
+            anyhow::bail!("Linear API error: {}", msg);
+        }
     }
+    Ok(())
+}
 
-    parse_response(&output.stdout)
+/// Escape `value` for a GraphQL string literal.
+fn quote(value: &str) -> String {
+    format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""))
 }
 
 /// Build the GraphQL query string.
-fn build_query(username: Option<&str>, team_key: Option<&str>) -> String {
+fn build_query(query: &IssueQuery, first: usize, after: Option<&str>) -> String {
     let mut filters = Vec::new();
 
-    // Exclude completed and cancelled issues
-    filters.push("state: { type: { nin: [\"completed\", \"cancelled\"] } }".to_string());
+    if query.states.is_empty() {
+        // Exclude completed and cancelled issues
+        filters.push("state: { type: { nin: [\"completed\", \"cancelled\"] } }".to_string());
+    } else {
+        let names: Vec<String> = query.states.iter().map(|s| quote(s)).collect();
+        filters.push(format!(
+            "state: {{ name: {{ in: [{}] }} }}",
+            names.join(", ")
+        ));
+    }
+
+    if let Some(team) = &query.team {
+        filters.push(format!("team: {{ key: {{ eq: {} }} }}", quote(team)));
+    }
 
-    if let Some(team) = team_key {
-        let safe_key = team.replace('\\', "\\\\").replace('"', "\\\"");
-        filters.push(format!("team: {{ key: {{ eq: \"{}\" }} }}", safe_key));
+    if let Some(project) = &query.project {
+        filters.push(format!(
+            "project: {{ name: {{ eqIgnoreCase: {} }} }}",
+            quote(project)
+        ));
+    }
+
+    match query.cycle.as_deref().map(str::trim) {
+        None => {}
+        Some(cycle) if cycle.eq_ignore_ascii_case("current") => {
+            filters.push("cycle: { isActive: { eq: true } }".to_string());
+        }
+        Some(cycle) => {
+            if let Ok(number) = cycle.parse::<u32>() {
+                filters.push(format!("cycle: {{ number: {{ eq: {} }} }}", number));
+            }
+        }
     }
 
     let filter_str = filters.join(", ");
 
     // When username is set, include issues assigned to that user OR unassigned.
     // Without username, return all non-completed workspace issues.
-    let assignee_filter = if let Some(email) = username {
-        let safe_user = email.replace('\\', "\\\\").replace('"', "\\\"");
+    let assignee_filter = if let Some(email) = &query.username {
         format!(
-            r#", or: [{{ assignee: {{ email: {{ eq: "{}" }} }} }}, {{ assignee: {{ null: true }} }}]"#,
-            safe_user
+            r#", or: [{{ assignee: {{ email: {{ eq: {} }} }} }}, {{ assignee: {{ null: true }} }}]"#,
+            quote(email)
         )
     } else {
         String::new()
     };
 
+    let after = after
+        .map(|cursor| format!(", after: {}", quote(cursor)))
+        .unwrap_or_default();
+
     format!(
-        r#"query {{ issues(filter: {{ {}{} }}, first: 50, orderBy: updatedAt) {{ nodes {{ identifier title description priority priorityLabel state {{ name type color }} assignee {{ name email }} labels {{ nodes {{ name color }} }} url team {{ name key }} createdAt updatedAt }} }} }}"#,
-        filter_str, assignee_filter
+        r#"query {{ issues(filter: {{ {}{} }}, first: {}{}, orderBy: updatedAt) {{ nodes {{ identifier title description priority priorityLabel state {{ name type color }} assignee {{ name email }} labels {{ nodes {{ name color }} }} url team {{ name key }} project {{ name }} cycle {{ number name }} createdAt updatedAt }} pageInfo {{ hasNextPage endCursor }} }} }}"#,
+        filter_str,
+        assignee_filter,
+        first.clamp(1, MAX_PAGE_SIZE),
+        after
     )
 }
 
-/// Parse the GraphQL JSON response into a list of LinearIssues.
-fn parse_response(data: &[u8]) -> Result<Vec<LinearIssue>> {
+/// Parse the GraphQL JSON response into a page of LinearIssues.
+fn parse_response(data: &[u8]) -> Result<IssuePage> {
     let value: serde_json::Value = serde_json::from_slice(data)?;
-
-    // Check for GraphQL errors
-    if let Some(errors) = value.get("errors").and_then(|e| e.as_array()) {
-        if let Some(first) = errors.first() {
-            let msg = first
-                .get("message")
-                .and_then(|m| m.as_str())
-                .unwrap_or("unknown error");
-            anyhow::bail!("Linear API error: {}", msg);
-        }
-    }
+    check_errors(&value)?;
 
     let nodes = value.pointer("/data/issues/nodes");
 
@@ -134,7 +265,20 @@ fn parse_response(data: &[u8]) -> Result<Vec<LinearIssue>> {
         })
         .collect::<Result<Vec<_>>>()?;
 
-    Ok(issues)
+    let has_next = value
+        .pointer("/data/issues/pageInfo/hasNextPage")
+        .and_then(|v| v.as_bool())
+        .unwrap_or(false);
+    let next_cursor = value
+        .pointer("/data/issues/pageInfo/endCursor")
+        .and_then(|v| v.as_str())
+        .filter(|_| has_next)
+        .map(|s| s.to_string());
+
+    Ok(IssuePage {
+        issues,
+        next_cursor,
+    })
 }
 
 /// Group issues into "My Tasks" (assigned to username) and "Unassigned" sections.
@@ -203,3 +347,57 @@ pub fn categorize_issues(issues: &[LinearIssue], username: Option<&str>) -> Vec<
 
     result
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_recorded_response() {
+        let data = include_bytes!("../../tests/fixtures/linear-graphql.json");
+        let page = parse_response(data).unwrap();
+        let issues = page.issues;
+        assert_eq!(issues.len(), 3);
+        assert_eq!(page.next_cursor.as_deref(), Some("c3"));
+        assert_eq!(issues[0].project.as_ref().unwrap().name, "Imports");
+        assert_eq!(issues[0].cycle.as_ref().unwrap().number, 12);
+
+        let flat = categorize_issues(&issues, Some("ALEX@acme.dev"));
+        assert!(matches!(&flat[0], FlatLinearItem::AssignmentHeader(h) if h == "My Tasks"));
+        // Started sorts ahead of unstarted
+        assert!(matches!(&flat[1], FlatLinearItem::Issue(i) if i.identifier == "ENG-91"));
+        assert!(matches!(&flat[3], FlatLinearItem::AssignmentHeader(h) if h == "Unassigned"));
+
+        let err = parse_response(br#"{"errors":[{"message":"Authentication required"}]}"#);
+        assert!(err.is_err());
+
+        let last = br#"{"data":{"issues":{"nodes":[],
+            "pageInfo":{"hasNextPage":false,"endCursor":"c9"}}}}"#;
+        assert_eq!(parse_response(last).unwrap().next_cursor, None);
+    }
+
+    #[test]
+    fn builds_filters_and_cursor() {
+        let query = IssueQuery {
+            team: Some("ENG".to_string()),
+            project: Some("Mobile \"v2\"".to_string()),
+            cycle: Some("current".to_string()),
+            states: vec!["Todo".to_string(), "In Progress".to_string()],
+            ..IssueQuery::default()
+        };
+        let built = build_query(&query, 500, Some("c3"));
+        assert!(built.contains(r#"state: { name: { in: ["Todo", "In Progress"] } }"#));
+        assert!(built.contains(r#"project: { name: { eqIgnoreCase: "Mobile \"v2\"" } }"#));
+        assert!(built.contains("cycle: { isActive: { eq: true } }"));
+        assert!(built.contains(r#"first: 250, after: "c3""#));
+        assert!(!built.contains("nin"));
+
+        let query = IssueQuery {
+            cycle: Some("14".to_string()),
+            ..IssueQuery::default()
+        };
+        let built = build_query(&query, PAGE_SIZE, None);
+        assert!(built.contains("cycle: { number: { eq: 14 } }"));
+        assert!(built.contains("first: 50, orderBy"));
+    }
+}
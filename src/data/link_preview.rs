@@ -0,0 +1,35 @@
+use std::process::{Command, Stdio};
+
+use anyhow::{bail, Result};
+
+use crate::model::link_preview::{self, LinkPreview};
+
+/// Fetch `url` with curl and build its preview. Redirects are followed and
+/// large or slow pages are cut off, since only the head of a page is used.
+pub fn fetch_preview(url: &str) -> Result<LinkPreview> {
+    let output = Command::new("curl")
+        .args([
+            "-sSL",
+            "--max-time",
+            "15",
+            "--max-filesize",
+            "5000000",
+            "-A",
+            "Mozilla/5.0 (compatible; assoc link preview)",
+            url,
+        ])
+        .stdin(Stdio::null())
+        .output()?;
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        match stderr.lines().next().map(str::trim) {
+            Some(msg) if !msg.is_empty() => bail!("{}", msg),
+            _ => bail!("curl exited with {}", output.status),
+        }
+    }
+    let preview = link_preview::parse_page(&String::from_utf8_lossy(&output.stdout));
+    if preview.title.is_empty() && preview.summary.is_empty() {
+        bail!("nothing to preview");
+    }
+    Ok(preview)
+}
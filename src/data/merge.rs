@@ -0,0 +1,136 @@
+use crate::model::merge::MergeChunk;
+
+/// Line-based three-way merge of `mine` and `theirs` against their common
+/// `base`. Regions that only one side touched are reported as `Changed`
+/// chunks just like true conflicts; callers decide how to resolve each.
+pub fn three_way_merge(base: &str, mine: &str, theirs: &str) -> Vec<MergeChunk> {
+    let base: Vec<&str> = base.lines().collect();
+    let mine: Vec<&str> = mine.lines().collect();
+    let theirs: Vec<&str> = theirs.lines().collect();
+
+    let to_mine = lcs_matches(&base, &mine);
+    let to_theirs = lcs_matches(&base, &theirs);
+
+    let owned = |lines: &[&str]| lines.iter().map(|s| s.to_string()).collect::<Vec<_>>();
+
+    let mut chunks = Vec::new();
+    let mut stable: Vec<String> = Vec::new();
+    let (mut i, mut j, mut k) = (0, 0, 0);
+
+    loop {
+        // Next base line that survives unchanged on both sides.
+        let next = (i..base.len()).find_map(|m| match (to_mine[m], to_theirs[m]) {
+            (Some(a), Some(t)) => Some((m, a, t)),
+            _ => None,
+        });
+
+        match next {
+            Some((m, a, t)) if m == i && a == j && t == k => {
+                stable.push(base[i].to_string());
+                i += 1;
+                j += 1;
+                k += 1;
+            }
+            Some((m, a, t)) => {
+                if !stable.is_empty() {
+                    chunks.push(MergeChunk::Stable(std::mem::take(&mut stable)));
+                }
+                chunks.push(MergeChunk::Changed {
+                    base: owned(&base[i..m]),
+                    mine: owned(&mine[j..a]),
+                    theirs: owned(&theirs[k..t]),
+                });
+                (i, j, k) = (m, a, t);
+            }
+            None => {
+                if !stable.is_empty() {
+                    chunks.push(MergeChunk::Stable(std::mem::take(&mut stable)));
+                }
+                if i < base.len() || j < mine.len() || k < theirs.len() {
+                    chunks.push(MergeChunk::Changed {
+                        base: owned(&base[i..]),
+                        mine: owned(&mine[j..]),
+                        theirs: owned(&theirs[k..]),
+                    });
+                }
+                break;
+            }
+        }
+    }
+
+    chunks
+}
+
+/// For each line of `a`, the index of the line of `b` it is paired with in
+/// a longest common subsequence, if any.
+fn lcs_matches(a: &[&str], b: &[&str]) -> Vec<Option<usize>> {
+    let (n, m) = (a.len(), b.len());
+    // table[x][y] = LCS length of a[x..] and b[y..]
+    let mut table = vec![vec![0u32; m + 1]; n + 1];
+    for x in (0..n).rev() {
+        for y in (0..m).rev() {
+            table[x][y] = if a[x] == b[y] {
+                table[x + 1][y + 1] + 1
+            } else {
+                table[x + 1][y].max(table[x][y + 1])
+            };
+        }
+    }
+
+    let mut matches = vec![None; n];
+    let (mut x, mut y) = (0, 0);
+    while x < n && y < m {
+        if a[x] == b[y] {
+            matches[x] = Some(y);
+            x += 1;
+            y += 1;
+        } else if table[x + 1][y] >= table[x][y + 1] {
+            x += 1;
+        } else {
+            y += 1;
+        }
+    }
+    matches
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::merge::{marked_merge, MergeSession};
+
+    #[test]
+    fn merge_keeps_both_sides_and_flags_conflicts() {
+        let base = "# Plan\none\ntwo\nthree\nfour";
+        let mine = "# Plan\nONE\ntwo\nthree\nfour";
+        let theirs = "# Plan\none\ntwo\nthree\nFOUR\nfive";
+
+        let chunks = three_way_merge(base, mine, theirs);
+        let session = MergeSession::new(chunks, theirs.to_string());
+        assert_eq!(session.hunk_indices().len(), 1);
+        assert_eq!(session.merged_text(), "# Plan\nONE\ntwo\nthree\nFOUR\nfive");
+
+        let theirs = "# Plan\nUno\ntwo\nthree\nfour";
+        let chunks = three_way_merge(base, mine, theirs);
+        assert!(chunks.iter().any(|c| c.is_conflict()));
+        let mut session = MergeSession::new(chunks, theirs.to_string());
+        assert_eq!(session.merged_text(), mine);
+        session.set_selected(true);
+        assert_eq!(session.merged_text(), theirs);
+    }
+
+    #[test]
+    fn marked_merge_takes_both_sides_and_marks_conflicts() {
+        let base = "intro\nbody\nsteps\noutro";
+        let mine = "intro\nbody\nmy steps\noutro";
+        let theirs = "new intro\nbody\nsteps\noutro";
+        let chunks = three_way_merge(base, mine, theirs);
+        assert_eq!(marked_merge(&chunks), "new intro\nbody\nmy steps\noutro");
+
+        let theirs = "intro\nbody\ntheir steps\noutro";
+        let chunks = three_way_merge(base, mine, theirs);
+        assert_eq!(
+            marked_merge(&chunks),
+            "intro\nbody\n<<<<<<< mine\nmy steps\n=======\ntheir steps\n>>>>>>> upstream\noutro"
+        );
+    }
+}
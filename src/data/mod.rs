@@ -1,17 +1,59 @@
+pub mod activity_calendar;
+pub mod agent_history;
+pub mod azure;
+pub mod bitbucket;
+pub mod claude_settings;
 pub mod cli_detect;
+pub mod cli_fixtures;
+pub mod command_import;
+pub mod commands;
+pub mod config_bundle;
+pub mod curl;
+pub mod deps;
+pub mod drafts;
 pub mod filebrowser;
 pub mod git;
 pub mod github;
+pub mod grep;
+pub mod hooks;
 pub mod inboxes;
+pub mod issue_templates;
 pub mod jira;
+pub mod jira_filter_choice;
+pub mod launch_args;
 pub mod linear;
+pub mod link_preview;
+pub mod merge;
+pub mod notes;
+pub mod notifications;
 pub mod path_encoding;
+pub mod plan_reviews;
 pub mod plans;
+pub mod power;
+pub mod process_group;
+pub mod process_registry;
 pub mod process_runner;
+pub mod process_usage;
 pub mod prompt_builder;
+pub mod prompt_library;
+pub mod remote_choice;
+pub mod report;
+pub mod run_report;
+pub mod serve;
+pub mod session_export;
+pub mod session_links;
+pub mod session_stats;
+pub mod session_titles;
 pub mod sessions;
+pub mod share;
+pub mod snapshots;
 pub mod subagents;
 pub mod tasks;
+pub mod team_resources;
 pub mod teams;
+pub mod ticket_notes;
+pub mod todo_tickets;
 pub mod todos;
 pub mod transcripts;
+pub mod translate;
+pub mod trash;
@@ -0,0 +1,69 @@
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use anyhow::Result;
+use chrono::{DateTime, Local};
+
+/// The project's scratchpad, shared by the Notes tab and `Ctrl+N`.
+pub fn notes_path(project_cwd: &Path) -> PathBuf {
+    project_cwd.join(".associate").join("notes.md")
+}
+
+/// The notes, or an empty string when none have been written yet.
+pub fn load_notes(path: &Path) -> String {
+    std::fs::read_to_string(path).unwrap_or_default()
+}
+
+pub fn save_notes(path: &Path, text: &str) -> Result<()> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    std::fs::write(path, text)?;
+    Ok(())
+}
+
+/// `- 2026-10-16 14:03 text`: one note as `Ctrl+N` appends it.
+pub fn note_line(text: &str, at: DateTime<Local>) -> String {
+    format!("- {} {}\n", at.format("%Y-%m-%d %H:%M"), text.trim())
+}
+
+/// Append `text` as a timestamped bullet, starting a new line if the file
+/// doesn't end with one.
+pub fn append_note(path: &Path, text: &str, at: DateTime<Local>) -> Result<()> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    let existing = load_notes(path);
+    let mut file = std::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)?;
+    if !existing.is_empty() && !existing.ends_with('\n') {
+        file.write_all(b"\n")?;
+    }
+    file.write_all(note_line(text, at).as_bytes())?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn appends_timestamped_notes() {
+        let dir = std::env::temp_dir().join(format!("assoc-notes-{}", std::process::id()));
+        let path = notes_path(&dir);
+        let at = Local.with_ymd_and_hms(2026, 10, 16, 14, 3, 0).unwrap();
+
+        append_note(&path, "  PR 123 blocked on infra ", at).unwrap();
+        std::fs::write(&path, load_notes(&path) + "# Todo").unwrap();
+        append_note(&path, "ping Sam", at).unwrap();
+        assert_eq!(
+            load_notes(&path),
+            "- 2026-10-16 14:03 PR 123 blocked on infra\n# Todo\n- 2026-10-16 14:03 ping Sam\n"
+        );
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}
@@ -0,0 +1,90 @@
+use anyhow::{bail, Result};
+
+use super::curl;
+use crate::config::NotificationSink;
+
+/// Discord rejects webhook messages longer than this.
+const DISCORD_MAX_CHARS: usize = 2000;
+
+/// Post `message` to `sink` with curl: a JSON body for Slack/Discord
+/// webhooks, or an email with `subject` over SMTP.
+pub fn send(sink: &NotificationSink, subject: &str, message: &str) -> Result<()> {
+    match sink.kind.as_str() {
+        "slack" => post_webhook(&sink.url, &serde_json::json!({ "text": message })),
+        "discord" => {
+            let content: String = message.chars().take(DISCORD_MAX_CHARS).collect();
+            post_webhook(&sink.url, &serde_json::json!({ "content": content }))
+        }
+        "email" => send_email(sink, subject, message),
+        other => bail!("unknown sink kind '{}'", other),
+    }
+}
+
+/// POST `body` to a webhook. The URL goes to curl on stdin along with the
+/// body, since it works as a password.
+fn post_webhook(url: &str, body: &serde_json::Value) -> Result<()> {
+    let body = serde_json::to_string(body)?;
+    curl::run(
+        &[
+            ("url", url),
+            ("header", "Content-Type: application/json"),
+            ("data-binary", &body),
+        ],
+        &["--max-time", "30"],
+    )?;
+    Ok(())
+}
+
+fn send_email(sink: &NotificationSink, subject: &str, message: &str) -> Result<()> {
+    let Some(from) = sink.from.as_deref() else {
+        bail!("email sink needs a 'from' address");
+    };
+    if sink.to.is_empty() {
+        bail!("email sink needs at least one 'to' address");
+    }
+
+    let mut args = vec![
+        "--max-time",
+        "30",
+        // Require TLS: STARTTLS for smtp://, implicit for smtps://
+        "--ssl-reqd",
+        "--mail-from",
+        from,
+    ];
+    for to in &sink.to {
+        args.push("--mail-rcpt");
+        args.push(to);
+    }
+
+    // curl reads its options from stdin, so the mail is uploaded from a file
+    let mail = format!(
+        "From: {}\r\nTo: {}\r\nSubject: {}\r\nContent-Type: text/plain; charset=utf-8\r\n\r\n{}\r\n",
+        from,
+        sink.to.join(", "),
+        subject.replace(['\r', '\n'], " "),
+        message.replace('\n', "\r\n")
+    );
+    let path = std::env::temp_dir().join(format!(
+        "assoc-mail-{}-{}.eml",
+        std::process::id(),
+        chrono::Utc::now().timestamp_nanos_opt().unwrap_or_default()
+    ));
+    std::fs::write(&path, mail)?;
+    let upload = path.to_string_lossy().to_string();
+
+    let login = sink.username.as_deref().map(|user| {
+        let password = sink
+            .password_env
+            .as_deref()
+            .and_then(|var| std::env::var(var).ok())
+            .unwrap_or_default();
+        format!("{}:{}", user, password)
+    });
+    let mut config = vec![("url", sink.url.as_str()), ("upload-file", upload.as_str())];
+    if let Some(login) = login.as_deref() {
+        config.push(("user", login));
+    }
+    let result = curl::run(&config, &args);
+    let _ = std::fs::remove_file(&path);
+    result.map(|_| ())
+}
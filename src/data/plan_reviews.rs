@@ -0,0 +1,36 @@
+use std::path::{Path, PathBuf};
+
+use anyhow::Result;
+
+use crate::model::plan::PlanDecision;
+
+/// Location of the plan review log for a project.
+pub fn decisions_path(assoc_home: &Path, encoded_project: &str) -> PathBuf {
+    assoc_home
+        .join("plan-reviews")
+        .join(format!("{}.json", encoded_project))
+}
+
+/// Load recorded plan decisions, returning an empty list if the file is
+/// missing or unreadable.
+pub fn load_decisions(path: &Path) -> Vec<PlanDecision> {
+    std::fs::read_to_string(path)
+        .ok()
+        .and_then(|data| serde_json::from_str(&data).ok())
+        .unwrap_or_default()
+}
+
+/// Write the plan decisions to disk via a temporary file.
+pub fn save_decisions(path: &Path, decisions: &[PlanDecision]) -> Result<()> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    let tmp = path.with_extension("json.tmp");
+    let json = serde_json::to_string_pretty(decisions)?;
+    std::fs::write(&tmp, json)?;
+    if let Err(e) = std::fs::rename(&tmp, path) {
+        let _ = std::fs::remove_file(&tmp);
+        return Err(e.into());
+    }
+    Ok(())
+}
@@ -2,7 +2,8 @@ use std::path::Path;
 
 use anyhow::Result;
 
-use crate::model::plan::{MarkdownLine, MarkdownLineKind, PlanFile};
+use crate::model::plan::{MarkdownLine, MarkdownLineKind, OutlineEntry, PlanFile, PlanStats};
+use crate::model::task::TaskDraft;
 
 /// Load all plan files from `~/.claude/plans/`, sorted newest-first.
 pub fn load_plans(claude_home: &Path) -> Result<Vec<PlanFile>> {
@@ -42,6 +43,12 @@ pub fn load_plans(claude_home: &Path) -> Result<Vec<PlanFile>> {
     Ok(plans)
 }
 
+/// Write a plan file back to `~/.claude/plans/`.
+pub fn save_plan(claude_home: &Path, filename: &str, content: &str) -> Result<()> {
+    std::fs::write(claude_home.join("plans").join(filename), content)?;
+    Ok(())
+}
+
 /// Pull the first `# ` heading from content, falling back to "(untitled)".
 fn extract_title(content: &str) -> String {
     for line in content.lines() {
@@ -95,3 +102,204 @@ pub fn parse_markdown_lines(content: &str) -> Vec<MarkdownLine> {
 
     result
 }
+
+/// Headings of a classified plan, in document order.
+pub fn outline(lines: &[MarkdownLine]) -> Vec<OutlineEntry> {
+    lines
+        .iter()
+        .enumerate()
+        .filter(|(_, ml)| ml.kind == MarkdownLineKind::Heading)
+        .map(|(i, ml)| {
+            let trimmed = ml.text.trim_start();
+            let level = trimmed.chars().take_while(|c| *c == '#').count();
+            OutlineEntry {
+                level,
+                title: trimmed[level..].trim().to_string(),
+                line: i,
+            }
+        })
+        .collect()
+}
+
+/// Unowned team tasks for a plan's unchecked checklist items or, when it has
+/// none, for its innermost headings below the title. Descriptions name the
+/// plan and section; a heading's also carries the section's text.
+pub fn plan_tasks(plan_name: &str, lines: &[MarkdownLine]) -> Vec<TaskDraft> {
+    let from = |section: Option<&str>| match section {
+        Some(section) => format!("From the plan {}, section \"{}\".", plan_name, section),
+        None => format!("From the plan {}.", plan_name),
+    };
+
+    let mut section: Option<String> = None;
+    let mut drafts = Vec::new();
+    for ml in lines {
+        match ml.kind {
+            MarkdownLineKind::Heading => {
+                section = Some(
+                    ml.text
+                        .trim_start()
+                        .trim_start_matches('#')
+                        .trim()
+                        .to_string(),
+                );
+            }
+            MarkdownLineKind::Normal => {
+                let Some(subject) = unchecked_item(&ml.text) else {
+                    continue;
+                };
+                drafts.push(TaskDraft {
+                    subject: subject.to_string(),
+                    description: from(section.as_deref()),
+                    ..TaskDraft::default()
+                });
+            }
+            _ => {}
+        }
+    }
+    if !drafts.is_empty() {
+        return drafts;
+    }
+
+    let headings = outline(lines);
+    for (i, heading) in headings.iter().enumerate() {
+        let next = headings.get(i + 1);
+        if heading.level < 2 || next.is_some_and(|n| n.level > heading.level) {
+            continue;
+        }
+        let end = next.map_or(lines.len(), |n| n.line);
+        let body: Vec<&str> = lines[heading.line + 1..end]
+            .iter()
+            .map(|ml| ml.text.as_str())
+            .collect();
+        let body = body.join("\n").trim().to_string();
+        let source = from(Some(&heading.title));
+        drafts.push(TaskDraft {
+            subject: heading.title.clone(),
+            description: if body.is_empty() {
+                source
+            } else {
+                format!("{}\n\n{}", body, source)
+            },
+            ..TaskDraft::default()
+        });
+    }
+    drafts
+}
+
+/// The text of a `- [ ] item` line (`*` and `+` bullets too).
+fn unchecked_item(line: &str) -> Option<&str> {
+    let rest = line.trim_start();
+    let rest = rest
+        .strip_prefix("- ")
+        .or_else(|| rest.strip_prefix("* "))
+        .or_else(|| rest.strip_prefix("+ "))?;
+    let item = rest.trim_start().strip_prefix("[ ]")?.trim();
+    (!item.is_empty()).then_some(item)
+}
+
+/// Count words and paragraphs in a plan's prose. Code blocks are skipped,
+/// and each CJK character counts as one word since that text has no spaces.
+pub fn plan_stats(lines: &[MarkdownLine]) -> PlanStats {
+    let mut stats = PlanStats::default();
+    let mut in_paragraph = false;
+    for ml in lines {
+        match ml.kind {
+            MarkdownLineKind::Heading => {
+                stats.words += count_words(ml.text.trim_start().trim_start_matches('#'));
+                in_paragraph = false;
+            }
+            MarkdownLineKind::Normal if !ml.text.trim().is_empty() => {
+                stats.words += count_words(&ml.text);
+                if !in_paragraph {
+                    stats.paragraphs += 1;
+                    in_paragraph = true;
+                }
+            }
+            _ => in_paragraph = false,
+        }
+    }
+    stats
+}
+
+fn count_words(text: &str) -> usize {
+    let mut words = 0;
+    let mut in_word = false;
+    for c in text.chars() {
+        if is_cjk(c) {
+            words += 1;
+            in_word = false;
+        } else if c.is_whitespace() {
+            in_word = false;
+        } else if !in_word {
+            words += 1;
+            in_word = true;
+        }
+    }
+    words
+}
+
+fn is_cjk(c: char) -> bool {
+    matches!(c,
+        '\u{3040}'..='\u{30FF}' // Hiragana, Katakana
+        | '\u{3400}'..='\u{4DBF}' // CJK Extension A
+        | '\u{4E00}'..='\u{9FFF}' // CJK Unified Ideographs
+        | '\u{F900}'..='\u{FAFF}' // CJK Compatibility Ideographs
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::task::{propose_tasks, Task};
+
+    #[test]
+    fn outline_and_stats() {
+        let lines = parse_markdown_lines(
+            "# Plan\n\nFirst paragraph here.\nStill first.\n\n## Phase 2\n实施计划\n```\nlet x = 1;\n```\n",
+        );
+        let entries = outline(&lines);
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[1].level, 2);
+        assert_eq!(entries[1].title, "Phase 2");
+        assert_eq!(entries[1].line, 5);
+
+        let stats = plan_stats(&lines);
+        // "Plan" + 5 words + "Phase 2" + 4 CJK characters
+        assert_eq!(stats.words, 12);
+        assert_eq!(stats.paragraphs, 2);
+    }
+
+    #[test]
+    fn turns_plans_into_tasks() {
+        let checklist = parse_markdown_lines(
+            "# Plan\n## Backend\n- [ ] Add the endpoint\n- [x] Done already\n```\n- [ ] not this\n```\n## Docs\n* [ ]  Update README \n",
+        );
+        let drafts = plan_tasks("api.md", &checklist);
+        let subjects: Vec<&str> = drafts.iter().map(|d| d.subject.as_str()).collect();
+        assert_eq!(subjects, ["Add the endpoint", "Update README"]);
+        assert_eq!(
+            drafts[0].description,
+            "From the plan api.md, section \"Backend\"."
+        );
+        assert_eq!(drafts[0].owner, None);
+
+        // Without a checklist, the leaf headings under the title
+        let headings =
+            parse_markdown_lines("# Plan\n## Phase 1\n### Migrate\nMove the data.\n### Verify\n");
+        let mut drafts = plan_tasks("db.md", &headings);
+        assert_eq!(drafts.len(), 2);
+        assert_eq!(drafts[0].subject, "Migrate");
+        assert_eq!(
+            drafts[0].description,
+            "Move the data.\n\nFrom the plan db.md, section \"Migrate\"."
+        );
+
+        // Against the team's tasks and each other
+        let existing: Vec<Task> =
+            serde_json::from_str(r#"[{"id":"1","subject":"migrate."}]"#).unwrap();
+        drafts.push(drafts[1].clone());
+        let proposed = propose_tasks(drafts, &existing);
+        let flags: Vec<(bool, bool)> = proposed.iter().map(|p| (p.duplicate, p.selected)).collect();
+        assert_eq!(flags, [(true, false), (false, true), (true, false)]);
+    }
+}
@@ -0,0 +1,92 @@
+//! Whether the machine is running on battery, for low-power mode.
+
+#[cfg(target_os = "linux")]
+use std::path::Path;
+
+/// True when the machine is running on battery. Machines without a battery,
+/// and platforms where this can't be told, count as plugged in.
+pub fn on_battery() -> bool {
+    #[cfg(target_os = "linux")]
+    {
+        on_battery_sysfs(Path::new("/sys/class/power_supply"))
+    }
+    #[cfg(target_os = "macos")]
+    {
+        std::process::Command::new("pmset")
+            .args(["-g", "batt"])
+            .output()
+            .map(|out| String::from_utf8_lossy(&out.stdout).contains("'Battery Power'"))
+            .unwrap_or(false)
+    }
+    #[cfg(windows)]
+    {
+        use windows_sys::Win32::System::Power::{GetSystemPowerStatus, SYSTEM_POWER_STATUS};
+        // SAFETY: GetSystemPowerStatus only writes to the struct it is given.
+        unsafe {
+            let mut status: SYSTEM_POWER_STATUS = std::mem::zeroed();
+            GetSystemPowerStatus(&mut status) != 0 && status.ACLineStatus == 0
+        }
+    }
+    #[cfg(not(any(target_os = "linux", target_os = "macos", windows)))]
+    {
+        false
+    }
+}
+
+/// On battery when a mains adapter reports offline, or, with no adapter
+/// listed, when a battery reports discharging.
+#[cfg(target_os = "linux")]
+fn on_battery_sysfs(dir: &Path) -> bool {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return false;
+    };
+    let read = |path: &Path, name: &str| {
+        std::fs::read_to_string(path.join(name))
+            .map(|s| s.trim().to_string())
+            .unwrap_or_default()
+    };
+    let mut mains_online = None;
+    let mut discharging = false;
+    for entry in entries.flatten() {
+        let path = entry.path();
+        match read(&path, "type").as_str() {
+            "Mains" => {
+                let online = read(&path, "online") == "1";
+                mains_online = Some(mains_online.unwrap_or(false) || online);
+            }
+            "Battery" => discharging |= read(&path, "status") == "Discharging",
+            _ => {}
+        }
+    }
+    match mains_online {
+        Some(online) => !online,
+        None => discharging,
+    }
+}
+
+#[cfg(all(test, target_os = "linux"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reads_power_supplies_from_sysfs() {
+        let dir = std::env::temp_dir().join(format!("assoc-power-{}", std::process::id()));
+        let supply = |name: &str, fields: &[(&str, &str)]| {
+            let path = dir.join(name);
+            std::fs::create_dir_all(&path).unwrap();
+            for (field, value) in fields {
+                std::fs::write(path.join(field), format!("{}\n", value)).unwrap();
+            }
+        };
+
+        assert!(!on_battery_sysfs(&dir));
+        supply("BAT0", &[("type", "Battery"), ("status", "Discharging")]);
+        assert!(on_battery_sysfs(&dir));
+        supply("AC", &[("type", "Mains"), ("online", "1")]);
+        assert!(!on_battery_sysfs(&dir));
+        supply("AC", &[("type", "Mains"), ("online", "0")]);
+        assert!(on_battery_sysfs(&dir));
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}
@@ -0,0 +1,232 @@
+use std::io;
+use std::process::{Child, Command, ExitStatus};
+
+use chrono::{DateTime, Utc};
+use sysinfo::{Pid, ProcessRefreshKind, ProcessesToUpdate, System};
+
+#[cfg(windows)]
+use windows_sys::Win32::Foundation::{CloseHandle, HANDLE};
+
+/// A spawned child together with every process it starts.
+///
+/// On Unix the child leads its own process group; on Windows it is placed in
+/// a Job Object. Either way `terminate` and `kill` reach the whole tree, and
+/// dropping the group kills anything still left in it.
+pub struct ProcessGroup {
+    child: Child,
+    #[cfg(windows)]
+    job: HANDLE,
+}
+
+impl ProcessGroup {
+    /// Spawn `cmd` as the leader of a new process group.
+    pub fn spawn(cmd: &mut Command) -> io::Result<Self> {
+        #[cfg(unix)]
+        {
+            use std::os::unix::process::CommandExt;
+            cmd.process_group(0);
+            Ok(Self {
+                child: cmd.spawn()?,
+            })
+        }
+
+        #[cfg(windows)]
+        {
+            use std::os::windows::process::CommandExt;
+            use windows_sys::Win32::System::Threading::CREATE_NEW_PROCESS_GROUP;
+            cmd.creation_flags(CREATE_NEW_PROCESS_GROUP);
+            let child = cmd.spawn()?;
+            let job = create_kill_on_close_job(&child);
+            Ok(Self { child, job })
+        }
+    }
+
+    pub fn id(&self) -> u32 {
+        self.child.id()
+    }
+
+    pub fn child_mut(&mut self) -> &mut Child {
+        &mut self.child
+    }
+
+    pub fn try_wait(&mut self) -> io::Result<Option<ExitStatus>> {
+        self.child.try_wait()
+    }
+
+    /// Ask the group to shut down: SIGTERM on Unix, Ctrl+Break on Windows.
+    pub fn terminate(&self) {
+        #[cfg(unix)]
+        unsafe {
+            libc::kill(-(self.child.id() as libc::pid_t), libc::SIGTERM);
+        }
+
+        #[cfg(windows)]
+        unsafe {
+            use windows_sys::Win32::System::Console::{GenerateConsoleCtrlEvent, CTRL_BREAK_EVENT};
+            GenerateConsoleCtrlEvent(CTRL_BREAK_EVENT, self.child.id());
+        }
+    }
+
+    /// Forcefully kill every process in the group.
+    pub fn kill(&mut self) {
+        #[cfg(unix)]
+        unsafe {
+            libc::kill(-(self.child.id() as libc::pid_t), libc::SIGKILL);
+        }
+
+        #[cfg(windows)]
+        {
+            if self.job.is_null() {
+                kill_process_tree(self.child.id());
+            } else {
+                unsafe {
+                    windows_sys::Win32::System::JobObjects::TerminateJobObject(self.job, 1);
+                }
+            }
+        }
+
+        let _ = self.child.kill();
+    }
+
+    /// Kill the group and reap the leader.
+    pub fn kill_and_wait(&mut self) {
+        self.kill();
+        let _ = self.child.wait();
+    }
+}
+
+impl Drop for ProcessGroup {
+    fn drop(&mut self) {
+        // The group id stays reserved while any member is alive, so this
+        // only reaches processes the leader left behind.
+        #[cfg(unix)]
+        unsafe {
+            libc::kill(-(self.child.id() as libc::pid_t), libc::SIGKILL);
+        }
+
+        // KILL_ON_JOB_CLOSE takes the remaining processes down with it.
+        #[cfg(windows)]
+        if !self.job.is_null() {
+            unsafe {
+                CloseHandle(self.job);
+            }
+        }
+    }
+}
+
+/// True if a process with this pid is running.
+pub fn process_alive(pid: u32) -> bool {
+    #[cfg(unix)]
+    {
+        // EPERM still means the pid exists, just not as ours to signal.
+        unsafe {
+            libc::kill(pid as libc::pid_t, 0) == 0
+                || io::Error::last_os_error().raw_os_error() == Some(libc::EPERM)
+        }
+    }
+
+    #[cfg(windows)]
+    unsafe {
+        use windows_sys::Win32::Foundation::STILL_ACTIVE;
+        use windows_sys::Win32::System::Threading::{
+            GetExitCodeProcess, OpenProcess, PROCESS_QUERY_LIMITED_INFORMATION,
+        };
+        let handle = OpenProcess(PROCESS_QUERY_LIMITED_INFORMATION, 0, pid);
+        if handle.is_null() {
+            return false;
+        }
+        let mut code = 0u32;
+        let alive = GetExitCodeProcess(handle, &mut code) != 0 && code == STILL_ACTIVE as u32;
+        CloseHandle(handle);
+        alive
+    }
+}
+
+/// True if any member of the group led by `pgid` is still running.
+///
+/// Windows groups are Job Objects that die with their owner, so only a
+/// surviving leader can be found there.
+pub fn group_alive(pgid: u32) -> bool {
+    #[cfg(unix)]
+    unsafe {
+        libc::kill(-(pgid as libc::pid_t), 0) == 0
+    }
+
+    #[cfg(windows)]
+    {
+        process_alive(pgid)
+    }
+}
+
+/// When process `pid` started, or None if it isn't running.
+pub fn process_started(pid: u32) -> Option<DateTime<Utc>> {
+    let pid = Pid::from_u32(pid);
+    let mut system = System::new();
+    system.refresh_processes_specifics(
+        ProcessesToUpdate::Some(&[pid]),
+        true,
+        ProcessRefreshKind::nothing(),
+    );
+    let secs = system.process(pid)?.start_time();
+    DateTime::from_timestamp(secs as i64, 0)
+}
+
+/// Force-kill a group this process no longer holds a handle to.
+pub fn kill_group(pgid: u32) {
+    #[cfg(unix)]
+    unsafe {
+        libc::kill(-(pgid as libc::pid_t), libc::SIGKILL);
+    }
+
+    #[cfg(windows)]
+    kill_process_tree(pgid);
+}
+
+/// Create a Job Object that kills its processes when the last handle is
+/// closed, and put `child` in it. Returns a null handle if any step fails,
+/// in which case `kill` falls back to `taskkill /T`.
+///
+/// The child runs briefly before it is assigned, so anything it spawns in
+/// that window escapes the job; `claude` does not start helpers that early.
+#[cfg(windows)]
+fn create_kill_on_close_job(child: &Child) -> HANDLE {
+    use std::os::windows::io::AsRawHandle;
+    use windows_sys::Win32::System::JobObjects::{
+        AssignProcessToJobObject, CreateJobObjectW, JobObjectExtendedLimitInformation,
+        SetInformationJobObject, JOBOBJECT_EXTENDED_LIMIT_INFORMATION,
+        JOB_OBJECT_LIMIT_KILL_ON_JOB_CLOSE,
+    };
+
+    unsafe {
+        let job = CreateJobObjectW(std::ptr::null(), std::ptr::null());
+        if job.is_null() {
+            return job;
+        }
+
+        let mut info = JOBOBJECT_EXTENDED_LIMIT_INFORMATION::default();
+        info.BasicLimitInformation.LimitFlags = JOB_OBJECT_LIMIT_KILL_ON_JOB_CLOSE;
+        let configured = SetInformationJobObject(
+            job,
+            JobObjectExtendedLimitInformation,
+            &info as *const _ as *const std::ffi::c_void,
+            std::mem::size_of::<JOBOBJECT_EXTENDED_LIMIT_INFORMATION>() as u32,
+        ) != 0;
+
+        if !configured || AssignProcessToJobObject(job, child.as_raw_handle() as HANDLE) == 0 {
+            CloseHandle(job);
+            return std::ptr::null_mut();
+        }
+        job
+    }
+}
+
+/// Kill a process and its entire process tree using `taskkill /F /T`.
+/// Falls back silently if taskkill is not available.
+#[cfg(windows)]
+fn kill_process_tree(pid: u32) {
+    let _ = Command::new("taskkill")
+        .args(["/F", "/T", "/PID", &pid.to_string()])
+        .stdout(std::process::Stdio::null())
+        .stderr(std::process::Stdio::null())
+        .output();
+}
@@ -0,0 +1,124 @@
+use std::path::{Path, PathBuf};
+
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+
+use crate::model::process::ProcessRecord;
+
+/// Most a record's start time may be off from the leader's start time the
+/// system reports: the record is written just after the spawn, and some
+/// systems report start times in whole seconds.
+const START_TOLERANCE_SECS: i64 = 10;
+
+/// Location of the spawned-process registry for a project.
+pub fn registry_path(assoc_home: &Path, encoded_project: &str) -> PathBuf {
+    assoc_home
+        .join("processes")
+        .join(format!("{}.json", encoded_project))
+}
+
+/// Load the registry, returning an empty list if it is missing or unreadable.
+pub fn load_registry(path: &Path) -> Vec<ProcessRecord> {
+    std::fs::read_to_string(path)
+        .ok()
+        .and_then(|data| serde_json::from_str(&data).ok())
+        .unwrap_or_default()
+}
+
+/// Write the registry to disk via a temporary file.
+pub fn save_registry(path: &Path, records: &[ProcessRecord]) -> Result<()> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    let tmp = path.with_extension("json.tmp");
+    let json = serde_json::to_string_pretty(records)?;
+    std::fs::write(&tmp, json)?;
+    if let Err(e) = std::fs::rename(&tmp, path) {
+        let _ = std::fs::remove_file(&tmp);
+        return Err(e.into());
+    }
+    Ok(())
+}
+
+/// Split registry entries into those still owned by a running assoc and
+/// orphans: groups that outlived the instance that spawned them. Entries
+/// whose group has exited, or whose pid now belongs to another process,
+/// are dropped.
+pub fn find_orphans(
+    records: Vec<ProcessRecord>,
+    owner_alive: impl Fn(u32) -> bool,
+    group_alive: impl Fn(u32) -> bool,
+    leader_started: impl Fn(u32) -> Option<DateTime<Utc>>,
+) -> (Vec<ProcessRecord>, Vec<ProcessRecord>) {
+    let mut owned = Vec::new();
+    let mut orphans = Vec::new();
+    for record in records {
+        if !is_same_group(&record, &group_alive, &leader_started) {
+            continue;
+        }
+        if owner_alive(record.owner) {
+            owned.push(record);
+        } else {
+            orphans.push(record);
+        }
+    }
+    (owned, orphans)
+}
+
+/// Whether `record`'s group is still running and is the one it was written
+/// for, rather than a later process that was given the same pid. A group
+/// whose leader has exited is taken as the same: its id stays in use, so it
+/// can't have been handed out again.
+pub fn is_same_group(
+    record: &ProcessRecord,
+    group_alive: impl Fn(u32) -> bool,
+    leader_started: impl Fn(u32) -> Option<DateTime<Utc>>,
+) -> bool {
+    if !group_alive(record.pid) {
+        return false;
+    }
+    match leader_started(record.pid) {
+        Some(started) => (started - record.started).num_seconds().abs() <= START_TOLERANCE_SECS,
+        None => true,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn orphans_are_live_groups_with_dead_owners() {
+        let now = Utc::now();
+        let record = |pid, owner| ProcessRecord {
+            pid,
+            owner,
+            label: format!("GH #{}", pid),
+            started: now,
+        };
+        let records = vec![
+            record(10, 1),
+            record(11, 2),
+            record(12, 2),
+            record(13, 2),
+            record(14, 2),
+        ];
+        let (owned, orphans) = find_orphans(
+            records,
+            |owner| owner == 1,
+            |pid| pid != 12,
+            |pid| match pid {
+                // The pid was reused by a process started an hour later
+                13 => Some(now + chrono::Duration::hours(1)),
+                // The leader exited but the rest of its group still runs
+                14 => None,
+                _ => Some(now - chrono::Duration::seconds(1)),
+            },
+        );
+        assert_eq!(owned.iter().map(|r| r.pid).collect::<Vec<_>>(), vec![10]);
+        assert_eq!(
+            orphans.iter().map(|r| r.pid).collect::<Vec<_>>(),
+            vec![11, 14]
+        );
+    }
+}
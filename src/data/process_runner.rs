@@ -1,11 +1,13 @@
 use std::io::{BufRead, BufReader};
 use std::path::Path;
-use std::process::{Child, Command, Stdio};
+use std::process::{Command, Stdio};
 use std::sync::mpsc;
 use std::thread;
 
 use anyhow::Result;
 
+use super::process_group::ProcessGroup;
+
 /// Message sent from a process reader thread back to the main event loop.
 #[derive(Debug)]
 pub enum ProcessOutput {
@@ -15,33 +17,71 @@ pub enum ProcessOutput {
     Stderr(usize, String),
 }
 
-/// Spawn `claude -p "<prompt>"` in headless mode.
+/// Arguments following `-p "<prompt>"` in a headless launch.
 ///
-/// Uses `--output-format stream-json --verbose` for streaming output and
-/// `--dangerously-skip-permissions` to allow fully autonomous execution.
+/// Uses `--output-format stream-json --verbose` for streaming output and,
+/// with `skip_permissions`, `--dangerously-skip-permissions` to allow fully
+/// autonomous execution. `extra_args` (e.g. `--model` from a prompt
+/// template) are appended after.
+pub fn headless_args(skip_permissions: bool, extra_args: &[String]) -> Vec<String> {
+    let mut args = Vec::new();
+    if skip_permissions {
+        args.push("--dangerously-skip-permissions".to_string());
+    }
+    for arg in ["--output-format", "stream-json", "--verbose"] {
+        args.push(arg.to_string());
+    }
+    args.extend(extra_args.iter().cloned());
+    args
+}
+
+/// `--model`, `--agents` / `--agent` and `--max-turns` for a run's
+/// choices. `agent` is the agent's name and its `--agents` JSON.
+pub fn choice_args(
+    model: Option<&str>,
+    agent: Option<(&str, &str)>,
+    max_turns: Option<u32>,
+) -> Vec<String> {
+    let mut args = Vec::new();
+    if let Some(model) = model {
+        args.push("--model".to_string());
+        args.push(model.to_string());
+    }
+    if let Some((name, definition)) = agent {
+        args.push("--agents".to_string());
+        args.push(definition.to_string());
+        args.push("--agent".to_string());
+        args.push(name.to_string());
+    }
+    if let Some(turns) = max_turns {
+        args.push("--max-turns".to_string());
+        args.push(turns.to_string());
+    }
+    args
+}
+
+/// Spawn `claude -p "<prompt>" <args>` in headless mode, with `args` as
+/// built by [`headless_args`].
 ///
-/// Returns the child process handle. Output is sent via `tx` on background
+/// Returns the child's process group, so stopping it also stops any
+/// subprocesses claude started. Output is sent via `tx` on background
 /// threads so the TUI event loop can poll it non-blockingly.
 pub fn spawn_claude_headless(
     process_id: usize,
     prompt: &str,
+    args: &[String],
     cwd: &Path,
     tx: mpsc::Sender<ProcessOutput>,
-) -> Result<Child> {
-    let mut child = Command::new("claude")
-        .args([
-            "-p",
-            prompt,
-            "--dangerously-skip-permissions",
-            "--output-format",
-            "stream-json",
-            "--verbose",
-        ])
+) -> Result<ProcessGroup> {
+    let mut cmd = Command::new("claude");
+    cmd.args(["-p", prompt])
+        .args(args)
         .current_dir(cwd)
         .stdout(Stdio::piped())
         .stderr(Stdio::piped())
-        .stdin(Stdio::null())
-        .spawn()?;
+        .stdin(Stdio::null());
+    let mut group = ProcessGroup::spawn(&mut cmd)?;
+    let child = group.child_mut();
 
     // Spawn thread to read stdout
     let stdout = child.stdout.take().expect("stdout was piped");
@@ -83,5 +123,5 @@ pub fn spawn_claude_headless(
         }
     });
 
-    Ok(child)
+    Ok(group)
 }
@@ -0,0 +1,201 @@
+//! CPU and memory of spawned Claude Code processes and of the Claude Code
+//! pane, sampled with sysinfo.
+
+use std::collections::{HashMap, HashSet};
+use std::ffi::OsStr;
+use std::path::{Path, PathBuf};
+
+use sysinfo::{ProcessRefreshKind, ProcessesToUpdate, System, UpdateKind};
+
+use crate::model::process::ProcessUsage;
+
+/// How often usage is sampled (seconds); four times as long in low-power mode.
+pub const USAGE_SAMPLE_SECS: u64 = 2;
+
+/// Usage found by one sample.
+#[derive(Debug, Default)]
+pub struct UsageSample {
+    /// Keyed by spawned process id, for processes that are still alive.
+    pub processes: HashMap<usize, ProcessUsage>,
+    /// Claude Code running in the project outside assoc, e.g. in the Claude
+    /// Code pane; None when there is none.
+    pub pane: Option<ProcessUsage>,
+}
+
+/// Keeps the previous sample, which CPU usage is measured against. The
+/// first sample reports 0% CPU.
+#[derive(Debug, Default)]
+pub struct UsageSampler {
+    system: System,
+}
+
+/// A process as the sample sees it.
+struct ProcInfo {
+    pid: u32,
+    parent: Option<u32>,
+    /// `claude`, or node running Claude Code, in the project.
+    claude_in_project: bool,
+    usage: ProcessUsage,
+}
+
+impl UsageSampler {
+    /// Usage of each `(id, pid)` spawned process tree and of any other
+    /// Claude Code running in `project`.
+    pub fn sample(&mut self, spawned: &[(usize, u32)], project: &Path) -> UsageSample {
+        self.system.refresh_processes_specifics(
+            ProcessesToUpdate::All,
+            true,
+            ProcessRefreshKind::nothing()
+                .with_cpu()
+                .with_memory()
+                .with_cwd(UpdateKind::OnlyIfNotSet)
+                .with_cmd(UpdateKind::OnlyIfNotSet),
+        );
+        let procs: Vec<ProcInfo> = self
+            .system
+            .processes()
+            .iter()
+            .map(|(pid, p)| ProcInfo {
+                pid: pid.as_u32(),
+                parent: p.parent().map(|pid| pid.as_u32()),
+                claude_in_project: p.cwd().is_some_and(|cwd| cwd.starts_with(project))
+                    && is_claude(p.name(), p.cmd()),
+                usage: ProcessUsage {
+                    cpu: p.cpu_usage(),
+                    memory: p.memory(),
+                },
+            })
+            .collect();
+        summarize(&procs, spawned)
+    }
+}
+
+/// `claude` itself, or a node process running Claude Code's CLI script.
+fn is_claude(name: &OsStr, cmd: &[std::ffi::OsString]) -> bool {
+    let stem = |s: &OsStr| {
+        PathBuf::from(s)
+            .file_stem()
+            .map(|s| s.to_string_lossy().to_lowercase())
+            .unwrap_or_default()
+    };
+    stem(name) == "claude"
+        || cmd
+            .iter()
+            .take(2)
+            .any(|arg| stem(arg) == "claude" || arg.to_string_lossy().contains("claude-code"))
+}
+
+fn summarize(procs: &[ProcInfo], spawned: &[(usize, u32)]) -> UsageSample {
+    let by_pid: HashMap<u32, &ProcInfo> = procs.iter().map(|p| (p.pid, p)).collect();
+    let mut children: HashMap<u32, Vec<u32>> = HashMap::new();
+    for p in procs {
+        if let Some(parent) = p.parent {
+            children.entry(parent).or_default().push(p.pid);
+        }
+    }
+    // Sums a process and its descendants, marking them as seen
+    let tree = |root: u32, seen: &mut HashSet<u32>| {
+        let mut usage = ProcessUsage::default();
+        let mut stack = vec![root];
+        while let Some(pid) = stack.pop() {
+            if !seen.insert(pid) {
+                continue;
+            }
+            if let Some(p) = by_pid.get(&pid) {
+                usage.add(p.usage);
+            }
+            stack.extend(children.get(&pid).into_iter().flatten());
+        }
+        usage
+    };
+
+    let mut seen = HashSet::new();
+    let mut sample = UsageSample::default();
+    for &(id, pid) in spawned {
+        if by_pid.contains_key(&pid) {
+            sample.processes.insert(id, tree(pid, &mut seen));
+        }
+    }
+
+    // The outermost Claude Code processes not spawned by assoc
+    let mut pane: Option<ProcessUsage> = None;
+    for p in procs.iter().filter(|p| p.claude_in_project) {
+        if seen.contains(&p.pid) || has_claude_ancestor(p, &by_pid) {
+            continue;
+        }
+        pane.get_or_insert_default().add(tree(p.pid, &mut seen));
+    }
+    sample.pane = pane;
+    sample
+}
+
+fn has_claude_ancestor(p: &ProcInfo, by_pid: &HashMap<u32, &ProcInfo>) -> bool {
+    let mut seen = HashSet::new();
+    let mut parent = p.parent;
+    while let Some(pid) = parent {
+        if !seen.insert(pid) {
+            return false;
+        }
+        match by_pid.get(&pid) {
+            Some(proc) if proc.claude_in_project => return true,
+            Some(proc) => parent = proc.parent,
+            None => return false,
+        }
+    }
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn proc(pid: u32, parent: Option<u32>, claude: bool, cpu: f32, mb: u64) -> ProcInfo {
+        ProcInfo {
+            pid,
+            parent,
+            claude_in_project: claude,
+            usage: ProcessUsage {
+                cpu,
+                memory: mb * 1024 * 1024,
+            },
+        }
+    }
+
+    #[test]
+    fn sums_process_trees_and_finds_the_pane() {
+        let procs = vec![
+            proc(1, None, false, 1.0, 10),
+            // Spawned process with a tool it started
+            proc(10, Some(1), true, 50.0, 300),
+            proc(11, Some(10), false, 25.0, 100),
+            // Claude Code in the pane, with a child of its own
+            proc(20, Some(1), true, 5.0, 200),
+            proc(21, Some(20), true, 1.0, 50),
+            // Unrelated
+            proc(30, Some(1), false, 90.0, 900),
+        ];
+        let sample = summarize(&procs, &[(7, 10), (8, 99)]);
+
+        assert_eq!(sample.processes.len(), 1);
+        let spawned = sample.processes[&7];
+        assert_eq!(spawned.cpu, 75.0);
+        assert_eq!(spawned.memory_label(), "400M");
+        let pane = sample.pane.unwrap();
+        assert_eq!(pane.cpu, 6.0);
+        assert_eq!(pane.memory_label(), "250M");
+
+        assert!(summarize(&procs[..1], &[]).pane.is_none());
+        assert!(is_claude(OsStr::new("claude.exe"), &[]));
+        assert!(is_claude(
+            OsStr::new("node"),
+            &[
+                "node".into(),
+                "/usr/lib/node_modules/@anthropic-ai/claude-code/cli.js".into()
+            ]
+        ));
+        assert!(!is_claude(
+            OsStr::new("node"),
+            &["node".into(), "server.js".into()]
+        ));
+    }
+}
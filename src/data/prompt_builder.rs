@@ -1,9 +1,17 @@
+use crate::model::azure::WorkItem;
+use crate::model::deps::OutdatedDep;
+use crate::model::git::BranchChanges;
 use crate::model::github::{GitHubIssue, PullRequest};
 use crate::model::jira::JiraIssue;
 use crate::model::linear::LinearIssue;
 use crate::model::process::TicketInfo;
 use crate::model::process::TicketSource;
 
+use super::prompt_library;
+
+/// Longest `{{diff}}` put into a prompt.
+const MAX_DIFF_CHARS: usize = 60_000;
+
 /// Extract ticket info from a GitHub PR.
 pub fn ticket_from_github_pr(pr: &PullRequest) -> TicketInfo {
     let mut extra = Vec::new();
@@ -94,6 +102,89 @@ pub fn ticket_from_jira(issue: &JiraIssue) -> TicketInfo {
     }
 }
 
+/// Extract ticket info from an Azure Boards work item.
+pub fn ticket_from_azure(item: &WorkItem) -> TicketInfo {
+    let mut extra = vec![
+        ("State".to_string(), item.state.clone()),
+        ("Type".to_string(), item.work_item_type.clone()),
+    ];
+    if let Some(priority) = item.priority {
+        extra.push(("Priority".to_string(), priority.to_string()));
+    }
+    if !item.project.is_empty() {
+        extra.push(("Project".to_string(), item.project.clone()));
+    }
+
+    TicketInfo {
+        source: TicketSource::Azure,
+        key: item.key(),
+        title: item.title.clone(),
+        description: item.description.clone().unwrap_or_default(),
+        labels: item.tags.clone(),
+        url: item.url.clone(),
+        extra_fields: extra,
+    }
+}
+
+/// Build ticket info for an outdated dependency from the Deps tab.
+pub fn ticket_from_outdated_dep(dep: &OutdatedDep) -> TicketInfo {
+    let mut extra = vec![
+        ("Ecosystem".to_string(), dep.ecosystem.clone()),
+        ("Current".to_string(), dep.current_display().to_string()),
+    ];
+    if let Some(ref wanted) = dep.wanted {
+        extra.push(("Wanted".to_string(), wanted.clone()));
+    }
+    extra.push(("Latest".to_string(), dep.latest.clone()));
+
+    TicketInfo {
+        source: TicketSource::Dependency,
+        key: dep.name.clone(),
+        title: format!(
+            "Update {} {} -> {}",
+            dep.name,
+            dep.current_display(),
+            dep.latest
+        ),
+        description: String::new(),
+        labels: vec![dep.ecosystem.clone()],
+        url: String::new(),
+        extra_fields: extra,
+    }
+}
+
+/// Generate the prompt for updating a single dependency.
+pub fn build_dependency_prompt(ticket: &TicketInfo) -> String {
+    let field = |name: &str| {
+        ticket
+            .extra_fields
+            .iter()
+            .find(|(k, _)| k == name)
+            .map(|(_, v)| v.as_str())
+            .unwrap_or("unknown")
+    };
+
+    format!(
+        r#"Update the {ecosystem} dependency `{name}` from {current} to {latest}.
+
+## Instructions
+
+1. Update the version requirement in the manifest and refresh the lockfile.
+
+2. Read the changelog / release notes between {current} and {latest} and adapt any code affected by breaking changes.
+
+3. Build the project, run the test suite and linters, and fix any failures caused by the update.
+
+4. Create a new git branch, commit the update with a clear message, push it and open a pull request summarising notable changes from the upgrade.
+
+Do not ask for user input — work autonomously to completion."#,
+        ecosystem = field("Ecosystem"),
+        name = ticket.key,
+        current = field("Current"),
+        latest = field("Latest"),
+    )
+}
+
 /// Generate the default prompt for a ticket.
 ///
 /// The prompt instructs Claude Code to:
@@ -147,12 +238,7 @@ Please complete this ticket by following these steps:
 Work as a team — use Claude's team/subagent capabilities to run tasks in parallel where possible. For example, you might have one agent handle implementation while another prepares tests, or split implementation across multiple modules.
 
 Do not ask for user input — work autonomously to completion."#,
-        source = match ticket.source {
-            TicketSource::GitHubPR => "GitHub PR",
-            TicketSource::GitHubIssue => "GitHub Issue",
-            TicketSource::Linear => "Linear",
-            TicketSource::Jira => "Jira",
-        },
+        source = source_label(&ticket.source),
         key = ticket.key,
         title = ticket.title,
         labels = labels_str,
@@ -169,3 +255,128 @@ Do not ask for user input — work autonomously to completion."#,
         },
     )
 }
+
+/// Ticket info for the headless run that drafts a PR description for the
+/// current branch.
+pub fn ticket_from_branch(changes: &BranchChanges) -> TicketInfo {
+    TicketInfo {
+        source: TicketSource::GitHubPR,
+        key: "PR draft".to_string(),
+        title: changes.branch.clone(),
+        description: String::new(),
+        labels: Vec::new(),
+        url: String::new(),
+        extra_fields: vec![("Base".to_string(), changes.base.clone())],
+    }
+}
+
+/// Generate the prompt asking Claude Code for a PR description of a
+/// branch's commits. The reply is read back by [`parse_pr_description`].
+pub fn build_pr_description_prompt(changes: &BranchChanges) -> String {
+    let commits = match changes.commits.char_indices().nth(MAX_DIFF_CHARS) {
+        Some((cut, _)) => format!("{}\n... (commits truncated)", &changes.commits[..cut]),
+        None => changes.commits.clone(),
+    };
+    format!(
+        r#"Write a pull request description for merging the branch `{branch}` into `{base}`.
+
+## Commits
+{commits}
+
+## Changed files
+{diff_stat}
+
+## Instructions
+
+Summarise what the change does and why, for a reviewer who hasn't seen the commits. You may read the code to understand the changes, but do not modify any files.
+
+Reply with only the description: a short title on the first line, then a blank line, then the body in Markdown. Do not wrap the reply in a code block."#,
+        branch = changes.branch,
+        base = changes.base,
+        commits = commits,
+        diff_stat = changes.diff_stat,
+    )
+}
+
+/// Split Claude Code's reply to [`build_pr_description_prompt`] into a title
+/// and body, dropping a `Title:` label or heading marks on the first line.
+pub fn parse_pr_description(reply: &str) -> (String, String) {
+    let reply = reply.trim();
+    let (first, rest) = reply.split_once('\n').unwrap_or((reply, ""));
+    let title = first.trim().trim_start_matches('#').trim();
+    let title = title
+        .strip_prefix("Title:")
+        .or_else(|| title.strip_prefix("title:"))
+        .unwrap_or(title)
+        .trim()
+        .trim_matches('*')
+        .trim();
+    (title.to_string(), rest.trim().to_string())
+}
+
+/// Fill a prompt template's `{{key}}`, `{{title}}`, `{{body}}`, `{{branch}}`,
+/// `{{url}}`, `{{labels}}` and `{{source}}` variables from a ticket, and
+/// `{{diff}}` from `diff`, cut to `MAX_DIFF_CHARS`.
+pub fn build_template_prompt(
+    template: &str,
+    ticket: &TicketInfo,
+    branch: &str,
+    diff: &str,
+) -> String {
+    let labels = ticket.labels.join(", ");
+    let diff = match diff.char_indices().nth(MAX_DIFF_CHARS) {
+        Some((cut, _)) => format!("{}\n... (diff truncated)", &diff[..cut]),
+        None => diff.to_string(),
+    };
+    prompt_library::interpolate(
+        template,
+        &[
+            ("key", &ticket.key),
+            ("title", &ticket.title),
+            ("body", &ticket.description),
+            ("branch", branch),
+            ("url", &ticket.url),
+            ("labels", &labels),
+            ("source", source_label(&ticket.source)),
+            ("diff", &diff),
+        ],
+    )
+}
+
+/// Rough token count of a prompt, at about four characters a token.
+pub fn estimate_tokens(text: &str) -> u64 {
+    (text.chars().count() as u64).div_ceil(4)
+}
+
+pub fn source_label(source: &TicketSource) -> &'static str {
+    match source {
+        TicketSource::GitHubPR => "GitHub PR",
+        TicketSource::GitHubIssue => "GitHub Issue",
+        TicketSource::Linear => "Linear",
+        TicketSource::Jira => "Jira",
+        TicketSource::Azure => "Azure DevOps",
+        TicketSource::Dependency => "Dependency",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn splits_pr_description_reply() {
+        let reply =
+            "## Title: **Retry failed webhooks**\n\n## Summary\n- Back off up to five times\n";
+        assert_eq!(
+            parse_pr_description(reply),
+            (
+                "Retry failed webhooks".to_string(),
+                "## Summary\n- Back off up to five times".to_string()
+            )
+        );
+        assert_eq!(
+            parse_pr_description("Only a title"),
+            ("Only a title".to_string(), String::new())
+        );
+    }
+}
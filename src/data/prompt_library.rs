@@ -0,0 +1,141 @@
+use std::path::Path;
+
+use crate::model::prompt::PromptTemplate;
+
+/// Load prompt templates from `<project>/.associate/prompts/*.md` followed
+/// by `~/.claude/prompts/*.md`, each directory sorted by filename.
+pub fn load_prompt_library(project_cwd: &Path, claude_home: &Path) -> Vec<PromptTemplate> {
+    let mut templates = Vec::new();
+    for dir in [
+        project_cwd.join(".associate").join("prompts"),
+        claude_home.join("prompts"),
+    ] {
+        let Ok(entries) = std::fs::read_dir(&dir) else {
+            continue;
+        };
+        let mut paths: Vec<_> = entries
+            .flatten()
+            .map(|e| e.path())
+            .filter(|p| p.extension().and_then(|e| e.to_str()) == Some("md"))
+            .collect();
+        paths.sort();
+        for path in paths {
+            let Ok(content) = std::fs::read_to_string(&path) else {
+                continue;
+            };
+            let stem = path
+                .file_stem()
+                .map(|s| s.to_string_lossy().to_string())
+                .unwrap_or_default();
+            templates.push(parse_template(&stem, &content));
+        }
+    }
+    templates
+}
+
+/// Parse a template file. An optional header between `---` lines sets
+/// `title`, `model` and `args`; everything after it is the prompt body.
+pub fn parse_template(default_title: &str, content: &str) -> PromptTemplate {
+    let mut template = PromptTemplate {
+        title: default_title.to_string(),
+        body: content.trim().to_string(),
+        model: None,
+        args: Vec::new(),
+    };
+
+    let Some(rest) = content.strip_prefix("---") else {
+        return template;
+    };
+    let Some((header, body)) = rest.split_once("\n---") else {
+        return template;
+    };
+
+    for line in header.lines() {
+        let Some((key, value)) = line.split_once(':') else {
+            continue;
+        };
+        let value = value.trim().trim_matches('"');
+        if value.is_empty() {
+            continue;
+        }
+        match key.trim() {
+            "title" => template.title = value.to_string(),
+            "model" => template.model = Some(value.to_string()),
+            "args" => template.args = value.split_whitespace().map(String::from).collect(),
+            _ => {}
+        }
+    }
+    template.body = body.trim().to_string();
+    template
+}
+
+/// Replace `{{name}}` placeholders with their values. Unknown placeholders
+/// are left in place so they stay visible in the prompt editor.
+pub fn interpolate(template: &str, vars: &[(&str, &str)]) -> String {
+    let mut out = String::with_capacity(template.len());
+    let mut rest = template;
+    while let Some(start) = rest.find("{{") {
+        out.push_str(&rest[..start]);
+        let after = &rest[start + 2..];
+        match after.find("}}") {
+            Some(end) => {
+                let name = after[..end].trim();
+                match vars.iter().find(|(k, _)| *k == name) {
+                    Some((_, value)) => out.push_str(value),
+                    None => out.push_str(&rest[start..start + 2 + end + 2]),
+                }
+                rest = &after[end + 2..];
+            }
+            None => {
+                out.push_str(&rest[start..]);
+                rest = "";
+            }
+        }
+    }
+    out.push_str(rest);
+    out
+}
+
+/// Names of the `{{name}}` placeholders in `text`, in order, without
+/// repeats.
+pub fn placeholders(text: &str) -> Vec<String> {
+    let mut names: Vec<String> = Vec::new();
+    let mut rest = text;
+    while let Some(start) = rest.find("{{") {
+        let after = &rest[start + 2..];
+        let Some(end) = after.find("}}") else {
+            break;
+        };
+        let name = after[..end].trim();
+        if !names.iter().any(|n| n == name) {
+            names.push(name.to_string());
+        }
+        rest = &after[end + 2..];
+    }
+    names
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn template_header_and_variables() {
+        let content = "---\ntitle: Fix it\nmodel: sonnet\nargs: --max-turns 5\n---\nFix {{key}}: {{ title }} on {{branch}} {{unknown}}\n";
+        let t = parse_template("fix", content);
+        assert_eq!(t.title, "Fix it");
+        assert_eq!(t.model.as_deref(), Some("sonnet"));
+        assert_eq!(t.args, vec!["--max-turns", "5"]);
+        let text = interpolate(
+            &t.body,
+            &[("key", "GH #1"), ("title", "Crash"), ("branch", "main")],
+        );
+        assert_eq!(text, "Fix GH #1: Crash on main {{unknown}}");
+        assert_eq!(placeholders(&t.body), ["key", "title", "branch", "unknown"]);
+        assert_eq!(placeholders(&text), ["unknown"]);
+
+        let plain = parse_template("plain", "Just {{body}}");
+        assert_eq!(plain.title, "plain");
+        assert!(plain.model.is_none());
+    }
+}
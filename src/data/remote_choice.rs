@@ -0,0 +1,26 @@
+use std::path::{Path, PathBuf};
+
+use anyhow::Result;
+
+/// Location of the remembered GitHub remote for a project.
+pub fn choice_path(assoc_home: &Path, encoded_project: &str) -> PathBuf {
+    assoc_home
+        .join("remotes")
+        .join(format!("{}.txt", encoded_project))
+}
+
+/// Load the remote picked for a project, if any.
+pub fn load_choice(path: &Path) -> Option<String> {
+    let name = std::fs::read_to_string(path).ok()?;
+    let name = name.trim();
+    (!name.is_empty()).then(|| name.to_string())
+}
+
+/// Remember `name` as the project's remote.
+pub fn save_choice(path: &Path, name: &str) -> Result<()> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    std::fs::write(path, name)?;
+    Ok(())
+}
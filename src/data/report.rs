@@ -0,0 +1,413 @@
+use std::fmt::Write;
+use std::path::Path;
+
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+
+use crate::config::{self, ProjectConfig};
+use crate::data::{
+    cli_detect, cli_fixtures, git, github, inboxes, path_encoding, process_group, process_registry,
+    session_titles, sessions, tasks, teams,
+};
+use crate::model::agent_status::{self, AgentStatus};
+use crate::model::git::{GitFileSection, GitStatus};
+
+/// A snapshot of the project for `assoc report`.
+#[derive(Debug, Serialize)]
+pub struct Report {
+    pub project: String,
+    pub generated_at: DateTime<Utc>,
+    pub branch: Option<String>,
+    /// Most recently active sessions first.
+    pub sessions: Vec<ReportSession>,
+    pub running_agents: Vec<ReportAgent>,
+    pub dirty_files: Vec<ReportFile>,
+    pub prs_needing_review: Vec<ReportItem>,
+    pub assigned_issues: Vec<ReportItem>,
+    /// Sources that could not be read; the rest of the report still holds.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub errors: Vec<String>,
+}
+
+#[derive(Debug, PartialEq, Serialize)]
+pub struct ReportSession {
+    pub session_id: String,
+    pub title: String,
+    pub branch: Option<String>,
+    pub last_activity: Option<DateTime<Utc>>,
+    pub messages: Option<u32>,
+}
+
+/// A teammate (the report lists those starting or working), or a Claude
+/// Code process spawned from the dashboard that is still running.
+#[derive(Debug, PartialEq, Serialize)]
+pub struct ReportAgent {
+    pub name: String,
+    /// Team name; `None` for spawned processes.
+    pub team: Option<String>,
+    pub status: String,
+}
+
+#[derive(Debug, PartialEq, Serialize)]
+pub struct ReportFile {
+    pub path: String,
+    /// `staged`, `unstaged` or `untracked`.
+    pub section: String,
+    pub status: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ReportItem {
+    pub number: u64,
+    pub title: String,
+    pub url: String,
+    pub author: String,
+}
+
+/// Gather the report for `project_cwd` with the loaders the dashboard uses.
+/// `session_limit` caps the sessions listed. PRs and issues are read with
+/// `gh` when it is installed and the repo is known.
+pub fn build_report(project_cwd: &Path, session_limit: usize) -> Report {
+    let project_config = config::load_project_config(project_cwd);
+    let mut report = Report {
+        project: project_cwd.display().to_string(),
+        generated_at: Utc::now(),
+        branch: cli_detect::detect_git_branch(project_cwd),
+        sessions: Vec::new(),
+        running_agents: Vec::new(),
+        dirty_files: Vec::new(),
+        prs_needing_review: Vec::new(),
+        assigned_issues: Vec::new(),
+        errors: Vec::new(),
+    };
+    report.sessions = recent_sessions(
+        &project_config,
+        project_cwd,
+        session_limit,
+        &mut report.errors,
+    );
+    add_agents(&mut report, &project_config, project_cwd);
+    match git::load_git_status(project_cwd) {
+        Ok(status) => report.dirty_files = dirty_files(&status),
+        Err(e) => report.errors.push(format!("git: {}", e)),
+    }
+    add_github(&mut report, &project_config, project_cwd);
+    report
+}
+
+/// Staged, unstaged and untracked files, in that order.
+pub fn dirty_files(status: &GitStatus) -> Vec<ReportFile> {
+    let files = status
+        .staged
+        .iter()
+        .chain(&status.unstaged)
+        .chain(&status.untracked);
+    files
+        .map(|f| ReportFile {
+            path: f.path.clone(),
+            section: match f.section {
+                GitFileSection::Staged => "staged",
+                GitFileSection::Unstaged => "unstaged",
+                GitFileSection::Untracked => "untracked",
+            }
+            .to_string(),
+            status: f.status_char.to_string(),
+        })
+        .collect()
+}
+
+/// The `limit` most recently active sessions in every Claude home, with
+/// their assoc titles. Unreadable homes are noted in `errors`.
+pub fn recent_sessions(
+    project_config: &ProjectConfig,
+    project_cwd: &Path,
+    limit: usize,
+    errors: &mut Vec<String>,
+) -> Vec<ReportSession> {
+    let encoded = path_encoding::encode_project_path(project_cwd);
+    let mut entries = Vec::new();
+    for home in project_config.claude_homes() {
+        let project_dir = home.path.join("projects").join(&encoded);
+        let scan_index = sessions::index_path(&config::assoc_home(), &project_dir);
+        match sessions::load_sessions(&project_dir, &scan_index, true) {
+            Ok(found) => entries.extend(found),
+            Err(e) => errors.push(format!("sessions: {}", e)),
+        }
+    }
+    entries.sort_by_key(|s| std::cmp::Reverse(s.modified));
+    let titles = session_titles::load_titles(&session_titles::titles_path(
+        &config::assoc_home(),
+        &encoded,
+    ));
+    session_titles::apply_titles(&titles, &mut entries);
+    entries
+        .into_iter()
+        .take(limit)
+        .map(|s| ReportSession {
+            title: s.display_title(),
+            branch: s.git_branch.filter(|b| !b.is_empty()),
+            last_activity: s.modified,
+            messages: s.message_count,
+            session_id: s.session_id,
+        })
+        .collect()
+}
+
+fn add_agents(report: &mut Report, project_config: &ProjectConfig, project_cwd: &Path) {
+    for (status, agent) in team_agents(project_config, project_cwd, &mut report.errors) {
+        if matches!(status, AgentStatus::Starting | AgentStatus::Working) {
+            report.running_agents.push(agent);
+        }
+    }
+
+    let encoded = path_encoding::encode_project_path(project_cwd);
+    let registry = process_registry::registry_path(&config::assoc_home(), &encoded);
+    for record in process_registry::load_registry(&registry) {
+        if process_group::group_alive(record.pid) {
+            report.running_agents.push(ReportAgent {
+                name: record.label,
+                team: None,
+                status: "running".to_string(),
+            });
+        }
+    }
+}
+
+/// Every member of the project's teams with the status the Teams tab
+/// derives for it. Unreadable homes are noted in `errors`.
+pub fn team_agents(
+    project_config: &ProjectConfig,
+    project_cwd: &Path,
+    errors: &mut Vec<String>,
+) -> Vec<(AgentStatus, ReportAgent)> {
+    let mut agents = Vec::new();
+    for home in project_config.claude_homes() {
+        let found = match teams::load_teams(&home.path, Some(project_cwd)) {
+            Ok(found) => found,
+            Err(e) => {
+                errors.push(format!("teams: {}", e));
+                continue;
+            }
+        };
+        for team in found {
+            let team_tasks = tasks::load_tasks(&home.path, &team.dir_name).unwrap_or_default();
+            let lead_inbox = inboxes::load_lead_inbox(&home.path, &team);
+            let names: Vec<&str> = team
+                .config
+                .members
+                .iter()
+                .map(|m| m.name.as_str())
+                .collect();
+            let statuses = agent_status::derive_all_statuses(&names, &lead_inbox, &team_tasks);
+            for name in names {
+                let status = statuses.get(name).cloned().unwrap_or(AgentStatus::Starting);
+                let agent = ReportAgent {
+                    name: name.to_string(),
+                    team: Some(team.display_name().to_string()),
+                    status: status.label().to_string(),
+                };
+                agents.push((status, agent));
+            }
+        }
+    }
+    agents
+}
+
+fn add_github(report: &mut Report, project_config: &ProjectConfig, project_cwd: &Path) {
+    if project_config.prs_from_bitbucket()
+        || !(cli_detect::is_available("gh") || cli_fixtures::has_replay_fixtures("gh"))
+    {
+        return;
+    }
+    let repo = project_config
+        .github_repo()
+        .map(String::from)
+        .or_else(|| cli_detect::pick_repo(&cli_detect::list_gh_remotes(project_cwd), None));
+    let Some(repo) = repo else {
+        return;
+    };
+    let Some(user) = cli_detect::detect_gh_user() else {
+        report
+            .errors
+            .push("GitHub: not authenticated; run 'gh auth login'".to_string());
+        return;
+    };
+
+    match github::list_open_prs(&repo, project_config.github_page_size()) {
+        Ok(prs) => {
+            report.prs_needing_review = prs
+                .iter()
+                .filter(|pr| !pr.is_draft && pr.review_requested_from(&user))
+                .map(|pr| ReportItem {
+                    number: pr.number,
+                    title: pr.title.clone(),
+                    url: pr.url.clone(),
+                    author: pr.author.login.clone(),
+                })
+                .collect();
+        }
+        Err(e) => report.errors.push(format!("PRs: {}", e)),
+    }
+
+    if !project_config.github_issues_enabled() {
+        return;
+    }
+    let issues_repo = project_config
+        .github_issues_repo()
+        .map(String::from)
+        .unwrap_or(repo);
+    match github::list_issues(&issues_repo, "open", project_config.github_page_size()) {
+        Ok(issues) => {
+            report.assigned_issues = issues
+                .iter()
+                .filter(|i| {
+                    i.assignees
+                        .iter()
+                        .any(|a| a.login.eq_ignore_ascii_case(&user))
+                })
+                .map(|i| ReportItem {
+                    number: i.number,
+                    title: i.title.clone(),
+                    url: i.url.clone(),
+                    author: i.author.login.clone(),
+                })
+                .collect();
+        }
+        Err(e) => report.errors.push(format!("Issues: {}", e)),
+    }
+}
+
+/// Render the report as a Markdown digest.
+pub fn to_markdown(report: &Report) -> String {
+    let mut out = String::new();
+    let _ = writeln!(out, "# Project report: {}", report.project);
+    let _ = writeln!(out);
+    let _ = write!(
+        out,
+        "Generated {}",
+        report.generated_at.format("%Y-%m-%d %H:%M UTC")
+    );
+    if let Some(branch) = &report.branch {
+        let _ = write!(out, " on branch `{}`", branch);
+    }
+    let _ = writeln!(out);
+
+    let sessions = report.sessions.iter().map(|s| {
+        // Prompts used as titles can span lines
+        let title = s.title.split_whitespace().collect::<Vec<_>>().join(" ");
+        let mut line = format!("{} (`{}`", title, short_id(&s.session_id));
+        if let Some(branch) = &s.branch {
+            let _ = write!(line, ", {}", branch);
+        }
+        if let Some(messages) = s.messages {
+            let _ = write!(line, ", {} messages", messages);
+        }
+        if let Some(at) = s.last_activity {
+            let _ = write!(line, ", last active {}", at.format("%Y-%m-%d %H:%M"));
+        }
+        line.push(')');
+        line
+    });
+    section(&mut out, "Recent sessions", sessions);
+
+    let agents = report.running_agents.iter().map(|a| match &a.team {
+        Some(team) => format!("{} ({}): {}", a.name, team, a.status),
+        None => format!("{}: {}", a.name, a.status),
+    });
+    section(&mut out, "Running agents", agents);
+
+    let files = report
+        .dirty_files
+        .iter()
+        .map(|f| format!("`{}` {} ({})", f.status, f.path, f.section));
+    section(&mut out, "Uncommitted files", files);
+
+    let item = |i: &ReportItem| format!("[#{} {}]({}) by {}", i.number, i.title, i.url, i.author);
+    section(
+        &mut out,
+        "PRs needing your review",
+        report.prs_needing_review.iter().map(item),
+    );
+    section(
+        &mut out,
+        "Issues assigned to you",
+        report.assigned_issues.iter().map(item),
+    );
+
+    if !report.errors.is_empty() {
+        section(&mut out, "Errors", report.errors.iter().cloned());
+    }
+    out
+}
+
+/// A `## title (count)` heading with one bullet per item, or "None".
+fn section(out: &mut String, title: &str, items: impl ExactSizeIterator<Item = String>) {
+    let _ = writeln!(out);
+    let _ = writeln!(out, "## {} ({})", title, items.len());
+    let _ = writeln!(out);
+    if items.len() == 0 {
+        let _ = writeln!(out, "None");
+    }
+    for item in items {
+        let _ = writeln!(out, "- {}", item);
+    }
+}
+
+fn short_id(id: &str) -> &str {
+    &id[..8.min(id.len())]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn renders_markdown_sections() {
+        let report = Report {
+            project: "/work/app".to_string(),
+            generated_at: "2025-02-01T08:00:00Z".parse().unwrap(),
+            branch: Some("main".to_string()),
+            sessions: vec![ReportSession {
+                session_id: "abcdef1234567890".to_string(),
+                title: "Fix login".to_string(),
+                branch: Some("fix/login".to_string()),
+                last_activity: Some("2025-02-01T07:30:00Z".parse().unwrap()),
+                messages: Some(12),
+            }],
+            running_agents: vec![ReportAgent {
+                name: "tester".to_string(),
+                team: Some("qa".to_string()),
+                status: "working".to_string(),
+            }],
+            dirty_files: vec![ReportFile {
+                path: "src/lib.rs".to_string(),
+                section: "unstaged".to_string(),
+                status: "M".to_string(),
+            }],
+            prs_needing_review: Vec::new(),
+            assigned_issues: vec![ReportItem {
+                number: 7,
+                title: "Crash on start".to_string(),
+                url: "https://github.com/o/r/issues/7".to_string(),
+                author: "hubot".to_string(),
+            }],
+            errors: Vec::new(),
+        };
+        let md = to_markdown(&report);
+        assert!(md.starts_with(
+            "# Project report: /work/app\n\nGenerated 2025-02-01 08:00 UTC on branch `main`\n"
+        ));
+        assert!(md.contains(
+            "- Fix login (`abcdef12`, fix/login, 12 messages, last active 2025-02-01 07:30)\n"
+        ));
+        assert!(md.contains("## Running agents (1)\n\n- tester (qa): working\n"));
+        assert!(md.contains("- `M` src/lib.rs (unstaged)\n"));
+        assert!(md.contains("## PRs needing your review (0)\n\nNone\n"));
+        assert!(md.contains("- [#7 Crash on start](https://github.com/o/r/issues/7) by hubot\n"));
+        assert!(!md.contains("## Errors"));
+
+        let json = serde_json::to_value(&report).unwrap();
+        assert_eq!(json["sessions"][0]["messages"], 12);
+        assert!(json.get("errors").is_none());
+    }
+}
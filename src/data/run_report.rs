@@ -0,0 +1,282 @@
+use std::fmt::Write;
+use std::path::{Path, PathBuf};
+
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+
+use super::{github, jira, linear, prompt_builder};
+use crate::model::process::{plain_text, ProcessStatus, SpawnedProcess, TicketSource};
+use crate::model::redact::redact;
+
+/// Longest report posted as a comment. Jira caps comments at 32,767
+/// characters, and the body travels as a command-line argument.
+pub const COMMENT_MAX_CHARS: usize = 30_000;
+
+/// What a run from `source` can post its report on, if anything.
+pub fn comment_target(source: &TicketSource) -> Option<&'static str> {
+    match source {
+        TicketSource::GitHubPR => Some("the PR"),
+        TicketSource::GitHubIssue => Some("the issue"),
+        TicketSource::Linear => Some("the Linear issue"),
+        TicketSource::Jira => Some("the Jira issue"),
+        TicketSource::Azure | TicketSource::Dependency => None,
+    }
+}
+
+/// Markdown report of a finished run: the ticket, outcome, prompt, the
+/// files it changed (`diff`) and its output. With `max_chars`, the diff
+/// keeps its start and the output its end, cut to fit. Secrets are
+/// redacted as in session exports.
+pub fn render(process: &SpawnedProcess, diff: Option<&str>, max_chars: Option<usize>) -> String {
+    let output: Vec<String> = if process.progress_lines.is_empty() {
+        process.output_lines.iter().map(|l| plain_text(l)).collect()
+    } else {
+        process
+            .progress_lines
+            .iter()
+            .map(|l| plain_text(l))
+            .collect()
+    };
+    let output = output.join("\n");
+    let errors: Vec<String> = process.error_lines.iter().map(|l| plain_text(l)).collect();
+    let errors = errors.join("\n");
+    let diff = diff.unwrap_or("");
+
+    let mut head = format!("# {}: {}\n\n", process.label, process.title);
+    let source = prompt_builder::source_label(&process.source);
+    if process.ticket_url.is_empty() {
+        let _ = writeln!(head, "- **Ticket:** {} ({})", process.label, source);
+    } else {
+        let _ = writeln!(
+            head,
+            "- **Ticket:** [{}]({}) ({})",
+            process.label, process.ticket_url, source
+        );
+    }
+    let outcome = match process.status {
+        ProcessStatus::Completed => "Completed",
+        ProcessStatus::Failed => "Failed",
+        ProcessStatus::Queued => "Queued",
+        ProcessStatus::Running | ProcessStatus::Terminating => "Running",
+    };
+    match process.exit_code {
+        Some(code) => {
+            let _ = writeln!(head, "- **Status:** {}, exit code {}", outcome, code);
+        }
+        None => {
+            let _ = writeln!(head, "- **Status:** {}", outcome);
+        }
+    }
+    let time = |t: DateTime<Utc>| t.format("%Y-%m-%d %H:%M:%S UTC").to_string();
+    if let Some(started) = process.started_at {
+        let _ = writeln!(head, "- **Started:** {}", time(started));
+    }
+    if let Some(finished) = process.finished_at {
+        let _ = writeln!(head, "- **Finished:** {}", time(finished));
+    }
+    if let (Some(started), Some(finished)) = (process.started_at, process.finished_at) {
+        let _ = writeln!(
+            head,
+            "- **Duration:** {}",
+            format_duration(finished - started)
+        );
+    }
+    if let Some(ref session) = process.session_id {
+        let _ = writeln!(head, "- **Session:** `{}`", session);
+    }
+    let _ = write!(head, "\n## Prompt\n\n{}", fenced("text", &process.prompt));
+
+    // The diff gets half of what is left, the output most of the rest
+    let budget = max_chars.map(|max| max.saturating_sub(head.len() + 200));
+    let share = |parts: usize| budget.map(|b| b * parts / 20);
+    let mut report = head;
+    report.push_str("\n## Changes\n\n");
+    if process.tree_before.is_none() || process.tree_after.is_none() {
+        report.push_str("Not recorded: the project isn't a git repository.\n");
+    } else if diff.trim().is_empty() {
+        report.push_str("No files changed.\n");
+    } else {
+        report.push_str(&fenced("diff", &clip_head(diff, share(10))));
+    }
+    report.push_str("\n## Output\n\n");
+    if output.is_empty() {
+        report.push_str("No output.\n");
+    } else {
+        report.push_str(&fenced("text", &clip_tail(&output, share(7))));
+    }
+    if !errors.is_empty() {
+        report.push_str("\n## Errors\n\n");
+        report.push_str(&fenced("text", &clip_tail(&errors, share(3))));
+    }
+    redact(&report)
+}
+
+/// Report file for `process` under `dir`, e.g. `ENG-12-20261016-142301.md`.
+pub fn report_path(dir: &Path, process: &SpawnedProcess) -> PathBuf {
+    let mut slug = String::new();
+    for c in process.label.chars() {
+        if c.is_ascii_alphanumeric() {
+            slug.push(c);
+        } else if !slug.ends_with('-') {
+            slug.push('-');
+        }
+    }
+    let slug = slug.trim_matches('-');
+    let slug = if slug.is_empty() { "run" } else { slug };
+    let at = process.finished_at.unwrap_or_else(Utc::now);
+    dir.join(format!("{}-{}.md", slug, at.format("%Y%m%d-%H%M%S")))
+}
+
+pub fn write_report(path: &Path, report: &str) -> Result<()> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    std::fs::write(path, report)?;
+    Ok(())
+}
+
+/// Post `body` as a comment on the ticket a run was launched from: the
+/// GitHub issue or PR at `url`, or the Jira or Linear issue `key`.
+pub fn post_comment(
+    source: &TicketSource,
+    key: &str,
+    url: &str,
+    linear_api_key: Option<&str>,
+    body: &str,
+) -> Result<()> {
+    match source {
+        TicketSource::GitHubPR => github::comment_on_url(url, true, body),
+        TicketSource::GitHubIssue => github::comment_on_url(url, false, body),
+        TicketSource::Jira => jira::add_comment(key, body),
+        TicketSource::Linear => match linear_api_key {
+            Some(api_key) => linear::add_comment(api_key, key, body),
+            None => anyhow::bail!("no Linear API key configured"),
+        },
+        TicketSource::Azure | TicketSource::Dependency => {
+            anyhow::bail!(
+                "{} tickets don't take comments",
+                prompt_builder::source_label(source)
+            )
+        }
+    }
+}
+
+/// `4m 12s`, `1h 03m` or `12s`.
+fn format_duration(duration: chrono::Duration) -> String {
+    let secs = duration.num_seconds().max(0);
+    if secs >= 3600 {
+        format!("{}h {:02}m", secs / 3600, secs % 3600 / 60)
+    } else if secs >= 60 {
+        format!("{}m {:02}s", secs / 60, secs % 60)
+    } else {
+        format!("{}s", secs)
+    }
+}
+
+/// `text` in a code fence longer than any backtick run inside it.
+fn fenced(lang: &str, text: &str) -> String {
+    let longest = text.split(|c| c != '`').map(str::len).max().unwrap_or(0);
+    let fence = "`".repeat(longest.max(2) + 1);
+    format!(
+        "{}{}\n{}\n{}\n",
+        fence,
+        lang,
+        text.trim_end_matches('\n'),
+        fence
+    )
+}
+
+/// The first lines of `text` that fit in `max` characters.
+fn clip_head(text: &str, max: Option<usize>) -> String {
+    let Some(max) = max.filter(|max| text.len() > *max) else {
+        return text.to_string();
+    };
+    let mut kept = Vec::new();
+    let mut used = 0;
+    for line in text.lines() {
+        if used + line.len() + 1 > max {
+            break;
+        }
+        used += line.len() + 1;
+        kept.push(line);
+    }
+    let cut = text.lines().count() - kept.len();
+    format!("{}\n... {} more line(s)", kept.join("\n"), cut)
+}
+
+/// The last lines of `text` that fit in `max` characters.
+fn clip_tail(text: &str, max: Option<usize>) -> String {
+    let Some(max) = max.filter(|max| text.len() > *max) else {
+        return text.to_string();
+    };
+    let mut kept = Vec::new();
+    let mut used = 0;
+    for line in text.lines().rev() {
+        if used + line.len() + 1 > max {
+            break;
+        }
+        used += line.len() + 1;
+        kept.push(line);
+    }
+    kept.reverse();
+    let cut = text.lines().count() - kept.len();
+    format!("... {} earlier line(s)\n{}", cut, kept.join("\n"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::VecDeque;
+
+    #[test]
+    fn renders_and_clips_a_run_report() {
+        let started = DateTime::parse_from_rfc3339("2026-10-16T14:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        let process = SpawnedProcess {
+            id: 1,
+            label: "ENG-12".to_string(),
+            title: "Fix login".to_string(),
+            source: TicketSource::Linear,
+            ticket_url: "https://linear.app/x/issue/ENG-12".to_string(),
+            status: ProcessStatus::Completed,
+            prompt: "Fix the login bug".to_string(),
+            model: None,
+            args: Vec::new(),
+            cwd: PathBuf::from("/tmp"),
+            output_lines: VecDeque::from(["raw".to_string()]),
+            error_lines: VecDeque::new(),
+            session_id: Some("abc".to_string()),
+            progress_lines: (1..=400)
+                .map(|i| format!("\x1b[32mstep {}\x1b[0m", i))
+                .collect(),
+            follow: true,
+            artifacts: Vec::new(),
+            started_at: Some(started),
+            finished_at: Some(started + chrono::Duration::seconds(252)),
+            exit_code: Some(0),
+            tree_before: Some("a".to_string()),
+            tree_after: Some("b".to_string()),
+        };
+        let diff = " src/login.rs | 2 +-\n```\n-old\n+new password=hunter2";
+
+        let full = render(&process, Some(diff), None);
+        assert!(full.starts_with("# ENG-12: Fix login\n"));
+        assert!(full.contains("- **Status:** Completed, exit code 0\n"));
+        assert!(full.contains("- **Duration:** 4m 12s\n"));
+        assert!(full.contains("````diff\n src/login.rs"));
+        assert!(!full.contains("hunter2"));
+        assert!(full.contains("step 1\nstep 2\n"));
+        assert!(!full.contains('\x1b'));
+
+        let clipped = render(&process, Some(diff), Some(2_000));
+        assert!(clipped.len() < 2_000);
+        assert!(clipped.contains("earlier line(s)\n"));
+        assert!(clipped.contains("step 400\n"));
+
+        assert_eq!(
+            report_path(Path::new("run-reports"), &process),
+            Path::new("run-reports/ENG-12-20261016-140412.md")
+        );
+    }
+}
@@ -0,0 +1,375 @@
+//! Read-only dashboard over HTTP.
+//!
+//! `assoc serve` answers `GET /` with a small page for phones, `GET
+//! /api/state` with the current [`Dashboard`] as JSON and `GET /events`
+//! with the same JSON as server-sent events, one whenever it changes.
+//! Nothing can be changed through it.
+
+use std::io::{BufRead, BufReader, Write};
+use std::net::{SocketAddr, TcpListener, TcpStream};
+use std::path::Path;
+use std::sync::mpsc::SyncSender;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+
+use crate::config;
+use crate::data::report::{self, ReportAgent, ReportFile, ReportSession};
+use crate::data::{cli_detect, git, path_encoding, process_group, process_registry};
+use crate::model::redact::redact;
+
+/// A client that stops reading for this long is dropped.
+const WRITE_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// Events waiting for a stream's writer thread. A stream that falls this
+/// far behind is dropped, so a slow phone never holds up publishing.
+const CLIENT_QUEUE: usize = 16;
+
+/// How long a client has to send its request.
+const READ_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Comment sent to event streams so dead connections get noticed.
+const KEEP_ALIVE: Duration = Duration::from_secs(15);
+
+const PAGE: &str = include_str!("serve_page.html");
+
+/// What the dashboard shows, as served.
+#[derive(Debug, PartialEq, Serialize)]
+pub struct Dashboard {
+    pub project: String,
+    /// Most recently active sessions first.
+    pub sessions: Vec<ReportSession>,
+    /// Every team member with its status.
+    pub agents: Vec<ReportAgent>,
+    /// Claude Code processes spawned from the dashboard that still run.
+    pub processes: Vec<DashboardProcess>,
+    pub git: GitSummary,
+    /// Sources that could not be read; the rest still holds.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub errors: Vec<String>,
+}
+
+#[derive(Debug, PartialEq, Serialize)]
+pub struct DashboardProcess {
+    pub label: String,
+    pub pid: u32,
+    pub started: DateTime<Utc>,
+}
+
+#[derive(Debug, Default, PartialEq, Serialize)]
+pub struct GitSummary {
+    pub branch: Option<String>,
+    pub staged: usize,
+    pub unstaged: usize,
+    pub untracked: usize,
+    pub stashes: usize,
+    pub files: Vec<ReportFile>,
+}
+
+/// Read the dashboard for `project_cwd` with the loaders the TUI uses.
+/// `session_limit` caps the sessions listed; their titles are redacted.
+pub fn snapshot(project_cwd: &Path, session_limit: usize) -> Dashboard {
+    let project_config = config::load_project_config(project_cwd);
+    let mut errors = Vec::new();
+    let mut sessions =
+        report::recent_sessions(&project_config, project_cwd, session_limit, &mut errors);
+    for session in &mut sessions {
+        session.title = redact(&session.title);
+    }
+    let agents = report::team_agents(&project_config, project_cwd, &mut errors)
+        .into_iter()
+        .map(|(_, agent)| agent)
+        .collect();
+
+    let encoded = path_encoding::encode_project_path(project_cwd);
+    let registry = process_registry::registry_path(&config::assoc_home(), &encoded);
+    let processes = process_registry::load_registry(&registry)
+        .into_iter()
+        .filter(|record| process_group::group_alive(record.pid))
+        .map(|record| DashboardProcess {
+            label: record.label,
+            pid: record.pid,
+            started: record.started,
+        })
+        .collect();
+
+    let mut git = GitSummary {
+        branch: cli_detect::detect_git_branch(project_cwd),
+        ..GitSummary::default()
+    };
+    match git::load_git_status(project_cwd) {
+        Ok(status) => {
+            git.staged = status.staged.len();
+            git.unstaged = status.unstaged.len();
+            git.untracked = status.untracked.len();
+            git.stashes = status.stashes.len();
+            git.files = report::dirty_files(&status);
+        }
+        Err(e) => errors.push(format!("git: {}", e)),
+    }
+
+    Dashboard {
+        project: project_cwd.display().to_string(),
+        sessions,
+        agents,
+        processes,
+        git,
+        errors,
+    }
+}
+
+/// The JSON sent for a dashboard, stamped with when it last changed.
+#[derive(Serialize)]
+struct Published<'a> {
+    updated_at: DateTime<Utc>,
+    #[serde(flatten)]
+    dashboard: &'a Dashboard,
+}
+
+struct ServeState {
+    current: Dashboard,
+    json: String,
+    /// Queues of the open `/events` streams' writer threads.
+    clients: Vec<SyncSender<Arc<Vec<u8>>>>,
+}
+
+/// Serves the latest published [`Dashboard`] over HTTP.
+pub struct DashboardServer {
+    addr: SocketAddr,
+    state: Arc<Mutex<ServeState>>,
+}
+
+impl DashboardServer {
+    /// Listen on `addr` (e.g. `0.0.0.0:8080`) and answer requests in the
+    /// background, starting with `dashboard`.
+    pub fn start(addr: &str, dashboard: Dashboard) -> Result<Self> {
+        let listener = TcpListener::bind(addr)?;
+        let addr = listener.local_addr()?;
+        let json = to_json(&dashboard)?;
+        let state = Arc::new(Mutex::new(ServeState {
+            current: dashboard,
+            json,
+            clients: Vec::new(),
+        }));
+
+        let accept_state = Arc::clone(&state);
+        std::thread::spawn(move || {
+            for stream in listener.incoming() {
+                let Ok(stream) = stream else {
+                    continue;
+                };
+                let state = Arc::clone(&accept_state);
+                std::thread::spawn(move || {
+                    let _ = handle_connection(stream, &state);
+                });
+            }
+        });
+
+        let ping_state = Arc::downgrade(&state);
+        std::thread::spawn(move || loop {
+            std::thread::sleep(KEEP_ALIVE);
+            let Some(state) = ping_state.upgrade() else {
+                return;
+            };
+            let Ok(mut state) = state.lock() else {
+                return;
+            };
+            broadcast(&mut state.clients, ": keep-alive\n\n".into());
+        });
+
+        Ok(Self { addr, state })
+    }
+
+    pub fn addr(&self) -> SocketAddr {
+        self.addr
+    }
+
+    /// Serve `dashboard` from now on, sending it to event streams if it
+    /// differs from the last one.
+    pub fn publish(&self, dashboard: Dashboard) {
+        let Ok(mut state) = self.state.lock() else {
+            return;
+        };
+        if state.current == dashboard {
+            return;
+        }
+        let Ok(json) = to_json(&dashboard) else {
+            return;
+        };
+        broadcast(&mut state.clients, sse_event(&json));
+        state.current = dashboard;
+        state.json = json;
+    }
+}
+
+fn to_json(dashboard: &Dashboard) -> serde_json::Result<String> {
+    serde_json::to_string(&Published {
+        updated_at: Utc::now(),
+        dashboard,
+    })
+}
+
+/// One server-sent event carrying `json`, which has no line breaks.
+fn sse_event(json: &str) -> String {
+    format!("data: {}\n\n", json)
+}
+
+/// Queue `text` for every event stream, dropping those whose writer has
+/// stopped or fallen behind.
+fn broadcast(clients: &mut Vec<SyncSender<Arc<Vec<u8>>>>, text: String) {
+    let text = Arc::new(text.into_bytes());
+    clients.retain(|client| client.try_send(Arc::clone(&text)).is_ok());
+}
+
+/// Start a thread writing queued events to `stream`. It stops when a write
+/// fails or the queue's sender is dropped.
+fn spawn_writer(mut stream: TcpStream) -> SyncSender<Arc<Vec<u8>>> {
+    let (tx, rx) = std::sync::mpsc::sync_channel::<Arc<Vec<u8>>>(CLIENT_QUEUE);
+    std::thread::spawn(move || {
+        for text in rx {
+            if stream.write_all(&text).is_err() {
+                break;
+            }
+        }
+        let _ = stream.shutdown(std::net::Shutdown::Both);
+    });
+    tx
+}
+
+#[derive(Debug, PartialEq)]
+enum Route {
+    Page,
+    State,
+    Events,
+    NotFound,
+    /// Anything but GET.
+    NotAllowed,
+}
+
+/// Where a request line such as `GET /api/state HTTP/1.1` goes. The query
+/// string is ignored.
+fn route(request_line: &str) -> Route {
+    let mut parts = request_line.split_whitespace();
+    if parts.next() != Some("GET") {
+        return Route::NotAllowed;
+    }
+    let target = parts.next().unwrap_or("/");
+    match target.split('?').next().unwrap_or(target) {
+        "/" | "/index.html" => Route::Page,
+        "/api/state" => Route::State,
+        "/events" => Route::Events,
+        _ => Route::NotFound,
+    }
+}
+
+fn handle_connection(mut stream: TcpStream, state: &Mutex<ServeState>) -> std::io::Result<()> {
+    stream.set_read_timeout(Some(READ_TIMEOUT))?;
+    stream.set_write_timeout(Some(WRITE_TIMEOUT))?;
+    let mut reader = BufReader::new(stream.try_clone()?);
+    let mut request_line = String::new();
+    reader.read_line(&mut request_line)?;
+    // Headers are not used, but are read so the client sees a clean close
+    let mut header = String::new();
+    while reader.read_line(&mut header)? > 2 {
+        header.clear();
+    }
+
+    match route(&request_line) {
+        Route::Page => respond(&mut stream, "200 OK", "text/html; charset=utf-8", PAGE),
+        Route::State => {
+            let json = match state.lock() {
+                Ok(state) => state.json.clone(),
+                Err(_) => return Ok(()),
+            };
+            respond(&mut stream, "200 OK", "application/json", &json)
+        }
+        Route::Events => {
+            stream.write_all(
+                b"HTTP/1.1 200 OK\r\nContent-Type: text/event-stream\r\n\
+                  Cache-Control: no-store\r\nConnection: keep-alive\r\n\r\n",
+            )?;
+            let Ok(mut state) = state.lock() else {
+                return Ok(());
+            };
+            // Queued under the lock, so no update falls between this one and
+            // the stream
+            let client = spawn_writer(stream);
+            let first = Arc::new(sse_event(&state.json).into_bytes());
+            if client.try_send(first).is_ok() {
+                state.clients.push(client);
+            }
+            Ok(())
+        }
+        Route::NotFound => respond(&mut stream, "404 Not Found", "text/plain", "Not found\n"),
+        Route::NotAllowed => respond(
+            &mut stream,
+            "405 Method Not Allowed",
+            "text/plain",
+            "This dashboard is read-only\n",
+        ),
+    }
+}
+
+fn respond(
+    stream: &mut TcpStream,
+    status: &str,
+    content_type: &str,
+    body: &str,
+) -> std::io::Result<()> {
+    write!(
+        stream,
+        "HTTP/1.1 {}\r\nContent-Type: {}\r\nContent-Length: {}\r\n\
+         Cache-Control: no-store\r\nConnection: close\r\n\r\n{}",
+        status,
+        content_type,
+        body.len(),
+        body
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn routes_get_requests_only() {
+        assert_eq!(route("GET / HTTP/1.1\r\n"), Route::Page);
+        assert_eq!(route("GET /api/state?t=1 HTTP/1.1\r\n"), Route::State);
+        assert_eq!(route("GET /events HTTP/1.1\r\n"), Route::Events);
+        assert_eq!(route("GET /api/kill HTTP/1.1\r\n"), Route::NotFound);
+        assert_eq!(route("POST /api/state HTTP/1.1\r\n"), Route::NotAllowed);
+        assert_eq!(route(""), Route::NotAllowed);
+        assert_eq!(sse_event("{}"), "data: {}\n\n");
+    }
+
+    #[test]
+    fn a_stream_that_stops_reading_is_dropped_without_blocking() {
+        let dashboard = |project: String| Dashboard {
+            project,
+            sessions: Vec::new(),
+            agents: Vec::new(),
+            processes: Vec::new(),
+            git: GitSummary::default(),
+            errors: Vec::new(),
+        };
+        let server = DashboardServer::start("127.0.0.1:0", dashboard(String::new())).unwrap();
+        let mut stalled = TcpStream::connect(server.addr()).unwrap();
+        stalled.write_all(b"GET /events HTTP/1.1\r\n\r\n").unwrap();
+        let clients = || server.state.lock().unwrap().clients.len();
+        while clients() == 0 {
+            std::thread::sleep(Duration::from_millis(5));
+        }
+
+        // Enough to fill the socket buffers and then the queue
+        let padding = "x".repeat(64 * 1024);
+        for i in 0..CLIENT_QUEUE * 16 {
+            let started = std::time::Instant::now();
+            server.publish(dashboard(format!("{}{}", i, padding)));
+            assert!(started.elapsed() < WRITE_TIMEOUT / 2);
+        }
+        assert_eq!(clients(), 0);
+    }
+}
@@ -0,0 +1,183 @@
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+
+use crate::model::redact::redact;
+use crate::model::transcript::{ContentBlock, MessageContent, TranscriptEnvelope};
+
+/// One exported message: who said it, what it said and which tools it called.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ExportRecord {
+    pub session_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timestamp: Option<DateTime<Utc>>,
+    /// `user` or `assistant`.
+    pub role: String,
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub text: String,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub tools: Vec<String>,
+}
+
+/// Where the TUI writes the export of `session_id`.
+pub fn export_path(assoc_home: &Path, session_id: &str) -> PathBuf {
+    assoc_home
+        .join("exports")
+        .join(format!("{}.jsonl", session_id))
+}
+
+/// Transcript of the session whose id starts with `session` in one of
+/// `project_dirs`, or the most recently modified transcript when `None`.
+pub fn find_transcript(project_dirs: &[PathBuf], session: Option<&str>) -> Result<PathBuf> {
+    let mut candidates: Vec<PathBuf> = project_dirs
+        .iter()
+        .filter_map(|dir| std::fs::read_dir(dir).ok())
+        .flat_map(|entries| entries.flatten().map(|e| e.path()))
+        .filter(|path| path.extension().is_some_and(|e| e == "jsonl"))
+        .filter(|path| match session {
+            Some(prefix) => path
+                .file_stem()
+                .is_some_and(|stem| stem.to_string_lossy().starts_with(prefix)),
+            None => true,
+        })
+        .collect();
+    if let Some(prefix) = session {
+        // An exact id wins over longer ids sharing it as a prefix
+        if let Some(exact) = candidates
+            .iter()
+            .find(|p| p.file_stem().is_some_and(|s| s.to_string_lossy() == prefix))
+        {
+            return Ok(exact.clone());
+        }
+        return match candidates.len() {
+            0 => anyhow::bail!("no session matching '{}'", prefix),
+            1 => Ok(candidates.remove(0)),
+            n => anyhow::bail!("'{}' matches {} sessions; give more of the id", prefix, n),
+        };
+    }
+    candidates.sort_by_key(|p| std::fs::metadata(p).and_then(|m| m.modified()).ok());
+    candidates
+        .pop()
+        .ok_or_else(|| anyhow::anyhow!("no sessions found for this project"))
+}
+
+/// Write the redacted records of the transcript at `path` to `out`, one JSON
+/// object per line. Returns the number of records written.
+pub fn export_jsonl(path: &Path, out: &mut impl Write) -> Result<usize> {
+    let session_id = path
+        .file_stem()
+        .map(|s| s.to_string_lossy().to_string())
+        .unwrap_or_default();
+    let content = std::fs::read_to_string(path)?;
+    let records = export_records(&session_id, &content);
+    for record in &records {
+        serde_json::to_writer(&mut *out, record)?;
+        writeln!(out)?;
+    }
+    out.flush()?;
+    Ok(records.len())
+}
+
+/// Export the transcript at `transcript` into the file at `path`, creating
+/// its directory. Returns the number of records written.
+pub fn export_to_file(transcript: &Path, path: &Path) -> Result<usize> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    let mut file = std::io::BufWriter::new(std::fs::File::create(path)?);
+    export_jsonl(transcript, &mut file)
+}
+
+/// User prompts and assistant messages of a JSONL transcript with secrets
+/// redacted. Tool results are left out; tool calls keep only their name.
+/// Assistant messages written as one line per content block are merged.
+pub fn export_records(session_id: &str, content: &str) -> Vec<ExportRecord> {
+    let mut records: Vec<ExportRecord> = Vec::new();
+    let mut last_message_id: Option<String> = None;
+    for line in content.lines() {
+        let Ok(envelope) = serde_json::from_str::<TranscriptEnvelope>(line) else {
+            continue;
+        };
+        let Some(message) = &envelope.message else {
+            continue;
+        };
+        let role = message.role.as_deref().unwrap_or(envelope.kind.as_str());
+        if role != "user" && role != "assistant" {
+            continue;
+        }
+
+        let mut texts = Vec::new();
+        let mut tools = Vec::new();
+        match &message.content {
+            MessageContent::Text(text) => texts.push(text.as_str()),
+            MessageContent::Blocks(blocks) => {
+                for block in blocks {
+                    match block {
+                        ContentBlock::Text { text } => texts.push(text.as_str()),
+                        ContentBlock::ToolUse { name, .. } => {
+                            tools.push(name.clone().unwrap_or_else(|| "unknown".to_string()))
+                        }
+                        ContentBlock::ToolResult { .. } | ContentBlock::Other => {}
+                    }
+                }
+            }
+        }
+        let text = redact(texts.join("\n").trim());
+        if text.is_empty() && tools.is_empty() {
+            continue;
+        }
+
+        let same_message = message.id.is_some() && message.id == last_message_id;
+        last_message_id = message.id.clone();
+        match records.last_mut() {
+            Some(record) if same_message => {
+                if !text.is_empty() {
+                    if !record.text.is_empty() {
+                        record.text.push('\n');
+                    }
+                    record.text.push_str(&text);
+                }
+                record.tools.extend(tools);
+            }
+            _ => records.push(ExportRecord {
+                session_id: session_id.to_string(),
+                timestamp: envelope.timestamp,
+                role: role.to_string(),
+                text,
+                tools,
+            }),
+        }
+    }
+    records
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn exports_messages_without_tool_results_or_secrets() {
+        let lines = [
+            r#"{"type":"user","timestamp":"2025-01-01T10:00:00Z","message":{"role":"user","content":"deploy with API_KEY=abc123xyz"}}"#,
+            r#"{"type":"assistant","message":{"id":"m1","role":"assistant","content":[{"type":"text","text":"Deploying."}]}}"#,
+            r#"{"type":"assistant","message":{"id":"m1","role":"assistant","content":[{"type":"tool_use","name":"Bash","input":{"command":"make deploy"}}]}}"#,
+            r#"{"type":"user","message":{"role":"user","content":[{"type":"tool_result","content":"password=hunter2"}]}}"#,
+            r#"{"type":"summary","summary":"Deploy"}"#,
+        ];
+        let records = export_records("s1", &lines.join("\n"));
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[0].role, "user");
+        assert_eq!(records[0].text, "deploy with API_KEY=[REDACTED]");
+        assert_eq!(records[1].text, "Deploying.");
+        assert_eq!(records[1].tools, ["Bash"]);
+
+        let json = serde_json::to_string(&records[1]).unwrap();
+        assert_eq!(
+            json,
+            r#"{"session_id":"s1","role":"assistant","text":"Deploying.","tools":["Bash"]}"#
+        );
+    }
+}
@@ -0,0 +1,45 @@
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use anyhow::Result;
+
+use crate::model::session::{SessionEntry, SessionLink};
+
+/// Location of the tickets a project's sessions are linked to. Like the
+/// custom titles, kept outside Claude's files.
+pub fn links_path(assoc_home: &Path, encoded_project: &str) -> PathBuf {
+    assoc_home
+        .join("session-links")
+        .join(format!("{}.json", encoded_project))
+}
+
+/// Load the links by session id, returning an empty map if the file is
+/// missing or unreadable.
+pub fn load_links(path: &Path) -> HashMap<String, SessionLink> {
+    std::fs::read_to_string(path)
+        .ok()
+        .and_then(|data| serde_json::from_str(&data).ok())
+        .unwrap_or_default()
+}
+
+/// Set each session's link from `links`.
+pub fn apply_links(links: &HashMap<String, SessionLink>, sessions: &mut [SessionEntry]) {
+    for session in sessions {
+        session.link = links.get(&session.session_id).cloned();
+    }
+}
+
+/// Write the links to disk via a temporary file.
+pub fn save_links(path: &Path, links: &HashMap<String, SessionLink>) -> Result<()> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    let tmp = path.with_extension("json.tmp");
+    let json = serde_json::to_string_pretty(links)?;
+    std::fs::write(&tmp, json)?;
+    if let Err(e) = std::fs::rename(&tmp, path) {
+        let _ = std::fs::remove_file(&tmp);
+        return Err(e.into());
+    }
+    Ok(())
+}
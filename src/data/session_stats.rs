@@ -0,0 +1,115 @@
+use std::collections::HashSet;
+use std::path::Path;
+
+use anyhow::Result;
+
+use crate::data::subagents;
+use crate::data::team_resources::EDIT_TOOLS;
+use crate::model::session::SessionStats;
+use crate::model::transcript::{ContentBlock, MessageContent, TranscriptEnvelope};
+
+/// Compute the stats of session `session_id` from its transcript in
+/// `project_dir`, counting the subagent transcripts next to it.
+pub fn scan_session_stats(project_dir: &Path, session_id: &str) -> Result<SessionStats> {
+    let content = std::fs::read_to_string(project_dir.join(format!("{}.jsonl", session_id)))?;
+    let mut stats = stats_from_transcript(&content);
+    stats.subagents = subagents::find_subagents(project_dir, session_id).len();
+    Ok(stats)
+}
+
+/// Message, tool, file and token totals of a JSONL transcript.
+pub fn stats_from_transcript(content: &str) -> SessionStats {
+    let mut stats = SessionStats {
+        bytes: content.len() as u64,
+        ..SessionStats::default()
+    };
+    let mut seen_messages = HashSet::new();
+    for line in content.lines() {
+        let Ok(envelope) = serde_json::from_str::<TranscriptEnvelope>(line) else {
+            continue;
+        };
+        if let Some(ts) = envelope.timestamp {
+            stats.started = Some(stats.started.map_or(ts, |s| s.min(ts)));
+            stats.ended = Some(stats.ended.map_or(ts, |e| e.max(ts)));
+        }
+        let Some(message) = &envelope.message else {
+            continue;
+        };
+
+        // Assistant messages span one line per content block, each repeating
+        // the usage; count the message and its usage once per id
+        let first_sighting = message
+            .id
+            .as_ref()
+            .is_none_or(|id| seen_messages.insert(id.clone()));
+        let blocks: &[ContentBlock] = match &message.content {
+            MessageContent::Blocks(blocks) => blocks,
+            MessageContent::Text(_) => &[],
+        };
+        match message.role.as_deref().unwrap_or(envelope.kind.as_str()) {
+            "assistant" if first_sighting => stats.assistant_messages += 1,
+            "user"
+                if blocks
+                    .iter()
+                    .any(|b| matches!(b, ContentBlock::ToolResult { .. })) =>
+            {
+                stats.tool_results += 1
+            }
+            "user" => stats.user_messages += 1,
+            _ => {}
+        }
+        if let Some(usage) = message.usage.as_ref().filter(|_| first_sighting) {
+            stats.input_tokens += usage.input_tokens;
+            stats.output_tokens += usage.output_tokens;
+            stats.cache_creation_tokens += usage.cache_creation_input_tokens;
+        }
+
+        for block in blocks {
+            let ContentBlock::ToolUse { name, input } = block else {
+                continue;
+            };
+            let name = name.as_deref().unwrap_or("unknown");
+            *stats.tools.entry(name.to_string()).or_default() += 1;
+            let file = input
+                .as_ref()
+                .filter(|_| EDIT_TOOLS.contains(&name))
+                .and_then(|input| {
+                    input
+                        .get("file_path")
+                        .or_else(|| input.get("notebook_path"))
+                        .and_then(|v| v.as_str())
+                });
+            if let Some(file) = file {
+                stats.files_touched.insert(file.to_string());
+            }
+        }
+    }
+    stats
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn totals_messages_tools_and_tokens() {
+        let lines = [
+            r#"{"type":"user","timestamp":"2025-01-01T10:00:00Z","message":{"role":"user","content":"fix it"}}"#,
+            r#"{"type":"assistant","timestamp":"2025-01-01T10:00:05Z","message":{"id":"m1","role":"assistant","usage":{"input_tokens":100,"output_tokens":20,"cache_creation_input_tokens":5},"content":[{"type":"text","text":"ok"}]}}"#,
+            r#"{"type":"assistant","timestamp":"2025-01-01T10:00:06Z","message":{"id":"m1","role":"assistant","usage":{"input_tokens":100,"output_tokens":20,"cache_creation_input_tokens":5},"content":[{"type":"tool_use","name":"Edit","input":{"file_path":"src/a.rs"}}]}}"#,
+            r#"{"type":"user","timestamp":"2025-01-01T10:01:00Z","message":{"role":"user","content":[{"type":"tool_result","content":"done"}]}}"#,
+            r#"{"type":"assistant","timestamp":"2025-01-01T10:02:30Z","message":{"id":"m2","role":"assistant","usage":{"input_tokens":50,"output_tokens":10},"content":[{"type":"tool_use","name":"Read","input":{"file_path":"src/b.rs"}},{"type":"tool_use","name":"Edit","input":{"file_path":"src/a.rs"}}]}}"#,
+            "not json",
+        ];
+        let stats = stats_from_transcript(&lines.join("\n"));
+        assert_eq!(stats.user_messages, 1);
+        assert_eq!(stats.assistant_messages, 2);
+        assert_eq!(stats.tool_results, 1);
+        assert_eq!(stats.tools["Edit"], 2);
+        assert_eq!(stats.tools["Read"], 1);
+        assert_eq!(stats.tool_calls(), 3);
+        assert_eq!(stats.files_touched.iter().collect::<Vec<_>>(), ["src/a.rs"]);
+        assert_eq!(stats.tokens(), 185);
+        assert_eq!(stats.duration_ms(), Some(150_000));
+    }
+}
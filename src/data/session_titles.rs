@@ -0,0 +1,46 @@
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use anyhow::Result;
+
+use crate::model::session::SessionEntry;
+
+/// Location of the titles given to a project's sessions. Kept beside
+/// Claude's files rather than in them, so renaming never touches a
+/// transcript.
+pub fn titles_path(assoc_home: &Path, encoded_project: &str) -> PathBuf {
+    assoc_home
+        .join("session-titles")
+        .join(format!("{}.json", encoded_project))
+}
+
+/// Load custom titles by session id, returning an empty map if the file
+/// is missing or unreadable.
+pub fn load_titles(path: &Path) -> HashMap<String, String> {
+    std::fs::read_to_string(path)
+        .ok()
+        .and_then(|data| serde_json::from_str(&data).ok())
+        .unwrap_or_default()
+}
+
+/// Set each session's custom title from `titles`.
+pub fn apply_titles(titles: &HashMap<String, String>, sessions: &mut [SessionEntry]) {
+    for session in sessions {
+        session.custom_title = titles.get(&session.session_id).cloned();
+    }
+}
+
+/// Write the custom titles to disk via a temporary file.
+pub fn save_titles(path: &Path, titles: &HashMap<String, String>) -> Result<()> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    let tmp = path.with_extension("json.tmp");
+    let json = serde_json::to_string_pretty(titles)?;
+    std::fs::write(&tmp, json)?;
+    if let Err(e) = std::fs::rename(&tmp, path) {
+        let _ = std::fs::remove_file(&tmp);
+        return Err(e.into());
+    }
+    Ok(())
+}
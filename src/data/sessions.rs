@@ -1,15 +1,31 @@
+use std::collections::HashMap;
 use std::io::{BufRead, BufReader};
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 use anyhow::Result;
 use chrono::{DateTime, Utc};
+use rusqlite::{params, Connection, OptionalExtension};
 use serde::Deserialize;
 
+use super::path_encoding::encode_project_path;
 use crate::model::session::{SessionEntry, SessionIndex};
 
+/// Location of the scan index for a Claude project directory.
+pub fn index_path(assoc_home: &Path, project_dir: &Path) -> PathBuf {
+    assoc_home
+        .join("session-index")
+        .join(format!("{}.sqlite", encode_project_path(project_dir)))
+}
+
 /// Load the sessions index file for a project.
-/// Falls back to scanning .jsonl files if the index doesn't exist.
-pub fn load_sessions(project_dir: &Path) -> Result<Vec<SessionEntry>> {
+/// Falls back to the scan index in `scan_index` if the index file doesn't
+/// exist. With `sync` the scan index is first checked against the
+/// transcripts on disk; otherwise it is trusted as the watcher left it.
+pub fn load_sessions(
+    project_dir: &Path,
+    scan_index: &Path,
+    sync: bool,
+) -> Result<Vec<SessionEntry>> {
     let index_path = project_dir.join("sessions-index.json");
     if index_path.exists() {
         let data = std::fs::read_to_string(&index_path)?;
@@ -23,8 +39,184 @@ pub fn load_sessions(project_dir: &Path) -> Result<Vec<SessionEntry>> {
         return Ok(entries);
     }
 
-    // Fallback: scan for .jsonl transcript files
-    scan_jsonl_files(project_dir)
+    // Fallback: sessions built from the first lines of .jsonl transcripts
+    let (index, opened) = ScanIndex::open(scan_index)?;
+    if sync || !opened {
+        index.sync(project_dir)?;
+    }
+    index.sessions()
+}
+
+/// Bring the scan index up to date with one transcript the watcher saw
+/// change. Returns whether the session list changed, which is never the
+/// case while the project has a `sessions-index.json`.
+pub fn update_index(project_dir: &Path, scan_index: &Path, transcript: &Path) -> Result<bool> {
+    let has_index_file = project_dir.join("sessions-index.json").exists();
+    if has_index_file || transcript.parent() != Some(project_dir) {
+        return Ok(false);
+    }
+    let (index, _) = ScanIndex::open(scan_index)?;
+    index.update(transcript)
+}
+
+/// Session metadata from earlier scans, one row per transcript file. A row
+/// stays valid while its transcript keeps the same size and mtime.
+struct ScanIndex {
+    conn: Connection,
+}
+
+impl ScanIndex {
+    /// Open the index at `path`, creating it if needed. An index that can't
+    /// be written is kept in memory instead, which `false` reports so the
+    /// caller fills it.
+    fn open(path: &Path) -> Result<(Self, bool)> {
+        let on_disk = path
+            .parent()
+            .map_or(Ok(()), std::fs::create_dir_all)
+            .ok()
+            .and_then(|_| Connection::open(path).ok());
+        let opened = on_disk.is_some();
+        let conn = match on_disk {
+            Some(conn) => conn,
+            None => Connection::open_in_memory()?,
+        };
+        conn.busy_timeout(std::time::Duration::from_secs(2))?;
+        conn.execute_batch(
+            "CREATE TABLE IF NOT EXISTS transcripts (
+                name TEXT PRIMARY KEY,
+                size INTEGER NOT NULL,
+                mtime INTEGER,
+                sort_key INTEGER,
+                entry TEXT NOT NULL
+            )",
+        )?;
+        Ok((Self { conn }, opened))
+    }
+
+    /// Indexed sessions, most recent first.
+    fn sessions(&self) -> Result<Vec<SessionEntry>> {
+        let mut stmt = self
+            .conn
+            .prepare("SELECT entry FROM transcripts ORDER BY sort_key DESC")?;
+        let rows = stmt.query_map([], |row| row.get::<_, String>(0))?;
+        let mut entries = Vec::new();
+        for row in rows {
+            if let Ok(entry) = serde_json::from_str(&row?) {
+                entries.push(entry);
+            }
+        }
+        Ok(entries)
+    }
+
+    /// Re-read the transcripts in `project_dir` whose size or mtime changed
+    /// and drop those that are gone.
+    fn sync(&self, project_dir: &Path) -> Result<()> {
+        let mut stale: HashMap<String, (u64, Option<i64>)> = HashMap::new();
+        {
+            let mut stmt = self
+                .conn
+                .prepare("SELECT name, size, mtime FROM transcripts")?;
+            let rows = stmt.query_map([], |row| Ok((row.get(0)?, (row.get(1)?, row.get(2)?))))?;
+            for row in rows {
+                let (name, key) = row?;
+                stale.insert(name, key);
+            }
+        }
+
+        let tx = self.conn.unchecked_transaction()?;
+        if project_dir.exists() {
+            for entry in std::fs::read_dir(project_dir)?.flatten() {
+                let path = entry.path();
+                let Some(name) = transcript_name(&path) else {
+                    continue;
+                };
+                let Ok(metadata) = entry.metadata() else {
+                    continue;
+                };
+                let key = file_key(&metadata);
+                if stale.remove(&name) != Some(key) {
+                    self.reindex(&path, &name, key)?;
+                }
+            }
+        }
+        for name in stale.keys() {
+            self.conn
+                .execute("DELETE FROM transcripts WHERE name = ?1", [name])?;
+        }
+        tx.commit()?;
+        Ok(())
+    }
+
+    /// Re-read `transcript` if it changed, or drop it if it is gone.
+    fn update(&self, transcript: &Path) -> Result<bool> {
+        let Some(name) = transcript_name(transcript) else {
+            return Ok(false);
+        };
+        let Ok(metadata) = std::fs::metadata(transcript) else {
+            let removed = self
+                .conn
+                .execute("DELETE FROM transcripts WHERE name = ?1", [&name])?;
+            return Ok(removed > 0);
+        };
+        let key = file_key(&metadata);
+        let indexed = self
+            .conn
+            .query_row(
+                "SELECT size, mtime FROM transcripts WHERE name = ?1",
+                [&name],
+                |row| Ok((row.get(0)?, row.get(1)?)),
+            )
+            .optional()?;
+        if indexed == Some(key) {
+            return Ok(false);
+        }
+        self.reindex(transcript, &name, key)?;
+        Ok(true)
+    }
+
+    fn reindex(&self, path: &Path, name: &str, (size, mtime): (u64, Option<i64>)) -> Result<()> {
+        let session_id = name.trim_end_matches(".jsonl");
+        let modified = mtime.map(DateTime::from_timestamp_nanos);
+        let Some(entry) = build_entry_from_jsonl(path, session_id, modified) else {
+            self.conn
+                .execute("DELETE FROM transcripts WHERE name = ?1", [name])?;
+            return Ok(());
+        };
+        self.conn.execute(
+            "INSERT OR REPLACE INTO transcripts (name, size, mtime, sort_key, entry)
+             VALUES (?1, ?2, ?3, ?4, ?5)",
+            params![
+                name,
+                size,
+                mtime,
+                entry.modified.map(|m| m.timestamp_millis()),
+                serde_json::to_string(&entry)?
+            ],
+        )?;
+        Ok(())
+    }
+}
+
+/// File name of a `<session-id>.jsonl` transcript.
+fn transcript_name(path: &Path) -> Option<String> {
+    if path.extension().and_then(|e| e.to_str()) != Some("jsonl") {
+        return None;
+    }
+    let stem = path.file_stem()?.to_string_lossy();
+    if stem.is_empty() {
+        return None;
+    }
+    Some(path.file_name()?.to_string_lossy().to_string())
+}
+
+/// Size and mtime (nanoseconds since the epoch) that a row is checked against.
+fn file_key(metadata: &std::fs::Metadata) -> (u64, Option<i64>) {
+    let mtime = metadata
+        .modified()
+        .ok()
+        .and_then(system_time_utc)
+        .and_then(|t| t.timestamp_nanos_opt());
+    (metadata.len(), mtime)
 }
 
 /// Envelope fields present on most JSONL lines.
@@ -47,43 +239,13 @@ struct JsonlEnvelope {
     message: Option<serde_json::Value>,
 }
 
-/// Scan a project directory for .jsonl files and build session entries
-/// from the envelope fields in early lines.
-fn scan_jsonl_files(project_dir: &Path) -> Result<Vec<SessionEntry>> {
-    if !project_dir.exists() {
-        return Ok(vec![]);
-    }
-
-    let mut entries = Vec::new();
-
-    let dir_entries = std::fs::read_dir(project_dir)?;
-    for entry in dir_entries.flatten() {
-        let path = entry.path();
-        if path.extension().and_then(|e| e.to_str()) != Some("jsonl") {
-            continue;
-        }
-
-        let session_id = path
-            .file_stem()
-            .map(|s| s.to_string_lossy().to_string())
-            .unwrap_or_default();
-
-        if session_id.is_empty() {
-            continue;
-        }
-
-        if let Some(entry) = build_entry_from_jsonl(&path, &session_id) {
-            entries.push(entry);
-        }
-    }
-
-    // Sort by modified time (timestamp), most recent first
-    entries.sort_by(|a, b| b.modified.cmp(&a.modified));
-    Ok(entries)
-}
-
 /// Read the first few lines of a .jsonl file and extract session metadata.
-fn build_entry_from_jsonl(path: &Path, session_id: &str) -> Option<SessionEntry> {
+/// `file_modified` stands in for timestamps the lines don't have.
+fn build_entry_from_jsonl(
+    path: &Path,
+    session_id: &str,
+    file_modified: Option<DateTime<Utc>>,
+) -> Option<SessionEntry> {
     let file = std::fs::File::open(path).ok()?;
     let reader = BufReader::new(file);
 
@@ -148,15 +310,6 @@ fn build_entry_from_jsonl(path: &Path, session_id: &str) -> Option<SessionEntry>
         }
     }
 
-    // Also get file modification time as a fallback for "modified"
-    let file_modified = std::fs::metadata(path)
-        .and_then(|m| m.modified())
-        .ok()
-        .and_then(|t| {
-            let duration = t.duration_since(std::time::UNIX_EPOCH).ok()?;
-            DateTime::from_timestamp(duration.as_secs() as i64, duration.subsec_nanos())
-        });
-
     let modified = last_timestamp.or(file_modified);
     let created = first_timestamp.or(file_modified);
 
@@ -174,5 +327,58 @@ fn build_entry_from_jsonl(path: &Path, session_id: &str) -> Option<SessionEntry>
         git_branch,
         project_path: cwd,
         is_sidechain: None,
+        home: 0,
+        custom_title: None,
+        link: None,
     })
 }
+
+fn system_time_utc(time: std::time::SystemTime) -> Option<DateTime<Utc>> {
+    let duration = time.duration_since(std::time::UNIX_EPOCH).ok()?;
+    DateTime::from_timestamp(duration.as_secs() as i64, duration.subsec_nanos())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn index_follows_the_watcher_between_syncs() {
+        let dir = std::env::temp_dir().join(format!("assoc-sessions-{}", std::process::id()));
+        let project_dir = dir.join("project");
+        let index = dir.join("index.sqlite");
+        std::fs::create_dir_all(&project_dir).unwrap();
+        let transcript = project_dir.join("abc.jsonl");
+        let line = r#"{"type":"user","message":{"content":"fix the build"},"cwd":"/w"}"#;
+        std::fs::write(&transcript, format!("{}\n", line)).unwrap();
+
+        let first = load_sessions(&project_dir, &index, true).unwrap();
+        assert_eq!(first[0].first_prompt.as_deref(), Some("fix the build"));
+
+        // Without a sync only the index is read, so a new transcript shows
+        // up once the watcher reports it
+        let other = project_dir.join("def.jsonl");
+        std::fs::write(&other, format!("{}\n", line)).unwrap();
+        assert_eq!(load_sessions(&project_dir, &index, false).unwrap().len(), 1);
+        assert!(update_index(&project_dir, &index, &other).unwrap());
+        assert!(!update_index(&project_dir, &index, &other).unwrap());
+        assert_eq!(load_sessions(&project_dir, &index, false).unwrap().len(), 2);
+
+        std::fs::write(&transcript, format!("{}\n{}\n", line, line)).unwrap();
+        assert!(update_index(&project_dir, &index, &transcript).unwrap());
+        let updated = load_sessions(&project_dir, &index, false).unwrap();
+        let abc = updated.iter().find(|s| s.session_id == "abc").unwrap();
+        assert_eq!(abc.message_count, Some(2));
+
+        std::fs::remove_file(&transcript).unwrap();
+        assert!(update_index(&project_dir, &index, &transcript).unwrap());
+        // A sync drops transcripts deleted while nothing was watching
+        std::fs::remove_file(&other).unwrap();
+        assert_eq!(load_sessions(&project_dir, &index, false).unwrap().len(), 1);
+        assert!(load_sessions(&project_dir, &index, true)
+            .unwrap()
+            .is_empty());
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}
@@ -0,0 +1,237 @@
+//! Read-only transcript sharing over TCP.
+//!
+//! `assoc --share <ADDR>` serves the transcript open on the Sessions tab as
+//! JSON lines ([`ShareMessage`]); `assoc mirror <ADDR>` connects and shows
+//! it. Nothing is read back from mirrors, and there is no authentication
+//! or encryption: anyone who can reach the port can watch.
+
+use std::io::{BufRead, BufReader, Write};
+use std::net::{SocketAddr, TcpListener, TcpStream};
+use std::sync::mpsc::{Sender, SyncSender};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use anyhow::Result;
+
+use crate::model::redact::redact;
+use crate::model::share::{ShareMessage, MAX_MIRROR_ITEMS};
+use crate::model::transcript::TranscriptItem;
+
+/// A mirror that stops reading for this long is dropped.
+const WRITE_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// Messages waiting for a mirror's writer thread. A mirror that falls this
+/// far behind is dropped, so a slow one never holds up the dashboard.
+const CLIENT_QUEUE: usize = 64;
+
+/// Wait between attempts to reach the host.
+const RECONNECT_DELAY: Duration = Duration::from_secs(2);
+
+/// Serves the host's current session to connected mirrors.
+pub struct ShareServer {
+    addr: SocketAddr,
+    project: String,
+    state: Arc<Mutex<ShareState>>,
+}
+
+#[derive(Default)]
+struct ShareState {
+    /// Sent first to a mirror that connects; items are kept up to date.
+    current: Option<ShareMessage>,
+    /// Queues of the mirrors' writer threads.
+    clients: Vec<SyncSender<Arc<Vec<u8>>>>,
+}
+
+impl ShareServer {
+    /// Listen on `addr` (e.g. `0.0.0.0:7878`) and accept mirrors in the
+    /// background.
+    pub fn start(addr: &str, project: String) -> Result<Self> {
+        let listener = TcpListener::bind(addr)?;
+        let addr = listener.local_addr()?;
+        let state = Arc::new(Mutex::new(ShareState::default()));
+        let accept_state = Arc::clone(&state);
+        std::thread::spawn(move || {
+            for stream in listener.incoming() {
+                let Ok(stream) = stream else {
+                    continue;
+                };
+                let client = spawn_writer(stream);
+                let Ok(mut state) = accept_state.lock() else {
+                    return;
+                };
+                let sent = match state.current.as_ref().and_then(encode) {
+                    Some(line) => client.try_send(line).is_ok(),
+                    None => true,
+                };
+                if sent {
+                    state.clients.push(client);
+                }
+            }
+        });
+        Ok(Self {
+            addr,
+            project,
+            state,
+        })
+    }
+
+    pub fn addr(&self) -> SocketAddr {
+        self.addr
+    }
+
+    /// Mirrors connected right now.
+    pub fn clients(&self) -> usize {
+        self.state.lock().map(|s| s.clients.len()).unwrap_or(0)
+    }
+
+    /// The host opened `session_id`: mirrors start over with `items`.
+    pub fn publish_session(&self, session_id: &str, title: &str, items: &[TranscriptItem]) {
+        let start = items.len().saturating_sub(MAX_MIRROR_ITEMS);
+        let message = ShareMessage::Session {
+            project: self.project.clone(),
+            session_id: session_id.to_string(),
+            title: redact(title),
+            items: redacted(&items[start..]),
+        };
+        let line = encode(&message);
+        let Ok(mut state) = self.state.lock() else {
+            return;
+        };
+        broadcast(&mut state.clients, line);
+        state.current = Some(message);
+    }
+
+    /// Items appended to the session last published.
+    pub fn publish_items(&self, items: &[TranscriptItem]) {
+        if items.is_empty() {
+            return;
+        }
+        let message = ShareMessage::Items {
+            items: redacted(items),
+        };
+        let line = encode(&message);
+        let Ok(mut state) = self.state.lock() else {
+            return;
+        };
+        broadcast(&mut state.clients, line);
+        if let (Some(ShareMessage::Session { items: current, .. }), ShareMessage::Items { items }) =
+            (&mut state.current, message)
+        {
+            current.extend(items);
+            let excess = current.len().saturating_sub(MAX_MIRROR_ITEMS);
+            current.drain(..excess);
+        }
+    }
+}
+
+/// Items with secrets in their text replaced, as in session exports.
+fn redacted(items: &[TranscriptItem]) -> Vec<TranscriptItem> {
+    items
+        .iter()
+        .map(|item| TranscriptItem {
+            text: redact(&item.text),
+            ..item.clone()
+        })
+        .collect()
+}
+
+/// Queue `line` for every client, dropping those that are gone or too far
+/// behind. Nothing is written here, so this never waits on a mirror.
+fn broadcast(clients: &mut Vec<SyncSender<Arc<Vec<u8>>>>, line: Option<Arc<Vec<u8>>>) {
+    let Some(line) = line else {
+        return;
+    };
+    clients.retain(|client| client.try_send(Arc::clone(&line)).is_ok());
+}
+
+/// `message` as a JSON line, shared between the clients' queues.
+fn encode(message: &ShareMessage) -> Option<Arc<Vec<u8>>> {
+    let mut line = serde_json::to_vec(message).ok()?;
+    line.push(b'\n');
+    Some(Arc::new(line))
+}
+
+/// Start a thread writing queued lines to `stream`. It stops when a write
+/// fails or the queue's sender is dropped.
+fn spawn_writer(mut stream: TcpStream) -> SyncSender<Arc<Vec<u8>>> {
+    let _ = stream.set_write_timeout(Some(WRITE_TIMEOUT));
+    let _ = stream.set_nodelay(true);
+    let (tx, rx) = std::sync::mpsc::sync_channel::<Arc<Vec<u8>>>(CLIENT_QUEUE);
+    std::thread::spawn(move || {
+        for line in rx {
+            if stream.write_all(&line).is_err() {
+                break;
+            }
+        }
+        let _ = stream.shutdown(std::net::Shutdown::Both);
+    });
+    tx
+}
+
+/// What the mirror's reader thread reports.
+pub enum MirrorEvent {
+    Connected,
+    Message(ShareMessage),
+    Disconnected(String),
+}
+
+/// Connect to the host at `addr` and forward its messages to `tx`,
+/// reconnecting whenever the connection drops. Stops once `tx` is closed.
+pub fn spawn_mirror_reader(addr: String, tx: Sender<MirrorEvent>) {
+    std::thread::spawn(move || loop {
+        let reason = match TcpStream::connect(&addr) {
+            Ok(stream) => {
+                if tx.send(MirrorEvent::Connected).is_err() {
+                    return;
+                }
+                for line in BufReader::new(stream).lines() {
+                    let Ok(line) = line else {
+                        break;
+                    };
+                    if let Ok(message) = serde_json::from_str(&line) {
+                        if tx.send(MirrorEvent::Message(message)).is_err() {
+                            return;
+                        }
+                    }
+                }
+                "the host closed the connection".to_string()
+            }
+            Err(e) => e.to_string(),
+        };
+        if tx.send(MirrorEvent::Disconnected(reason)).is_err() {
+            return;
+        }
+        std::thread::sleep(RECONNECT_DELAY);
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::transcript::TranscriptItemKind;
+
+    #[test]
+    fn a_mirror_that_stops_reading_is_dropped_without_blocking() {
+        let server = ShareServer::start("127.0.0.1:0", "demo".to_string()).unwrap();
+        let _stalled = TcpStream::connect(server.addr()).unwrap();
+        while server.clients() == 0 {
+            std::thread::sleep(Duration::from_millis(5));
+        }
+
+        let item = TranscriptItem {
+            timestamp: None,
+            kind: TranscriptItemKind::Assistant,
+            text: "x".repeat(64 * 1024),
+            usage: None,
+            block: 0,
+            line_offset: None,
+        };
+        // Enough to fill the socket buffers and then the queue
+        for _ in 0..CLIENT_QUEUE * 4 {
+            let started = std::time::Instant::now();
+            server.publish_items(std::slice::from_ref(&item));
+            assert!(started.elapsed() < WRITE_TIMEOUT / 2);
+        }
+        assert_eq!(server.clients(), 0);
+    }
+}
@@ -0,0 +1,36 @@
+use std::path::{Path, PathBuf};
+
+use anyhow::Result;
+
+use crate::model::snapshot::SnapshotStore;
+
+/// Location of the snapshot file for a project.
+pub fn snapshot_path(assoc_home: &Path, encoded_project: &str) -> PathBuf {
+    assoc_home
+        .join("snapshots")
+        .join(format!("{}.json", encoded_project))
+}
+
+/// Load a project's snapshot history, returning an empty store if it is
+/// missing or unreadable.
+pub fn load_snapshots(path: &Path) -> SnapshotStore {
+    std::fs::read_to_string(path)
+        .ok()
+        .and_then(|data| serde_json::from_str(&data).ok())
+        .unwrap_or_default()
+}
+
+/// Write a project's snapshot history to disk via a temporary file.
+pub fn save_snapshots(path: &Path, store: &SnapshotStore) -> Result<()> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    let tmp = path.with_extension("json.tmp");
+    let json = serde_json::to_string_pretty(store)?;
+    std::fs::write(&tmp, json)?;
+    if let Err(e) = std::fs::rename(&tmp, path) {
+        let _ = std::fs::remove_file(&tmp);
+        return Err(e.into());
+    }
+    Ok(())
+}
@@ -1,8 +1,9 @@
 use std::path::Path;
 
-use anyhow::Result;
+use anyhow::{bail, Result};
+use serde_json::{json, Value};
 
-use crate::model::task::{Task, TaskStatus};
+use crate::model::task::{Task, TaskDraft, TaskStatus};
 
 /// Load all tasks for a given team name.
 pub fn load_tasks(claude_home: &Path, team_name: &str) -> Result<Vec<Task>> {
@@ -60,3 +61,162 @@ pub fn load_tasks(claude_home: &Path, team_name: &str) -> Result<Vec<Task>> {
 
     Ok(tasks)
 }
+
+/// Create a task from `draft` (when `id` is None) or update task `id` in
+/// `tasks/<team_name>/`, keeping any fields the draft doesn't cover. The
+/// `blocks` lists of the tasks named in `blocked_by` are kept in step.
+/// Returns the task's id.
+pub fn save_task(
+    claude_home: &Path,
+    team_name: &str,
+    id: Option<&str>,
+    draft: &TaskDraft,
+) -> Result<String> {
+    let tasks_dir = claude_home.join("tasks").join(team_name);
+    std::fs::create_dir_all(&tasks_dir)?;
+
+    let (id, mut task) = match id {
+        Some(id) => {
+            let data = std::fs::read_to_string(tasks_dir.join(format!("{}.json", id)))?;
+            (id.to_string(), serde_json::from_str::<Value>(&data)?)
+        }
+        None => {
+            let id = next_task_id(&tasks_dir).to_string();
+            let task = json!({
+                "id": id,
+                "status": "pending",
+                "blocks": [],
+                "blockedBy": [],
+            });
+            (id, task)
+        }
+    };
+    let Some(fields) = task.as_object_mut() else {
+        bail!("task {} is not a JSON object", id);
+    };
+
+    let previous: Vec<String> = fields
+        .get("blockedBy")
+        .and_then(|v| serde_json::from_value(v.clone()).ok())
+        .unwrap_or_default();
+    fields.insert("subject".to_string(), json!(draft.subject));
+    fields.insert("description".to_string(), json!(draft.description));
+    match &draft.owner {
+        Some(owner) => fields.insert("owner".to_string(), json!(owner)),
+        None => fields.remove("owner"),
+    };
+    fields.insert("blockedBy".to_string(), json!(draft.blocked_by));
+    write_task(&tasks_dir, &id, &task)?;
+
+    // Mirror the change on the other side of each dependency
+    for blocker in draft.blocked_by.iter().filter(|b| !previous.contains(b)) {
+        update_blocks(&tasks_dir, blocker, &id, true)?;
+    }
+    for blocker in previous.iter().filter(|b| !draft.blocked_by.contains(b)) {
+        update_blocks(&tasks_dir, blocker, &id, false)?;
+    }
+    Ok(id)
+}
+
+/// One more than the highest numeric task id in `tasks_dir` (including
+/// deleted tasks and Claude Code's `.highwatermark`), so ids are never reused.
+fn next_task_id(tasks_dir: &Path) -> u64 {
+    let from_files = std::fs::read_dir(tasks_dir)
+        .into_iter()
+        .flatten()
+        .flatten()
+        .filter_map(|e| {
+            let path = e.path();
+            if path.extension().and_then(|x| x.to_str()) != Some("json") {
+                return None;
+            }
+            path.file_stem()?.to_str()?.parse::<u64>().ok()
+        })
+        .max()
+        .unwrap_or(0);
+    let watermark = std::fs::read_to_string(tasks_dir.join(".highwatermark"))
+        .ok()
+        .and_then(|s| s.trim().parse::<u64>().ok())
+        .unwrap_or(0);
+    from_files.max(watermark) + 1
+}
+
+/// Add `blocked` to (or remove it from) the `blocks` list of task `blocker`.
+/// Missing blocker files are skipped.
+fn update_blocks(tasks_dir: &Path, blocker: &str, blocked: &str, add: bool) -> Result<()> {
+    let path = tasks_dir.join(format!("{}.json", blocker));
+    let Ok(data) = std::fs::read_to_string(&path) else {
+        return Ok(());
+    };
+    let mut task: Value = serde_json::from_str(&data)?;
+    let Some(fields) = task.as_object_mut() else {
+        return Ok(());
+    };
+    let mut blocks: Vec<String> = fields
+        .get("blocks")
+        .and_then(|v| serde_json::from_value(v.clone()).ok())
+        .unwrap_or_default();
+    blocks.retain(|b| b != blocked);
+    if add {
+        blocks.push(blocked.to_string());
+    }
+    fields.insert("blocks".to_string(), json!(blocks));
+    write_task(tasks_dir, blocker, &task)
+}
+
+fn write_task(tasks_dir: &Path, id: &str, task: &Value) -> Result<()> {
+    let path = tasks_dir.join(format!("{}.json", id));
+    let tmp = path.with_extension("json.tmp");
+    std::fs::write(&tmp, serde_json::to_string_pretty(task)?)?;
+    if let Err(e) = std::fs::rename(&tmp, &path) {
+        let _ = std::fs::remove_file(&tmp);
+        return Err(e.into());
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn creates_and_edits_tasks() {
+        let home = std::env::temp_dir().join(format!("assoc-tasks-{}", std::process::id()));
+        let dir = home.join("tasks").join("alpha");
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(
+            dir.join("3.json"),
+            r#"{"id":"3","subject":"Old","status":"in_progress","activeForm":"Doing it"}"#,
+        )
+        .unwrap();
+
+        let draft = TaskDraft {
+            subject: "Write docs".to_string(),
+            description: "For the new API".to_string(),
+            owner: Some("writer".to_string()),
+            blocked_by: vec!["3".to_string()],
+        };
+        let id = save_task(&home, "alpha", None, &draft).unwrap();
+        assert_eq!(id, "4");
+
+        let tasks = load_tasks(&home, "alpha").unwrap();
+        assert_eq!(tasks[0].blocks, ["4"]);
+        assert_eq!(tasks[1].owner.as_deref(), Some("writer"));
+        assert_eq!(tasks[1].blocked_by, ["3"]);
+
+        let edit = TaskDraft {
+            subject: "Renamed".to_string(),
+            ..TaskDraft::default()
+        };
+        save_task(&home, "alpha", Some("3"), &edit).unwrap();
+        save_task(&home, "alpha", Some("4"), &edit).unwrap();
+        let tasks = load_tasks(&home, "alpha").unwrap();
+        // Untouched fields survive an edit, and dropped dependencies unlink
+        assert_eq!(tasks[0].status, TaskStatus::InProgress);
+        assert_eq!(tasks[0].active_form.as_deref(), Some("Doing it"));
+        assert!(tasks[0].blocks.is_empty());
+        assert_eq!(tasks[1].owner, None);
+
+        let _ = std::fs::remove_dir_all(&home);
+    }
+}
@@ -0,0 +1,390 @@
+use std::collections::{HashMap, HashSet};
+use std::io::{BufRead, BufReader};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::data::{path_encoding, tasks};
+use crate::model::task::TaskStatus;
+use crate::model::team::{Team, TeamMember, TeamResources};
+use crate::model::transcript::{ContentBlock, MessageContent, TranscriptEnvelope};
+
+/// Tools whose path input counts as a touched file.
+pub const EDIT_TOOLS: &[&str] = &["Write", "Edit", "MultiEdit", "NotebookEdit"];
+
+/// Aggregate token usage, tool calls and edited files over the transcripts of
+/// `team`'s sessions in `claude_home`, plus its task completion counts.
+///
+/// Transcripts are looked up in the project directories of the members' cwds
+/// (falling back to `encoded_project`). One belongs to the team when it is
+/// the lead session, a subagent of the lead session, or its envelopes carry
+/// the team's `teamName`. Envelopes with a member's `agentName` are credited
+/// to that member; the rest go to the lead.
+pub fn scan_team_resources(
+    claude_home: &Path,
+    encoded_project: &str,
+    team: &Team,
+) -> TeamResources {
+    let mut resources = TeamResources::default();
+    let lead = team
+        .config
+        .members
+        .iter()
+        .find(|m| m.is_lead(&team.config))
+        .map(|m| m.name.as_str());
+    let member_names: HashSet<&str> = team
+        .config
+        .members
+        .iter()
+        .map(|m| m.name.as_str())
+        .collect();
+    let team_names: Vec<&str> = [Some(team.dir_name.as_str()), team.config.name.as_deref()]
+        .into_iter()
+        .flatten()
+        .collect();
+    // Transcripts untouched since the team was created can't belong to it
+    let since = team
+        .config
+        .created_at
+        .and_then(|ms| UNIX_EPOCH.checked_add(Duration::from_millis(ms)));
+
+    let mut member_files: HashMap<String, HashSet<String>> = HashMap::new();
+    for (session_id, path) in candidate_transcripts(claude_home, encoded_project, team, since) {
+        let is_lead_session = team.config.lead_session_id.as_deref() == Some(session_id.as_str());
+        let Ok(content) = std::fs::read_to_string(&path) else {
+            continue;
+        };
+        let envelopes: Vec<TranscriptEnvelope> = content
+            .lines()
+            .filter_map(|line| serde_json::from_str(line).ok())
+            .collect();
+        let in_team = is_lead_session
+            || envelopes.iter().any(|e| {
+                e.extra
+                    .get("teamName")
+                    .and_then(|v| v.as_str())
+                    .is_some_and(|name| team_names.contains(&name))
+            });
+        if !in_team {
+            continue;
+        }
+
+        resources.transcripts += 1;
+        let mut seen_messages = HashSet::new();
+        for envelope in &envelopes {
+            let Some(message) = &envelope.message else {
+                continue;
+            };
+            let member = envelope
+                .extra
+                .get("agentName")
+                .and_then(|v| v.as_str())
+                .filter(|name| member_names.contains(name))
+                .or(lead);
+
+            // Claude Code writes one line per content block, repeating the
+            // message's usage on each; count it once per message id
+            let first_sighting = message
+                .id
+                .as_ref()
+                .is_none_or(|id| seen_messages.insert(id.clone()));
+            if let Some(usage) = message.usage.as_ref().filter(|_| first_sighting) {
+                let tokens =
+                    usage.input_tokens + usage.cache_creation_input_tokens + usage.output_tokens;
+                resources.tokens += tokens;
+                if let Some(name) = member {
+                    resources
+                        .members
+                        .entry(name.to_string())
+                        .or_default()
+                        .tokens += tokens;
+                }
+            }
+
+            let MessageContent::Blocks(blocks) = &message.content else {
+                continue;
+            };
+            for block in blocks {
+                let ContentBlock::ToolUse { name, input } = block else {
+                    continue;
+                };
+                resources.tool_calls += 1;
+                if let Some(name) = member {
+                    resources
+                        .members
+                        .entry(name.to_string())
+                        .or_default()
+                        .tool_calls += 1;
+                }
+                let file = name
+                    .as_deref()
+                    .filter(|tool| EDIT_TOOLS.contains(tool))
+                    .and(input.as_ref())
+                    .and_then(|input| {
+                        input
+                            .get("file_path")
+                            .or_else(|| input.get("notebook_path"))
+                            .and_then(|v| v.as_str())
+                    });
+                if let Some(file) = file {
+                    resources.files_touched.insert(file.to_string());
+                    if let Some(name) = member {
+                        member_files
+                            .entry(name.to_string())
+                            .or_default()
+                            .insert(file.to_string());
+                    }
+                }
+            }
+        }
+    }
+    for (name, files) in member_files {
+        resources.members.entry(name).or_default().files_touched = files.len();
+    }
+
+    if let Ok(tasks) = tasks::load_tasks(claude_home, &team.dir_name) {
+        resources.tasks_total = tasks
+            .iter()
+            .filter(|t| t.status != TaskStatus::Deleted)
+            .count();
+        resources.tasks_completed = tasks
+            .iter()
+            .filter(|t| t.status == TaskStatus::Completed)
+            .count();
+    }
+    resources
+}
+
+/// Lines read from the top of a transcript looking for its agent's name.
+const AGENT_NAME_LINES: usize = 50;
+
+/// The transcript `member` of `team` writes to: the lead session for the
+/// lead, otherwise the newest of the team's transcripts that is named after
+/// the member's agent id (`agent-<id>.jsonl`) or whose envelopes carry its
+/// `agentName`.
+pub fn member_transcript(
+    claude_home: &Path,
+    encoded_project: &str,
+    team: &Team,
+    member: &TeamMember,
+) -> Option<PathBuf> {
+    let candidates = candidate_transcripts(claude_home, encoded_project, team, None);
+    if member.is_lead(&team.config) {
+        if let Some(lead_session) = team.config.lead_session_id.as_deref() {
+            let lead = format!("{}.jsonl", lead_session);
+            let found = candidates
+                .iter()
+                .find(|(_, path)| path.file_name().is_some_and(|n| n == lead.as_str()));
+            if let Some((_, path)) = found {
+                return Some(path.clone());
+            }
+        }
+    }
+
+    // Teammate ids look like `name@team`; subagent files may use either
+    let file_names: Vec<String> = member
+        .agent_id
+        .iter()
+        .flat_map(|id| [id.as_str(), id.split('@').next().unwrap_or(id)])
+        .map(|id| format!("agent-{}.jsonl", id))
+        .collect();
+    let team_names = [Some(team.dir_name.as_str()), team.config.name.as_deref()];
+    let modified = |path: &Path| {
+        std::fs::metadata(path)
+            .and_then(|m| m.modified())
+            .unwrap_or(UNIX_EPOCH)
+    };
+    candidates
+        .into_iter()
+        .map(|(_, path)| path)
+        .filter(|path| {
+            let named = path
+                .file_name()
+                .is_some_and(|n| file_names.iter().any(|f| n == f.as_str()));
+            named || written_by(path, &member.name, &team_names)
+        })
+        .max_by_key(|path| modified(path))
+}
+
+/// Whether the first lines of the transcript at `path` carry `agent_name`,
+/// and a `teamName` from `team_names` if they name a team at all.
+fn written_by(path: &Path, agent_name: &str, team_names: &[Option<&str>]) -> bool {
+    let Ok(file) = std::fs::File::open(path) else {
+        return false;
+    };
+    BufReader::new(file)
+        .lines()
+        .take(AGENT_NAME_LINES)
+        .map_while(Result::ok)
+        .filter_map(|line| serde_json::from_str::<serde_json::Value>(&line).ok())
+        .any(|envelope| {
+            let field = |key: &str| envelope.get(key).and_then(|v| v.as_str());
+            field("agentName") == Some(agent_name)
+                && field("teamName").is_none_or(|team| team_names.contains(&Some(team)))
+        })
+}
+
+/// Session transcripts and their subagent transcripts in the project
+/// directories `team` works in, as `(session id, path)` pairs. Files last
+/// modified before `since` are skipped.
+fn candidate_transcripts(
+    claude_home: &Path,
+    encoded_project: &str,
+    team: &Team,
+    since: Option<SystemTime>,
+) -> Vec<(String, PathBuf)> {
+    let mut encoded: Vec<String> = team
+        .config
+        .members
+        .iter()
+        .filter_map(|m| m.cwd.as_deref())
+        .map(|cwd| path_encoding::encode_project_path(Path::new(cwd)))
+        .collect();
+    if encoded.is_empty() {
+        encoded.push(encoded_project.to_string());
+    }
+    encoded.sort();
+    encoded.dedup();
+
+    let recent = |path: &Path| match since {
+        Some(since) => std::fs::metadata(path)
+            .and_then(|m| m.modified())
+            .is_ok_and(|modified| modified >= since),
+        None => true,
+    };
+    let stem = |path: &Path| {
+        path.file_stem()
+            .map(|s| s.to_string_lossy().to_string())
+            .unwrap_or_default()
+    };
+
+    let mut results = Vec::new();
+    for dir in encoded {
+        let project_dir = claude_home.join("projects").join(dir);
+        let Ok(entries) = std::fs::read_dir(&project_dir) else {
+            continue;
+        };
+        for path in entries.flatten().map(|e| e.path()) {
+            if path.is_dir() {
+                // <session>/subagents/agent-*.jsonl belong to <session>
+                let session_id = stem(&path);
+                let Ok(subagents) = std::fs::read_dir(path.join("subagents")) else {
+                    continue;
+                };
+                for sub in subagents.flatten().map(|e| e.path()) {
+                    if sub.extension().is_some_and(|e| e == "jsonl") && recent(&sub) {
+                        results.push((session_id.clone(), sub));
+                    }
+                }
+            } else if path.extension().is_some_and(|e| e == "jsonl") && recent(&path) {
+                results.push((stem(&path), path));
+            }
+        }
+    }
+    results
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::team::{TeamConfig, TeamMember};
+
+    #[test]
+    fn aggregates_team_transcripts() {
+        let home = std::env::temp_dir().join(format!("assoc-team-res-{}", std::process::id()));
+        let project_dir = home.join("projects").join("proj");
+        std::fs::create_dir_all(project_dir.join("lead-1").join("subagents")).unwrap();
+
+        let assistant = |id: &str, tokens: u64, extra: &str, tool: &str| {
+            format!(
+                r#"{{"type":"assistant",{}"message":{{"id":"{}","role":"assistant","usage":{{"input_tokens":{},"output_tokens":0}},"content":[{}]}}}}"#,
+                extra, id, tokens, tool
+            )
+        };
+        let edit = r#"{"type":"tool_use","name":"Edit","input":{"file_path":"src/a.rs"}}"#;
+        let read = r#"{"type":"tool_use","name":"Read","input":{"file_path":"src/b.rs"}}"#;
+        // The lead session repeats message m1's usage on a second block line
+        let lead = [
+            assistant("m1", 100, "", edit),
+            assistant("m1", 100, "", read),
+        ];
+        std::fs::write(project_dir.join("lead-1.jsonl"), lead.join("\n")).unwrap();
+        let worker = assistant(
+            "m2",
+            40,
+            r#""teamName":"alpha","agentName":"worker","#,
+            edit,
+        );
+        std::fs::write(project_dir.join("worker-1.jsonl"), worker).unwrap();
+        std::fs::write(
+            project_dir
+                .join("lead-1")
+                .join("subagents")
+                .join("agent-x.jsonl"),
+            assistant("m3", 10, "", r#"{"type":"text","text":"hi"}"#),
+        )
+        .unwrap();
+        std::fs::write(
+            project_dir.join("other.jsonl"),
+            assistant("m4", 999, "", edit),
+        )
+        .unwrap();
+
+        let member = |name: &str, id: &str| TeamMember {
+            name: name.to_string(),
+            agent_id: Some(id.to_string()),
+            agent_type: None,
+            model: None,
+            cwd: None,
+            color: None,
+            joined_at: None,
+            tmux_pane_id: None,
+            backend_type: None,
+            prompt: None,
+            plan_mode_required: None,
+            subscriptions: None,
+        };
+        let team = Team {
+            dir_name: "alpha".to_string(),
+            config: TeamConfig {
+                lead_agent_id: Some("lead@alpha".to_string()),
+                lead_session_id: Some("lead-1".to_string()),
+                members: vec![
+                    member("lead", "lead@alpha"),
+                    member("worker", "worker@alpha"),
+                ],
+                ..TeamConfig::default()
+            },
+            home: 0,
+        };
+
+        let resources = scan_team_resources(&home, "proj", &team);
+        assert_eq!(resources.transcripts, 3);
+        assert_eq!(resources.tokens, 150);
+        assert_eq!(resources.tool_calls, 3);
+        assert_eq!(resources.files_touched.len(), 1);
+        assert_eq!(resources.members["lead"].tokens, 110);
+        assert_eq!(resources.members["worker"].tokens, 40);
+        assert_eq!(resources.members["worker"].files_touched, 1);
+
+        let transcript = |name: &str| {
+            let member = team.config.members.iter().find(|m| m.name == name)?;
+            member_transcript(&home, "proj", &team, member)
+        };
+        assert_eq!(transcript("lead"), Some(project_dir.join("lead-1.jsonl")));
+        assert_eq!(
+            transcript("worker"),
+            Some(project_dir.join("worker-1.jsonl"))
+        );
+        assert_eq!(
+            member_transcript(&home, "proj", &team, &member("helper", "x@alpha")),
+            Some(
+                project_dir
+                    .join("lead-1")
+                    .join("subagents")
+                    .join("agent-x.jsonl")
+            )
+        );
+
+        let _ = std::fs::remove_dir_all(&home);
+    }
+}
@@ -34,6 +34,7 @@ pub fn load_teams(claude_home: &Path, project_cwd: Option<&Path>) -> Result<Vec<
                 teams.push(Team {
                     dir_name,
                     config: TeamConfig::default(),
+                    home: 0,
                 });
             }
             continue;
@@ -69,7 +70,11 @@ pub fn load_teams(claude_home: &Path, project_cwd: Option<&Path>) -> Result<Vec<
             had_cwd_match = true;
         }
 
-        teams.push(Team { dir_name, config });
+        teams.push(Team {
+            dir_name,
+            config,
+            home: 0,
+        });
     }
 
     // If no team with config matched the CWD, load all teams
@@ -0,0 +1,35 @@
+use std::path::{Path, PathBuf};
+
+use anyhow::Result;
+
+use crate::model::artifact::TicketNotes;
+
+/// Location of the artifacts recorded against a project's tickets.
+pub fn notes_path(assoc_home: &Path, encoded_project: &str) -> PathBuf {
+    assoc_home
+        .join("ticket-notes")
+        .join(format!("{}.json", encoded_project))
+}
+
+/// Load the ticket notes, returning an empty set if the file is missing or
+/// unreadable.
+pub fn load_notes(path: &Path) -> TicketNotes {
+    std::fs::read_to_string(path)
+        .ok()
+        .and_then(|data| serde_json::from_str(&data).ok())
+        .unwrap_or_default()
+}
+
+/// Write the ticket notes to disk via a temporary file.
+pub fn save_notes(path: &Path, notes: &TicketNotes) -> Result<()> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    let tmp = path.with_extension("json.tmp");
+    std::fs::write(&tmp, serde_json::to_string_pretty(notes)?)?;
+    if let Err(e) = std::fs::rename(&tmp, path) {
+        let _ = std::fs::remove_file(&tmp);
+        return Err(e.into());
+    }
+    Ok(())
+}
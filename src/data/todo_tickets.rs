@@ -0,0 +1,104 @@
+//! Turning a todo item into a GitHub, Jira or Linear issue (`t` on the
+//! Todos tab).
+
+use anyhow::Result;
+
+use super::{github, jira, linear};
+use crate::model::todo::{TodoFile, TodoItem};
+
+/// Longest title taken from a todo; the full text goes in the body.
+const MAX_TITLE_CHARS: usize = 120;
+
+/// A tracker the project can create issues in.
+#[derive(Debug, Clone, PartialEq)]
+pub enum TicketTarget {
+    /// `owner/repo` of the Issues tab.
+    GitHub(String),
+    /// `jira.project` key.
+    Jira(String),
+    /// `linear.team` key and the API key.
+    Linear { team: String, api_key: String },
+}
+
+impl TicketTarget {
+    pub fn label(&self) -> String {
+        match self {
+            TicketTarget::GitHub(repo) => format!("GitHub issue in {}", repo),
+            TicketTarget::Jira(project) => format!("Jira task in {}", project),
+            TicketTarget::Linear { team, .. } => format!("Linear issue in {}", team),
+        }
+    }
+
+    /// Create the issue and return the key written back to the todo:
+    /// `GH #42`, `PROJ-123` or `ENG-7`.
+    pub fn create(&self, title: &str, body: &str) -> Result<String> {
+        match self {
+            TicketTarget::GitHub(repo) => {
+                let url = github::create_issue(repo, title, body, &[])?;
+                match github_number(&url) {
+                    Some(number) => Ok(format!("GH #{}", number)),
+                    None => anyhow::bail!("gh did not return the new issue's URL"),
+                }
+            }
+            TicketTarget::Jira(project) => jira::create_issue(project, title, body),
+            TicketTarget::Linear { team, api_key } => {
+                linear::create_issue(api_key, team, title, body)
+            }
+        }
+    }
+}
+
+/// The issue number at the end of a `.../issues/42` URL.
+fn github_number(url: &str) -> Option<u64> {
+    url.trim_end_matches('/').rsplit('/').next()?.parse().ok()
+}
+
+/// Title and body for a ticket made from `item`: the first line of the
+/// todo, shortened, and its full text with where it came from.
+pub fn draft(file: &TodoFile, item: &TodoItem) -> (String, String) {
+    let text = item.content.as_deref().unwrap_or("").trim();
+    let first = text.lines().next().unwrap_or("").trim();
+    let title = if first.chars().count() > MAX_TITLE_CHARS {
+        let cut: String = first.chars().take(MAX_TITLE_CHARS - 3).collect();
+        format!("{}...", cut.trim_end())
+    } else {
+        first.to_string()
+    };
+    let status = item
+        .status
+        .as_deref()
+        .unwrap_or("pending")
+        .replace('_', " ");
+    let body = format!(
+        "{}\n\nFrom the Claude Code todo list `{}` ({}).",
+        text, file.filename, status
+    );
+    (title, body)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn drafts_title_and_body_from_the_todo() {
+        let mut item = TodoItem::new(format!("Retry uploads {}\nsee logs", "x".repeat(150)));
+        item.status = Some("in_progress".to_string());
+        let file = TodoFile {
+            filename: "abc-agent-abc.json".to_string(),
+            items: vec![item.clone()],
+            home: 0,
+        };
+        let (title, body) = draft(&file, &item);
+        assert_eq!(title.chars().count(), MAX_TITLE_CHARS);
+        assert!(title.starts_with("Retry uploads x") && title.ends_with("..."));
+        assert!(body.starts_with("Retry uploads"));
+        assert!(body.ends_with("`abc-agent-abc.json` (in progress)."));
+
+        assert_eq!(
+            github_number("https://github.com/acme/app/issues/42"),
+            Some(42)
+        );
+        assert_eq!(github_number("Creating issue in acme/app"), None);
+    }
+}
@@ -40,10 +40,30 @@ pub fn load_todos(claude_home: &Path) -> Result<Vec<TodoFile>> {
             continue;
         }
 
-        todo_files.push(TodoFile { filename, items });
+        todo_files.push(TodoFile {
+            filename,
+            items,
+            home: 0,
+        });
     }
 
     // Sort by filename
     todo_files.sort_by(|a, b| a.filename.cmp(&b.filename));
     Ok(todo_files)
 }
+
+/// Write a todo file back to disk.
+///
+/// The JSON is written to a temporary file first and then renamed over the
+/// original so Claude Code never sees a half-written file.
+pub fn save_todo_file(claude_home: &Path, file: &TodoFile) -> Result<()> {
+    let path = claude_home.join("todos").join(&file.filename);
+    let tmp = path.with_extension("json.tmp");
+    let json = serde_json::to_string_pretty(&file.items)?;
+    std::fs::write(&tmp, json)?;
+    if let Err(e) = std::fs::rename(&tmp, &path) {
+        let _ = std::fs::remove_file(&tmp);
+        return Err(e.into());
+    }
+    Ok(())
+}
@@ -1,9 +1,23 @@
-use std::io::{BufRead, BufReader, Seek, SeekFrom};
-use std::path::Path;
+use std::fs::File;
+use std::io::{BufRead, BufReader, Read, Seek, SeekFrom};
+use std::path::{Path, PathBuf};
 
 use anyhow::Result;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
 
-use crate::model::transcript::{parse_envelope, TranscriptEnvelope, TranscriptItem};
+use crate::model::transcript::{
+    block_detail, fill_elapsed, parse_envelope, TranscriptEnvelope, TranscriptItem,
+};
+
+/// Leading bytes hashed to recognise a transcript that was replaced in place.
+const HEAD_BYTES: u64 = 256;
+
+/// Line offsets kept per index entry (at least `tail_lines`).
+const INDEX_KEEP_LINES: usize = 2048;
+
+/// Cap on items held in memory for one transcript.
+const MAX_TRANSCRIPT_ITEMS: usize = 5000;
 
 /// State for incrementally reading a JSONL transcript.
 #[derive(Debug)]
@@ -11,6 +25,13 @@ pub struct TranscriptReader {
     pub items: Vec<TranscriptItem>,
     pub last_offset: u64,
     pub tail_lines: usize,
+    /// Timestamp of the last user envelope (prompt or tool result), used to
+    /// time the assistant turns that answer it.
+    last_timestamp: Option<DateTime<Utc>>,
+    /// Directory for persisted line indexes; `None` disables the cache.
+    index_dir: Option<PathBuf>,
+    /// Fingerprint of the file as loaded, to detect rotation.
+    head: Head,
 }
 
 impl TranscriptReader {
@@ -19,94 +40,354 @@ impl TranscriptReader {
             items: Vec::new(),
             last_offset: 0,
             tail_lines,
+            last_timestamp: None,
+            index_dir: None,
+            head: Head::default(),
         }
     }
 
-    /// Initial load: read last N lines from end of file.
+    /// Persist line offsets under `dir` so reopening a large transcript
+    /// seeks straight to its tail instead of scanning it again.
+    pub fn with_index_cache(mut self, dir: PathBuf) -> Self {
+        self.index_dir = Some(dir);
+        self
+    }
+
+    /// Initial load: parse the last N lines of the file.
     pub fn load_initial(&mut self, path: &Path) -> Result<()> {
         self.items.clear();
         self.last_offset = 0;
+        self.last_timestamp = None;
+        self.head = Head::default();
 
         if !path.exists() {
             return Ok(());
         }
 
-        let content = std::fs::read_to_string(path)?;
-        let lines: Vec<&str> = content.lines().collect();
-        let start = lines.len().saturating_sub(self.tail_lines);
+        let mut file = File::open(path)?;
+        let file_len = file.metadata()?.len();
+        let head = Head::read(&mut file, file_len)?;
+        let keep = self.tail_lines.max(INDEX_KEEP_LINES);
 
-        for line in &lines[start..] {
-            let line = line.trim();
-            if line.is_empty() {
-                continue;
+        let mut cached = self
+            .index_dir
+            .as_deref()
+            .and_then(|dir| LineIndex::load(dir, path))
+            .filter(|index| index.len <= file_len)
+            // Offsets trimmed below what this reader's tail needs
+            .filter(|index| index.offsets.len() >= self.tail_lines.min(index.lines));
+        if let Some(index) = &cached {
+            // Same file if the bytes it was indexed with are unchanged
+            if Head::read(&mut file, index.head.len)? != index.head {
+                cached = None;
             }
-            if let Ok(envelope) = serde_json::from_str::<TranscriptEnvelope>(line) {
-                let parsed = parse_envelope(&envelope);
-                self.items.extend(parsed);
+        }
+        let reused = cached.is_some();
+        let mut index = cached.unwrap_or_else(|| LineIndex {
+            path: path.to_path_buf(),
+            ..LineIndex::default()
+        });
+        let unchanged = reused && index.head == head;
+        index.head = head;
+        let scanned_to = index.len;
+        index.extend(&mut file, file_len, keep)?;
+        if let Some(dir) = self.index_dir.as_deref() {
+            if !unchanged || index.len != scanned_to {
+                // The index only saves time; a failed write is not an error
+                let _ = index.save(dir);
             }
         }
 
-        // Track file size as offset for incremental reads
-        self.last_offset = content.len() as u64;
+        let skip = index.lines.saturating_sub(self.tail_lines);
+        let first = skip - (index.lines - index.offsets.len());
+        let start = index.offsets.get(first).copied().unwrap_or(index.len);
+        self.head = head;
+        self.read_from(file, start)?;
         Ok(())
     }
 
     /// Incremental read: only parse new lines since last_offset.
     /// Returns `Ok((had_new, drained_count))` where `drained_count` is the
-    /// number of items removed from the front when the cap is exceeded.
+    /// number of items removed from the front: by the cap, or all of them
+    /// when the file was truncated or replaced and its tail reloaded.
     pub fn read_new(&mut self, path: &Path) -> Result<(bool, usize)> {
         if !path.exists() {
             return Ok((false, 0));
         }
 
-        let file = std::fs::File::open(path)?;
+        let mut file = File::open(path)?;
         let file_len = file.metadata()?.len();
 
-        if file_len < self.last_offset {
-            // File was truncated (e.g., session rotated) — reset and do full reload
-            self.items.clear();
-            self.last_offset = 0;
+        let rotated = file_len < self.last_offset
+            || (self.head.len > 0 && Head::read(&mut file, self.head.len)? != self.head);
+        if rotated {
+            let dropped = self.items.len();
+            self.load_initial(path)?;
+            return Ok((true, dropped));
         }
 
         if file_len <= self.last_offset {
             return Ok((false, 0));
         }
+        if self.head.len < HEAD_BYTES {
+            self.head = Head::read(&mut file, file_len)?;
+        }
 
-        let mut reader = BufReader::new(file);
-        reader.seek(SeekFrom::Start(self.last_offset))?;
+        let before = self.items.len();
+        self.read_from(file, self.last_offset)?;
+        let had_new = self.items.len() > before;
+
+        // Cap transcript items to prevent unbounded memory growth
+        let drained = if self.items.len() > MAX_TRANSCRIPT_ITEMS {
+            let drain_count = self.items.len() - MAX_TRANSCRIPT_ITEMS;
+            self.items.drain(0..drain_count);
+            drain_count
+        } else {
+            0
+        };
+
+        Ok((had_new, drained))
+    }
 
-        let mut had_new = false;
+    /// Parse lines from `start` to the end of `file` and move `last_offset`
+    /// past them. A final line without a newline that doesn't parse yet is
+    /// still being written; it is left for the next read.
+    fn read_from(&mut self, mut file: File, start: u64) -> Result<()> {
+        file.seek(SeekFrom::Start(start))?;
+        let mut reader = BufReader::new(file);
         let mut line = String::new();
+        let mut offset = start;
         loop {
             line.clear();
             let bytes_read = reader.read_line(&mut line)?;
             if bytes_read == 0 {
                 break;
             }
+            let line_offset = offset;
             let trimmed = line.trim();
-            if trimmed.is_empty() {
-                continue;
-            }
-            if let Ok(envelope) = serde_json::from_str::<TranscriptEnvelope>(trimmed) {
-                let parsed = parse_envelope(&envelope);
-                if !parsed.is_empty() {
-                    had_new = true;
-                    self.items.extend(parsed);
+            if !trimmed.is_empty() {
+                match serde_json::from_str::<TranscriptEnvelope>(trimmed) {
+                    Ok(envelope) => {
+                        let parsed = self.parse_timed(&envelope, line_offset);
+                        self.items.extend(parsed);
+                    }
+                    Err(_) if !line.ends_with('\n') => break,
+                    Err(_) => {}
                 }
             }
+            offset += bytes_read as u64;
         }
+        self.last_offset = offset;
+        Ok(())
+    }
 
-        // Cap transcript items to prevent unbounded memory growth
-        const MAX_TRANSCRIPT_ITEMS: usize = 5000;
-        let drained = if self.items.len() > MAX_TRANSCRIPT_ITEMS {
-            let drain_count = self.items.len() - MAX_TRANSCRIPT_ITEMS;
-            self.items.drain(0..drain_count);
-            drain_count
-        } else {
-            0
+    /// Parse an envelope, timing assistant items against the last user input.
+    fn parse_timed(&mut self, envelope: &TranscriptEnvelope, offset: u64) -> Vec<TranscriptItem> {
+        let mut parsed = parse_envelope(envelope);
+        for item in &mut parsed {
+            item.line_offset = Some(offset);
+        }
+        fill_elapsed(&mut parsed, self.last_timestamp);
+        if envelope.kind == "user" && envelope.timestamp.is_some() {
+            self.last_timestamp = envelope.timestamp;
+        }
+        parsed
+    }
+}
+
+/// Hash of a file's first `len` bytes (up to `HEAD_BYTES`).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+struct Head {
+    len: u64,
+    hash: u64,
+}
+
+impl Head {
+    fn read(file: &mut File, len: u64) -> Result<Self> {
+        let len = len.min(HEAD_BYTES);
+        let mut bytes = Vec::with_capacity(len as usize);
+        file.seek(SeekFrom::Start(0))?;
+        file.by_ref().take(len).read_to_end(&mut bytes)?;
+        // FNV-1a: stable across builds, unlike std's hasher
+        let hash = bytes.iter().fold(0xcbf2_9ce4_8422_2325u64, |h, &b| {
+            (h ^ b as u64).wrapping_mul(0x0100_0000_01b3)
+        });
+        Ok(Self {
+            len: bytes.len() as u64,
+            hash,
+        })
+    }
+}
+
+/// Start offsets of a transcript's newline-terminated lines, persisted as
+/// `<index dir>/<file stem>.json`.
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct LineIndex {
+    path: PathBuf,
+    head: Head,
+    /// Bytes scanned so far; always the end of a complete line.
+    len: u64,
+    /// Complete lines in the first `len` bytes.
+    lines: usize,
+    /// Start offsets of the last `offsets.len()` of those lines.
+    offsets: Vec<u64>,
+}
+
+impl LineIndex {
+    fn file_for(dir: &Path, path: &Path) -> PathBuf {
+        let stem = path.file_stem().unwrap_or_default().to_string_lossy();
+        dir.join(format!("{}.json", stem))
+    }
+
+    fn load(dir: &Path, path: &Path) -> Option<Self> {
+        let content = std::fs::read_to_string(Self::file_for(dir, path)).ok()?;
+        serde_json::from_str::<Self>(&content)
+            .ok()
+            .filter(|index| index.path == path)
+    }
+
+    fn save(&self, dir: &Path) -> Result<()> {
+        std::fs::create_dir_all(dir)?;
+        let ignore = dir.join(".gitignore");
+        if !ignore.exists() {
+            std::fs::write(ignore, "*\n")?;
+        }
+        let path = Self::file_for(dir, &self.path);
+        let tmp = path.with_extension("json.tmp");
+        std::fs::write(&tmp, serde_json::to_string(self)?)?;
+        std::fs::rename(&tmp, &path)?;
+        Ok(())
+    }
+
+    /// Scan `file` from `len` to `file_len` for line starts, keeping the
+    /// last `keep` offsets.
+    fn extend(&mut self, file: &mut File, file_len: u64, keep: usize) -> Result<()> {
+        if self.len >= file_len {
+            return Ok(());
+        }
+        file.seek(SeekFrom::Start(self.len))?;
+        let mut reader = BufReader::new(file.by_ref().take(file_len - self.len));
+        let mut line = Vec::new();
+        loop {
+            line.clear();
+            let bytes_read = reader.read_until(b'\n', &mut line)?;
+            if bytes_read == 0 || line.last() != Some(&b'\n') {
+                break;
+            }
+            self.offsets.push(self.len);
+            self.lines += 1;
+            self.len += bytes_read as u64;
+        }
+        if self.offsets.len() > keep {
+            self.offsets.drain(..self.offsets.len() - keep);
+        }
+        Ok(())
+    }
+}
+
+/// Re-read an item's JSONL line and return its full, untruncated content.
+/// `None` when the item has no message block to expand.
+pub fn read_item_detail(path: &Path, item: &TranscriptItem) -> Result<Option<String>> {
+    let Some(offset) = item.line_offset else {
+        return Ok(None);
+    };
+    let mut reader = BufReader::new(std::fs::File::open(path)?);
+    reader.seek(SeekFrom::Start(offset))?;
+    let mut line = String::new();
+    reader.read_line(&mut line)?;
+    let envelope: TranscriptEnvelope = serde_json::from_str(line.trim())?;
+    Ok(block_detail(&envelope, item.block))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::transcript::TranscriptItemKind;
+
+    #[test]
+    fn rereads_full_tool_items() {
+        let long_result = "x".repeat(200);
+        let lines = [
+            r#"{"type":"user","message":{"role":"user","content":"go"}}"#.to_string(),
+            r#"{"type":"assistant","message":{"role":"assistant","content":[{"type":"text","text":"ok"},{"type":"tool_use","name":"Bash","input":{"command":"ls"}}]}}"#.to_string(),
+            format!(
+                r#"{{"type":"user","message":{{"role":"user","content":[{{"type":"tool_result","content":"{}"}}]}}}}"#,
+                long_result
+            ),
+        ];
+        let path =
+            std::env::temp_dir().join(format!("assoc-transcript-{}.jsonl", std::process::id()));
+        std::fs::write(&path, lines.join("\n") + "\n").unwrap();
+
+        let mut reader = TranscriptReader::with_tail_lines(10);
+        reader.load_initial(&path).unwrap();
+        let tool = &reader.items[2];
+        assert_eq!(tool.kind, TranscriptItemKind::ToolUse);
+        let detail = read_item_detail(&path, tool).unwrap().unwrap();
+        assert_eq!(detail, "Bash\n\n{\n  \"command\": \"ls\"\n}");
+
+        let result = &reader.items[3];
+        assert_eq!(result.text.len(), 80);
+        let detail = read_item_detail(&path, result).unwrap().unwrap();
+        assert_eq!(detail, long_result);
+
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[test]
+    fn indexes_tail_and_survives_rotation() {
+        let dir = std::env::temp_dir().join(format!("assoc-index-{}", std::process::id()));
+        let index_dir = dir.join("cache");
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("s1.jsonl");
+        let user = |text: &str| {
+            format!(
+                r#"{{"type":"user","message":{{"role":"user","content":"{}"}}}}"#,
+                text
+            )
+        };
+        let texts = |reader: &TranscriptReader| -> Vec<String> {
+            reader.items.iter().map(|i| i.text.clone()).collect()
         };
+        std::fs::write(
+            &path,
+            format!("{}\n{}\n{}\n", user("a"), user("b"), user("c")),
+        )
+        .unwrap();
 
-        self.last_offset = file_len;
-        Ok((had_new, drained))
+        let open = || {
+            let mut reader =
+                TranscriptReader::with_tail_lines(2).with_index_cache(index_dir.clone());
+            reader.load_initial(&path).unwrap();
+            reader
+        };
+        let mut reader = open();
+        assert_eq!(texts(&reader), ["b", "c"]);
+        let index = LineIndex::load(&index_dir, &path).unwrap();
+        assert_eq!((index.lines, index.len), (3, reader.last_offset));
+
+        // A half-written line waits for the rest
+        let mut file = std::fs::OpenOptions::new()
+            .append(true)
+            .open(&path)
+            .unwrap();
+        std::io::Write::write_all(&mut file, br#"{"type":"user","#).unwrap();
+        assert_eq!(reader.read_new(&path).unwrap(), (false, 0));
+        std::io::Write::write_all(&mut file, br#""message":{"role":"user","content":"d"}}"#)
+            .unwrap();
+        std::io::Write::write_all(&mut file, b"\n").unwrap();
+        assert_eq!(reader.read_new(&path).unwrap(), (true, 0));
+        assert_eq!(texts(&reader), ["b", "c", "d"]);
+
+        // Reopening extends the cached index rather than rescanning
+        assert_eq!(texts(&open()), ["c", "d"]);
+        assert_eq!(LineIndex::load(&index_dir, &path).unwrap().lines, 4);
+
+        // Replaced by a different file: the old items are all dropped
+        std::fs::write(&path, format!("{}\n", user("new session"))).unwrap();
+        assert_eq!(reader.read_new(&path).unwrap(), (true, 3));
+        assert_eq!(texts(&reader), ["new session"]);
+
+        let _ = std::fs::remove_dir_all(&dir);
     }
 }
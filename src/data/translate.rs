@@ -0,0 +1,72 @@
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+use anyhow::{bail, Result};
+
+/// Translate `text` by piping it through the configured shell command, run
+/// once per message. The command reads the original on stdin and writes
+/// the translation to stdout.
+pub fn translate(command: &str, text: &str) -> Result<String> {
+    let mut child = shell(command)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()?;
+
+    // Written from its own thread: a command that answers while still
+    // reading would otherwise fill both pipes and wait on us forever
+    let writer = child.stdin.take().map(|mut stdin| {
+        let text = text.to_string();
+        std::thread::spawn(move || {
+            // A command that stops reading early shows up in its exit status
+            let _ = stdin.write_all(text.as_bytes());
+            // Dropping stdin closes the pipe so the command sees EOF
+        })
+    });
+
+    let output = child.wait_with_output()?;
+    if let Some(writer) = writer {
+        let _ = writer.join();
+    }
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        let msg = stderr.lines().next().unwrap_or("").trim();
+        if msg.is_empty() {
+            bail!("translation command exited with {}", output.status);
+        }
+        bail!("{}", msg);
+    }
+
+    let translated = String::from_utf8_lossy(&output.stdout)
+        .trim_end()
+        .to_string();
+    if translated.is_empty() {
+        bail!("translation command produced no output");
+    }
+    Ok(translated)
+}
+
+/// `command` run by the platform's shell: `cmd /C` on Windows, `sh -c`
+/// elsewhere.
+fn shell(command: &str) -> Command {
+    #[cfg(windows)]
+    let (program, flag) = ("cmd", "/C");
+    #[cfg(not(windows))]
+    let (program, flag) = ("sh", "-c");
+    let mut cmd = Command::new(program);
+    cmd.args([flag, command]);
+    cmd
+}
+
+#[cfg(all(test, not(windows)))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pipes_large_messages_through_the_command() {
+        // cat fills the output pipe while the input is still being written
+        let text = "hello world\n".repeat(100_000);
+        let translated = translate("cat", &text).unwrap();
+        assert_eq!(translated, text.trim_end());
+    }
+}
@@ -0,0 +1,186 @@
+use std::path::{Path, PathBuf};
+
+use anyhow::{bail, Context, Result};
+use chrono::Utc;
+
+use crate::model::trash::{TrashEntry, TrashSource};
+
+/// The project's trash: one directory per deleted item, plus a manifest.
+pub fn trash_dir(project_cwd: &Path) -> PathBuf {
+    project_cwd.join(".associate").join("trash")
+}
+
+fn manifest_path(project_cwd: &Path) -> PathBuf {
+    trash_dir(project_cwd).join("manifest.json")
+}
+
+/// Entries in the trash, newest first. A missing or unreadable manifest is
+/// an empty trash.
+pub fn load_trash(project_cwd: &Path) -> Vec<TrashEntry> {
+    let mut entries: Vec<TrashEntry> = std::fs::read_to_string(manifest_path(project_cwd))
+        .ok()
+        .and_then(|data| serde_json::from_str(&data).ok())
+        .unwrap_or_default();
+    entries.sort_by_key(|e| std::cmp::Reverse(e.deleted_at));
+    entries
+}
+
+fn save_manifest(project_cwd: &Path, entries: &[TrashEntry]) -> Result<()> {
+    let path = manifest_path(project_cwd);
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    let tmp = path.with_extension("json.tmp");
+    std::fs::write(&tmp, serde_json::to_string_pretty(entries)?)?;
+    if let Err(e) = std::fs::rename(&tmp, &path) {
+        let _ = std::fs::remove_file(&tmp);
+        return Err(e.into());
+    }
+    Ok(())
+}
+
+/// Move the file or directory at `path` into the trash instead of deleting
+/// it, and record where it came from.
+pub fn move_to_trash(
+    project_cwd: &Path,
+    name: &str,
+    source: TrashSource,
+    path: &Path,
+) -> Result<TrashEntry> {
+    let file_name = path.file_name().context("Nothing to delete")?;
+    let now = Utc::now();
+    let stamp = now.format("%Y%m%d-%H%M%S%3f").to_string();
+    let mut id = stamp.clone();
+    let mut n = 1;
+    while trash_dir(project_cwd).join(&id).exists() {
+        n += 1;
+        id = format!("{}-{}", stamp, n);
+    }
+    let dir = trash_dir(project_cwd).join(&id);
+    std::fs::create_dir_all(&dir)?;
+    if let Err(e) = move_path(path, &dir.join(file_name)) {
+        let _ = std::fs::remove_dir_all(&dir);
+        return Err(e);
+    }
+
+    let entry = TrashEntry {
+        id,
+        name: name.to_string(),
+        source,
+        original: path.to_path_buf(),
+        deleted_at: now,
+    };
+    let mut entries = load_trash(project_cwd);
+    entries.push(entry.clone());
+    save_manifest(project_cwd, &entries)?;
+    Ok(entry)
+}
+
+/// Put the trashed item `id` back where it was deleted from. Refuses to
+/// replace anything created there since.
+pub fn restore(project_cwd: &Path, id: &str) -> Result<TrashEntry> {
+    let mut entries = load_trash(project_cwd);
+    let pos = entries
+        .iter()
+        .position(|e| e.id == id)
+        .context("No longer in the trash")?;
+    let entry = entries.remove(pos);
+    if entry.original.exists() {
+        bail!("{} already exists", entry.original.display());
+    }
+    let file_name = entry.original.file_name().context("No file name")?;
+    let dir = trash_dir(project_cwd).join(&entry.id);
+    if let Some(parent) = entry.original.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    move_path(&dir.join(file_name), &entry.original)?;
+    let _ = std::fs::remove_dir_all(&dir);
+    save_manifest(project_cwd, &entries)?;
+    Ok(entry)
+}
+
+/// Delete the trashed item `id` for good.
+pub fn purge(project_cwd: &Path, id: &str) -> Result<()> {
+    let mut entries = load_trash(project_cwd);
+    entries.retain(|e| e.id != id);
+    let dir = trash_dir(project_cwd).join(id);
+    if dir.exists() {
+        std::fs::remove_dir_all(&dir)?;
+    }
+    save_manifest(project_cwd, &entries)
+}
+
+/// Delete everything in the trash for good.
+pub fn purge_all(project_cwd: &Path) -> Result<()> {
+    for entry in load_trash(project_cwd) {
+        purge(project_cwd, &entry.id)?;
+    }
+    Ok(())
+}
+
+/// Rename `from` to `to`, copying and then removing when they are on
+/// different filesystems (the trash lives in the project, sessions under
+/// the Claude home).
+fn move_path(from: &Path, to: &Path) -> Result<()> {
+    if std::fs::rename(from, to).is_ok() {
+        return Ok(());
+    }
+    copy_recursive(from, to).with_context(|| format!("Moving {}", from.display()))?;
+    if from.is_dir() {
+        std::fs::remove_dir_all(from)?;
+    } else {
+        std::fs::remove_file(from)?;
+    }
+    Ok(())
+}
+
+fn copy_recursive(from: &Path, to: &Path) -> Result<()> {
+    if from.is_dir() {
+        std::fs::create_dir_all(to)?;
+        for entry in std::fs::read_dir(from)?.flatten() {
+            copy_recursive(&entry.path(), &to.join(entry.file_name()))?;
+        }
+    } else {
+        std::fs::copy(from, to)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn trashes_and_restores() {
+        let root = std::env::temp_dir().join(format!("assoc-trash-{}", std::process::id()));
+        let project = root.join("project");
+        let team = root.join("home").join("teams").join("alpha");
+        std::fs::create_dir_all(&team).unwrap();
+        std::fs::write(team.join("config.json"), "{}").unwrap();
+        let plan = root.join("home").join("plans").join("p.md");
+        std::fs::create_dir_all(plan.parent().unwrap()).unwrap();
+        std::fs::write(&plan, "# Plan").unwrap();
+
+        let trashed_team = move_to_trash(&project, "alpha", TrashSource::Team, &team).unwrap();
+        let trashed_plan = move_to_trash(&project, "p.md", TrashSource::Plan, &plan).unwrap();
+        assert!(!team.exists() && !plan.exists());
+        assert_eq!(load_trash(&project).len(), 2);
+
+        let restored = restore(&project, &trashed_team.id).unwrap();
+        assert_eq!(restored.source, TrashSource::Team);
+        assert_eq!(
+            std::fs::read_to_string(team.join("config.json")).unwrap(),
+            "{}"
+        );
+        // Something new at the old path is never overwritten
+        std::fs::write(&plan, "new").unwrap();
+        assert!(restore(&project, &trashed_plan.id).is_err());
+        assert_eq!(load_trash(&project).len(), 1);
+
+        purge_all(&project).unwrap();
+        assert!(load_trash(&project).is_empty());
+        assert!(!trash_dir(&project).join(&trashed_plan.id).exists());
+
+        let _ = std::fs::remove_dir_all(&root);
+    }
+}
@@ -1,29 +1,105 @@
+use std::collections::HashMap;
 use std::path::PathBuf;
 
+use crate::data::linear::IssuePage;
+use crate::data::process_usage::UsageSample;
+use crate::model::azure::{WorkItem, WorkItemComment};
+use crate::model::command::ImportCandidate;
+use crate::model::deps::OutdatedDep;
 use crate::model::git::{DiffLine, GitStatus};
-use crate::model::github::{GitHubIssue, PullRequest};
+use crate::model::github::{GitHubIssue, IssueMetaField, PullRequest, ReviewThread};
+use crate::model::grep::GrepResults;
 use crate::model::jira::JiraIssue;
-use crate::model::linear::LinearIssue;
+use crate::model::link_preview::LinkPreview;
+use crate::model::session::{ActivityCalendar, SessionStats};
+use crate::model::team::TeamResources;
 
 /// All events the app loop handles.
 #[derive(Debug)]
 pub enum AppEvent {
     /// A watched file was created or modified.
     FileChanged(FileChange),
+    /// The project's `.assoc.toml` was created, edited or removed.
+    ConfigChanged,
     /// Pane send completed: None = success, Some = error message.
     PaneSendComplete(Option<String>),
-    /// Background load of GitHub PRs completed.
-    GitHubPrsLoaded(Result<Vec<PullRequest>, String>),
-    /// Background load of GitHub Issues completed.
-    GitHubIssuesLoaded(Result<Vec<GitHubIssue>, String>),
-    /// Background load of Jira issues completed.
-    JiraIssuesLoaded(Result<Vec<JiraIssue>, String>),
+    /// Pane resize completed: None = success, Some = error message.
+    PaneResizeComplete(Option<String>),
+    /// Background lookup of the GitHub login finished; None when gh isn't
+    /// signed in.
+    GhUserDetected(Option<String>),
+    /// Background load of GitHub PRs completed, with the open PR count when
+    /// it was fetched.
+    GitHubPrsLoaded(Result<Vec<PullRequest>, String>, Option<usize>),
+    /// Bitbucket PRs and the signed-in user's nickname.
+    BitbucketPrsLoaded(Result<(String, Vec<PullRequest>), String>),
+    /// Review threads of a PR were fetched: (PR number, PR updated_at, threads).
+    ReviewThreadsLoaded(u64, String, Result<Vec<ReviewThread>, String>),
+    /// Background load of GitHub Issues completed, with the matching issue
+    /// count when it was fetched.
+    GitHubIssuesLoaded(Result<Vec<GitHubIssue>, String>, Option<usize>),
+    /// Options for an issue label / assignee / milestone picker loaded.
+    IssueMetaOptionsLoaded(IssueMetaField, Result<Vec<String>, String>),
+    /// An issue label / assignee / milestone change finished.
+    IssueMetaUpdated(Result<(), String>),
+    /// Background load of Jira issues completed, for a `[jira.filters]`
+    /// name or `None` for the default query.
+    JiraIssuesLoaded(Option<String>, Result<Vec<JiraIssue>, String>),
+    /// Background load of the Jira board (active sprint) completed.
+    JiraBoardLoaded(Result<Vec<JiraIssue>, String>),
+    /// A Jira attachment finished downloading to the given temp path.
+    JiraAttachmentDownloaded(Result<PathBuf, String>),
     /// Background load of Linear issues completed.
-    LinearIssuesLoaded(Result<Vec<LinearIssue>, String>),
+    LinearIssuesLoaded(Result<IssuePage, String>),
+    /// Background load of the Linear page after the given cursor completed.
+    LinearMoreLoaded(String, Result<IssuePage, String>),
+    /// Background load of Azure Boards work items completed.
+    AzureItemsLoaded(Result<Vec<WorkItem>, String>),
+    /// Background load of a work item's detail and comments completed.
+    AzureDetailLoaded(Result<(WorkItem, Vec<WorkItemComment>), String>),
     /// Background load of git status completed.
     GitStatusLoaded(Result<GitStatus, String>),
+    /// A project search finished: (pattern, results).
+    GrepLoaded(String, Result<GrepResults, String>),
     /// Background load of git diff completed.
     GitDiffLoaded(Result<Vec<DiffLine>, String>),
+    /// CLI auth status check completed: (cli name, (authenticated, output)).
+    AuthStatusLoaded(String, Result<(bool, String), String>),
+    /// Dependency checkers finished: (outdated deps, per-checker errors).
+    DepsLoaded(Vec<OutdatedDep>, Vec<String>),
+    /// Per-team transcript usage scan completed, keyed by (home, dir name).
+    TeamResourcesLoaded(HashMap<(usize, String), TeamResources>),
+    /// Notification sinks were posted to; holds one message per failed sink.
+    NotificationsSent(Vec<String>),
+    /// A transcript message was translated: (original text, translation).
+    TranslationLoaded(String, Result<String, String>),
+    /// A linked page was fetched for its preview: (url, preview).
+    LinkPreviewLoaded(String, Result<LinkPreview, String>),
+    /// A session's transcript was scanned for the stats pane: (session id, stats).
+    SessionStatsLoaded(String, Result<SessionStats, String>),
+    /// The project's transcripts were scanned for the activity heatmap.
+    ActivityCalendarLoaded(ActivityCalendar),
+    /// The battery was checked: whether the machine is running on it.
+    PowerStatusLoaded(bool),
+    /// Process CPU and memory were sampled.
+    ProcessUsageSampled(UsageSample),
+    /// The working tree was snapshotted before a queued process starts:
+    /// (process id, tree id).
+    ProcessTreeBefore(usize, Option<String>),
+    /// The working tree was snapshotted after a process exited: (process
+    /// id, tree id).
+    ProcessTreeAfter(usize, Option<String>),
+    /// A run report was posted on the ticket: (ticket label, result).
+    ProcessReportPosted(String, Result<(), String>),
+    /// Agent/command definitions were loaded for import.
+    CommandImportLoaded(Result<Vec<ImportCandidate>, String>),
+    /// A ticket was created from a todo: (Claude home, todo file, item
+    /// content, ticket key).
+    TodoTicketCreated(usize, String, String, Result<String, String>),
+    /// A session was exported: (file written, records).
+    SessionExported(Result<(PathBuf, usize), String>),
+    /// `assoc launch` finished: the status to show, or why it failed.
+    AssocLaunched(Result<String, String>),
 }
 
 /// Categorized file change from the watcher.
@@ -37,5 +113,9 @@ pub enum FileChange {
     TaskFile(String),
     TodoFile(PathBuf),
     GitChange,
+    /// A file in the project's working tree.
+    Worktree,
     PlanFile(PathBuf),
+    /// A Claude Code settings file of the project or the user.
+    ClaudeSettings,
 }
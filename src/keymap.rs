@@ -0,0 +1,1390 @@
+//! The dashboard's normal-mode key bindings.
+//!
+//! Every key the tabs respond to outside of an editor, input line or popup
+//! is listed once in `BINDINGS`, with the context it applies in. Key
+//! handling looks actions up here, and the status bar hints and the help
+//! overlay are generated from the same table, so the three cannot drift
+//! apart.
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+use crate::app::{
+    ActiveTab, App, FileBrowserPane, GitMode, JiraPane, PlansPane, SessionsPane, TeamsPane,
+};
+
+/// What a binding does; `main::run_action` carries it out.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Action {
+    Quit,
+    ToggleHelp,
+    CycleTab,
+    JumpToTab,
+    /// j/k/h/l and the arrow keys.
+    Navigate,
+    Select,
+    /// `g` top, `G` bottom.
+    JumpEnd,
+    Filter,
+    ActivityFeed,
+    GotoAnything,
+    UndoDelete,
+    Trash,
+    SendToPane,
+    TogglePaneRatio,
+    JumpToUnread,
+    ToggleFollow,
+    CycleSubagent,
+    ToggleSessionStats,
+    ExportSession,
+    RenameSession,
+    LinkSession,
+    GroupSessionsByTicket,
+    ToggleTranslation,
+    ToggleTurnUsage,
+    ToggleTranscriptWrap,
+    ResumeSession,
+    ReviewPlan,
+    OpenSessionInPane,
+    OpenFileReference,
+    EditTask,
+    ToggleTaskGraph,
+    Broadcast,
+    MemberTranscript,
+    NewTask,
+    EditTodo,
+    NewTodo,
+    PromoteTodo,
+    ToggleTodoStatus,
+    ToggleGitMode,
+    ToggleDiffSplit,
+    BranchPicker,
+    Stash,
+    StashCommand,
+    DraftPr,
+    EditFile,
+    ProjectSearch,
+    OpenSearchMatch,
+    CloseSearch,
+    FileBrowserUp,
+    /// `n` new file, `N` new folder.
+    NewProjectFile,
+    RenameProjectFile,
+    EditPlan,
+    PlanToTasks,
+    EditCommand,
+    NewCommand,
+    ImportCommand,
+    NewHook,
+    EditHook,
+    EditNotes,
+    ToggleNotesRaw,
+    AddNote,
+    OpenInBrowser,
+    Refresh,
+    /// `s` next sort column, `S` reverse the order.
+    SortTable,
+    /// `<` / `>` scroll the table's columns.
+    ScrollColumns,
+    CycleChecks,
+    ToggleResolvedThreads,
+    EditIssue,
+    NewIssue,
+    CommentOnIssue,
+    /// `L` labels, `A` assignees, `M` milestone.
+    IssuePicker,
+    ToggleIssueState,
+    LinkPreview,
+    RemotePicker,
+    JiraFilterPicker,
+    ToggleJiraBoard,
+    /// `t` next column, `T` previous column.
+    JiraBoardMove,
+    /// Select the next attachment or web link of a Jira issue.
+    CycleJiraResources,
+    Transitions,
+    Prompt,
+    StopProcess,
+    JumpToProcessSession,
+    CycleArtifacts,
+    OpenArtifact,
+    ExportProcessReport,
+    SearchProcessOutput,
+    StepProcessMatch,
+    JumpToProcessError,
+    RequestDelete,
+    CheckAuth,
+    RepoSettings,
+    StarterConfig,
+}
+
+/// Where a binding is listed in the help overlay.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Section {
+    /// Keys of the active tab.
+    Tab,
+    Global,
+}
+
+/// A key press a binding answers to. Control must match; Shift is implied
+/// by the character.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Key {
+    code: KeyCode,
+    ctrl: bool,
+}
+
+impl Key {
+    const fn char(c: char) -> Self {
+        Key {
+            code: KeyCode::Char(c),
+            ctrl: false,
+        }
+    }
+
+    const fn ctrl(c: char) -> Self {
+        Key {
+            code: KeyCode::Char(c),
+            ctrl: true,
+        }
+    }
+
+    const fn code(code: KeyCode) -> Self {
+        Key { code, ctrl: false }
+    }
+
+    fn matches(&self, key: &KeyEvent) -> bool {
+        self.code == key.code && self.ctrl == key.modifiers.contains(KeyModifiers::CONTROL)
+    }
+}
+
+#[derive(Clone, Copy)]
+pub struct Binding {
+    /// Keys as shown in hints and help, e.g. `j/k`.
+    pub label: &'static str,
+    keys: &'static [Key],
+    pub action: Action,
+    /// Context the binding applies in.
+    when: fn(&App) -> bool,
+    /// Status bar hint, shown when `when` and `hint_when` hold.
+    pub hint: Option<&'static str>,
+    hint_when: fn(&App) -> bool,
+    pub help: &'static str,
+    pub section: Section,
+}
+
+impl Binding {
+    const fn new(
+        label: &'static str,
+        keys: &'static [Key],
+        action: Action,
+        when: fn(&App) -> bool,
+        help: &'static str,
+    ) -> Self {
+        Binding {
+            label,
+            keys,
+            action,
+            when,
+            hint: None,
+            hint_when: always,
+            help,
+            section: Section::Tab,
+        }
+    }
+
+    const fn hint(mut self, hint: &'static str) -> Self {
+        self.hint = Some(hint);
+        self
+    }
+
+    const fn hint_when(mut self, hint_when: fn(&App) -> bool) -> Self {
+        self.hint_when = hint_when;
+        self
+    }
+
+    const fn global(mut self) -> Self {
+        self.section = Section::Global;
+        self
+    }
+
+    pub fn active(&self, app: &App) -> bool {
+        (self.when)(app)
+    }
+}
+
+/// The action `key` triggers in the current context: the first active
+/// binding for it.
+pub fn action_for(app: &App, key: &KeyEvent) -> Option<Action> {
+    BINDINGS
+        .iter()
+        .find(|b| b.keys.iter().any(|k| k.matches(key)) && b.active(app))
+        .map(|b| b.action)
+}
+
+/// Bindings that run in the current context: active, and with at least
+/// one key not taken by an earlier active binding.
+pub fn active_bindings(app: &App) -> Vec<&'static Binding> {
+    let mut taken: Vec<Key> = Vec::new();
+    let mut found = Vec::new();
+    for binding in BINDINGS.iter().filter(|b| b.active(app)) {
+        let free = binding.keys.iter().any(|k| !taken.contains(k));
+        taken.extend(binding.keys);
+        if free {
+            found.push(binding);
+        }
+    }
+    found
+}
+
+/// Status bar hints for the current context, as `(keys, label)`.
+pub fn hints(app: &App) -> Vec<(&'static str, &'static str)> {
+    active_bindings(app)
+        .into_iter()
+        .filter(|b| (b.hint_when)(app))
+        .filter_map(|b| Some((b.label, b.hint?)))
+        .collect()
+}
+
+const ARROWS_V: &[Key] = &[
+    Key::char('j'),
+    Key::code(KeyCode::Down),
+    Key::char('k'),
+    Key::code(KeyCode::Up),
+];
+const ARROWS_H: &[Key] = &[
+    Key::char('h'),
+    Key::code(KeyCode::Left),
+    Key::char('l'),
+    Key::code(KeyCode::Right),
+];
+const ARROWS_ALL: &[Key] = &[
+    Key::char('h'),
+    Key::code(KeyCode::Left),
+    Key::char('j'),
+    Key::code(KeyCode::Down),
+    Key::char('k'),
+    Key::code(KeyCode::Up),
+    Key::char('l'),
+    Key::code(KeyCode::Right),
+];
+const ENTER: &[Key] = &[Key::code(KeyCode::Enter)];
+const DIGITS: &[Key] = &[
+    Key::char('1'),
+    Key::char('2'),
+    Key::char('3'),
+    Key::char('4'),
+    Key::char('5'),
+    Key::char('6'),
+    Key::char('7'),
+    Key::char('8'),
+    Key::char('9'),
+];
+
+/// Every normal-mode binding. Order decides which of two bindings for the
+/// same key wins and the order of the hints.
+const BINDINGS: &[Binding] = &[
+    // Moving around; tabs with their own names for it come first
+    Binding::new(
+        "h/j/k/l",
+        ARROWS_ALL,
+        Action::Navigate,
+        |app| tab(app, ActiveTab::Overview),
+        "Move between cards",
+    )
+    .hint("cards"),
+    Binding::new(
+        "h/l",
+        ARROWS_H,
+        Action::Navigate,
+        jira_board,
+        "Move between board columns",
+    )
+    .hint("column"),
+    Binding::new(
+        "j/k",
+        ARROWS_V,
+        Action::Navigate,
+        jira_board,
+        "Move between cards in a column",
+    )
+    .hint("card"),
+    Binding::new(
+        "j/k",
+        ARROWS_V,
+        Action::Navigate,
+        plans_outline,
+        "Move between sections",
+    )
+    .hint("section"),
+    Binding::new(
+        "j/k",
+        ARROWS_V,
+        Action::Navigate,
+        sessions_transcript,
+        "Scroll the transcript",
+    )
+    .hint("scroll"),
+    Binding::new(
+        "j/k",
+        ARROWS_V,
+        Action::Navigate,
+        always,
+        "Move through the list / scroll",
+    )
+    .hint("nav")
+    .hint_when(|app| !git_browse(app))
+    .global(),
+    Binding::new("h/l", ARROWS_H, Action::Navigate, always, "Switch panes")
+        .hint("panes")
+        .hint_when(has_panes)
+        .global(),
+    Binding::new(
+        "Enter",
+        ENTER,
+        Action::Select,
+        |app| tab(app, ActiveTab::Overview),
+        "Open the card's tab",
+    )
+    .hint("open tab"),
+    Binding::new(
+        "Enter",
+        ENTER,
+        Action::Select,
+        sessions_list,
+        "Load the session's transcript",
+    )
+    .hint("select"),
+    Binding::new(
+        "Enter",
+        ENTER,
+        Action::Select,
+        sessions_transcript,
+        "Show the transcript item in full",
+    )
+    .hint("full item"),
+    Binding::new(
+        "Enter",
+        ENTER,
+        Action::Select,
+        |app| tab(app, ActiveTab::Teams) && app.teams_pane == TeamsPane::Teams,
+        "Drill into the team",
+    )
+    .hint("drill"),
+    Binding::new(
+        "Enter/t",
+        &[Key::code(KeyCode::Enter), Key::char('t')],
+        Action::MemberTranscript,
+        teams_members,
+        "Open the member's transcript",
+    )
+    .hint("transcript"),
+    Binding::new(
+        "Enter",
+        ENTER,
+        Action::Select,
+        git_browse,
+        "Open the file / expand the folder",
+    )
+    .hint("open"),
+    Binding::new(
+        "Enter",
+        ENTER,
+        Action::Select,
+        plans_outline,
+        "Jump to the section",
+    )
+    .hint("jump"),
+    Binding::new(
+        "Enter",
+        ENTER,
+        Action::Select,
+        |app| jira_board(app) || tab(app, ActiveTab::Azure),
+        "Show the item's details",
+    )
+    .hint("detail"),
+    Binding::new(
+        "Enter",
+        ENTER,
+        Action::Select,
+        |app| tab(app, ActiveTab::Linear),
+        "Open the issue in the browser",
+    ),
+    Binding::new("Enter", ENTER, Action::Select, always, "Select / open").global(),
+    Binding::new(
+        "g/G",
+        &[Key::char('g'), Key::char('G')],
+        Action::JumpEnd,
+        always,
+        "Jump to top / bottom",
+    )
+    .global(),
+    // Sessions
+    Binding::new(
+        "/",
+        &[Key::char('/')],
+        Action::Filter,
+        |app| app.filter_keys().is_some(),
+        "Filter the list",
+    )
+    .hint("filter")
+    .hint_when(|app| !sessions_transcript(app)),
+    Binding::new(
+        "o",
+        &[Key::char('o')],
+        Action::OpenSessionInPane,
+        |app| tab(app, ActiveTab::Sessions),
+        "Open the session in a new terminal pane",
+    )
+    .hint("open in pane")
+    .hint_when(sessions_list),
+    Binding::new(
+        "R",
+        &[Key::char('R')],
+        Action::ResumeSession,
+        |app| tab(app, ActiveTab::Sessions),
+        "Resume the session in the Claude pane / a new tab",
+    )
+    .hint("resume")
+    .hint_when(sessions_list),
+    Binding::new(
+        "e",
+        &[Key::char('e')],
+        Action::OpenFileReference,
+        |app| sessions_transcript(app) || git_status(app),
+        "Open the referenced file at its line",
+    )
+    .hint("open file"),
+    Binding::new(
+        "f",
+        &[Key::char('f')],
+        Action::ToggleFollow,
+        |app| {
+            tab(app, ActiveTab::Sessions)
+                || tab(app, ActiveTab::Processes)
+                || (git_browse(app) && !app.fb_editing)
+        },
+        "Toggle follow mode (in the Git tab's browse mode, the open file's end)",
+    )
+    .hint("follow")
+    .hint_when(|app| {
+        if git_browse(app) {
+            app.fb_content_path.is_some()
+        } else {
+            !sessions_list(app)
+        }
+    }),
+    Binding::new(
+        "u",
+        &[Key::char('u')],
+        Action::JumpToUnread,
+        |app| tab(app, ActiveTab::Sessions),
+        "Jump to new transcript lines",
+    )
+    .hint("new")
+    .hint_when(sessions_transcript),
+    Binding::new(
+        "s",
+        &[Key::char('s')],
+        Action::CycleSubagent,
+        sessions_transcript,
+        "Cycle subagent transcripts",
+    )
+    .hint("subagent"),
+    Binding::new(
+        "T",
+        &[Key::char('T')],
+        Action::ToggleTranslation,
+        |app| tab(app, ActiveTab::Sessions),
+        "Toggle transcript translation",
+    )
+    .hint("translate")
+    .hint_when(sessions_transcript),
+    Binding::new(
+        "U",
+        &[Key::char('U')],
+        Action::ToggleTurnUsage,
+        |app| tab(app, ActiveTab::Sessions),
+        "Toggle the turn tokens / elapsed column",
+    )
+    .hint("usage")
+    .hint_when(sessions_transcript),
+    Binding::new(
+        "w",
+        &[Key::char('w')],
+        Action::ToggleTranscriptWrap,
+        sessions_transcript,
+        "Toggle wrapping long messages",
+    )
+    .hint("wrap"),
+    Binding::new(
+        "S",
+        &[Key::char('S')],
+        Action::ToggleSessionStats,
+        |app| tab(app, ActiveTab::Sessions),
+        "Toggle session stats and activity heatmap",
+    )
+    .hint("stats"),
+    Binding::new(
+        "X",
+        &[Key::char('X')],
+        Action::ExportSession,
+        |app| tab(app, ActiveTab::Sessions),
+        "Export the session as redacted JSONL",
+    )
+    .hint("export")
+    .hint_when(sessions_list),
+    Binding::new(
+        "r",
+        &[Key::char('r')],
+        Action::RenameSession,
+        sessions_list,
+        "Rename the session",
+    )
+    .hint("rename"),
+    Binding::new(
+        "L",
+        &[Key::char('L')],
+        Action::LinkSession,
+        sessions_list,
+        "Link the session to a PR / issue / ticket",
+    )
+    .hint("link"),
+    Binding::new(
+        "b",
+        &[Key::char('b')],
+        Action::GroupSessionsByTicket,
+        sessions_list,
+        "Group sessions by linked ticket",
+    )
+    .hint("by ticket"),
+    Binding::new(
+        "P",
+        &[Key::char('P')],
+        Action::ReviewPlan,
+        |app| tab(app, ActiveTab::Sessions),
+        "Review the latest proposed plan",
+    )
+    .hint("plan")
+    .hint_when(sessions_transcript),
+    // Teams
+    Binding::new(
+        "n",
+        &[Key::char('n')],
+        Action::NewTask,
+        |app| teams_tasks(app) && !app.teams.is_empty(),
+        "New task",
+    )
+    .hint("new task"),
+    Binding::new(
+        "n",
+        &[Key::char('n')],
+        Action::SendToPane,
+        |app| tab(app, ActiveTab::Teams) && app.teams.is_empty(),
+        "Send input to the Claude pane",
+    ),
+    Binding::new(
+        "e",
+        &[Key::char('e')],
+        Action::EditTask,
+        teams_tasks,
+        "Edit the task",
+    )
+    .hint("edit task"),
+    Binding::new(
+        "D",
+        &[Key::char('D')],
+        Action::ToggleTaskGraph,
+        |app| teams_tasks(app) && app.task_graph,
+        "Back to the selected task",
+    )
+    .hint("task"),
+    Binding::new(
+        "D",
+        &[Key::char('D')],
+        Action::ToggleTaskGraph,
+        teams_tasks,
+        "Task dependency graph",
+    )
+    .hint("deps"),
+    Binding::new(
+        "B",
+        &[Key::char('B')],
+        Action::Broadcast,
+        |app| tab(app, ActiveTab::Teams) && !app.teams.is_empty(),
+        "Broadcast a message to every member of the team",
+    )
+    .hint("broadcast"),
+    // Todos
+    Binding::new(
+        "Space",
+        &[Key::char(' ')],
+        Action::ToggleTodoStatus,
+        |app| tab(app, ActiveTab::Todos),
+        "Cycle the item's status",
+    )
+    .hint("status"),
+    Binding::new(
+        "e",
+        &[Key::char('e')],
+        Action::EditTodo,
+        |app| tab(app, ActiveTab::Todos),
+        "Edit the item",
+    )
+    .hint("edit"),
+    Binding::new(
+        "n",
+        &[Key::char('n')],
+        Action::NewTodo,
+        |app| tab(app, ActiveTab::Todos),
+        "New item",
+    )
+    .hint("new"),
+    Binding::new(
+        "t",
+        &[Key::char('t')],
+        Action::PromoteTodo,
+        |app| tab(app, ActiveTab::Todos) && !app.current_todo_items().is_empty(),
+        "Turn the item into a GitHub, Jira or Linear issue",
+    )
+    .hint("ticket"),
+    // Git
+    Binding::new(
+        "/",
+        &[Key::char('/')],
+        Action::ProjectSearch,
+        git_browse,
+        "Search the project's files",
+    )
+    .hint("search"),
+    Binding::new(
+        "e",
+        &[Key::char('e')],
+        Action::OpenSearchMatch,
+        |app| git_search(app) && app.fb_pane == FileBrowserPane::Tree,
+        "Edit the file at the match",
+    )
+    .hint("edit"),
+    Binding::new(
+        "Esc",
+        &[Key::code(KeyCode::Esc)],
+        Action::CloseSearch,
+        git_search,
+        "Close the search results",
+    )
+    .hint("close search"),
+    Binding::new(
+        "e",
+        &[Key::char('e')],
+        Action::EditFile,
+        git_browse,
+        "Edit the file",
+    )
+    .hint("edit"),
+    Binding::new(
+        "Bksp",
+        &[Key::code(KeyCode::Backspace)],
+        Action::FileBrowserUp,
+        |app| git_browse(app) && !git_search(app),
+        "Collapse the folder / go to the parent",
+    )
+    .hint("up"),
+    Binding::new(
+        "b",
+        &[Key::char('b')],
+        Action::ToggleGitMode,
+        git_browse,
+        "Back to git status",
+    )
+    .hint("status"),
+    Binding::new(
+        "n/N",
+        &[Key::char('n'), Key::char('N')],
+        Action::NewProjectFile,
+        |app| git_browse(app) && !git_search(app) && !app.fb_editing,
+        "New file / folder in the project",
+    )
+    .hint("new"),
+    Binding::new(
+        "r",
+        &[Key::char('r')],
+        Action::RenameProjectFile,
+        |app| git_browse_tree(app) && !app.fb_entries.is_empty(),
+        "Rename or move the file / folder",
+    )
+    .hint("rename"),
+    Binding::new(
+        "v",
+        &[Key::char('v')],
+        Action::ToggleDiffSplit,
+        git_status,
+        "Toggle the side-by-side diff",
+    )
+    .hint("split diff"),
+    Binding::new(
+        "b",
+        &[Key::char('b')],
+        Action::ToggleGitMode,
+        git_status,
+        "Browse the project's files",
+    )
+    .hint("browse"),
+    Binding::new(
+        "B",
+        &[Key::char('B')],
+        Action::BranchPicker,
+        |app| tab(app, ActiveTab::Git),
+        "Branch / worktree picker",
+    )
+    .hint("branches")
+    .hint_when(git_status),
+    Binding::new(
+        "z",
+        &[Key::char('z')],
+        Action::Stash,
+        git_status,
+        "Stash all changes, with an optional message",
+    )
+    .hint("stash")
+    .hint_when(|app| git_status(app) && app.git_status.total_files() > 0),
+    Binding::new(
+        "a/p/d",
+        &[Key::char('a'), Key::char('p'), Key::char('d')],
+        Action::StashCommand,
+        |app| git_status(app) && app.selected_stash().is_some(),
+        "Apply / pop / drop the selected stash",
+    )
+    .hint("apply/pop/drop"),
+    Binding::new(
+        "P",
+        &[Key::char('P')],
+        Action::DraftPr,
+        git_status,
+        "Draft a PR description with Claude Code, then create the PR",
+    )
+    .hint("draft PR"),
+    // Plans and Commands
+    Binding::new(
+        "e",
+        &[Key::char('e')],
+        Action::EditPlan,
+        |app| tab(app, ActiveTab::Plans),
+        "Edit the plan",
+    )
+    .hint("edit")
+    .hint_when(|app| !plans_outline(app)),
+    Binding::new(
+        "t",
+        &[Key::char('t')],
+        Action::PlanToTasks,
+        |app| tab(app, ActiveTab::Plans),
+        "Turn the plan's checklist into team tasks",
+    )
+    .hint("to tasks")
+    .hint_when(|app| !plans_outline(app) && !app.teams.is_empty()),
+    Binding::new(
+        "e",
+        &[Key::char('e')],
+        Action::EditCommand,
+        |app| tab(app, ActiveTab::Commands),
+        "Edit the agent / command",
+    )
+    .hint("edit"),
+    Binding::new(
+        "n",
+        &[Key::char('n')],
+        Action::NewCommand,
+        |app| tab(app, ActiveTab::Commands),
+        "New agent / command",
+    )
+    .hint("new"),
+    Binding::new(
+        "I",
+        &[Key::char('I')],
+        Action::ImportCommand,
+        |app| tab(app, ActiveTab::Commands),
+        "Import an agent / command from a URL or repo",
+    )
+    .hint("import"),
+    Binding::new(
+        "n",
+        &[Key::char('n')],
+        Action::NewHook,
+        |app| tab(app, ActiveTab::Hooks),
+        "New hook",
+    )
+    .hint("new"),
+    Binding::new(
+        "e",
+        &[Key::char('e')],
+        Action::EditHook,
+        |app| tab(app, ActiveTab::Hooks) && app.selected_hook().is_some(),
+        "Edit the hook",
+    )
+    .hint("edit"),
+    // Notes
+    Binding::new(
+        "e",
+        &[Key::char('e')],
+        Action::EditNotes,
+        |app| tab(app, ActiveTab::Notes),
+        "Edit the notes",
+    )
+    .hint("edit"),
+    Binding::new(
+        "m",
+        &[Key::char('m')],
+        Action::ToggleNotesRaw,
+        |app| tab(app, ActiveTab::Notes),
+        "Toggle the markdown preview / raw text",
+    )
+    .hint("raw"),
+    // PRs, Issues and the other trackers
+    Binding::new(
+        "n",
+        &[Key::char('n')],
+        Action::NewIssue,
+        |app| tab(app, ActiveTab::GitHubIssues),
+        "New issue",
+    )
+    .hint("new"),
+    Binding::new(
+        "e",
+        &[Key::char('e')],
+        Action::EditIssue,
+        |app| tab(app, ActiveTab::GitHubIssues),
+        "Edit the issue",
+    )
+    .hint("edit"),
+    Binding::new(
+        "o",
+        &[Key::char('o')],
+        Action::OpenInBrowser,
+        |app| {
+            matches!(
+                app.active_tab,
+                ActiveTab::GitHubPRs
+                    | ActiveTab::GitHubIssues
+                    | ActiveTab::Jira
+                    | ActiveTab::Linear
+                    | ActiveTab::Azure
+            )
+        },
+        "Open in the browser / the failing check's log (PRs) / the attachment (Jira)",
+    )
+    .hint("open")
+    .hint_when(|app| !jira_board(app)),
+    Binding::new(
+        "s/S",
+        &[Key::char('s'), Key::char('S')],
+        Action::SortTable,
+        |app| gh_lists(app) && !App::active_list_is_empty(app),
+        "Sort by the next column / reverse the order",
+    )
+    .hint("sort"),
+    Binding::new(
+        "</>",
+        &[Key::char('<'), Key::char('>')],
+        Action::ScrollColumns,
+        gh_lists,
+        "Scroll the table's columns left / right",
+    ),
+    Binding::new(
+        "c",
+        &[Key::char('c')],
+        Action::CycleChecks,
+        |app| tab(app, ActiveTab::GitHubPRs),
+        "Cycle through the failing checks",
+    )
+    .hint("checks"),
+    Binding::new(
+        "R",
+        &[Key::char('R')],
+        Action::ToggleResolvedThreads,
+        |app| tab(app, ActiveTab::GitHubPRs),
+        "Expand / collapse resolved review threads",
+    )
+    .hint("resolved"),
+    Binding::new(
+        "c",
+        &[Key::char('c')],
+        Action::CommentOnIssue,
+        |app| tab(app, ActiveTab::GitHubIssues),
+        "Comment on the issue",
+    )
+    .hint("comment"),
+    Binding::new(
+        "L/A/M",
+        &[Key::char('L'), Key::char('A'), Key::char('M')],
+        Action::IssuePicker,
+        |app| tab(app, ActiveTab::GitHubIssues),
+        "Edit labels / assignees / milestone",
+    )
+    .hint("labels/assignees/milestone"),
+    Binding::new(
+        "x",
+        &[Key::char('x')],
+        Action::ToggleIssueState,
+        |app| tab(app, ActiveTab::GitHubIssues),
+        "Close / reopen the issue",
+    )
+    .hint("close/open"),
+    Binding::new(
+        "V",
+        &[Key::char('V')],
+        Action::LinkPreview,
+        |app| {
+            matches!(
+                app.active_tab,
+                ActiveTab::GitHubPRs | ActiveTab::GitHubIssues | ActiveTab::Jira
+            )
+        },
+        "Preview the links in the selected item",
+    )
+    .hint("links")
+    .hint_when(|app| !jira_board(app)),
+    Binding::new(
+        "t/T",
+        &[Key::char('t'), Key::char('T')],
+        Action::JiraBoardMove,
+        jira_board,
+        "Move the card to the next / previous column",
+    )
+    .hint("move"),
+    Binding::new(
+        "t",
+        &[Key::char('t')],
+        Action::Transitions,
+        |app| tab(app, ActiveTab::Jira),
+        "Transition the issue",
+    )
+    .hint("transition"),
+    Binding::new(
+        "c",
+        &[Key::char('c')],
+        Action::CycleJiraResources,
+        |app| tab(app, ActiveTab::Jira) && app.jira_pane == JiraPane::Detail,
+        "Select the next attachment / web link (o opens it)",
+    )
+    .hint("attachments"),
+    Binding::new(
+        "t",
+        &[Key::char('t')],
+        Action::Transitions,
+        |app| tab(app, ActiveTab::Azure),
+        "Change the work item's state",
+    )
+    .hint("state"),
+    Binding::new(
+        "v",
+        &[Key::char('v')],
+        Action::ToggleJiraBoard,
+        jira_board,
+        "Back to the issue list",
+    )
+    .hint("list"),
+    Binding::new(
+        "v",
+        &[Key::char('v')],
+        Action::ToggleJiraBoard,
+        |app| tab(app, ActiveTab::Jira),
+        "Sprint board",
+    )
+    .hint("board"),
+    Binding::new(
+        "r",
+        &[Key::char('r')],
+        Action::Refresh,
+        |app| tab(app, ActiveTab::Deps),
+        "Check for outdated dependencies",
+    )
+    .hint("check"),
+    Binding::new(
+        "r",
+        &[Key::char('r')],
+        Action::Refresh,
+        |app| {
+            matches!(
+                app.active_tab,
+                ActiveTab::Overview
+                    | ActiveTab::Commands
+                    | ActiveTab::Hooks
+                    | ActiveTab::Settings
+                    | ActiveTab::Notes
+                    | ActiveTab::GitHubPRs
+                    | ActiveTab::GitHubIssues
+                    | ActiveTab::Jira
+                    | ActiveTab::Linear
+                    | ActiveTab::Azure
+            )
+        },
+        "Refresh",
+    )
+    .hint("refresh")
+    .hint_when(|app| !jira_board(app)),
+    Binding::new(
+        "p",
+        &[Key::char('p')],
+        Action::Prompt,
+        |app| tab(app, ActiveTab::Deps),
+        "Update the dependency with Claude Code",
+    )
+    .hint("update with Claude"),
+    Binding::new(
+        "p",
+        &[Key::char('p')],
+        Action::Prompt,
+        |app| {
+            matches!(
+                app.active_tab,
+                ActiveTab::GitHubPRs
+                    | ActiveTab::GitHubIssues
+                    | ActiveTab::Jira
+                    | ActiveTab::Linear
+                    | ActiveTab::Azure
+            )
+        },
+        "Launch Claude Code on the item",
+    )
+    .hint("prompt")
+    .hint_when(|app| !jira_board(app)),
+    // Processes
+    Binding::new(
+        "x",
+        &[Key::char('x')],
+        Action::StopProcess,
+        |app| tab(app, ActiveTab::Processes),
+        "Stop the process (again to force-kill) / cancel a queued one",
+    )
+    .hint("kill"),
+    Binding::new(
+        "s",
+        &[Key::char('s')],
+        Action::JumpToProcessSession,
+        |app| tab(app, ActiveTab::Processes),
+        "Jump to the process's session",
+    )
+    .hint("jump to session"),
+    Binding::new(
+        "c",
+        &[Key::char('c')],
+        Action::CycleArtifacts,
+        |app| tab(app, ActiveTab::Processes),
+        "Cycle through the PRs, issues and commits the run linked",
+    )
+    .hint("artifacts")
+    .hint_when(|app| app.selected_artifacts().len() > 1),
+    Binding::new(
+        "o",
+        &[Key::char('o')],
+        Action::OpenArtifact,
+        |app| tab(app, ActiveTab::Processes),
+        "Open the selected artifact in the browser",
+    )
+    .hint("open")
+    .hint_when(|app| !app.selected_artifacts().is_empty()),
+    Binding::new(
+        "E",
+        &[Key::char('E')],
+        Action::ExportProcessReport,
+        |app| tab(app, ActiveTab::Processes),
+        "Export a finished run's report to a file or its ticket",
+    )
+    .hint("report")
+    .hint_when(|app| app.selected_process().is_some_and(|p| p.is_finished())),
+    Binding::new(
+        "/",
+        &[Key::char('/')],
+        Action::SearchProcessOutput,
+        |app| tab(app, ActiveTab::Processes),
+        "Search the process's output",
+    )
+    .hint("search"),
+    Binding::new(
+        "n N",
+        &[Key::char('n'), Key::char('N')],
+        Action::StepProcessMatch,
+        |app| tab(app, ActiveTab::Processes) && app.process_search.is_some(),
+        "Next / previous search match",
+    )
+    .hint("next match"),
+    Binding::new(
+        "e",
+        &[Key::char('e')],
+        Action::JumpToProcessError,
+        |app| tab(app, ActiveTab::Processes),
+        "Jump to the first stderr line or error in the output",
+    )
+    .hint("error")
+    .hint_when(|app| app.selected_process().is_some_and(|p| p.is_finished())),
+    // Deleting files
+    Binding::new(
+        "d",
+        &[Key::char('d'), Key::code(KeyCode::Delete)],
+        Action::RequestDelete,
+        |app| {
+            matches!(
+                app.active_tab,
+                ActiveTab::Sessions
+                    | ActiveTab::Teams
+                    | ActiveTab::Todos
+                    | ActiveTab::Plans
+                    | ActiveTab::Commands
+            ) || (git_browse_tree(app) && !app.fb_entries.is_empty())
+        },
+        "Delete the file",
+    )
+    .hint("delete")
+    .hint_when(|app| !sessions_transcript(app) && !plans_outline(app)),
+    // Empty list actions
+    Binding::new(
+        "a",
+        &[Key::char('a')],
+        Action::CheckAuth,
+        App::active_list_is_empty,
+        "Check the CLI's auth status",
+    ),
+    Binding::new(
+        "w",
+        &[Key::char('w')],
+        Action::RepoSettings,
+        App::active_list_is_empty,
+        "Open the repo's settings page",
+    ),
+    Binding::new(
+        "S",
+        &[Key::char('S')],
+        Action::StarterConfig,
+        App::active_list_is_empty,
+        "Write a starter config",
+    ),
+    // Everywhere
+    Binding::new(
+        "Tab / Shift+Tab",
+        &[Key::code(KeyCode::Tab), Key::code(KeyCode::BackTab)],
+        Action::CycleTab,
+        always,
+        "Cycle tabs",
+    )
+    .global(),
+    Binding::new(
+        "1-9",
+        DIGITS,
+        Action::JumpToTab,
+        always,
+        "Jump to tab by number",
+    )
+    .global(),
+    Binding::new(
+        "f",
+        &[Key::char('f')],
+        Action::JiraFilterPicker,
+        |app| {
+            tab(app, ActiveTab::Jira)
+                && !app.jira_board_mode
+                && !app.project_config.jira_filter_names().is_empty()
+        },
+        "Switch between the [jira.filters] queries",
+    )
+    .hint("filters"),
+    Binding::new(
+        "O",
+        &[Key::char('O')],
+        Action::RemotePicker,
+        |app| {
+            matches!(
+                app.active_tab,
+                ActiveTab::GitHubPRs | ActiveTab::GitHubIssues
+            )
+        },
+        "Pick the git remote",
+    )
+    .hint("remote")
+    .hint_when(|app| {
+        app.gh_remotes.len() > 1 && !(tab(app, ActiveTab::GitHubPRs) && app.prs_from_bitbucket)
+    }),
+    Binding::new(
+        "F",
+        &[Key::char('F')],
+        Action::ActivityFeed,
+        always,
+        "Activity feed of tracker changes",
+    )
+    .hint("activity")
+    .hint_when(|app| app.unread_activity() > 0)
+    .global(),
+    Binding::new(
+        "z",
+        &[Key::char('z')],
+        Action::UndoDelete,
+        always,
+        "Undo the last delete (within 10s)",
+    )
+    .hint("undo delete")
+    .hint_when(App::can_undo_delete)
+    .global(),
+    Binding::new(
+        "Z",
+        &[Key::char('Z')],
+        Action::Trash,
+        always,
+        "Trash: restore or purge deleted items",
+    )
+    .global(),
+    Binding::new(
+        "i",
+        &[Key::char('i')],
+        Action::SendToPane,
+        |app| !app.send_pending,
+        "Send input to the Claude pane",
+    )
+    .hint("send")
+    .global(),
+    Binding::new(
+        "=",
+        &[Key::char('=')],
+        Action::TogglePaneRatio,
+        always,
+        "Widen the dashboard / Claude pane",
+    )
+    .global(),
+    Binding::new(
+        "^K",
+        &[Key::ctrl('k')],
+        Action::GotoAnything,
+        always,
+        "Goto anything: search every tab's items and files",
+    )
+    .hint("goto")
+    .global(),
+    Binding::new(
+        "^N",
+        &[Key::ctrl('n')],
+        Action::AddNote,
+        |app| app.is_tab_enabled(&ActiveTab::Notes),
+        "Jot a note into the Notes tab",
+    )
+    .global(),
+    Binding::new(
+        "^H",
+        &[Key::char('?'), Key::ctrl('h')],
+        Action::ToggleHelp,
+        always,
+        "Toggle this help (also ?)",
+    )
+    .hint("help")
+    .global(),
+    Binding::new(
+        "q",
+        &[Key::char('q')],
+        Action::Quit,
+        always,
+        "Quit (also Ctrl+C)",
+    )
+    .global(),
+];
+
+fn always(_: &App) -> bool {
+    true
+}
+
+fn tab(app: &App, tab: ActiveTab) -> bool {
+    app.active_tab == tab
+}
+
+fn sessions_list(app: &App) -> bool {
+    tab(app, ActiveTab::Sessions) && app.sessions_pane == SessionsPane::List
+}
+
+fn sessions_transcript(app: &App) -> bool {
+    tab(app, ActiveTab::Sessions) && app.sessions_pane == SessionsPane::Transcript
+}
+
+fn teams_members(app: &App) -> bool {
+    tab(app, ActiveTab::Teams) && app.teams_pane == TeamsPane::Members
+}
+
+fn teams_tasks(app: &App) -> bool {
+    tab(app, ActiveTab::Teams) && matches!(app.teams_pane, TeamsPane::Tasks | TeamsPane::Detail)
+}
+
+fn git_status(app: &App) -> bool {
+    tab(app, ActiveTab::Git) && app.git_mode == GitMode::Status
+}
+
+fn git_browse(app: &App) -> bool {
+    tab(app, ActiveTab::Git) && app.git_mode == GitMode::Browse
+}
+
+/// The file tree has focus, not search results or the content pane.
+fn git_browse_tree(app: &App) -> bool {
+    git_browse(app)
+        && app.fb_pane == FileBrowserPane::Tree
+        && app.grep_query.is_none()
+        && !app.fb_editing
+}
+
+/// Search results in place of the file tree.
+fn git_search(app: &App) -> bool {
+    git_browse(app) && app.grep_query.is_some() && !app.fb_editing
+}
+
+fn plans_outline(app: &App) -> bool {
+    tab(app, ActiveTab::Plans) && app.plans_pane == PlansPane::Outline
+}
+
+fn jira_board(app: &App) -> bool {
+    tab(app, ActiveTab::Jira) && app.jira_board_mode
+}
+
+/// The PRs and Issues tables.
+fn gh_lists(app: &App) -> bool {
+    matches!(
+        app.active_tab,
+        ActiveTab::GitHubPRs | ActiveTab::GitHubIssues
+    )
+}
+
+/// Tabs whose panes h/l switch between.
+fn has_panes(app: &App) -> bool {
+    match app.active_tab {
+        ActiveTab::Teams
+        | ActiveTab::Todos
+        | ActiveTab::Plans
+        | ActiveTab::Commands
+        | ActiveTab::Hooks
+        | ActiveTab::Settings
+        | ActiveTab::Processes => !plans_outline(app),
+        ActiveTab::Git => app.git_mode == GitMode::Status,
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keys_match_control_but_not_shift() {
+        let key = |code, modifiers| KeyEvent::new(code, modifiers);
+        assert!(Key::char('G').matches(&key(KeyCode::Char('G'), KeyModifiers::SHIFT)));
+        assert!(!Key::char('h').matches(&key(KeyCode::Char('h'), KeyModifiers::CONTROL)));
+        assert!(Key::ctrl('h').matches(&key(KeyCode::Char('h'), KeyModifiers::CONTROL)));
+        assert!(!Key::ctrl('h').matches(&key(KeyCode::Char('h'), KeyModifiers::NONE)));
+    }
+
+    #[test]
+    fn every_binding_is_described() {
+        for binding in BINDINGS {
+            assert!(!binding.keys.is_empty(), "{} has no keys", binding.label);
+            assert!(!binding.help.is_empty(), "{} has no help", binding.label);
+            // Global keys are the same on every tab, so one entry each
+            if binding.section == Section::Global {
+                let globals = BINDINGS
+                    .iter()
+                    .filter(|b| b.section == Section::Global && b.label == binding.label)
+                    .count();
+                assert_eq!(globals, 1, "{} listed twice", binding.label);
+            }
+        }
+    }
+}
@@ -2,13 +2,15 @@ mod app;
 mod config;
 mod data;
 mod event;
+mod keymap;
 mod model;
 mod pane_send;
+mod shutdown;
 mod ui;
 mod watcher;
 
 use std::io;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::sync::mpsc;
 use std::time::{Duration, Instant};
 
@@ -22,8 +24,13 @@ use crossterm::terminal::{
 use ratatui::backend::CrosstermBackend;
 use ratatui::Terminal;
 
-use crate::app::App;
+use crate::app::{App, TrashPurge};
+use crate::data::share::MirrorEvent;
 use crate::event::AppEvent;
+use crate::keymap::Action;
+use crate::model::github::IssueMetaField;
+use crate::model::share::{MirrorState, MirrorStatus};
+use crate::pane_send::{PaneRegistry, TerminalBackend, ASSOC_PANE, CLAUDE_PANE};
 
 #[derive(Parser)]
 #[command(
@@ -40,35 +47,126 @@ struct Cli {
     #[arg(long, global = true)]
     cwd: Option<PathBuf>,
 
-    /// Indicate that exactly two WT panes are open (enables pane-send with 'i')
+    /// Indicate that Claude Code runs in a neighbouring pane (enables pane-send with 'i')
     #[arg(long, global = true)]
     two_pane: bool,
+
+    /// Panes by name and index, e.g. assoc=0,claude=1,dev=2 (set by `assoc launch`)
+    #[arg(long, global = true, value_name = "SPEC", value_parser = PaneRegistry::parse)]
+    panes: Option<PaneRegistry>,
+
+    /// Serve the open transcript to `assoc mirror` on ADDR (e.g. 0.0.0.0:7878)
+    #[arg(long, value_name = "ADDR")]
+    share: Option<String>,
+
+    /// Poll less often and skip stats rescans, as on battery
+    #[arg(long)]
+    low_power: bool,
 }
 
 #[derive(clap::Subcommand)]
 enum Command {
-    /// Launch Windows Terminal with Claude Code + Associate side by side
+    /// Open Claude Code + Associate side by side in Windows Terminal, Zellij or WezTerm
     Launch {
-        /// Session ID to resume
+        /// Named [launch.<name>] profile from .assoc.toml
+        #[arg(long)]
+        profile: Option<String>,
+
+        /// Session ID to resume ("latest" continues the most recent)
         #[arg(long)]
         resume: Option<String>,
 
-        /// Claude pane width ratio (0.01-0.99)
-        #[arg(long, default_value_t = 0.5, value_parser = parse_claude_ratio)]
-        claude_ratio: f64,
+        /// Claude pane width ratio (0.01-0.99) [default: 0.5]
+        #[arg(long, value_parser = parse_claude_ratio)]
+        claude_ratio: Option<f64>,
 
-        /// Terminal columns
-        #[arg(long, default_value_t = 200)]
-        cols: u32,
+        /// Terminal columns [default: 200]
+        #[arg(long)]
+        cols: Option<u32>,
 
-        /// Terminal rows
-        #[arg(long, default_value_t = 50)]
-        rows: u32,
+        /// Terminal rows [default: 50]
+        #[arg(long)]
+        rows: Option<u32>,
 
         /// Extra arguments passed to claude (e.g. --dangerously-skip-permissions)
         #[arg(last = true)]
         claude_args: Vec<String>,
     },
+
+    /// Export or import a shareable bundle of the project's Associate setup
+    Config {
+        #[command(subcommand)]
+        action: ConfigAction,
+    },
+
+    /// Export a session transcript for analysis, with secrets redacted
+    Export {
+        /// Session ID or ID prefix (defaults to the most recent session)
+        session: Option<String>,
+
+        /// Output format
+        #[arg(long, default_value = "jsonl", value_parser = ["jsonl"])]
+        format: String,
+
+        /// File to write (stdout if omitted)
+        #[arg(long, short)]
+        output: Option<PathBuf>,
+    },
+
+    /// Watch the transcript another assoc serves with --share, read-only
+    Mirror {
+        /// Host and port of the sharing instance, e.g. 192.168.1.20:7878
+        addr: String,
+    },
+
+    /// Print a snapshot of sessions, agents, git and GitHub for scripts
+    Report {
+        /// Output format
+        #[arg(long, default_value = "json", value_parser = ["json", "markdown"])]
+        format: String,
+
+        /// Number of recent sessions to list
+        #[arg(long, default_value_t = 10)]
+        sessions: usize,
+
+        /// File to write (stdout if omitted)
+        #[arg(long, short)]
+        output: Option<PathBuf>,
+    },
+
+    /// Serve sessions, agents, processes and git status read-only over HTTP
+    Serve {
+        /// Port to listen on
+        #[arg(long, default_value_t = 8080)]
+        port: u16,
+
+        /// Address to listen on; the default is only reachable from this
+        /// machine, 0.0.0.0 reaches other devices on the network
+        #[arg(long, default_value = "127.0.0.1")]
+        host: String,
+
+        /// Number of recent sessions to list
+        #[arg(long, default_value_t = 10)]
+        sessions: usize,
+    },
+}
+
+#[derive(clap::Subcommand)]
+enum ConfigAction {
+    /// Write .assoc.toml and .associate/ into a bundle file (stdout if omitted)
+    Export {
+        /// Bundle file to write
+        file: Option<PathBuf>,
+    },
+    /// Unpack a bundle into the project directory
+    Import {
+        /// Bundle file to read
+        file: PathBuf,
+
+        /// Overwrite files that already exist
+        #[arg(long)]
+        force: bool,
+    },
 }
 
 const HELP_TEXT: &str = "\
@@ -76,61 +174,189 @@ The Associate - Claude Code Session Dashboard
 
 USAGE:
   assoc [OPTIONS]                   Start the TUI dashboard
-  assoc launch [OPTIONS] [-- ...]   Open Windows Terminal with Claude + dashboard
+  assoc launch [OPTIONS] [-- ...]   Open Claude + dashboard in a new terminal tab
+  assoc config export [FILE]        Bundle .assoc.toml + .associate/ (stdout if no FILE)
+  assoc config import FILE          Unpack a bundle into the project (--force overwrites)
+  assoc export [ID] [OPTIONS]       Write a redacted JSONL subset of a session
+  assoc report [OPTIONS]            Print a JSON or Markdown snapshot of the project
+  assoc mirror <ADDR>               Watch the transcript another assoc shares, read-only
+  assoc serve [OPTIONS]             Serve sessions, agents and git read-only over HTTP
 
 MODES:
   (default)   Interactive TUI that monitors Claude Code sessions, teams,
               todos, git status, and plans for the given project directory.
 
-  launch      Opens Windows Terminal with two panes side by side:
+  launch      Opens a new tab with two panes side by side:
               left = Claude Code, right = Associate dashboard.
-              Requires Windows Terminal (wt.exe) to be installed.
+              Uses Zellij or WezTerm when run inside one (pane.backend
+              overrides), otherwise Windows Terminal (wt.exe).
+
+  config      Share a project's Associate setup. `export` bundles
+              .assoc.toml and everything under .associate/ (prompt
+              templates, etc.) into one JSON file; `import` unpacks it
+              into another checkout of the repo.
+
+  export      Writes one JSON object per user prompt or assistant message
+              (role, text, tool names) of a session, with tool results
+              left out and secrets redacted. ID may be a prefix; the most
+              recent session is used when it is omitted.
+
+  report      Prints recent sessions, running agents (working teammates
+              and spawned processes), uncommitted files, PRs requesting
+              your review and issues assigned to you, without starting
+              the TUI. GitHub sections need gh; sources that fail are
+              listed under errors and the rest is still printed.
+
+  mirror      Connects to an instance started with --share and shows
+              the transcript open on its Sessions tab as it grows, so a
+              colleague can follow an agent run without screen sharing.
+              Reconnects when the host goes away. j/k scroll, g/G top /
+              bottom, f follow, q quit.
 
 GLOBAL OPTIONS:
   --cwd <DIR>       Project directory to monitor [default: current dir]
-  --two-pane        Enable two-pane mode (pane send with 'i')
+  --two-pane        Enable two-pane mode (pane send 'i', ratio toggle '=')
+  --panes <SPEC>    Named panes by index, e.g. assoc=0,claude=1,dev=2;
+                    pane send targets them by index (set by launch)
+  --share <ADDR>    Serve the open transcript to `assoc mirror` on ADDR
+                    (e.g. 0.0.0.0:7878); secrets are redacted, no input
+                    is accepted, and there is no authentication: anyone
+                    who can reach ADDR can watch
+  --low-power       Poll less often, tick slower and skip stats rescans,
+                    as happens automatically on battery
   -h, --help        Print this help
   -V, --version     Print version
 
 LAUNCH OPTIONS:
-  --resume <ID>             Resume a Claude Code session by ID
+  --profile <NAME>          Use the [launch.<NAME>] profile from .assoc.toml;
+                            flags below override its values
+  --resume <ID>             Resume a Claude Code session by ID (\"latest\"
+                            continues the most recent one)
   --claude-ratio <FLOAT>    Claude pane width ratio, 0.01-0.99 [default: 0.5]
   --cols <N>                Terminal columns [default: 200]
   --rows <N>                Terminal rows [default: 50]
   -- <ARGS>...              Extra arguments passed to claude
                             (e.g. -- --dangerously-skip-permissions)
 
+EXPORT OPTIONS:
+  --format <FORMAT>         Output format [default: jsonl]
+  -o, --output <FILE>       File to write [default: stdout]
+
+REPORT OPTIONS:
+  --format <FORMAT>         json or markdown [default: json]
+  --sessions <N>            Recent sessions to list [default: 10]
+  -o, --output <FILE>       File to write [default: stdout]
+
 TUI KEYBINDINGS:
   1-9                Jump to tab by number
   Tab / Shift+Tab    Cycle tabs
   j/k  Up/Down       Navigate list / scroll content
   h/l  Left/Right    Switch panes
   Enter              Select item / open content pane
-  g / G              Jump to top / bottom
-  f                  Toggle follow mode (Sessions tab)
-  o                  Open session in new WT pane (Sessions tab)
+                     On Overview, h/j/k/l pick a card and Enter opens its tab
+  g / G              Jump to top / bottom; G on PRs, Issues and Linear loads
+                     the next page
+  f                  Toggle follow mode (Sessions / Processes tabs)
+                     On Jira with [jira.filters] set, switches the filter
+  u                  Jump to new transcript lines since follow was off (Sessions tab)
+  o                  Open session in a new pane (Sessions tab)
+  R                  Resume session: Claude pane or new tab (Sessions tab)
   s                  Cycle subagent transcripts (Sessions tab)
+  Enter              Show the transcript item at the cursor in full (Sessions tab)
+  e                  Open the referenced file at its line (Sessions transcript / Git diff)
+  T                  Toggle transcript translation (Sessions tab)
+  U                  Toggle turn tokens/elapsed column (Sessions tab)
+  S                  Toggle session stats and activity heatmap (Sessions tab)
+  X                  Export session as redacted JSONL to ~/.assoc/exports/ (Sessions tab)
+  r                  Rename the selected session (Sessions tab)
+  P                  Review latest proposed plan: a accept, r reject (Sessions tab)
   b                  Toggle file browser (Git tab)
+  /                  Search the project's files (file browser): e edit match, Esc close
+  B                  Branch / worktree picker (Git tab)
+                     Enter switch, n new branch, w new worktree
+  z                  Stash all changes (Git tab)
+  a / p / d          Apply / pop / drop the selected stash (Git tab)
+  P                  Draft a PR description with Claude Code (Git tab)
+  O                  Pick the git remote the GitHub tabs use (PRs / Issues tabs)
+  V                  Preview links in the selected item: j/k pick, o browser (PRs / Issues / Jira)
   e                  Edit file (file browser, Content pane)
   Ctrl+S / Esc       Save / cancel edit (file browser)
+  n / N / r / d      New file / new folder / rename / trash (file browser)
   n                  New issue (Issues tab)
   e                  Edit issue (Issues tab) / file (browser)
-  c                  Comment on issue (Issues tab)
-  p                  Launch Claude Code prompt (PRs / Issues / Linear / Jira)
-  x                  Close/reopen issue (Issues tab) / Kill process (Processes tab)
-  d / Del            Delete file (Sessions / Teams / Todos / Plans)
-  o                  Open in browser (PRs / Issues / Jira / Linear)
-  r                  Refresh data (PRs / Issues / Jira / Linear)
-  t                  Show transitions (Jira)
-  /                  Search issues (Jira)
+                     If the issue changed upstream, saving asks: o overwrite,
+                     m merge by hand, c post as comment, Esc back
+  c                  Comment on issue (Issues) / cycle failing checks (PRs)
+  R                  Expand / collapse resolved review threads (PRs tab)
+  L / A / M          Edit labels / assignees / milestone (Issues tab)
+  s / S              Sort by the next column / reverse the order (PRs / Issues)
+  < / >              Scroll table columns on narrow terminals (PRs / Issues)
+  p                  Launch Claude Code prompt (PRs / Issues / Linear / Jira / Work Items)
+                     On Deps, prompts Claude to update the selected dependency
+  x                  Close/reopen issue (Issues tab) / Stop or cancel a queued process
+                     (Processes tab)
+  E                  Export a finished run's report (Processes tab): f save as
+                     Markdown, c comment on the ticket
+  Space              Cycle todo status pending/in progress/done (Todos tab)
+  e / n              Edit / add task (Teams tab, Tasks pane; Tab next field, Ctrl+S save)
+  D                  Task dependency graph / selected task (Teams tab, Tasks pane)
+  Enter / t          Member's transcript (Teams tab, Members pane)
+  e / n              Edit / add todo item (Todos tab)
+  t                  Turn todo item into a GitHub / Jira / Linear issue (Todos tab)
+  e                  Edit plan (Plans tab; Ctrl+S save, Esc cancel)
+                     h/l reach the outline; j/k there jump between sections
+  e / n              Edit / create command or agent (Commands tab)
+                     Tab cycles project/user command/agent while naming
+  I                  Import an agent / command from a URL or shared repo
+                     (Commands tab); review it, Enter import, o overwrite
+  e / n              Edit / add hook (Hooks tab; Tab next field, ←/→ choose
+                     scope and event, Enter save)
+  e / m              Edit notes / toggle raw text (Notes tab; Ctrl+S save)
+  Ctrl+N             Jot a note into .associate/notes.md from any tab
+  d / Del            Move to the trash (Sessions / Teams / Todos / Plans / Commands)
+                     Small files go at once; team directories and large files ask
+  z                  Undo the last delete (within 10s)
+  Z                  Trash: Enter restore, d purge, D empty (.associate/trash/)
+  o                  Open in browser (PRs / Issues / Jira / Linear / Work Items)
+                     From PR detail pane, opens the selected failing check log
+  F                  Activity feed: changes to PRs / Issues / Jira / Linear
+                     since the last poll (Enter jumps to the item)
+  r                  Refresh data (Overview / PRs / Issues / Jira / Linear / Work Items /
+                     Commands / Hooks / Settings / Notes)
+                     On Deps, runs the dependency checkers
+  a                  Check gh / acli / az auth status (empty PRs / Issues / Jira / Work Items)
+  w                  Open repo settings (empty PRs / Issues)
+  S                  Write a starter .assoc.toml (empty issue tabs)
+  n                  Create a team via Claude pane (empty Teams tab)
+  t                  Show transitions (Jira) / state changes (Work Items)
+  c                  Select the next attachment / web link (Jira detail pane);
+                     o downloads and opens it
+  v                  Toggle side-by-side diff (Git) / sprint board (Jira);
+                     t / T move card right / left
+  /                  Filter list, e.g. label:bug updated:<2d
+                     (Sessions / PRs / Issues / Jira / Linear)
   i                  Send input to Claude pane
+                     1-9 on an empty bar (Alt+1-9 anytime) sends a snippet
+                     Tab switches to another named pane (--panes)
+  =                  Toggle pane ratio (widen dashboard / Claude pane)
   ?                  Toggle help overlay
   q / Ctrl+C         Quit
 
 EXAMPLES:
   assoc --cwd C:\\dev\\myproject
   assoc launch --cwd C:\\dev\\myproject -- --dangerously-skip-permissions
-  assoc launch --resume abc123 --claude-ratio 0.6";
+  assoc launch --resume abc123 --claude-ratio 0.6
+  assoc launch --profile dev
+  assoc export abc123 -o session.jsonl
+  assoc report --format markdown -o digest.md
+  assoc --share 0.0.0.0:7878
+  assoc mirror 192.168.1.20:7878
+  assoc serve --port 8080";
+
+/// `assoc launch` defaults when neither a flag nor the profile sets them.
+const LAUNCH_CLAUDE_RATIO: f64 = 0.5;
+const LAUNCH_COLS: u32 = 200;
+const LAUNCH_ROWS: u32 = 50;
 
 fn parse_claude_ratio(s: &str) -> Result<f64, String> {
     let v: f64 = s
@@ -152,14 +378,186 @@ fn main() -> Result<()> {
 
     match cli.command {
         Some(Command::Launch {
+            profile,
             resume,
             claude_ratio,
             cols,
             rows,
             claude_args,
-        }) => launch_wt(&project_cwd, resume, claude_ratio, cols, rows, &claude_args),
-        None => run_tui(project_cwd, cli.two_pane),
+        }) => {
+            let project_config = config::load_project_config(&project_cwd);
+            let mut launch = match profile {
+                Some(name) => project_config
+                    .launch_profile(&name)
+                    .map_err(anyhow::Error::msg)?
+                    .clone(),
+                None => config::LaunchProfile::default(),
+            };
+            // Flags win over the profile
+            launch.resume = resume.or(launch.resume);
+            launch.claude_ratio = claude_ratio.or(launch.claude_ratio);
+            launch.cols = cols.or(launch.cols);
+            launch.rows = rows.or(launch.rows);
+            launch.claude_args.extend(claude_args);
+            match project_config.terminal_backend() {
+                TerminalBackend::WindowsTerminal => launch_wt(&project_cwd, &launch),
+                TerminalBackend::Zellij => {
+                    launch_zellij(&project_cwd, &launch, project_config.send_direction())
+                }
+                TerminalBackend::WezTerm => launch_wezterm(&project_cwd, &launch),
+            }
+        }
+        Some(Command::Config { action }) => run_config_action(&project_cwd, action),
+        Some(Command::Export {
+            session,
+            format,
+            output,
+        }) => run_export(&project_cwd, session.as_deref(), &format, output),
+        Some(Command::Report {
+            format,
+            sessions,
+            output,
+        }) => run_report(&project_cwd, &format, sessions, output),
+        Some(Command::Mirror { addr }) => run_mirror(addr),
+        Some(Command::Serve {
+            port,
+            host,
+            sessions,
+        }) => run_serve(&project_cwd, &host, port, sessions),
+        None => run_tui(
+            project_cwd,
+            cli.two_pane,
+            cli.panes,
+            cli.share,
+            cli.low_power,
+        ),
+    }
+}
+
+fn run_config_action(project_cwd: &Path, action: ConfigAction) -> Result<()> {
+    match action {
+        ConfigAction::Export { file } => {
+            let bundle = data::config_bundle::export_bundle(project_cwd)?;
+            let json = serde_json::to_string_pretty(&bundle)?;
+            match file {
+                Some(path) => {
+                    std::fs::write(&path, json)?;
+                    eprintln!(
+                        "Exported {} file(s) to {}",
+                        bundle.files.len(),
+                        path.display()
+                    );
+                }
+                None => println!("{}", json),
+            }
+        }
+        ConfigAction::Import { file, force } => {
+            let data = std::fs::read_to_string(&file)?;
+            let bundle: data::config_bundle::ConfigBundle = serde_json::from_str(&data)?;
+            let written = data::config_bundle::import_bundle(project_cwd, &bundle, force)?;
+            for path in &written {
+                eprintln!("  wrote {}", path.display());
+            }
+            eprintln!("Imported {} file(s)", written.len());
+        }
+    }
+    Ok(())
+}
+
+fn run_export(
+    project_cwd: &Path,
+    session: Option<&str>,
+    format: &str,
+    output: Option<PathBuf>,
+) -> Result<()> {
+    if format != "jsonl" {
+        anyhow::bail!("unsupported export format '{}'", format);
+    }
+    let encoded = data::path_encoding::encode_project_path(project_cwd);
+    let project_dirs: Vec<PathBuf> = config::load_project_config(project_cwd)
+        .claude_homes()
+        .iter()
+        .map(|home| home.path.join("projects").join(&encoded))
+        .collect();
+    let transcript = data::session_export::find_transcript(&project_dirs, session)?;
+    match output {
+        Some(path) => {
+            let count = data::session_export::export_to_file(&transcript, &path)?;
+            eprintln!("Exported {} record(s) to {}", count, path.display());
+        }
+        None => {
+            data::session_export::export_jsonl(&transcript, &mut io::stdout().lock())?;
+        }
+    }
+    Ok(())
+}
+
+fn run_report(
+    project_cwd: &Path,
+    format: &str,
+    sessions: usize,
+    output: Option<PathBuf>,
+) -> Result<()> {
+    let report = data::report::build_report(project_cwd, sessions);
+    let text = match format {
+        "json" => serde_json::to_string_pretty(&report)?,
+        "markdown" => data::report::to_markdown(&report),
+        other => anyhow::bail!("unsupported report format '{}'", other),
+    };
+    match output {
+        Some(path) => {
+            std::fs::write(&path, text)?;
+            eprintln!("Wrote report to {}", path.display());
+        }
+        None => println!("{}", text.trim_end()),
+    }
+    Ok(())
+}
+
+/// Serve the dashboard for `assoc serve`, rebuilding it when the watcher
+/// sees a change and every `SERVE_REFRESH_SECS` for what it doesn't watch.
+fn run_serve(project_cwd: &Path, host: &str, port: u16, sessions: usize) -> Result<()> {
+    let project_config = config::load_project_config(project_cwd);
+    let snapshot = || data::serve::snapshot(project_cwd, sessions);
+    let server = data::serve::DashboardServer::start(&format!("{}:{}", host, port), snapshot())?;
+    let (tx, rx) = mpsc::channel();
+    let _watcher = watcher::start_watcher(
+        project_config
+            .claude_homes()
+            .iter()
+            .map(|h| h.path.clone())
+            .collect(),
+        data::path_encoding::encode_project_path(project_cwd),
+        project_cwd.to_path_buf(),
+        tx,
+        &project_config.tabs,
+    )?;
+    eprintln!(
+        "Serving {} on http://{} (Ctrl+C to stop)",
+        project_cwd.display(),
+        server.addr()
+    );
+
+    shutdown::install();
+    let mut refreshed = Instant::now();
+    let mut pending = false;
+    while !shutdown::requested() {
+        match rx.recv_timeout(Duration::from_secs(1)) {
+            Ok(_) => pending = true,
+            Err(mpsc::RecvTimeoutError::Timeout) => {}
+            Err(mpsc::RecvTimeoutError::Disconnected) => break,
+        }
+        // A busy session writes often, so changes wait out SERVE_MIN_GAP_MS
+        let since = refreshed.elapsed();
+        if since >= Duration::from_secs(config::SERVE_REFRESH_SECS)
+            || (pending && since >= Duration::from_millis(config::SERVE_MIN_GAP_MS))
+        {
+            server.publish(snapshot());
+            refreshed = Instant::now();
+            pending = false;
+        }
     }
+    Ok(())
 }
 
 fn resolve_cwd(cwd: Option<PathBuf>) -> Result<PathBuf> {
@@ -179,7 +577,13 @@ fn resolve_cwd(cwd: Option<PathBuf>) -> Result<PathBuf> {
     }
 }
 
-fn run_tui(project_cwd: PathBuf, two_pane: bool) -> Result<()> {
+fn run_tui(
+    project_cwd: PathBuf,
+    two_pane: bool,
+    panes: Option<PaneRegistry>,
+    share: Option<String>,
+    low_power: bool,
+) -> Result<()> {
     // Setup terminal
     enable_raw_mode()?;
     let mut stdout = io::stdout();
@@ -188,12 +592,22 @@ fn run_tui(project_cwd: PathBuf, two_pane: bool) -> Result<()> {
     let mut terminal = Terminal::new(backend)?;
 
     // Run app
-    let result = run_app(&mut terminal, project_cwd, two_pane);
-
-    // Restore terminal
-    disable_raw_mode()?;
-    execute!(terminal.backend_mut(), LeaveAlternateScreen)?;
-    terminal.show_cursor()?;
+    shutdown::install();
+    let result = run_app(
+        &mut terminal,
+        project_cwd,
+        two_pane,
+        panes,
+        share,
+        low_power,
+    );
+
+    // Restore terminal; this can fail when the terminal is already gone
+    let restored = disable_raw_mode()
+        .and_then(|_| execute!(terminal.backend_mut(), LeaveAlternateScreen))
+        .and_then(|_| terminal.show_cursor());
+    shutdown::finished();
+    restored?;
 
     if let Err(ref e) = result {
         eprintln!("Error: {}", e);
@@ -201,39 +615,123 @@ fn run_tui(project_cwd: PathBuf, two_pane: bool) -> Result<()> {
     result
 }
 
-fn launch_wt(
-    project_cwd: &PathBuf,
-    resume: Option<String>,
-    claude_ratio: f64,
-    cols: u32,
-    rows: u32,
-    claude_args: &[String],
-) -> Result<()> {
-    // Find our own exe to spawn in the assoc pane
-    let self_exe = std::env::current_exe()?;
-    let dir = project_cwd.to_string_lossy();
+/// `assoc mirror`: show another instance's shared transcript until `q`.
+fn run_mirror(addr: String) -> Result<()> {
+    let (tx, rx) = mpsc::channel();
+    data::share::spawn_mirror_reader(addr.clone(), tx);
+    let mut mirror = MirrorState::new(addr);
 
-    // Build claude arguments
-    let mut claude_cmd_args: Vec<String> = Vec::new();
-    if let Some(ref session_id) = resume {
-        claude_cmd_args.push("--resume".to_string());
-        claude_cmd_args.push(session_id.clone());
-    }
-    // Append any extra args passed after --
-    claude_cmd_args.extend_from_slice(claude_args);
+    enable_raw_mode()?;
+    let mut stdout = io::stdout();
+    execute!(stdout, EnterAlternateScreen)?;
+    let mut terminal = Terminal::new(CrosstermBackend::new(stdout))?;
+    shutdown::install();
+
+    let result = (|| -> Result<()> {
+        let mut dirty = true;
+        while !shutdown::requested() {
+            let size = terminal.size()?;
+            let height =
+                size.height
+                    .saturating_sub(ui::mirror_view::MIRROR_CHROME_ROWS) as usize;
+            if height != mirror.height {
+                mirror.height = height;
+                dirty = true;
+            }
+            if dirty {
+                terminal.draw(|f| ui::mirror_view::draw_mirror(f, &mirror))?;
+                dirty = false;
+            }
+            if ct_event::poll(Duration::from_millis(config::TICK_RATE_MS))? {
+                if let Event::Key(key) = ct_event::read()? {
+                    if key.kind != KeyEventKind::Press {
+                        continue;
+                    }
+                    let page = mirror.height.max(1);
+                    match key.code {
+                        KeyCode::Char('q') | KeyCode::Esc => break,
+                        KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                            break
+                        }
+                        KeyCode::Char('j') | KeyCode::Down => mirror.scroll_by(true, 1),
+                        KeyCode::Char('k') | KeyCode::Up => mirror.scroll_by(false, 1),
+                        KeyCode::PageDown => mirror.scroll_by(true, page),
+                        KeyCode::PageUp => mirror.scroll_by(false, page),
+                        KeyCode::Char('g') => mirror.jump_top(),
+                        KeyCode::Char('G') => mirror.follow = true,
+                        KeyCode::Char('f') => mirror.follow = !mirror.follow,
+                        _ => {}
+                    }
+                    dirty = true;
+                }
+            }
+            while let Ok(event) = rx.try_recv() {
+                match event {
+                    MirrorEvent::Connected => mirror.status = MirrorStatus::Connected,
+                    MirrorEvent::Message(message) => mirror.apply(message),
+                    MirrorEvent::Disconnected(reason) => {
+                        mirror.status = MirrorStatus::Disconnected(reason)
+                    }
+                }
+                dirty = true;
+            }
+        }
+        Ok(())
+    })();
+
+    let restored = disable_raw_mode()
+        .and_then(|_| execute!(terminal.backend_mut(), LeaveAlternateScreen))
+        .and_then(|_| terminal.show_cursor());
+    shutdown::finished();
+    restored?;
+    result
+}
 
-    let claude_full = if claude_cmd_args.is_empty() {
+/// The claude command line for a launch: the resume flags, then the
+/// profile's arguments.
+fn launch_claude_command(launch: &config::LaunchProfile) -> String {
+    let mut claude_cmd_args = launch.resume_args();
+    claude_cmd_args.extend_from_slice(&launch.claude_args);
+    if claude_cmd_args.is_empty() {
         "claude".to_string()
     } else {
         format!("claude {}", claude_cmd_args.join(" "))
-    };
+    }
+}
+
+/// Panes are numbered in the order they open: assoc, claude, then the
+/// profile's panes. Named ones go in the registry pane send targets by.
+fn launch_registry(launch: &config::LaunchProfile) -> PaneRegistry {
+    let mut registry = PaneRegistry::default();
+    registry.push(ASSOC_PANE, 0);
+    registry.push(CLAUDE_PANE, 1);
+    for (i, pane) in launch.panes.iter().enumerate() {
+        if let Some(ref name) = pane.name {
+            registry.push(name, i as u32 + 2);
+        }
+    }
+    registry
+}
+
+fn launch_wt(project_cwd: &Path, launch: &config::LaunchProfile) -> Result<()> {
+    // Find our own exe to spawn in the assoc pane
+    let self_exe = std::env::current_exe()?;
+    let dir = project_cwd.to_string_lossy();
+    let claude_full = launch_claude_command(launch);
+    let registry = launch_registry(launch);
 
     // wt.exe new-tab: assoc (right/initial pane)
     // split-pane: claude (left pane, takes claude_ratio of width)
+    // focus-pane + split-pane per profile pane: split the pane it is from
     // focus-pane: focus claude pane
-    let status = std::process::Command::new("wt.exe")
+    let mut command = std::process::Command::new("wt.exe");
+    command
         .arg("--size")
-        .arg(format!("{},{}", cols, rows))
+        .arg(format!(
+            "{},{}",
+            launch.cols.unwrap_or(LAUNCH_COLS),
+            launch.rows.unwrap_or(LAUNCH_ROWS)
+        ))
         .arg("new-tab")
         .arg("--title")
         .arg("The Associate")
@@ -243,18 +741,48 @@ fn launch_wt(
         .arg(&self_exe)
         .arg("--cwd")
         .arg(&*dir)
-        .arg("--two-pane")
+        .arg("--panes")
+        .arg(registry.to_string())
         .arg(";")
         .arg("split-pane")
         .arg("-V")
         .arg("-s")
-        .arg(format!("{}", claude_ratio))
+        .arg(format!(
+            "{}",
+            launch.claude_ratio.unwrap_or(LAUNCH_CLAUDE_RATIO)
+        ))
         .arg("--title")
         .arg("Claude Code")
         .arg("-d")
         .arg(&*dir)
         .arg("--")
-        .args(claude_full.split_whitespace())
+        .args(claude_full.split_whitespace());
+    for pane in &launch.panes {
+        let pane_dir = match &pane.cwd {
+            Some(cwd) => project_cwd.join(cwd),
+            None => project_cwd.to_path_buf(),
+        };
+        let from = pane.from.as_deref().unwrap_or(CLAUDE_PANE);
+        command
+            .arg(";")
+            .arg("focus-pane")
+            .arg("-t")
+            .arg(registry.index(from).unwrap_or(1).to_string())
+            .arg(";")
+            .arg("split-pane")
+            .arg(if pane.splits_right() { "-V" } else { "-H" })
+            .arg("-s")
+            .arg(format!("{}", pane.size.unwrap_or(0.5)))
+            .arg("--title")
+            .arg(pane.display_title())
+            .arg("-d")
+            .arg(&pane_dir);
+        // Without a command WT opens its default profile's shell
+        if let Some(ref cmd) = pane.command {
+            command.arg("--").args(cmd.split_whitespace());
+        }
+    }
+    let status = command
         .arg(";")
         .arg("focus-pane")
         .arg("-t")
@@ -271,120 +799,453 @@ fn launch_wt(
     }
 }
 
+/// Open the launch layout in a new Zellij tab. Zellij can't focus a pane by
+/// number, so Claude Code goes on the `claude_side` of the dashboard, where
+/// pane send finds it by direction.
+fn launch_zellij(
+    project_cwd: &Path,
+    launch: &config::LaunchProfile,
+    claude_side: &str,
+) -> Result<()> {
+    let self_exe = std::env::current_exe()?;
+    let dir = project_cwd.to_string_lossy().to_string();
+    let assoc = vec![
+        self_exe.to_string_lossy().to_string(),
+        "--cwd".to_string(),
+        dir.clone(),
+        "--two-pane".to_string(),
+    ];
+    let claude: Vec<String> = launch_claude_command(launch)
+        .split_whitespace()
+        .map(str::to_string)
+        .collect();
+    let layout = pane_send::zellij_layout(project_cwd, &assoc, &claude, launch, claude_side);
+
+    let path = std::env::temp_dir().join(format!("assoc-launch-{}.kdl", std::process::id()));
+    std::fs::write(&path, layout)?;
+    let status = std::process::Command::new("zellij")
+        .args(["action", "new-tab", "--name", "The Associate"])
+        .args(["--cwd", &dir])
+        .arg("--layout")
+        .arg(&path)
+        .status();
+    let _ = std::fs::remove_file(&path);
+
+    match status {
+        Ok(s) if s.success() => Ok(()),
+        Ok(s) => anyhow::bail!("zellij exited with {}", s),
+        Err(e) => anyhow::bail!("Failed to run zellij: {}. Is Zellij installed?", e),
+    }
+}
+
+/// Open the launch layout in a new WezTerm tab with `wezterm cli`, splitting
+/// panes by the ids each split prints.
+fn launch_wezterm(project_cwd: &Path, launch: &config::LaunchProfile) -> Result<()> {
+    let self_exe = std::env::current_exe()?;
+    let dir = project_cwd.to_string_lossy().to_string();
+    let registry = launch_registry(launch);
+    let percent = |share: f64| format!("{}", (share * 100.0).round());
+    let claude_percent = percent(launch.claude_ratio.unwrap_or(LAUNCH_CLAUDE_RATIO));
+
+    let assoc = wezterm_spawn(
+        std::process::Command::new("wezterm")
+            .args(["cli", "spawn", "--cwd", &dir, "--"])
+            .arg(&self_exe)
+            .args(["--cwd", &dir, "--panes", &registry.to_string()]),
+    )?;
+    let claude = wezterm_spawn(
+        std::process::Command::new("wezterm")
+            .args(["cli", "split-pane", "--pane-id", &assoc, "--left"])
+            .args(["--percent", &claude_percent])
+            .args(["--cwd", &dir, "--"])
+            .args(launch_claude_command(launch).split_whitespace()),
+    )?;
+
+    // Pane ids in opening order, so `from` names index into them
+    let mut ids = vec![assoc, claude.clone()];
+    for pane in &launch.panes {
+        let pane_dir = match &pane.cwd {
+            Some(cwd) => project_cwd.join(cwd),
+            None => project_cwd.to_path_buf(),
+        };
+        let from = pane.from.as_deref().unwrap_or(CLAUDE_PANE);
+        let from = registry.index(from).unwrap_or(1) as usize;
+        let side = if pane.splits_right() {
+            "--right"
+        } else {
+            "--bottom"
+        };
+        let mut command = std::process::Command::new("wezterm");
+        command
+            .args(["cli", "split-pane", "--pane-id", &ids[from]])
+            .arg(side)
+            .args(["--percent", &percent(pane.size.unwrap_or(0.5))])
+            .arg("--cwd")
+            .arg(&pane_dir);
+        // Without a command WezTerm opens the default shell
+        if let Some(ref cmd) = pane.command {
+            command.arg("--").args(cmd.split_whitespace());
+        }
+        ids.push(wezterm_spawn(&mut command)?);
+    }
+
+    let status = std::process::Command::new("wezterm")
+        .args(["cli", "activate-pane", "--pane-id", &claude])
+        .status()?;
+    if !status.success() {
+        anyhow::bail!("wezterm cli activate-pane exited with {}", status);
+    }
+    Ok(())
+}
+
+/// Run a `wezterm cli spawn` or `split-pane` and return the new pane's id.
+fn wezterm_spawn(command: &mut std::process::Command) -> Result<String> {
+    let output = command
+        .output()
+        .map_err(|e| anyhow::anyhow!("Failed to run wezterm: {}. Is WezTerm installed?", e))?;
+    if !output.status.success() {
+        anyhow::bail!(
+            "wezterm cli failed: {}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+/// Run an external editor in the foreground, handing it the terminal until
+/// it exits.
+fn run_editor(
+    terminal: &mut Terminal<CrosstermBackend<io::Stdout>>,
+    command: &[String],
+) -> Result<()> {
+    let Some((program, args)) = command.split_first() else {
+        anyhow::bail!("editor.command is empty");
+    };
+    disable_raw_mode()?;
+    execute!(terminal.backend_mut(), LeaveAlternateScreen)?;
+    let status = std::process::Command::new(program).args(args).status();
+    enable_raw_mode()?;
+    execute!(terminal.backend_mut(), EnterAlternateScreen)?;
+    terminal.clear()?;
+    match status {
+        Ok(s) if s.success() => Ok(()),
+        Ok(s) => anyhow::bail!("{} exited with {}", program, s),
+        Err(e) => anyhow::bail!("Failed to run {}: {}", program, e),
+    }
+}
+
+/// Tick and integration poll intervals, re-read when the config or
+/// low-power mode changes.
+struct PollIntervals {
+    tick_rate: Duration,
+    gh_poll: Option<Duration>,
+    /// PRs tab: `gh_poll`, or Bitbucket's own interval.
+    pr_poll: Option<Duration>,
+    jira_poll: Option<Duration>,
+    linear_poll: Option<Duration>,
+    azure_poll: Option<Duration>,
+}
+
+impl PollIntervals {
+    fn from_config(config: &config::ProjectConfig, low_power: bool) -> Self {
+        let poll = |interval| config::effective_poll(interval, low_power);
+        Self {
+            tick_rate: Duration::from_millis(config.effective_tick_rate(low_power)),
+            gh_poll: poll(config.github_poll_interval()),
+            pr_poll: poll(if config.prs_from_bitbucket() {
+                config.bitbucket_poll_interval()
+            } else {
+                config.github_poll_interval()
+            }),
+            jira_poll: poll(config.jira_poll_interval()),
+            linear_poll: poll(config.linear_poll_interval()),
+            azure_poll: poll(config.azure_poll_interval()),
+        }
+    }
+}
+
+fn start_app_watcher(
+    app: &App,
+    tx: mpsc::Sender<AppEvent>,
+) -> Result<notify_debouncer_mini::Debouncer<notify::RecommendedWatcher>> {
+    watcher::start_watcher(
+        app.claude_homes.iter().map(|h| h.path.clone()).collect(),
+        app.encoded_project.clone(),
+        app.project_cwd.clone(),
+        tx,
+        &app.project_config.tabs,
+    )
+}
+
 fn run_app(
     terminal: &mut Terminal<CrosstermBackend<io::Stdout>>,
     project_cwd: PathBuf,
     two_pane: bool,
+    panes: Option<PaneRegistry>,
+    share: Option<String>,
+    low_power: bool,
 ) -> Result<()> {
     let mut app = App::new(project_cwd);
-    app.two_pane = two_pane;
+    app.two_pane = two_pane || panes.is_some();
+    app.pane_registry = panes;
+    app.low_power_flag = low_power;
+    if let Some(addr) = share {
+        let project = app
+            .project_cwd
+            .file_name()
+            .map(|n| n.to_string_lossy().to_string())
+            .unwrap_or_default();
+        let server = data::share::ShareServer::start(&addr, project)
+            .map_err(|e| anyhow::anyhow!("Failed to share on {}: {}", addr, e))?;
+        app.share = Some(server);
+    }
 
     // Create event channel before initial load so async spawners can send results
     let (tx, rx) = mpsc::channel::<AppEvent>();
     app.event_tx = Some(tx.clone());
 
-    // Initial data load (async loaders will send results through the channel)
-    app.load_all();
+    // Load Sessions and the active tab now; the rest load once per tick
+    // after the first frame (async loaders send results through the channel)
+    app.start_loading();
 
     // Setup file watcher (skips directories for disabled tabs)
-    let _debouncer = watcher::start_watcher(
-        app.claude_home.clone(),
-        app.encoded_project.clone(),
-        app.project_cwd.clone(),
-        tx,
-        &app.project_config.tabs,
-    )?;
+    let mut _debouncer = start_app_watcher(&app, tx.clone())?;
 
-    let tick_rate = Duration::from_millis(app.project_config.tick_rate());
-    let poll_interval = Duration::from_secs(60);
+    app.check_power();
+    let mut intervals = PollIntervals::from_config(&app.project_config, app.low_power);
+    let mut intervals_low_power = app.low_power;
     let mut last_tick = Instant::now();
+    let mut frame_interval = app.project_config.frame_interval();
+    let mut last_draw = Instant::now()
+        .checked_sub(frame_interval)
+        .unwrap_or_else(Instant::now);
+    let mut pane_cache = ui::pane_cache::PaneCache::default();
+    let width = terminal.size()?.width;
+    app.handle_resize(
+        ui::list_table::inner_width(width),
+        ui::sessions_view::transcript_width(width),
+    );
 
     loop {
-        // Draw only when dirty
-        if app.dirty {
-            terminal.draw(|f| ui::draw(f, &app))?;
-            app.dirty = false;
+        // Checked before drawing, which fails once the terminal is closed
+        if shutdown::requested() {
+            app.shutdown();
+            return Ok(());
+        }
+
+        // Draw only when dirty, and at most once per frame interval so
+        // bursts of keys and file changes share a frame
+        let next_frame = frame_interval.saturating_sub(last_draw.elapsed());
+        if app.dirty.any() && next_frame.is_zero() {
+            terminal.draw(|f| ui::draw(f, &app, &mut pane_cache))?;
+            app.dirty = app::Dirty::default();
+            last_draw = Instant::now();
         }
 
-        // Handle events
-        let timeout = tick_rate.saturating_sub(last_tick.elapsed());
+        // Handle events, waking up for the held-back frame if there is one
+        let mut timeout = intervals.tick_rate.saturating_sub(last_tick.elapsed());
+        if app.dirty.any() {
+            timeout = timeout.min(next_frame);
+        }
 
         // Check for crossterm events
         if ct_event::poll(timeout)? {
-            if let Event::Key(key) = ct_event::read()? {
-                if key.kind == KeyEventKind::Press {
-                    handle_key(&mut app, key);
-                    app.mark_dirty();
+            match ct_event::read()? {
+                Event::Key(key) => {
+                    if key.kind == KeyEventKind::Press {
+                        handle_key(&mut app, key);
+                        app.mark_dirty();
+                    }
+                    if let Some(command) = app.pending_editor.take() {
+                        if let Err(e) = run_editor(terminal, &command) {
+                            app.last_error = Some(format!("Editor: {}", e));
+                        }
+                    }
                 }
+                Event::Resize(width, _) => {
+                    app.handle_resize(
+                        ui::list_table::inner_width(width),
+                        ui::sessions_view::transcript_width(width),
+                    );
+                }
+                _ => {}
             }
         }
 
         // Check for file watcher and pane send events
         while let Ok(evt) = rx.try_recv() {
+            // File changes mark only the panes they touch
+            let marks_own_panes = matches!(evt, AppEvent::FileChanged(_));
             match evt {
                 AppEvent::FileChanged(change) => app.handle_file_change(change),
+                AppEvent::ConfigChanged => {
+                    if app.reload_config() {
+                        // Tabs and Claude homes decide what is watched
+                        _debouncer = start_app_watcher(&app, tx.clone())?;
+                        intervals = PollIntervals::from_config(&app.project_config, app.low_power);
+                        frame_interval = app.project_config.frame_interval();
+                    }
+                }
                 AppEvent::PaneSendComplete(err) => app.handle_send_complete(err),
-                AppEvent::GitHubPrsLoaded(result) => app.handle_github_prs_loaded(result),
-                AppEvent::GitHubIssuesLoaded(result) => {
-                    app.handle_github_issues_loaded(result)
+                AppEvent::PaneResizeComplete(err) => app.handle_resize_complete(err),
+                AppEvent::GhUserDetected(user) => app.handle_gh_user_detected(user),
+                AppEvent::GitHubPrsLoaded(result, total) => {
+                    app.handle_github_pr_page(result, total)
+                }
+                AppEvent::BitbucketPrsLoaded(result) => app.handle_bitbucket_prs_loaded(result),
+                AppEvent::ReviewThreadsLoaded(number, updated_at, result) => {
+                    app.handle_review_threads_loaded(number, updated_at, result)
+                }
+                AppEvent::GitHubIssuesLoaded(result, total) => {
+                    app.handle_github_issues_loaded(result, total)
+                }
+                AppEvent::IssueMetaOptionsLoaded(field, result) => {
+                    app.handle_issue_meta_options_loaded(field, result)
                 }
-                AppEvent::JiraIssuesLoaded(result) => {
-                    app.handle_jira_issues_loaded(result)
+                AppEvent::IssueMetaUpdated(result) => app.handle_issue_meta_updated(result),
+                AppEvent::JiraBoardLoaded(result) => app.handle_jira_board_loaded(result),
+                AppEvent::JiraAttachmentDownloaded(result) => {
+                    app.handle_jira_attachment_downloaded(result)
+                }
+                AppEvent::JiraIssuesLoaded(filter, result) => {
+                    app.handle_jira_issues_loaded(filter, result)
                 }
                 AppEvent::LinearIssuesLoaded(result) => {
                     app.handle_linear_issues_loaded(result)
                 }
+                AppEvent::LinearMoreLoaded(after, result) => {
+                    app.handle_linear_more_loaded(after, result)
+                }
+                AppEvent::AzureItemsLoaded(result) => app.handle_azure_items_loaded(result),
+                AppEvent::AzureDetailLoaded(result) => app.handle_azure_detail_loaded(result),
                 AppEvent::GitStatusLoaded(result) => app.handle_git_status_loaded(result),
                 AppEvent::GitDiffLoaded(result) => app.handle_git_diff_loaded(result),
+                AppEvent::GrepLoaded(pattern, result) => app.handle_grep_loaded(pattern, result),
+                AppEvent::AuthStatusLoaded(cli, result) => {
+                    app.handle_auth_status_loaded(cli, result)
+                }
+                AppEvent::DepsLoaded(found, errors) => app.handle_deps_loaded(found, errors),
+                AppEvent::TeamResourcesLoaded(resources) => {
+                    app.handle_team_resources_loaded(resources)
+                }
+                AppEvent::NotificationsSent(errors) => app.handle_notifications_sent(errors),
+                AppEvent::TranslationLoaded(original, result) => {
+                    app.handle_translation_loaded(original, result)
+                }
+                AppEvent::LinkPreviewLoaded(url, result) => {
+                    app.handle_link_preview_loaded(url, result)
+                }
+                AppEvent::SessionStatsLoaded(session_id, result) => {
+                    app.handle_session_stats_loaded(session_id, result)
+                }
+                AppEvent::ActivityCalendarLoaded(calendar) => {
+                    app.handle_activity_calendar_loaded(calendar)
+                }
+                AppEvent::PowerStatusLoaded(on_battery) => {
+                    app.handle_power_status_loaded(on_battery)
+                }
+                AppEvent::ProcessUsageSampled(sample) => app.handle_process_usage_sampled(sample),
+                AppEvent::ProcessTreeBefore(id, tree) => app.handle_process_tree_before(id, tree),
+                AppEvent::ProcessTreeAfter(id, tree) => app.handle_process_tree_after(id, tree),
+                AppEvent::SessionExported(result) => app.handle_session_exported(result),
+                AppEvent::TodoTicketCreated(home, filename, content, result) => {
+                    app.handle_todo_ticket_created(home, filename, content, result)
+                }
+                AppEvent::CommandImportLoaded(result) => app.handle_command_import_loaded(result),
+                AppEvent::ProcessReportPosted(label, result) => {
+                    app.handle_process_report_posted(label, result)
+                }
+                AppEvent::AssocLaunched(result) => app.handle_assoc_launched(result),
+            }
+            if !marks_own_panes {
+                app.mark_dirty();
             }
-            app.mark_dirty();
         }
 
         // Tick
-        if last_tick.elapsed() >= tick_rate {
+        if last_tick.elapsed() >= intervals.tick_rate {
             last_tick = Instant::now();
 
-            // Poll GitHub PRs every 60s (skip if tab disabled)
+            app.check_power();
+            if app.low_power != intervals_low_power {
+                intervals_low_power = app.low_power;
+                intervals = PollIntervals::from_config(&app.project_config, app.low_power);
+            }
+
+            // Load one tab that hasn't been opened yet
+            app.load_next_pending();
+
+            // Poll PRs from GitHub or Bitbucket (skip if tab disabled or polling is off)
             if app.is_tab_enabled(&app::ActiveTab::GitHubPRs)
-                && app.has_gh
-                && app.gh_repo.is_some()
-                && app.gh_last_poll.elapsed() >= poll_interval
+                && app.prs_available()
+                && intervals.pr_poll.is_some_and(|p| {
+                    app.gh_last_poll.elapsed() >= app.poll_delay(&app::ActiveTab::GitHubPRs, p)
+                })
             {
                 app.load_github_prs();
             }
 
-            // Poll GitHub Issues every 60s (skip if tab disabled)
+            // Poll GitHub Issues (skip if tab disabled or polling is off)
             if app.is_tab_enabled(&app::ActiveTab::GitHubIssues)
                 && app.gh_issues_enabled
                 && app.gh_issues_repo.is_some()
-                && app.gh_issues_last_poll.elapsed() >= poll_interval
+                && intervals.gh_poll.is_some_and(|p| {
+                    app.gh_issues_last_poll.elapsed()
+                        >= app.poll_delay(&app::ActiveTab::GitHubIssues, p)
+                })
             {
                 app.load_github_issues();
             }
 
-            // Poll Jira every 60s (skip if tab disabled)
+            // Poll Jira (skip if tab disabled or polling is off)
             if app.is_tab_enabled(&app::ActiveTab::Jira)
                 && app.has_jira
-                && app.jira_last_poll.elapsed() >= poll_interval
+                && intervals.jira_poll.is_some_and(|p| {
+                    app.jira_last_poll.elapsed() >= app.poll_delay(&app::ActiveTab::Jira, p)
+                })
             {
                 app.load_jira_issues();
             }
 
-            // Poll Linear every 60s (skip if tab disabled)
+            // Poll Linear (skip if tab disabled or polling is off)
             if app.is_tab_enabled(&app::ActiveTab::Linear)
                 && app.has_linear
-                && app.linear_last_poll.elapsed() >= poll_interval
+                && intervals.linear_poll.is_some_and(|p| {
+                    app.linear_last_poll.elapsed() >= app.poll_delay(&app::ActiveTab::Linear, p)
+                })
             {
                 app.load_linear_issues();
             }
 
+            // Poll Azure DevOps work items (skip if tab disabled or polling is off)
+            if app.is_tab_enabled(&app::ActiveTab::Azure)
+                && app.has_azure
+                && intervals.azure_poll.is_some_and(|p| {
+                    app.azure_last_poll.elapsed() >= app.poll_delay(&app::ActiveTab::Azure, p)
+                })
+            {
+                app.load_azure_items();
+            }
+
+            // Reload git status once file changes settle
+            app.flush_git_refresh();
+
             // Poll spawned process output
             app.poll_process_output();
 
+            // Sample CPU and memory of Claude Code processes
+            app.sample_process_usage();
+
+            // Follow an open team member transcript
+            app.refresh_member_transcript();
+
+            // Follow the end of the file open in the Git tab
+            app.refresh_file_tail();
+
             // Clear stale send status
             app.clear_stale_send_status();
 
-            app.mark_dirty();
+            app.mark_tick_dirty();
         }
 
         if app.should_quit {
@@ -401,7 +1262,30 @@ fn handle_key(app: &mut App, key: KeyEvent) {
             return;
         }
         KeyCode::Char('?')
-            if !app.fb_editing && !app.jira_search_mode && !app.gh_issues_editing =>
+            if !app.fb_editing
+                && !app.plan_editing
+                && !app.command_editing
+                && app.command_new_target.is_none()
+                && app.command_import_input.is_none()
+                && app.hook_form.is_none()
+                && app.filter_input.is_none()
+                && !app.gh_issues_editing
+                && app.task_editor.is_none()
+                && app.broadcast.is_none()
+                && app.todo_edit_mode.is_none()
+                && app.todo_promote.is_none()
+                && app.branch_picker_input.is_none()
+                && app.session_rename_input.is_none()
+                && app.session_link_picker.is_none()
+                && app.grep_input.is_none()
+                && app.git_stash_input.is_none()
+                && app.fb_op.is_none()
+                && app.process_search_input.is_none()
+                && !app.notes_editing
+                && app.note_input.is_none()
+                && app.prompt_args_input.is_none()
+                && app.goto.is_none()
+                && app.pr_draft.is_none() =>
         {
             app.show_help = !app.show_help;
             return;
@@ -422,243 +1306,744 @@ fn handle_key(app: &mut App, key: KeyEvent) {
         return;
     }
 
-    // Delete confirmation dialog
-    if app.confirm_delete {
+    // Note jotted with Ctrl+N (any tab)
+    if let Some(ref mut text) = app.note_input {
         match key.code {
-            KeyCode::Char('y') | KeyCode::Char('Y') => app.execute_delete(),
-            KeyCode::Char('n') | KeyCode::Char('N') | KeyCode::Esc => app.cancel_delete(),
+            KeyCode::Enter => app.submit_note_input(),
+            KeyCode::Esc => app.note_input = None,
+            KeyCode::Backspace => {
+                text.pop();
+            }
+            KeyCode::Char(c) => text.push(c),
             _ => {}
         }
         return;
     }
 
-    // Prompt picker — select from available prompts
-    if app.show_prompt_picker {
-        handle_prompt_picker_key(app, key);
+    // Goto anything (Ctrl+K, any tab)
+    if app.goto.is_some() {
+        match key.code {
+            KeyCode::Enter => app.goto_select(),
+            KeyCode::Esc => app.goto = None,
+            KeyCode::Char('k') if key.modifiers.contains(KeyModifiers::CONTROL) => app.goto = None,
+            KeyCode::Down => app.goto_move(true),
+            KeyCode::Up => app.goto_move(false),
+            KeyCode::Char('n') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                app.goto_move(true)
+            }
+            KeyCode::Char('p') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                app.goto_move(false)
+            }
+            KeyCode::Backspace => app.goto_backspace(),
+            KeyCode::Char(c) => app.goto_type(c),
+            _ => {}
+        }
         return;
     }
 
-    // Prompt modal — pass keys to prompt editor
-    if app.show_prompt_modal {
-        handle_prompt_modal_key(app, key);
+    // PR description drafted with P on the Git tab (opens on any tab)
+    if app.pr_draft.is_some() {
+        match key.code {
+            KeyCode::Char('s') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                app.pr_draft_create()
+            }
+            KeyCode::Esc => app.pr_draft_cancel(),
+            KeyCode::Tab => app.pr_draft_toggle_field(),
+            _ => app.pr_draft_input(key),
+        }
         return;
     }
 
-    // Pane send input mode
-    if app.send_mode {
-        handle_send_key(app, key);
+    // Delete confirmation dialog
+    if app.confirm_delete {
+        match key.code {
+            KeyCode::Char('y') | KeyCode::Char('Y') => app.execute_delete(),
+            KeyCode::Char('n') | KeyCode::Char('N') | KeyCode::Esc => app.cancel_delete(),
+            _ => {}
+        }
         return;
     }
 
-    // File browser edit mode — pass keys to TextArea
-    if app.fb_editing {
-        handle_fb_edit_key(app, key);
+    // Resume session confirmation
+    if app.resume_target.is_some() {
+        match key.code {
+            KeyCode::Char('y') | KeyCode::Char('Y') | KeyCode::Enter => {
+                app.confirm_resume_session(true)
+            }
+            KeyCode::Char('n') | KeyCode::Char('N') | KeyCode::Esc => {
+                app.confirm_resume_session(false)
+            }
+            _ => {}
+        }
         return;
     }
 
-    // GitHub Issues edit mode — pass keys to TextArea editors
-    if app.gh_issues_editing {
-        handle_issues_edit_key(app, key);
+    // Plan review overlay
+    if app.plan_review.is_some() {
+        match key.code {
+            KeyCode::Char('j') | KeyCode::Down => app.plan_review_scroll(true),
+            KeyCode::Char('k') | KeyCode::Up => app.plan_review_scroll(false),
+            KeyCode::Char('a') => app.decide_plan_review(true),
+            KeyCode::Char('r') => app.decide_plan_review(false),
+            KeyCode::Esc => app.close_plan_review(),
+            _ => {}
+        }
         return;
     }
 
-    // Jira transition popup — number keys select transition
-    if app.jira_show_transitions {
+    // Team member transcript popup
+    if app.member_transcript.is_some() {
         match key.code {
-            KeyCode::Esc => app.jira_show_transitions = false,
-            KeyCode::Char(c) if c.is_ascii_digit() && c != '0' => {
-                let idx = (c as usize) - ('1' as usize);
-                app.jira_do_transition(idx);
-            }
+            KeyCode::Char('j') | KeyCode::Down => app.member_transcript_scroll(1),
+            KeyCode::Char('k') | KeyCode::Up => app.member_transcript_scroll(-1),
+            KeyCode::PageDown => app.member_transcript_scroll(20),
+            KeyCode::PageUp => app.member_transcript_scroll(-20),
+            KeyCode::Char('g') => app.member_transcript_jump(false),
+            KeyCode::Char('G') => app.member_transcript_jump(true),
+            KeyCode::Esc | KeyCode::Char('q') => app.close_member_transcript(),
             _ => {}
         }
         return;
     }
 
-    // Jira search mode — text input
-    if app.jira_search_mode {
+    // Transcript item popup
+    if app.transcript_detail.is_some() {
         match key.code {
-            KeyCode::Esc => {
-                app.jira_search_mode = false;
-                app.jira_search_input.clear();
-                app.load_jira_issues(); // reset to default view
-            }
-            KeyCode::Enter => {
-                app.jira_search();
+            KeyCode::Char('j') | KeyCode::Down => app.transcript_detail_scroll(true),
+            KeyCode::Char('k') | KeyCode::Up => app.transcript_detail_scroll(false),
+            KeyCode::Char('g') => {
+                if let Some(detail) = app.transcript_detail.as_mut() {
+                    detail.scroll = 0;
+                }
             }
-            KeyCode::Backspace => {
-                app.jira_search_input.pop();
+            KeyCode::Char('e') => {
+                app.close_transcript_detail();
+                app.open_file_reference();
             }
-            KeyCode::Char(c) => {
-                app.jira_search_input.push(c);
+            KeyCode::Esc | KeyCode::Enter | KeyCode::Char('q') => app.close_transcript_detail(),
+            _ => {}
+        }
+        return;
+    }
+
+    // Orphaned process cleanup prompt (shown at startup)
+    if !app.orphan_processes.is_empty() {
+        match key.code {
+            KeyCode::Char('y') | KeyCode::Char('Y') => app.kill_orphan_processes(),
+            KeyCode::Char('n') | KeyCode::Char('N') | KeyCode::Esc => {
+                app.dismiss_orphan_processes()
             }
             _ => {}
         }
         return;
     }
 
-    // Quit
-    if key.code == KeyCode::Char('q') {
-        app.should_quit = true;
+    // Prompt picker — select from available prompts
+    if app.show_prompt_picker {
+        handle_prompt_picker_key(app, key);
         return;
     }
 
-    match key.code {
-        // Tab switching
-        KeyCode::Tab => {
-            if key.modifiers.contains(KeyModifiers::SHIFT) {
-                app.prev_tab();
-            } else {
-                app.next_tab();
-            }
-        }
+    // Prompt modal — pass keys to prompt editor
+    if app.show_prompt_modal {
+        handle_prompt_modal_key(app, key);
+        return;
+    }
 
-        // Dynamic number keys
-        KeyCode::Char(c) if c.is_ascii_digit() && c != '0' => {
-            let idx = (c as usize) - ('1' as usize);
-            let tabs = app.visible_tabs();
-            if idx < tabs.len() {
-                app.switch_to_tab(tabs[idx].clone());
+    // Session title input (Sessions tab)
+    if let Some(ref mut title) = app.session_rename_input {
+        match key.code {
+            KeyCode::Enter => app.submit_session_rename(),
+            KeyCode::Esc => app.session_rename_input = None,
+            KeyCode::Backspace => {
+                title.pop();
             }
+            KeyCode::Char(c) => title.push(c),
+            _ => {}
         }
+        return;
+    }
 
-        // Navigation
-        KeyCode::Char('j') | KeyCode::Down => app.navigate_down(),
-        KeyCode::Char('k') | KeyCode::Up => app.navigate_up(),
-        KeyCode::Char('h') | KeyCode::Left => app.navigate_left(),
-        KeyCode::Char('l') | KeyCode::Right => app.navigate_right(),
-        KeyCode::Enter => app.select_item(),
-
-        // Jump
-        KeyCode::Char('g') => app.jump_top(),
-        KeyCode::Char('G') => app.jump_bottom(),
+    // Session link picker (Sessions tab): type to narrow, Delete unlinks
+    if app.session_link_picker.is_some() {
+        match key.code {
+            KeyCode::Esc => app.session_link_picker = None,
+            KeyCode::Down => app.session_link_picker_move(true),
+            KeyCode::Up => app.session_link_picker_move(false),
+            KeyCode::Enter => app.submit_session_link(),
+            KeyCode::Delete => app.remove_session_link(),
+            KeyCode::Backspace => app.session_link_picker_edit(None),
+            KeyCode::Char(c) => app.session_link_picker_edit(Some(c)),
+            _ => {}
+        }
+        return;
+    }
 
-        // Follow mode (Sessions tab / Processes tab)
-        KeyCode::Char('f') => match app.active_tab {
-            app::ActiveTab::Sessions => app.toggle_follow(),
-            app::ActiveTab::Processes => app.toggle_process_follow(),
+    // Output search input (Processes tab)
+    if let Some(ref mut query) = app.process_search_input {
+        match key.code {
+            KeyCode::Enter => app.process_search_submit(),
+            KeyCode::Esc => app.process_search_input = None,
+            KeyCode::Backspace => {
+                query.pop();
+            }
+            KeyCode::Char(c) => query.push(c),
             _ => {}
-        },
+        }
+        return;
+    }
 
-        // Subagent transcript cycling (Sessions tab) / Jump to session (Processes tab)
-        KeyCode::Char('s') => {
-            if app.active_tab == app::ActiveTab::Sessions
-                && app.sessions_pane == app::SessionsPane::Transcript
-            {
-                app.cycle_subagent();
-            } else if app.active_tab == app::ActiveTab::Processes {
-                app.jump_to_process_session();
+    // New file / folder or rename path input (Git tab browse mode)
+    if let Some(ref mut op) = app.fb_op {
+        match key.code {
+            KeyCode::Enter => app.fb_submit_op(),
+            KeyCode::Esc => app.fb_op = None,
+            KeyCode::Backspace => {
+                op.input.pop();
             }
+            KeyCode::Char(c) => op.input.push(c),
+            _ => {}
         }
+        return;
+    }
 
-        // File browser toggle (Git tab)
-        KeyCode::Char('b') => {
-            if app.active_tab == app::ActiveTab::Git {
-                app.toggle_git_mode();
+    // Project search pattern input (Git tab browse mode)
+    if let Some(ref mut pattern) = app.grep_input {
+        match key.code {
+            KeyCode::Enter => app.grep_submit(),
+            KeyCode::Esc => app.grep_input = None,
+            KeyCode::Backspace => {
+                pattern.pop();
             }
+            KeyCode::Char(c) => pattern.push(c),
+            _ => {}
         }
+        return;
+    }
 
-        // Backspace for file browser navigation
-        KeyCode::Backspace => {
-            if app.active_tab == app::ActiveTab::Git && app.git_mode == app::GitMode::Browse {
-                app.fb_backspace();
+    // Stash message input and confirmation (Git tab)
+    if let Some(ref mut message) = app.git_stash_input {
+        match key.code {
+            KeyCode::Enter => app.git_submit_stash(),
+            KeyCode::Esc => app.git_stash_input = None,
+            KeyCode::Backspace => {
+                message.pop();
             }
+            KeyCode::Char(c) => message.push(c),
+            _ => {}
         }
+        return;
+    }
+    if app.git_stash_confirm.is_some() {
+        match key.code {
+            KeyCode::Char('y') | KeyCode::Char('Y') => app.git_confirm_stash_action(true),
+            KeyCode::Char('n') | KeyCode::Char('N') | KeyCode::Esc => {
+                app.git_confirm_stash_action(false)
+            }
+            _ => {}
+        }
+        return;
+    }
+
+    // Run report destination (Processes tab)
+    if app.process_report_prompt {
+        match key.code {
+            KeyCode::Char('f') => app.save_process_report(),
+            KeyCode::Char('c') => app.post_process_report(),
+            KeyCode::Esc => app.process_report_prompt = false,
+            _ => {}
+        }
+        return;
+    }
 
-        // Edit file (file browser) or edit issue (Issues tab)
-        KeyCode::Char('e') => match app.active_tab {
-            app::ActiveTab::Git if app.git_mode == app::GitMode::Browse => {
-                app.fb_start_edit();
+    // Branch picker popup (Git tab)
+    if app.show_branch_picker {
+        handle_branch_picker_key(app, key);
+        return;
+    }
+
+    // Link preview popup (PRs / Issues / Jira)
+    if app.link_popup.is_some() {
+        match key.code {
+            KeyCode::Char('j') | KeyCode::Down => app.link_popup_move(true),
+            KeyCode::Char('k') | KeyCode::Up => app.link_popup_move(false),
+            KeyCode::Char('o') => app.open_selected_link(),
+            KeyCode::Esc | KeyCode::Char('V') | KeyCode::Char('q') => app.close_link_popup(),
+            _ => {}
+        }
+        return;
+    }
+
+    // Jira filter switcher
+    if app.show_jira_filter_picker {
+        match key.code {
+            KeyCode::Char('j') | KeyCode::Down => app.jira_filter_picker_move(true),
+            KeyCode::Char('k') | KeyCode::Up => app.jira_filter_picker_move(false),
+            KeyCode::Enter => app.jira_filter_picker_select(),
+            KeyCode::Esc | KeyCode::Char('F') | KeyCode::Char('q') => {
+                app.close_jira_filter_picker()
             }
-            app::ActiveTab::GitHubIssues => {
-                app.issues_start_edit();
+            _ => {}
+        }
+        return;
+    }
+
+    // Remote picker (PRs / Issues tabs)
+    if app.show_remote_picker {
+        match key.code {
+            KeyCode::Char('j') | KeyCode::Down => app.remote_picker_move(true),
+            KeyCode::Char('k') | KeyCode::Up => app.remote_picker_move(false),
+            KeyCode::Enter => app.remote_picker_select(),
+            KeyCode::Esc | KeyCode::Char('O') | KeyCode::Char('q') => app.close_remote_picker(),
+            _ => {}
+        }
+        return;
+    }
+
+    // Activity feed dropdown
+    if app.show_activity {
+        match key.code {
+            KeyCode::Char('j') | KeyCode::Down => app.activity_move(true),
+            KeyCode::Char('k') | KeyCode::Up => app.activity_move(false),
+            KeyCode::Enter => app.activity_jump(),
+            KeyCode::Esc | KeyCode::Char('F') | KeyCode::Char('q') => app.close_activity(),
+            _ => {}
+        }
+        return;
+    }
+
+    // Trash: restore or purge deleted items
+    if let Some(confirm) = app.trash.as_ref().map(|t| t.confirm.is_some()) {
+        match key.code {
+            KeyCode::Char('y') if confirm => app.trash_confirm_purge(),
+            _ if confirm => app.trash_cancel_purge(),
+            KeyCode::Char('j') | KeyCode::Down => app.trash_move(true),
+            KeyCode::Char('k') | KeyCode::Up => app.trash_move(false),
+            KeyCode::Enter | KeyCode::Char('r') => app.trash_restore(),
+            KeyCode::Char('d') | KeyCode::Delete => app.trash_request_purge(TrashPurge::Selected),
+            KeyCode::Char('D') => app.trash_request_purge(TrashPurge::All),
+            KeyCode::Esc | KeyCode::Char('Z') | KeyCode::Char('q') => app.close_trash(),
+            _ => {}
+        }
+        return;
+    }
+
+    // Pane send input mode
+    if app.send_mode {
+        handle_send_key(app, key);
+        return;
+    }
+
+    // File browser edit mode — pass keys to TextArea
+    if app.fb_editing {
+        handle_fb_edit_key(app, key);
+        return;
+    }
+
+    // Plan edit mode — merge review first, otherwise keys go to the TextArea
+    if app.plan_editing {
+        if app.plan_merge.is_some() {
+            handle_plan_merge_key(app, key);
+        } else {
+            handle_plan_edit_key(app, key);
+        }
+        return;
+    }
+
+    // Tasks proposed from a plan
+    if app.plan_tasks.is_some() {
+        match key.code {
+            KeyCode::Char('j') | KeyCode::Down => app.plan_tasks_move(true),
+            KeyCode::Char('k') | KeyCode::Up => app.plan_tasks_move(false),
+            KeyCode::Char(' ') => app.plan_tasks_toggle(),
+            KeyCode::Tab => app.plan_tasks_next_team(),
+            KeyCode::Enter => app.plan_tasks_create(),
+            KeyCode::Esc => app.plan_tasks = None,
+            _ => {}
+        }
+        return;
+    }
+
+    // Command edit mode — pass keys to TextArea
+    if app.command_editing {
+        handle_command_edit_key(app, key);
+        return;
+    }
+
+    // Notes edit mode — pass keys to TextArea
+    if app.notes_editing {
+        handle_notes_edit_key(app, key);
+        return;
+    }
+
+    // Import source input (Commands tab)
+    if let Some(ref mut input) = app.command_import_input {
+        match key.code {
+            KeyCode::Esc => app.command_cancel_import_input(),
+            KeyCode::Enter => app.command_submit_import_source(),
+            KeyCode::Backspace => {
+                input.pop();
             }
+            KeyCode::Char(c) => input.push(c),
             _ => {}
-        },
+        }
+        return;
+    }
 
-        // New issue (Issues tab)
-        KeyCode::Char('n') => {
-            if app.active_tab == app::ActiveTab::GitHubIssues {
-                app.issues_start_create();
+    // Import review popup (Commands tab)
+    if app.command_import.is_some() {
+        match key.code {
+            KeyCode::Char('j') | KeyCode::Down => app.command_import_scroll(true),
+            KeyCode::Char('k') | KeyCode::Up => app.command_import_scroll(false),
+            KeyCode::Tab => app.command_import_move(true),
+            KeyCode::BackTab => app.command_import_move(false),
+            KeyCode::Enter => app.command_import_confirm(false),
+            KeyCode::Char('o') => app.command_import_confirm(true),
+            KeyCode::Esc | KeyCode::Char('q') => app.close_command_import(),
+            _ => {}
+        }
+        return;
+    }
+
+    // New command / agent name input
+    if app.command_new_target.is_some() {
+        match key.code {
+            KeyCode::Esc => app.command_cancel_new(),
+            KeyCode::Enter => app.command_submit_new(),
+            KeyCode::Tab => app.command_cycle_new_target(),
+            KeyCode::Backspace => {
+                app.command_new_name.pop();
             }
+            KeyCode::Char(c) => app.command_new_name.push(c),
+            _ => {}
+        }
+        return;
+    }
+
+    // Hook form (Hooks tab)
+    if app.hook_form.is_some() {
+        match key.code {
+            KeyCode::Esc => app.hook_cancel_form(),
+            KeyCode::Enter => app.hook_submit_form(),
+            KeyCode::Tab | KeyCode::Down => app.hook_form_next_field(true),
+            KeyCode::BackTab | KeyCode::Up => app.hook_form_next_field(false),
+            KeyCode::Left => app.hook_form_cycle(false),
+            KeyCode::Right => app.hook_form_cycle(true),
+            KeyCode::Backspace => app.hook_form_backspace(),
+            KeyCode::Char(c) => app.hook_form_input(c),
+            _ => {}
         }
+        return;
+    }
+
+    // GitHub Issues edit mode — pass keys to TextArea editors
+    if app.gh_issues_editing {
+        handle_issues_edit_key(app, key);
+        return;
+    }
+
+    // Team task editor — TextArea fields plus owner / blocked-by pickers
+    if app.task_editor.is_some() {
+        handle_task_edit_key(app, key);
+        return;
+    }
 
-        // Comment on issue (Issues tab)
-        KeyCode::Char('c') => {
-            if app.active_tab == app::ActiveTab::GitHubIssues {
-                app.issues_start_comment();
+    // Team broadcast — message editor, then a recipient confirmation
+    if app.broadcast.is_some() {
+        handle_broadcast_key(app, key);
+        return;
+    }
+
+    // Todo-to-ticket picker: the title is typed, arrows pick the tracker
+    if let Some(ref mut promote) = app.todo_promote {
+        if promote.creating {
+            return;
+        }
+        match key.code {
+            KeyCode::Enter => app.submit_todo_promote(),
+            KeyCode::Esc => app.todo_promote = None,
+            KeyCode::Up | KeyCode::BackTab => app.todo_promote_move(false),
+            KeyCode::Down | KeyCode::Tab => app.todo_promote_move(true),
+            KeyCode::Backspace => {
+                promote.title.pop();
             }
+            KeyCode::Char(c) => promote.title.push(c),
+            _ => {}
         }
+        return;
+    }
 
-        // Launch Claude Code prompt modal (all issue tabs)
-        KeyCode::Char('p') => match app.active_tab {
-            app::ActiveTab::GitHubPRs
-            | app::ActiveTab::GitHubIssues
-            | app::ActiveTab::Linear
-            | app::ActiveTab::Jira => {
-                app.open_prompt_modal_for_current();
+    // Todo item input line — text input
+    if app.todo_edit_mode.is_some() {
+        match key.code {
+            KeyCode::Esc => app.todo_cancel_edit(),
+            KeyCode::Enter => app.todo_save_edit(),
+            KeyCode::Backspace => {
+                app.todo_edit_input.pop();
             }
+            KeyCode::Char(c) => app.todo_edit_input.push(c),
             _ => {}
-        },
+        }
+        return;
+    }
 
-        // Close/reopen issue (Issues tab) / Kill process (Processes tab)
-        KeyCode::Char('x') => match app.active_tab {
-            app::ActiveTab::GitHubIssues => app.issues_toggle_state(),
-            app::ActiveTab::Processes => app.kill_selected_process(),
+    // Issue label / assignee / milestone picker
+    if app.gh_issues_picker.is_some() {
+        match key.code {
+            KeyCode::Esc => app.gh_issues_picker = None,
+            KeyCode::Char('j') | KeyCode::Down => app.issues_picker_move(true),
+            KeyCode::Char('k') | KeyCode::Up => app.issues_picker_move(false),
+            KeyCode::Char(' ') => app.issues_picker_toggle(),
+            KeyCode::Enter => app.issues_picker_apply(),
             _ => {}
-        },
+        }
+        return;
+    }
 
-        // Open in browser / open session in WT pane
-        KeyCode::Char('o') => match app.active_tab {
-            app::ActiveTab::GitHubPRs => app.gh_open_selected(),
-            app::ActiveTab::GitHubIssues => app.issues_open_in_browser(),
-            app::ActiveTab::Jira => app.jira_open_selected(),
-            app::ActiveTab::Linear => app.linear_open_selected(),
-            app::ActiveTab::Sessions => app.open_session_in_wt(),
+    // Issue template picker (new issue)
+    if app.gh_issues_template_picker.is_some() {
+        match key.code {
+            KeyCode::Esc => app.gh_issues_template_picker = None,
+            KeyCode::Char('j') | KeyCode::Down => app.issues_template_picker_move(true),
+            KeyCode::Char('k') | KeyCode::Up => app.issues_template_picker_move(false),
+            KeyCode::Enter => app.issues_template_picker_select(),
             _ => {}
-        },
+        }
+        return;
+    }
 
-        // Refresh
-        KeyCode::Char('r') => match app.active_tab {
-            app::ActiveTab::GitHubPRs => app.load_github_prs(),
-            app::ActiveTab::GitHubIssues => app.load_github_issues(),
-            app::ActiveTab::Jira => app.load_jira_issues(),
-            app::ActiveTab::Linear => app.load_linear_issues(),
+    // Jira transition popup — number keys select transition
+    if app.jira_show_transitions {
+        match key.code {
+            KeyCode::Esc => app.jira_show_transitions = false,
+            KeyCode::Char(c) if c.is_ascii_digit() && c != '0' => {
+                let idx = (c as usize) - ('1' as usize);
+                app.jira_do_transition(idx);
+            }
             _ => {}
-        },
+        }
+        return;
+    }
 
-        // Jira transitions
-        KeyCode::Char('t') => {
-            if app.active_tab == app::ActiveTab::Jira {
-                app.jira_load_transitions();
+    // Work item state popup — number keys select the new state
+    if app.azure_show_transitions {
+        match key.code {
+            KeyCode::Esc => app.azure_show_transitions = false,
+            KeyCode::Char(c) if c.is_ascii_digit() && c != '0' => {
+                let idx = (c as usize) - ('1' as usize);
+                app.azure_do_transition(idx);
             }
+            _ => {}
         }
+        return;
+    }
 
-        // Jira search
-        KeyCode::Char('/') => {
-            if app.active_tab == app::ActiveTab::Jira {
-                app.jira_search_mode = true;
-                app.jira_search_input.clear();
+    // List filter — text input
+    if let Some(input) = app.filter_input.as_mut() {
+        match key.code {
+            KeyCode::Esc => app.filter_cancel(),
+            KeyCode::Enter => app.filter_apply(),
+            KeyCode::Tab => app.filter_complete(),
+            KeyCode::Backspace => {
+                input.pop();
             }
+            KeyCode::Char('u') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                input.clear();
+            }
+            KeyCode::Char(c) => {
+                input.push(c);
+            }
+            _ => {}
         }
+        return;
+    }
 
-        // Delete file
-        KeyCode::Char('d') | KeyCode::Delete => match app.active_tab {
-            app::ActiveTab::Todos
-            | app::ActiveTab::Plans
-            | app::ActiveTab::Sessions
-            | app::ActiveTab::Teams => app.request_delete(),
+    if let Some(action) = keymap::action_for(app, &key) {
+        run_action(app, action, key);
+    }
+}
+
+/// Carry out a normal-mode binding from the keymap. `key` tells apart the
+/// keys that share an action, e.g. `j` and `k`.
+fn run_action(app: &mut App, action: Action, key: KeyEvent) {
+    use app::ActiveTab;
+    match action {
+        Action::Quit => app.should_quit = true,
+        Action::ToggleHelp => app.show_help = !app.show_help,
+        Action::CycleTab => {
+            if key.code == KeyCode::BackTab || key.modifiers.contains(KeyModifiers::SHIFT) {
+                app.prev_tab();
+            } else {
+                app.next_tab();
+            }
+        }
+        Action::JumpToTab => {
+            if let KeyCode::Char(c) = key.code {
+                let idx = (c as usize) - ('1' as usize);
+                let tabs = app.visible_tabs();
+                if idx < tabs.len() {
+                    app.switch_to_tab(tabs[idx].clone());
+                }
+            }
+        }
+        Action::Navigate => match key.code {
+            KeyCode::Char('j') | KeyCode::Down => app.navigate_down(),
+            KeyCode::Char('k') | KeyCode::Up => app.navigate_up(),
+            KeyCode::Char('h') | KeyCode::Left => app.navigate_left(),
+            _ => app.navigate_right(),
+        },
+        Action::Select => app.select_item(),
+        Action::JumpEnd => {
+            if key.code == KeyCode::Char('G') {
+                app.jump_bottom();
+            } else {
+                app.jump_top();
+            }
+        }
+        Action::Filter => app.filter_start(),
+        Action::ActivityFeed => app.toggle_activity(),
+        Action::GotoAnything => app.goto_start(),
+        Action::UndoDelete => app.undo_last_delete(),
+        Action::Trash => app.toggle_trash(),
+        Action::SendToPane => app.start_send_mode(),
+        Action::TogglePaneRatio => app.toggle_pane_ratio(),
+        Action::JumpToUnread => app.jump_to_unread(),
+        Action::ToggleFollow => {
+            if app.active_tab == ActiveTab::Processes {
+                app.toggle_process_follow();
+            } else if app.active_tab == ActiveTab::Git {
+                app.fb_toggle_tail();
+            } else {
+                app.toggle_follow();
+            }
+        }
+        Action::CycleSubagent => app.cycle_subagent(),
+        Action::ToggleSessionStats => app.toggle_session_stats(),
+        Action::ExportSession => app.export_selected_session(),
+        Action::RenameSession => app.start_session_rename(),
+        Action::LinkSession => app.open_session_link_picker(),
+        Action::GroupSessionsByTicket => app.toggle_sessions_by_ticket(),
+        Action::ToggleTranslation => app.toggle_translation(),
+        Action::ToggleTurnUsage => app.toggle_turn_usage(),
+        Action::ToggleTranscriptWrap => app.toggle_transcript_wrap(),
+        Action::ResumeSession => app.request_resume_session(),
+        Action::ReviewPlan => app.open_latest_plan_review(),
+        Action::OpenSessionInPane => app.open_session_in_pane(),
+        Action::OpenFileReference => app.open_file_reference(),
+        Action::EditTask => app.task_start_edit(),
+        Action::ToggleTaskGraph => app.toggle_task_graph(),
+        Action::Broadcast => app.broadcast_start(),
+        Action::MemberTranscript => app.open_member_transcript(),
+        Action::NewTask => app.task_start_new(),
+        Action::EditTodo => app.todo_start_edit(),
+        Action::NewTodo => app.todo_start_new(),
+        Action::PromoteTodo => app.open_todo_promote(),
+        Action::ToggleTodoStatus => app.todo_toggle_status(),
+        Action::ToggleGitMode => app.toggle_git_mode(),
+        Action::ToggleDiffSplit => app.toggle_diff_split(),
+        Action::BranchPicker => app.open_branch_picker(),
+        Action::Stash => app.git_start_stash(),
+        Action::DraftPr => app.draft_pr_description(),
+        Action::StashCommand => match key.code {
+            KeyCode::Char('a') => app.git_request_stash_action(app::StashAction::Apply),
+            KeyCode::Char('p') => app.git_request_stash_action(app::StashAction::Pop),
+            _ => app.git_request_stash_action(app::StashAction::Drop),
+        },
+        Action::EditFile => app.fb_start_edit(),
+        Action::ProjectSearch => app.grep_start(),
+        Action::OpenSearchMatch => app.grep_open_match(),
+        Action::CloseSearch => app.grep_close(),
+        Action::FileBrowserUp => app.fb_backspace(),
+        Action::NewProjectFile => app.fb_start_new(key.code == KeyCode::Char('N')),
+        Action::RenameProjectFile => app.fb_start_rename(),
+        Action::EditPlan => app.plan_start_edit(),
+        Action::PlanToTasks => app.plan_tasks_open(),
+        Action::EditCommand => app.command_start_edit(),
+        Action::NewCommand => app.command_start_new(),
+        Action::ImportCommand => app.command_start_import(),
+        Action::NewHook => app.hook_start_new(),
+        Action::EditHook => app.hook_start_edit(),
+        Action::EditNotes => app.notes_start_edit(),
+        Action::ToggleNotesRaw => app.toggle_notes_raw(),
+        Action::AddNote => app.start_note_input(),
+        Action::OpenInBrowser => match app.active_tab {
+            ActiveTab::GitHubPRs => app.gh_open_selected(),
+            ActiveTab::GitHubIssues => app.issues_open_in_browser(),
+            ActiveTab::Jira => app.jira_open_selected(),
+            ActiveTab::Linear => app.linear_open_selected(),
+            ActiveTab::Azure => app.azure_open_selected(),
             _ => {}
         },
-
-        // Send to Claude pane
-        KeyCode::Char('i') => {
-            if !app.send_pending {
-                app.start_send_mode();
+        Action::Refresh => {
+            app.note_manual_refresh();
+            match app.active_tab {
+                ActiveTab::GitHubPRs => app.load_github_prs(),
+                ActiveTab::GitHubIssues => app.load_github_issues(),
+                ActiveTab::Jira => app.load_jira_issues(),
+                ActiveTab::Linear => app.load_linear_issues(),
+                ActiveTab::Azure => app.load_azure_items(),
+                ActiveTab::Deps => app.load_outdated_deps(),
+                ActiveTab::Commands => app.load_commands(),
+                ActiveTab::Hooks => app.load_hooks(),
+                ActiveTab::Settings => app.load_settings(),
+                ActiveTab::Notes => app.load_notes(),
+                ActiveTab::Overview => app.load_all(),
+                _ => {}
             }
         }
-
-        _ => {}
+        Action::SortTable => app.table_sort(key.code == KeyCode::Char('S')),
+        Action::ScrollColumns => {
+            let step = if key.code == KeyCode::Char('<') {
+                -1
+            } else {
+                1
+            };
+            app.table_scroll(step);
+        }
+        Action::CycleChecks => app.gh_cycle_check(),
+        Action::CycleJiraResources => app.jira_cycle_resource(),
+        Action::ToggleResolvedThreads => app.gh_toggle_resolved_threads(),
+        Action::EditIssue => app.issues_start_edit(),
+        Action::NewIssue => app.issues_start_create(),
+        Action::CommentOnIssue => app.issues_start_comment(),
+        Action::IssuePicker => match key.code {
+            KeyCode::Char('L') => app.issues_open_picker(IssueMetaField::Labels),
+            KeyCode::Char('A') => app.issues_open_picker(IssueMetaField::Assignees),
+            _ => app.issues_open_picker(IssueMetaField::Milestone),
+        },
+        Action::ToggleIssueState => app.issues_toggle_state(),
+        Action::LinkPreview => app.open_link_popup(),
+        Action::RemotePicker => app.open_remote_picker(),
+        Action::JiraFilterPicker => app.open_jira_filter_picker(),
+        Action::ToggleJiraBoard => app.jira_toggle_board(),
+        Action::JiraBoardMove => {
+            let step = if key.code == KeyCode::Char('T') {
+                -1
+            } else {
+                1
+            };
+            app.jira_board_transition(step);
+        }
+        Action::Transitions => {
+            if app.active_tab == ActiveTab::Azure {
+                app.azure_load_transitions();
+            } else {
+                app.jira_load_transitions();
+            }
+        }
+        Action::Prompt => app.open_prompt_modal_for_current(),
+        Action::StopProcess => app.kill_selected_process(),
+        Action::JumpToProcessSession => app.jump_to_process_session(),
+        Action::CycleArtifacts => app.process_cycle_artifact(),
+        Action::OpenArtifact => app.process_open_artifact(),
+        Action::ExportProcessReport => app.start_process_report(),
+        Action::SearchProcessOutput => app.process_search_start(),
+        Action::StepProcessMatch => app.process_search_step(key.code == KeyCode::Char('n')),
+        Action::JumpToProcessError => app.process_jump_to_error(),
+        Action::RequestDelete => app.request_delete(),
+        Action::CheckAuth => app.check_auth_status(),
+        Action::RepoSettings => app.open_repo_settings(),
+        Action::StarterConfig => app.write_starter_config(),
     }
 }
 
@@ -670,17 +2055,31 @@ fn handle_send_key(app: &mut App, key: KeyEvent) {
         KeyCode::Enter => {
             app.execute_send();
         }
+        KeyCode::Tab => app.cycle_send_target(),
         KeyCode::Backspace => {
             app.send_input.pop();
         }
+        KeyCode::Char(c @ '1'..='9') if key.modifiers.contains(KeyModifiers::ALT) => {
+            app.send_snippet(c as usize - '1' as usize);
+        }
         KeyCode::Char(c) => {
-            app.send_input.push(c);
+            // A digit typed into an empty input sends that snippet, if there is one
+            let snippet = c
+                .to_digit(10)
+                .filter(|d| *d >= 1 && app.send_input.is_empty());
+            if !snippet.is_some_and(|d| app.send_snippet(d as usize - 1)) {
+                app.send_input.push(c);
+            }
         }
         _ => {}
     }
 }
 
 fn handle_issues_edit_key(app: &mut App, key: KeyEvent) {
+    if app.gh_issues_conflict.is_some() {
+        handle_issues_conflict_key(app, key);
+        return;
+    }
     match key.code {
         KeyCode::Char('s') if key.modifiers.contains(KeyModifiers::CONTROL) => {
             app.issues_save_edit();
@@ -715,6 +2114,103 @@ fn handle_issues_edit_key(app: &mut App, key: KeyEvent) {
     }
 }
 
+/// The dialog shown when the issue changed upstream while being edited.
+fn handle_issues_conflict_key(app: &mut App, key: KeyEvent) {
+    match key.code {
+        KeyCode::Char('o') => app.issues_conflict_overwrite(),
+        KeyCode::Char('m') => app.issues_conflict_merge(),
+        KeyCode::Char('c') => app.issues_conflict_comment(),
+        KeyCode::Char('j') | KeyCode::Down => app.issues_conflict_scroll(true),
+        KeyCode::Char('k') | KeyCode::Up => app.issues_conflict_scroll(false),
+        KeyCode::Esc => app.issues_conflict_cancel(),
+        _ => {}
+    }
+}
+
+fn handle_task_edit_key(app: &mut App, key: KeyEvent) {
+    match key.code {
+        KeyCode::Char('s') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+            app.task_save_edit();
+            return;
+        }
+        KeyCode::Esc => {
+            app.task_cancel_edit();
+            return;
+        }
+        KeyCode::Tab => {
+            app.task_editor_cycle_field(true);
+            return;
+        }
+        KeyCode::BackTab => {
+            app.task_editor_cycle_field(false);
+            return;
+        }
+        _ => {}
+    }
+
+    let Some(field) = app.task_editor.as_ref().map(|e| e.field) else {
+        return;
+    };
+    match field {
+        // The subject is a single line; Enter moves on to the description
+        app::TaskEditField::Subject if key.code == KeyCode::Enter => {
+            app.task_editor_cycle_field(true);
+        }
+        app::TaskEditField::Subject => {
+            if let Some(editor) = app.task_editor.as_mut() {
+                editor.subject.input(key);
+            }
+        }
+        app::TaskEditField::Description => {
+            if let Some(editor) = app.task_editor.as_mut() {
+                editor.description.input(key);
+            }
+        }
+        app::TaskEditField::Owner => match key.code {
+            KeyCode::Char('j')
+            | KeyCode::Char('l')
+            | KeyCode::Char(' ')
+            | KeyCode::Down
+            | KeyCode::Right => app.task_editor_cycle_owner(true),
+            KeyCode::Char('k') | KeyCode::Char('h') | KeyCode::Up | KeyCode::Left => {
+                app.task_editor_cycle_owner(false)
+            }
+            _ => {}
+        },
+        app::TaskEditField::BlockedBy => match key.code {
+            KeyCode::Char('j') | KeyCode::Down => app.task_editor_move_blocker(true),
+            KeyCode::Char('k') | KeyCode::Up => app.task_editor_move_blocker(false),
+            KeyCode::Char(' ') => app.task_editor_toggle_blocker(),
+            _ => {}
+        },
+    }
+}
+
+fn handle_broadcast_key(app: &mut App, key: KeyEvent) {
+    if app.broadcast.as_ref().is_some_and(|b| b.confirming) {
+        match key.code {
+            KeyCode::Char('y') | KeyCode::Char('Y') => app.broadcast_send(),
+            KeyCode::Char('n') | KeyCode::Char('N') | KeyCode::Esc => app.broadcast_cancel(),
+            _ => {}
+        }
+        return;
+    }
+
+    match key.code {
+        KeyCode::Char('s') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+            app.broadcast_request_send();
+        }
+        KeyCode::Esc => app.broadcast_cancel(),
+        KeyCode::Tab => app.broadcast_cycle_template(true),
+        KeyCode::BackTab => app.broadcast_cycle_template(false),
+        _ => {
+            if let Some(composer) = app.broadcast.as_mut() {
+                composer.editor.input(key);
+            }
+        }
+    }
+}
+
 fn handle_prompt_picker_key(app: &mut App, key: KeyEvent) {
     match key.code {
         KeyCode::Char('j') | KeyCode::Down => {
@@ -736,11 +2232,67 @@ fn handle_prompt_picker_key(app: &mut App, key: KeyEvent) {
 }
 
 fn handle_prompt_modal_key(app: &mut App, key: KeyEvent) {
+    // Typing the launch's extra arguments
+    if let Some(ref mut input) = app.prompt_args_input {
+        match key.code {
+            KeyCode::Enter => app.apply_prompt_args_edit(),
+            KeyCode::Esc => app.prompt_args_input = None,
+            KeyCode::Backspace => {
+                input.pop();
+            }
+            KeyCode::Char(c) => input.push(c),
+            _ => {}
+        }
+        return;
+    }
+
+    // Typing the launch's max turns
+    if let Some(ref mut input) = app.prompt_turns_input {
+        match key.code {
+            KeyCode::Enter => app.apply_prompt_turns_edit(),
+            KeyCode::Esc => app.prompt_turns_input = None,
+            KeyCode::Backspace => {
+                input.pop();
+            }
+            KeyCode::Char(c) if c.is_ascii_digit() => input.push(c),
+            _ => {}
+        }
+        return;
+    }
+
+    // Dry run preview: confirm the launch's command line, or go back to the editor
+    if app.prompt_preview.is_some() {
+        match key.code {
+            KeyCode::Enter if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                app.confirm_prompt_modal();
+            }
+            KeyCode::Char('t') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                app.toggle_prompt_preview();
+            }
+            KeyCode::Esc => app.toggle_prompt_preview(),
+            KeyCode::Char('a') => app.start_prompt_args_edit(),
+            KeyCode::Char('p') => app.toggle_prompt_skip_permissions(),
+            KeyCode::Char('m') => app.cycle_prompt_model(),
+            KeyCode::Char('g') => app.cycle_prompt_agent(),
+            KeyCode::Char('t') => app.start_prompt_turns_edit(),
+            KeyCode::Char('j') | KeyCode::Down => app.prompt_preview_scroll(true, 1),
+            KeyCode::Char('k') | KeyCode::Up => app.prompt_preview_scroll(false, 1),
+            KeyCode::PageDown => app.prompt_preview_scroll(true, 10),
+            KeyCode::PageUp => app.prompt_preview_scroll(false, 10),
+            _ => {}
+        }
+        return;
+    }
+
     match key.code {
-        // Ctrl+Enter to confirm and launch
+        // Ctrl+Enter to review the command line before launching
         KeyCode::Enter if key.modifiers.contains(KeyModifiers::CONTROL) => {
             app.confirm_prompt_modal();
         }
+        // Ctrl+T to preview the prompt a launch would send
+        KeyCode::Char('t') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+            app.toggle_prompt_preview();
+        }
         // Esc to cancel
         KeyCode::Esc => {
             app.cancel_prompt_modal();
@@ -754,6 +2306,98 @@ fn handle_prompt_modal_key(app: &mut App, key: KeyEvent) {
     }
 }
 
+fn handle_branch_picker_key(app: &mut App, key: KeyEvent) {
+    // "Launch Claude in this worktree?" confirmation
+    if app.branch_picker_launch_path.is_some() {
+        match key.code {
+            KeyCode::Char('y') | KeyCode::Char('Y') => app.branch_picker_confirm_launch(true),
+            KeyCode::Char('n') | KeyCode::Char('N') | KeyCode::Esc => {
+                app.branch_picker_confirm_launch(false)
+            }
+            _ => {}
+        }
+        return;
+    }
+
+    // New branch / worktree name input
+    if app.branch_picker_input.is_some() {
+        match key.code {
+            KeyCode::Esc => app.branch_picker_cancel_input(),
+            KeyCode::Enter => app.branch_picker_submit_input(),
+            KeyCode::Backspace => {
+                app.branch_picker_input_text.pop();
+            }
+            KeyCode::Char(c) => app.branch_picker_input_text.push(c),
+            _ => {}
+        }
+        return;
+    }
+
+    match key.code {
+        KeyCode::Char('j') | KeyCode::Down => app.branch_picker_down(),
+        KeyCode::Char('k') | KeyCode::Up => app.branch_picker_up(),
+        KeyCode::Enter => app.branch_picker_select(),
+        KeyCode::Char('n') => app.branch_picker_start_input(app::BranchInputMode::NewBranch),
+        KeyCode::Char('w') => app.branch_picker_start_input(app::BranchInputMode::NewWorktree),
+        KeyCode::Esc | KeyCode::Char('B') | KeyCode::Char('q') => app.close_branch_picker(),
+        _ => {}
+    }
+}
+
+fn handle_plan_edit_key(app: &mut App, key: KeyEvent) {
+    match key.code {
+        KeyCode::Char('s') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+            app.plan_save_edit();
+        }
+        KeyCode::Esc => app.plan_cancel_edit(),
+        _ => {
+            if let Some(ref mut editor) = app.plan_editor {
+                editor.input(key);
+            }
+        }
+    }
+}
+
+fn handle_command_edit_key(app: &mut App, key: KeyEvent) {
+    match key.code {
+        KeyCode::Char('s') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+            app.command_save_edit();
+        }
+        KeyCode::Esc => app.command_cancel_edit(),
+        _ => {
+            if let Some(ref mut editor) = app.command_editor {
+                editor.input(key);
+            }
+        }
+    }
+}
+
+fn handle_notes_edit_key(app: &mut App, key: KeyEvent) {
+    match key.code {
+        KeyCode::Char('s') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+            app.notes_save_edit();
+        }
+        KeyCode::Esc => app.notes_cancel_edit(),
+        _ => {
+            if let Some(ref mut editor) = app.notes_editor {
+                editor.input(key);
+            }
+        }
+    }
+}
+
+fn handle_plan_merge_key(app: &mut App, key: KeyEvent) {
+    match key.code {
+        KeyCode::Char('j') | KeyCode::Down => app.plan_merge_next(),
+        KeyCode::Char('k') | KeyCode::Up => app.plan_merge_prev(),
+        KeyCode::Char('a') => app.plan_merge_decide(true),
+        KeyCode::Char('r') => app.plan_merge_decide(false),
+        KeyCode::Enter => app.plan_merge_finish(false),
+        KeyCode::Esc => app.plan_merge_finish(true),
+        _ => {}
+    }
+}
+
 fn handle_fb_edit_key(app: &mut App, key: KeyEvent) {
     match key.code {
         KeyCode::Char('s') if key.modifiers.contains(KeyModifiers::CONTROL) => {
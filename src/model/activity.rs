@@ -0,0 +1,273 @@
+use std::collections::HashMap;
+
+use chrono::{DateTime, Local};
+
+use crate::model::github::{GitHubIssue, IssueComment, PullRequest};
+use crate::model::jira::JiraIssue;
+use crate::model::linear::LinearIssue;
+
+/// Most entries kept in the activity feed.
+pub const MAX_ACTIVITY: usize = 200;
+
+/// Integration an activity entry came from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ActivitySource {
+    GitHubPr,
+    GitHubIssue,
+    Jira,
+    Linear,
+}
+
+impl ActivitySource {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::GitHubPr => "PR",
+            Self::GitHubIssue => "Issue",
+            Self::Jira => "Jira",
+            Self::Linear => "Linear",
+        }
+    }
+}
+
+/// One change noticed between two polls.
+#[derive(Debug, Clone)]
+pub struct ActivityItem {
+    pub at: DateTime<Local>,
+    pub source: ActivitySource,
+    /// PR/issue number or tracker key, used to jump to the item.
+    pub item_id: String,
+    pub title: String,
+    /// What changed, e.g. "New comment by bob".
+    pub summary: String,
+    pub read: bool,
+}
+
+/// The parts of a PR or issue that are compared between polls.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ItemState {
+    pub title: String,
+    pub status: String,
+    pub comments: usize,
+    /// Author of the newest comment.
+    pub last_commenter: Option<String>,
+    pub assignees: Vec<String>,
+    pub review: Option<String>,
+    /// The tracker's last-updated time, for changes not compared above.
+    pub updated: String,
+}
+
+impl ItemState {
+    pub fn from_pr(pr: &PullRequest) -> (String, Self) {
+        let state = Self {
+            title: pr.title.clone(),
+            status: pr.state.clone(),
+            comments: pr.comments.len(),
+            last_commenter: last_commenter(&pr.comments),
+            assignees: pr.assignees.iter().map(|a| a.login.clone()).collect(),
+            review: pr.review_decision.clone().filter(|r| !r.is_empty()),
+            updated: pr.updated_at.clone(),
+        };
+        (pr.number.to_string(), state)
+    }
+
+    pub fn from_github_issue(issue: &GitHubIssue) -> (String, Self) {
+        let state = Self {
+            title: issue.title.clone(),
+            status: issue.state.clone(),
+            comments: issue.comments.len(),
+            last_commenter: last_commenter(&issue.comments),
+            assignees: issue.assignees.iter().map(|a| a.login.clone()).collect(),
+            review: None,
+            updated: issue.updated_at.clone(),
+        };
+        (issue.number.to_string(), state)
+    }
+
+    pub fn from_jira(issue: &JiraIssue) -> (String, Self) {
+        let state = Self {
+            title: issue.summary.clone(),
+            status: issue.status_name.clone(),
+            ..Self::default()
+        };
+        (issue.key.clone(), state)
+    }
+
+    pub fn from_linear(issue: &LinearIssue) -> (String, Self) {
+        let state = Self {
+            title: issue.title.clone(),
+            status: issue.state.name.clone(),
+            assignees: issue.assignee.iter().map(|a| a.name.clone()).collect(),
+            updated: issue.updated_at.clone(),
+            ..Self::default()
+        };
+        (issue.identifier.clone(), state)
+    }
+}
+
+fn last_commenter(comments: &[IssueComment]) -> Option<String> {
+    comments.last().map(|c| c.author.login.clone())
+}
+
+/// Last polled state of every item, per source.
+#[derive(Debug, Default)]
+pub struct ActivityTracker {
+    known: HashMap<ActivitySource, HashMap<String, ItemState>>,
+}
+
+impl ActivityTracker {
+    /// Record `source`'s freshly polled items and return what changed since
+    /// the previous poll. The first poll of a source only sets the baseline.
+    /// `me` is matched against assignees for "Assigned to you".
+    pub fn update(
+        &mut self,
+        source: ActivitySource,
+        items: Vec<(String, ItemState)>,
+        me: Option<&str>,
+    ) -> Vec<ActivityItem> {
+        let current: HashMap<String, ItemState> = items.into_iter().collect();
+        let Some(previous) = self.known.insert(source, current) else {
+            return Vec::new();
+        };
+        let current = &self.known[&source];
+
+        let now = Local::now();
+        let mut changes = Vec::new();
+        let mut ids: Vec<&String> = current.keys().collect();
+        ids.sort();
+        for id in ids {
+            let new = &current[id];
+            let mut summaries = Vec::new();
+            match previous.get(id) {
+                None => summaries.push("New".to_string()),
+                Some(old) => {
+                    if !old.status.eq_ignore_ascii_case(&new.status) {
+                        summaries.push(format!("Status: {} → {}", old.status, new.status));
+                    }
+                    if new.comments > old.comments {
+                        let added = new.comments - old.comments;
+                        summaries.push(match (&new.last_commenter, added) {
+                            (Some(who), 1) => format!("New comment by {}", who),
+                            (Some(who), n) => format!("{} new comments, latest by {}", n, who),
+                            (None, n) => format!("{} new comments", n),
+                        });
+                    }
+                    if let Some(me) = me.filter(|me| !me.is_empty()) {
+                        let has_me =
+                            |s: &ItemState| s.assignees.iter().any(|a| a.eq_ignore_ascii_case(me));
+                        if has_me(new) && !has_me(old) {
+                            summaries.push("Assigned to you".to_string());
+                        }
+                    }
+                    if new.review != old.review {
+                        if let Some(review) = &new.review {
+                            summaries.push(format!("Review: {}", review.to_lowercase()));
+                        }
+                    }
+                    if summaries.is_empty() && new.updated != old.updated {
+                        summaries.push("Updated".to_string());
+                    }
+                }
+            }
+            changes.extend(summaries.into_iter().map(|summary| ActivityItem {
+                at: now,
+                source,
+                item_id: id.clone(),
+                title: new.title.clone(),
+                summary,
+                read: false,
+            }));
+        }
+        changes
+    }
+
+    /// Drop `source`'s baseline so its next poll only sets a new one, e.g.
+    /// after switching the repo it polls.
+    pub fn forget(&mut self, source: ActivitySource) {
+        self.known.remove(&source);
+    }
+
+    /// Add items from a further page of `source` to its baseline without
+    /// reporting them.
+    pub fn extend(&mut self, source: ActivitySource, items: Vec<(String, ItemState)>) {
+        self.known.entry(source).or_default().extend(items);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reports_changes_after_baseline() {
+        let state = |status: &str, comments: usize, assignees: &[&str]| ItemState {
+            title: "Fix login".to_string(),
+            status: status.to_string(),
+            comments,
+            last_commenter: (comments > 0).then(|| "bob".to_string()),
+            assignees: assignees.iter().map(|a| a.to_string()).collect(),
+            review: None,
+            updated: String::new(),
+        };
+        let mut tracker = ActivityTracker::default();
+        let source = ActivitySource::GitHubIssue;
+        assert!(tracker
+            .update(
+                source,
+                vec![("1".into(), state("OPEN", 0, &[]))],
+                Some("me")
+            )
+            .is_empty());
+
+        let changes = tracker.update(
+            source,
+            vec![
+                ("1".into(), state("OPEN", 1, &["me"])),
+                ("2".into(), state("OPEN", 0, &[])),
+            ],
+            Some("me"),
+        );
+        let summaries: Vec<(&str, &str)> = changes
+            .iter()
+            .map(|c| (c.item_id.as_str(), c.summary.as_str()))
+            .collect();
+        assert_eq!(
+            summaries,
+            [
+                ("1", "New comment by bob"),
+                ("1", "Assigned to you"),
+                ("2", "New")
+            ]
+        );
+
+        let changes = tracker.update(
+            source,
+            vec![("1".into(), state("CLOSED", 1, &["me"]))],
+            None,
+        );
+        assert_eq!(changes.len(), 1);
+        assert_eq!(changes[0].summary, "Status: OPEN → CLOSED");
+    }
+
+    #[test]
+    fn a_further_page_is_not_reported() {
+        let state = |title: &str| ItemState {
+            title: title.to_string(),
+            status: "OPEN".to_string(),
+            comments: 0,
+            last_commenter: None,
+            assignees: Vec::new(),
+            review: None,
+            updated: String::new(),
+        };
+        let mut tracker = ActivityTracker::default();
+        let source = ActivitySource::GitHubPr;
+        let page = vec![("1".into(), state("Fix login"))];
+        tracker.update(source, page.clone(), None);
+
+        // Loading more refetches the first page along with the second
+        let mut more = page;
+        more.push(("2".into(), state("Add logout")));
+        tracker.extend(source, more.clone());
+        assert!(tracker.update(source, more, None).is_empty());
+    }
+}
@@ -1,9 +1,16 @@
 use std::collections::HashMap;
 
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
 use crate::model::inbox::InboxMessage;
 use crate::model::task::{Task, TaskStatus};
 
-#[derive(Debug, Clone, PartialEq)]
+/// Most status changes kept per member; older ones are dropped.
+const MAX_STATUS_CHANGES: usize = 500;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
 pub enum AgentStatus {
     Starting, // in config but no inbox messages from this agent
     Working,  // owns an in_progress task, or last message is not idle/shutdown
@@ -86,3 +93,83 @@ pub fn derive_all_statuses(
         })
         .collect()
 }
+
+/// A member's status as first seen at `at`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StatusChange {
+    pub at: DateTime<Utc>,
+    pub status: AgentStatus,
+}
+
+/// When each member of a team changed status, oldest first.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct StatusHistory {
+    #[serde(default)]
+    members: HashMap<String, Vec<StatusChange>>,
+}
+
+impl StatusHistory {
+    /// Record the statuses seen at `now`, adding a change for each member
+    /// whose status differs from the last one recorded. Returns whether
+    /// anything was added.
+    pub fn record(&mut self, statuses: &HashMap<String, AgentStatus>, now: DateTime<Utc>) -> bool {
+        let mut changed = false;
+        for (name, status) in statuses {
+            let changes = self.members.entry(name.clone()).or_default();
+            if changes.last().is_some_and(|c| c.status == *status) {
+                continue;
+            }
+            changes.push(StatusChange {
+                at: now,
+                status: status.clone(),
+            });
+            let excess = changes.len().saturating_sub(MAX_STATUS_CHANGES);
+            changes.drain(..excess);
+            changed = true;
+        }
+        changed
+    }
+
+    pub fn changes(&self, member: &str) -> &[StatusChange] {
+        self.members.get(member).map(Vec::as_slice).unwrap_or(&[])
+    }
+
+    /// The member's status at `at`, if anything was recorded by then.
+    pub fn status_at(&self, member: &str, at: DateTime<Utc>) -> Option<&AgentStatus> {
+        self.changes(member)
+            .iter()
+            .rev()
+            .find(|c| c.at <= at)
+            .map(|c| &c.status)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn history_records_only_transitions() {
+        let at = |m: i64| DateTime::from_timestamp(1_700_000_000 + m * 60, 0).unwrap();
+        let statuses = |s: AgentStatus| HashMap::from([("tester".to_string(), s)]);
+        let mut history = StatusHistory::default();
+
+        assert!(history.record(&statuses(AgentStatus::Starting), at(0)));
+        assert!(history.record(&statuses(AgentStatus::Working), at(2)));
+        assert!(!history.record(&statuses(AgentStatus::Working), at(5)));
+        assert!(history.record(&statuses(AgentStatus::Idle), at(30)));
+
+        assert_eq!(history.changes("tester").len(), 3);
+        assert_eq!(
+            history.status_at("tester", at(10)),
+            Some(&AgentStatus::Working)
+        );
+        assert_eq!(history.status_at("tester", at(-1)), None);
+        assert_eq!(history.status_at("other", at(10)), None);
+
+        let json = serde_json::to_string(&history).unwrap();
+        assert!(json.contains(r#""status":"idle""#));
+        let loaded: StatusHistory = serde_json::from_str(&json).unwrap();
+        assert_eq!(loaded.changes("tester"), history.changes("tester"));
+    }
+}
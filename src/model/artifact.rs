@@ -0,0 +1,246 @@
+use std::collections::BTreeMap;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::model::link_preview::find_urls;
+
+/// Most notes kept per ticket; older ones are dropped.
+const MAX_NOTES_PER_TICKET: usize = 50;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ArtifactKind {
+    PullRequest,
+    Issue,
+    Commit,
+}
+
+impl ArtifactKind {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::PullRequest => "PR",
+            Self::Issue => "issue",
+            Self::Commit => "commit",
+        }
+    }
+}
+
+/// A pull request, issue or commit a spawned process linked to in its
+/// output.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Artifact {
+    pub kind: ArtifactKind,
+    /// Short reference such as `owner/repo#12`, `PROJ-7` or `a1b2c3d`.
+    pub reference: String,
+    pub url: String,
+}
+
+/// Recognize a GitHub, Bitbucket, Azure DevOps, Jira or Linear URL for a
+/// pull request, issue or commit.
+pub fn parse_artifact(url: &str) -> Option<Artifact> {
+    let rest = url
+        .strip_prefix("https://")
+        .or_else(|| url.strip_prefix("http://"))?;
+    let rest = rest.split(['?', '#']).next().unwrap_or(rest);
+    let parts: Vec<&str> = rest.split('/').filter(|p| !p.is_empty()).collect();
+    let host = *parts.first()?;
+    let artifact = |kind, reference: String| {
+        Some(Artifact {
+            kind,
+            reference,
+            url: url.to_string(),
+        })
+    };
+
+    match (host, &parts[1..]) {
+        ("github.com", [owner, repo, section, id, ..]) => {
+            let repo = format!("{}/{}", owner, repo);
+            match *section {
+                "pull" if is_number(id) => {
+                    artifact(ArtifactKind::PullRequest, format!("{}#{}", repo, id))
+                }
+                "issues" if is_number(id) => {
+                    artifact(ArtifactKind::Issue, format!("{}#{}", repo, id))
+                }
+                "commit" if is_sha(id) => {
+                    artifact(ArtifactKind::Commit, format!("{}@{}", repo, short_sha(id)))
+                }
+                _ => None,
+            }
+        }
+        ("bitbucket.org", [owner, repo, section, id, ..]) => {
+            let repo = format!("{}/{}", owner, repo);
+            match *section {
+                "pull-requests" if is_number(id) => {
+                    artifact(ArtifactKind::PullRequest, format!("{}#{}", repo, id))
+                }
+                "commits" if is_sha(id) => {
+                    artifact(ArtifactKind::Commit, format!("{}@{}", repo, short_sha(id)))
+                }
+                _ => None,
+            }
+        }
+        ("dev.azure.com", path) => {
+            let at = |name: &str| path.iter().position(|p| p.eq_ignore_ascii_case(name));
+            if let Some(i) = at("pullrequest") {
+                let id = path.get(i + 1).filter(|id| is_number(id))?;
+                let repo = path.get(i.checked_sub(1)?)?;
+                artifact(ArtifactKind::PullRequest, format!("{}!{}", repo, id))
+            } else if let Some(i) = at("_workitems") {
+                let id = path.get(i + 2).filter(|id| is_number(id))?;
+                artifact(ArtifactKind::Issue, format!("#{}", id))
+            } else if let Some(i) = at("commit") {
+                let id = path.get(i + 1).filter(|id| is_sha(id))?;
+                let repo = path.get(i.checked_sub(1)?)?;
+                artifact(ArtifactKind::Commit, format!("{}@{}", repo, short_sha(id)))
+            } else {
+                None
+            }
+        }
+        ("linear.app", [_, "issue", key, ..]) if is_issue_key(key) => {
+            artifact(ArtifactKind::Issue, key.to_string())
+        }
+        (host, [.., "browse", key]) if host.ends_with(".atlassian.net") && is_issue_key(key) => {
+            artifact(ArtifactKind::Issue, key.to_string())
+        }
+        _ => None,
+    }
+}
+
+/// Artifacts linked in one line of process output, without duplicates.
+/// Lines of `stream-json` are decoded first, so links inside escaped text
+/// are found too.
+pub fn find_artifacts(line: &str) -> Vec<Artifact> {
+    let mut texts = Vec::new();
+    match serde_json::from_str::<serde_json::Value>(line) {
+        Ok(value) => collect_strings(&value, &mut texts),
+        Err(_) => texts.push(line.to_string()),
+    }
+    let mut artifacts: Vec<Artifact> = Vec::new();
+    for text in &texts {
+        for url in find_urls(text) {
+            if let Some(artifact) = parse_artifact(&url) {
+                if !artifacts.iter().any(|a| a.url == artifact.url) {
+                    artifacts.push(artifact);
+                }
+            }
+        }
+    }
+    artifacts
+}
+
+fn collect_strings(value: &serde_json::Value, out: &mut Vec<String>) {
+    match value {
+        serde_json::Value::String(s) => out.push(s.clone()),
+        serde_json::Value::Array(items) => items.iter().for_each(|v| collect_strings(v, out)),
+        serde_json::Value::Object(map) => map.values().for_each(|v| collect_strings(v, out)),
+        _ => {}
+    }
+}
+
+fn is_number(s: &str) -> bool {
+    !s.is_empty() && s.chars().all(|c| c.is_ascii_digit())
+}
+
+fn is_sha(s: &str) -> bool {
+    s.len() >= 7 && s.chars().all(|c| c.is_ascii_hexdigit())
+}
+
+fn short_sha(sha: &str) -> &str {
+    &sha[..7]
+}
+
+/// `PROJ-123`: uppercase letters or digits, a dash, then a number.
+fn is_issue_key(s: &str) -> bool {
+    s.split_once('-').is_some_and(|(project, number)| {
+        project.starts_with(|c: char| c.is_ascii_uppercase())
+            && project
+                .chars()
+                .all(|c| c.is_ascii_uppercase() || c.is_ascii_digit())
+            && is_number(number)
+    })
+}
+
+/// An artifact recorded against the ticket whose process produced it.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TicketNote {
+    pub at: DateTime<Utc>,
+    /// Title of the process that printed the link.
+    pub process: String,
+    pub artifact: Artifact,
+}
+
+/// Run outcomes per ticket, keyed by the ticket's URL.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct TicketNotes {
+    #[serde(default)]
+    tickets: BTreeMap<String, Vec<TicketNote>>,
+}
+
+impl TicketNotes {
+    /// Add a note unless the ticket already has one for the same artifact.
+    /// Returns whether it was added.
+    pub fn add(&mut self, ticket_url: &str, note: TicketNote) -> bool {
+        let notes = self.tickets.entry(ticket_url.to_string()).or_default();
+        if notes.iter().any(|n| n.artifact.url == note.artifact.url) {
+            return false;
+        }
+        notes.push(note);
+        let excess = notes.len().saturating_sub(MAX_NOTES_PER_TICKET);
+        notes.drain(..excess);
+        true
+    }
+
+    pub fn for_ticket(&self, ticket_url: &str) -> &[TicketNote] {
+        self.tickets
+            .get(ticket_url)
+            .map(Vec::as_slice)
+            .unwrap_or(&[])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finds_artifacts_in_process_output() {
+        let line = r#"{"type":"user","message":{"content":[{"type":"tool_result","content":"https://github.com/acme/app/pull/42\n"}]}}"#;
+        let found = find_artifacts(line);
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].kind, ArtifactKind::PullRequest);
+        assert_eq!(found[0].reference, "acme/app#42");
+        assert_eq!(found[0].url, "https://github.com/acme/app/pull/42");
+
+        let plain = "Opened https://acme.atlassian.net/browse/PROJ-7, pushed \
+                     https://github.com/acme/app/commit/0123456789abcdef and see https://docs.rs/serde";
+        let refs: Vec<String> = find_artifacts(plain)
+            .into_iter()
+            .map(|a| format!("{} {}", a.kind.label(), a.reference))
+            .collect();
+        assert_eq!(refs, ["issue PROJ-7", "commit acme/app@0123456"]);
+
+        let azure = "https://dev.azure.com/org/proj/_git/web/pullrequest/15";
+        assert_eq!(parse_artifact(azure).unwrap().reference, "web!15");
+        let linear = "https://linear.app/acme/issue/ENG-9/fix-login";
+        assert_eq!(parse_artifact(linear).unwrap().reference, "ENG-9");
+        assert_eq!(parse_artifact("https://github.com/acme/app/pulls"), None);
+    }
+
+    #[test]
+    fn ticket_notes_skip_duplicate_artifacts() {
+        let artifact = parse_artifact("https://github.com/acme/app/issues/3").unwrap();
+        let note = TicketNote {
+            at: DateTime::from_timestamp(1_700_000_000, 0).unwrap(),
+            process: "Fix login".to_string(),
+            artifact,
+        };
+        let mut notes = TicketNotes::default();
+        let ticket = "https://acme.atlassian.net/browse/PROJ-1";
+        assert!(notes.add(ticket, note.clone()));
+        assert!(!notes.add(ticket, note));
+        assert_eq!(notes.for_ticket(ticket).len(), 1);
+        assert!(notes.for_ticket("https://example.com").is_empty());
+    }
+}
@@ -0,0 +1,67 @@
+/// An Azure Boards work item, flattened from its `System.*` fields.
+#[derive(Debug, Clone)]
+pub struct WorkItem {
+    pub id: u64,
+    pub title: String,
+    pub state: String,
+    pub work_item_type: String,
+    pub assigned_to: Option<String>,
+    pub priority: Option<u64>,
+    pub tags: Vec<String>,
+    pub project: String,
+    /// Plain text converted from the HTML description. Only filled in by
+    /// the detail view.
+    pub description: Option<String>,
+    pub url: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct WorkItemComment {
+    pub author: String,
+    pub created: String,
+    pub text: String,
+}
+
+#[derive(Debug, Clone)]
+pub enum FlatAzureItem {
+    StateHeader(String),
+    WorkItem(Box<WorkItem>),
+}
+
+impl WorkItem {
+    /// Key used for prompts and process labels, in Azure Boards' `AB#123` form.
+    pub fn key(&self) -> String {
+        format!("AB#{}", self.id)
+    }
+
+    /// Icon based on work item type.
+    pub fn type_icon(&self) -> &'static str {
+        match self.work_item_type.to_lowercase().as_str() {
+            "bug" => "B",
+            "user story" | "product backlog item" | "requirement" => "S",
+            "task" => "T",
+            "feature" => "F",
+            "epic" => "E",
+            "issue" | "impediment" => "I",
+            _ => "?",
+        }
+    }
+
+    /// Sort rank of the work item's state: in-progress states first, then
+    /// new ones, then resolved, then anything else. Covers the Agile, Scrum,
+    /// Basic and CMMI process templates.
+    pub fn state_rank(&self) -> u8 {
+        match self.state.to_lowercase().as_str() {
+            "active" | "committed" | "doing" | "in progress" => 0,
+            "new" | "approved" | "to do" | "proposed" => 1,
+            "resolved" => 2,
+            "closed" | "done" | "removed" => 4,
+            _ => 3,
+        }
+    }
+
+    /// Whether the work item is finished and off the open list.
+    pub fn is_closed(&self) -> bool {
+        self.state_rank() == 4
+    }
+}
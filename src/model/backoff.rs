@@ -0,0 +1,100 @@
+use std::time::Duration;
+
+/// Failures in a row before an integration shows as down.
+pub const DOWN_AFTER: u32 = 3;
+
+/// Longest wait between polls of a failing integration.
+const MAX_DELAY: Duration = Duration::from_secs(30 * 60);
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PollHealth {
+    Ok,
+    /// The last poll failed; the next waits longer.
+    Retrying,
+    /// Failed `DOWN_AFTER` times in a row, or rate limited.
+    Down,
+}
+
+/// Failed background polls of one integration since the last success. Each failure in a row
+/// doubles the time to the next poll, up to 30 minutes, and only the first
+/// error of a run is reported so a broken CLI doesn't flood the status bar.
+#[derive(Debug, Clone, Default)]
+pub struct Backoff {
+    failures: u32,
+    /// Report the next failure even mid-run; set by a manual refresh.
+    report_next: bool,
+}
+
+impl Backoff {
+    /// Record a failed poll; returns whether to report `error`. A rate
+    /// limit skips straight to the long delays.
+    pub fn failed(&mut self, error: &str) -> bool {
+        let report = self.failures == 0 || std::mem::take(&mut self.report_next);
+        self.failures += 1;
+        if is_rate_limited(error) {
+            self.failures = self.failures.max(DOWN_AFTER);
+        }
+        report
+    }
+
+    /// A refresh was asked for by hand: its error is worth showing.
+    pub fn refresh_requested(&mut self) {
+        self.report_next = true;
+    }
+
+    /// Time from one poll to the next, given the configured `interval`.
+    pub fn delay(&self, interval: Duration) -> Duration {
+        if self.failures == 0 {
+            return interval;
+        }
+        let factor = 1u32 << self.failures.min(16);
+        interval.saturating_mul(factor).min(MAX_DELAY.max(interval))
+    }
+
+    pub fn health(&self) -> PollHealth {
+        match self.failures {
+            0 => PollHealth::Ok,
+            n if n < DOWN_AFTER => PollHealth::Retrying,
+            _ => PollHealth::Down,
+        }
+    }
+}
+
+/// Whether a CLI or API error says requests are being throttled.
+fn is_rate_limited(error: &str) -> bool {
+    let error = error.to_lowercase();
+    ["rate limit", "too many requests", "429"]
+        .iter()
+        .any(|pattern| error.contains(pattern))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn backs_off_and_reports_the_first_failure_of_a_run() {
+        let minute = Duration::from_secs(60);
+        let mut backoff = Backoff::default();
+        assert_eq!(backoff.delay(minute), minute);
+
+        assert!(backoff.failed("gh: connection refused"));
+        assert!(!backoff.failed("gh: connection refused"));
+        assert_eq!(backoff.delay(minute), minute * 4);
+        assert_eq!(backoff.health(), PollHealth::Retrying);
+
+        backoff.refresh_requested();
+        assert!(backoff.failed("gh: connection refused"));
+        assert_eq!(backoff.health(), PollHealth::Down);
+        for _ in 0..10 {
+            backoff.failed("gh: connection refused");
+        }
+        assert_eq!(backoff.delay(minute), MAX_DELAY);
+
+        // A success starts over
+        let mut backoff = Backoff::default();
+        assert!(backoff.failed("HTTP 403: API rate limit exceeded for user"));
+        assert_eq!(backoff.health(), PollHealth::Down);
+        assert_eq!(backoff.delay(minute), minute * 8);
+    }
+}
@@ -0,0 +1,110 @@
+use std::path::PathBuf;
+
+use crate::model::hook::SettingsScope;
+
+/// Scopes from the one that wins to the one that loses when a value is set
+/// in more than one file.
+pub const PRECEDENCE: [SettingsScope; 3] = [
+    SettingsScope::Local,
+    SettingsScope::Project,
+    SettingsScope::User,
+];
+
+/// A group of values in the Settings tab, in the order they're listed.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SettingsSection {
+    Model,
+    Allow,
+    Ask,
+    Deny,
+    Hooks,
+}
+
+impl SettingsSection {
+    pub const ALL: [SettingsSection; 5] = [
+        SettingsSection::Model,
+        SettingsSection::Allow,
+        SettingsSection::Ask,
+        SettingsSection::Deny,
+        SettingsSection::Hooks,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            SettingsSection::Model => "Model",
+            SettingsSection::Allow => "Permissions: allow",
+            SettingsSection::Ask => "Permissions: ask",
+            SettingsSection::Deny => "Permissions: deny",
+            SettingsSection::Hooks => "Hooks",
+        }
+    }
+
+    /// How values from several files combine.
+    pub fn merge_note(&self) -> &'static str {
+        match self {
+            SettingsSection::Model => "The most specific file wins: local, then project, then user",
+            SettingsSection::Allow | SettingsSection::Ask | SettingsSection::Deny => {
+                "Rules from every file apply; deny wins over ask, and ask over allow"
+            }
+            SettingsSection::Hooks => "Hooks from every file run",
+        }
+    }
+}
+
+/// One value from a settings file.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SettingsEntry {
+    pub section: SettingsSection,
+    pub scope: SettingsScope,
+    /// The model name, the permission rule, or the hook's event and matcher.
+    pub value: String,
+    /// The hook's command; empty for the other sections.
+    pub detail: String,
+    /// The file whose value is used instead of this one.
+    pub overridden_by: Option<SettingsScope>,
+}
+
+/// A settings file and what is wrong with it.
+#[derive(Debug, Clone)]
+pub struct SettingsLayer {
+    pub scope: SettingsScope,
+    pub path: PathBuf,
+    pub exists: bool,
+    /// Parse errors and values of the wrong type.
+    pub problems: Vec<String>,
+}
+
+/// Every scope's settings file, and the values read from them.
+#[derive(Debug, Clone, Default)]
+pub struct ClaudeSettings {
+    pub layers: Vec<SettingsLayer>,
+    pub entries: Vec<SettingsEntry>,
+}
+
+impl ClaudeSettings {
+    pub fn layer(&self, scope: SettingsScope) -> Option<&SettingsLayer> {
+        self.layers.iter().find(|l| l.scope == scope)
+    }
+
+    pub fn problem_count(&self) -> usize {
+        self.layers.iter().map(|l| l.problems.len()).sum()
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum FlatSettingsItem {
+    SectionHeader(SettingsSection),
+    Entry(SettingsEntry),
+    ProblemHeader,
+    Problem(SettingsScope, String),
+}
+
+impl FlatSettingsItem {
+    /// Values and problems can be selected; headers are skipped.
+    pub fn is_selectable(&self) -> bool {
+        matches!(
+            self,
+            FlatSettingsItem::Entry(_) | FlatSettingsItem::Problem(..)
+        )
+    }
+}
@@ -0,0 +1,63 @@
+use std::path::PathBuf;
+
+use super::plan::MarkdownLine;
+
+/// Whether a file lives in the project's `.claude/` or in `~/.claude/`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CommandScope {
+    Project,
+    User,
+}
+
+/// Slash commands live in `commands/`, subagents/skills in `agents/`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CommandKind {
+    Command,
+    Agent,
+}
+
+impl CommandKind {
+    /// Directory name under `.claude/`.
+    pub fn dir_name(self) -> &'static str {
+        match self {
+            CommandKind::Command => "commands",
+            CommandKind::Agent => "agents",
+        }
+    }
+}
+
+/// A custom slash command or agent definition file.
+#[derive(Debug, Clone)]
+pub struct CommandFile {
+    pub scope: CommandScope,
+    pub kind: CommandKind,
+    /// Name relative to the scope's directory, without `.md`; subdirectories
+    /// are joined with `:` the way Claude Code namespaces commands.
+    pub name: String,
+    pub path: PathBuf,
+    /// `description:` from the file's frontmatter, if any.
+    pub description: Option<String>,
+    pub lines: Vec<MarkdownLine>,
+}
+
+impl CommandFile {
+    /// How the file is invoked: `/name` for commands, the bare name for agents.
+    pub fn display_name(&self) -> String {
+        match self.kind {
+            CommandKind::Command => format!("/{}", self.name),
+            CommandKind::Agent => self.name.clone(),
+        }
+    }
+}
+
+/// A command or agent definition fetched for import, reviewed before it is
+/// written to the project's `.claude/`.
+#[derive(Debug, Clone)]
+pub struct ImportCandidate {
+    pub kind: CommandKind,
+    /// Name it is saved under, `:`-namespaced like `CommandFile::name`.
+    pub name: String,
+    pub content: String,
+    /// URL or path it came from.
+    pub source: String,
+}
@@ -0,0 +1,30 @@
+/// An outdated dependency reported by one of the configured checkers.
+#[derive(Debug, Clone, PartialEq)]
+pub struct OutdatedDep {
+    /// Checker that reported it ("cargo" or "npm").
+    pub ecosystem: String,
+    pub name: String,
+    /// Version currently in use (None if not installed).
+    pub current: Option<String>,
+    /// Newest version allowed by the existing version requirement.
+    pub wanted: Option<String>,
+    pub latest: String,
+    /// Dependency kind, e.g. "Normal" / "Development" (cargo) or the dependent package (npm).
+    pub kind: Option<String>,
+}
+
+impl OutdatedDep {
+    pub fn current_display(&self) -> &str {
+        self.current.as_deref().unwrap_or("(missing)")
+    }
+
+    /// True if the latest version is outside the current version requirement.
+    /// No `wanted` version means no compatible update exists, so `latest`
+    /// is the breaking one.
+    pub fn is_breaking(&self) -> bool {
+        match self.wanted.as_deref() {
+            Some(wanted) => wanted != self.latest,
+            None => true,
+        }
+    }
+}
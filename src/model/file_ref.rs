@@ -0,0 +1,163 @@
+use serde_json::Value;
+
+/// Tool input keys that name the file a tool call works on.
+const PATH_KEYS: &[&str] = &["file_path", "notebook_path", "path"];
+
+/// A file mentioned in a transcript or diff, and where to open it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FileRef {
+    pub path: String,
+    /// 1-based line, when the mention gives one.
+    pub line: Option<usize>,
+    /// Text to look for when `line` is unknown, e.g. an Edit's `new_string`.
+    pub anchor: Option<String>,
+}
+
+/// Files referenced by an expanded transcript item, best first: the path
+/// argument of a tool call (its detail is the tool name, a blank line and
+/// the input as JSON), then every `path` or `path:line` mention in the text.
+pub fn transcript_refs(detail: &str) -> Vec<FileRef> {
+    let mut refs = Vec::new();
+    if let Some((_, json)) = detail.split_once("\n\n") {
+        if let Ok(input) = serde_json::from_str::<Value>(json) {
+            refs.extend(from_tool_input(&input));
+        }
+    }
+    refs.extend(mentions(detail));
+    refs
+}
+
+fn from_tool_input(input: &Value) -> Option<FileRef> {
+    let path = PATH_KEYS
+        .iter()
+        .find_map(|k| input.get(*k).and_then(Value::as_str))?;
+    let line = ["offset", "line"]
+        .iter()
+        .find_map(|k| input.get(*k).and_then(Value::as_u64))
+        .map(|n| (n as usize).max(1));
+    // MultiEdit keeps its edits in an array
+    let edit = input.get("edits").and_then(|e| e.get(0)).unwrap_or(input);
+    let anchor = edit
+        .get("new_string")
+        .and_then(Value::as_str)
+        .map(str::to_string);
+    Some(FileRef {
+        path: path.to_string(),
+        line,
+        anchor,
+    })
+}
+
+/// `path` and `path:line[:col]` mentions in free text, such as grep output
+/// or "see src/app.rs:42". Only tokens whose last component has a file
+/// extension count.
+pub fn mentions(text: &str) -> Vec<FileRef> {
+    let is_separator = |c: char| c.is_whitespace() || "\"'`()[]<>,".contains(c);
+    text.split(is_separator)
+        .filter_map(|token| {
+            let mut token = token.trim_end_matches(['.', ';', ':']);
+            // Peel `:col` and `:line` off the end
+            let mut numbers = Vec::new();
+            while let Some((rest, n)) = token.rsplit_once(':') {
+                match n.parse::<usize>() {
+                    Ok(n) => {
+                        numbers.push(n);
+                        token = rest;
+                    }
+                    Err(_) => break,
+                }
+            }
+            if token.contains("://") || !has_extension(token) {
+                return None;
+            }
+            Some(FileRef {
+                path: token.to_string(),
+                line: numbers.last().copied().filter(|n| *n > 0),
+                anchor: None,
+            })
+        })
+        .collect()
+}
+
+fn has_extension(path: &str) -> bool {
+    let name = path.rsplit(['/', '\\']).next().unwrap_or(path);
+    match name.rsplit_once('.') {
+        Some((stem, ext)) => {
+            !stem.is_empty()
+                && (1..=8).contains(&ext.len())
+                && ext.chars().all(|c| c.is_ascii_alphanumeric())
+                && ext.chars().any(|c| c.is_ascii_alphabetic())
+        }
+        None => false,
+    }
+}
+
+/// 1-based line where `anchor`'s first non-blank line appears in `contents`.
+pub fn locate(contents: &str, anchor: &str) -> Option<usize> {
+    let needle = anchor.lines().map(str::trim).find(|l| !l.is_empty())?;
+    contents
+        .lines()
+        .position(|l| l.contains(needle))
+        .map(|i| i + 1)
+}
+
+/// Argument list for the external editor. `{path}` and `{line}` in the
+/// template are filled in and `$VAR` words are read from the environment.
+/// A template without `{path}` gets `+{line} {path}` appended, which vi,
+/// nano, emacs and most terminal editors understand.
+pub fn editor_command(template: &str, path: &str, line: usize) -> Vec<String> {
+    let mut words: Vec<String> = template
+        .split_whitespace()
+        .flat_map(|word| match word.strip_prefix('$') {
+            Some(var) => std::env::var(var)
+                .map(|v| v.split_whitespace().map(str::to_string).collect())
+                .unwrap_or_default(),
+            None => vec![word.to_string()],
+        })
+        .collect();
+    if !template.contains("{path}") {
+        words.push("+{line}".to_string());
+        words.push("{path}".to_string());
+    }
+    words
+        .into_iter()
+        .map(|w| {
+            w.replace("{path}", path)
+                .replace("{line}", &line.to_string())
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finds_tool_paths_and_mentions() {
+        let detail = "Edit\n\n{\n  \"file_path\": \"/repo/src/app.rs\",\n  \"new_string\": \"let x = 2;\"\n}";
+        let refs = transcript_refs(detail);
+        assert_eq!(refs[0].path, "/repo/src/app.rs");
+        assert_eq!(refs[0].line, None);
+        assert_eq!(refs[0].anchor.as_deref(), Some("let x = 2;"));
+        assert_eq!(
+            locate("fn main() {\n    let x = 2;\n}", "let x = 2;"),
+            Some(2)
+        );
+
+        let refs = mentions(
+            "src/app.rs:120:    let x\nsee (docs/index.html) and https://x.io/a.html, v1.2",
+        );
+        let found: Vec<(&str, Option<usize>)> =
+            refs.iter().map(|r| (r.path.as_str(), r.line)).collect();
+        assert_eq!(
+            found,
+            [("src/app.rs", Some(120)), ("docs/index.html", None)]
+        );
+
+        assert_eq!(
+            editor_command("code -g {path}:{line}", "a.rs", 7),
+            ["code", "-g", "a.rs:7"]
+        );
+        assert_eq!(editor_command("vim", "a.rs", 7), ["vim", "+7", "a.rs"]);
+    }
+}
@@ -21,6 +21,78 @@ pub struct FileBrowserEntry {
 pub enum FileContent {
     Text(Vec<String>),
     Markdown(Vec<MarkdownLine>),
+    /// A small binary file as hexdump rows.
+    Hexdump(Vec<String>),
+    Image(ImagePreview),
+    /// A text file too large to read whole, paged in as it scrolls.
+    Large(LargeFile),
+    /// A binary file too large for a hexdump.
     Binary,
     TooLarge,
 }
+
+/// A large text file read a page of lines at a time. Lines are indexed
+/// from the start of the file only as far as the view has scrolled.
+#[derive(Debug, Clone, Default)]
+pub struct LargeFile {
+    /// Size of the file when it was last read.
+    pub size: u64,
+    /// Byte offset of every `filebrowser::LINE_CHECKPOINT`-th line.
+    pub checkpoints: Vec<u64>,
+    /// Newlines counted so far, where the count stopped, and the offset
+    /// just past the last newline counted.
+    pub lines_scanned: usize,
+    pub bytes_scanned: u64,
+    pub last_line_start: u64,
+    /// Line number (from 0) of the first line in `lines`.
+    pub start: usize,
+    /// The loaded page, each line cut to `filebrowser::MAX_LINE_BYTES`.
+    pub lines: Vec<String>,
+}
+
+impl LargeFile {
+    /// Whether every line has been counted.
+    pub fn fully_scanned(&self) -> bool {
+        self.bytes_scanned >= self.size
+    }
+
+    /// Number of lines: exact once fully scanned, otherwise estimated from
+    /// the length of the lines counted so far.
+    pub fn line_count(&self) -> usize {
+        if self.fully_scanned() {
+            // A final line without a newline still counts
+            return self.lines_scanned + usize::from(self.size > self.last_line_start);
+        }
+        if self.lines_scanned == 0 {
+            return 1;
+        }
+        let per_line = self.last_line_start as f64 / self.lines_scanned as f64;
+        ((self.size as f64 / per_line).round() as usize).max(self.lines_scanned + 1)
+    }
+}
+
+/// Size and detected type of the file shown in the content pane.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FileInfo {
+    pub size: u64,
+    pub mime: &'static str,
+}
+
+/// A decoded image, scaled down to at most `filebrowser::PREVIEW_MAX_SIDE`
+/// pixels a side for drawing.
+#[derive(Debug, Clone)]
+pub struct ImagePreview {
+    /// Dimensions of the image file.
+    pub original_width: u32,
+    pub original_height: u32,
+    pub width: u32,
+    pub height: u32,
+    /// RGB pixels, row by row, with transparency blended onto black.
+    pub pixels: Vec<[u8; 3]>,
+}
+
+impl ImagePreview {
+    pub fn pixel(&self, x: u32, y: u32) -> [u8; 3] {
+        self.pixels[(y * self.width + x) as usize]
+    }
+}
@@ -0,0 +1,485 @@
+use chrono::{DateTime, Duration, Utc};
+
+use crate::model::github::{GitHubIssue, PullRequest};
+use crate::model::jira::JiraIssue;
+use crate::model::linear::LinearIssue;
+use crate::model::session::SessionEntry;
+
+/// Value of one field of a filterable item.
+pub enum FieldValue {
+    /// Zero or more names: labels, assignees, a state.
+    Text(Vec<String>),
+    /// A timestamp compared with `key:<2d` / `key:>2d`.
+    Time(Option<DateTime<Utc>>),
+}
+
+/// An item that can be matched by a [`Filter`].
+pub trait Filterable {
+    /// Field names this item answers to, in completion order.
+    const FILTER_KEYS: &'static [&'static str];
+
+    /// Value of `key`, or `None` when the item has no such field.
+    fn field(&self, key: &str) -> Option<FieldValue>;
+
+    /// Text searched by bare words (title, key, summary...).
+    fn search_text(&self) -> String;
+}
+
+/// What a filter is evaluated against besides the item itself.
+pub struct FilterContext<'a> {
+    /// Substituted for the value `me`.
+    pub me: Option<&'a str>,
+    pub now: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum TermKind {
+    /// Bare word: case-insensitive substring of the search text.
+    Text(String),
+    /// `key:value`: some value of the field equals `value`
+    /// (case-insensitive, a trailing `*` matches a prefix).
+    Field { key: String, value: String },
+    /// `key:<2d` (`newer`) or `key:>2d`: the field's time is within / beyond
+    /// the age.
+    Age {
+        key: String,
+        newer: bool,
+        age: Duration,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Term {
+    negated: bool,
+    kind: TermKind,
+}
+
+/// A parsed filter expression such as `label:bug assignee:me updated:<2d`.
+/// Every term must match; `-` in front of a term negates it.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Filter {
+    /// The expression as typed, shown in list titles.
+    pub source: String,
+    terms: Vec<Term>,
+}
+
+impl Filter {
+    pub fn parse(input: &str) -> Result<Filter, String> {
+        let mut terms = Vec::new();
+        for token in tokenize(input) {
+            let (negated, token) = match token.strip_prefix('-') {
+                Some(rest) if !rest.is_empty() => (true, rest.to_string()),
+                _ => (false, token),
+            };
+            let kind = match token.split_once(':') {
+                Some((key, value)) if !key.is_empty() && !key.contains('"') => {
+                    let key = key.to_lowercase();
+                    let value = value.trim_matches('"').to_string();
+                    if let Some(age) = value.strip_prefix('<') {
+                        TermKind::Age {
+                            age: parse_age(age)?,
+                            key,
+                            newer: true,
+                        }
+                    } else if let Some(age) = value.strip_prefix('>') {
+                        TermKind::Age {
+                            age: parse_age(age)?,
+                            key,
+                            newer: false,
+                        }
+                    } else if value.is_empty() {
+                        return Err(format!("'{}:' needs a value", key));
+                    } else {
+                        TermKind::Field { key, value }
+                    }
+                }
+                _ => TermKind::Text(token.trim_matches('"').to_lowercase()),
+            };
+            terms.push(Term { negated, kind });
+        }
+        Ok(Filter {
+            source: input.trim().to_string(),
+            terms,
+        })
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.terms.is_empty()
+    }
+
+    /// The first field the expression uses that isn't in `keys`.
+    pub fn unknown_key(&self, keys: &[&str]) -> Option<&str> {
+        self.terms.iter().find_map(|t| match &t.kind {
+            TermKind::Field { key, .. } | TermKind::Age { key, .. }
+                if !keys.contains(&key.as_str()) =>
+            {
+                Some(key.as_str())
+            }
+            _ => None,
+        })
+    }
+
+    /// Values of `key` terms, e.g. to also query a tracker for them.
+    pub fn values_of(&self, key: &str) -> Vec<&str> {
+        self.terms
+            .iter()
+            .filter(|t| !t.negated)
+            .filter_map(|t| match &t.kind {
+                TermKind::Field { key: k, value } if k == key => Some(value.as_str()),
+                _ => None,
+            })
+            .collect()
+    }
+
+    pub fn matches<T: Filterable>(&self, item: &T, ctx: &FilterContext) -> bool {
+        self.terms
+            .iter()
+            .all(|term| term_matches(&term.kind, item, ctx) != term.negated)
+    }
+}
+
+fn term_matches<T: Filterable>(kind: &TermKind, item: &T, ctx: &FilterContext) -> bool {
+    match kind {
+        TermKind::Text(text) => item.search_text().to_lowercase().contains(text.as_str()),
+        TermKind::Field { key, value } => {
+            let Some(FieldValue::Text(values)) = item.field(key) else {
+                return false;
+            };
+            let wanted = match (value.as_str(), ctx.me) {
+                ("me", Some(me)) => me.to_lowercase(),
+                _ => value.to_lowercase(),
+            };
+            values.iter().any(|v| {
+                let v = v.to_lowercase();
+                match wanted.strip_suffix('*') {
+                    Some(prefix) => v.starts_with(prefix),
+                    None => v == wanted,
+                }
+            })
+        }
+        TermKind::Age { key, newer, age } => {
+            let Some(FieldValue::Time(Some(time))) = item.field(key) else {
+                return false;
+            };
+            let elapsed = ctx.now.signed_duration_since(time);
+            if *newer {
+                elapsed < *age
+            } else {
+                elapsed > *age
+            }
+        }
+    }
+}
+
+/// Split on whitespace, keeping `"quoted phrases"` together.
+fn tokenize(input: &str) -> Vec<String> {
+    let mut tokens = Vec::new();
+    let mut current = String::new();
+    let mut quoted = false;
+    for c in input.chars() {
+        match c {
+            '"' => {
+                quoted = !quoted;
+                current.push(c);
+            }
+            c if c.is_whitespace() && !quoted => {
+                if !current.is_empty() {
+                    tokens.push(std::mem::take(&mut current));
+                }
+            }
+            c => current.push(c),
+        }
+    }
+    if !current.is_empty() {
+        tokens.push(current);
+    }
+    tokens
+}
+
+/// Parse `30m`, `4h`, `2d` or `1w`.
+fn parse_age(s: &str) -> Result<Duration, String> {
+    let invalid = || format!("bad age '{}' (use e.g. 30m, 4h, 2d, 1w)", s);
+    let split = s.find(|c: char| !c.is_ascii_digit()).ok_or_else(invalid)?;
+    let n: i64 = s[..split].parse().map_err(|_| invalid())?;
+    match &s[split..] {
+        "m" => Ok(Duration::minutes(n)),
+        "h" => Ok(Duration::hours(n)),
+        "d" => Ok(Duration::days(n)),
+        "w" => Ok(Duration::weeks(n)),
+        _ => Err(invalid()),
+    }
+}
+
+/// Complete the last word of `input`: a field name from `T::FILTER_KEYS`,
+/// or after `key:` a value that field has on one of `items`. Extends to the
+/// longest common prefix of the candidates and closes the word when only one
+/// is left. Returns `None` when there is nothing to add.
+pub fn complete<T: Filterable>(input: &str, items: &[T]) -> Option<String> {
+    let start = input.rfind(char::is_whitespace).map_or(0, |i| i + 1);
+    let (head, word) = input.split_at(start);
+    let (sign, word) = match word.strip_prefix('-') {
+        Some(rest) => ("-", rest),
+        None => ("", word),
+    };
+
+    let (prefix, candidates, suffix) = match word.split_once(':') {
+        None => {
+            let candidates: Vec<String> = T::FILTER_KEYS
+                .iter()
+                .filter(|k| k.starts_with(&word.to_lowercase()))
+                .map(|k| k.to_string())
+                .collect();
+            (String::new(), candidates, ":")
+        }
+        Some((key, value)) => {
+            let value = value.trim_start_matches('"').to_lowercase();
+            let mut candidates: Vec<String> = items
+                .iter()
+                .filter_map(|item| match item.field(&key.to_lowercase()) {
+                    Some(FieldValue::Text(values)) => Some(values),
+                    _ => None,
+                })
+                .flatten()
+                .filter(|v| v.to_lowercase().starts_with(&value))
+                .collect();
+            candidates.sort_by_key(|v| v.to_lowercase());
+            candidates.dedup_by(|a, b| a.eq_ignore_ascii_case(b));
+            (format!("{}:", key), candidates, " ")
+        }
+    };
+
+    let completed = match candidates.as_slice() {
+        [] => return None,
+        [only] if only.contains(' ') => format!("\"{}\"{}", only, suffix),
+        [only] => format!("{}{}", only, suffix),
+        [first, rest @ ..] => {
+            let mut common: String = first.clone();
+            for c in rest {
+                let len = common
+                    .chars()
+                    .zip(c.chars())
+                    .take_while(|(a, b)| a.eq_ignore_ascii_case(b))
+                    .count();
+                common = common.chars().take(len).collect();
+            }
+            common
+        }
+    };
+    let result = format!("{}{}{}{}", head, sign, prefix, completed);
+    (result.len() > input.len()).then_some(result)
+}
+
+fn parse_time(s: &str) -> Option<DateTime<Utc>> {
+    DateTime::parse_from_rfc3339(s)
+        .ok()
+        .map(|t| t.with_timezone(&Utc))
+}
+
+fn text<I: IntoIterator<Item = S>, S: Into<String>>(values: I) -> Option<FieldValue> {
+    Some(FieldValue::Text(
+        values.into_iter().map(Into::into).collect(),
+    ))
+}
+
+impl Filterable for PullRequest {
+    const FILTER_KEYS: &'static [&'static str] = &[
+        "author", "assignee", "label", "state", "branch", "base", "review", "updated", "created",
+    ];
+
+    fn field(&self, key: &str) -> Option<FieldValue> {
+        match key {
+            "author" => text([self.author.login.as_str()]),
+            "assignee" => text(self.assignees.iter().map(|a| a.login.as_str())),
+            "label" => text(self.labels.iter().map(|l| l.name.as_str())),
+            "state" => {
+                let mut states = vec![self.state.to_lowercase()];
+                if self.is_draft {
+                    states.push("draft".to_string());
+                }
+                text(states)
+            }
+            "branch" => text([self.head_ref_name.as_str()]),
+            "base" => text([self.base_ref_name.as_str()]),
+            "review" => text(self.review_decision.iter().map(|r| r.to_lowercase())),
+            "updated" => Some(FieldValue::Time(parse_time(&self.updated_at))),
+            "created" => Some(FieldValue::Time(parse_time(&self.created_at))),
+            _ => None,
+        }
+    }
+
+    fn search_text(&self) -> String {
+        format!("#{} {}", self.number, self.title)
+    }
+}
+
+impl Filterable for GitHubIssue {
+    const FILTER_KEYS: &'static [&'static str] = &[
+        "author",
+        "assignee",
+        "label",
+        "state",
+        "milestone",
+        "updated",
+        "created",
+    ];
+
+    fn field(&self, key: &str) -> Option<FieldValue> {
+        match key {
+            "author" => text([self.author.login.as_str()]),
+            "assignee" => text(self.assignees.iter().map(|a| a.login.as_str())),
+            "label" => text(self.labels.iter().map(|l| l.name.as_str())),
+            "state" => text([self.state.to_lowercase()]),
+            "milestone" => text(self.milestone.iter().map(|m| m.title.as_str())),
+            "updated" => Some(FieldValue::Time(parse_time(&self.updated_at))),
+            "created" => Some(FieldValue::Time(parse_time(&self.created_at))),
+            _ => None,
+        }
+    }
+
+    fn search_text(&self) -> String {
+        format!("#{} {}", self.number, self.title)
+    }
+}
+
+impl Filterable for JiraIssue {
+    const FILTER_KEYS: &'static [&'static str] =
+        &["key", "label", "status", "category", "type", "priority"];
+
+    fn field(&self, key: &str) -> Option<FieldValue> {
+        match key {
+            "key" => text([self.key.as_str()]),
+            "label" => text(self.labels.iter().map(String::as_str)),
+            "status" => text([self.status_name.as_str()]),
+            "category" => text([self.status_category.as_str()]),
+            "type" => text([self.issue_type.as_str()]),
+            "priority" => text([self.priority.as_str()]),
+            _ => None,
+        }
+    }
+
+    fn search_text(&self) -> String {
+        format!("{} {}", self.key, self.summary)
+    }
+}
+
+impl Filterable for LinearIssue {
+    const FILTER_KEYS: &'static [&'static str] = &[
+        "assignee", "label", "state", "priority", "team", "project", "cycle", "updated", "created",
+    ];
+
+    fn field(&self, key: &str) -> Option<FieldValue> {
+        match key {
+            "assignee" => text(self.assignee.iter().map(|a| a.name.as_str())),
+            "label" => text(self.labels.nodes.iter().map(|l| l.name.as_str())),
+            "state" => text([self.state.name.as_str()]),
+            "priority" => text([self.priority_label.as_str()]),
+            "team" => text(self.team.iter().map(|t| t.key.as_str())),
+            "project" => text(self.project.iter().map(|p| p.name.as_str())),
+            "cycle" => text(
+                self.cycle
+                    .iter()
+                    .flat_map(|c| [c.number.to_string(), c.label()]),
+            ),
+            "updated" => Some(FieldValue::Time(parse_time(&self.updated_at))),
+            "created" => Some(FieldValue::Time(parse_time(&self.created_at))),
+            _ => None,
+        }
+    }
+
+    fn search_text(&self) -> String {
+        format!("{} {}", self.identifier, self.title)
+    }
+}
+
+impl Filterable for SessionEntry {
+    const FILTER_KEYS: &'static [&'static str] = &["branch", "ticket", "updated", "created"];
+
+    fn field(&self, key: &str) -> Option<FieldValue> {
+        match key {
+            "branch" => text(self.git_branch.iter().map(String::as_str)),
+            "ticket" => text(self.link.iter().map(|link| link.key.as_str())),
+            "updated" => Some(FieldValue::Time(self.modified)),
+            "created" => Some(FieldValue::Time(self.created)),
+            _ => None,
+        }
+    }
+
+    fn search_text(&self) -> String {
+        [
+            Some(self.session_id.as_str()),
+            self.custom_title.as_deref(),
+            self.summary.as_deref(),
+            self.first_prompt.as_deref(),
+        ]
+        .into_iter()
+        .flatten()
+        .collect::<Vec<_>>()
+        .join(" ")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_matches_and_completes() {
+        let issue = |title: &str, labels: &[&str], assignee: &str, updated: &str| {
+            serde_json::from_value::<GitHubIssue>(serde_json::json!({
+                "number": 1,
+                "title": title,
+                "state": "OPEN",
+                "url": "",
+                "createdAt": "2026-01-01T00:00:00Z",
+                "updatedAt": updated,
+                "author": { "login": "alice" },
+                "labels": labels.iter().map(|l| serde_json::json!({ "name": l })).collect::<Vec<_>>(),
+                "assignees": [{ "login": assignee }],
+                "body": null
+            }))
+            .unwrap()
+        };
+        let issues = vec![
+            issue("Crash on start", &["bug"], "bob", "2026-01-09T00:00:00Z"),
+            issue(
+                "Dark mode",
+                &["feature", "ui"],
+                "carol",
+                "2026-01-01T00:00:00Z",
+            ),
+        ];
+        let ctx = FilterContext {
+            me: Some("Bob"),
+            now: parse_time("2026-01-10T00:00:00Z").unwrap(),
+        };
+        let hits = |expr: &str| -> Vec<String> {
+            let filter = Filter::parse(expr).unwrap();
+            issues
+                .iter()
+                .filter(|i| filter.matches(*i, &ctx))
+                .map(|i| i.title.clone())
+                .collect()
+        };
+
+        assert_eq!(hits("label:bug assignee:me"), ["Crash on start"]);
+        assert_eq!(hits("updated:>2d"), ["Dark mode"]);
+        assert_eq!(hits("-label:bug \"dark mode\""), ["Dark mode"]);
+        assert_eq!(hits("label:fea*"), ["Dark mode"]);
+        assert_eq!(hits("state:open").len(), 2);
+        assert!(Filter::parse("updated:<2x").is_err());
+        assert_eq!(
+            Filter::parse("lable:bug")
+                .unwrap()
+                .unknown_key(GitHubIssue::FILTER_KEYS),
+            Some("lable")
+        );
+
+        assert_eq!(complete("la", &issues).as_deref(), Some("label:"));
+        assert_eq!(complete("as", &issues).as_deref(), Some("assignee:"));
+        assert_eq!(complete("a", &issues), None);
+        assert_eq!(
+            complete("x -label:f", &issues).as_deref(),
+            Some("x -label:feature ")
+        );
+    }
+}
@@ -27,17 +27,109 @@ pub struct DiffLine {
     pub text: String,
 }
 
+/// One row of the side-by-side diff.
+#[derive(Debug, Clone)]
+pub enum SplitDiffRow {
+    /// Header and hunk lines span both columns.
+    Full(DiffLine),
+    /// Old (removed or context) line on the left, new (added or context)
+    /// line on the right. A side is empty where the other has no partner.
+    Pair {
+        old: Option<DiffLine>,
+        new: Option<DiffLine>,
+    },
+}
+
+/// Where the diff pane is scrolled to, relative to the hunk above the top
+/// row, so a reload stays on that hunk when lines above it come and go.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DiffAnchor {
+    /// Old-file start line of the hunk; None above the first hunk.
+    pub hunk: Option<usize>,
+    /// Rows below the hunk header, or below the top when `hunk` is None.
+    pub offset: usize,
+}
+
+/// What a PR from the current branch would contain.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BranchChanges {
+    pub branch: String,
+    /// The branch the PR would merge into.
+    pub base: String,
+    /// `git log` of the commits not on `base`, oldest first.
+    pub commits: String,
+    /// `git diff --stat` against where the branch left `base`.
+    pub diff_stat: String,
+}
+
+/// A piece of a changed line; `changed` marks words the other side lacks.
+#[derive(Debug, Clone, PartialEq)]
+pub struct WordSpan {
+    pub text: String,
+    pub changed: bool,
+}
+
 #[derive(Debug, Clone, Default)]
 pub struct GitStatus {
     pub staged: Vec<GitFileEntry>,
     pub unstaged: Vec<GitFileEntry>,
     pub untracked: Vec<GitFileEntry>,
+    pub stashes: Vec<GitStash>,
+}
+
+/// A stash as listed by `git stash list`, with its `--shortstat` totals.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct GitStash {
+    /// Position in the stash list, 0 being the newest.
+    pub index: usize,
+    pub message: String,
+    pub files: usize,
+    pub insertions: usize,
+    pub deletions: usize,
+}
+
+impl GitStash {
+    /// The ref git commands take, e.g. `stash@{0}`.
+    pub fn name(&self) -> String {
+        format!("stash@{{{}}}", self.index)
+    }
+}
+
+/// A local branch as listed by `git branch`.
+#[derive(Debug, Clone)]
+pub struct GitBranch {
+    pub name: String,
+    pub is_current: bool,
+    pub upstream: Option<String>,
+    /// Path of the worktree that has this branch checked out, if any.
+    pub worktree_path: Option<String>,
+}
+
+/// A worktree as listed by `git worktree list --porcelain`.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct GitWorktree {
+    pub path: String,
+    pub head: String,
+    pub branch: Option<String>,
+    pub is_bare: bool,
+}
+
+impl GitWorktree {
+    pub fn display_branch(&self) -> String {
+        match (&self.branch, self.is_bare) {
+            (Some(b), _) => b.clone(),
+            (None, true) => "(bare)".to_string(),
+            (None, false) => format!("(detached {})", &self.head[..self.head.len().min(7)]),
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
 pub enum FlatGitItem {
     SectionHeader(String, GitFileSection),
     File(GitFileEntry),
+    StashHeader(String),
+    Stash(GitStash),
 }
 
 impl GitStatus {
@@ -45,6 +137,12 @@ impl GitStatus {
         self.staged.is_empty() && self.unstaged.is_empty() && self.untracked.is_empty()
     }
 
+    /// True if there are staged or unstaged changes to tracked files.
+    /// Untracked files don't block a branch switch, so they're ignored.
+    pub fn has_uncommitted_changes(&self) -> bool {
+        !self.staged.is_empty() || !self.unstaged.is_empty()
+    }
+
     pub fn total_files(&self) -> usize {
         self.staged.len() + self.unstaged.len() + self.untracked.len()
     }
@@ -82,12 +180,34 @@ impl GitStatus {
             }
         }
 
+        if !self.stashes.is_empty() {
+            items.push(FlatGitItem::StashHeader(format!(
+                "Stashes ({})",
+                self.stashes.len()
+            )));
+            for stash in &self.stashes {
+                items.push(FlatGitItem::Stash(stash.clone()));
+            }
+        }
+
         items
     }
 }
 
 impl FlatGitItem {
-    pub fn is_file(&self) -> bool {
-        matches!(self, FlatGitItem::File(_))
+    /// A file or stash row, as opposed to a section header.
+    pub fn is_entry(&self) -> bool {
+        matches!(self, FlatGitItem::File(_) | FlatGitItem::Stash(_))
+    }
+
+    /// Whether both rows show the same file, in the same section, or stash.
+    pub fn same_entry(&self, other: &FlatGitItem) -> bool {
+        match (self, other) {
+            (FlatGitItem::File(a), FlatGitItem::File(b)) => {
+                a.path == b.path && a.section == b.section
+            }
+            (FlatGitItem::Stash(a), FlatGitItem::Stash(b)) => a == b,
+            _ => false,
+        }
     }
 }
@@ -21,8 +21,18 @@ pub struct PullRequest {
     #[serde(default)]
     pub assignees: Vec<PrAssignee>,
     #[serde(default)]
+    pub review_requests: Vec<PrReviewRequest>,
+    #[serde(default)]
     pub labels: Vec<PrLabel>,
     pub body: Option<String>,
+    #[serde(default)]
+    pub status_check_rollup: Vec<PrCheck>,
+    #[serde(default)]
+    pub comments: Vec<IssueComment>,
+    /// Reviewers who approved. Only Bitbucket fills this in; GitHub sums
+    /// reviews up in `review_decision`.
+    #[serde(default)]
+    pub approved_by: Vec<String>,
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -36,11 +46,116 @@ pub struct PrAssignee {
     pub login: String,
 }
 
+/// A requested reviewer. Team requests carry a name instead of a login.
+#[derive(Debug, Clone, Deserialize)]
+pub struct PrReviewRequest {
+    #[serde(default)]
+    pub login: Option<String>,
+}
+
 #[derive(Debug, Clone, Deserialize)]
 pub struct PrLabel {
     pub name: String,
 }
 
+/// A single entry from `statusCheckRollup`. GitHub returns two shapes here:
+/// check runs (name/status/conclusion/detailsUrl) and legacy commit statuses
+/// (context/state/targetUrl), so every field is optional.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PrCheck {
+    #[serde(default)]
+    pub name: Option<String>,
+    #[serde(default)]
+    pub context: Option<String>,
+    #[serde(default)]
+    pub status: Option<String>,
+    #[serde(default)]
+    pub conclusion: Option<String>,
+    #[serde(default)]
+    pub state: Option<String>,
+    #[serde(default)]
+    pub details_url: Option<String>,
+    #[serde(default)]
+    pub target_url: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CheckOutcome {
+    Success,
+    Failure,
+    Pending,
+    Neutral,
+}
+
+impl PrCheck {
+    pub fn display_name(&self) -> &str {
+        self.name
+            .as_deref()
+            .or(self.context.as_deref())
+            .unwrap_or("(unnamed check)")
+    }
+
+    /// Link to the check's log / details page, if GitHub provided one.
+    pub fn url(&self) -> Option<&str> {
+        self.details_url
+            .as_deref()
+            .or(self.target_url.as_deref())
+            .filter(|u| !u.is_empty())
+    }
+
+    pub fn outcome(&self) -> CheckOutcome {
+        // Legacy commit status
+        if let Some(state) = self.state.as_deref() {
+            return match state {
+                "SUCCESS" => CheckOutcome::Success,
+                "FAILURE" | "ERROR" => CheckOutcome::Failure,
+                _ => CheckOutcome::Pending,
+            };
+        }
+        // Check run: not finished yet
+        if self.status.as_deref().is_some_and(|s| s != "COMPLETED") {
+            return CheckOutcome::Pending;
+        }
+        match self.conclusion.as_deref() {
+            Some("SUCCESS") => CheckOutcome::Success,
+            Some("FAILURE")
+            | Some("TIMED_OUT")
+            | Some("CANCELLED")
+            | Some("ACTION_REQUIRED")
+            | Some("STARTUP_FAILURE") => CheckOutcome::Failure,
+            Some("NEUTRAL") | Some("SKIPPED") | Some("STALE") => CheckOutcome::Neutral,
+            _ => CheckOutcome::Pending,
+        }
+    }
+}
+
+/// An inline review thread on a PR: the comments on one line of one file.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReviewThread {
+    pub path: String,
+    /// Line in the current diff; `None` once the code it was on is gone.
+    pub line: Option<u64>,
+    pub original_line: Option<u64>,
+    pub is_resolved: bool,
+    pub is_outdated: bool,
+    pub comments: Vec<ReviewComment>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReviewComment {
+    pub author: String,
+    pub body: String,
+    pub created_at: String,
+}
+
+impl ReviewThread {
+    /// The line to show, falling back to where an outdated thread started.
+    pub fn display_line(&self) -> Option<u64> {
+        self.line.or(self.original_line)
+    }
+}
+
 #[derive(Debug, Clone)]
 pub enum FlatPrItem {
     SectionHeader(String),
@@ -48,6 +163,14 @@ pub enum FlatPrItem {
 }
 
 impl PullRequest {
+    /// True if `user` is individually requested to review this PR.
+    pub fn review_requested_from(&self, user: &str) -> bool {
+        self.review_requests
+            .iter()
+            .filter_map(|r| r.login.as_deref())
+            .any(|login| login.eq_ignore_ascii_case(user))
+    }
+
     /// Size label based on total changes (additions + deletions).
     pub fn size_label(&self) -> &'static str {
         let total = self.additions + self.deletions;
@@ -69,6 +192,44 @@ impl PullRequest {
             _ => "[ ]",
         }
     }
+
+    /// Overall CI state: any failure wins, then any pending, otherwise success.
+    /// Returns None when the PR has no checks at all.
+    pub fn checks_outcome(&self) -> Option<CheckOutcome> {
+        if self.status_check_rollup.is_empty() {
+            return None;
+        }
+        let outcomes: Vec<CheckOutcome> = self
+            .status_check_rollup
+            .iter()
+            .map(|c| c.outcome())
+            .collect();
+        if outcomes.contains(&CheckOutcome::Failure) {
+            Some(CheckOutcome::Failure)
+        } else if outcomes.contains(&CheckOutcome::Pending) {
+            Some(CheckOutcome::Pending)
+        } else {
+            Some(CheckOutcome::Success)
+        }
+    }
+
+    /// Checks column icon.
+    pub fn checks_icon(&self) -> &'static str {
+        match self.checks_outcome() {
+            Some(CheckOutcome::Success) | Some(CheckOutcome::Neutral) => "✓",
+            Some(CheckOutcome::Failure) => "✗",
+            Some(CheckOutcome::Pending) => "●",
+            None => " ",
+        }
+    }
+
+    /// Checks that failed, in rollup order.
+    pub fn failing_checks(&self) -> Vec<&PrCheck> {
+        self.status_check_rollup
+            .iter()
+            .filter(|c| c.outcome() == CheckOutcome::Failure)
+            .collect()
+    }
 }
 
 // ---------------------------------------------------------------------------
@@ -131,6 +292,53 @@ pub enum FlatIssueItem {
     Issue(Box<GitHubIssue>),
 }
 
+/// A Markdown template from the repo's `.github/ISSUE_TEMPLATE/`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct IssueTemplate {
+    /// The frontmatter's `name:`, else the file's name.
+    pub name: String,
+    pub about: Option<String>,
+    /// Title the new issue starts with.
+    pub title: String,
+    /// Labels put on the created issue.
+    pub labels: Vec<String>,
+    /// The template without its frontmatter.
+    pub body: String,
+}
+
+/// Issue metadata edited through the Issues tab pickers.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum IssueMetaField {
+    Labels,
+    Assignees,
+    Milestone,
+}
+
+impl IssueMetaField {
+    pub fn title(&self) -> &'static str {
+        match self {
+            IssueMetaField::Labels => "Labels",
+            IssueMetaField::Assignees => "Assignees",
+            IssueMetaField::Milestone => "Milestone",
+        }
+    }
+}
+
+/// A change to an issue's labels, assignees or milestone.
+#[derive(Debug, Clone)]
+pub enum IssueMetaEdit {
+    Labels {
+        add: Vec<String>,
+        remove: Vec<String>,
+    },
+    Assignees {
+        add: Vec<String>,
+        remove: Vec<String>,
+    },
+    /// None clears the milestone.
+    Milestone(Option<String>),
+}
+
 impl GitHubIssue {
     pub fn state_icon(&self) -> &'static str {
         match self.state.as_str() {
@@ -139,4 +347,72 @@ impl GitHubIssue {
             _ => "[ ]",
         }
     }
+
+    /// The issue's current values for `field`.
+    pub fn meta_values(&self, field: IssueMetaField) -> Vec<String> {
+        match field {
+            IssueMetaField::Labels => self.labels.iter().map(|l| l.name.clone()).collect(),
+            IssueMetaField::Assignees => self.assignees.iter().map(|a| a.login.clone()).collect(),
+            IssueMetaField::Milestone => self.milestone.iter().map(|m| m.title.clone()).collect(),
+        }
+    }
+
+    /// Apply an edit locally, ahead of gh confirming it.
+    pub fn apply_meta_edit(&mut self, edit: &IssueMetaEdit) {
+        match edit {
+            IssueMetaEdit::Labels { add, remove } => {
+                self.labels.retain(|l| !remove.contains(&l.name));
+                for name in add {
+                    self.labels.push(IssueLabel { name: name.clone() });
+                }
+            }
+            IssueMetaEdit::Assignees { add, remove } => {
+                self.assignees.retain(|a| !remove.contains(&a.login));
+                for login in add {
+                    self.assignees.push(IssueAssignee {
+                        login: login.clone(),
+                    });
+                }
+            }
+            IssueMetaEdit::Milestone(title) => {
+                self.milestone = title.clone().map(|title| IssueMilestone { title });
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn check(json: &str) -> PrCheck {
+        serde_json::from_str(json).unwrap()
+    }
+
+    #[test]
+    fn test_check_run_outcomes() {
+        let ok = check(
+            r#"{"__typename":"CheckRun","name":"build","status":"COMPLETED","conclusion":"SUCCESS"}"#,
+        );
+        let bad = check(
+            r#"{"__typename":"CheckRun","name":"test","status":"COMPLETED","conclusion":"FAILURE","detailsUrl":"https://example.com/1"}"#,
+        );
+        let running = check(
+            r#"{"__typename":"CheckRun","name":"lint","status":"IN_PROGRESS","conclusion":""}"#,
+        );
+        assert_eq!(ok.outcome(), CheckOutcome::Success);
+        assert_eq!(bad.outcome(), CheckOutcome::Failure);
+        assert_eq!(bad.url(), Some("https://example.com/1"));
+        assert_eq!(running.outcome(), CheckOutcome::Pending);
+    }
+
+    #[test]
+    fn test_status_context_outcomes() {
+        let ctx = check(
+            r#"{"__typename":"StatusContext","context":"ci/jenkins","state":"ERROR","targetUrl":"https://ci.example.com"}"#,
+        );
+        assert_eq!(ctx.display_name(), "ci/jenkins");
+        assert_eq!(ctx.outcome(), CheckOutcome::Failure);
+        assert_eq!(ctx.url(), Some("https://ci.example.com"));
+    }
 }
@@ -0,0 +1,49 @@
+/// A line matched by a project search.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GrepMatch {
+    /// Relative to the project root.
+    pub path: String,
+    /// 1-based line number.
+    pub line: usize,
+    pub text: String,
+    /// Byte ranges of the matched text within `text`.
+    pub ranges: Vec<(usize, usize)>,
+}
+
+/// Matched lines of a search, in file order.
+#[derive(Debug, Clone, Default)]
+pub struct GrepResults {
+    pub matches: Vec<GrepMatch>,
+    /// More lines matched than were kept.
+    pub truncated: bool,
+}
+
+#[derive(Debug, Clone)]
+pub enum FlatGrepItem {
+    /// A file header: (path, matched line count).
+    File(String, usize),
+    Match(GrepMatch),
+}
+
+impl GrepResults {
+    pub fn file_count(&self) -> usize {
+        let changes = self.matches.windows(2).filter(|w| w[0].path != w[1].path);
+        usize::from(!self.matches.is_empty()) + changes.count()
+    }
+
+    /// Each file's header followed by its matched lines.
+    pub fn flat_list(&self) -> Vec<FlatGrepItem> {
+        let mut items = Vec::new();
+        for (i, m) in self.matches.iter().enumerate() {
+            if i == 0 || self.matches[i - 1].path != m.path {
+                let count = self.matches[i..]
+                    .iter()
+                    .take_while(|other| other.path == m.path)
+                    .count();
+                items.push(FlatGrepItem::File(m.path.clone(), count));
+            }
+            items.push(FlatGrepItem::Match(m.clone()));
+        }
+        items
+    }
+}
@@ -0,0 +1,124 @@
+use std::path::PathBuf;
+
+/// Which Claude Code settings file a hook is configured in.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SettingsScope {
+    /// `.claude/settings.json`, shared with the repo.
+    Project,
+    /// `.claude/settings.local.json`, not committed.
+    Local,
+    /// `~/.claude/settings.json`.
+    User,
+}
+
+impl SettingsScope {
+    pub const ALL: [SettingsScope; 3] = [
+        SettingsScope::Project,
+        SettingsScope::Local,
+        SettingsScope::User,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            SettingsScope::Project => "project",
+            SettingsScope::Local => "local",
+            SettingsScope::User => "user",
+        }
+    }
+
+    /// One-letter tag for the list.
+    pub fn tag(&self) -> &'static str {
+        match self {
+            SettingsScope::Project => "P",
+            SettingsScope::Local => "L",
+            SettingsScope::User => "U",
+        }
+    }
+}
+
+/// Hook events Claude Code runs hooks for, in the order they're listed.
+pub const HOOK_EVENTS: &[&str] = &[
+    "PreToolUse",
+    "PermissionRequest",
+    "PostToolUse",
+    "UserPromptSubmit",
+    "Notification",
+    "Stop",
+    "SubagentStop",
+    "PreCompact",
+    "SessionStart",
+    "SessionEnd",
+];
+
+/// When an event fires, and what its matcher is matched against.
+pub fn event_description(event: &str) -> &'static str {
+    match event {
+        "PreToolUse" => {
+            "Before a tool runs; can block it. Matcher: tool name, e.g. Bash or Edit|Write"
+        }
+        "PermissionRequest" => "When a permission dialog would be shown. Matcher: tool name",
+        "PostToolUse" => "After a tool succeeds. Matcher: tool name, e.g. Edit|Write",
+        "UserPromptSubmit" => "When a prompt is submitted, before Claude sees it",
+        "Notification" => "When Claude Code sends a notification",
+        "Stop" => "When Claude finishes responding",
+        "SubagentStop" => "When a subagent finishes",
+        "PreCompact" => "Before the conversation is compacted. Matcher: manual or auto",
+        "SessionStart" => {
+            "When a session starts or resumes. Matcher: startup, resume, clear or compact"
+        }
+        "SessionEnd" => "When a session ends",
+        _ => "Not an event Claude Code knows",
+    }
+}
+
+/// Whether hooks for `event` are filtered by a matcher.
+pub fn event_takes_matcher(event: &str) -> bool {
+    matches!(
+        event,
+        "PreToolUse" | "PermissionRequest" | "PostToolUse" | "PreCompact" | "SessionStart"
+    )
+}
+
+/// One hook from a settings file's `hooks` object.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Hook {
+    pub scope: SettingsScope,
+    pub event: String,
+    /// None matches everything.
+    pub matcher: Option<String>,
+    /// `command` or `prompt`.
+    pub kind: String,
+    /// The shell command, or the prompt for prompt hooks.
+    pub command: String,
+    /// Seconds.
+    pub timeout: Option<u64>,
+    /// Index of the matcher group in the event's array.
+    pub group: usize,
+    /// Index of the hook in the group's `hooks` array.
+    pub index: usize,
+}
+
+/// A settings file's hooks and what is wrong with it.
+#[derive(Debug, Clone)]
+pub struct SettingsFile {
+    pub scope: SettingsScope,
+    pub path: PathBuf,
+    pub hooks: Vec<Hook>,
+    /// Schema problems, e.g. `hooks.Stop[0].hooks must be an array`.
+    pub problems: Vec<String>,
+}
+
+#[derive(Debug, Clone)]
+pub enum FlatHookItem {
+    EventHeader(String),
+    Hook(Hook),
+    ProblemHeader,
+    Problem(SettingsScope, String),
+}
+
+impl FlatHookItem {
+    /// Hooks and problems can be selected; headers are skipped.
+    pub fn is_selectable(&self) -> bool {
+        matches!(self, FlatHookItem::Hook(_) | FlatHookItem::Problem(..))
+    }
+}
@@ -1,6 +1,26 @@
 use chrono::{DateTime, Utc};
 use serde::Deserialize;
 
+/// Built-in messages offered when broadcasting to a team: (name, text).
+pub const BROADCAST_TEMPLATES: &[(&str, &str)] = &[
+    (
+        "Wrap up",
+        "Wrap up what you are working on, then report your status to the team lead.",
+    ),
+    (
+        "Status report",
+        "Report to the team lead: what is done, what is left and what is blocking you.",
+    ),
+    (
+        "Pause",
+        "Pause after your current step and wait for further instructions.",
+    ),
+    (
+        "Commit work",
+        "Commit your finished work with a clear message before picking up anything new.",
+    ),
+];
+
 #[derive(Debug, Clone, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct InboxMessage {
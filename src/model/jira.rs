@@ -17,20 +17,76 @@ pub struct JiraIssue {
     pub description: Option<String>,
     #[serde(default)]
     pub url: String,
+    /// Files attached to the issue; only filled in by `view_issue`.
+    #[serde(default)]
+    pub attachments: Vec<JiraAttachment>,
+    /// Web links (remote links) on the issue; only filled in by `view_issue`.
+    #[serde(rename = "webLinks", alias = "web_links", default)]
+    pub web_links: Vec<JiraWebLink>,
+}
+
+/// A file attached to a Jira issue.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct JiraAttachment {
+    pub id: String,
+    pub filename: String,
+    /// Bytes.
+    #[serde(default)]
+    pub size: u64,
+    #[serde(rename = "mimeType", alias = "mime_type", default)]
+    pub mime_type: String,
+    /// Download URL of the file's content.
+    #[serde(default)]
+    pub content: String,
+}
+
+/// A link from a Jira issue to a web page.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct JiraWebLink {
+    pub title: String,
+    pub url: String,
+}
+
+/// An attachment or web link selected in the detail pane.
+#[derive(Debug, Clone, Copy)]
+pub enum JiraResource<'a> {
+    Attachment(&'a JiraAttachment),
+    WebLink(&'a JiraWebLink),
 }
 
 #[derive(Debug, Clone)]
 pub enum FlatJiraItem {
     StatusHeader(String, String), // (status_name, status_category)
-    Issue(JiraIssue),
+    Issue(Box<JiraIssue>),
 }
 
+/// Board columns, one per Jira status category.
+pub const BOARD_COLUMNS: [&str; 3] = ["To Do", "In Progress", "Done"];
+
 #[derive(Debug, Clone)]
 pub struct JiraTransition {
     pub name: String,
 }
 
 impl JiraIssue {
+    /// Attachments then web links, in the order the detail pane lists them.
+    pub fn resources(&self) -> Vec<JiraResource<'_>> {
+        self.attachments
+            .iter()
+            .map(JiraResource::Attachment)
+            .chain(self.web_links.iter().map(JiraResource::WebLink))
+            .collect()
+    }
+
+    /// Index into `BOARD_COLUMNS` for this issue's status category.
+    pub fn board_column(&self) -> usize {
+        match self.status_category.to_lowercase().as_str() {
+            "in progress" | "indeterminate" => 1,
+            "done" => 2,
+            _ => 0,
+        }
+    }
+
     /// Icon based on issue type.
     pub fn type_icon(&self) -> &'static str {
         match self.issue_type.to_lowercase().as_str() {
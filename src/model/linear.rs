@@ -20,6 +20,10 @@ pub struct LinearIssue {
     #[serde(default)]
     pub team: Option<LinearTeam>,
     #[serde(default)]
+    pub project: Option<LinearProject>,
+    #[serde(default)]
+    pub cycle: Option<LinearCycle>,
+    #[serde(default)]
     pub created_at: String,
     #[serde(default)]
     pub updated_at: String,
@@ -62,6 +66,27 @@ pub struct LinearTeam {
     pub key: String,
 }
 
+#[derive(Debug, Clone, Deserialize)]
+pub struct LinearProject {
+    pub name: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct LinearCycle {
+    pub number: u32,
+    /// Unnamed cycles go by their number.
+    pub name: Option<String>,
+}
+
+impl LinearCycle {
+    pub fn label(&self) -> String {
+        match &self.name {
+            Some(name) if !name.is_empty() => name.clone(),
+            _ => format!("Cycle {}", self.number),
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub enum FlatLinearItem {
     AssignmentHeader(String), // "My Tasks", "Unassigned"
@@ -0,0 +1,221 @@
+/// Longest summary kept from a page, in characters.
+const MAX_SUMMARY: usize = 600;
+
+/// Title and opening text of a fetched web page.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LinkPreview {
+    pub title: String,
+    pub summary: String,
+}
+
+/// `http(s)://` URLs in `text`, in order and without duplicates. Markdown
+/// and angle-bracket wrapping and trailing punctuation are left off.
+pub fn find_urls(text: &str) -> Vec<String> {
+    let mut urls: Vec<String> = Vec::new();
+    let mut rest = text;
+    while let Some(start) = rest.find("http") {
+        let candidate = &rest[start..];
+        let end = candidate
+            .find(|c: char| c.is_whitespace() || "<>\"'`".contains(c))
+            .unwrap_or(candidate.len());
+        let mut url = &candidate[..end];
+        // A markdown link's closing paren belongs to the link syntax unless
+        // the URL opened one itself
+        loop {
+            let trimmed = url.trim_end_matches(['.', ',', ';', ':', '!', '?', ']', '*']);
+            let trimmed = match trimmed.strip_suffix(')') {
+                Some(t) if t.matches('(').count() < t.matches(')').count() + 1 => t,
+                _ => trimmed,
+            };
+            if trimmed.len() == url.len() {
+                break;
+            }
+            url = trimmed;
+        }
+        let is_url = ["http://", "https://"]
+            .iter()
+            .any(|scheme| url.starts_with(scheme) && url.len() > scheme.len());
+        if is_url && !urls.iter().any(|u| u == url) {
+            urls.push(url.to_string());
+        }
+        rest = &candidate[end.max(4)..];
+    }
+    urls
+}
+
+/// Build a preview from a fetched page. HTML pages use `og:title` or
+/// `<title>`, then the page description or its first paragraph. Anything
+/// else is treated as plain text: its first line and first paragraph.
+pub fn parse_page(body: &str) -> LinkPreview {
+    let lower = body.to_ascii_lowercase();
+    if !lower.contains("<html") && !lower.contains("<title") && !lower.contains("<p") {
+        let mut paragraphs = body.split("\n\n").map(str::trim).filter(|p| !p.is_empty());
+        let first = paragraphs.next().unwrap_or("");
+        let title = first.lines().next().unwrap_or("").trim().to_string();
+        return LinkPreview {
+            title,
+            summary: shorten(&collapse_whitespace(first)),
+        };
+    }
+
+    let metas = meta_tags(body, &lower);
+    let meta = |key: &str| {
+        metas
+            .iter()
+            .find(|(k, v)| k == key && !v.is_empty())
+            .map(|(_, v)| v.clone())
+    };
+    let title = meta("og:title")
+        .or_else(|| element_text(body, &lower, "title"))
+        .unwrap_or_default();
+    let summary = meta("og:description")
+        .or_else(|| meta("description"))
+        .or_else(|| first_paragraph(body, &lower))
+        .unwrap_or_default();
+    LinkPreview {
+        title,
+        summary: shorten(&summary),
+    }
+}
+
+/// `(name or property, content)` of every `<meta>` tag, names lowercased.
+fn meta_tags(body: &str, lower: &str) -> Vec<(String, String)> {
+    let mut tags = Vec::new();
+    let mut from = 0;
+    while let Some(start) = lower[from..].find("<meta").map(|i| from + i) {
+        let Some(end) = lower[start..].find('>').map(|i| start + i) else {
+            break;
+        };
+        let tag = &body[start..end];
+        let key = attribute(tag, "property").or_else(|| attribute(tag, "name"));
+        if let (Some(key), Some(content)) = (key, attribute(tag, "content")) {
+            tags.push((key.to_ascii_lowercase(), decode_entities(&content)));
+        }
+        from = end;
+    }
+    tags
+}
+
+/// Value of `name="..."` (or single-quoted) inside an HTML tag.
+fn attribute(tag: &str, name: &str) -> Option<String> {
+    let lower = tag.to_ascii_lowercase();
+    let mut from = 0;
+    while let Some(i) = lower[from..].find(name).map(|i| from + i) {
+        from = i + name.len();
+        // Skip matches inside another attribute's name, e.g. "og:name"
+        let preceded_by_space = lower[..i].ends_with(|c: char| c.is_whitespace());
+        let after = lower[from..].trim_start();
+        let Some(value) = after.strip_prefix('=') else {
+            continue;
+        };
+        if !preceded_by_space {
+            continue;
+        }
+        let value = value.trim_start();
+        let offset = tag.len() - value.len();
+        let quote = value.chars().next()?;
+        if quote != '"' && quote != '\'' {
+            continue;
+        }
+        let raw = &tag[offset + 1..];
+        return raw.find(quote).map(|end| raw[..end].to_string());
+    }
+    None
+}
+
+/// Text inside the first `<name>` element.
+fn element_text(body: &str, lower: &str, name: &str) -> Option<String> {
+    let open = lower.find(&format!("<{}", name))?;
+    let content_start = open + lower[open..].find('>')? + 1;
+    let content_end = content_start + lower[content_start..].find(&format!("</{}", name))?;
+    let text = collapse_whitespace(&decode_entities(&strip_tags(
+        &body[content_start..content_end],
+    )));
+    (!text.is_empty()).then_some(text)
+}
+
+/// Text of the first `<p>` with something in it.
+fn first_paragraph(body: &str, lower: &str) -> Option<String> {
+    let mut from = 0;
+    while let Some(i) = lower[from..].find("<p").map(|i| from + i) {
+        from = i + 2;
+        // `<p>` or `<p class=...>`, not `<pre>` or `<path>`
+        if !lower[from..].starts_with(['>', ' ', '\t', '\n']) {
+            continue;
+        }
+        if let Some(text) = element_text(&body[i..], &lower[i..], "p") {
+            return Some(text);
+        }
+    }
+    None
+}
+
+fn strip_tags(html: &str) -> String {
+    let mut text = String::with_capacity(html.len());
+    let mut in_tag = false;
+    for c in html.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' if in_tag => {
+                in_tag = false;
+                text.push(' ');
+            }
+            _ if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    text
+}
+
+fn decode_entities(text: &str) -> String {
+    text.replace("&nbsp;", " ")
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&#x27;", "'")
+        .replace("&amp;", "&")
+}
+
+fn collapse_whitespace(text: &str) -> String {
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+fn shorten(text: &str) -> String {
+    if text.chars().count() <= MAX_SUMMARY {
+        return text.to_string();
+    }
+    let cut: String = text.chars().take(MAX_SUMMARY).collect();
+    format!("{}...", cut.trim_end())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finds_urls_and_previews_pages() {
+        let text = "See [the docs](https://example.com/docs), and \
+                    <https://x.io/a_(b)>. Again: https://example.com/docs.";
+        assert_eq!(
+            find_urls(text),
+            ["https://example.com/docs", "https://x.io/a_(b)"]
+        );
+
+        let page = r#"<html><head><title>Fallback</title>
+            <meta property="og:title" content="Release &amp; notes">
+            </head><body><pre>code</pre><p class="lead">First <b>real</b>
+            paragraph.</p><p>Second.</p></body></html>"#;
+        assert_eq!(
+            parse_page(page),
+            LinkPreview {
+                title: "Release & notes".to_string(),
+                summary: "First real paragraph.".to_string(),
+            }
+        );
+
+        let text = parse_page("README\nLine two\n\nMore");
+        assert_eq!(text.title, "README");
+        assert_eq!(text.summary, "README Line two");
+    }
+}
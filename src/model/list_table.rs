@@ -0,0 +1,392 @@
+use std::cmp::Ordering;
+use std::ops::Range;
+
+use chrono::{DateTime, Utc};
+
+use crate::model::github::{CheckOutcome, GitHubIssue, PullRequest};
+
+/// A column of the PR and issue tables.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ListColumn {
+    Review,
+    Ci,
+    State,
+    Number,
+    Title,
+    Author,
+    Age,
+    Size,
+    Labels,
+}
+
+/// PR table columns, in display order, when `prs.columns` is unset.
+pub const PR_COLUMNS: &[ListColumn] = &[
+    ListColumn::Review,
+    ListColumn::Ci,
+    ListColumn::Number,
+    ListColumn::Title,
+    ListColumn::Author,
+    ListColumn::Age,
+    ListColumn::Size,
+];
+
+/// Issue table columns, in display order, when `github.issues.columns` is
+/// unset.
+pub const ISSUE_COLUMNS: &[ListColumn] = &[
+    ListColumn::State,
+    ListColumn::Number,
+    ListColumn::Title,
+    ListColumn::Author,
+    ListColumn::Age,
+    ListColumn::Labels,
+];
+
+/// Width the title column shrinks to before the table scrolls.
+pub const TITLE_MIN_WIDTH: u16 = 20;
+
+impl ListColumn {
+    /// Name used in `.assoc.toml`.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Review => "review",
+            Self::Ci => "ci",
+            Self::State => "state",
+            Self::Number => "number",
+            Self::Title => "title",
+            Self::Author => "author",
+            Self::Age => "age",
+            Self::Size => "size",
+            Self::Labels => "labels",
+        }
+    }
+
+    pub fn parse(name: &str) -> Option<Self> {
+        [
+            Self::Review,
+            Self::Ci,
+            Self::State,
+            Self::Number,
+            Self::Title,
+            Self::Author,
+            Self::Age,
+            Self::Size,
+            Self::Labels,
+        ]
+        .into_iter()
+        .find(|c| c.name().eq_ignore_ascii_case(name.trim()))
+    }
+
+    /// Header text.
+    pub fn header(&self) -> &'static str {
+        match self {
+            Self::Review => "Rev",
+            Self::Ci => "CI",
+            Self::State => "St",
+            Self::Number => "#",
+            Self::Title => "Title",
+            Self::Author => "Author",
+            Self::Age => "Age",
+            Self::Size => "Size",
+            Self::Labels => "Labels",
+        }
+    }
+
+    /// Fixed width, room for the header and its sort arrow; the title's is
+    /// its minimum, it takes the space left.
+    pub fn width(&self) -> u16 {
+        match self {
+            Self::Review => 4,
+            Self::Ci | Self::State => 3,
+            Self::Number => 6,
+            Self::Title => TITLE_MIN_WIDTH,
+            Self::Author => 12,
+            Self::Age => 4,
+            Self::Size => 5,
+            Self::Labels => 16,
+        }
+    }
+}
+
+/// Parse configured column names, warning about unknown ones. Falls back
+/// to `default` when the list is unset or names nothing known.
+pub fn parse_columns(
+    names: Option<&[String]>,
+    key: &str,
+    default: &[ListColumn],
+) -> Vec<ListColumn> {
+    let Some(names) = names else {
+        return default.to_vec();
+    };
+    let mut columns = Vec::new();
+    for name in names {
+        match ListColumn::parse(name) {
+            Some(column) if !columns.contains(&column) => columns.push(column),
+            Some(_) => {}
+            None => eprintln!("Warning: unknown column {:?} in {}, ignoring it", name, key),
+        }
+    }
+    if columns.is_empty() {
+        default.to_vec()
+    } else {
+        columns
+    }
+}
+
+/// Columns, sort order and horizontal scroll of one tab's table.
+#[derive(Debug, Clone)]
+pub struct TableView {
+    pub columns: Vec<ListColumn>,
+    /// None keeps the default order, most recently updated first.
+    pub sort: Option<ListColumn>,
+    pub descending: bool,
+    /// Leading columns scrolled out of view.
+    pub scroll: usize,
+}
+
+impl TableView {
+    pub fn new(columns: Vec<ListColumn>) -> Self {
+        TableView {
+            columns,
+            sort: None,
+            descending: false,
+            scroll: 0,
+        }
+    }
+
+    /// Sort by the next column, ascending; after the last, back to the
+    /// default order.
+    pub fn cycle_sort(&mut self) {
+        let next = match self.sort {
+            None => 0,
+            Some(current) => match self.columns.iter().position(|c| *c == current) {
+                Some(i) => i + 1,
+                None => 0,
+            },
+        };
+        self.sort = self.columns.get(next).copied();
+        self.descending = false;
+    }
+
+    /// Status text for the current order, e.g. `sorted by age ↓`.
+    pub fn sort_label(&self) -> String {
+        let arrow = if self.descending { "↓" } else { "↑" };
+        match self.sort {
+            Some(column) => format!("sorted by {} {}", column.name(), arrow),
+            None if self.descending => "least recently updated first".to_string(),
+            None => "most recently updated first".to_string(),
+        }
+    }
+
+    /// Columns shown in `width` cells: from the scroll position, as many as
+    /// fit, and always at least one.
+    pub fn visible_columns(&self, width: u16) -> Range<usize> {
+        let first = self.first_column(width);
+        let mut used = 0;
+        let mut end = first;
+        for column in &self.columns[first..] {
+            used += column.width() + u16::from(end > first);
+            if used > width && end > first {
+                break;
+            }
+            end += 1;
+        }
+        first..end
+    }
+
+    /// The first column shown in `width` cells. Scrolling stops once the
+    /// remaining columns fit.
+    pub fn first_column(&self, width: u16) -> usize {
+        let fits = |from: usize| {
+            let columns = &self.columns[from..];
+            let spacing = columns.len().saturating_sub(1) as u16;
+            columns.iter().map(|c| c.width()).sum::<u16>() + spacing <= width
+        };
+        let last = self.columns.len().saturating_sub(1);
+        let max_scroll = (0..last).find(|&from| fits(from)).unwrap_or(last);
+        self.scroll.min(max_scroll)
+    }
+
+    /// Order of two rows under the current sort.
+    fn order(&self, ordering: Ordering) -> Ordering {
+        if self.descending {
+            ordering.reverse()
+        } else {
+            ordering
+        }
+    }
+
+    /// Sort the rows between each pair of section headers, keeping the
+    /// sections themselves in place.
+    pub fn sort_sections<T>(
+        &self,
+        items: &mut [T],
+        is_header: impl Fn(&T) -> bool,
+        compare: impl Fn(&T, &T, ListColumn) -> Ordering,
+    ) {
+        for rows in items.split_mut(|item| is_header(item)) {
+            match self.sort {
+                Some(column) => rows.sort_by(|a, b| self.order(compare(a, b, column))),
+                None if self.descending => rows.reverse(),
+                None => {}
+            }
+        }
+    }
+}
+
+/// Compare two PRs by one column. Age sorts youngest first.
+pub fn compare_prs(a: &PullRequest, b: &PullRequest, column: ListColumn) -> Ordering {
+    match column {
+        ListColumn::Review => review_rank(a).cmp(&review_rank(b)),
+        ListColumn::Ci => ci_rank(a).cmp(&ci_rank(b)),
+        ListColumn::State => a.state.cmp(&b.state),
+        ListColumn::Number => a.number.cmp(&b.number),
+        ListColumn::Title => compare_text(&a.title, &b.title),
+        ListColumn::Author => compare_text(&a.author.login, &b.author.login),
+        ListColumn::Age => b.created_at.cmp(&a.created_at),
+        ListColumn::Size => (a.additions + a.deletions).cmp(&(b.additions + b.deletions)),
+        ListColumn::Labels => compare_text(&pr_labels(a), &pr_labels(b)),
+    }
+}
+
+/// Compare two issues by one column. Age sorts youngest first; PR-only
+/// columns leave the order alone.
+pub fn compare_issues(a: &GitHubIssue, b: &GitHubIssue, column: ListColumn) -> Ordering {
+    match column {
+        ListColumn::State => a.state.cmp(&b.state),
+        ListColumn::Number => a.number.cmp(&b.number),
+        ListColumn::Title => compare_text(&a.title, &b.title),
+        ListColumn::Author => compare_text(&a.author.login, &b.author.login),
+        ListColumn::Age => b.created_at.cmp(&a.created_at),
+        ListColumn::Labels => compare_text(&issue_labels(a), &issue_labels(b)),
+        ListColumn::Review | ListColumn::Ci | ListColumn::Size => Ordering::Equal,
+    }
+}
+
+fn compare_text(a: &str, b: &str) -> Ordering {
+    a.to_lowercase().cmp(&b.to_lowercase())
+}
+
+/// Changes requested first, approved last.
+fn review_rank(pr: &PullRequest) -> u8 {
+    match pr.review_decision.as_deref() {
+        Some("CHANGES_REQUESTED") => 0,
+        Some("REVIEW_REQUIRED") => 1,
+        Some("APPROVED") => 3,
+        _ => 2,
+    }
+}
+
+/// Failing first, PRs without checks last.
+fn ci_rank(pr: &PullRequest) -> u8 {
+    match pr.checks_outcome() {
+        Some(CheckOutcome::Failure) => 0,
+        Some(CheckOutcome::Pending) => 1,
+        Some(_) => 2,
+        None => 3,
+    }
+}
+
+pub fn pr_labels(pr: &PullRequest) -> String {
+    let names: Vec<&str> = pr.labels.iter().map(|l| l.name.as_str()).collect();
+    names.join(",")
+}
+
+pub fn issue_labels(issue: &GitHubIssue) -> String {
+    let names: Vec<&str> = issue.labels.iter().map(|l| l.name.as_str()).collect();
+    names.join(",")
+}
+
+/// Compact age of an RFC 3339 timestamp: `now`, `12m`, `3h`, `5d`, `7w`.
+/// Empty when the timestamp doesn't parse.
+pub fn short_age(timestamp: &str, now: DateTime<Utc>) -> String {
+    let Ok(then) = DateTime::parse_from_rfc3339(timestamp) else {
+        return String::new();
+    };
+    let age = now.signed_duration_since(then);
+    if age.num_minutes() < 1 {
+        "now".to_string()
+    } else if age.num_hours() < 1 {
+        format!("{}m", age.num_minutes())
+    } else if age.num_days() < 1 {
+        format!("{}h", age.num_hours())
+    } else if age.num_weeks() < 8 {
+        format!("{}d", age.num_days())
+    } else {
+        format!("{}w", age.num_weeks())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sorts_within_sections_and_scrolls_columns() {
+        #[derive(Debug, PartialEq)]
+        enum Row {
+            Header,
+            Item(u64),
+        }
+        let mut rows = vec![
+            Row::Header,
+            Row::Item(3),
+            Row::Item(1),
+            Row::Header,
+            Row::Item(2),
+            Row::Item(9),
+        ];
+        let mut view = TableView::new(PR_COLUMNS.to_vec());
+        let is_header = |r: &Row| *r == Row::Header;
+        let compare = |a: &Row, b: &Row, _| match (a, b) {
+            (Row::Item(a), Row::Item(b)) => a.cmp(b),
+            _ => Ordering::Equal,
+        };
+
+        view.cycle_sort();
+        assert_eq!(view.sort, Some(ListColumn::Review));
+        view.descending = true;
+        view.sort_sections(&mut rows, is_header, compare);
+        assert_eq!(
+            rows,
+            [
+                Row::Header,
+                Row::Item(3),
+                Row::Item(1),
+                Row::Header,
+                Row::Item(9),
+                Row::Item(2)
+            ]
+        );
+        for _ in 0..PR_COLUMNS.len() {
+            view.cycle_sort();
+        }
+        assert_eq!(view.sort, None);
+
+        // 4+3+6+20+12+4+5 cells plus 6 gaps: everything fits in 60
+        view.scroll = 4;
+        assert_eq!(view.first_column(60), 0);
+        assert_eq!(view.first_column(45), 3);
+        assert_eq!(view.first_column(10), 4);
+        assert_eq!(view.visible_columns(45), 3..7);
+        assert_eq!(view.visible_columns(10), 4..5);
+        view.scroll = 0;
+        assert_eq!(view.visible_columns(40), 0..4);
+
+        let columns = vec!["title".to_string(), "bogus".to_string(), "AGE".to_string()];
+        assert_eq!(
+            parse_columns(Some(&columns), "prs.columns", PR_COLUMNS),
+            [ListColumn::Title, ListColumn::Age]
+        );
+        assert_eq!(
+            parse_columns(None, "prs.columns", ISSUE_COLUMNS),
+            ISSUE_COLUMNS
+        );
+
+        let now = DateTime::parse_from_rfc3339("2024-05-10T12:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        assert_eq!(short_age("2024-05-10T09:00:00Z", now), "3h");
+        assert_eq!(short_age("2024-01-01T00:00:00Z", now), "18w");
+        assert_eq!(short_age("", now), "");
+    }
+}
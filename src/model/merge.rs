@@ -0,0 +1,123 @@
+/// A region of a three-way merge between a common base, the user's edits
+/// ("mine") and a concurrently written version ("theirs").
+#[derive(Debug, Clone, PartialEq)]
+pub enum MergeChunk {
+    /// Lines identical in all three versions.
+    Stable(Vec<String>),
+    /// A region where at least one side differs from the base.
+    Changed {
+        base: Vec<String>,
+        mine: Vec<String>,
+        theirs: Vec<String>,
+    },
+}
+
+impl MergeChunk {
+    /// True when the other side changed this region differently from us,
+    /// so the user has to decide whether to take it.
+    pub fn is_hunk(&self) -> bool {
+        match self {
+            MergeChunk::Stable(_) => false,
+            MergeChunk::Changed { base, mine, theirs } => theirs != base && theirs != mine,
+        }
+    }
+
+    /// True when both sides changed the same region.
+    pub fn is_conflict(&self) -> bool {
+        match self {
+            MergeChunk::Stable(_) => false,
+            MergeChunk::Changed { base, mine, theirs } => {
+                mine != base && theirs != base && mine != theirs
+            }
+        }
+    }
+}
+
+/// Merge every change from either side, writing regions both sides changed
+/// differently with `<<<<<<< mine` / `=======` / `>>>>>>> upstream` marker
+/// lines around the two versions, for the user to resolve by hand.
+pub fn marked_merge(chunks: &[MergeChunk]) -> String {
+    let mut lines: Vec<&str> = Vec::new();
+    for chunk in chunks {
+        match chunk {
+            MergeChunk::Stable(l) => lines.extend(l.iter().map(|s| s.as_str())),
+            MergeChunk::Changed { mine, theirs, .. } if chunk.is_conflict() => {
+                lines.push("<<<<<<< mine");
+                lines.extend(mine.iter().map(|s| s.as_str()));
+                lines.push("=======");
+                lines.extend(theirs.iter().map(|s| s.as_str()));
+                lines.push(">>>>>>> upstream");
+            }
+            MergeChunk::Changed { mine, theirs, .. } => {
+                let side = if chunk.is_hunk() { theirs } else { mine };
+                lines.extend(side.iter().map(|s| s.as_str()));
+            }
+        }
+    }
+    lines.join("\n")
+}
+
+/// An in-progress merge of an agent's rewrite into a file being edited.
+#[derive(Debug, Clone)]
+pub struct MergeSession {
+    pub chunks: Vec<MergeChunk>,
+    /// Per-chunk decision; only consulted for hunks. `true` takes theirs.
+    pub accepted: Vec<bool>,
+    /// Index into `hunk_indices()` of the selected hunk.
+    pub selected: usize,
+    /// The full text on disk that the merge was computed against.
+    pub theirs_text: String,
+}
+
+impl MergeSession {
+    /// Start a session. Non-conflicting hunks default to accepted,
+    /// conflicting ones default to keeping the user's lines.
+    pub fn new(chunks: Vec<MergeChunk>, theirs_text: String) -> Self {
+        let accepted = chunks
+            .iter()
+            .map(|c| c.is_hunk() && !c.is_conflict())
+            .collect();
+        Self {
+            chunks,
+            accepted,
+            selected: 0,
+            theirs_text,
+        }
+    }
+
+    /// Indices into `chunks` of the regions that need a decision.
+    pub fn hunk_indices(&self) -> Vec<usize> {
+        self.chunks
+            .iter()
+            .enumerate()
+            .filter(|(_, c)| c.is_hunk())
+            .map(|(i, _)| i)
+            .collect()
+    }
+
+    /// Flip the decision for the selected hunk.
+    pub fn set_selected(&mut self, accept: bool) {
+        if let Some(&idx) = self.hunk_indices().get(self.selected) {
+            self.accepted[idx] = accept;
+        }
+    }
+
+    /// Assemble the merged text from the current decisions.
+    pub fn merged_text(&self) -> String {
+        let mut lines: Vec<&str> = Vec::new();
+        for (chunk, &accept) in self.chunks.iter().zip(&self.accepted) {
+            match chunk {
+                MergeChunk::Stable(l) => lines.extend(l.iter().map(|s| s.as_str())),
+                MergeChunk::Changed { mine, theirs, .. } => {
+                    let side = if chunk.is_hunk() && accept {
+                        theirs
+                    } else {
+                        mine
+                    };
+                    lines.extend(side.iter().map(|s| s.as_str()));
+                }
+            }
+        }
+        lines.join("\n")
+    }
+}
@@ -1,14 +1,37 @@
+pub mod activity;
 pub mod agent_status;
+pub mod artifact;
+pub mod azure;
+pub mod backoff;
+pub mod claude_settings;
+pub mod command;
+pub mod deps;
+pub mod file_ref;
 pub mod filebrowser;
+pub mod filter;
 pub mod git;
 pub mod github;
+pub mod grep;
+pub mod hook;
 pub mod inbox;
 pub mod jira;
 pub mod linear;
+pub mod link_preview;
+pub mod list_table;
+pub mod merge;
+pub mod notification;
+pub mod overview;
 pub mod plan;
 pub mod process;
+pub mod prompt;
+pub mod redact;
+pub mod search;
 pub mod session;
+pub mod share;
+pub mod snapshot;
+pub mod status_bar;
 pub mod task;
 pub mod team;
 pub mod todo;
 pub mod transcript;
+pub mod trash;
@@ -0,0 +1,82 @@
+/// Template used by sinks that don't set their own.
+pub const DEFAULT_TEMPLATE: &str = "[{project}] {title}\n{detail}";
+
+/// Activity that can be posted to the configured notification sinks.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum NotificationEvent {
+    /// A spawned Claude Code process exited.
+    ProcessFinished,
+    /// A PR from an agent's branch (or carrying Claude Code's footer) appeared.
+    PrOpened,
+    /// A team's token total reached `notifications.token_budget`.
+    BudgetExceeded,
+}
+
+impl NotificationEvent {
+    /// Name used in a sink's `events` list and the `{event}` placeholder.
+    pub fn key(&self) -> &'static str {
+        match self {
+            Self::ProcessFinished => "process_finished",
+            Self::PrOpened => "pr_opened",
+            Self::BudgetExceeded => "budget_exceeded",
+        }
+    }
+}
+
+/// One notification, rendered per sink with that sink's template.
+#[derive(Debug, Clone)]
+pub struct Notification {
+    pub event: NotificationEvent,
+    pub title: String,
+    pub detail: String,
+}
+
+impl Notification {
+    /// Fill `{event}`, `{title}`, `{detail}` and `{project}` in `template`.
+    /// Unknown placeholders are left as written.
+    pub fn render(&self, template: &str, project: &str) -> String {
+        let mut out = String::new();
+        let mut rest = template;
+        while let Some(start) = rest.find('{') {
+            out.push_str(&rest[..start]);
+            let after = &rest[start..];
+            let Some(end) = after.find('}') else {
+                rest = after;
+                break;
+            };
+            let value = match &after[1..end] {
+                "event" => Some(self.event.key()),
+                "title" => Some(self.title.as_str()),
+                "detail" => Some(self.detail.as_str()),
+                "project" => Some(project),
+                _ => None,
+            };
+            out.push_str(value.unwrap_or(&after[..=end]));
+            rest = &after[end + 1..];
+        }
+        out.push_str(rest);
+        out.trim_end().to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn renders_templates() {
+        let n = Notification {
+            event: NotificationEvent::PrOpened,
+            title: "PR #7 opened: {title}".to_string(),
+            detail: String::new(),
+        };
+        assert_eq!(
+            n.render(DEFAULT_TEMPLATE, "assoc"),
+            "[assoc] PR #7 opened: {title}"
+        );
+        assert_eq!(
+            n.render("{event} {unknown} {title", "assoc"),
+            "pr_opened {unknown} {title"
+        );
+    }
+}
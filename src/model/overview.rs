@@ -0,0 +1,90 @@
+use chrono::{DateTime, Duration, Utc};
+
+use crate::model::agent_status::AgentStatus;
+use crate::model::session::SessionEntry;
+
+/// Sessions modified within this many minutes count as active.
+pub const ACTIVE_SESSION_MINUTES: i64 = 15;
+
+/// Number of sessions modified within the last `ACTIVE_SESSION_MINUTES`.
+pub fn active_session_count(sessions: &[SessionEntry], now: DateTime<Utc>) -> usize {
+    let cutoff = now - Duration::minutes(ACTIVE_SESSION_MINUTES);
+    sessions
+        .iter()
+        .filter(|s| s.modified.is_some_and(|m| m >= cutoff))
+        .count()
+}
+
+/// Short age like "just now", "12m ago", "3h ago" or "5d ago".
+pub fn relative_age(then: DateTime<Utc>, now: DateTime<Utc>) -> String {
+    let age = now.signed_duration_since(then);
+    if age.num_minutes() < 1 {
+        "just now".to_string()
+    } else if age.num_hours() < 1 {
+        format!("{}m ago", age.num_minutes())
+    } else if age.num_days() < 1 {
+        format!("{}h ago", age.num_hours())
+    } else {
+        format!("{}d ago", age.num_days())
+    }
+}
+
+/// Summarize a team's agents as "2 working, 1 idle", in status order.
+pub fn status_summary(statuses: &[AgentStatus]) -> String {
+    let parts: Vec<String> = [
+        AgentStatus::Working,
+        AgentStatus::Starting,
+        AgentStatus::Idle,
+        AgentStatus::ShutDown,
+    ]
+    .iter()
+    .filter_map(|status| {
+        let count = statuses.iter().filter(|s| *s == status).count();
+        (count > 0).then(|| format!("{} {}", count, status.label()))
+    })
+    .collect();
+    if parts.is_empty() {
+        "no agents".to_string()
+    } else {
+        parts.join(", ")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn overview_summaries() {
+        let now = Utc::now();
+        let session = |mins: i64| SessionEntry {
+            session_id: "abc".to_string(),
+            first_prompt: None,
+            summary: None,
+            message_count: None,
+            created: None,
+            modified: Some(now - Duration::minutes(mins)),
+            git_branch: None,
+            project_path: None,
+            is_sidechain: None,
+            home: 0,
+            custom_title: None,
+            link: None,
+        };
+        let sessions = vec![session(1), session(14), session(30)];
+        assert_eq!(active_session_count(&sessions, now), 2);
+
+        assert_eq!(relative_age(now, now), "just now");
+        assert_eq!(relative_age(now - Duration::minutes(12), now), "12m ago");
+        assert_eq!(relative_age(now - Duration::hours(3), now), "3h ago");
+        assert_eq!(relative_age(now - Duration::days(5), now), "5d ago");
+
+        let statuses = vec![
+            AgentStatus::Idle,
+            AgentStatus::Working,
+            AgentStatus::Working,
+        ];
+        assert_eq!(status_summary(&statuses), "2 working, 1 idle");
+        assert_eq!(status_summary(&[]), "no agents");
+    }
+}
@@ -1,5 +1,8 @@
 use std::time::SystemTime;
 
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
 #[derive(Debug, Clone, PartialEq)]
 pub enum MarkdownLineKind {
     Heading,
@@ -14,6 +17,23 @@ pub struct MarkdownLine {
     pub text: String,
 }
 
+/// A heading in a plan's outline sidebar.
+#[derive(Debug, Clone, PartialEq)]
+pub struct OutlineEntry {
+    /// Heading depth: 1 for `#`, 2 for `##`, ...
+    pub level: usize,
+    pub title: String,
+    /// Index of the heading in the plan's lines.
+    pub line: usize,
+}
+
+/// Word and paragraph counts shown under a plan's content.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct PlanStats {
+    pub words: usize,
+    pub paragraphs: usize,
+}
+
 #[derive(Debug, Clone)]
 pub struct PlanFile {
     pub filename: String,
@@ -27,3 +47,19 @@ impl PlanFile {
         self.filename.strip_suffix(".md").unwrap_or(&self.filename)
     }
 }
+
+/// Accept/reject decision on a plan an agent proposed in a session.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct PlanDecision {
+    pub session_id: String,
+    /// Timestamp of the transcript entry that proposed the plan.
+    pub proposed: Option<DateTime<Utc>>,
+    pub accepted: bool,
+    pub decided: DateTime<Utc>,
+}
+
+impl PlanDecision {
+    pub fn is_for(&self, session_id: &str, proposed: Option<DateTime<Utc>>) -> bool {
+        self.session_id == session_id && self.proposed == proposed
+    }
+}
@@ -1,9 +1,67 @@
 use std::collections::VecDeque;
 use std::path::PathBuf;
 
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::model::artifact::Artifact;
+
 /// Maximum number of output/error lines retained per process.
 pub const MAX_PROCESS_OUTPUT_LINES: usize = 10_000;
 
+/// Line the output pane puts between stdout and stderr.
+pub const STDERR_HEADER: &str = "--- stderr ---";
+
+/// Lowercase text that marks an output line as an error, for runs that
+/// wrote nothing to stderr.
+const ERROR_PATTERNS: [&str; 7] = [
+    "error",
+    "failed",
+    "[fail",
+    "panic",
+    "exception",
+    "traceback",
+    "fatal",
+];
+
+/// A line of terminal output without escape sequences, keeping only the
+/// last frame a carriage return redrew.
+pub fn plain_text(line: &str) -> String {
+    let line = line
+        .split('\r')
+        .rev()
+        .find(|frame| !frame.is_empty())
+        .unwrap_or("");
+    let mut out = String::with_capacity(line.len());
+    let mut chars = line.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\x1b' => match chars.next() {
+                // CSI: parameters up to a final byte in @..~
+                Some('[') => {
+                    for c in chars.by_ref() {
+                        if ('@'..='~').contains(&c) {
+                            break;
+                        }
+                    }
+                }
+                // OSC: up to BEL or ESC \
+                Some(']') => {
+                    while let Some(c) = chars.next() {
+                        if c == '\x07' || (c == '\x1b' && chars.next().is_some()) {
+                            break;
+                        }
+                    }
+                }
+                _ => {}
+            },
+            c if c.is_control() && c != '\t' => {}
+            c => out.push(c),
+        }
+    }
+    out
+}
+
 /// Represents a Claude Code process spawned from a ticket.
 #[derive(Debug, Clone)]
 pub struct SpawnedProcess {
@@ -15,10 +73,18 @@ pub struct SpawnedProcess {
     pub title: String,
     /// Source of the ticket.
     pub source: TicketSource,
+    /// Web URL of the ticket, which run artifacts are recorded against.
+    pub ticket_url: String,
     /// Current status.
     pub status: ProcessStatus,
     /// The prompt that was sent to Claude Code.
     pub prompt: String,
+    /// `--model` the process was launched with; None for Claude Code's
+    /// default.
+    pub model: Option<String>,
+    /// `claude` arguments after the prompt, kept so a queued process starts
+    /// as launched.
+    pub args: Vec<String>,
     /// Working directory where the process was spawned.
     pub cwd: PathBuf,
     /// Captured stdout lines (raw, kept for debug). Capped at MAX_PROCESS_OUTPUT_LINES.
@@ -29,21 +95,120 @@ pub struct SpawnedProcess {
     pub session_id: Option<String>,
     /// Human-readable parsed progress lines for the UI.
     pub progress_lines: Vec<String>,
+    /// Whether the output pane sticks to the bottom as new lines arrive.
+    pub follow: bool,
+    /// PRs, issues and commits linked in the output, oldest first.
+    pub artifacts: Vec<Artifact>,
+    /// When the process started; None while queued.
+    pub started_at: Option<DateTime<Utc>>,
+    pub finished_at: Option<DateTime<Utc>>,
+    /// None while running, or when the process was killed by a signal.
+    pub exit_code: Option<i32>,
+    /// Git tree ids of the working tree when the run started and ended,
+    /// which the run report diffs.
+    pub tree_before: Option<String>,
+    pub tree_after: Option<String>,
+}
+
+impl SpawnedProcess {
+    /// Number of lines the output pane shows: progress (or raw stdout until
+    /// the first parsed event), then a stderr section.
+    pub fn output_line_count(&self) -> usize {
+        let stdout = if self.progress_lines.is_empty() {
+            self.output_lines.len()
+        } else {
+            self.progress_lines.len()
+        };
+        let stderr = if self.error_lines.is_empty() {
+            0
+        } else {
+            self.error_lines.len() + 2
+        };
+        (stdout + stderr).max(1)
+    }
+
+    /// The output pane's lines as plain text, laid out as
+    /// `output_line_count` counts them.
+    pub fn output_text(&self) -> Vec<String> {
+        let mut lines: Vec<String> = if self.progress_lines.is_empty() {
+            self.output_lines.iter().map(|l| plain_text(l)).collect()
+        } else {
+            self.progress_lines.iter().map(|l| plain_text(l)).collect()
+        };
+        if !self.error_lines.is_empty() {
+            lines.push(String::new());
+            lines.push(STDERR_HEADER.to_string());
+            lines.extend(self.error_lines.iter().map(|l| plain_text(l)));
+        }
+        lines
+    }
+
+    /// Output lines containing `query`, ignoring case.
+    pub fn output_matches(&self, query: &str) -> Vec<usize> {
+        let query = query.to_lowercase();
+        self.output_text()
+            .iter()
+            .enumerate()
+            .filter(|(_, line)| line.to_lowercase().contains(&query))
+            .map(|(i, _)| i)
+            .collect()
+    }
+
+    /// Where to look first when a run went wrong: the first stderr line,
+    /// or else the first line that reads like an error.
+    pub fn first_error_line(&self) -> Option<usize> {
+        let lines = self.output_text();
+        if !self.error_lines.is_empty() {
+            return Some(lines.len() - self.error_lines.len());
+        }
+        lines.iter().position(|line| {
+            let line = line.to_lowercase();
+            ERROR_PATTERNS.iter().any(|p| line.contains(p))
+        })
+    }
+
+    /// Whether the process has exited.
+    pub fn is_finished(&self) -> bool {
+        matches!(
+            self.status,
+            ProcessStatus::Completed | ProcessStatus::Failed
+        )
+    }
 }
 
 /// Where the ticket came from.
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum TicketSource {
     GitHubPR,
     GitHubIssue,
     Linear,
     Jira,
+    Azure,
+    Dependency,
+}
+
+impl TicketSource {
+    /// Stable name for files keyed by source.
+    pub fn key(&self) -> &'static str {
+        match self {
+            TicketSource::GitHubPR => "github_pr",
+            TicketSource::GitHubIssue => "github_issue",
+            TicketSource::Linear => "linear",
+            TicketSource::Jira => "jira",
+            TicketSource::Azure => "azure",
+            TicketSource::Dependency => "dependency",
+        }
+    }
 }
 
 /// Status of a spawned process.
 #[derive(Debug, Clone, PartialEq)]
 pub enum ProcessStatus {
+    /// Waiting for a slot under `processes.max_concurrent`.
+    Queued,
     Running,
+    /// Asked to stop; force-killed if still alive when the timeout expires.
+    Terminating,
     Completed,
     Failed,
 }
@@ -59,3 +224,98 @@ pub struct TicketInfo {
     pub url: String,
     pub extra_fields: Vec<(String, String)>,
 }
+
+/// A spawned process group, persisted so a later run can find it if this
+/// one exits without cleaning up.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ProcessRecord {
+    /// Process group id (the leader's pid).
+    pub pid: u32,
+    /// Pid of the assoc instance that spawned it.
+    pub owner: u32,
+    pub label: String,
+    pub started: DateTime<Utc>,
+}
+
+/// CPU and resident memory of a process together with the processes it
+/// started.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct ProcessUsage {
+    /// Percent of one core, so a process busy on two cores shows 200.
+    pub cpu: f32,
+    /// Resident memory in bytes.
+    pub memory: u64,
+}
+
+impl ProcessUsage {
+    pub fn add(&mut self, other: ProcessUsage) {
+        self.cpu += other.cpu;
+        self.memory += other.memory;
+    }
+
+    /// Compact `12%` CPU for the Processes list.
+    pub fn cpu_label(&self) -> String {
+        format!("{:.0}%", self.cpu)
+    }
+
+    /// Compact `340M` / `1.2G` memory for the Processes list.
+    pub fn memory_label(&self) -> String {
+        const MB: u64 = 1024 * 1024;
+        if self.memory >= 1024 * MB {
+            format!("{:.1}G", self.memory as f64 / (1024 * MB) as f64)
+        } else {
+            format!("{}M", self.memory / MB)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn process(progress: &[&str], stderr: &[&str]) -> SpawnedProcess {
+        SpawnedProcess {
+            id: 1,
+            label: "ENG-12".to_string(),
+            title: "Fix login".to_string(),
+            source: TicketSource::Linear,
+            ticket_url: String::new(),
+            status: ProcessStatus::Failed,
+            prompt: String::new(),
+            model: None,
+            args: Vec::new(),
+            cwd: PathBuf::from("/tmp"),
+            output_lines: VecDeque::new(),
+            error_lines: stderr.iter().map(|l| l.to_string()).collect(),
+            session_id: None,
+            progress_lines: progress.iter().map(|l| l.to_string()).collect(),
+            follow: true,
+            artifacts: Vec::new(),
+            started_at: None,
+            finished_at: None,
+            exit_code: Some(1),
+            tree_before: None,
+            tree_after: None,
+        }
+    }
+
+    #[test]
+    fn finds_matches_and_the_first_error() {
+        let run = process(
+            &[
+                "Session: abc",
+                "-> Bash cargo test",
+                "\x1b[31mtest login FAILED\x1b[0m",
+            ],
+            &[],
+        );
+        assert_eq!(run.output_matches("BASH"), vec![1]);
+        assert_eq!(run.first_error_line(), Some(2));
+
+        // stderr wins over error-looking stdout
+        let run = process(&["-> Bash cargo test", "error: 1 test failed"], &["oops"]);
+        assert_eq!(run.output_text()[4], "oops");
+        assert_eq!(run.first_error_line(), Some(4));
+        assert_eq!(run.output_line_count(), run.output_text().len());
+    }
+}
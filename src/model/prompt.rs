@@ -0,0 +1,66 @@
+use serde::{Deserialize, Serialize};
+
+/// A reusable prompt loaded from a `prompts/*.md` file.
+#[derive(Debug, Clone)]
+pub struct PromptTemplate {
+    /// Display name in the prompt picker.
+    pub title: String,
+    /// Prompt text, possibly containing `{{variable}}` placeholders.
+    pub body: String,
+    /// Model to launch Claude Code with (`--model`).
+    pub model: Option<String>,
+    /// Extra command-line arguments for the launched process.
+    pub args: Vec<String>,
+}
+
+/// The prompt exactly as a launch would send it, shown by the prompt
+/// modal's dry run and confirmed before every launch.
+#[derive(Debug, Clone)]
+pub struct PromptPreview {
+    pub text: String,
+    /// Estimated at about four characters a token.
+    pub tokens: u64,
+    /// `{{name}}` placeholders left unfilled.
+    pub unresolved: Vec<String>,
+    pub scroll: usize,
+}
+
+/// Models the prompt modal's model choice steps through after Claude
+/// Code's default.
+pub const MODEL_CHOICES: &[&str] = &["sonnet", "opus", "haiku"];
+
+/// How the last launch from a ticket source ran, offered again for the
+/// next one.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LaunchDefaults {
+    /// Extra `claude` arguments.
+    #[serde(default)]
+    pub args: Vec<String>,
+    /// Whether to pass `--dangerously-skip-permissions`.
+    #[serde(default = "default_skip_permissions")]
+    pub skip_permissions: bool,
+    /// `--model`; None uses Claude Code's default.
+    #[serde(default)]
+    pub model: Option<String>,
+    /// Name of the agent from `.claude/agents/` the run used.
+    #[serde(default)]
+    pub agent: Option<String>,
+    #[serde(default)]
+    pub max_turns: Option<u32>,
+}
+
+impl Default for LaunchDefaults {
+    fn default() -> Self {
+        Self {
+            args: Vec::new(),
+            skip_permissions: true,
+            model: None,
+            agent: None,
+            max_turns: None,
+        }
+    }
+}
+
+fn default_skip_permissions() -> bool {
+    true
+}
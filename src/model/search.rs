@@ -0,0 +1,193 @@
+//! Goto anything (Ctrl+K): one ranked list of what every tab has loaded.
+//! Each tab's loader replaces its own entries in the [`SearchRegistry`]
+//! whenever its list changes.
+
+/// The tab a group of entries comes from, in the order ties are listed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum SearchSource {
+    Sessions,
+    Plans,
+    Todos,
+    Prs,
+    Issues,
+    Jira,
+    Linear,
+    Teams,
+    Files,
+}
+
+impl SearchSource {
+    pub fn label(&self) -> &'static str {
+        match self {
+            SearchSource::Sessions => "Session",
+            SearchSource::Plans => "Plan",
+            SearchSource::Todos => "Todo",
+            SearchSource::Prs => "PR",
+            SearchSource::Issues => "Issue",
+            SearchSource::Jira => "Jira",
+            SearchSource::Linear => "Linear",
+            SearchSource::Teams => "Member",
+            SearchSource::Files => "File",
+        }
+    }
+}
+
+/// Where choosing an entry leads.
+#[derive(Debug, Clone, PartialEq)]
+pub enum SearchTarget {
+    Session(String),
+    /// A plan's file name.
+    Plan(String),
+    Todo {
+        file: String,
+        item: usize,
+    },
+    Pr(u64),
+    Issue(u64),
+    Jira(String),
+    Linear(String),
+    Member {
+        team: String,
+        member: String,
+    },
+    /// Relative to the project.
+    File(String),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SearchEntry {
+    /// What the query is matched against.
+    pub text: String,
+    /// Shown dimmed after the text: the team, the todo list, the branch...
+    pub detail: String,
+    pub target: SearchTarget,
+}
+
+/// Entries from every tab that has loaded.
+#[derive(Debug, Default)]
+pub struct SearchRegistry {
+    sources: Vec<(SearchSource, Vec<SearchEntry>)>,
+}
+
+impl SearchRegistry {
+    /// Replace `source`'s entries with `entries`.
+    pub fn set(&mut self, source: SearchSource, entries: Vec<SearchEntry>) {
+        self.sources.retain(|(s, _)| *s != source);
+        self.sources.push((source, entries));
+        self.sources.sort_by_key(|(s, _)| *s);
+    }
+
+    /// The best `limit` entries for `query`, best first. An empty query
+    /// matches nothing.
+    pub fn search(&self, query: &str, limit: usize) -> Vec<(SearchSource, &SearchEntry)> {
+        let query = query.trim().to_lowercase();
+        if query.is_empty() {
+            return Vec::new();
+        }
+        let mut found: Vec<(u32, SearchSource, &SearchEntry)> = self
+            .sources
+            .iter()
+            .flat_map(|(source, entries)| entries.iter().map(move |e| (*source, e)))
+            .filter_map(|(source, entry)| Some((score(&entry.text, &query)?, source, entry)))
+            .collect();
+        // Stable, so equal scores keep the source order
+        found.sort_by_key(|(score, ..)| std::cmp::Reverse(*score));
+        found
+            .into_iter()
+            .take(limit)
+            .map(|(_, source, entry)| (source, entry))
+            .collect()
+    }
+}
+
+/// How well `text` matches the lowercase `query`, higher is better; None
+/// when it doesn't. Whole matches beat prefixes, prefixes beat matches at
+/// a word start, those beat other substrings, and those beat the query's
+/// letters appearing in order. Shorter texts win ties.
+pub fn score(text: &str, query: &str) -> Option<u32> {
+    let text = text.to_lowercase();
+    let rank = if text == query {
+        5
+    } else if text.starts_with(query) {
+        4
+    } else if let Some(at) = text.find(query) {
+        let word_start = text[..at]
+            .chars()
+            .next_back()
+            .is_some_and(|c| !c.is_alphanumeric());
+        if word_start {
+            3
+        } else {
+            2
+        }
+    } else if is_subsequence(&text, query) {
+        1
+    } else {
+        return None;
+    };
+    let length_penalty = text.chars().count().min(999) as u32;
+    Some(rank * 1_000 + (999 - length_penalty))
+}
+
+fn is_subsequence(text: &str, query: &str) -> bool {
+    let mut chars = text.chars();
+    query.chars().all(|q| chars.any(|c| c == q))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(text: &str, target: SearchTarget) -> SearchEntry {
+        SearchEntry {
+            text: text.to_string(),
+            detail: String::new(),
+            target,
+        }
+    }
+
+    #[test]
+    fn ranks_matches_across_sources() {
+        let mut registry = SearchRegistry::default();
+        registry.set(
+            SearchSource::Files,
+            vec![
+                entry(
+                    "src/ui/layout.rs",
+                    SearchTarget::File("src/ui/layout.rs".into()),
+                ),
+                entry("docs/login.md", SearchTarget::File("docs/login.md".into())),
+            ],
+        );
+        registry.set(
+            SearchSource::Jira,
+            vec![entry(
+                "PROJ-12 Fix login",
+                SearchTarget::Jira("PROJ-12".into()),
+            )],
+        );
+        registry.set(
+            SearchSource::Plans,
+            vec![entry(
+                "login-flow.md",
+                SearchTarget::Plan("login-flow.md".into()),
+            )],
+        );
+
+        let found: Vec<&str> = registry
+            .search("Login", 10)
+            .iter()
+            .map(|(_, e)| e.text.as_str())
+            .collect();
+        // Prefix, then word starts (shorter first), and layout.rs not at all
+        assert_eq!(
+            found,
+            ["login-flow.md", "docs/login.md", "PROJ-12 Fix login"]
+        );
+        assert_eq!(registry.search("slrs", 10)[0].0, SearchSource::Files);
+        assert!(registry.search("  ", 10).is_empty());
+
+        registry.set(SearchSource::Plans, Vec::new());
+        assert_eq!(registry.search("login", 10).len(), 2);
+    }
+}
@@ -1,5 +1,9 @@
-use chrono::{DateTime, Utc};
-use serde::Deserialize;
+use std::collections::{BTreeMap, BTreeSet};
+
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::model::process::{TicketInfo, TicketSource};
 
 #[derive(Debug, Deserialize)]
 pub struct SessionIndex {
@@ -8,7 +12,7 @@ pub struct SessionIndex {
     pub entries: Vec<SessionEntry>,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct SessionEntry {
     pub session_id: String,
@@ -28,11 +32,61 @@ pub struct SessionEntry {
     pub project_path: Option<String>,
     #[serde(default)]
     pub is_sidechain: Option<bool>,
+    /// Index of the Claude home this session was loaded from (0 = primary).
+    #[serde(skip)]
+    pub home: usize,
+    /// Title given with `r`, stored by assoc rather than in Claude's files.
+    #[serde(skip)]
+    pub custom_title: Option<String>,
+    /// Ticket linked with `L`, stored beside the custom titles.
+    #[serde(skip)]
+    pub link: Option<SessionLink>,
+}
+
+/// A PR, issue or ticket a session was linked to by hand.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SessionLink {
+    pub source: TicketSource,
+    /// `#42`, `PROJ-123` or `ENG-7`, as the ticket's tab shows it.
+    pub key: String,
+    pub title: String,
+    pub url: String,
+}
+
+impl SessionLink {
+    pub fn from_ticket(ticket: &TicketInfo) -> Self {
+        Self {
+            source: ticket.source.clone(),
+            key: ticket.key.clone(),
+            title: ticket.title.clone(),
+            url: ticket.url.clone(),
+        }
+    }
+
+    /// Whether this link points at `key` from `source`. Jira and Linear
+    /// keys compare without case, as the current-issue detection does.
+    pub fn is_to(&self, source: &TicketSource, key: &str) -> bool {
+        self.source == *source && self.key.eq_ignore_ascii_case(key)
+    }
+
+    /// Short name for badges and group headers, e.g. `PR #42`.
+    pub fn label(&self) -> String {
+        let prefix = match self.source {
+            TicketSource::GitHubPR => "PR ",
+            TicketSource::GitHubIssue => "Issue ",
+            _ => "",
+        };
+        format!("{}{}", prefix, self.key)
+    }
 }
 
 impl SessionEntry {
-    /// Display title: summary, first_prompt truncated, or session ID.
+    /// Display title: custom title, summary, first_prompt truncated, or
+    /// session ID.
     pub fn display_title(&self) -> String {
+        if let Some(ref t) = self.custom_title {
+            return t.clone();
+        }
         if let Some(ref s) = self.summary {
             if !s.is_empty() {
                 return s.clone();
@@ -55,3 +109,74 @@ impl SessionEntry {
         self.git_branch.as_deref().unwrap_or("")
     }
 }
+
+/// Totals computed from one session's transcript for the stats pane.
+#[derive(Debug, Clone, Default)]
+pub struct SessionStats {
+    /// Prompts typed by the user (tool results are counted separately).
+    pub user_messages: usize,
+    pub assistant_messages: usize,
+    pub tool_results: usize,
+    /// Tool calls per tool name.
+    pub tools: BTreeMap<String, usize>,
+    /// Files written or edited, deduplicated.
+    pub files_touched: BTreeSet<String>,
+    pub input_tokens: u64,
+    pub output_tokens: u64,
+    pub cache_creation_tokens: u64,
+    pub started: Option<DateTime<Utc>>,
+    pub ended: Option<DateTime<Utc>>,
+    pub subagents: usize,
+    /// Size of the transcript the stats were computed from, to tell when
+    /// they are out of date.
+    pub bytes: u64,
+}
+
+impl SessionStats {
+    /// Tokens processed, excluding cache reads.
+    pub fn tokens(&self) -> u64 {
+        self.input_tokens + self.output_tokens + self.cache_creation_tokens
+    }
+
+    pub fn tool_calls(&self) -> usize {
+        self.tools.values().sum()
+    }
+
+    /// Milliseconds between the first and last timestamped entry.
+    pub fn duration_ms(&self) -> Option<u64> {
+        let (start, end) = (self.started?, self.ended?);
+        u64::try_from((end - start).num_milliseconds()).ok()
+    }
+}
+
+/// Agent activity on one day across the project's sessions.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct DayActivity {
+    /// User prompts and assistant messages.
+    pub messages: usize,
+    /// Sessions with at least one message that day.
+    pub sessions: usize,
+}
+
+/// Per-day activity over the project's recent sessions for the heatmap.
+#[derive(Debug, Clone, Default)]
+pub struct ActivityCalendar {
+    pub days: BTreeMap<NaiveDate, DayActivity>,
+}
+
+impl ActivityCalendar {
+    pub fn day(&self, date: NaiveDate) -> DayActivity {
+        self.days.get(&date).copied().unwrap_or_default()
+    }
+
+    /// Days from `start` through `end` with any activity.
+    pub fn range(
+        &self,
+        start: NaiveDate,
+        end: NaiveDate,
+    ) -> impl Iterator<Item = (NaiveDate, DayActivity)> + '_ {
+        self.days
+            .range(start..=end)
+            .map(|(date, day)| (*date, *day))
+    }
+}
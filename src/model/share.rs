@@ -0,0 +1,155 @@
+use serde::{Deserialize, Serialize};
+
+use super::transcript::TranscriptItem;
+
+/// Most transcript items a mirror keeps; older ones are dropped.
+pub const MAX_MIRROR_ITEMS: usize = 5000;
+
+/// One line of the `--share` stream, sent as JSON.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ShareMessage {
+    /// The host opened a session; the mirror starts over with `items`.
+    Session {
+        project: String,
+        session_id: String,
+        title: String,
+        items: Vec<TranscriptItem>,
+    },
+    /// Items appended to the current session.
+    Items { items: Vec<TranscriptItem> },
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum MirrorStatus {
+    Connecting,
+    Connected,
+    /// Lost or refused; the reason is shown while reconnecting.
+    Disconnected(String),
+}
+
+/// What `assoc mirror` shows: the host's current session, read-only.
+#[derive(Debug)]
+pub struct MirrorState {
+    pub addr: String,
+    pub status: MirrorStatus,
+    pub project: String,
+    pub title: String,
+    pub items: Vec<TranscriptItem>,
+    /// Index of the first visible item when not following.
+    pub scroll: usize,
+    pub follow: bool,
+    /// Rows the transcript gets, set from the terminal size.
+    pub height: usize,
+}
+
+impl MirrorState {
+    pub fn new(addr: String) -> Self {
+        Self {
+            addr,
+            status: MirrorStatus::Connecting,
+            project: String::new(),
+            title: String::new(),
+            items: Vec::new(),
+            scroll: 0,
+            follow: true,
+            height: 0,
+        }
+    }
+
+    pub fn apply(&mut self, message: ShareMessage) {
+        match message {
+            ShareMessage::Session {
+                project,
+                title,
+                items,
+                ..
+            } => {
+                self.project = project;
+                self.title = title;
+                self.items = items;
+                self.scroll = 0;
+            }
+            ShareMessage::Items { items } => self.items.extend(items),
+        }
+        let excess = self.items.len().saturating_sub(MAX_MIRROR_ITEMS);
+        if excess > 0 {
+            self.items.drain(..excess);
+            self.scroll = self.scroll.saturating_sub(excess);
+        }
+    }
+
+    /// First visible item.
+    pub fn top(&self) -> usize {
+        let last_page = self.items.len().saturating_sub(self.height);
+        if self.follow {
+            last_page
+        } else {
+            self.scroll.min(last_page)
+        }
+    }
+
+    /// Scroll by `lines`; moving up stops following.
+    pub fn scroll_by(&mut self, down: bool, lines: usize) {
+        let top = self.top();
+        if down {
+            self.scroll = top + lines;
+            if self.scroll >= self.items.len().saturating_sub(self.height) {
+                self.follow = true;
+            }
+        } else {
+            self.scroll = top.saturating_sub(lines);
+            self.follow = false;
+        }
+    }
+
+    pub fn jump_top(&mut self) {
+        self.scroll = 0;
+        self.follow = false;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::transcript::TranscriptItemKind;
+
+    fn item(text: &str) -> TranscriptItem {
+        TranscriptItem {
+            timestamp: None,
+            kind: TranscriptItemKind::Assistant,
+            text: text.to_string(),
+            usage: None,
+            block: 0,
+            line_offset: None,
+        }
+    }
+
+    #[test]
+    fn mirror_follows_the_stream() {
+        let session = ShareMessage::Session {
+            project: "app".to_string(),
+            session_id: "abc".to_string(),
+            title: "Fix login".to_string(),
+            items: vec![item("one"), item("two")],
+        };
+        let line = serde_json::to_string(&session).unwrap();
+        assert!(line.starts_with(r#"{"type":"session","#));
+
+        let mut mirror = MirrorState::new("host:7878".to_string());
+        mirror.height = 2;
+        mirror.apply(serde_json::from_str(&line).unwrap());
+        mirror.apply(ShareMessage::Items {
+            items: vec![item("three")],
+        });
+        assert_eq!(mirror.title, "Fix login");
+        assert_eq!(mirror.items.len(), 3);
+        assert_eq!(mirror.top(), 1);
+
+        mirror.scroll_by(false, 1);
+        assert!(!mirror.follow);
+        assert_eq!(mirror.top(), 0);
+        mirror.scroll_by(true, 1);
+        assert!(mirror.follow);
+    }
+}
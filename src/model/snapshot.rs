@@ -0,0 +1,107 @@
+use std::collections::{BTreeMap, HashSet};
+
+use serde::{Deserialize, Serialize};
+
+/// How many days of history are kept per tracker.
+pub const SNAPSHOT_HISTORY_DAYS: usize = 30;
+
+/// Tracker keys used in the snapshot store.
+pub const TRACKER_GITHUB_ISSUES: &str = "github_issues";
+pub const TRACKER_JIRA: &str = "jira";
+pub const TRACKER_LINEAR: &str = "linear";
+pub const TRACKER_AZURE: &str = "azure";
+
+/// One day's view of a tracker's open issue list.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct DailySnapshot {
+    /// Local date, `YYYY-MM-DD`.
+    pub date: String,
+    /// Open issues at the most recent load that day.
+    pub open: usize,
+    /// Issues that left the open list during the day.
+    #[serde(default)]
+    pub closed: usize,
+    /// Issue ids seen at the most recent load, used to detect closures.
+    #[serde(default)]
+    pub open_ids: Vec<String>,
+}
+
+/// Per-project history of daily snapshots, keyed by tracker.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SnapshotStore {
+    #[serde(default)]
+    pub trackers: BTreeMap<String, Vec<DailySnapshot>>,
+}
+
+impl SnapshotStore {
+    /// Record the current open issue ids for `tracker` on `date`.
+    ///
+    /// Issues present in the previous snapshot but missing now are counted
+    /// as closed for `date`. Loads on the same day update that day's entry.
+    pub fn record(&mut self, tracker: &str, date: &str, open_ids: Vec<String>) {
+        let history = self.trackers.entry(tracker.to_string()).or_default();
+
+        let closed_now = match history.last() {
+            Some(prev) => {
+                let current: HashSet<&str> = open_ids.iter().map(|s| s.as_str()).collect();
+                prev.open_ids
+                    .iter()
+                    .filter(|id| !current.contains(id.as_str()))
+                    .count()
+            }
+            None => 0,
+        };
+
+        match history.last_mut() {
+            Some(last) if last.date == date => {
+                last.closed += closed_now;
+                last.open = open_ids.len();
+                last.open_ids = open_ids;
+            }
+            _ => history.push(DailySnapshot {
+                date: date.to_string(),
+                open: open_ids.len(),
+                closed: closed_now,
+                open_ids,
+            }),
+        }
+
+        if history.len() > SNAPSHOT_HISTORY_DAYS {
+            let excess = history.len() - SNAPSHOT_HISTORY_DAYS;
+            history.drain(..excess);
+        }
+    }
+
+    /// The most recent `days` snapshots for `tracker`, oldest first.
+    pub fn recent(&self, tracker: &str, days: usize) -> &[DailySnapshot] {
+        match self.trackers.get(tracker) {
+            Some(history) => &history[history.len().saturating_sub(days)..],
+            None => &[],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ids(list: &[&str]) -> Vec<String> {
+        list.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn record_counts_closures_across_days() {
+        let mut store = SnapshotStore::default();
+        store.record(TRACKER_JIRA, "2026-01-01", ids(&["A-1", "A-2", "A-3"]));
+        store.record(TRACKER_JIRA, "2026-01-01", ids(&["A-1", "A-2"]));
+        store.record(TRACKER_JIRA, "2026-01-02", ids(&["A-2", "A-4"]));
+
+        let history = store.recent(TRACKER_JIRA, 14);
+        assert_eq!(history.len(), 2);
+        assert_eq!(history[0].open, 2);
+        assert_eq!(history[0].closed, 1);
+        assert_eq!(history[1].open, 2);
+        assert_eq!(history[1].closed, 1);
+        assert!(store.recent(TRACKER_LINEAR, 14).is_empty());
+    }
+}
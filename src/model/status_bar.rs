@@ -0,0 +1,174 @@
+/// A part of the status bar that `[status_bar]` can place.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum StatusSegment {
+    /// The last error.
+    Errors,
+    /// FOLLOW on the Sessions tab.
+    Follow,
+    /// SHARING with the mirror count (`--share`).
+    Sharing,
+    /// LOW POWER.
+    Power,
+    /// Edit, browse, merge, filter and other mode badges of the active tab.
+    Modes,
+    /// Pane send progress and short-lived confirmations.
+    Messages,
+    /// Countdown to the active tab's next background refresh.
+    Polls,
+    /// Running and queued Claude Code processes.
+    Processes,
+    /// Local time.
+    Clock,
+    /// The project's git branch.
+    Branch,
+    /// Team agents by status, e.g. `2 working, 1 idle`.
+    Agents,
+    /// Key hints for the active tab.
+    Hints,
+}
+
+impl StatusSegment {
+    pub const ALL: [StatusSegment; 12] = [
+        Self::Errors,
+        Self::Follow,
+        Self::Sharing,
+        Self::Power,
+        Self::Modes,
+        Self::Messages,
+        Self::Polls,
+        Self::Processes,
+        Self::Clock,
+        Self::Branch,
+        Self::Agents,
+        Self::Hints,
+    ];
+
+    /// Name used in `.assoc.toml`.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Errors => "errors",
+            Self::Follow => "follow",
+            Self::Sharing => "sharing",
+            Self::Power => "power",
+            Self::Modes => "modes",
+            Self::Messages => "messages",
+            Self::Polls => "polls",
+            Self::Processes => "processes",
+            Self::Clock => "clock",
+            Self::Branch => "branch",
+            Self::Agents => "agents",
+            Self::Hints => "hints",
+        }
+    }
+
+    pub fn parse(name: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|s| s.name().eq_ignore_ascii_case(name.trim()))
+    }
+}
+
+/// Left-aligned segments when `status_bar.left` is unset.
+pub const LEFT_SEGMENTS: &[StatusSegment] = &[
+    StatusSegment::Errors,
+    StatusSegment::Follow,
+    StatusSegment::Sharing,
+    StatusSegment::Power,
+    StatusSegment::Modes,
+    StatusSegment::Messages,
+    StatusSegment::Polls,
+];
+
+/// Right-aligned segments when `status_bar.right` is unset.
+pub const RIGHT_SEGMENTS: &[StatusSegment] = &[StatusSegment::Hints];
+
+/// Which segments the status bar shows, in order, on each side.
+#[derive(Debug, Clone, PartialEq)]
+pub struct StatusBarLayout {
+    pub left: Vec<StatusSegment>,
+    pub center: Vec<StatusSegment>,
+    pub right: Vec<StatusSegment>,
+}
+
+impl Default for StatusBarLayout {
+    fn default() -> Self {
+        parse_layout(None, None, None)
+    }
+}
+
+/// Parse the configured segment names of each side, warning about unknown
+/// ones. A segment is shown once, on the first side that names it. An unset
+/// side gets its default segments that no other side names; an empty list
+/// leaves the side empty.
+pub fn parse_layout(
+    left: Option<&[String]>,
+    center: Option<&[String]>,
+    right: Option<&[String]>,
+) -> StatusBarLayout {
+    let mut placed: Vec<StatusSegment> = Vec::new();
+    let mut side = |names: Option<&[String]>, key: &str| -> Option<Vec<StatusSegment>> {
+        let mut segments = Vec::new();
+        for name in names? {
+            match StatusSegment::parse(name) {
+                Some(segment) if !placed.contains(&segment) => {
+                    placed.push(segment);
+                    segments.push(segment);
+                }
+                Some(_) => {}
+                None => eprintln!(
+                    "Warning: unknown segment {:?} in {}, ignoring it",
+                    name, key
+                ),
+            }
+        }
+        Some(segments)
+    };
+    let left_set = side(left, "status_bar.left");
+    let center_set = side(center, "status_bar.center");
+    let right_set = side(right, "status_bar.right");
+
+    let unplaced = |default: &[StatusSegment]| -> Vec<StatusSegment> {
+        default
+            .iter()
+            .copied()
+            .filter(|s| !placed.contains(s))
+            .collect()
+    };
+    StatusBarLayout {
+        left: left_set.unwrap_or_else(|| unplaced(LEFT_SEGMENTS)),
+        center: center_set.unwrap_or_default(),
+        right: right_set.unwrap_or_else(|| unplaced(RIGHT_SEGMENTS)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn places_each_segment_once() {
+        assert_eq!(StatusBarLayout::default().right, [StatusSegment::Hints]);
+
+        let names = |list: &[&str]| list.iter().map(|s| s.to_string()).collect::<Vec<_>>();
+        let right = names(&["Clock", "bogus", "hints", "errors"]);
+        let layout = parse_layout(None, None, Some(&right));
+        assert_eq!(
+            layout.right,
+            [
+                StatusSegment::Clock,
+                StatusSegment::Hints,
+                StatusSegment::Errors
+            ]
+        );
+        // The default left side leaves out what the right side took
+        assert_eq!(layout.left[0], StatusSegment::Follow);
+        assert!(layout.center.is_empty());
+
+        let left = names(&["branch", "agents"]);
+        let center = names(&["agents", "clock"]);
+        let layout = parse_layout(Some(&left), Some(&center), Some(&[]));
+        assert_eq!(layout.left, [StatusSegment::Branch, StatusSegment::Agents]);
+        assert_eq!(layout.center, [StatusSegment::Clock]);
+        assert!(layout.right.is_empty());
+    }
+}
@@ -48,3 +48,196 @@ impl Task {
         self.subject.as_deref().unwrap_or(&self.id).to_string()
     }
 }
+
+/// Fields a human lead sets when creating or editing a task.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TaskDraft {
+    pub subject: String,
+    pub description: String,
+    pub owner: Option<String>,
+    pub blocked_by: Vec<String>,
+}
+
+/// A task proposed from a plan (`t` on the Plans tab), before it's created.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PlanTask {
+    pub draft: TaskDraft,
+    /// The team already has a task with this subject, or an earlier
+    /// proposal has it.
+    pub duplicate: bool,
+    pub selected: bool,
+}
+
+/// Propose `drafts` to a team holding `existing`. Subjects match ignoring
+/// case, spacing and a trailing period; duplicates start unselected.
+pub fn propose_tasks(drafts: Vec<TaskDraft>, existing: &[Task]) -> Vec<PlanTask> {
+    let key = |subject: &str| {
+        let words: Vec<&str> = subject.split_whitespace().collect();
+        words.join(" ").trim_end_matches('.').to_lowercase()
+    };
+    let mut seen: Vec<String> = existing
+        .iter()
+        .filter_map(|t| t.subject.as_deref())
+        .map(key)
+        .collect();
+    drafts
+        .into_iter()
+        .map(|draft| {
+            let subject = key(&draft.subject);
+            let duplicate = seen.contains(&subject);
+            seen.push(subject);
+            PlanTask {
+                draft,
+                duplicate,
+                selected: !duplicate,
+            }
+        })
+        .collect()
+}
+
+/// A team's tasks laid out by dependency. Lane 0 holds tasks nothing
+/// blocks; a task sits one lane after its latest blocker.
+#[derive(Debug, Default, PartialEq)]
+pub struct TaskGraph {
+    /// Indices into the task list, by lane.
+    pub lanes: Vec<Vec<usize>>,
+    /// Tasks in a dependency cycle.
+    pub cycle: Vec<usize>,
+    /// Tasks that can't start until a cycle is broken.
+    pub behind_cycle: Vec<usize>,
+    /// (task, blocker ID) for blockers that don't exist or were deleted.
+    pub orphans: Vec<(usize, String)>,
+    /// Each task's blockers, from its `blocked_by` and other tasks' `blocks`.
+    pub blockers: Vec<Vec<usize>>,
+}
+
+impl TaskGraph {
+    /// Deleted tasks are left out.
+    pub fn build(tasks: &[Task]) -> Self {
+        let live = |i: usize| tasks[i].status != TaskStatus::Deleted;
+        let find = |id: &str| tasks.iter().position(|t| t.id == id).filter(|&i| live(i));
+
+        let mut graph = TaskGraph {
+            blockers: vec![Vec::new(); tasks.len()],
+            ..Default::default()
+        };
+        for (i, task) in tasks.iter().enumerate().filter(|(i, _)| live(*i)) {
+            for id in &task.blocked_by {
+                match find(id) {
+                    Some(b) if !graph.blockers[i].contains(&b) => graph.blockers[i].push(b),
+                    Some(_) => {}
+                    None => graph.orphans.push((i, id.clone())),
+                }
+            }
+            for id in &task.blocks {
+                if let Some(b) = find(id) {
+                    if !graph.blockers[b].contains(&i) {
+                        graph.blockers[b].push(i);
+                    }
+                }
+            }
+        }
+
+        // Place a task once all its blockers are placed, until nothing moves
+        let mut lane: Vec<Option<usize>> = vec![None; tasks.len()];
+        loop {
+            let mut placed = false;
+            for i in 0..tasks.len() {
+                if !live(i) || lane[i].is_some() {
+                    continue;
+                }
+                let deps: Option<Vec<usize>> = graph.blockers[i].iter().map(|&b| lane[b]).collect();
+                if let Some(deps) = deps {
+                    lane[i] = Some(deps.iter().max().map_or(0, |l| l + 1));
+                    placed = true;
+                }
+            }
+            if !placed {
+                break;
+            }
+        }
+        for (i, l) in lane.iter().enumerate() {
+            match l {
+                Some(l) => {
+                    if graph.lanes.len() <= *l {
+                        graph.lanes.resize(l + 1, Vec::new());
+                    }
+                    graph.lanes[*l].push(i);
+                }
+                None if !live(i) => {}
+                None if graph.reaches(i, i) => graph.cycle.push(i),
+                None => graph.behind_cycle.push(i),
+            }
+        }
+        graph
+    }
+
+    /// Whether `task` waits on a blocker that isn't completed.
+    pub fn is_blocked(&self, tasks: &[Task], task: usize) -> bool {
+        self.blockers[task]
+            .iter()
+            .any(|&b| tasks[b].status != TaskStatus::Completed)
+    }
+
+    /// Whether following blockers from `from` leads to `to`.
+    fn reaches(&self, from: usize, to: usize) -> bool {
+        let mut seen = vec![false; self.blockers.len()];
+        let mut stack = self.blockers[from].clone();
+        while let Some(i) = stack.pop() {
+            if i == to {
+                return true;
+            }
+            if !std::mem::replace(&mut seen[i], true) {
+                stack.extend(&self.blockers[i]);
+            }
+        }
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn task(id: &str, blocks: &[&str], blocked_by: &[&str]) -> Task {
+        Task {
+            id: id.to_string(),
+            subject: None,
+            description: None,
+            status: TaskStatus::Pending,
+            owner: None,
+            blocks: blocks.iter().map(|s| s.to_string()).collect(),
+            blocked_by: blocked_by.iter().map(|s| s.to_string()).collect(),
+            active_form: None,
+            metadata: None,
+        }
+    }
+
+    #[test]
+    fn lays_out_lanes_cycles_and_orphans() {
+        let mut tasks = vec![
+            task("1", &["3"], &[]),
+            task("2", &[], &["1"]),
+            task("3", &[], &["2"]),
+            task("4", &[], &["9", "8"]),
+            task("5", &[], &["6"]),
+            task("6", &[], &["5"]),
+            task("7", &[], &["5"]),
+            task("8", &[], &[]),
+        ];
+        tasks[0].status = TaskStatus::Completed;
+        tasks[7].status = TaskStatus::Deleted;
+        let graph = TaskGraph::build(&tasks);
+
+        assert_eq!(graph.lanes, vec![vec![0, 3], vec![1], vec![2]]);
+        assert_eq!(graph.blockers[2], vec![0, 1]);
+        assert_eq!(graph.cycle, vec![4, 5]);
+        assert_eq!(graph.behind_cycle, vec![6]);
+        assert_eq!(
+            graph.orphans,
+            vec![(3, "9".to_string()), (3, "8".to_string())]
+        );
+        assert!(!graph.is_blocked(&tasks, 1));
+        assert!(graph.is_blocked(&tasks, 2));
+    }
+}
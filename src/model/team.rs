@@ -1,3 +1,5 @@
+use std::collections::{BTreeMap, BTreeSet};
+
 use serde::Deserialize;
 
 #[derive(Debug, Clone, Default, Deserialize)]
@@ -60,6 +62,8 @@ impl TeamMember {
 pub struct Team {
     pub dir_name: String,
     pub config: TeamConfig,
+    /// Index of the Claude home this team was loaded from (0 = primary).
+    pub home: usize,
 }
 
 impl Team {
@@ -67,3 +71,34 @@ impl Team {
         self.config.name.as_deref().unwrap_or(&self.dir_name)
     }
 }
+
+/// Resource usage aggregated over the transcripts of a team's sessions.
+#[derive(Debug, Clone, Default)]
+pub struct TeamResources {
+    /// Transcript files attributed to the team (sessions and subagents).
+    pub transcripts: usize,
+    /// Tokens processed, excluding cache reads.
+    pub tokens: u64,
+    pub tool_calls: usize,
+    /// Files written or edited, deduplicated.
+    pub files_touched: BTreeSet<String>,
+    pub tasks_completed: usize,
+    pub tasks_total: usize,
+    /// Per-member breakdown, keyed by member name.
+    pub members: BTreeMap<String, MemberResources>,
+}
+
+/// One member's share of a team's resource usage.
+#[derive(Debug, Clone, Default)]
+pub struct MemberResources {
+    pub tokens: u64,
+    pub tool_calls: usize,
+    pub files_touched: usize,
+}
+
+impl TeamResources {
+    /// Tokens spent per completed task, or `None` before any task is done.
+    pub fn tokens_per_task(&self) -> Option<u64> {
+        (self.tasks_completed > 0).then(|| self.tokens / self.tasks_completed as u64)
+    }
+}
@@ -1,13 +1,20 @@
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct TodoItem {
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub content: Option<String>,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub status: Option<String>,
-    #[serde(default, rename = "activeForm")]
+    #[serde(
+        default,
+        rename = "activeForm",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub active_form: Option<String>,
+    /// Fields we don't model, kept so saving doesn't drop them.
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 impl TodoItem {
@@ -22,6 +29,63 @@ impl TodoItem {
             _ => "[ ]",
         }
     }
+
+    /// Create a new pending item.
+    pub fn new(content: String) -> Self {
+        Self {
+            active_form: Some(content.clone()),
+            content: Some(content),
+            status: Some("pending".to_string()),
+            extra: serde_json::Map::new(),
+        }
+    }
+
+    /// Ticket the item was promoted to, from the ` [KEY]` its content ends
+    /// with: `PROJ-123`, `ENG-7` or `GH #42`.
+    pub fn ticket(&self) -> Option<&str> {
+        let (_, key) = self
+            .content
+            .as_deref()?
+            .trim_end()
+            .strip_suffix(']')?
+            .rsplit_once(" [")?;
+        let number = match key.strip_prefix("GH #") {
+            Some(number) => number,
+            None => {
+                let (project, number) = key.rsplit_once('-')?;
+                if !project.starts_with(|c: char| c.is_ascii_alphabetic())
+                    || !project
+                        .chars()
+                        .all(|c| c.is_ascii_alphanumeric() || c == '_')
+                {
+                    return None;
+                }
+                number
+            }
+        };
+        (!number.is_empty() && number.chars().all(|c| c.is_ascii_digit())).then_some(key)
+    }
+
+    /// Record the ticket the item was promoted to at the end of its content
+    /// and active form.
+    pub fn set_ticket(&mut self, key: &str) {
+        for text in [&mut self.content, &mut self.active_form]
+            .into_iter()
+            .flatten()
+        {
+            text.push_str(&format!(" [{}]", key));
+        }
+    }
+
+    /// Advance the status: pending -> in_progress -> completed -> pending.
+    pub fn cycle_status(&mut self) {
+        let next = match self.status.as_deref() {
+            Some("in_progress") => "completed",
+            Some("completed") => "pending",
+            _ => "in_progress",
+        };
+        self.status = Some(next.to_string());
+    }
 }
 
 /// A todo file with its items.
@@ -29,6 +93,8 @@ impl TodoItem {
 pub struct TodoFile {
     pub filename: String,
     pub items: Vec<TodoItem>,
+    /// Index of the Claude home this file was loaded from (0 = primary).
+    pub home: usize,
 }
 
 impl TodoFile {
@@ -43,3 +109,50 @@ impl TodoFile {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cycle_status() {
+        let mut item = TodoItem::new("Write tests".to_string());
+        item.cycle_status();
+        assert_eq!(item.status.as_deref(), Some("in_progress"));
+        item.cycle_status();
+        assert_eq!(item.status.as_deref(), Some("completed"));
+        item.cycle_status();
+        assert_eq!(item.status.as_deref(), Some("pending"));
+    }
+
+    #[test]
+    fn test_ticket_written_back() {
+        let mut item = TodoItem::new("Fix flaky [auth] test".to_string());
+        assert_eq!(item.ticket(), None);
+        item.set_ticket("GH #42");
+        assert_eq!(
+            item.content.as_deref(),
+            Some("Fix flaky [auth] test [GH #42]")
+        );
+        assert_eq!(
+            item.active_form.as_deref(),
+            Some("Fix flaky [auth] test [GH #42]")
+        );
+        assert_eq!(item.ticket(), Some("GH #42"));
+
+        let item = TodoItem::new("Ship it [ENG-7]".to_string());
+        assert_eq!(item.ticket(), Some("ENG-7"));
+        let item = TodoItem::new("Pin version [1.2-3]".to_string());
+        assert_eq!(item.ticket(), None);
+    }
+
+    #[test]
+    fn test_roundtrip_keeps_unknown_fields() {
+        let json = r#"{"content":"Fix bug","status":"pending","priority":"high","id":"3"}"#;
+        let item: TodoItem = serde_json::from_str(json).unwrap();
+        let value = serde_json::to_value(&item).unwrap();
+        assert_eq!(value["priority"], "high");
+        assert_eq!(value["id"], "3");
+        assert!(value.get("activeForm").is_none());
+    }
+}
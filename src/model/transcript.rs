@@ -1,5 +1,5 @@
 use chrono::{DateTime, Utc};
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
 /// A single line from a .jsonl transcript file.
@@ -18,10 +18,26 @@ pub struct TranscriptEnvelope {
 
 #[derive(Debug, Clone, Deserialize)]
 pub struct TranscriptMessage {
+    #[serde(default)]
+    pub id: Option<String>,
     #[serde(default)]
     pub role: Option<String>,
     #[serde(default)]
     pub content: MessageContent,
+    #[serde(default)]
+    pub usage: Option<MessageUsage>,
+}
+
+/// Token counts reported on assistant messages. Cache reads are left out
+/// since they are cheap and would dwarf the rest.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct MessageUsage {
+    #[serde(default)]
+    pub input_tokens: u64,
+    #[serde(default)]
+    pub output_tokens: u64,
+    #[serde(default)]
+    pub cache_creation_input_tokens: u64,
 }
 
 /// Content can be a plain string or an array of content blocks.
@@ -60,18 +76,73 @@ pub enum ContentBlock {
 }
 
 /// Processed transcript item for display.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TranscriptItem {
     pub timestamp: Option<DateTime<Utc>>,
     pub kind: TranscriptItemKind,
     pub text: String,
+    /// Cost/latency of the assistant turn this item came from, if known.
+    pub usage: Option<TurnUsage>,
+    /// Index of the content block within its message this item shows.
+    pub block: usize,
+    /// Byte offset of the JSONL line this item was parsed from.
+    pub line_offset: Option<u64>,
+}
+
+/// Tokens and elapsed time for one assistant turn.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct TurnUsage {
+    /// Tokens processed by the turn, excluding cache reads.
+    pub tokens: Option<u64>,
+    pub elapsed_ms: Option<u64>,
+}
+
+impl TurnUsage {
+    /// Short annotation such as `3.2k tok, 12s`.
+    pub fn summary(&self) -> String {
+        let mut parts = Vec::new();
+        if let Some(t) = self.tokens {
+            parts.push(format!("{} tok", format_tokens(t)));
+        }
+        if let Some(ms) = self.elapsed_ms {
+            parts.push(format_elapsed(ms));
+        }
+        parts.join(", ")
+    }
+}
+
+/// Compact token count such as `950`, `3.2k` or `1.4M`.
+pub fn format_tokens(n: u64) -> String {
+    if n < 1_000 {
+        n.to_string()
+    } else if n < 1_000_000 {
+        format!("{:.1}k", n as f64 / 1_000.0)
+    } else {
+        format!("{:.1}M", n as f64 / 1_000_000.0)
+    }
 }
 
-#[derive(Debug, Clone, PartialEq)]
+/// Compact duration such as `850ms`, `42s`, `3m05s` or `2h10m`.
+pub fn format_elapsed(ms: u64) -> String {
+    let secs = ms / 1_000;
+    if ms < 1_000 {
+        format!("{}ms", ms)
+    } else if secs < 60 {
+        format!("{}s", secs)
+    } else if secs < 3_600 {
+        format!("{}m{:02}s", secs / 60, secs % 60)
+    } else {
+        format!("{}h{:02}m", secs / 3_600, secs % 3_600 / 60)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum TranscriptItemKind {
     User,
     Assistant,
     ToolUse,
+    /// Plan proposed through the ExitPlanMode tool; `text` is the full plan.
+    Plan,
     ToolResult,
     System,
     Progress,
@@ -84,12 +155,57 @@ impl TranscriptItemKind {
             Self::User => "USER",
             Self::Assistant => "ASST",
             Self::ToolUse => "TOOL",
+            Self::Plan => "PLAN",
             Self::ToolResult => "RSLT",
             Self::System => "SYS ",
             Self::Progress => "PROG",
             Self::Other => "    ",
         }
     }
+
+    /// Whether the item's text spreads over several lines in the
+    /// transcript's wrapped mode. Tool calls and results stay on one line.
+    pub fn wraps(&self) -> bool {
+        matches!(self, Self::User | Self::Assistant | Self::Plan)
+    }
+}
+
+/// Width of the tokens/elapsed column, e.g. `12.3k tok, 1m05s`.
+pub const USAGE_COLUMN_WIDTH: usize = 17;
+
+/// Columns before an item's text in the transcript pane: the time, the
+/// kind label and, when shown, the usage column.
+pub fn prefix_width(show_turn_usage: bool) -> usize {
+    if show_turn_usage {
+        14 + USAGE_COLUMN_WIDTH + 1
+    } else {
+        14
+    }
+}
+
+/// Split `text` into rows of at most `width` characters, breaking after
+/// spaces where possible. Each newline starts a new row. Always returns at
+/// least one row.
+pub fn wrap_text(text: &str, width: usize) -> Vec<String> {
+    let width = width.max(1);
+    let mut rows = Vec::new();
+    for line in text.replace('\r', "").split('\n') {
+        let mut rest: Vec<char> = line.chars().collect();
+        while rest.len() > width {
+            // Break after the last space that fits, else mid-word
+            let cut = rest[..=width]
+                .iter()
+                .rposition(|c| *c == ' ')
+                .filter(|&i| i > 0)
+                .unwrap_or(width);
+            let row: String = rest[..cut].iter().collect();
+            rows.push(row.trim_end().to_string());
+            let skip = rest[cut..].iter().take_while(|c| **c == ' ').count();
+            rest.drain(..cut + skip);
+        }
+        rows.push(rest.into_iter().collect());
+    }
+    rows
 }
 
 /// Parse a JSONL line into zero or more TranscriptItems.
@@ -98,7 +214,14 @@ pub fn parse_envelope(envelope: &TranscriptEnvelope) -> Vec<TranscriptItem> {
 
     match envelope.kind.as_str() {
         "user" => parse_message_items(envelope, ts, TranscriptItemKind::User),
-        "assistant" => parse_message_items(envelope, ts, TranscriptItemKind::Assistant),
+        "assistant" => {
+            let usage = envelope_usage(envelope);
+            let mut items = parse_message_items(envelope, ts, TranscriptItemKind::Assistant);
+            for item in &mut items {
+                item.usage = usage;
+            }
+            items
+        }
         "system" => {
             let text = extract_message_text(envelope);
             if text.is_empty() {
@@ -108,6 +231,9 @@ pub fn parse_envelope(envelope: &TranscriptEnvelope) -> Vec<TranscriptItem> {
                 timestamp: ts,
                 kind: TranscriptItemKind::System,
                 text,
+                usage: None,
+                block: 0,
+                line_offset: None,
             }]
         }
         "progress" => {
@@ -124,12 +250,50 @@ pub fn parse_envelope(envelope: &TranscriptEnvelope) -> Vec<TranscriptItem> {
                 timestamp: ts,
                 kind: TranscriptItemKind::Progress,
                 text,
+                usage: None,
+                block: 0,
+                line_offset: None,
             }]
         }
         _ => vec![],
     }
 }
 
+/// Token usage and explicit duration (`durationMs`) carried by an
+/// assistant envelope.
+fn envelope_usage(envelope: &TranscriptEnvelope) -> Option<TurnUsage> {
+    let tokens = envelope
+        .message
+        .as_ref()
+        .and_then(|m| m.usage.as_ref())
+        .map(|u| u.input_tokens + u.cache_creation_input_tokens + u.output_tokens);
+    let elapsed_ms = ["durationMs", "duration_ms"]
+        .iter()
+        .find_map(|k| envelope.extra.get(*k).and_then(|v| v.as_u64()));
+    if tokens.is_none() && elapsed_ms.is_none() {
+        return None;
+    }
+    Some(TurnUsage { tokens, elapsed_ms })
+}
+
+/// Fill in missing elapsed times on assistant items from the gap since the
+/// previous envelope's timestamp.
+pub fn fill_elapsed(items: &mut [TranscriptItem], previous: Option<DateTime<Utc>>) {
+    let Some(prev) = previous else {
+        return;
+    };
+    for item in items {
+        let Some(ts) = item.timestamp else {
+            continue;
+        };
+        if let Some(usage) = item.usage.as_mut() {
+            if usage.elapsed_ms.is_none() {
+                usage.elapsed_ms = u64::try_from((ts - prev).num_milliseconds()).ok();
+            }
+        }
+    }
+}
+
 fn parse_message_items(
     envelope: &TranscriptEnvelope,
     ts: Option<DateTime<Utc>>,
@@ -149,11 +313,14 @@ fn parse_message_items(
                 timestamp: ts,
                 kind: default_kind,
                 text: s.clone(),
+                usage: None,
+                block: 0,
+                line_offset: None,
             }]
         }
         MessageContent::Blocks(blocks) => {
             let mut items = Vec::new();
-            for block in blocks {
+            for (index, block) in blocks.iter().enumerate() {
                 match block {
                     ContentBlock::Text { text } => {
                         if !text.is_empty() {
@@ -161,11 +328,25 @@ fn parse_message_items(
                                 timestamp: ts,
                                 kind: default_kind.clone(),
                                 text: text.clone(),
+                                usage: None,
+                                block: index,
+                                line_offset: None,
                             });
                         }
                     }
                     ContentBlock::ToolUse { name, input } => {
                         let tool_name = name.as_deref().unwrap_or("unknown");
+                        if let Some(plan) = proposed_plan(tool_name, input.as_ref()) {
+                            items.push(TranscriptItem {
+                                timestamp: ts,
+                                kind: TranscriptItemKind::Plan,
+                                text: plan,
+                                usage: None,
+                                block: index,
+                                line_offset: None,
+                            });
+                            continue;
+                        }
                         let summary = match input {
                             Some(Value::Object(map)) => {
                                 // Show first string field as context
@@ -189,6 +370,9 @@ fn parse_message_items(
                             timestamp: ts,
                             kind: TranscriptItemKind::ToolUse,
                             text,
+                            usage: None,
+                            block: index,
+                            line_offset: None,
                         });
                     }
                     ContentBlock::ToolResult { content } => {
@@ -214,6 +398,9 @@ fn parse_message_items(
                             timestamp: ts,
                             kind: TranscriptItemKind::ToolResult,
                             text,
+                            usage: None,
+                            block: index,
+                            line_offset: None,
                         });
                     }
                     ContentBlock::Other => {}
@@ -224,6 +411,58 @@ fn parse_message_items(
     }
 }
 
+/// Plan text of an ExitPlanMode tool call (`exit_plan_mode` in older
+/// transcripts).
+fn proposed_plan(tool_name: &str, input: Option<&Value>) -> Option<String> {
+    if tool_name != "ExitPlanMode" && tool_name != "exit_plan_mode" {
+        return None;
+    }
+    input?
+        .get("plan")?
+        .as_str()
+        .filter(|p| !p.trim().is_empty())
+        .map(str::to_string)
+}
+
+/// Full, untruncated text of content block `block` of an envelope's
+/// message: the text itself, a tool's name and pretty-printed input, or the
+/// complete tool result. `None` for envelopes without message blocks.
+pub fn block_detail(envelope: &TranscriptEnvelope, block: usize) -> Option<String> {
+    let blocks = match &envelope.message.as_ref()?.content {
+        MessageContent::Text(s) => return Some(s.clone()),
+        MessageContent::Blocks(blocks) => blocks,
+    };
+    let detail = match blocks.get(block)? {
+        ContentBlock::Text { text } => text.clone(),
+        ContentBlock::ToolUse { name, input } => {
+            let name = name.as_deref().unwrap_or("unknown");
+            match input {
+                Some(input) => format!("{}\n\n{}", name, pretty_json(input)),
+                None => name.to_string(),
+            }
+        }
+        ContentBlock::ToolResult { content } => match content {
+            Some(Value::String(s)) => s.clone(),
+            Some(Value::Array(arr)) => arr
+                .iter()
+                .map(|v| match v.get("text").and_then(|t| t.as_str()) {
+                    Some(text) => text.to_string(),
+                    None => pretty_json(v),
+                })
+                .collect::<Vec<_>>()
+                .join("\n\n"),
+            Some(other) => pretty_json(other),
+            None => "[result]".to_string(),
+        },
+        ContentBlock::Other => return None,
+    };
+    Some(detail)
+}
+
+fn pretty_json(value: &Value) -> String {
+    serde_json::to_string_pretty(value).unwrap_or_else(|_| value.to_string())
+}
+
 fn extract_message_text(envelope: &TranscriptEnvelope) -> String {
     if let Some(ref msg) = envelope.message {
         match &msg.content {
@@ -239,3 +478,61 @@ fn extract_message_text(envelope: &TranscriptEnvelope) -> String {
     }
     String::new()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn annotates_assistant_turns() {
+        let prompt: TranscriptEnvelope = serde_json::from_str(
+            r#"{"type":"user","timestamp":"2026-01-05T10:00:00Z","message":{"role":"user","content":"hi"}}"#,
+        )
+        .unwrap();
+        let reply: TranscriptEnvelope = serde_json::from_str(
+            r#"{"type":"assistant","timestamp":"2026-01-05T10:00:12Z","message":{"role":"assistant",
+                "content":[{"type":"text","text":"hello"}],
+                "usage":{"input_tokens":200,"cache_creation_input_tokens":1000,
+                         "cache_read_input_tokens":90000,"output_tokens":2000}}}"#,
+        )
+        .unwrap();
+
+        assert!(parse_envelope(&prompt)[0].usage.is_none());
+        let mut items = parse_envelope(&reply);
+        fill_elapsed(&mut items, prompt.timestamp);
+        let usage = items[0].usage.unwrap();
+        assert_eq!(usage.tokens, Some(3200));
+        assert_eq!(usage.summary(), "3.2k tok, 12s");
+
+        let timed = TurnUsage {
+            tokens: None,
+            elapsed_ms: Some(65_000),
+        };
+        assert_eq!(timed.summary(), "1m05s");
+    }
+
+    #[test]
+    fn detects_plan_proposals() {
+        let envelope: TranscriptEnvelope = serde_json::from_str(
+            r##"{"type":"assistant","message":{"role":"assistant","content":[
+                {"type":"tool_use","name":"ExitPlanMode","input":{"plan":"# Plan\n1. Do it"}},
+                {"type":"tool_use","name":"Read","input":{"file_path":"a.rs"}}]}}"##,
+        )
+        .unwrap();
+        let items = parse_envelope(&envelope);
+        assert_eq!(items[0].kind, TranscriptItemKind::Plan);
+        assert_eq!(items[0].text, "# Plan\n1. Do it");
+        assert_eq!(items[1].kind, TranscriptItemKind::ToolUse);
+    }
+
+    #[test]
+    fn wraps_at_spaces_and_newlines() {
+        assert_eq!(
+            wrap_text("the quick brown fox", 10),
+            vec!["the quick", "brown fox"]
+        );
+        assert_eq!(wrap_text("abcdefghij", 4), vec!["abcd", "efgh", "ij"]);
+        assert_eq!(wrap_text("one\n\ntwo", 10), vec!["one", "", "two"]);
+        assert_eq!(wrap_text("", 10), vec![""]);
+    }
+}
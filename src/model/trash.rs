@@ -0,0 +1,44 @@
+use std::path::PathBuf;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// The kind of item a trash entry holds, which decides the tab reloaded
+/// when it is restored.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TrashSource {
+    Session,
+    Plan,
+    Todo,
+    Command,
+    Team,
+    /// A file or folder from the Git tab's file browser.
+    File,
+}
+
+impl TrashSource {
+    pub fn label(&self) -> &'static str {
+        match self {
+            TrashSource::Session => "Session",
+            TrashSource::Plan => "Plan",
+            TrashSource::Todo => "Todo",
+            TrashSource::Command => "Command",
+            TrashSource::Team => "Team",
+            TrashSource::File => "File",
+        }
+    }
+}
+
+/// A deleted file or directory kept in the project's trash.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TrashEntry {
+    /// Directory under the trash that holds the item.
+    pub id: String,
+    /// Name shown in the trash view, as it was shown in its tab.
+    pub name: String,
+    pub source: TrashSource,
+    /// Where the item is restored to.
+    pub original: PathBuf,
+    pub deleted_at: DateTime<Utc>,
+}
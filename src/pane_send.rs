@@ -1,23 +1,251 @@
+use std::path::Path;
 use std::process::Command;
 use std::sync::mpsc;
 use std::thread;
 use std::time::Duration;
 
+use serde_json::Value;
+
+use crate::config::LaunchProfile;
 use crate::event::AppEvent;
 
-/// Send text to the Claude Code pane asynchronously.
+/// The Associate's own pane in a pane registry.
+pub const ASSOC_PANE: &str = "assoc";
+/// The Claude Code pane in a pane registry.
+pub const CLAUDE_PANE: &str = "claude";
+
+/// The terminal whose panes `assoc launch` opens and pane send types into.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TerminalBackend {
+    WindowsTerminal,
+    Zellij,
+    WezTerm,
+}
+
+impl TerminalBackend {
+    /// The terminal assoc runs in, from the variables Zellij and WezTerm set
+    /// in their panes; Windows Terminal otherwise. Zellij is checked first
+    /// since a Zellij session inside WezTerm inherits `WEZTERM_PANE`.
+    pub fn detect() -> Self {
+        Self::from_env(|name| std::env::var_os(name).is_some())
+    }
+
+    fn from_env(is_set: impl Fn(&str) -> bool) -> Self {
+        if is_set("ZELLIJ") {
+            TerminalBackend::Zellij
+        } else if is_set("WEZTERM_PANE") {
+            TerminalBackend::WezTerm
+        } else {
+            TerminalBackend::WindowsTerminal
+        }
+    }
+
+    /// A `pane.backend` value other than "auto".
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "wt" | "windows-terminal" => Some(TerminalBackend::WindowsTerminal),
+            "zellij" => Some(TerminalBackend::Zellij),
+            "wezterm" => Some(TerminalBackend::WezTerm),
+            _ => None,
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            TerminalBackend::WindowsTerminal => "Windows Terminal",
+            TerminalBackend::Zellij => "Zellij",
+            TerminalBackend::WezTerm => "WezTerm",
+        }
+    }
+
+    /// The executable the backend is driven through.
+    pub fn program(&self) -> &'static str {
+        match self {
+            TerminalBackend::WindowsTerminal => "wt.exe",
+            TerminalBackend::Zellij => "zellij",
+            TerminalBackend::WezTerm => "wezterm",
+        }
+    }
+
+    /// A command that opens a new pane in the current tab running `command`
+    /// in `cwd`.
+    pub fn new_pane_command(&self, cwd: &str, command: &[&str]) -> Command {
+        let mut cmd = Command::new(self.program());
+        match self {
+            TerminalBackend::WindowsTerminal => cmd.args(["split-pane", "-d", cwd]),
+            TerminalBackend::Zellij => cmd.args(["action", "new-pane", "--cwd", cwd]),
+            TerminalBackend::WezTerm => cmd.args(["cli", "split-pane", "--cwd", cwd]),
+        };
+        cmd.arg("--").args(command);
+        cmd
+    }
+}
+
+/// How to reach a pane from The Associate pane and get back.
+#[derive(Debug, Clone, PartialEq)]
+pub enum PaneRoute {
+    /// `move-focus` in a direction, and the opposite way back.
+    Direction(String),
+    /// `focus-pane` by index, and back to The Associate pane's index.
+    Index { target: u32, home: u32 },
+}
+
+impl PaneRoute {
+    fn there(&self) -> Vec<String> {
+        match self {
+            PaneRoute::Direction(dir) => vec!["move-focus".to_string(), dir.clone()],
+            PaneRoute::Index { target, .. } => {
+                vec![
+                    "focus-pane".to_string(),
+                    "-t".to_string(),
+                    target.to_string(),
+                ]
+            }
+        }
+    }
+
+    fn back(&self) -> Vec<String> {
+        match self {
+            PaneRoute::Direction(dir) => {
+                vec![
+                    "move-focus".to_string(),
+                    opposite_direction(dir).to_string(),
+                ]
+            }
+            PaneRoute::Index { home, .. } => {
+                vec!["focus-pane".to_string(), "-t".to_string(), home.to_string()]
+            }
+        }
+    }
+}
+
+/// The named panes of a tab and their indices, in creation order, as
+/// passed with `--panes assoc=0,claude=1,dev=2`.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PaneRegistry {
+    panes: Vec<(String, u32)>,
+}
+
+impl PaneRegistry {
+    pub fn parse(spec: &str) -> Result<Self, String> {
+        let mut registry = PaneRegistry::default();
+        for entry in spec.split(',').map(str::trim).filter(|e| !e.is_empty()) {
+            let Some((name, index)) = entry.split_once('=') else {
+                return Err(format!("expected NAME=INDEX, got {:?}", entry));
+            };
+            let index = index
+                .trim()
+                .parse()
+                .map_err(|_| format!("pane index must be a number, got {:?}", index))?;
+            let name = name.trim();
+            if !is_pane_name(name) {
+                return Err(format!("invalid pane name {:?}", name));
+            }
+            if registry.index(name).is_some() {
+                return Err(format!("pane {:?} is listed twice", name));
+            }
+            registry.push(name, index);
+        }
+        if registry.index(ASSOC_PANE).is_none() {
+            return Err(format!("the {:?} pane is missing", ASSOC_PANE));
+        }
+        Ok(registry)
+    }
+
+    pub fn push(&mut self, name: &str, index: u32) {
+        self.panes.push((name.to_string(), index));
+    }
+
+    pub fn index(&self, name: &str) -> Option<u32> {
+        self.panes
+            .iter()
+            .find(|(n, _)| n == name)
+            .map(|(_, index)| *index)
+    }
+
+    /// Panes the send bar can type into: all but The Associate's own.
+    pub fn targets(&self) -> Vec<&str> {
+        self.panes
+            .iter()
+            .map(|(name, _)| name.as_str())
+            .filter(|name| *name != ASSOC_PANE)
+            .collect()
+    }
+
+    pub fn route(&self, name: &str) -> Option<PaneRoute> {
+        Some(PaneRoute::Index {
+            target: self.index(name)?,
+            home: self.index(ASSOC_PANE)?,
+        })
+    }
+}
+
+impl std::fmt::Display for PaneRegistry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let entries: Vec<String> = self
+            .panes
+            .iter()
+            .map(|(name, index)| format!("{}={}", name, index))
+            .collect();
+        f.write_str(&entries.join(","))
+    }
+}
+
+/// Letters, digits, `-` and `_`.
+pub fn is_pane_name(name: &str) -> bool {
+    !name.is_empty()
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+}
+
+/// Send text to a pane asynchronously and press Enter.
 ///
-/// This uses a three-step approach:
+/// In Windows Terminal this uses a three-step approach:
 /// 1. Copy text to the clipboard via PowerShell `Set-Clipboard`
-/// 2. Focus the Claude Code pane via `wt.exe -w 0 move-focus <direction>`
+/// 2. Focus the pane via `wt.exe -w 0 move-focus` or `focus-pane`
 /// 3. Paste (Ctrl+V) and press Enter via PowerShell `SendKeys`
 /// 4. Refocus back to The Associate pane
 ///
+/// Zellij moves focus the same way and types with `write-chars`; WezTerm
+/// pastes into the pane by id with `send-text`, without moving focus.
+///
 /// The result is sent back through the event channel.
-pub fn send_to_claude_pane(text: String, direction: &str, tx: mpsc::Sender<AppEvent>) {
-    let dir = direction.to_string();
+pub fn send_to_pane(
+    backend: TerminalBackend,
+    text: String,
+    route: PaneRoute,
+    tx: mpsc::Sender<AppEvent>,
+) {
+    thread::spawn(move || {
+        let result = match backend {
+            TerminalBackend::WindowsTerminal => do_send(&text, &route),
+            TerminalBackend::Zellij => zellij_send(&text, &route, true),
+            TerminalBackend::WezTerm => wezterm_send(&text, &route, true),
+        };
+        let msg = match result {
+            Ok(()) => None,
+            Err(e) => Some(e.to_string()),
+        };
+        let _ = tx.send(AppEvent::PaneSendComplete(msg));
+    });
+}
+
+/// Type `keys` into a pane asynchronously, without going through the
+/// clipboard or pressing Enter. Used to answer Claude Code's numbered
+/// prompts, such as the plan approval menu.
+pub fn send_keys_to_pane(
+    backend: TerminalBackend,
+    keys: String,
+    route: PaneRoute,
+    tx: mpsc::Sender<AppEvent>,
+) {
     thread::spawn(move || {
-        let result = do_send(&text, &dir);
+        let result = match backend {
+            TerminalBackend::WindowsTerminal => do_send_keys(&keys, &route),
+            TerminalBackend::Zellij => zellij_send(&keys, &route, false),
+            TerminalBackend::WezTerm => wezterm_send(&keys, &route, false),
+        };
         let msg = match result {
             Ok(()) => None,
             Err(e) => Some(e.to_string()),
@@ -26,6 +254,73 @@ pub fn send_to_claude_pane(text: String, direction: &str, tx: mpsc::Sender<AppEv
     });
 }
 
+/// Move the split between the two panes by `steps` resize steps, widening
+/// The Associate pane when `widen` is set and the Claude Code pane
+/// otherwise. `direction` points at the Claude Code pane.
+///
+/// wt.exe has no resize subcommand, so this sends Windows Terminal's default
+/// `resizePane` binding (Alt+Shift+Arrow) via PowerShell `SendKeys`. It must
+/// run while The Associate pane has focus. Zellij resizes the focused pane
+/// with `resize`, and WezTerm this pane with `adjust-pane-size`, by `steps`
+/// cells.
+pub fn resize_panes(
+    backend: TerminalBackend,
+    widen: bool,
+    direction: &str,
+    steps: u32,
+    tx: mpsc::Sender<AppEvent>,
+) {
+    // Moving the split toward the Claude pane widens this one
+    let toward = if widen {
+        direction
+    } else {
+        opposite_direction(direction)
+    };
+    if backend != TerminalBackend::WindowsTerminal {
+        let (zellij_resize, direction) = (
+            if widen { "increase" } else { "decrease" },
+            direction.to_string(),
+        );
+        let toward = capitalized(toward);
+        thread::spawn(move || {
+            let result = match backend {
+                TerminalBackend::Zellij => (0..steps).try_for_each(|_| {
+                    zellij_action(&[
+                        "resize".to_string(),
+                        zellij_resize.to_string(),
+                        direction.clone(),
+                    ])
+                }),
+                _ => wezterm_cli(&["adjust-pane-size", "--amount", &steps.to_string(), &toward])
+                    .map(|_| ()),
+            };
+            let _ = tx.send(AppEvent::PaneResizeComplete(
+                result.err().map(|e| e.to_string()),
+            ));
+        });
+        return;
+    }
+    let arrow = format!("%+{{{}}}", toward.to_uppercase());
+    thread::spawn(move || {
+        let ps_resize = format!(
+            "Add-Type -AssemblyName System.Windows.Forms; \
+             [System.Windows.Forms.SendKeys]::SendWait('{}')",
+            arrow.repeat(steps as usize)
+        );
+        let result = Command::new("powershell")
+            .args(["-NoProfile", "-NonInteractive", "-Command", &ps_resize])
+            .stdout(std::process::Stdio::null())
+            .stderr(std::process::Stdio::null())
+            .status();
+        let msg = match result {
+            Ok(s) if s.success() => None,
+            Ok(_) => Some("SendKeys failed".to_string()),
+            Err(e) => Some(format!("SendKeys error: {}", e)),
+        };
+        let _ = tx.send(AppEvent::PaneResizeComplete(msg));
+    });
+}
+
 fn opposite_direction(direction: &str) -> &str {
     match direction {
         "right" => "left",
@@ -36,7 +331,68 @@ fn opposite_direction(direction: &str) -> &str {
     }
 }
 
-fn do_send(text: &str, direction: &str) -> anyhow::Result<()> {
+/// "right" as WezTerm spells directions, "Right".
+fn capitalized(direction: &str) -> String {
+    let mut chars = direction.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+/// Escape SendKeys metacharacters (and quotes for the PowerShell string) so
+/// `keys` is typed literally.
+fn escape_send_keys(keys: &str) -> String {
+    keys.chars()
+        .map(|c| match c {
+            '+' | '^' | '%' | '~' | '(' | ')' | '{' | '}' | '[' | ']' => format!("{{{}}}", c),
+            '\'' => "''".to_string(),
+            _ => c.to_string(),
+        })
+        .collect()
+}
+
+/// Run `wt.exe -w 0` with a `move-focus` or `focus-pane` action.
+fn focus(action: &[String]) -> anyhow::Result<()> {
+    let status = Command::new("wt.exe")
+        .args(["-w", "0"])
+        .args(action)
+        .stdout(std::process::Stdio::null())
+        .stderr(std::process::Stdio::null())
+        .status()?;
+    if !status.success() {
+        anyhow::bail!("Failed to focus pane ({})", action.join(" "));
+    }
+    Ok(())
+}
+
+fn do_send_keys(keys: &str, route: &PaneRoute) -> anyhow::Result<()> {
+    focus(&route.there())?;
+    thread::sleep(Duration::from_millis(300));
+
+    let ps_send = format!(
+        "Add-Type -AssemblyName System.Windows.Forms; \
+         [System.Windows.Forms.SendKeys]::SendWait('{}')",
+        escape_send_keys(keys)
+    );
+    let send_result = Command::new("powershell")
+        .args(["-NoProfile", "-NonInteractive", "-Command", &ps_send])
+        .stdout(std::process::Stdio::null())
+        .stderr(std::process::Stdio::null())
+        .status();
+
+    // Always refocus back, even if SendKeys failed
+    thread::sleep(Duration::from_millis(300));
+    let _ = focus(&route.back());
+
+    match send_result {
+        Ok(s) if s.success() => Ok(()),
+        Ok(_) => anyhow::bail!("SendKeys failed"),
+        Err(e) => anyhow::bail!("SendKeys error: {}", e),
+    }
+}
+
+fn do_send(text: &str, route: &PaneRoute) -> anyhow::Result<()> {
     // Step 1: Copy text to clipboard via PowerShell
     let escaped = text.replace('\'', "''");
     let ps_clip = format!("Set-Clipboard -Value '{}'", escaped);
@@ -49,15 +405,8 @@ fn do_send(text: &str, direction: &str) -> anyhow::Result<()> {
         anyhow::bail!("Failed to set clipboard");
     }
 
-    // Step 2: Focus the Claude Code pane
-    let status = Command::new("wt.exe")
-        .args(["-w", "0", "move-focus", direction])
-        .stdout(std::process::Stdio::null())
-        .stderr(std::process::Stdio::null())
-        .status()?;
-    if !status.success() {
-        anyhow::bail!("Failed to focus Claude pane (move-focus {})", direction);
-    }
+    // Step 2: Focus the target pane
+    focus(&route.there())?;
 
     // Step 3: Wait for focus change to take effect
     thread::sleep(Duration::from_millis(300));
@@ -77,12 +426,7 @@ fn do_send(text: &str, direction: &str) -> anyhow::Result<()> {
 
     // Step 5: Always refocus back, even if SendKeys failed
     thread::sleep(Duration::from_millis(300));
-    let back = opposite_direction(direction);
-    let _ = Command::new("wt.exe")
-        .args(["-w", "0", "move-focus", back])
-        .stdout(std::process::Stdio::null())
-        .stderr(std::process::Stdio::null())
-        .status();
+    let _ = focus(&route.back());
 
     match send_result {
         Ok(s) if s.success() => Ok(()),
@@ -90,3 +434,370 @@ fn do_send(text: &str, direction: &str) -> anyhow::Result<()> {
         Err(e) => anyhow::bail!("SendKeys error: {}", e),
     }
 }
+
+/// Run `zellij action` with `args`, which act on the focused pane.
+fn zellij_action(args: &[String]) -> anyhow::Result<()> {
+    let status = Command::new("zellij")
+        .arg("action")
+        .args(args)
+        .stdout(std::process::Stdio::null())
+        .stderr(std::process::Stdio::null())
+        .status()
+        .map_err(|e| anyhow::anyhow!("Failed to run zellij: {}", e))?;
+    if !status.success() {
+        anyhow::bail!("zellij action {} failed", args.join(" "));
+    }
+    Ok(())
+}
+
+/// Zellij can only type into the focused pane, so focus moves there by
+/// direction and back, as in Windows Terminal.
+fn zellij_send(text: &str, route: &PaneRoute, enter: bool) -> anyhow::Result<()> {
+    if !matches!(route, PaneRoute::Direction(_)) {
+        anyhow::bail!("Zellij panes can only be reached by direction (pane.direction)");
+    }
+    zellij_action(&route.there())?;
+    let mut result = zellij_action(&["write-chars".to_string(), text.to_string()]);
+    if enter && result.is_ok() {
+        result = zellij_action(&["write".to_string(), "13".to_string()]);
+    }
+    // Always refocus back, even if typing failed
+    let _ = zellij_action(&route.back());
+    result
+}
+
+/// Run `wezterm cli` with `args` and return what it printed.
+fn wezterm_cli(args: &[&str]) -> anyhow::Result<String> {
+    let output = Command::new("wezterm")
+        .arg("cli")
+        .args(args)
+        .stderr(std::process::Stdio::null())
+        .output()
+        .map_err(|e| anyhow::anyhow!("Failed to run wezterm: {}", e))?;
+    if !output.status.success() {
+        anyhow::bail!("wezterm cli {} failed", args[0]);
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+/// WezTerm's id for the pane at `route`: its neighbour in a direction, or
+/// the registry's index among this tab's panes.
+fn wezterm_pane(route: &PaneRoute) -> anyhow::Result<String> {
+    match route {
+        PaneRoute::Direction(dir) => {
+            let id = wezterm_cli(&["get-pane-direction", &capitalized(dir)])?;
+            if id.is_empty() {
+                anyhow::bail!("No pane to the {}", dir);
+            }
+            Ok(id)
+        }
+        PaneRoute::Index { target, .. } => {
+            let own = std::env::var("WEZTERM_PANE")
+                .ok()
+                .and_then(|id| id.parse().ok())
+                .ok_or_else(|| anyhow::anyhow!("WEZTERM_PANE is not set"))?;
+            let list = wezterm_cli(&["list", "--format", "json"])?;
+            let panes: Value = serde_json::from_str(&list)?;
+            tab_pane(&panes, own, *target)
+                .map(|id| id.to_string())
+                .ok_or_else(|| anyhow::anyhow!("No pane {} in this tab", target))
+        }
+    }
+}
+
+/// The `index`th pane of the tab `own` is in, from `wezterm cli list`.
+/// Pane ids only grow, so sorting them gives the order panes opened in.
+fn tab_pane(panes: &Value, own: u64, index: u32) -> Option<u64> {
+    let panes = panes.as_array()?;
+    let field = |pane: &Value, key: &str| pane.get(key).and_then(Value::as_u64);
+    let tab = panes
+        .iter()
+        .find(|p| field(p, "pane_id") == Some(own))
+        .and_then(|p| field(p, "tab_id"))?;
+    let mut ids: Vec<u64> = panes
+        .iter()
+        .filter(|p| field(p, "tab_id") == Some(tab))
+        .filter_map(|p| field(p, "pane_id"))
+        .collect();
+    ids.sort_unstable();
+    ids.get(index as usize).copied()
+}
+
+/// WezTerm types into a pane by id without moving focus. Text is pasted
+/// and submitted with Enter; keys are typed as they are.
+fn wezterm_send(text: &str, route: &PaneRoute, submit: bool) -> anyhow::Result<()> {
+    let pane = wezterm_pane(route)?;
+    if submit {
+        wezterm_cli(&["send-text", "--pane-id", &pane, text])?;
+        thread::sleep(Duration::from_millis(200));
+        wezterm_cli(&["send-text", "--pane-id", &pane, "--no-paste", "\r"])?;
+    } else {
+        wezterm_cli(&["send-text", "--pane-id", &pane, "--no-paste", text])?;
+    }
+    Ok(())
+}
+
+/// A Zellij layout for `assoc launch`: The Associate (`assoc`) and Claude
+/// Code (`claude`) side by side, Claude on the `claude_side` of the
+/// dashboard so pane send reaches it by direction, then the profile's panes
+/// splitting the panes they name. Each command is a program and its
+/// arguments.
+pub fn zellij_layout(
+    project_cwd: &Path,
+    assoc: &[String],
+    claude: &[String],
+    launch: &LaunchProfile,
+    claude_side: &str,
+) -> String {
+    let dir = project_cwd.to_string_lossy().to_string();
+    let mut panes = vec![
+        LayoutPane {
+            title: "The Associate".to_string(),
+            cwd: dir.clone(),
+            command: assoc.to_vec(),
+        },
+        LayoutPane {
+            title: "Claude Code".to_string(),
+            cwd: dir.clone(),
+            command: claude.to_vec(),
+        },
+    ];
+    let ratio = launch.claude_ratio.unwrap_or(0.5);
+    let beside = matches!(claude_side, "right" | "left");
+    let mut root = if matches!(claude_side, "right" | "down") {
+        LayoutNode::split(LayoutNode::Pane(0), LayoutNode::Pane(1), beside, ratio)
+    } else {
+        LayoutNode::split(
+            LayoutNode::Pane(1),
+            LayoutNode::Pane(0),
+            beside,
+            1.0 - ratio,
+        )
+    };
+
+    let mut names = vec![ASSOC_PANE.to_string(), CLAUDE_PANE.to_string()];
+    for pane in &launch.panes {
+        let from = pane.from.as_deref().unwrap_or(CLAUDE_PANE);
+        let from = names.iter().position(|n| n == from).unwrap_or(1);
+        let cwd = match &pane.cwd {
+            Some(cwd) => project_cwd.join(cwd).to_string_lossy().to_string(),
+            None => dir.clone(),
+        };
+        let command = pane
+            .command
+            .as_deref()
+            .map(|c| c.split_whitespace().map(str::to_string).collect())
+            .unwrap_or_default();
+        root.split_pane(
+            from,
+            panes.len(),
+            pane.splits_right(),
+            pane.size.unwrap_or(0.5),
+        );
+        names.push(pane.name.clone().unwrap_or_default());
+        panes.push(LayoutPane {
+            title: pane.display_title().to_string(),
+            cwd,
+            command,
+        });
+    }
+
+    let mut kdl = String::from("layout {\n");
+    kdl.push_str(
+        "    pane size=1 borderless=true {\n        plugin location=\"zellij:tab-bar\"\n    }\n",
+    );
+    root.write_kdl(&panes, None, 1, &mut kdl);
+    kdl.push_str(
+        "    pane size=2 borderless=true {\n        plugin location=\"zellij:status-bar\"\n    }\n",
+    );
+    kdl.push_str("}\n");
+    kdl
+}
+
+struct LayoutPane {
+    title: String,
+    cwd: String,
+    /// Empty for the default shell.
+    command: Vec<String>,
+}
+
+/// A tree of splits whose leaves index into the layout's panes.
+enum LayoutNode {
+    Pane(usize),
+    Split {
+        /// Side by side rather than one above the other.
+        beside: bool,
+        /// Share of the split the second node takes.
+        size: f64,
+        first: Box<LayoutNode>,
+        second: Box<LayoutNode>,
+    },
+}
+
+impl LayoutNode {
+    fn split(first: LayoutNode, second: LayoutNode, beside: bool, size: f64) -> Self {
+        LayoutNode::Split {
+            beside,
+            size,
+            first: Box::new(first),
+            second: Box::new(second),
+        }
+    }
+
+    /// Put pane `new` below or beside pane `target`, taking `size` of it.
+    fn split_pane(&mut self, target: usize, new: usize, beside: bool, size: f64) -> bool {
+        match self {
+            LayoutNode::Pane(pane) if *pane == target => {
+                let old = std::mem::replace(self, LayoutNode::Pane(new));
+                *self = LayoutNode::split(old, LayoutNode::Pane(new), beside, size);
+                true
+            }
+            LayoutNode::Pane(_) => false,
+            LayoutNode::Split { first, second, .. } => {
+                first.split_pane(target, new, beside, size)
+                    || second.split_pane(target, new, beside, size)
+            }
+        }
+    }
+
+    fn write_kdl(&self, panes: &[LayoutPane], size: Option<f64>, depth: usize, out: &mut String) {
+        let indent = "    ".repeat(depth);
+        let size = size
+            .map(|s| format!(" size=\"{}%\"", (s * 100.0).round()))
+            .unwrap_or_default();
+        match self {
+            LayoutNode::Pane(i) => {
+                let pane = &panes[*i];
+                out.push_str(&format!(
+                    "{}pane{} name={} cwd={}",
+                    indent,
+                    size,
+                    kdl_string(&pane.title),
+                    kdl_string(&pane.cwd)
+                ));
+                // Claude Code starts focused
+                if *i == 1 {
+                    out.push_str(" focus=true");
+                }
+                match pane.command.split_first() {
+                    Some((program, args)) => {
+                        out.push_str(&format!(" command={}", kdl_string(program)));
+                        if args.is_empty() {
+                            out.push('\n');
+                        } else {
+                            let args: Vec<String> = args.iter().map(|a| kdl_string(a)).collect();
+                            out.push_str(&format!(
+                                " {{\n{}    args {}\n{}}}\n",
+                                indent,
+                                args.join(" "),
+                                indent
+                            ));
+                        }
+                    }
+                    None => out.push('\n'),
+                }
+            }
+            LayoutNode::Split {
+                beside,
+                size: second_size,
+                first,
+                second,
+            } => {
+                let direction = if *beside { "vertical" } else { "horizontal" };
+                out.push_str(&format!(
+                    "{}pane{} split_direction=\"{}\" {{\n",
+                    indent, size, direction
+                ));
+                first.write_kdl(panes, Some(1.0 - second_size), depth + 1, out);
+                second.write_kdl(panes, Some(*second_size), depth + 1, out);
+                out.push_str(&format!("{}}}\n", indent));
+            }
+        }
+    }
+}
+
+/// A quoted KDL string.
+fn kdl_string(text: &str) -> String {
+    format!("\"{}\"", text.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_and_routes_a_pane_registry() {
+        let registry = PaneRegistry::parse("assoc=0, claude=1,dev-server=2").unwrap();
+        assert_eq!(registry.targets(), ["claude", "dev-server"]);
+        assert_eq!(
+            registry.route("dev-server"),
+            Some(PaneRoute::Index { target: 2, home: 0 })
+        );
+        assert_eq!(registry.route("shell"), None);
+        assert_eq!(registry.to_string(), "assoc=0,claude=1,dev-server=2");
+
+        assert!(PaneRegistry::parse("claude=1").is_err());
+        assert!(PaneRegistry::parse("assoc=0,claude").is_err());
+        assert!(PaneRegistry::parse("assoc=0,a b=1").is_err());
+        assert!(PaneRegistry::parse("assoc=0,assoc=1").is_err());
+
+        let back = PaneRoute::Direction("right".to_string()).back();
+        assert_eq!(back, ["move-focus", "left"]);
+    }
+
+    #[test]
+    fn detects_backends_and_lays_out_panes() {
+        let env = |set: &'static [&'static str]| move |name: &str| set.contains(&name);
+        assert_eq!(
+            TerminalBackend::from_env(env(&["WEZTERM_PANE", "ZELLIJ"])),
+            TerminalBackend::Zellij
+        );
+        assert_eq!(
+            TerminalBackend::from_env(env(&["WEZTERM_PANE"])),
+            TerminalBackend::WezTerm
+        );
+        assert_eq!(
+            TerminalBackend::from_env(env(&[])),
+            TerminalBackend::WindowsTerminal
+        );
+
+        let panes: Value = serde_json::from_str(
+            r#"[{"tab_id":1,"pane_id":7},{"tab_id":0,"pane_id":2},{"tab_id":1,"pane_id":4}]"#,
+        )
+        .unwrap();
+        assert_eq!(tab_pane(&panes, 7, 0), Some(4));
+        assert_eq!(tab_pane(&panes, 7, 2), None);
+
+        let launch = LaunchProfile {
+            claude_ratio: Some(0.6),
+            panes: vec![crate::config::LaunchPane {
+                name: Some("server".to_string()),
+                title: None,
+                command: Some("npm run dev".to_string()),
+                from: Some("assoc".to_string()),
+                split: None,
+                size: Some(0.3),
+                cwd: None,
+            }],
+            ..LaunchProfile::default()
+        };
+        let kdl = zellij_layout(
+            Path::new("/work"),
+            &["assoc".to_string()],
+            &["claude".to_string(), "--continue".to_string()],
+            &launch,
+            "left",
+        );
+        let expected = [
+            "    pane split_direction=\"vertical\" {",
+            "        pane size=\"60%\" name=\"Claude Code\" cwd=\"/work\" focus=true command=\"claude\" {",
+            "            args \"--continue\"",
+            "        pane size=\"40%\" split_direction=\"horizontal\" {",
+            "            pane size=\"70%\" name=\"The Associate\" cwd=\"/work\" command=\"assoc\"",
+            "            pane size=\"30%\" name=\"server\" cwd=\"/work\" command=\"npm\" {",
+        ];
+        for line in expected {
+            assert!(kdl.lines().any(|l| l == line), "{}\n{}", line, kdl);
+        }
+    }
+}
@@ -0,0 +1,75 @@
+//! Shut down cleanly when the terminal goes away.
+//!
+//! SIGTERM, SIGHUP (terminal closed) and SIGINT on Unix, and the console
+//! close, logoff and shutdown events on Windows, ask the main loop to quit
+//! through `App::shutdown`, which stops owned processes and keeps unsaved
+//! edits. Windows ends the process as soon as the handler returns, so the
+//! handler waits (up to the few seconds Windows allows) for `finished`.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+#[cfg(windows)]
+use std::time::{Duration, Instant};
+
+static REQUESTED: AtomicBool = AtomicBool::new(false);
+#[cfg(windows)]
+static FINISHED: AtomicBool = AtomicBool::new(false);
+
+/// How long the Windows handler holds the process open for cleanup; the
+/// system kills it about five seconds after a console close.
+#[cfg(windows)]
+const WINDOWS_CLOSE_WAIT: Duration = Duration::from_millis(4500);
+
+/// Install the signal / console control handlers.
+pub fn install() {
+    #[cfg(unix)]
+    unsafe {
+        let handler = on_signal as extern "C" fn(libc::c_int) as libc::sighandler_t;
+        for signal in [libc::SIGTERM, libc::SIGHUP, libc::SIGINT] {
+            libc::signal(signal, handler);
+        }
+    }
+
+    #[cfg(windows)]
+    unsafe {
+        windows_sys::Win32::System::Console::SetConsoleCtrlHandler(Some(on_console_event), 1);
+    }
+}
+
+/// Whether a signal asked the app to quit.
+pub fn requested() -> bool {
+    REQUESTED.load(Ordering::SeqCst)
+}
+
+/// Cleanup is done; let a waiting console handler return.
+pub fn finished() {
+    #[cfg(windows)]
+    FINISHED.store(true, Ordering::SeqCst);
+}
+
+#[cfg(unix)]
+extern "C" fn on_signal(_signal: libc::c_int) {
+    REQUESTED.store(true, Ordering::SeqCst);
+}
+
+#[cfg(windows)]
+unsafe extern "system" fn on_console_event(event: u32) -> i32 {
+    use windows_sys::Win32::System::Console::{
+        CTRL_BREAK_EVENT, CTRL_CLOSE_EVENT, CTRL_C_EVENT, CTRL_LOGOFF_EVENT, CTRL_SHUTDOWN_EVENT,
+    };
+    match event {
+        CTRL_C_EVENT | CTRL_BREAK_EVENT => {
+            REQUESTED.store(true, Ordering::SeqCst);
+            1
+        }
+        CTRL_CLOSE_EVENT | CTRL_LOGOFF_EVENT | CTRL_SHUTDOWN_EVENT => {
+            REQUESTED.store(true, Ordering::SeqCst);
+            // Runs on its own thread; returning ends the process
+            let deadline = Instant::now() + WINDOWS_CLOSE_WAIT;
+            while !FINISHED.load(Ordering::SeqCst) && Instant::now() < deadline {
+                std::thread::sleep(Duration::from_millis(50));
+            }
+            1
+        }
+        _ => 0,
+    }
+}
@@ -0,0 +1,73 @@
+use ratatui::layout::Rect;
+use ratatui::style::Modifier;
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph};
+use ratatui::Frame;
+
+use super::theme;
+use super::util::truncate_chars;
+use crate::app::App;
+
+/// Draw the activity feed as a dropdown under the tab bar's right edge.
+pub fn draw_activity_feed(f: &mut Frame, area: Rect, app: &App) {
+    let width = 72u16.min(area.width.saturating_sub(2));
+    let height = (app.activity.len().max(1) as u16 + 2).min(area.height.saturating_sub(2));
+    let popup_area = Rect {
+        x: area.x + area.width.saturating_sub(width + 1),
+        y: area.y + 1,
+        width,
+        height,
+    };
+    f.render_widget(Clear, popup_area);
+
+    let block = Block::default()
+        .title(" Activity (Enter jump, Esc close) ")
+        .borders(Borders::ALL)
+        .border_style(theme::PROMPT_MODAL_BORDER);
+
+    if app.activity.is_empty() {
+        let p = Paragraph::new(Span::styled(
+            "No changes since the first poll",
+            theme::EMPTY_STATE,
+        ))
+        .block(block);
+        f.render_widget(p, popup_area);
+        return;
+    }
+
+    // Room left for the title after the time, source and id columns
+    let title_width = (width as usize).saturating_sub(2 + 6 + 16 + 30);
+    let items: Vec<ListItem> = app
+        .activity
+        .iter()
+        .map(|item| {
+            let id = match item.item_id.parse::<u64>() {
+                Ok(number) => format!("#{}", number),
+                Err(_) => item.item_id.clone(),
+            };
+            let mut summary_style = theme::ACTIVITY_SUMMARY;
+            if !item.read {
+                summary_style = summary_style.add_modifier(Modifier::BOLD);
+            }
+            ListItem::new(Line::from(vec![
+                Span::styled(item.at.format("%H:%M ").to_string(), theme::ACTIVITY_TIME),
+                Span::styled(
+                    format!("{:<16}", format!("{} {}", item.source.label(), id)),
+                    theme::ACTIVITY_SOURCE,
+                ),
+                Span::styled(
+                    format!("{:<30}", truncate_chars(&item.summary, 29)),
+                    summary_style,
+                ),
+                Span::raw(truncate_chars(&item.title, title_width)),
+            ]))
+        })
+        .collect();
+
+    let list = List::new(items)
+        .block(block)
+        .highlight_style(theme::LIST_SELECTED);
+    let mut state = ListState::default();
+    state.select(Some(app.activity_index));
+    f.render_stateful_widget(list, popup_area, &mut state);
+}
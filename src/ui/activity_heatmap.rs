@@ -0,0 +1,141 @@
+use chrono::{Datelike, Days, Local, NaiveDate};
+use ratatui::layout::{Constraint, Direction, Layout, Rect};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::Paragraph;
+use ratatui::Frame;
+
+use super::theme;
+use crate::app::App;
+use crate::data::activity_calendar::{calendar_start, CALENDAR_WEEKS};
+use crate::model::session::ActivityCalendar;
+
+const CELL: &str = "■ ";
+/// Width of the weekday labels left of the grid.
+const GUTTER: usize = 4;
+/// Summary, month labels and one row per weekday, then a blank line.
+const HEIGHT: u16 = 10;
+const WEEKDAY_LABELS: [&str; 7] = ["", "Mon", "", "Wed", "", "Fri", ""];
+
+/// Draw a GitHub-style heatmap of messages per day across the project's
+/// sessions at the top of `area` and return the space left below it. The
+/// most recent weeks that fit the width are shown; nothing is drawn when
+/// the area is too small to leave room below.
+pub fn draw_activity_heatmap(f: &mut Frame, area: Rect, app: &App) -> Rect {
+    let max_weeks = (area.width as usize).saturating_sub(GUTTER) / CELL.chars().count();
+    let weeks = max_weeks.min(CALENDAR_WEEKS as usize);
+    if weeks < 4 || area.height < HEIGHT + 4 {
+        return area;
+    }
+
+    let Some(calendar) = &app.activity_calendar else {
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(2), Constraint::Min(0)])
+            .split(area);
+        let p = Paragraph::new("Scanning activity...").style(theme::EMPTY_STATE);
+        f.render_widget(p, chunks[0]);
+        return chunks[1];
+    };
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(HEIGHT), Constraint::Min(0)])
+        .split(area);
+    let today = Local::now().date_naive();
+    let start = calendar_start(today) + Days::new(((CALENDAR_WEEKS as usize - weeks) * 7) as u64);
+    f.render_widget(
+        Paragraph::new(heatmap_lines(calendar, start, weeks, today)),
+        chunks[0],
+    );
+    chunks[1]
+}
+
+fn heatmap_lines(
+    calendar: &ActivityCalendar,
+    start: NaiveDate,
+    weeks: usize,
+    today: NaiveDate,
+) -> Vec<Line<'static>> {
+    let days: Vec<_> = calendar.range(start, today).collect();
+    let total: usize = days.iter().map(|(_, day)| day.messages).sum();
+    let active = days.iter().filter(|(_, day)| day.messages > 0).count();
+    // Busiest day, the earliest of a tie
+    let busiest = days
+        .iter()
+        .rev()
+        .max_by_key(|(_, day)| day.messages)
+        .filter(|(_, day)| day.messages > 0);
+    let max = busiest.map_or(0, |(_, day)| day.messages);
+
+    let mut summary = vec![Span::styled(
+        format!(
+            "{} messages on {} days in the last {} weeks",
+            total, active, weeks
+        ),
+        theme::HEATMAP_LABEL,
+    )];
+    if let Some((date, day)) = busiest {
+        summary.push(Span::styled(
+            format!(
+                ", busiest {} ({} in {} sessions)",
+                date.format("%b %-d"),
+                day.messages,
+                day.sessions
+            ),
+            theme::HEATMAP_LABEL,
+        ));
+    }
+    summary.push(Span::styled("   Less ", theme::HEATMAP_LABEL));
+    for style in theme::HEATMAP_LEVELS {
+        summary.push(Span::styled("■", style));
+    }
+    summary.push(Span::styled(" More", theme::HEATMAP_LABEL));
+
+    let mut lines = vec![Line::from(summary), month_labels(start, weeks)];
+    for (weekday, label) in WEEKDAY_LABELS.iter().enumerate() {
+        let mut spans = vec![Span::styled(
+            format!("{:<width$}", label, width = GUTTER),
+            theme::HEATMAP_LABEL,
+        )];
+        for week in 0..weeks {
+            let date = start + Days::new((week * 7 + weekday) as u64);
+            if date > today {
+                break;
+            }
+            let level = heat_level(calendar.day(date).messages, max);
+            spans.push(Span::styled(CELL, theme::HEATMAP_LEVELS[level]));
+        }
+        lines.push(Line::from(spans));
+    }
+    lines
+}
+
+/// Month names above the first week of each month.
+fn month_labels(start: NaiveDate, weeks: usize) -> Line<'static> {
+    let cell_width = CELL.chars().count();
+    let mut row = " ".repeat(GUTTER + weeks * cell_width);
+    let mut free_from = 0;
+    let mut last_month = None;
+    for week in 0..weeks {
+        let date = start + Days::new((week * 7) as u64);
+        if last_month == Some(date.month()) {
+            continue;
+        }
+        last_month = Some(date.month());
+        let label = date.format("%b").to_string();
+        let at = GUTTER + week * cell_width;
+        if at >= free_from && at + label.len() <= row.len() {
+            row.replace_range(at..at + label.len(), &label);
+            free_from = at + label.len() + 1;
+        }
+    }
+    Line::from(Span::styled(row, theme::HEATMAP_LABEL))
+}
+
+/// Shade 0 for no messages, else 1-4 by quarter of the busiest day.
+fn heat_level(messages: usize, max: usize) -> usize {
+    if messages == 0 || max == 0 {
+        return 0;
+    }
+    (messages * 4).div_ceil(max).clamp(1, 4)
+}
@@ -0,0 +1,199 @@
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::{Line, Span};
+
+const ESC: char = '\x1b';
+const BEL: char = '\x07';
+
+/// Convert a line of terminal output into styled spans. SGR color and
+/// attribute codes become styles layered on `base`; cursor movement, screen
+/// clearing, title (OSC) sequences and other control characters are dropped.
+/// Text overwritten by a carriage return, as spinners and progress bars do,
+/// is dropped too.
+pub fn ansi_line(text: &str, base: Style) -> Line<'static> {
+    // A spinner redraws the line after each `\r`; only the last frame shows
+    let text = text
+        .split('\r')
+        .rev()
+        .find(|frame| !frame.is_empty())
+        .unwrap_or("");
+
+    let mut spans = Vec::new();
+    let mut style = base;
+    let mut current = String::new();
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            ESC => match chars.next() {
+                Some('[') => {
+                    let mut params = String::new();
+                    let mut final_byte = None;
+                    for c in chars.by_ref() {
+                        if ('\x40'..='\x7e').contains(&c) {
+                            final_byte = Some(c);
+                            break;
+                        }
+                        params.push(c);
+                    }
+                    if final_byte == Some('m') {
+                        let next = apply_sgr(style, base, &params);
+                        if next != style && !current.is_empty() {
+                            spans.push(Span::styled(std::mem::take(&mut current), style));
+                        }
+                        style = next;
+                    }
+                }
+                Some(']') => {
+                    // OSC runs to BEL or ESC `\`
+                    while let Some(c) = chars.next() {
+                        if c == BEL {
+                            break;
+                        }
+                        if c == ESC {
+                            chars.next_if_eq(&'\\');
+                            break;
+                        }
+                    }
+                }
+                // Character set selection takes one more character
+                Some('(' | ')' | '*' | '+') => {
+                    chars.next();
+                }
+                _ => {}
+            },
+            '\t' => current.push_str("    "),
+            '\x08' => {
+                current.pop();
+            }
+            c if c.is_control() => {}
+            c => current.push(c),
+        }
+    }
+    if !current.is_empty() {
+        spans.push(Span::styled(current, style));
+    }
+    Line::from(spans)
+}
+
+/// Apply a `;`-separated SGR parameter list to `style`. Resets go back to
+/// `base` rather than the terminal default, so uncolored text keeps the
+/// pane's own style.
+fn apply_sgr(mut style: Style, base: Style, params: &str) -> Style {
+    let codes: Vec<u16> = params
+        .split([';', ':'])
+        .map(|p| p.parse().unwrap_or(0))
+        .collect();
+    let mut i = 0;
+    while i < codes.len() {
+        match codes[i] {
+            0 => style = base,
+            1 => style = style.add_modifier(Modifier::BOLD),
+            2 => style = style.add_modifier(Modifier::DIM),
+            3 => style = style.add_modifier(Modifier::ITALIC),
+            4 => style = style.add_modifier(Modifier::UNDERLINED),
+            5 | 6 => style = style.add_modifier(Modifier::SLOW_BLINK),
+            7 => style = style.add_modifier(Modifier::REVERSED),
+            9 => style = style.add_modifier(Modifier::CROSSED_OUT),
+            22 => style = style.remove_modifier(Modifier::BOLD | Modifier::DIM),
+            23 => style = style.remove_modifier(Modifier::ITALIC),
+            24 => style = style.remove_modifier(Modifier::UNDERLINED),
+            25 => style = style.remove_modifier(Modifier::SLOW_BLINK),
+            27 => style = style.remove_modifier(Modifier::REVERSED),
+            29 => style = style.remove_modifier(Modifier::CROSSED_OUT),
+            n @ 30..=37 => style.fg = Some(basic_color(n - 30)),
+            n @ 90..=97 => style.fg = Some(bright_color(n - 90)),
+            n @ 40..=47 => style.bg = Some(basic_color(n - 40)),
+            n @ 100..=107 => style.bg = Some(bright_color(n - 100)),
+            39 => style.fg = base.fg,
+            49 => style.bg = base.bg,
+            n @ (38 | 48) => {
+                let (color, used) = extended_color(&codes[i + 1..]);
+                if let Some(color) = color {
+                    if n == 38 {
+                        style.fg = Some(color);
+                    } else {
+                        style.bg = Some(color);
+                    }
+                }
+                i += used;
+            }
+            _ => {}
+        }
+        i += 1;
+    }
+    style
+}
+
+/// `5;n` (256-color) or `2;r;g;b` (truecolor) after a 38/48 code, and how
+/// many parameters it took.
+fn extended_color(args: &[u16]) -> (Option<Color>, usize) {
+    match args {
+        [5, n, ..] => (u8::try_from(*n).ok().map(Color::Indexed), 2),
+        [2, r, g, b, ..] => {
+            let channel = |v: u16| v.min(255) as u8;
+            (Some(Color::Rgb(channel(*r), channel(*g), channel(*b))), 4)
+        }
+        _ => (None, args.len()),
+    }
+}
+
+fn basic_color(n: u16) -> Color {
+    match n {
+        0 => Color::Black,
+        1 => Color::Red,
+        2 => Color::Green,
+        3 => Color::Yellow,
+        4 => Color::Blue,
+        5 => Color::Magenta,
+        6 => Color::Cyan,
+        _ => Color::Gray,
+    }
+}
+
+fn bright_color(n: u16) -> Color {
+    match n {
+        0 => Color::DarkGray,
+        1 => Color::LightRed,
+        2 => Color::LightGreen,
+        3 => Color::LightYellow,
+        4 => Color::LightBlue,
+        5 => Color::LightMagenta,
+        6 => Color::LightCyan,
+        _ => Color::White,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn styles_sgr_and_drops_cursor_noise() {
+        let base = Style::new().fg(Color::Gray);
+        let line = ansi_line(
+            "\x1b[2K\x1b[1G\x1b]0;title\x07ok \x1b[1;32mpass\x1b[0m \x1b[38;5;208mwarn\x1b[39m.",
+            base,
+        );
+        let spans: Vec<(&str, Style)> = line
+            .spans
+            .iter()
+            .map(|s| (s.content.as_ref(), s.style))
+            .collect();
+        assert_eq!(
+            spans,
+            [
+                ("ok ", base),
+                (
+                    "pass",
+                    Style::new().fg(Color::Green).add_modifier(Modifier::BOLD)
+                ),
+                (" ", base),
+                ("warn", Style::new().fg(Color::Indexed(208))),
+                (".", base),
+            ]
+        );
+
+        let spinner = ansi_line("⠋ Loading\r⠙ Loading\r\x1b[32m✔\x1b[0m Done\r", base);
+        let text: String = spinner.spans.iter().map(|s| s.content.as_ref()).collect();
+        assert_eq!(text, "✔ Done");
+    }
+}
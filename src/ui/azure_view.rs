@@ -0,0 +1,299 @@
+use ratatui::layout::{Constraint, Direction, Layout, Rect};
+use ratatui::style::{Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph, Wrap};
+use ratatui::Frame;
+
+use super::util::{centered_rect, ticket_notes_lines};
+use super::{empty_state, theme, trend_chart};
+use crate::app::{App, AzurePane};
+use crate::model::azure::{FlatAzureItem, WorkItem};
+use crate::model::snapshot;
+
+pub fn draw_azure(f: &mut Frame, area: Rect, app: &App) {
+    let body = trend_chart::draw_trend_header(f, area, app, snapshot::TRACKER_AZURE);
+    let chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(40), Constraint::Percentage(60)])
+        .split(body);
+
+    draw_item_list(f, chunks[0], app);
+    draw_detail_pane(f, chunks[1], app);
+
+    if app.azure_show_transitions {
+        draw_transition_popup(f, area, app);
+    }
+}
+
+fn state_style(item: &WorkItem) -> Style {
+    match item.state_rank() {
+        0 => theme::AZURE_ACTIVE,
+        1 => theme::AZURE_NEW,
+        2 | 4 => theme::AZURE_RESOLVED,
+        _ => theme::LIST_NORMAL,
+    }
+}
+
+fn type_style(item: &WorkItem) -> Style {
+    match item.type_icon() {
+        "B" => theme::AZURE_BUG,
+        "S" => theme::AZURE_STORY,
+        "T" => theme::AZURE_TASK,
+        _ => theme::LIST_NORMAL,
+    }
+}
+
+fn draw_item_list(f: &mut Frame, area: Rect, app: &App) {
+    let is_active = app.azure_pane == AzurePane::List;
+    let border_style = if is_active {
+        theme::BORDER_ACTIVE
+    } else {
+        theme::BORDER_INACTIVE
+    };
+
+    let title = format!(" Work Items [{}] ", app.azure_items.len());
+    let block = Block::default()
+        .title(title)
+        .borders(Borders::ALL)
+        .border_style(border_style);
+
+    if app.azure_flat_list.is_empty() {
+        empty_state::draw_empty_state(f, area, block, "No work items found", app);
+        return;
+    }
+
+    let items: Vec<ListItem> = app
+        .azure_flat_list
+        .iter()
+        .map(|entry| match entry {
+            FlatAzureItem::StateHeader(state) => {
+                let rank_style = app
+                    .azure_items
+                    .iter()
+                    .find(|i| &i.state == state)
+                    .map(state_style)
+                    .unwrap_or(theme::LIST_NORMAL);
+                ListItem::new(Line::from(Span::styled(state.clone(), rank_style)))
+            }
+            FlatAzureItem::WorkItem(item) => {
+                let line = Line::from(vec![
+                    Span::styled(format!("  [{}] ", item.type_icon()), type_style(item)),
+                    Span::styled(
+                        format!("#{}", item.id),
+                        theme::LIST_NORMAL.add_modifier(Modifier::BOLD),
+                    ),
+                    Span::styled(" ", theme::LIST_NORMAL),
+                    Span::styled(&item.title, theme::LIST_NORMAL),
+                ]);
+                ListItem::new(line)
+            }
+        })
+        .collect();
+
+    let mut state = ListState::default();
+    state.select(Some(app.azure_index));
+
+    let list = List::new(items)
+        .block(block)
+        .highlight_style(theme::LIST_SELECTED);
+
+    f.render_stateful_widget(list, area, &mut state);
+}
+
+fn draw_detail_pane(f: &mut Frame, area: Rect, app: &App) {
+    let is_active = app.azure_pane == AzurePane::Detail;
+    let border_style = if is_active {
+        theme::BORDER_ACTIVE
+    } else {
+        theme::BORDER_INACTIVE
+    };
+
+    let selected = app.azure_selected_item();
+
+    let title = if let Some(item) = selected {
+        format!(" {} ", item.key())
+    } else {
+        " Detail ".to_string()
+    };
+
+    let block = Block::default()
+        .title(title)
+        .borders(Borders::ALL)
+        .border_style(border_style);
+
+    let item = match selected {
+        Some(i) => i,
+        None => {
+            let p = Paragraph::new("Select a work item to view details")
+                .style(theme::EMPTY_STATE)
+                .block(block);
+            f.render_widget(p, area);
+            return;
+        }
+    };
+
+    // Description and comments come from the detail fetched on Enter
+    let detail = app.azure_detail.as_ref().filter(|d| d.id == item.id);
+
+    let inner = block.inner(area);
+    f.render_widget(block, area);
+
+    let mut lines: Vec<Line> = Vec::new();
+
+    // Title
+    lines.push(Line::from(vec![
+        Span::styled("Title: ", theme::LIST_NORMAL.add_modifier(Modifier::BOLD)),
+        Span::styled(&item.title, theme::LIST_NORMAL.add_modifier(Modifier::BOLD)),
+    ]));
+
+    // State with color
+    lines.push(Line::from(vec![
+        Span::styled("State: ", theme::LIST_NORMAL.add_modifier(Modifier::BOLD)),
+        Span::styled(&item.state, state_style(item)),
+    ]));
+
+    // Type
+    lines.push(Line::from(vec![
+        Span::styled("Type: ", theme::LIST_NORMAL.add_modifier(Modifier::BOLD)),
+        Span::styled(&item.work_item_type, type_style(item)),
+    ]));
+
+    // Priority
+    if let Some(priority) = item.priority {
+        lines.push(Line::from(vec![
+            Span::styled(
+                "Priority: ",
+                theme::LIST_NORMAL.add_modifier(Modifier::BOLD),
+            ),
+            Span::raw(priority.to_string()),
+        ]));
+    }
+
+    // Assignee
+    if let Some(ref assignee) = item.assigned_to {
+        lines.push(Line::from(vec![
+            Span::styled(
+                "Assigned To: ",
+                theme::LIST_NORMAL.add_modifier(Modifier::BOLD),
+            ),
+            Span::raw(assignee),
+        ]));
+    }
+
+    // Project
+    if !item.project.is_empty() {
+        lines.push(Line::from(vec![
+            Span::styled("Project: ", theme::LIST_NORMAL.add_modifier(Modifier::BOLD)),
+            Span::raw(&item.project),
+        ]));
+    }
+
+    // Tags
+    if !item.tags.is_empty() {
+        lines.push(Line::from(vec![
+            Span::styled("Tags: ", theme::LIST_NORMAL.add_modifier(Modifier::BOLD)),
+            Span::raw(item.tags.join(", ")),
+        ]));
+    }
+
+    // Blank line
+    lines.push(Line::from(""));
+
+    // Description
+    lines.push(Line::from(Span::styled(
+        "Description:",
+        theme::LIST_NORMAL.add_modifier(Modifier::BOLD),
+    )));
+
+    match detail {
+        Some(d) => match d.description {
+            Some(ref desc) => {
+                for line in desc.lines() {
+                    lines.push(Line::from(Span::raw(line.to_string())));
+                }
+            }
+            None => {
+                lines.push(Line::from(Span::styled(
+                    "No description",
+                    theme::EMPTY_STATE,
+                )));
+            }
+        },
+        None => {
+            let msg = if app.azure_detail_loading {
+                "Loading..."
+            } else {
+                "Press Enter to load description and comments"
+            };
+            lines.push(Line::from(Span::styled(msg, theme::EMPTY_STATE)));
+        }
+    }
+
+    // Comments
+    if detail.is_some() && !app.azure_comments.is_empty() {
+        lines.push(Line::from(""));
+        lines.push(Line::from(Span::styled(
+            format!("Comments ({}):", app.azure_comments.len()),
+            theme::LIST_NORMAL.add_modifier(Modifier::BOLD),
+        )));
+        for comment in &app.azure_comments {
+            let date = comment.created.get(..10).unwrap_or(&comment.created);
+            lines.push(Line::from(vec![
+                Span::styled(&comment.author, theme::AZURE_COMMENT_AUTHOR),
+                Span::styled(format!("  {}", date), theme::EMPTY_STATE),
+            ]));
+            for line in comment.text.lines() {
+                lines.push(Line::from(Span::raw(format!("  {}", line))));
+            }
+        }
+    }
+
+    lines.extend(ticket_notes_lines(app, &item.url));
+
+    // URL
+    if !item.url.is_empty() {
+        lines.push(Line::from(""));
+        lines.push(Line::from(vec![
+            Span::styled("URL: ", theme::LIST_NORMAL.add_modifier(Modifier::BOLD)),
+            Span::raw(&item.url),
+        ]));
+    }
+
+    // Apply scroll offset
+    let inner_height = inner.height as usize;
+    let total = lines.len();
+    let scroll_offset = app
+        .azure_detail_scroll
+        .min(total.saturating_sub(inner_height));
+    let visible_end = (scroll_offset + inner_height).min(total);
+
+    let visible_lines: Vec<Line> = lines[scroll_offset..visible_end].to_vec();
+    let paragraph = Paragraph::new(visible_lines).wrap(Wrap { trim: false });
+    f.render_widget(paragraph, inner);
+}
+
+fn draw_transition_popup(f: &mut Frame, area: Rect, app: &App) {
+    let width = 40u16.min(area.width.saturating_sub(4));
+    let height = (app.azure_transitions.len() as u16 + 4).min(area.height.saturating_sub(4));
+
+    let popup_area = centered_rect(area, width, height);
+
+    // Clear background behind popup
+    f.render_widget(Clear, popup_area);
+
+    let block = Block::default()
+        .title(" Move to State ")
+        .borders(Borders::ALL)
+        .border_style(theme::HELP_TITLE)
+        .style(theme::JIRA_TRANSITION_POPUP);
+
+    let mut lines: Vec<Line> = Vec::new();
+    lines.push(Line::from(""));
+
+    for (i, state) in app.azure_transitions.iter().enumerate() {
+        lines.push(Line::from(format!("  {}. {}", i + 1, state)));
+    }
+
+    let paragraph = Paragraph::new(lines).block(block);
+    f.render_widget(paragraph, popup_area);
+}
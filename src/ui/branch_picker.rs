@@ -0,0 +1,145 @@
+use ratatui::layout::{Constraint, Direction, Layout, Rect};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph};
+use ratatui::Frame;
+
+use super::theme;
+use crate::app::{App, BranchInputMode};
+
+/// Draw the Git tab's branch / worktree picker popup.
+pub fn draw_branch_picker(f: &mut Frame, area: Rect, app: &App) {
+    // Rows: one per branch/worktree plus a header for each non-empty section
+    let branches = &app.branch_picker_branches;
+    let worktrees = &app.branch_picker_worktrees;
+    let row_count = branches.len() + worktrees.len() + 2;
+    let height = (row_count as u16).min(20) + 4;
+    let width = 76u16.min(area.width.saturating_sub(4));
+
+    let vert = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(area.height.saturating_sub(height) / 2),
+            Constraint::Length(height.min(area.height)),
+            Constraint::Min(0),
+        ])
+        .split(area);
+
+    let horiz = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([
+            Constraint::Length(area.width.saturating_sub(width) / 2),
+            Constraint::Length(width),
+            Constraint::Min(0),
+        ])
+        .split(vert[1]);
+
+    let popup_area = horiz[1];
+    f.render_widget(Clear, popup_area);
+
+    let block = Block::default()
+        .title(" Branches & Worktrees ")
+        .borders(Borders::ALL)
+        .border_style(theme::PROMPT_MODAL_BORDER);
+    let inner = block.inner(popup_area);
+    f.render_widget(block, popup_area);
+
+    let parts = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(1), Constraint::Length(2)])
+        .split(inner);
+
+    let mut items: Vec<ListItem> = Vec::with_capacity(row_count);
+    let mut selected_row = 0;
+
+    items.push(ListItem::new(Line::from(Span::styled(
+        format!("Branches ({})", branches.len()),
+        theme::BRANCH_PICKER_SECTION,
+    ))));
+    for (i, b) in branches.iter().enumerate() {
+        if i == app.branch_picker_index {
+            selected_row = items.len();
+        }
+        let marker = if b.is_current { "* " } else { "  " };
+        let name_style = if b.is_current {
+            theme::BRANCH_CURRENT
+        } else {
+            theme::FB_FILE
+        };
+        let mut spans = vec![
+            Span::styled(marker, theme::BRANCH_CURRENT),
+            Span::styled(b.name.clone(), name_style),
+        ];
+        if let Some(ref upstream) = b.upstream {
+            spans.push(Span::styled(
+                format!("  ({})", upstream),
+                theme::BRANCH_DETAIL,
+            ));
+        }
+        if !b.is_current && b.worktree_path.is_some() {
+            spans.push(Span::styled("  [worktree]", theme::BRANCH_DETAIL));
+        }
+        items.push(ListItem::new(Line::from(spans)));
+    }
+
+    items.push(ListItem::new(Line::from(Span::styled(
+        format!("Worktrees ({})", worktrees.len()),
+        theme::BRANCH_PICKER_SECTION,
+    ))));
+    for (i, wt) in worktrees.iter().enumerate() {
+        if branches.len() + i == app.branch_picker_index {
+            selected_row = items.len();
+        }
+        let is_current = app.is_current_worktree(&wt.path);
+        let marker = if is_current { "* " } else { "  " };
+        items.push(ListItem::new(Line::from(vec![
+            Span::styled(marker, theme::BRANCH_CURRENT),
+            Span::styled(wt.display_branch(), theme::BRANCH_LABEL),
+            Span::styled(format!("  {}", wt.path), theme::BRANCH_DETAIL),
+        ])));
+    }
+
+    let mut state = ListState::default();
+    state.select(Some(selected_row));
+    let list = List::new(items).highlight_style(theme::LIST_SELECTED);
+    f.render_stateful_widget(list, parts[0], &mut state);
+
+    // Footer: launch confirmation, name input, or key hints
+    let footer_block = Block::default()
+        .borders(Borders::TOP)
+        .border_style(theme::PROMPT_MODAL_BORDER);
+    let footer = if let Some(ref path) = app.branch_picker_launch_path {
+        Line::from(vec![
+            Span::styled(
+                format!(" Launch Claude in {}? ", path.display()),
+                theme::BRANCH_PICKER_INPUT,
+            ),
+            Span::styled("y", theme::HELP_KEY),
+            Span::styled("/", theme::HELP_DESC),
+            Span::styled("n", theme::HELP_KEY),
+        ])
+    } else if let Some(ref mode) = app.branch_picker_input {
+        let label = match mode {
+            BranchInputMode::NewBranch => " New branch from HEAD: ",
+            BranchInputMode::NewWorktree => " New worktree branch: ",
+        };
+        Line::from(vec![
+            Span::styled(label, theme::HELP_DESC),
+            Span::styled(
+                format!("{}_", app.branch_picker_input_text),
+                theme::BRANCH_PICKER_INPUT,
+            ),
+        ])
+    } else {
+        Line::from(vec![
+            Span::styled(" Enter", theme::HELP_KEY),
+            Span::styled(": Switch/launch  ", theme::HELP_DESC),
+            Span::styled("n", theme::HELP_KEY),
+            Span::styled(": New branch  ", theme::HELP_DESC),
+            Span::styled("w", theme::HELP_KEY),
+            Span::styled(": New worktree  ", theme::HELP_DESC),
+            Span::styled("Esc", theme::HELP_KEY),
+            Span::styled(": Close ", theme::HELP_DESC),
+        ])
+    };
+    f.render_widget(Paragraph::new(footer).block(footer_block), parts[1]);
+}
@@ -0,0 +1,269 @@
+use ratatui::layout::{Constraint, Direction, Layout, Rect};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph, Wrap};
+use ratatui::Frame;
+
+use super::plans_view::markdown_line;
+use super::theme;
+use crate::app::{App, CommandsPane};
+use crate::model::command::{CommandKind, CommandScope};
+
+pub fn draw_commands(f: &mut Frame, area: Rect, app: &App) {
+    let chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(35), Constraint::Percentage(65)])
+        .split(area);
+
+    if app.command_new_target.is_some() || app.command_import_input.is_some() {
+        let left = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Min(3), Constraint::Length(3)])
+            .split(chunks[0]);
+        draw_command_list(f, left[0], app);
+        if app.command_import_input.is_some() {
+            draw_import_input(f, left[1], app);
+        } else {
+            draw_new_input(f, left[1], app);
+        }
+    } else {
+        draw_command_list(f, chunks[0], app);
+    }
+    draw_command_content(f, chunks[1], app);
+}
+
+fn draw_command_list(f: &mut Frame, area: Rect, app: &App) {
+    let is_active = app.commands_pane == CommandsPane::List;
+    let border_style = if is_active {
+        theme::BORDER_ACTIVE
+    } else {
+        theme::BORDER_INACTIVE
+    };
+
+    let title = format!(" Commands [{}] ", app.command_files.len());
+    let block = Block::default()
+        .title(title)
+        .borders(Borders::ALL)
+        .border_style(border_style);
+
+    if app.command_files.is_empty() {
+        let msg = format!(
+            "No commands or agents found in\n{}\n{}\n\nPress n to create one",
+            app.project_cwd.join(".claude").display(),
+            app.claude_home.display()
+        );
+        let p = Paragraph::new(msg)
+            .style(theme::EMPTY_STATE)
+            .block(block)
+            .wrap(Wrap { trim: false });
+        f.render_widget(p, area);
+        return;
+    }
+
+    let items: Vec<ListItem> = app
+        .command_files
+        .iter()
+        .enumerate()
+        .map(|(i, cmd)| {
+            let prefix = if i == app.command_index { ">" } else { " " };
+            let mut spans = vec![
+                Span::raw(format!("{} ", prefix)),
+                Span::styled(
+                    format!("{} ", scope_tag(cmd.scope, cmd.kind)),
+                    theme::COMMAND_SCOPE,
+                ),
+                Span::raw(cmd.display_name()),
+            ];
+            if let Some(ref desc) = cmd.description {
+                spans.push(Span::styled(
+                    format!("  {}", desc),
+                    theme::COMMAND_DESCRIPTION,
+                ));
+            }
+            ListItem::new(Line::from(spans))
+        })
+        .collect();
+
+    let mut state = ListState::default();
+    state.select(Some(app.command_index));
+
+    let list = List::new(items)
+        .block(block)
+        .highlight_style(theme::LIST_SELECTED);
+
+    f.render_stateful_widget(list, area, &mut state);
+}
+
+fn draw_command_content(f: &mut Frame, area: Rect, app: &App) {
+    let is_active = app.commands_pane == CommandsPane::Content;
+    let border_style = if is_active {
+        theme::BORDER_ACTIVE
+    } else {
+        theme::BORDER_INACTIVE
+    };
+
+    let title = match app.selected_command() {
+        Some(cmd) => format!(" {} ", cmd.path.display()),
+        None => " Preview ".to_string(),
+    };
+
+    // Edit mode: render the TextArea widget
+    if app.command_editing {
+        let block = Block::default()
+            .title(title)
+            .borders(Borders::ALL)
+            .border_style(theme::FB_EDIT_BORDER);
+        if let Some(ref editor) = app.command_editor {
+            let inner = block.inner(area);
+            f.render_widget(block, area);
+            f.render_widget(editor, inner);
+        }
+        return;
+    }
+
+    let block = Block::default()
+        .title(title)
+        .borders(Borders::ALL)
+        .border_style(border_style);
+
+    let lines = app.current_command_lines();
+    if lines.is_empty() {
+        let p = Paragraph::new("Select a command to preview")
+            .style(theme::EMPTY_STATE)
+            .block(block);
+        f.render_widget(p, area);
+        return;
+    }
+
+    let inner = block.inner(area);
+    f.render_widget(block, area);
+
+    let inner_height = inner.height as usize;
+    let scroll_offset = app
+        .command_content_scroll
+        .min(lines.len().saturating_sub(inner_height));
+    let visible_end = (scroll_offset + inner_height).min(lines.len());
+
+    let rendered: Vec<Line> = lines[scroll_offset..visible_end]
+        .iter()
+        .map(|ml| markdown_line(ml, inner.width as usize))
+        .collect();
+    f.render_widget(Paragraph::new(rendered), inner);
+}
+
+fn draw_new_input(f: &mut Frame, area: Rect, app: &App) {
+    let Some((scope, kind)) = app.command_new_target else {
+        return;
+    };
+    let scope_name = match scope {
+        CommandScope::Project => "project",
+        CommandScope::User => "user",
+    };
+    let kind_name = match kind {
+        CommandKind::Command => "command",
+        CommandKind::Agent => "agent",
+    };
+    let block = Block::default()
+        .title(format!(" New {} {} (Tab: change) ", scope_name, kind_name))
+        .borders(Borders::ALL)
+        .border_style(theme::FB_EDIT_BORDER);
+    let line = Line::from(vec![
+        Span::styled(" Name: ", theme::HELP_DESC),
+        Span::styled(
+            format!("{}_", app.command_new_name),
+            theme::BRANCH_PICKER_INPUT,
+        ),
+    ]);
+    f.render_widget(Paragraph::new(line).block(block), area);
+}
+
+fn draw_import_input(f: &mut Frame, area: Rect, app: &App) {
+    let Some(ref source) = app.command_import_input else {
+        return;
+    };
+    let block = Block::default()
+        .title(" Import agent / command (URL or path) ")
+        .borders(Borders::ALL)
+        .border_style(theme::FB_EDIT_BORDER);
+    let line = Line::from(vec![
+        Span::styled(" From: ", theme::HELP_DESC),
+        Span::styled(format!("{}_", source), theme::BRANCH_PICKER_INPUT),
+    ]);
+    f.render_widget(Paragraph::new(line).block(block), area);
+}
+
+/// Popup showing a definition loaded for import: its target path (flagged
+/// when it would overwrite a file) and its full text as it will be written.
+pub fn draw_command_import(f: &mut Frame, area: Rect, app: &App) {
+    let (Some(import), Some(candidate)) = (&app.command_import, app.selected_import_candidate())
+    else {
+        return;
+    };
+    let width = 100u16.min(area.width.saturating_sub(4));
+    let height = area.height.saturating_sub(4);
+    let popup_area = Rect {
+        x: area.x + area.width.saturating_sub(width) / 2,
+        y: area.y + area.height.saturating_sub(height) / 2,
+        width,
+        height,
+    };
+    f.render_widget(Clear, popup_area);
+
+    let kind = match candidate.kind {
+        CommandKind::Command => "command",
+        CommandKind::Agent => "agent",
+    };
+    let counter = if import.candidates.len() > 1 {
+        format!(" {}/{}", import.index + 1, import.candidates.len())
+    } else {
+        String::new()
+    };
+    let block = Block::default()
+        .title(format!(" Import {} {}{} ", kind, candidate.name, counter))
+        .title_bottom(" Enter import  o overwrite  Tab next  j/k scroll  Esc close ")
+        .borders(Borders::ALL)
+        .border_style(theme::PROMPT_MODAL_BORDER);
+    let inner = block.inner(popup_area);
+    f.render_widget(block, popup_area);
+
+    let parts = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(3), Constraint::Min(1)])
+        .split(inner);
+
+    let target = match app.import_target() {
+        Some((path, true)) => Line::from(vec![
+            Span::styled(" To:   ", theme::HELP_DESC),
+            Span::raw(path.display().to_string()),
+            Span::styled("  (exists, o to overwrite)", theme::CHECK_FAIL),
+        ]),
+        Some((path, false)) => Line::from(vec![
+            Span::styled(" To:   ", theme::HELP_DESC),
+            Span::raw(path.display().to_string()),
+        ]),
+        None => Line::from(Span::styled(" To:   invalid name", theme::CHECK_FAIL)),
+    };
+    let header = Paragraph::new(vec![
+        Line::from(vec![
+            Span::styled(" From: ", theme::HELP_DESC),
+            Span::raw(candidate.source.clone()),
+        ]),
+        target,
+    ])
+    .block(Block::default().borders(Borders::BOTTOM));
+    f.render_widget(header, parts[0]);
+
+    let content = Paragraph::new(candidate.content.as_str())
+        .wrap(Wrap { trim: false })
+        .scroll((import.scroll.min(u16::MAX as usize) as u16, 0));
+    f.render_widget(content, parts[1]);
+}
+
+/// Two-letter tag: scope (P/U) and kind (C/A).
+fn scope_tag(scope: CommandScope, kind: CommandKind) -> &'static str {
+    match (scope, kind) {
+        (CommandScope::Project, CommandKind::Command) => "PC",
+        (CommandScope::Project, CommandKind::Agent) => "PA",
+        (CommandScope::User, CommandKind::Command) => "UC",
+        (CommandScope::User, CommandKind::Agent) => "UA",
+    }
+}
@@ -0,0 +1,155 @@
+use ratatui::layout::{Constraint, Direction, Layout, Rect};
+use ratatui::style::Modifier;
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, List, ListItem, ListState, Paragraph, Wrap};
+use ratatui::Frame;
+
+use super::theme;
+use crate::app::App;
+
+pub fn draw_deps(f: &mut Frame, area: Rect, app: &App) {
+    let chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+        .split(area);
+
+    draw_dep_list(f, chunks[0], app);
+    draw_detail_pane(f, chunks[1], app);
+}
+
+fn draw_dep_list(f: &mut Frame, area: Rect, app: &App) {
+    let title = if app.deps_loading {
+        format!(" Outdated [{}] (checking...) ", app.outdated_deps.len())
+    } else {
+        format!(" Outdated [{}] ", app.outdated_deps.len())
+    };
+    let block = Block::default()
+        .title(title)
+        .borders(Borders::ALL)
+        .border_style(theme::BORDER_ACTIVE);
+
+    if app.outdated_deps.is_empty() {
+        let msg = if app.deps_loading {
+            "Running dependency checkers...".to_string()
+        } else if !app.deps_checked {
+            format!(
+                "Press r to run: {}",
+                app.project_config.deps_checkers().join(", ")
+            )
+        } else if app.deps_errors.is_empty() {
+            "All dependencies are up to date.".to_string()
+        } else {
+            format!("Checkers failed:\n{}", app.deps_errors.join("\n"))
+        };
+        let p = Paragraph::new(msg)
+            .style(theme::EMPTY_STATE)
+            .block(block)
+            .wrap(Wrap { trim: false });
+        f.render_widget(p, area);
+        return;
+    }
+
+    let items: Vec<ListItem> = app
+        .outdated_deps
+        .iter()
+        .map(|dep| {
+            let latest_style = if dep.is_breaking() {
+                theme::DEP_BREAKING
+            } else {
+                theme::DEP_COMPATIBLE
+            };
+            ListItem::new(Line::from(vec![
+                Span::styled(format!("[{}] ", dep.ecosystem), theme::DEP_ECOSYSTEM),
+                Span::raw(format!("{} ", dep.name)),
+                Span::styled(dep.current_display().to_string(), theme::DEP_ECOSYSTEM),
+                Span::raw(" -> "),
+                Span::styled(dep.latest.clone(), latest_style),
+            ]))
+        })
+        .collect();
+
+    let mut state = ListState::default();
+    state.select(Some(app.deps_index));
+
+    let list = List::new(items)
+        .block(block)
+        .highlight_style(theme::LIST_SELECTED);
+
+    f.render_stateful_widget(list, area, &mut state);
+}
+
+fn draw_detail_pane(f: &mut Frame, area: Rect, app: &App) {
+    let selected = app.selected_outdated_dep();
+
+    let title = match selected {
+        Some(dep) => format!(" {} ", dep.name),
+        None => " Detail ".to_string(),
+    };
+    let block = Block::default()
+        .title(title)
+        .borders(Borders::ALL)
+        .border_style(theme::BORDER_INACTIVE);
+
+    let dep = match selected {
+        Some(d) => d,
+        None => {
+            let p = Paragraph::new("Select a dependency to view details")
+                .style(theme::EMPTY_STATE)
+                .block(block);
+            f.render_widget(p, area);
+            return;
+        }
+    };
+
+    let label = theme::LIST_NORMAL.add_modifier(Modifier::BOLD);
+    let mut lines = vec![
+        Line::from(vec![
+            Span::styled("Ecosystem: ", label),
+            Span::raw(dep.ecosystem.as_str()),
+        ]),
+        Line::from(vec![
+            Span::styled("Current:   ", label),
+            Span::raw(dep.current_display()),
+        ]),
+    ];
+    if let Some(ref wanted) = dep.wanted {
+        lines.push(Line::from(vec![
+            Span::styled("Wanted:    ", label),
+            Span::raw(wanted.as_str()),
+        ]));
+    }
+    lines.push(Line::from(vec![
+        Span::styled("Latest:    ", label),
+        Span::styled(
+            dep.latest.as_str(),
+            if dep.is_breaking() {
+                theme::DEP_BREAKING
+            } else {
+                theme::DEP_COMPATIBLE
+            },
+        ),
+    ]));
+    if let Some(ref kind) = dep.kind {
+        lines.push(Line::from(vec![
+            Span::styled("Kind:      ", label),
+            Span::raw(kind.as_str()),
+        ]));
+    }
+    if dep.is_breaking() {
+        lines.push(Line::from(""));
+        lines.push(Line::from(Span::styled(
+            "Latest is outside the current version requirement.",
+            theme::DEP_BREAKING,
+        )));
+    }
+    lines.push(Line::from(""));
+    lines.push(Line::from(vec![
+        Span::styled("p", theme::HINT_KEY),
+        Span::styled(": spawn Claude to update this dependency", theme::HINT_DESC),
+    ]));
+
+    let p = Paragraph::new(lines)
+        .block(block)
+        .wrap(Wrap { trim: false });
+    f.render_widget(p, area);
+}
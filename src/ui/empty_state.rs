@@ -0,0 +1,92 @@
+use ratatui::layout::Rect;
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, Paragraph, Wrap};
+use ratatui::Frame;
+
+use super::theme;
+use crate::app::{ActiveTab, App};
+
+/// Shortcuts offered on the active tab's empty state.
+fn actions(app: &App) -> Vec<(&'static str, &'static str)> {
+    let mut actions = match app.active_tab {
+        ActiveTab::Teams => vec![("n", "create a team (sends to Claude pane)")],
+        ActiveTab::GitHubPRs if app.prs_from_bitbucket => vec![("r", "refresh")],
+        ActiveTab::GitHubPRs | ActiveTab::GitHubIssues => vec![
+            ("r", "refresh"),
+            ("a", "check gh auth status"),
+            ("w", "open repo settings"),
+        ],
+        ActiveTab::Jira => vec![("r", "refresh"), ("a", "check acli auth status")],
+        ActiveTab::Linear => vec![("r", "refresh")],
+        _ => vec![],
+    };
+    let wants_config = matches!(
+        app.active_tab,
+        ActiveTab::GitHubPRs | ActiveTab::GitHubIssues | ActiveTab::Jira | ActiveTab::Linear
+    );
+    if wants_config && !app.has_config_file() {
+        actions.push(("S", "write a starter .assoc.toml"));
+    }
+    actions
+}
+
+/// Draw an empty list as an actionable screen: the message, the shortcuts
+/// that can help, and the output of the last auth status check.
+pub fn draw_empty_state(f: &mut Frame, area: Rect, block: Block, message: &str, app: &App) {
+    let mut lines: Vec<Line> = message
+        .lines()
+        .map(|l| Line::from(Span::styled(l.to_string(), theme::EMPTY_STATE)))
+        .collect();
+
+    let actions = actions(app);
+    if !actions.is_empty() {
+        lines.push(Line::from(""));
+        for (key, desc) in actions {
+            lines.push(Line::from(vec![
+                Span::styled(format!("  {:3}", key), theme::HELP_KEY),
+                Span::styled(desc, theme::HELP_DESC),
+            ]));
+        }
+    }
+
+    if let Some((ref cli, ok, ref output)) = app.auth_status {
+        if app.active_tab_cli() == Some(cli.as_str()) {
+            lines.push(Line::from(""));
+            let style = if ok {
+                theme::CHECK_PASS
+            } else {
+                theme::CHECK_FAIL
+            };
+            lines.push(Line::from(Span::styled(
+                format!("{} auth status:", cli),
+                style,
+            )));
+            for l in output.lines() {
+                lines.push(Line::from(format!("  {}", l)));
+            }
+        }
+    }
+
+    let p = Paragraph::new(lines)
+        .block(block)
+        .wrap(Wrap { trim: false });
+    f.render_widget(p, area);
+}
+
+/// Draw placeholder rows while the active tab's first load is running.
+pub fn draw_loading_state(f: &mut Frame, area: Rect) {
+    let block = Block::default().title(" Loading... ").borders(Borders::ALL);
+    let width = area.width.saturating_sub(4) as usize;
+    let rows = (area.height.saturating_sub(2) as usize).min(12);
+    // Uneven bar widths so the placeholder reads as a list
+    let lines: Vec<Line> = [9, 6, 8, 5]
+        .iter()
+        .cycle()
+        .take(rows)
+        .map(|tenths| {
+            let bar = "░".repeat(width * tenths / 10);
+            Line::from(Span::styled(format!(" {}", bar), theme::SKELETON))
+        })
+        .collect();
+    f.render_widget(Paragraph::new(lines).block(block), area);
+}
@@ -1,12 +1,15 @@
 use ratatui::layout::{Constraint, Direction, Layout, Rect};
+use ratatui::style::{Color, Style};
 use ratatui::text::{Line, Span};
-use ratatui::widgets::{Block, Borders, List, ListItem, ListState, Paragraph};
+use ratatui::widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph};
 use ratatui::Frame;
 
 use super::theme;
-use super::util::truncate_chars;
-use crate::app::{App, FileBrowserPane};
-use crate::model::filebrowser::{EntryKind, FileContent};
+use super::util::{centered_rect, format_size, truncate_chars};
+use crate::app::{App, FileBrowserPane, FileOp};
+use crate::data::grep::MAX_MATCHES;
+use crate::model::filebrowser::{EntryKind, FileContent, FileInfo, ImagePreview, LargeFile};
+use crate::model::grep::FlatGrepItem;
 use crate::model::plan::MarkdownLineKind;
 
 pub fn draw_filebrowser(f: &mut Frame, area: Rect, app: &App) {
@@ -15,8 +18,146 @@ pub fn draw_filebrowser(f: &mut Frame, area: Rect, app: &App) {
         .constraints([Constraint::Percentage(40), Constraint::Percentage(60)])
         .split(area);
 
-    draw_tree_pane(f, chunks[0], app);
+    if app.grep_query.is_some() {
+        draw_search_pane(f, chunks[0], app);
+    } else {
+        draw_tree_pane(f, chunks[0], app);
+    }
     draw_content_pane(f, chunks[1], app);
+
+    if app.grep_input.is_some() {
+        draw_search_input(f, area, app);
+    }
+    if app.fb_op.is_some() {
+        draw_file_op_input(f, area, app);
+    }
+}
+
+/// Search results grouped by file, in place of the tree.
+fn draw_search_pane(f: &mut Frame, area: Rect, app: &App) {
+    let border_style = if app.fb_pane == FileBrowserPane::Tree {
+        theme::BORDER_ACTIVE
+    } else {
+        theme::BORDER_INACTIVE
+    };
+    let pattern = app.grep_query.as_deref().unwrap_or_default();
+    let results = &app.grep_results;
+    let title = if app.grep_loading {
+        format!(" Search: {} (searching...) ", pattern)
+    } else if results.truncated {
+        format!(" Search: {} (first {} lines) ", pattern, MAX_MATCHES)
+    } else {
+        format!(
+            " Search: {} ({} in {} files) ",
+            pattern,
+            results.matches.len(),
+            results.file_count()
+        )
+    };
+    let block = Block::default()
+        .title(title)
+        .borders(Borders::ALL)
+        .border_style(border_style);
+
+    if app.grep_items.is_empty() {
+        let message = if app.grep_loading {
+            "Searching..."
+        } else {
+            "No matches"
+        };
+        let p = Paragraph::new(message)
+            .style(theme::EMPTY_STATE)
+            .block(block);
+        f.render_widget(p, area);
+        return;
+    }
+
+    let available = block.inner(area).width as usize;
+    let items: Vec<ListItem> = app
+        .grep_items
+        .iter()
+        .map(|item| match item {
+            FlatGrepItem::File(path, count) => ListItem::new(Line::from(vec![
+                Span::styled(path.as_str(), theme::FB_DIR),
+                Span::styled(format!(" ({})", count), theme::FB_LINE_NUMBER),
+            ])),
+            FlatGrepItem::Match(m) => {
+                let number = format!("  {:>4} ", m.line);
+                let width = available.saturating_sub(number.len());
+                let mut spans = vec![Span::styled(number, theme::FB_LINE_NUMBER)];
+                spans.extend(highlighted(m.text.trim_end(), &m.ranges, width));
+                ListItem::new(Line::from(spans))
+            }
+        })
+        .collect();
+
+    let mut state = ListState::default();
+    state.select(Some(app.grep_index));
+
+    let list = List::new(items)
+        .block(block)
+        .highlight_style(theme::LIST_SELECTED);
+
+    f.render_stateful_widget(list, area, &mut state);
+}
+
+/// `text` cut to `width` characters, with the byte `ranges` picked out.
+fn highlighted<'a>(text: &'a str, ranges: &[(usize, usize)], width: usize) -> Vec<Span<'a>> {
+    let end = truncate_chars(text, width).len();
+    let mut spans = Vec::new();
+    let mut at = 0;
+    for &(start, stop) in ranges {
+        let (start, stop) = (start.max(at).min(end), stop.min(end));
+        if start >= stop || !text.is_char_boundary(start) || !text.is_char_boundary(stop) {
+            continue;
+        }
+        spans.push(Span::styled(&text[at..start], theme::LIST_NORMAL));
+        spans.push(Span::styled(&text[start..stop], theme::FB_GREP_MATCH));
+        at = stop;
+    }
+    spans.push(Span::styled(&text[at..end], theme::LIST_NORMAL));
+    spans
+}
+
+fn draw_search_input(f: &mut Frame, area: Rect, app: &App) {
+    let Some(ref pattern) = app.grep_input else {
+        return;
+    };
+    let popup_area = centered_rect(area, 72, 3);
+    f.render_widget(Clear, popup_area);
+    let block = Block::default()
+        .title(" Search the project ")
+        .title_bottom(" Enter search  Esc cancel ")
+        .borders(Borders::ALL)
+        .border_style(theme::FB_EDIT_BORDER);
+    let line = Line::from(vec![
+        Span::styled(" Pattern: ", theme::HELP_DESC),
+        Span::styled(format!("{}_", pattern), theme::BRANCH_PICKER_INPUT),
+    ]);
+    f.render_widget(Paragraph::new(line).block(block), popup_area);
+}
+
+fn draw_file_op_input(f: &mut Frame, area: Rect, app: &App) {
+    let Some(ref op) = app.fb_op else {
+        return;
+    };
+    let title = match op.op {
+        FileOp::NewFile => " New file ",
+        FileOp::NewDir => " New folder ",
+        FileOp::Rename(_) => " Rename / move ",
+    };
+    let popup_area = centered_rect(area, 72, 3);
+    f.render_widget(Clear, popup_area);
+    let block = Block::default()
+        .title(title)
+        .title_bottom(" Enter confirm  Esc cancel ")
+        .borders(Borders::ALL)
+        .border_style(theme::FB_EDIT_BORDER);
+    let line = Line::from(vec![
+        Span::styled(" Path: ", theme::HELP_DESC),
+        Span::styled(format!("{}_", op.input), theme::BRANCH_PICKER_INPUT),
+    ]);
+    f.render_widget(Paragraph::new(line).block(block), popup_area);
 }
 
 fn draw_tree_pane(f: &mut Frame, area: Rect, app: &App) {
@@ -84,15 +225,40 @@ fn draw_content_pane(f: &mut Frame, area: Rect, app: &App) {
         theme::BORDER_INACTIVE
     };
 
-    let title = if let Some(ref path) = app.fb_content_path {
+    let mut title = if let Some(ref path) = app.fb_content_path {
         let name = path
             .file_name()
             .map(|n| n.to_string_lossy().to_string())
             .unwrap_or_else(|| "File".to_string());
-        format!(" {} ", name)
+        match &app.fb_content_info {
+            Some(info) => {
+                let dimensions = match &app.fb_content {
+                    Some(FileContent::Image(image)) => {
+                        Some((image.original_width, image.original_height))
+                    }
+                    _ => None,
+                };
+                let mut details = file_details(info, dimensions);
+                if let Some(FileContent::Large(file)) = &app.fb_content {
+                    let top = large_file_top(app, file, area.height.saturating_sub(2) as usize);
+                    let about = if file.fully_scanned() { "" } else { "~" };
+                    details.push_str(&format!(
+                        ", line {} of {}{}",
+                        top + 1,
+                        about,
+                        file.line_count()
+                    ));
+                }
+                format!(" {} - {} ", name, details)
+            }
+            None => format!(" {} ", name),
+        }
     } else {
         " Content ".to_string()
     };
+    if app.fb_tail {
+        title.push_str("[follow] ");
+    }
 
     let block = Block::default()
         .title(title)
@@ -134,6 +300,7 @@ fn draw_content_pane(f: &mut Frame, area: Rect, app: &App) {
                 1
             };
             let text_width = available.saturating_sub(num_width + 1);
+            let matches = app.grep_content_matches();
 
             let rendered: Vec<Line> = lines[scroll_offset..visible_end]
                 .iter()
@@ -141,6 +308,11 @@ fn draw_content_pane(f: &mut Frame, area: Rect, app: &App) {
                 .map(|(i, line_text)| {
                     let line_num = scroll_offset + i + 1;
                     let num_str = format!("{:>width$} ", line_num, width = num_width);
+                    if let Some(m) = matches.iter().find(|m| m.line == line_num) {
+                        let mut spans = vec![Span::styled(num_str, theme::FB_GREP_LINE_NUMBER)];
+                        spans.extend(highlighted(line_text, &m.ranges, text_width));
+                        return Line::from(spans);
+                    }
                     let text = truncate_chars(line_text, text_width);
                     Line::from(vec![
                         Span::styled(num_str, theme::FB_LINE_NUMBER),
@@ -181,14 +353,65 @@ fn draw_content_pane(f: &mut Frame, area: Rect, app: &App) {
             let paragraph = Paragraph::new(rendered);
             f.render_widget(paragraph, inner);
         }
+        Some(FileContent::Hexdump(ref rows)) => {
+            let inner = block.inner(area);
+            f.render_widget(block, area);
+
+            let inner_height = inner.height as usize;
+            let scroll_offset = app
+                .fb_content_scroll
+                .min(rows.len().saturating_sub(inner_height));
+            let visible_end = (scroll_offset + inner_height).min(rows.len());
+            let rendered: Vec<Line> = rows[scroll_offset..visible_end]
+                .iter()
+                .map(|row| {
+                    // Offset, hex bytes, then the ASCII column from '|'
+                    let (offset, rest) = row.split_at(8);
+                    let (hex, ascii) = rest.split_at(rest.find('|').unwrap_or(rest.len()));
+                    Line::from(vec![
+                        Span::styled(offset.to_string(), theme::FB_LINE_NUMBER),
+                        Span::styled(hex.to_string(), theme::LIST_NORMAL),
+                        Span::styled(ascii.to_string(), theme::FB_HEX_ASCII),
+                    ])
+                })
+                .collect();
+            f.render_widget(Paragraph::new(rendered), inner);
+        }
+        Some(FileContent::Large(ref file)) => {
+            let inner = block.inner(area);
+            f.render_widget(block, area);
+
+            let top = large_file_top(app, file, inner.height as usize);
+            let num_width = format!("{}", file.line_count()).len();
+            let text_width = (inner.width as usize).saturating_sub(num_width + 1);
+            // Lines outside the loaded page show once it pages in
+            let rendered: Vec<Line> = (top..top + inner.height as usize)
+                .filter_map(|n| Some((n, file.lines.get(n.checked_sub(file.start)?)?)))
+                .map(|(n, line_text)| {
+                    Line::from(vec![
+                        Span::styled(
+                            format!("{:>width$} ", n + 1, width = num_width),
+                            theme::FB_LINE_NUMBER,
+                        ),
+                        Span::styled(truncate_chars(line_text, text_width), theme::LIST_NORMAL),
+                    ])
+                })
+                .collect();
+            f.render_widget(Paragraph::new(rendered), inner);
+        }
+        Some(FileContent::Image(ref image)) => {
+            let inner = block.inner(area);
+            f.render_widget(block, area);
+            f.render_widget(Paragraph::new(image_lines(image, inner)), inner);
+        }
         Some(FileContent::Binary) => {
-            let p = Paragraph::new("Binary file - cannot display")
+            let p = Paragraph::new("Binary file too large for a hexdump (>64 KB)")
                 .style(theme::EMPTY_STATE)
                 .block(block);
             f.render_widget(p, area);
         }
         Some(FileContent::TooLarge) => {
-            let p = Paragraph::new("File too large (>1MB)")
+            let p = Paragraph::new("File too large to preview")
                 .style(theme::EMPTY_STATE)
                 .block(block);
             f.render_widget(p, area);
@@ -201,3 +424,69 @@ fn draw_content_pane(f: &mut Frame, area: Rect, app: &App) {
         }
     }
 }
+
+/// Line at the top of a large file's `height`-row view.
+fn large_file_top(app: &App, file: &LargeFile, height: usize) -> usize {
+    app.fb_content_scroll
+        .min(file.line_count().saturating_sub(height))
+}
+
+/// `12.4 KB, image/png, 640x480` for the content pane title.
+fn file_details(info: &FileInfo, dimensions: Option<(u32, u32)>) -> String {
+    let size = format_size(info.size);
+    match dimensions {
+        Some((width, height)) => format!("{}, {}, {}x{}", size, info.mime, width, height),
+        None => format!("{}, {}", size, info.mime),
+    }
+}
+
+/// The image scaled to fit `area`, centred, two pixels per cell: the upper
+/// half block takes the top pixel as its foreground and the bottom one as
+/// its background.
+fn image_lines(image: &ImagePreview, area: Rect) -> Vec<Line<'static>> {
+    let (cols, rows) = (u32::from(area.width), u32::from(area.height) * 2);
+    if cols == 0 || rows == 0 {
+        return Vec::new();
+    }
+    let scale =
+        (f64::from(cols) / f64::from(image.width)).min(f64::from(rows) / f64::from(image.height));
+    let width = ((f64::from(image.width) * scale) as u32).clamp(1, cols);
+    let height = ((f64::from(image.height) * scale) as u32).clamp(1, rows);
+    let pad = " ".repeat(((cols - width) / 2) as usize);
+    let truecolor = supports_truecolor();
+    let color = |x: u32, y: u32| {
+        let [r, g, b] = image.pixel(x * image.width / width, y * image.height / height);
+        if truecolor {
+            Color::Rgb(r, g, b)
+        } else {
+            Color::Indexed(ansi_256(r, g, b))
+        }
+    };
+
+    (0..height.div_ceil(2))
+        .map(|row| {
+            let mut spans = vec![Span::raw(pad.clone())];
+            for x in 0..width {
+                let mut style = Style::new().fg(color(x, row * 2));
+                if row * 2 + 1 < height {
+                    style = style.bg(color(x, row * 2 + 1));
+                }
+                spans.push(Span::styled("▀", style));
+            }
+            Line::from(spans)
+        })
+        .collect()
+}
+
+/// Whether the terminal takes 24-bit colour; Windows Terminal does but does
+/// not set `COLORTERM`.
+fn supports_truecolor() -> bool {
+    std::env::var("COLORTERM").is_ok_and(|v| v == "truecolor" || v == "24bit")
+        || std::env::var_os("WT_SESSION").is_some()
+}
+
+/// Nearest colour in the 6x6x6 cube of the 256-colour palette.
+fn ansi_256(r: u8, g: u8, b: u8) -> u8 {
+    let level = |c: u8| ((u16::from(c) * 5 + 127) / 255) as u8;
+    16 + 36 * level(r) + 6 * level(g) + level(b)
+}
@@ -0,0 +1,61 @@
+use ratatui::layout::{Constraint, Direction, Layout, Rect};
+use ratatui::widgets::{Block, Borders, Paragraph};
+use ratatui::Frame;
+
+use super::theme;
+use crate::app::App;
+
+/// Title for a filterable list: `label [total]`, or `label [shown/total]`
+/// plus the expression while a filter is applied.
+pub fn list_title(label: &str, shown: usize, total: usize, app: &App) -> String {
+    paged_list_title(label, shown, total, false, app)
+}
+
+/// `list_title` for a list loaded a page at a time; `more` marks the total
+/// with a `+` while further pages remain.
+pub fn paged_list_title(label: &str, shown: usize, total: usize, more: bool, app: &App) -> String {
+    let more = if more { "+" } else { "" };
+    match app.active_filter() {
+        Some(filter) => format!(" {} [{}/{}{}] /{} ", label, shown, total, more, filter),
+        None => format!(" {} [{}{}] ", label, total, more),
+    }
+}
+
+/// Footer for a list with further pages, e.g. `showing 100 of 342`.
+pub fn paged_footer(loaded: usize, total: Option<usize>, more: bool) -> Option<String> {
+    if !more {
+        return None;
+    }
+    Some(match total {
+        Some(total) => format!(" showing {} of {} · G loads more ", loaded, total),
+        None => format!(" showing the first {} · G loads more ", loaded),
+    })
+}
+
+/// Draw `area`'s list via `draw_list`, leaving room below it for the filter
+/// input while that is open.
+pub fn with_filter_input(
+    f: &mut Frame,
+    area: Rect,
+    app: &App,
+    draw_list: impl FnOnce(&mut Frame, Rect),
+) {
+    let Some(input) = app.filter_input.as_deref() else {
+        draw_list(f, area);
+        return;
+    };
+    let parts = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(1), Constraint::Length(3)])
+        .split(area);
+    draw_list(f, parts[0]);
+
+    let block = Block::default()
+        .title(" Filter (Tab complete, Enter apply, Esc cancel) ")
+        .borders(Borders::ALL)
+        .border_style(theme::FILTER_INPUT);
+    let p = Paragraph::new(format!("/{}_", input))
+        .style(theme::FILTER_INPUT)
+        .block(block);
+    f.render_widget(p, parts[1]);
+}
@@ -1,12 +1,19 @@
 use ratatui::layout::{Constraint, Direction, Layout, Rect};
+use ratatui::style::Style;
 use ratatui::text::{Line, Span};
-use ratatui::widgets::{Block, Borders, List, ListItem, ListState, Paragraph, Wrap};
+use ratatui::widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph, Wrap};
 use ratatui::Frame;
 
 use super::{filebrowser_view, theme};
 use super::util::truncate_chars;
-use crate::app::{App, GitMode, GitPane};
-use crate::model::git::{DiffLineKind, FlatGitItem, GitFileSection};
+use crate::app::{App, GitMode, GitPane, StashAction};
+use crate::data::git;
+use crate::model::git::{
+    DiffLine, DiffLineKind, FlatGitItem, GitFileSection, SplitDiffRow, WordSpan,
+};
+
+/// Narrowest diff pane (inner width) that still shows side by side.
+const MIN_SPLIT_WIDTH: u16 = 80;
 
 pub fn draw_git(f: &mut Frame, area: Rect, app: &App) {
     if app.git_mode == GitMode::Browse {
@@ -21,6 +28,10 @@ pub fn draw_git(f: &mut Frame, area: Rect, app: &App) {
 
     draw_file_list(f, chunks[0], app);
     draw_diff_pane(f, chunks[1], app);
+
+    if app.git_stash_input.is_some() || app.git_stash_confirm.is_some() {
+        draw_stash_prompt(f, area, app);
+    }
 }
 
 fn draw_file_list(f: &mut Frame, area: Rect, app: &App) {
@@ -80,6 +91,25 @@ fn draw_file_list(f: &mut Frame, area: Rect, app: &App) {
                 ]);
                 ListItem::new(line)
             }
+            FlatGitItem::StashHeader(label) => {
+                ListItem::new(Line::from(Span::styled(label.clone(), theme::GIT_STASH)))
+            }
+            FlatGitItem::Stash(stash) => {
+                let prefix = if i == app.git_file_index { ">" } else { " " };
+                let line = Line::from(vec![
+                    Span::raw(format!("{} ", prefix)),
+                    Span::styled(format!("{{{}}} ", stash.index), theme::GIT_STASH),
+                    Span::raw(&stash.message),
+                    Span::styled(
+                        format!(
+                            "  {}f +{} -{}",
+                            stash.files, stash.insertions, stash.deletions
+                        ),
+                        theme::EMPTY_STATE,
+                    ),
+                ]);
+                ListItem::new(line)
+            }
         })
         .collect();
 
@@ -101,11 +131,22 @@ fn draw_diff_pane(f: &mut Frame, area: Rect, app: &App) {
         theme::BORDER_INACTIVE
     };
 
-    // Title shows selected filename
-    let title = if let Some(FlatGitItem::File(entry)) = app.git_flat_list.get(app.git_file_index) {
-        format!(" {} ", entry.path)
-    } else {
-        " Diff ".to_string()
+    // Side by side only when both columns get a usable width
+    let split = app.git_diff_split && area.width.saturating_sub(2) >= MIN_SPLIT_WIDTH;
+
+    // Title shows selected filename or stash; [follow] while the diff
+    // reloads as files change
+    let mode = format!(
+        "{}{}",
+        if split { " [split]" } else { "" },
+        if app.follow_mode { " [follow]" } else { "" }
+    );
+    let title = match app.git_flat_list.get(app.git_file_index) {
+        Some(FlatGitItem::File(entry)) => format!(" {}{} ", entry.path, mode),
+        Some(FlatGitItem::Stash(stash)) => {
+            format!(" {}: {}{} ", stash.name(), stash.message, mode)
+        }
+        _ => " Diff ".to_string(),
     };
 
     let block = Block::default()
@@ -124,6 +165,11 @@ fn draw_diff_pane(f: &mut Frame, area: Rect, app: &App) {
     let inner = block.inner(area);
     f.render_widget(block, area);
 
+    if split {
+        draw_split_diff(f, inner, app);
+        return;
+    }
+
     let inner_height = inner.height as usize;
     let total = app.git_diff_lines.len();
 
@@ -133,13 +179,7 @@ fn draw_diff_pane(f: &mut Frame, area: Rect, app: &App) {
     let lines: Vec<Line> = app.git_diff_lines[scroll_offset..visible_end]
         .iter()
         .map(|dl| {
-            let style = match dl.kind {
-                DiffLineKind::Add => theme::DIFF_ADD,
-                DiffLineKind::Remove => theme::DIFF_REMOVE,
-                DiffLineKind::Hunk => theme::DIFF_HUNK,
-                DiffLineKind::Header => theme::DIFF_HEADER,
-                DiffLineKind::Context => theme::LIST_NORMAL,
-            };
+            let style = line_style(&dl.kind);
             // Truncate to available width
             let available = inner.width as usize;
             let text = truncate_chars(&dl.text, available);
@@ -150,3 +190,160 @@ fn draw_diff_pane(f: &mut Frame, area: Rect, app: &App) {
     let paragraph = Paragraph::new(lines);
     f.render_widget(paragraph, inner);
 }
+
+/// The stash message input, or the apply / pop / drop confirmation.
+fn draw_stash_prompt(f: &mut Frame, area: Rect, app: &App) {
+    let width = 60u16.min(area.width.saturating_sub(4));
+    let height = 6u16.min(area.height);
+    let popup_area = Rect {
+        x: area.x + (area.width.saturating_sub(width)) / 2,
+        y: area.y + (area.height.saturating_sub(height)) / 2,
+        width,
+        height,
+    };
+    f.render_widget(Clear, popup_area);
+
+    let key_hints = |keys: &[(&'static str, &'static str)]| {
+        let mut spans = vec![Span::raw(" ")];
+        for (key, desc) in keys {
+            spans.push(Span::styled(*key, theme::HELP_KEY));
+            spans.push(Span::raw(format!(" {}  ", desc)));
+        }
+        Line::from(spans)
+    };
+
+    let (title, lines, border) = if let Some(ref message) = app.git_stash_input {
+        let lines = vec![
+            Line::from(vec![
+                Span::styled(" Message: ", theme::HELP_DESC),
+                Span::raw(format!("{}_", message)),
+            ]),
+            Line::from(Span::styled(
+                " Optional. Untracked files are stashed too.",
+                theme::EMPTY_STATE,
+            )),
+            Line::from(""),
+            key_hints(&[("Enter", "stash"), ("Esc", "cancel")]),
+        ];
+        (" Stash changes ", lines, theme::BORDER_ACTIVE)
+    } else {
+        let (Some(action), Some(stash)) = (app.git_stash_confirm, app.selected_stash()) else {
+            return;
+        };
+        let (question, note) = match action {
+            StashAction::Apply => ("Apply", "Keeps the stash."),
+            StashAction::Pop => ("Pop", "Drops the stash once it applies cleanly."),
+            StashAction::Drop => ("Drop", "This can't be undone."),
+        };
+        let (style, border) = match action {
+            StashAction::Drop => (theme::DELETE_CONFIRM, theme::DELETE_CONFIRM_BORDER),
+            _ => (theme::HELP_TITLE, theme::BORDER_ACTIVE),
+        };
+        let lines = vec![
+            Line::from(Span::styled(
+                format!(" {} {}?", question, stash.name()),
+                style,
+            )),
+            Line::from(Span::raw(format!(" {}", stash.message))),
+            Line::from(Span::styled(format!(" {}", note), theme::HELP_DESC)),
+            key_hints(&[("y", "yes"), ("n", "no")]),
+        ];
+        (" Stash ", lines, border)
+    };
+
+    let block = Block::default()
+        .title(title)
+        .borders(Borders::ALL)
+        .border_style(border);
+    f.render_widget(Paragraph::new(lines).block(block), popup_area);
+}
+
+fn line_style(kind: &DiffLineKind) -> Style {
+    match kind {
+        DiffLineKind::Add => theme::DIFF_ADD,
+        DiffLineKind::Remove => theme::DIFF_REMOVE,
+        DiffLineKind::Hunk => theme::DIFF_HUNK,
+        DiffLineKind::Header => theme::DIFF_HEADER,
+        DiffLineKind::Context => theme::LIST_NORMAL,
+    }
+}
+
+fn draw_split_diff(f: &mut Frame, inner: Rect, app: &App) {
+    let rows = git::split_diff_rows(&app.git_diff_lines);
+    let inner_height = inner.height as usize;
+    let total = rows.len();
+
+    let scroll_offset = app.diff_scroll.min(total.saturating_sub(inner_height));
+    let visible_end = (scroll_offset + inner_height).min(total);
+
+    let full_width = inner.width as usize;
+    let column = full_width.saturating_sub(1) / 2;
+
+    let lines: Vec<Line> = rows[scroll_offset..visible_end]
+        .iter()
+        .map(|row| match row {
+            SplitDiffRow::Full(dl) => Line::from(Span::styled(
+                truncate_chars(&dl.text, full_width).to_string(),
+                line_style(&dl.kind),
+            )),
+            SplitDiffRow::Pair { old, new } => {
+                let (old_spans, new_spans) = match (old, new) {
+                    // A changed pair gets word-level highlighting
+                    (Some(o), Some(n)) if o.kind == DiffLineKind::Remove => {
+                        let (o_words, n_words) = git::word_diff(
+                            o.text.get(1..).unwrap_or(""),
+                            n.text.get(1..).unwrap_or(""),
+                        );
+                        (
+                            word_spans(&o_words, theme::DIFF_REMOVE, theme::DIFF_REMOVE_WORD),
+                            word_spans(&n_words, theme::DIFF_ADD, theme::DIFF_ADD_WORD),
+                        )
+                    }
+                    _ => (plain_spans(old.as_ref()), plain_spans(new.as_ref())),
+                };
+                let mut spans = fit_column(old_spans, column);
+                spans.push(Span::styled("\u{2502}", theme::DIFF_SPLIT_DIVIDER));
+                spans.extend(fit_column(new_spans, column));
+                Line::from(spans)
+            }
+        })
+        .collect();
+
+    f.render_widget(Paragraph::new(lines), inner);
+}
+
+/// One side of a pair without its +/-/space marker.
+fn plain_spans(line: Option<&DiffLine>) -> Vec<Span<'static>> {
+    match line {
+        Some(dl) => {
+            let text = dl.text.get(1..).unwrap_or("").to_string();
+            vec![Span::styled(text, line_style(&dl.kind))]
+        }
+        None => Vec::new(),
+    }
+}
+
+fn word_spans(words: &[WordSpan], base: Style, changed: Style) -> Vec<Span<'static>> {
+    words
+        .iter()
+        .map(|w| Span::styled(w.text.clone(), if w.changed { changed } else { base }))
+        .collect()
+}
+
+/// Truncate or pad spans to exactly `width` characters.
+fn fit_column(spans: Vec<Span<'static>>, width: usize) -> Vec<Span<'static>> {
+    let mut out = Vec::new();
+    let mut used = 0;
+    for span in spans {
+        if used >= width {
+            break;
+        }
+        let text = truncate_chars(&span.content, width - used).to_string();
+        used += text.chars().count();
+        out.push(Span::styled(text, span.style));
+    }
+    if used < width {
+        out.push(Span::raw(" ".repeat(width - used)));
+    }
+    out
+}
@@ -1,20 +1,22 @@
-use ratatui::layout::{Constraint, Direction, Layout, Rect};
+use chrono::{DateTime, Utc};
+use ratatui::layout::Rect;
 use ratatui::style::Modifier;
 use ratatui::text::{Line, Span};
-use ratatui::widgets::{Block, Borders, List, ListItem, ListState, Paragraph};
+use ratatui::widgets::{Block, Borders, Cell, Paragraph};
 use ratatui::Frame;
 
-use super::theme;
+use super::list_table::{self, TableRow};
+use super::util::{linked_sessions_lines, ticket_notes_lines, truncate_chars};
+use super::{empty_state, filter_bar, theme};
 use crate::app::{App, GitHubPane};
-use crate::model::github::FlatPrItem;
+use crate::model::github::{CheckOutcome, FlatPrItem, PullRequest, ReviewThread};
+use crate::model::list_table::{pr_labels, short_age, ListColumn};
+use crate::model::process::TicketSource;
 
 pub fn draw_github(f: &mut Frame, area: Rect, app: &App) {
-    let chunks = Layout::default()
-        .direction(Direction::Horizontal)
-        .constraints([Constraint::Percentage(40), Constraint::Percentage(60)])
-        .split(area);
+    let chunks = list_table::split(area);
 
-    draw_pr_list(f, chunks[0], app);
+    filter_bar::with_filter_input(f, chunks[0], app, |f, area| draw_pr_list(f, area, app));
     draw_pr_detail(f, chunks[1], app);
 }
 
@@ -26,65 +28,79 @@ fn draw_pr_list(f: &mut Frame, area: Rect, app: &App) {
         theme::BORDER_INACTIVE
     };
 
-    let title = format!(" Pull Requests [{}] ", app.gh_prs.len());
-    let block = Block::default()
+    let shown = app
+        .gh_flat_list
+        .iter()
+        .filter(|item| matches!(item, FlatPrItem::Pr(_)))
+        .count();
+    let label = match app.remote_label(app.gh_repo.as_ref()) {
+        Some(remote) => format!("Pull Requests ({})", remote),
+        None => "Pull Requests".to_string(),
+    };
+    let more = app.gh_prs_have_more();
+    let title = filter_bar::paged_list_title(&label, shown, app.gh_prs.len(), more, app);
+    let mut block = Block::default()
         .title(title)
         .borders(Borders::ALL)
         .border_style(border_style);
+    if let Some(footer) = filter_bar::paged_footer(app.gh_prs.len(), app.gh_pr_total, more) {
+        block = block.title_bottom(footer);
+    }
 
     if app.gh_flat_list.is_empty() {
-        let p = Paragraph::new("No open PRs")
-            .style(theme::EMPTY_STATE)
-            .block(block);
-        f.render_widget(p, area);
+        empty_state::draw_empty_state(f, area, block, "No open PRs", app);
         return;
     }
 
-    let items: Vec<ListItem> = app
+    let now = Utc::now();
+    let view = &app.gh_pr_table;
+    let rows: Vec<TableRow> = app
         .gh_flat_list
         .iter()
         .map(|item| match item {
-            FlatPrItem::SectionHeader(label) => {
-                ListItem::new(Line::from(Span::styled(label.clone(), theme::PR_SECTION)))
-            }
-            FlatPrItem::Pr(pr) => {
-                let icon = pr.review_icon();
-                let icon_style = match icon {
-                    "[+]" => theme::PR_APPROVED,
-                    "[!]" => theme::PR_CHANGES_REQUESTED,
-                    "[?]" => theme::PR_PENDING_REVIEW,
-                    _ => theme::LIST_NORMAL,
-                };
-
-                let size = pr.size_label();
-
-                let line = if pr.is_draft {
-                    Line::from(vec![
-                        Span::styled(format!("{} ", icon), theme::PR_DRAFT),
-                        Span::styled(format!("#{} {}  ", pr.number, pr.title), theme::PR_DRAFT),
-                        Span::styled(size, theme::PR_DRAFT),
-                    ])
-                } else {
-                    Line::from(vec![
-                        Span::styled(format!("{} ", icon), icon_style),
-                        Span::styled(format!("#{} {}  ", pr.number, pr.title), theme::LIST_NORMAL),
-                        Span::styled(size, theme::PR_SIZE),
-                    ])
-                };
-
-                ListItem::new(line)
-            }
+            FlatPrItem::SectionHeader(label) => TableRow::Section(label.clone(), theme::PR_SECTION),
+            FlatPrItem::Pr(pr) => TableRow::Item(
+                view.columns
+                    .iter()
+                    .map(|column| pr_cell(pr, *column, now))
+                    .collect(),
+            ),
         })
         .collect();
 
-    let mut state = ListState::default();
-    state.select(Some(app.gh_pr_index));
-
-    let list = List::new(items)
-        .block(block)
-        .highlight_style(theme::LIST_SELECTED);
+    list_table::draw_table(f, area, block, view, rows, app.gh_pr_index);
+}
 
-    f.render_stateful_widget(list, area, &mut state);
+/// One cell of a PR's row. Drafts are dimmed throughout.
+fn pr_cell(pr: &PullRequest, column: ListColumn, now: DateTime<Utc>) -> Cell<'static> {
+    let (text, style) = match column {
+        ListColumn::Review => {
+            let icon = pr.review_icon();
+            let style = match icon {
+                "[+]" => theme::PR_APPROVED,
+                "[!]" => theme::PR_CHANGES_REQUESTED,
+                "[?]" => theme::PR_PENDING_REVIEW,
+                _ => theme::LIST_NORMAL,
+            };
+            (icon.to_string(), style)
+        }
+        ListColumn::Ci => (pr.checks_icon().to_string(), checks_style(pr)),
+        ListColumn::State => {
+            let state = if pr.is_draft { "[D]" } else { "[O]" };
+            (state.to_string(), theme::LIST_NORMAL)
+        }
+        ListColumn::Number => (
+            format!("#{}", pr.number),
+            theme::LIST_NORMAL.add_modifier(Modifier::BOLD),
+        ),
+        ListColumn::Title => (pr.title.clone(), theme::LIST_NORMAL),
+        ListColumn::Author => (pr.author.login.clone(), theme::PR_AUTHOR),
+        ListColumn::Age => (short_age(&pr.created_at, now), theme::PR_AGE),
+        ListColumn::Size => (pr.size_label().to_string(), theme::PR_SIZE),
+        ListColumn::Labels => (pr_labels(pr), theme::ISSUE_LABEL),
+    };
+    let style = if pr.is_draft { theme::PR_DRAFT } else { style };
+    Cell::from(text).style(style)
 }
 
 fn draw_pr_detail(f: &mut Frame, area: Rect, app: &App) {
@@ -176,6 +192,23 @@ fn draw_pr_detail(f: &mut Frame, area: Rect, app: &App) {
         Span::styled(review_text, review_style),
     ]));
 
+    // Checks
+    let checks_text = match pr.checks_outcome() {
+        Some(CheckOutcome::Failure) => format!(
+            "{} {} of {} failing",
+            pr.checks_icon(),
+            pr.failing_checks().len(),
+            pr.status_check_rollup.len()
+        ),
+        Some(CheckOutcome::Pending) => format!("{} pending", pr.checks_icon()),
+        Some(_) => format!("{} passing", pr.checks_icon()),
+        None => "none".to_string(),
+    };
+    lines.push(Line::from(vec![
+        Span::styled("Checks: ", theme::LIST_NORMAL),
+        Span::styled(checks_text, checks_style(pr)),
+    ]));
+
     // Size
     lines.push(Line::from(vec![
         Span::styled(format!("Size: {} (", pr.size_label()), theme::LIST_NORMAL),
@@ -206,6 +239,14 @@ fn draw_pr_detail(f: &mut Frame, area: Rect, app: &App) {
         )));
     }
 
+    // Approvals
+    if !pr.approved_by.is_empty() {
+        lines.push(Line::from(vec![
+            Span::styled("Approved by: ", theme::LIST_NORMAL),
+            Span::styled(pr.approved_by.join(", "), theme::PR_APPROVED),
+        ]));
+    }
+
     // Assignees
     if !pr.assignees.is_empty() {
         let assignee_names: Vec<&str> = pr.assignees.iter().map(|a| a.login.as_str()).collect();
@@ -215,6 +256,51 @@ fn draw_pr_detail(f: &mut Frame, area: Rect, app: &App) {
         )));
     }
 
+    // Failing checks (c cycles selection, o opens the log)
+    let failing = pr.failing_checks();
+    if !failing.is_empty() {
+        lines.push(Line::from(""));
+        let label_style = theme::LIST_NORMAL.add_modifier(Modifier::BOLD);
+        lines.push(Line::from(Span::styled("Failing checks:", label_style)));
+        let selected = app.gh_check_index.min(failing.len() - 1);
+        for (i, check) in failing.iter().enumerate() {
+            let prefix = if i == selected { "> " } else { "  " };
+            lines.push(Line::from(vec![
+                Span::raw(prefix),
+                Span::styled("✗ ", theme::CHECK_FAIL),
+                Span::styled(check.display_name().to_string(), theme::LIST_NORMAL),
+            ]));
+            if let Some(url) = check.url() {
+                lines.push(Line::from(Span::styled(
+                    format!("    {}", url),
+                    theme::EMPTY_STATE,
+                )));
+            }
+        }
+    }
+
+    // Review threads, grouped by file (R expands resolved ones)
+    if !app.prs_from_bitbucket {
+        match app.gh_selected_review_threads() {
+            Some([]) => {}
+            Some(threads) => review_thread_lines(&mut lines, threads, app.gh_show_resolved_threads),
+            None => {
+                lines.push(Line::from(""));
+                lines.push(Line::from(Span::styled(
+                    "Review threads: loading...",
+                    theme::EMPTY_STATE,
+                )));
+            }
+        }
+    }
+
+    lines.extend(ticket_notes_lines(app, &pr.url));
+    lines.extend(linked_sessions_lines(
+        app,
+        &TicketSource::GitHubPR,
+        &format!("#{}", pr.number),
+    ));
+
     // Blank line
     lines.push(Line::from(""));
 
@@ -249,3 +335,99 @@ fn draw_pr_detail(f: &mut Frame, area: Rect, app: &App) {
     let paragraph = Paragraph::new(visible_lines);
     f.render_widget(paragraph, inner);
 }
+
+fn review_thread_lines(lines: &mut Vec<Line>, threads: &[ReviewThread], show_resolved: bool) {
+    let unresolved = threads.iter().filter(|t| !t.is_resolved).count();
+    lines.push(Line::from(""));
+    lines.push(Line::from(Span::styled(
+        format!(
+            "Review threads ({}, {} unresolved):",
+            threads.len(),
+            unresolved
+        ),
+        theme::LIST_NORMAL.add_modifier(Modifier::BOLD),
+    )));
+
+    let mut current_path = None;
+    for thread in threads {
+        if current_path != Some(thread.path.as_str()) {
+            current_path = Some(thread.path.as_str());
+            lines.push(Line::from(Span::styled(
+                format!("  {}", thread.path),
+                theme::REVIEW_THREAD_FILE,
+            )));
+        }
+        let place = match thread.display_line() {
+            Some(line) => format!("L{}", line),
+            None => "file".to_string(),
+        };
+        let outdated = if thread.is_outdated {
+            " (outdated)"
+        } else {
+            ""
+        };
+
+        if thread.is_resolved && !show_resolved {
+            // Collapsed to one line: who started it and how
+            let summary = thread
+                .comments
+                .first()
+                .map(|c| {
+                    let first_line = c.body.lines().next().unwrap_or("");
+                    format!("{}: {}", c.author, truncate_chars(first_line, 60))
+                })
+                .unwrap_or_default();
+            lines.push(Line::from(Span::styled(
+                format!(
+                    "    ✓ {} resolved{}, {} comment(s)  {}",
+                    place,
+                    outdated,
+                    thread.comments.len(),
+                    summary
+                ),
+                theme::REVIEW_THREAD_RESOLVED,
+            )));
+            continue;
+        }
+
+        let (marker, marker_style) = if thread.is_resolved {
+            ("✓ ", theme::REVIEW_THREAD_RESOLVED)
+        } else {
+            ("● ", theme::REVIEW_THREAD_OPEN)
+        };
+        lines.push(Line::from(vec![
+            Span::raw("    "),
+            Span::styled(marker, marker_style),
+            Span::styled(format!("{}{}", place, outdated), theme::LIST_NORMAL),
+        ]));
+        for comment in &thread.comments {
+            lines.push(Line::from(vec![
+                Span::styled(
+                    format!("      {} ", comment.author),
+                    theme::ISSUE_COMMENT_AUTHOR,
+                ),
+                Span::styled(comment.created_at.clone(), theme::EMPTY_STATE),
+            ]));
+            for body_line in comment.body.lines() {
+                lines.push(Line::from(format!("        {}", body_line)));
+            }
+        }
+    }
+
+    let hidden = threads.iter().filter(|t| t.is_resolved).count();
+    if hidden > 0 && !show_resolved {
+        lines.push(Line::from(Span::styled(
+            "  R expands resolved threads",
+            theme::EMPTY_STATE,
+        )));
+    }
+}
+
+fn checks_style(pr: &PullRequest) -> ratatui::style::Style {
+    match pr.checks_outcome() {
+        Some(CheckOutcome::Failure) => theme::CHECK_FAIL,
+        Some(CheckOutcome::Pending) => theme::CHECK_PENDING,
+        Some(_) => theme::CHECK_PASS,
+        None => theme::LIST_NORMAL,
+    }
+}
@@ -0,0 +1,66 @@
+use ratatui::layout::{Constraint, Direction, Layout, Rect};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph};
+use ratatui::Frame;
+
+use super::theme;
+use super::util::{centered_rect, truncate_chars};
+use crate::app::App;
+
+/// Draw the goto-anything overlay: the query line over the ranked results.
+pub fn draw_goto(f: &mut Frame, area: Rect, app: &App) {
+    let Some(ref goto) = app.goto else {
+        return;
+    };
+    let width = 90u16.min(area.width.saturating_sub(4));
+    let height = 24u16.min(area.height.saturating_sub(4));
+    let popup_area = centered_rect(area, width, height);
+    f.render_widget(Clear, popup_area);
+
+    let block = Block::default()
+        .title(" Goto anything ")
+        .title_bottom(" ↑/↓ move  Enter jump  Esc close ")
+        .borders(Borders::ALL)
+        .border_style(theme::PROMPT_MODAL_BORDER);
+    let inner = block.inner(popup_area);
+    f.render_widget(block, popup_area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(1), Constraint::Min(1)])
+        .split(inner);
+    let query = Line::from(vec![
+        Span::styled(" > ", theme::HELP_DESC),
+        Span::styled(format!("{}_", goto.query), theme::BRANCH_PICKER_INPUT),
+    ]);
+    f.render_widget(Paragraph::new(query), chunks[0]);
+
+    let results = app.goto_results();
+    if results.is_empty() {
+        let msg = if goto.query.trim().is_empty() {
+            "Sessions, plans, todos, PRs, issues, tickets, team members and files"
+        } else {
+            "No matches"
+        };
+        let p = Paragraph::new(Span::styled(format!(" {}", msg), theme::EMPTY_STATE));
+        f.render_widget(p, chunks[1]);
+        return;
+    }
+
+    // Room for the text after the source column, leaving some for the detail
+    let text_width = (chunks[1].width as usize).saturating_sub(10 + 20).max(10);
+    let items: Vec<ListItem> = results
+        .iter()
+        .map(|(source, entry)| {
+            ListItem::new(Line::from(vec![
+                Span::styled(format!(" {:<8}", source.label()), theme::ACTIVITY_SOURCE),
+                Span::raw(truncate_chars(&entry.text, text_width)),
+                Span::styled(format!("  {}", entry.detail), theme::HINT_DESC),
+            ]))
+        })
+        .collect();
+    let list = List::new(items).highlight_style(theme::LIST_SELECTED);
+    let mut state = ListState::default();
+    state.select(Some(goto.index));
+    f.render_stateful_widget(list, chunks[1], &mut state);
+}
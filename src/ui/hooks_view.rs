@@ -0,0 +1,274 @@
+use ratatui::layout::{Constraint, Direction, Layout, Rect};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph, Wrap};
+use ratatui::Frame;
+
+use super::theme;
+use crate::app::{App, HookField, HooksPane};
+use crate::model::hook::{event_description, event_takes_matcher, FlatHookItem};
+
+pub fn draw_hooks(f: &mut Frame, area: Rect, app: &App) {
+    let chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(40), Constraint::Percentage(60)])
+        .split(area);
+
+    draw_hook_list(f, chunks[0], app);
+    draw_hook_detail(f, chunks[1], app);
+    if app.hook_form.is_some() {
+        draw_hook_form(f, area, app);
+    }
+}
+
+fn draw_hook_list(f: &mut Frame, area: Rect, app: &App) {
+    let border_style = if app.hooks_pane == HooksPane::List {
+        theme::BORDER_ACTIVE
+    } else {
+        theme::BORDER_INACTIVE
+    };
+
+    let hooks: usize = app.hook_files.iter().map(|f| f.hooks.len()).sum();
+    let problems: usize = app.hook_files.iter().map(|f| f.problems.len()).sum();
+    let title = match problems {
+        0 => format!(" Hooks [{}] ", hooks),
+        1 => format!(" Hooks [{}] 1 problem ", hooks),
+        n => format!(" Hooks [{}] {} problems ", hooks, n),
+    };
+    let block = Block::default()
+        .title(title)
+        .borders(Borders::ALL)
+        .border_style(border_style);
+
+    if app.hook_flat_list.is_empty() {
+        let files: Vec<String> = app
+            .hook_files
+            .iter()
+            .map(|file| file.path.display().to_string())
+            .collect();
+        let msg = format!(
+            "No hooks configured in\n{}\n\nPress n to add one",
+            files.join("\n")
+        );
+        let p = Paragraph::new(msg)
+            .style(theme::EMPTY_STATE)
+            .block(block)
+            .wrap(Wrap { trim: false });
+        f.render_widget(p, area);
+        return;
+    }
+
+    let items: Vec<ListItem> = app
+        .hook_flat_list
+        .iter()
+        .map(|item| match item {
+            FlatHookItem::ProblemHeader => {
+                ListItem::new(Line::from(Span::styled("Problems", theme::CHECK_FAIL)))
+            }
+            FlatHookItem::Problem(scope, problem) => ListItem::new(Line::from(vec![
+                Span::styled(format!("  {} ", scope.tag()), theme::COMMAND_SCOPE),
+                Span::styled(problem.clone(), theme::CHECK_FAIL),
+            ])),
+            FlatHookItem::EventHeader(event) => {
+                ListItem::new(Line::from(Span::styled(event.clone(), theme::HOOK_EVENT)))
+            }
+            FlatHookItem::Hook(hook) => {
+                let mut spans = vec![Span::styled(
+                    format!("  {} ", hook.scope.tag()),
+                    theme::COMMAND_SCOPE,
+                )];
+                if let Some(ref matcher) = hook.matcher {
+                    spans.push(Span::styled(format!("{} ", matcher), theme::HOOK_MATCHER));
+                }
+                spans.push(Span::raw(
+                    hook.command.lines().next().unwrap_or("").to_string(),
+                ));
+                ListItem::new(Line::from(spans))
+            }
+        })
+        .collect();
+
+    let mut state = ListState::default();
+    state.select(Some(app.hook_index));
+
+    let list = List::new(items)
+        .block(block)
+        .highlight_style(theme::LIST_SELECTED);
+    f.render_stateful_widget(list, area, &mut state);
+}
+
+fn draw_hook_detail(f: &mut Frame, area: Rect, app: &App) {
+    let border_style = if app.hooks_pane == HooksPane::Detail {
+        theme::BORDER_ACTIVE
+    } else {
+        theme::BORDER_INACTIVE
+    };
+    let field = |name: &str, value: String| {
+        Line::from(vec![
+            Span::styled(format!("{}: ", name), theme::HELP_DESC),
+            Span::raw(value),
+        ])
+    };
+
+    let (title, lines) = match app.selected_hook_item() {
+        Some(FlatHookItem::Hook(hook)) => {
+            let mut lines = vec![
+                field("Event", hook.event.clone()),
+                Line::from(Span::styled(
+                    event_description(&hook.event),
+                    theme::EMPTY_STATE,
+                )),
+                Line::from(""),
+                field(
+                    "Scope",
+                    format!(
+                        "{} ({})",
+                        hook.scope.label(),
+                        app.hook_settings_path(hook.scope).display()
+                    ),
+                ),
+                field(
+                    "Matcher",
+                    hook.matcher.clone().unwrap_or_else(|| "(all)".to_string()),
+                ),
+                field("Type", hook.kind.clone()),
+                field(
+                    "Timeout",
+                    hook.timeout
+                        .map(|t| format!("{}s", t))
+                        .unwrap_or_else(|| "default".to_string()),
+                ),
+                Line::from(""),
+                Line::from(Span::styled(
+                    if hook.kind == "prompt" {
+                        "Prompt"
+                    } else {
+                        "Command"
+                    },
+                    theme::HELP_DESC,
+                )),
+            ];
+            lines.extend(hook.command.lines().map(|l| Line::from(l.to_string())));
+            (format!(" {} ", hook.event), lines)
+        }
+        Some(FlatHookItem::Problem(scope, problem)) => {
+            let lines = vec![
+                field("File", app.hook_settings_path(*scope).display().to_string()),
+                Line::from(""),
+                Line::from(Span::styled(problem.clone(), theme::CHECK_FAIL)),
+                Line::from(""),
+                Line::from(Span::styled(
+                    "Claude Code skips hooks it can't read. Fix the file, then press r.",
+                    theme::EMPTY_STATE,
+                )),
+            ];
+            (" Problem ".to_string(), lines)
+        }
+        _ => (
+            " Detail ".to_string(),
+            vec![Line::from(Span::styled(
+                "Select a hook to see its details",
+                theme::EMPTY_STATE,
+            ))],
+        ),
+    };
+
+    let block = Block::default()
+        .title(title)
+        .borders(Borders::ALL)
+        .border_style(border_style);
+    let inner_height = area.height.saturating_sub(2) as usize;
+    let scroll = app
+        .hook_detail_scroll
+        .min(lines.len().saturating_sub(inner_height));
+    let p = Paragraph::new(lines)
+        .block(block)
+        .wrap(Wrap { trim: false })
+        .scroll((scroll.min(u16::MAX as usize) as u16, 0));
+    f.render_widget(p, area);
+}
+
+/// Popup form: scope and event are picked with Left/Right, the rest typed.
+fn draw_hook_form(f: &mut Frame, area: Rect, app: &App) {
+    let Some(ref form) = app.hook_form else {
+        return;
+    };
+    let width = 80u16.min(area.width.saturating_sub(4));
+    let height = 10u16.min(area.height);
+    let popup_area = Rect {
+        x: area.x + (area.width.saturating_sub(width)) / 2,
+        y: area.y + (area.height.saturating_sub(height)) / 2,
+        width,
+        height,
+    };
+    f.render_widget(Clear, popup_area);
+
+    let command_label = if form.kind == "prompt" {
+        "Prompt"
+    } else {
+        "Command"
+    };
+    let mut lines = Vec::new();
+    for field in HookField::ALL {
+        let selected = form.field == field;
+        let value = match field {
+            HookField::Scope => format!(
+                "◀ {} ▶  {}",
+                form.scope.label(),
+                app.hook_settings_path(form.scope).display()
+            ),
+            HookField::Event => format!("◀ {} ▶", form.event),
+            HookField::Matcher => form.matcher.clone(),
+            HookField::Command => form.command.clone(),
+            HookField::Timeout => form.timeout.clone(),
+        };
+        let cursor = match field {
+            HookField::Scope | HookField::Event => "",
+            _ if selected => "_",
+            _ => "",
+        };
+        let label = match field {
+            HookField::Command => command_label,
+            _ => field.label(),
+        };
+        let label_style = if selected {
+            theme::HELP_KEY
+        } else {
+            theme::HELP_DESC
+        };
+        lines.push(Line::from(vec![
+            Span::styled(format!(" {:<9}", label), label_style),
+            Span::styled(format!("{}{}", value, cursor), theme::BRANCH_PICKER_INPUT),
+        ]));
+    }
+
+    let note = match form.field {
+        HookField::Scope => "Left/Right: project, local (not committed) or user settings",
+        HookField::Event => event_description(&form.event),
+        HookField::Matcher if event_takes_matcher(&form.event) => {
+            "Tool name or regex, e.g. Bash or Edit|Write. Empty matches everything"
+        }
+        HookField::Matcher => "This event has no matcher; leave it empty",
+        HookField::Command => "Runs in the project directory with the event as JSON on stdin",
+        HookField::Timeout => "Seconds before the hook is stopped. Empty uses the default (60)",
+    };
+    lines.push(Line::from(""));
+    lines.push(Line::from(Span::styled(
+        format!(" {}", note),
+        theme::EMPTY_STATE,
+    )));
+
+    let title = if form.editing.is_some() {
+        " Edit hook "
+    } else {
+        " New hook "
+    };
+    let block = Block::default()
+        .title(title)
+        .title_bottom(" Tab next field  ←/→ choose  Enter save  Esc cancel ")
+        .borders(Borders::ALL)
+        .border_style(theme::BORDER_ACTIVE);
+    let p = Paragraph::new(lines)
+        .block(block)
+        .wrap(Wrap { trim: false });
+    f.render_widget(p, popup_area);
+}
@@ -1,25 +1,42 @@
+use chrono::{DateTime, Utc};
 use ratatui::layout::{Constraint, Direction, Layout, Rect};
 use ratatui::style::Modifier;
 use ratatui::text::{Line, Span};
-use ratatui::widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph, Wrap};
+use ratatui::widgets::{Block, Borders, Cell, Clear, List, ListItem, ListState, Paragraph, Wrap};
 use ratatui::Frame;
 
-use super::theme;
-use crate::app::{App, IssueEditField, IssueEditMode, IssuesPane};
-use crate::model::github::FlatIssueItem;
+use super::list_table::{self, TableRow};
+use super::util::{centered_rect, linked_sessions_lines, ticket_notes_lines};
+use super::{empty_state, filter_bar, theme, trend_chart};
+use crate::app::{App, IssueConflict, IssueEditField, IssueEditMode, IssueMetaPicker, IssuesPane};
+use crate::model::github::{FlatIssueItem, GitHubIssue, IssueMetaField, IssueTemplate};
+use crate::model::list_table::{issue_labels, short_age, ListColumn};
+use crate::model::merge::MergeChunk;
+use crate::model::process::TicketSource;
+use crate::model::snapshot;
 
 pub fn draw_issues(f: &mut Frame, area: Rect, app: &App) {
-    let chunks = Layout::default()
-        .direction(Direction::Horizontal)
-        .constraints([Constraint::Percentage(40), Constraint::Percentage(60)])
-        .split(area);
+    let body = trend_chart::draw_trend_header(f, area, app, snapshot::TRACKER_GITHUB_ISSUES);
+    let chunks = list_table::split(body);
 
-    draw_issue_list(f, chunks[0], app);
+    filter_bar::with_filter_input(f, chunks[0], app, |f, area| draw_issue_list(f, area, app));
     draw_issue_detail(f, chunks[1], app);
 
     if app.gh_issues_editing {
         draw_edit_popup(f, area, app);
     }
+
+    if let Some(ref conflict) = app.gh_issues_conflict {
+        draw_conflict_popup(f, area, conflict);
+    }
+
+    if let Some(ref picker) = app.gh_issues_picker {
+        draw_meta_picker(f, area, picker);
+    }
+
+    if let Some(index) = app.gh_issues_template_picker {
+        draw_template_picker(f, area, &app.gh_issue_templates, index);
+    }
 }
 
 fn draw_issue_list(f: &mut Frame, area: Rect, app: &App) {
@@ -30,21 +47,34 @@ fn draw_issue_list(f: &mut Frame, area: Rect, app: &App) {
         theme::BORDER_INACTIVE
     };
 
-    let title = format!(" Issues [{}] ", app.gh_issues.len());
-    let block = Block::default()
+    let shown = app
+        .gh_issues_flat_list
+        .iter()
+        .filter(|item| matches!(item, FlatIssueItem::Issue(_)))
+        .count();
+    let label = match app.remote_label(app.gh_issues_repo.as_ref()) {
+        Some(remote) => format!("Issues ({})", remote),
+        None => "Issues".to_string(),
+    };
+    let more = app.gh_issues_have_more();
+    let title = filter_bar::paged_list_title(&label, shown, app.gh_issues.len(), more, app);
+    let mut block = Block::default()
         .title(title)
         .borders(Borders::ALL)
         .border_style(border_style);
+    let footer = filter_bar::paged_footer(app.gh_issues.len(), app.gh_issues_total, more);
+    if let Some(footer) = footer {
+        block = block.title_bottom(footer);
+    }
 
     if app.gh_issues_flat_list.is_empty() {
-        let p = Paragraph::new("No issues found")
-            .style(theme::EMPTY_STATE)
-            .block(block);
-        f.render_widget(p, area);
+        empty_state::draw_empty_state(f, area, block, "No issues found", app);
         return;
     }
 
-    let items: Vec<ListItem> = app
+    let now = Utc::now();
+    let view = &app.gh_issues_table;
+    let rows: Vec<TableRow> = app
         .gh_issues_flat_list
         .iter()
         .map(|item| match item {
@@ -54,61 +84,58 @@ fn draw_issue_list(f: &mut Frame, area: Rect, app: &App) {
                 } else {
                     theme::ISSUE_SECTION
                 };
-                ListItem::new(Line::from(Span::styled(label.clone(), style)))
+                TableRow::Section(label.clone(), style)
             }
             FlatIssueItem::Issue(issue) => {
                 let is_current = app.is_current_github_issue(issue.number);
-
-                let icon = issue.state_icon();
-                let icon_style = if is_current {
-                    theme::CURRENT_ISSUE
-                } else if issue.state == "OPEN" {
-                    theme::ISSUE_OPEN
-                } else {
-                    theme::ISSUE_CLOSED
-                };
-
-                let text_style = if is_current {
-                    theme::CURRENT_ISSUE
-                } else {
-                    theme::LIST_NORMAL
-                };
-
-                let mut spans = vec![
-                    Span::styled(format!("{} ", icon), icon_style),
-                    Span::styled(
-                        format!("#{} ", issue.number),
-                        text_style.add_modifier(Modifier::BOLD),
-                    ),
-                    Span::styled(&issue.title, text_style),
-                ];
-
-                if !issue.labels.is_empty() {
-                    let label_text: Vec<&str> =
-                        issue.labels.iter().map(|l| l.name.as_str()).collect();
-                    spans.push(Span::styled(
-                        format!("  [{}]", label_text.join(",")),
-                        if is_current {
-                            theme::CURRENT_ISSUE
-                        } else {
-                            theme::ISSUE_LABEL
-                        },
-                    ));
-                }
-
-                ListItem::new(Line::from(spans))
+                TableRow::Item(
+                    view.columns
+                        .iter()
+                        .map(|column| issue_cell(issue, *column, is_current, now))
+                        .collect(),
+                )
             }
         })
         .collect();
 
-    let mut state = ListState::default();
-    state.select(Some(app.gh_issues_index));
-
-    let list = List::new(items)
-        .block(block)
-        .highlight_style(theme::LIST_SELECTED);
+    list_table::draw_table(f, area, block, view, rows, app.gh_issues_index);
+}
 
-    f.render_stateful_widget(list, area, &mut state);
+/// One cell of an issue's row. The current issue is highlighted throughout;
+/// PR-only columns stay empty.
+fn issue_cell(
+    issue: &GitHubIssue,
+    column: ListColumn,
+    is_current: bool,
+    now: DateTime<Utc>,
+) -> Cell<'static> {
+    let (text, style) = match column {
+        ListColumn::State => {
+            let style = if issue.state == "OPEN" {
+                theme::ISSUE_OPEN
+            } else {
+                theme::ISSUE_CLOSED
+            };
+            (issue.state_icon().to_string(), style)
+        }
+        ListColumn::Number => (
+            format!("#{}", issue.number),
+            theme::LIST_NORMAL.add_modifier(Modifier::BOLD),
+        ),
+        ListColumn::Title => (issue.title.clone(), theme::LIST_NORMAL),
+        ListColumn::Author => (issue.author.login.clone(), theme::PR_AUTHOR),
+        ListColumn::Age => (short_age(&issue.created_at, now), theme::PR_AGE),
+        ListColumn::Labels => (issue_labels(issue), theme::ISSUE_LABEL),
+        ListColumn::Review | ListColumn::Ci | ListColumn::Size => {
+            (String::new(), theme::LIST_NORMAL)
+        }
+    };
+    let style = if is_current {
+        theme::CURRENT_ISSUE
+    } else {
+        style
+    };
+    Cell::from(text).style(style)
 }
 
 fn draw_issue_detail(f: &mut Frame, area: Rect, app: &App) {
@@ -253,6 +280,13 @@ fn draw_issue_detail(f: &mut Frame, area: Rect, app: &App) {
         }
     }
 
+    lines.extend(ticket_notes_lines(app, &issue.url));
+    lines.extend(linked_sessions_lines(
+        app,
+        &TicketSource::GitHubIssue,
+        &format!("#{}", issue.number),
+    ));
+
     // URL
     lines.push(Line::from(""));
     lines.push(Line::from(vec![
@@ -280,30 +314,15 @@ fn draw_edit_popup(f: &mut Frame, area: Rect, app: &App) {
     let width = 70u16.min(area.width.saturating_sub(6));
     let height = 24u16.min(area.height.saturating_sub(4));
 
-    let vert = Layout::default()
-        .direction(Direction::Vertical)
-        .constraints([
-            Constraint::Length((area.height - height) / 2),
-            Constraint::Length(height),
-            Constraint::Min(0),
-        ])
-        .split(area);
-
-    let horiz = Layout::default()
-        .direction(Direction::Horizontal)
-        .constraints([
-            Constraint::Length((area.width - width) / 2),
-            Constraint::Length(width),
-            Constraint::Min(0),
-        ])
-        .split(vert[1]);
-
-    let popup_area = horiz[1];
+    let popup_area = centered_rect(area, width, height);
     f.render_widget(Clear, popup_area);
 
     let dynamic_title = match &app.gh_issues_edit_mode {
         Some(IssueEditMode::Edit(n)) => format!(" Edit Issue #{} ", n),
         Some(IssueEditMode::Comment(n)) => format!(" Comment on #{} ", n),
+        Some(IssueEditMode::Create) if !app.gh_issues_create_labels.is_empty() => {
+            format!(" New Issue [{}] ", app.gh_issues_create_labels.join(", "))
+        }
         Some(IssueEditMode::Create) => " New Issue ".to_string(),
         None => " Editor ".to_string(),
     };
@@ -399,3 +418,171 @@ fn draw_edit_popup(f: &mut Frame, area: Rect, app: &App) {
         f.render_widget(Paragraph::new(hint), chunks[2]);
     }
 }
+
+/// What changed on GitHub since editing started, against my edits: `-`
+/// lines are mine, `+` lines upstream's.
+fn draw_conflict_popup(f: &mut Frame, area: Rect, conflict: &IssueConflict) {
+    let width = (area.width * 4 / 5).max(20);
+    let height = (area.height * 4 / 5).max(8);
+    let popup_area = centered_rect(area, width, height);
+    f.render_widget(Clear, popup_area);
+
+    let block = Block::default()
+        .title(format!(
+            " Issue #{} changed on GitHub since you opened it ",
+            conflict.number
+        ))
+        .title_bottom(" o overwrite  m merge by hand  c post mine as comment  Esc back ")
+        .borders(Borders::ALL)
+        .border_style(theme::FB_EDIT_BORDER);
+
+    let mut lines: Vec<Line> = Vec::new();
+    if conflict.title != conflict.upstream.title {
+        let mut header = vec![Span::styled("Title", theme::DIFF_HUNK)];
+        if conflict.title_conflict {
+            header.push(Span::styled(" conflict", theme::MERGE_REJECT));
+        }
+        lines.push(Line::from(header));
+        lines.push(Line::from(Span::styled(
+            format!("  - {}", conflict.title),
+            theme::DIFF_REMOVE,
+        )));
+        lines.push(Line::from(Span::styled(
+            format!("  + {}", conflict.upstream.title),
+            theme::DIFF_ADD,
+        )));
+        lines.push(Line::from(""));
+    }
+
+    let mut change = 0;
+    for chunk in &conflict.chunks {
+        match chunk {
+            MergeChunk::Stable(stable) => lines.push(Line::from(Span::styled(
+                format!("  ({} unchanged line(s))", stable.len()),
+                theme::EMPTY_STATE,
+            ))),
+            MergeChunk::Changed { mine, theirs, .. } => {
+                change += 1;
+                let mut header = vec![Span::styled(format!("Change {}", change), theme::DIFF_HUNK)];
+                if chunk.is_conflict() {
+                    header.push(Span::styled(" conflict", theme::MERGE_REJECT));
+                } else if mine == theirs {
+                    header.push(Span::styled(" same on both sides", theme::EMPTY_STATE));
+                }
+                lines.push(Line::from(header));
+                for l in mine {
+                    lines.push(Line::from(Span::styled(
+                        format!("  - {}", l),
+                        theme::DIFF_REMOVE,
+                    )));
+                }
+                for l in theirs {
+                    lines.push(Line::from(Span::styled(
+                        format!("  + {}", l),
+                        theme::DIFF_ADD,
+                    )));
+                }
+            }
+        }
+    }
+    if change == 0 {
+        lines.push(Line::from(Span::styled(
+            "Body unchanged on both sides",
+            theme::EMPTY_STATE,
+        )));
+    }
+
+    let max_scroll = lines.len().saturating_sub(1);
+    let paragraph = Paragraph::new(lines)
+        .block(block)
+        .scroll((conflict.scroll.min(max_scroll) as u16, 0));
+    f.render_widget(paragraph, popup_area);
+}
+
+fn draw_meta_picker(f: &mut Frame, area: Rect, picker: &IssueMetaPicker) {
+    let rows = picker.options.as_ref().map_or(1, |o| o.len().max(1)) as u16;
+    let width = 50u16.min(area.width.saturating_sub(4));
+    let height = (rows + 4).min(area.height.saturating_sub(4));
+    let popup_area = Rect {
+        x: area.x + (area.width.saturating_sub(width)) / 2,
+        y: area.y + (area.height.saturating_sub(height)) / 2,
+        width,
+        height,
+    };
+
+    f.render_widget(Clear, popup_area);
+
+    let block = Block::default()
+        .title(format!(" {} for #{} ", picker.field.title(), picker.number))
+        .title_bottom(" Space toggle  Enter apply  Esc cancel ")
+        .borders(Borders::ALL)
+        .border_style(theme::HELP_TITLE)
+        .style(theme::JIRA_TRANSITION_POPUP);
+
+    let Some(ref options) = picker.options else {
+        let p = Paragraph::new("Loading...")
+            .style(theme::EMPTY_STATE)
+            .block(block);
+        f.render_widget(p, popup_area);
+        return;
+    };
+
+    let single = picker.field == IssueMetaField::Milestone;
+    let items: Vec<ListItem> = options
+        .iter()
+        .map(|option| {
+            let on = picker.checked.contains(option);
+            let mark = match (single, on) {
+                (true, true) => "(*)",
+                (true, false) => "( )",
+                (false, true) => "[x]",
+                (false, false) => "[ ]",
+            };
+            ListItem::new(Line::from(format!(" {} {}", mark, option)))
+        })
+        .collect();
+
+    let mut state = ListState::default();
+    state.select(Some(picker.index));
+    let list = List::new(items)
+        .block(block)
+        .highlight_style(theme::LIST_SELECTED);
+    f.render_stateful_widget(list, popup_area, &mut state);
+}
+
+/// Templates to start a new issue from, then a blank issue.
+fn draw_template_picker(f: &mut Frame, area: Rect, templates: &[IssueTemplate], index: usize) {
+    let width = 60u16.min(area.width.saturating_sub(4));
+    let height = (templates.len() as u16 + 3).min(area.height.saturating_sub(4));
+    let popup_area = centered_rect(area, width, height);
+    f.render_widget(Clear, popup_area);
+
+    let block = Block::default()
+        .title(" New issue from template ")
+        .title_bottom(" Enter choose  Esc cancel ")
+        .borders(Borders::ALL)
+        .border_style(theme::HELP_TITLE)
+        .style(theme::JIRA_TRANSITION_POPUP);
+
+    let mut items: Vec<ListItem> = templates
+        .iter()
+        .map(|template| {
+            let mut spans = vec![Span::raw(format!(" {}", template.name))];
+            if let Some(ref about) = template.about {
+                spans.push(Span::styled(format!("  {}", about), theme::HINT_DESC));
+            }
+            ListItem::new(Line::from(spans))
+        })
+        .collect();
+    items.push(ListItem::new(Line::from(Span::styled(
+        " Blank issue",
+        theme::EMPTY_STATE,
+    ))));
+
+    let mut state = ListState::default();
+    state.select(Some(index));
+    let list = List::new(items)
+        .block(block)
+        .highlight_style(theme::LIST_SELECTED);
+    f.render_stateful_widget(list, popup_area, &mut state);
+}
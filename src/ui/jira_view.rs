@@ -4,19 +4,41 @@ use ratatui::text::{Line, Span};
 use ratatui::widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph, Wrap};
 use ratatui::Frame;
 
-use super::theme;
+use super::util::{centered_rect, format_size, linked_sessions_lines, ticket_notes_lines};
+use super::{empty_state, filter_bar, theme, trend_chart};
 use crate::app::{App, JiraPane};
-use crate::model::jira::FlatJiraItem;
+use crate::model::jira::{FlatJiraItem, JiraIssue, BOARD_COLUMNS};
+use crate::model::process::TicketSource;
+use crate::model::snapshot;
 
 pub fn draw_jira(f: &mut Frame, area: Rect, app: &App) {
+    let body = trend_chart::draw_trend_header(f, area, app, snapshot::TRACKER_JIRA);
+    if app.jira_board_mode {
+        draw_board(f, body, app);
+        if app.jira_pane == JiraPane::Detail {
+            let popup = Rect {
+                x: body.x + body.width / 8,
+                y: body.y + 1,
+                width: body.width - body.width / 4,
+                height: body.height.saturating_sub(2),
+            };
+            f.render_widget(Clear, popup);
+            draw_detail_pane(f, popup, app);
+        }
+        return;
+    }
+
     let chunks = Layout::default()
         .direction(Direction::Horizontal)
         .constraints([Constraint::Percentage(40), Constraint::Percentage(60)])
-        .split(area);
+        .split(body);
 
-    draw_issue_list(f, chunks[0], app);
+    filter_bar::with_filter_input(f, chunks[0], app, |f, area| draw_issue_list(f, area, app));
     draw_detail_pane(f, chunks[1], app);
 
+    if app.show_jira_filter_picker {
+        draw_filter_picker(f, area, app);
+    }
     if app.jira_show_transitions {
         draw_transition_popup(f, area, app);
     }
@@ -30,29 +52,23 @@ fn draw_issue_list(f: &mut Frame, area: Rect, app: &App) {
         theme::BORDER_INACTIVE
     };
 
-    // If search mode is active, split vertically to show search input at bottom
-    let (list_area, search_area) = if app.jira_search_mode {
-        let parts = Layout::default()
-            .direction(Direction::Vertical)
-            .constraints([Constraint::Min(1), Constraint::Length(3)])
-            .split(area);
-        (parts[0], Some(parts[1]))
-    } else {
-        (area, None)
+    let shown = app
+        .jira_flat_list
+        .iter()
+        .filter(|item| matches!(item, FlatJiraItem::Issue(_)))
+        .count();
+    let label = match app.jira_filter {
+        Some(ref name) => format!("Issues filter:{}", name),
+        None => "Issues".to_string(),
     };
-
-    let title = format!(" Issues [{}] ", app.jira_issues.len());
+    let title = filter_bar::list_title(&label, shown, app.jira_issues.len(), app);
     let block = Block::default()
         .title(title)
         .borders(Borders::ALL)
         .border_style(border_style);
 
     if app.jira_flat_list.is_empty() {
-        let p = Paragraph::new("No issues found")
-            .style(theme::EMPTY_STATE)
-            .block(block)
-            .wrap(Wrap { trim: false });
-        f.render_widget(p, list_area);
+        empty_state::draw_empty_state(f, area, block, "No issues found", app);
     } else {
         let items: Vec<ListItem> = app
             .jira_flat_list
@@ -108,24 +124,83 @@ fn draw_issue_list(f: &mut Frame, area: Rect, app: &App) {
             .block(block)
             .highlight_style(theme::LIST_SELECTED);
 
-        f.render_stateful_widget(list, list_area, &mut state);
+        f.render_stateful_widget(list, area, &mut state);
     }
+}
+
+/// Sprint board: one column per status category.
+fn draw_board(f: &mut Frame, area: Rect, app: &App) {
+    let columns = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Ratio(1, BOARD_COLUMNS.len() as u32); BOARD_COLUMNS.len()])
+        .split(area);
 
-    // Draw search input if active
-    if let Some(search_area) = search_area {
-        let search_block = Block::default()
-            .title(" Search (Enter to search, Esc to cancel) ")
+    for (col, name) in BOARD_COLUMNS.iter().enumerate() {
+        let cards = &app.jira_board_columns[col];
+        let is_active = app.jira_pane == JiraPane::List && app.jira_board_column == col;
+        let border_style = if is_active {
+            theme::BORDER_ACTIVE
+        } else {
+            theme::BORDER_INACTIVE
+        };
+        let title_style = match col {
+            1 => theme::JIRA_IN_PROGRESS,
+            2 => theme::JIRA_DONE,
+            _ => theme::JIRA_TODO,
+        };
+        let block = Block::default()
+            .title(Span::styled(
+                format!(" {} [{}] ", name, cards.len()),
+                title_style,
+            ))
             .borders(Borders::ALL)
-            .border_style(theme::JIRA_SEARCH_INPUT);
+            .border_style(border_style);
+
+        if cards.is_empty() {
+            let msg = if app.jira_board_loading {
+                "Loading..."
+            } else {
+                "No issues"
+            };
+            let p = Paragraph::new(msg).style(theme::EMPTY_STATE).block(block);
+            f.render_widget(p, columns[col]);
+            continue;
+        }
 
-        let search_text = format!("> {}_", app.jira_search_input);
-        let p = Paragraph::new(search_text)
-            .style(theme::JIRA_SEARCH_INPUT)
-            .block(search_block);
-        f.render_widget(p, search_area);
+        let items: Vec<ListItem> = cards.iter().map(|issue| board_card(app, issue)).collect();
+        let mut state = ListState::default();
+        if is_active {
+            state.select(Some(app.jira_board_rows[col]));
+        }
+        let list = List::new(items)
+            .block(block)
+            .highlight_style(theme::LIST_SELECTED);
+        f.render_stateful_widget(list, columns[col], &mut state);
     }
 }
 
+fn board_card<'a>(app: &App, issue: &'a JiraIssue) -> ListItem<'a> {
+    let text_style = if app.is_current_jira_issue(&issue.key) {
+        theme::CURRENT_ISSUE
+    } else {
+        theme::LIST_NORMAL
+    };
+    let type_style = match issue.issue_type.to_lowercase().as_str() {
+        "bug" => theme::JIRA_BUG,
+        "story" => theme::JIRA_STORY,
+        "task" => theme::JIRA_TASK,
+        _ => theme::LIST_NORMAL,
+    };
+    ListItem::new(vec![
+        Line::from(vec![
+            Span::styled(format!("[{}] ", issue.type_icon()), type_style),
+            Span::styled(&issue.key, text_style.add_modifier(Modifier::BOLD)),
+            Span::styled(format!("  {}", issue.status_name), theme::JIRA_BOARD_STATUS),
+        ]),
+        Line::from(Span::styled(format!("  {}", issue.summary), text_style)),
+    ])
+}
+
 fn draw_detail_pane(f: &mut Frame, area: Rect, app: &App) {
     let is_active = app.jira_pane == JiraPane::Detail;
     let border_style = if is_active {
@@ -233,6 +308,62 @@ fn draw_detail_pane(f: &mut Frame, area: Rect, app: &App) {
         }
     }
 
+    // Attachments and web links; `c` moves the marker and `o` opens it
+    let mut resource = 0;
+    let mut marker = |lines: &mut Vec<Line>, spans: Vec<Span<'static>>| {
+        let selected = is_active && resource == app.jira_resource_index;
+        resource += 1;
+        let mut line = vec![Span::raw(if selected { "▸ " } else { "  " })];
+        line.extend(spans);
+        let line = Line::from(line);
+        lines.push(if selected {
+            line.style(theme::LIST_SELECTED)
+        } else {
+            line
+        });
+    };
+    if !detail.attachments.is_empty() {
+        lines.push(Line::from(""));
+        lines.push(Line::from(Span::styled(
+            "Attachments:",
+            theme::LIST_NORMAL.add_modifier(Modifier::BOLD),
+        )));
+        for attachment in &detail.attachments {
+            marker(
+                &mut lines,
+                vec![
+                    Span::raw(attachment.filename.clone()),
+                    Span::styled(
+                        match attachment.mime_type.as_str() {
+                            "" => format!("  {}", format_size(attachment.size)),
+                            mime => format!("  {}, {}", format_size(attachment.size), mime),
+                        },
+                        theme::EMPTY_STATE,
+                    ),
+                ],
+            );
+        }
+    }
+    if !detail.web_links.is_empty() {
+        lines.push(Line::from(""));
+        lines.push(Line::from(Span::styled(
+            "Web links:",
+            theme::LIST_NORMAL.add_modifier(Modifier::BOLD),
+        )));
+        for link in &detail.web_links {
+            marker(
+                &mut lines,
+                vec![
+                    Span::raw(link.title.clone()),
+                    Span::styled(format!("  {}", link.url), theme::EMPTY_STATE),
+                ],
+            );
+        }
+    }
+
+    lines.extend(ticket_notes_lines(app, &detail.url));
+    lines.extend(linked_sessions_lines(app, &TicketSource::Jira, &detail.key));
+
     // Blank line + URL
     if !detail.url.is_empty() {
         lines.push(Line::from(""));
@@ -259,25 +390,7 @@ fn draw_transition_popup(f: &mut Frame, area: Rect, app: &App) {
     let width = 40u16.min(area.width.saturating_sub(4));
     let height = (app.jira_transitions.len() as u16 + 4).min(area.height.saturating_sub(4));
 
-    let vert = Layout::default()
-        .direction(Direction::Vertical)
-        .constraints([
-            Constraint::Length((area.height - height) / 2),
-            Constraint::Length(height),
-            Constraint::Min(0),
-        ])
-        .split(area);
-
-    let horiz = Layout::default()
-        .direction(Direction::Horizontal)
-        .constraints([
-            Constraint::Length((area.width - width) / 2),
-            Constraint::Length(width),
-            Constraint::Min(0),
-        ])
-        .split(vert[1]);
-
-    let popup_area = horiz[1];
+    let popup_area = centered_rect(area, width, height);
 
     // Clear background behind popup
     f.render_widget(Clear, popup_area);
@@ -298,3 +411,50 @@ fn draw_transition_popup(f: &mut Frame, area: Rect, app: &App) {
     let paragraph = Paragraph::new(lines).block(block);
     f.render_widget(paragraph, popup_area);
 }
+
+/// The `[jira.filters]` switcher, with the default query first.
+fn draw_filter_picker(f: &mut Frame, area: Rect, app: &App) {
+    let names = app.project_config.jira_filter_names();
+    let width = 60u16.min(area.width.saturating_sub(4));
+    let height = (names.len() as u16 + 3).min(area.height.saturating_sub(4));
+    let popup_area = centered_rect(area, width, height);
+    f.render_widget(Clear, popup_area);
+
+    let block = Block::default()
+        .title(" Jira filter (Enter use, Esc close) ")
+        .borders(Borders::ALL)
+        .border_style(theme::PROMPT_MODAL_BORDER);
+
+    let default_jql = app
+        .project_config
+        .jira_jql()
+        .unwrap_or("my unresolved issues");
+    let entries = std::iter::once((None, default_jql)).chain(names.iter().map(|name| {
+        (
+            Some(*name),
+            app.project_config.jira_filter_jql(Some(name)).unwrap_or(""),
+        )
+    }));
+    let items: Vec<ListItem> = entries
+        .map(|(name, jql)| {
+            let current = app.jira_filter.as_deref() == name;
+            let (marker, name_style) = if current {
+                ("* ", theme::BRANCH_CURRENT)
+            } else {
+                ("  ", theme::FB_FILE)
+            };
+            ListItem::new(Line::from(vec![
+                Span::styled(marker, theme::BRANCH_CURRENT),
+                Span::styled(format!("{:<12} ", name.unwrap_or("default")), name_style),
+                Span::styled(jql.to_string(), theme::BRANCH_DETAIL),
+            ]))
+        })
+        .collect();
+
+    let list = List::new(items)
+        .block(block)
+        .highlight_style(theme::LIST_SELECTED);
+    let mut state = ListState::default();
+    state.select(Some(app.jira_filter_picker_index));
+    f.render_stateful_widget(list, popup_area, &mut state);
+}
@@ -3,13 +3,22 @@ use ratatui::text::{Line, Span};
 use ratatui::widgets::{Block, Borders, Clear, Paragraph};
 use ratatui::Frame;
 
+use super::pane_cache::{CachedPane, PaneCache};
+use super::util::truncate_chars;
 use super::{
-    git_view, github_view, help_overlay, issues_view, jira_view, linear_view, plans_view,
-    processes_view, prompt_modal, sessions_view, tabs, teams_view, theme, todos_view,
+    activity_feed, azure_view, branch_picker, commands_view, deps_view, empty_state, git_view,
+    github_view, goto_anything, help_overlay, hooks_view, issues_view, jira_view, linear_view,
+    link_preview, notes_view, overview_view, plans_view, pr_draft, processes_view, prompt_modal,
+    remote_picker, sessions_view, settings_view, tabs, teams_view, theme, todos_view, trash_view,
 };
-use crate::app::{ActiveTab, App, GitMode, SessionsPane};
-
-pub fn draw_layout(f: &mut Frame, app: &App) {
+use crate::app::{ActiveTab, App, GitMode, ResumeTarget};
+use crate::keymap;
+use crate::model::overview;
+use crate::model::process::ProcessStatus;
+use crate::model::status_bar::StatusSegment;
+use crate::pane_send::CLAUDE_PANE;
+
+pub fn draw_layout(f: &mut Frame, app: &App, cache: &mut PaneCache) {
     let has_input_bar = app.send_mode;
     let constraints = if has_input_bar {
         vec![
@@ -35,7 +44,7 @@ pub fn draw_layout(f: &mut Frame, app: &App) {
     tabs::draw_tab_bar(f, chunks[0], app);
 
     // Content area
-    draw_content(f, chunks[1], app);
+    draw_content(f, chunks[1], app, cache);
 
     if has_input_bar {
         // Input bar
@@ -49,12 +58,92 @@ pub fn draw_layout(f: &mut Frame, app: &App) {
 
     // Delete confirmation overlay
     if app.confirm_delete {
-        draw_delete_confirm(f, f.area(), &app.delete_target_name);
+        draw_delete_confirm(
+            f,
+            f.area(),
+            &app.delete_target_name,
+            &app.delete_target_detail,
+        );
+    }
+
+    // Resume session confirmation
+    if let Some(ref target) = app.resume_target {
+        draw_resume_confirm(f, f.area(), target, app.two_pane);
+    }
+
+    // Transcript item popup (Sessions tab)
+    if app.transcript_detail.is_some() {
+        sessions_view::draw_transcript_detail(f, f.area(), app);
+    }
+
+    // Team member transcript (Teams tab)
+    if app.member_transcript.is_some() {
+        teams_view::draw_member_transcript(f, f.area(), app);
+    }
+
+    // Session link picker (Sessions tab)
+    if app.session_link_picker.is_some() {
+        sessions_view::draw_session_link_picker(f, f.area(), app);
+    }
+
+    // Plan review overlay (Sessions tab)
+    if app.plan_review.is_some() {
+        sessions_view::draw_plan_review(f, f.area(), app);
+    }
+
+    // Orphaned process cleanup prompt
+    if !app.orphan_processes.is_empty() {
+        draw_orphan_confirm(f, f.area(), app);
+    }
+
+    // Branch / worktree picker (Git tab)
+    if app.show_branch_picker {
+        branch_picker::draw_branch_picker(f, f.area(), app);
+    }
+
+    // Activity feed dropdown
+    if app.show_activity {
+        activity_feed::draw_activity_feed(f, f.area(), app);
+    }
+
+    // Trash (any tab)
+    if app.trash.is_some() {
+        trash_view::draw_trash(f, f.area(), app);
+    }
+
+    // Git remote picker (PRs / Issues tabs)
+    if app.show_remote_picker {
+        remote_picker::draw_remote_picker(f, f.area(), app);
+    }
+
+    // Link preview popup (PRs / Issues / Jira)
+    if app.link_popup.is_some() {
+        link_preview::draw_link_popup(f, f.area(), app);
+    }
+
+    // Agent / command import review (Commands tab)
+    if app.command_import.is_some() {
+        commands_view::draw_command_import(f, f.area(), app);
+    }
+
+    // Note jotted with Ctrl+N (any tab)
+    if app.note_input.is_some() {
+        notes_view::draw_note_input(f, f.area(), app);
+    }
+
+    // Goto anything (Ctrl+K, any tab)
+    if app.goto.is_some() {
+        goto_anything::draw_goto(f, f.area(), app);
+    }
+
+    // PR description drafted with P on the Git tab (any tab)
+    if app.pr_draft.is_some() {
+        pr_draft::draw_pr_draft(f, f.area(), app);
     }
 
     // Help overlay (on top of everything)
     if app.show_help {
-        help_overlay::draw_help(f, f.area());
+        help_overlay::draw_help(f, f.area(), app);
     }
 
     // Prompt picker (on top of everything, before prompt modal)
@@ -68,9 +157,9 @@ pub fn draw_layout(f: &mut Frame, app: &App) {
     }
 }
 
-fn draw_delete_confirm(f: &mut Frame, area: Rect, name: &str) {
+fn draw_delete_confirm(f: &mut Frame, area: Rect, name: &str, detail: &str) {
     let width = 50u16.min(area.width.saturating_sub(4));
-    let height = 5u16;
+    let height = 6u16;
 
     let vert = Layout::default()
         .direction(Direction::Vertical)
@@ -107,6 +196,13 @@ fn draw_delete_confirm(f: &mut Frame, area: Rect, name: &str) {
             format!("  Delete {}?", display_name),
             theme::DELETE_CONFIRM,
         )),
+        Line::from(Span::styled(
+            match detail {
+                "" => "  It goes to the trash (Z).".to_string(),
+                detail => format!("  {}, goes to the trash (Z).", detail),
+            },
+            theme::HELP_DESC,
+        )),
         Line::from(vec![
             Span::styled("  y", theme::HELP_KEY),
             Span::raw(" yes  "),
@@ -124,172 +220,517 @@ fn draw_delete_confirm(f: &mut Frame, area: Rect, name: &str) {
     f.render_widget(paragraph, popup_area);
 }
 
-fn draw_content(f: &mut Frame, area: Rect, app: &App) {
-    match app.active_tab {
-        ActiveTab::Sessions => sessions_view::draw_sessions(f, area, app),
-        ActiveTab::Teams => teams_view::draw_teams(f, area, app),
-        ActiveTab::Todos => todos_view::draw_todos(f, area, app),
-        ActiveTab::Git => git_view::draw_git(f, area, app),
-        ActiveTab::Plans => plans_view::draw_plans(f, area, app),
-        ActiveTab::GitHubPRs => github_view::draw_github(f, area, app),
-        ActiveTab::GitHubIssues => issues_view::draw_issues(f, area, app),
-        ActiveTab::Jira => jira_view::draw_jira(f, area, app),
-        ActiveTab::Linear => linear_view::draw_linear(f, area, app),
-        ActiveTab::Processes => processes_view::draw_processes(f, area, app),
+fn draw_resume_confirm(f: &mut Frame, area: Rect, target: &ResumeTarget, two_pane: bool) {
+    let width = 60u16.min(area.width.saturating_sub(4));
+    let height = 8u16.min(area.height);
+
+    let vert = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length((area.height.saturating_sub(height)) / 2),
+            Constraint::Length(height),
+            Constraint::Min(0),
+        ])
+        .split(area);
+
+    let horiz = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([
+            Constraint::Length((area.width.saturating_sub(width)) / 2),
+            Constraint::Length(width),
+            Constraint::Min(0),
+        ])
+        .split(vert[1]);
+
+    let popup_area = horiz[1];
+
+    f.render_widget(Clear, popup_area);
+
+    let text_width = (width as usize).saturating_sub(6);
+    let branch = if target.branch.is_empty() {
+        "(unknown)"
+    } else {
+        &target.branch
+    };
+    let destination = if two_pane {
+        "  Types claude --resume into the Claude Code pane."
+    } else {
+        "  Opens a new tab with Claude Code + Associate."
+    };
+
+    let lines = vec![
+        Line::from(Span::styled(
+            format!("  {}", truncate_chars(&target.title, text_width)),
+            theme::HELP_DESC,
+        )),
+        Line::from(vec![
+            Span::raw("  branch "),
+            Span::styled(truncate_chars(branch, text_width), theme::BRANCH_LABEL),
+        ]),
+        Line::from(""),
+        Line::from(destination),
+        Line::from(""),
+        Line::from(vec![
+            Span::styled("  y", theme::HELP_KEY),
+            Span::raw(" resume  "),
+            Span::styled("n", theme::HELP_KEY),
+            Span::raw(" cancel"),
+        ]),
+    ];
+
+    let block = Block::default()
+        .title(" Resume Session ")
+        .borders(Borders::ALL)
+        .border_style(theme::BORDER_ACTIVE);
+
+    let paragraph = Paragraph::new(lines).block(block);
+    f.render_widget(paragraph, popup_area);
+}
+
+fn draw_orphan_confirm(f: &mut Frame, area: Rect, app: &App) {
+    const MAX_LISTED: usize = 5;
+    let orphans = &app.orphan_processes;
+    let listed = orphans.len().min(MAX_LISTED);
+    let more = orphans.len() > MAX_LISTED;
+
+    let width = 60u16.min(area.width.saturating_sub(4));
+    let height = (listed as u16 + if more { 7 } else { 6 }).min(area.height);
+
+    let vert = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length((area.height.saturating_sub(height)) / 2),
+            Constraint::Length(height),
+            Constraint::Min(0),
+        ])
+        .split(area);
+
+    let horiz = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([
+            Constraint::Length((area.width.saturating_sub(width)) / 2),
+            Constraint::Length(width),
+            Constraint::Min(0),
+        ])
+        .split(vert[1]);
+
+    let popup_area = horiz[1];
+
+    f.render_widget(Clear, popup_area);
+
+    let mut lines = vec![Line::from(Span::styled(
+        format!(
+            "  {} process group(s) left running by a previous session:",
+            orphans.len()
+        ),
+        theme::DELETE_CONFIRM,
+    ))];
+    for record in orphans.iter().take(MAX_LISTED) {
+        lines.push(Line::from(format!(
+            "    {} (pid {}, started {})",
+            record.label,
+            record.pid,
+            record
+                .started
+                .with_timezone(&chrono::Local)
+                .format("%Y-%m-%d %H:%M")
+        )));
+    }
+    if more {
+        lines.push(Line::from(format!(
+            "    ...and {} more",
+            orphans.len() - MAX_LISTED
+        )));
+    }
+    lines.push(Line::from(""));
+    lines.push(Line::from("  Kill them now?"));
+    lines.push(Line::from(vec![
+        Span::styled("  y", theme::HELP_KEY),
+        Span::raw(" kill  "),
+        Span::styled("n", theme::HELP_KEY),
+        Span::raw(" leave running"),
+    ]));
+
+    let block = Block::default()
+        .title(" Orphaned Processes ")
+        .borders(Borders::ALL)
+        .border_style(theme::DELETE_CONFIRM_BORDER);
+
+    let paragraph = Paragraph::new(lines).block(block);
+    f.render_widget(paragraph, popup_area);
+}
+
+fn draw_content(f: &mut Frame, area: Rect, app: &App, cache: &mut PaneCache) {
+    if app.tab_loading(&app.active_tab) {
+        empty_state::draw_loading_state(f, area);
+        return;
     }
+    let draw_body: fn(&mut Frame, Rect, &App) = match app.active_tab {
+        ActiveTab::Sessions => {
+            // Cached pane by pane
+            sessions_view::draw_sessions(f, area, app, cache);
+            return;
+        }
+        ActiveTab::Overview => overview_view::draw_overview,
+        ActiveTab::Teams => teams_view::draw_teams,
+        ActiveTab::Todos => todos_view::draw_todos,
+        ActiveTab::Git => git_view::draw_git,
+        ActiveTab::Plans => plans_view::draw_plans,
+        ActiveTab::Commands => commands_view::draw_commands,
+        ActiveTab::Hooks => hooks_view::draw_hooks,
+        ActiveTab::Settings => settings_view::draw_settings,
+        ActiveTab::Notes => notes_view::draw_notes,
+        ActiveTab::GitHubPRs => github_view::draw_github,
+        ActiveTab::GitHubIssues => issues_view::draw_issues,
+        ActiveTab::Jira => jira_view::draw_jira,
+        ActiveTab::Linear => linear_view::draw_linear,
+        ActiveTab::Azure => azure_view::draw_azure,
+        ActiveTab::Deps => deps_view::draw_deps,
+        ActiveTab::Processes => processes_view::draw_processes,
+    };
+    let pane = CachedPane::Content(app.active_tab.clone());
+    cache.draw(f, pane, area, app.dirty.content, |f| {
+        draw_body(f, area, app)
+    });
 }
 
 fn draw_send_input_bar(f: &mut Frame, area: Rect, app: &App) {
-    let label = Span::styled(" Send to Claude: ", theme::SEND_LABEL);
+    let target = if app.send_target == CLAUDE_PANE {
+        "Claude"
+    } else {
+        app.send_target.as_str()
+    };
+    let label = format!(" Send to {}: ", target);
     let cursor_pos = app.send_input.len();
     let input_text = format!("{}_", &app.send_input);
     let input = Span::styled(input_text, theme::SEND_INPUT);
+    let mut used = label.chars().count() + cursor_pos + 1; // label width + input + cursor
+    let label = Span::styled(label, theme::SEND_LABEL);
+    let mut spans = vec![label, input];
+
+    if app
+        .pane_registry
+        .as_ref()
+        .is_some_and(|r| r.targets().len() > 1)
+    {
+        used += 10;
+        spans.push(Span::styled("  Tab ", theme::HINT_KEY));
+        spans.push(Span::styled("pane", theme::HINT_DESC));
+    }
+
+    // Quick-send snippets, while they fit: digits alone on an empty bar
+    let modifier = if app.send_input.is_empty() {
+        ""
+    } else {
+        "Alt+"
+    };
+    for (i, snippet) in app.send_snippets.iter().enumerate() {
+        let key = format!("  {}{} ", modifier, i + 1);
+        let width = key.chars().count() + snippet.chars().count();
+        if used + width > area.width as usize {
+            break;
+        }
+        used += width;
+        spans.push(Span::styled(key, theme::HINT_KEY));
+        spans.push(Span::styled(snippet.as_str(), theme::HINT_DESC));
+    }
 
     // Fill remaining width with input background
-    let used = 17 + cursor_pos + 1; // label width + input + cursor
     let remaining = (area.width as usize).saturating_sub(used);
-    let pad = Span::styled(" ".repeat(remaining), theme::SEND_INPUT);
+    spans.push(Span::styled(" ".repeat(remaining), theme::SEND_INPUT));
 
-    let line = Line::from(vec![label, input, pad]);
-    f.render_widget(Paragraph::new(line), area);
+    f.render_widget(Paragraph::new(Line::from(spans)), area);
 }
 
 fn hint_text(app: &App) -> Vec<(&'static str, &'static str)> {
-    let mut hints: Vec<(&str, &str)> = match app.active_tab {
-        ActiveTab::Sessions => match app.sessions_pane {
-            SessionsPane::List => vec![
-                ("j/k", "nav"),
-                ("Enter", "select"),
-                ("o", "open in WT"),
-                ("d", "delete"),
-            ],
-            SessionsPane::Transcript => vec![("f", "follow"), ("s", "subagent"), ("j/k", "scroll")],
-        },
-        ActiveTab::Teams => vec![
-            ("j/k", "nav"),
-            ("h/l", "panes"),
-            ("Enter", "drill"),
-            ("d", "delete"),
-        ],
-        ActiveTab::Todos => vec![("j/k", "nav"), ("h/l", "panes"), ("d", "delete")],
-        ActiveTab::Git => {
-            if app.git_mode == GitMode::Browse {
-                vec![
-                    ("e", "edit"),
-                    ("Enter", "open"),
-                    ("Bksp", "up"),
-                    ("b", "status"),
-                ]
-            } else {
-                vec![("j/k", "nav"), ("h/l", "panes"), ("b", "browse")]
-            }
+    mode_hints(app).unwrap_or_else(|| keymap::hints(app))
+}
+
+/// Hints for the text inputs and editors, which handle keys themselves.
+fn mode_hints(app: &App) -> Option<Vec<(&'static str, &'static str)>> {
+    let hints = if app.filter_input.is_some() {
+        vec![("Tab", "complete"), ("Enter", "apply"), ("Esc", "cancel")]
+    } else if app.note_input.is_some() {
+        vec![("Enter", "add"), ("Esc", "cancel")]
+    } else if app.goto.is_some() {
+        vec![("↑/↓", "move"), ("Enter", "jump"), ("Esc", "close")]
+    } else if app.pr_draft.is_some() {
+        vec![
+            ("Tab", "field"),
+            ("Ctrl+S", "create PR"),
+            ("Esc", "discard"),
+        ]
+    } else if app.active_tab == ActiveTab::Teams && app.task_editor.is_some() {
+        vec![("Tab", "field"), ("Ctrl+S", "save"), ("Esc", "cancel")]
+    } else if let Some(ref broadcast) = app.broadcast {
+        if broadcast.confirming {
+            vec![("y", "send"), ("n", "back")]
+        } else {
+            vec![("Tab", "template"), ("Ctrl+S", "send"), ("Esc", "cancel")]
         }
-        ActiveTab::Plans => vec![("j/k", "nav"), ("h/l", "panes"), ("d", "delete")],
-        ActiveTab::GitHubPRs => vec![
-            ("j/k", "nav"),
-            ("o", "open"),
-            ("r", "refresh"),
-            ("p", "prompt"),
-        ],
-        ActiveTab::GitHubIssues => vec![
-            ("j/k", "nav"),
-            ("n", "new"),
-            ("e", "edit"),
+    } else if app.active_tab == ActiveTab::Todos && app.todo_promote.is_some() {
+        vec![("↑/↓", "tracker"), ("Enter", "create"), ("Esc", "cancel")]
+    } else if app.active_tab == ActiveTab::Todos && app.todo_edit_mode.is_some() {
+        vec![("Enter", "save"), ("Esc", "cancel")]
+    } else if app.active_tab == ActiveTab::Plans && app.plan_merge.is_some() {
+        vec![
+            ("j/k", "hunk"),
+            ("a", "accept"),
+            ("r", "reject"),
+            ("Enter", "apply"),
+            ("Esc", "keep mine"),
+        ]
+    } else if app.active_tab == ActiveTab::Plans && app.plan_tasks.is_some() {
+        vec![
+            ("j/k", "move"),
+            ("Space", "toggle"),
+            ("Tab", "team"),
+            ("Enter", "create"),
+            ("Esc", "cancel"),
+        ]
+    } else if app.active_tab == ActiveTab::Plans && app.plan_editing {
+        vec![("Ctrl+S", "save"), ("Esc", "cancel")]
+    } else if app.active_tab == ActiveTab::Sessions && app.session_rename_input.is_some() {
+        vec![("Enter", "save"), ("Esc", "cancel")]
+    } else if app.active_tab == ActiveTab::Git && app.grep_input.is_some() {
+        vec![("Enter", "search"), ("Esc", "cancel")]
+    } else if app.active_tab == ActiveTab::Git && app.fb_op.is_some() {
+        vec![("Enter", "confirm"), ("Esc", "cancel")]
+    } else if app.active_tab == ActiveTab::Git && app.git_stash_input.is_some() {
+        vec![("Enter", "stash"), ("Esc", "cancel")]
+    } else if app.active_tab == ActiveTab::Git && app.git_stash_confirm.is_some() {
+        vec![("y", "yes"), ("n", "no")]
+    } else if app.active_tab == ActiveTab::Processes && app.process_search_input.is_some() {
+        vec![("Enter", "search"), ("Esc", "cancel")]
+    } else if app.active_tab == ActiveTab::Processes && app.process_report_prompt {
+        vec![("f", "file"), ("c", "comment"), ("Esc", "cancel")]
+    } else if app.active_tab == ActiveTab::GitHubIssues && app.gh_issues_conflict.is_some() {
+        vec![
+            ("o", "overwrite"),
+            ("m", "merge"),
             ("c", "comment"),
-            ("x", "close/open"),
-            ("o", "browser"),
-            ("r", "refresh"),
-            ("p", "prompt"),
-        ],
-        ActiveTab::Jira => vec![
-            ("j/k", "nav"),
-            ("o", "open"),
-            ("r", "refresh"),
-            ("/", "search"),
-            ("t", "transition"),
-            ("p", "prompt"),
-        ],
-        ActiveTab::Linear => vec![
-            ("j/k", "nav"),
-            ("o", "open"),
-            ("r", "refresh"),
-            ("p", "prompt"),
-        ],
-        ActiveTab::Processes => vec![
-            ("j/k", "nav"),
-            ("h/l", "panes"),
-            ("x", "kill"),
-            ("s", "jump to session"),
-        ],
+            ("j/k", "scroll"),
+            ("Esc", "back"),
+        ]
+    } else if app.active_tab == ActiveTab::Commands && app.command_new_target.is_some() {
+        vec![("Tab", "type"), ("Enter", "create"), ("Esc", "cancel")]
+    } else if app.active_tab == ActiveTab::Commands && app.command_import_input.is_some() {
+        vec![("Enter", "load"), ("Esc", "cancel")]
+    } else if (app.active_tab == ActiveTab::Commands && app.command_editing)
+        || (app.active_tab == ActiveTab::Notes && app.notes_editing)
+    {
+        vec![("Ctrl+S", "save"), ("Esc", "cancel")]
+    } else if app.active_tab == ActiveTab::Hooks && app.hook_form.is_some() {
+        vec![
+            ("Tab", "next field"),
+            ("←/→", "choose"),
+            ("Enter", "save"),
+            ("Esc", "cancel"),
+        ]
+    } else {
+        return None;
     };
-    hints.push(("i", "send"));
-    hints.push(("^H", "help"));
-    hints
+    Some(hints)
 }
 
 fn draw_status_bar(f: &mut Frame, area: Rect, app: &App) {
-    let mut left_spans: Vec<Span> = Vec::new();
+    let side = |segments: &[StatusSegment]| -> Vec<Span> {
+        segments
+            .iter()
+            .flat_map(|segment| segment_spans(app, *segment))
+            .collect()
+    };
+    let left_spans = side(&app.status_bar.left);
+    let center_spans = side(&app.status_bar.center);
+    let right_spans = side(&app.status_bar.right);
+
+    let width = |spans: &[Span]| -> usize { spans.iter().map(|s| s.width()).sum() };
+    let (left_width, center_width, right_width) = (
+        width(&left_spans),
+        width(&center_spans),
+        width(&right_spans),
+    );
+    let total = area.width as usize;
+    let gap = total.saturating_sub(left_width + center_width + right_width);
+    // Centred on the bar when there's room, else pushed aside by the left
+    let before_center = (total.saturating_sub(center_width) / 2)
+        .saturating_sub(left_width)
+        .min(gap);
+
+    let mut spans = left_spans;
+    spans.push(Span::styled(" ".repeat(before_center), theme::STATUS_BAR));
+    spans.extend(center_spans);
+    spans.push(Span::styled(
+        " ".repeat(gap - before_center),
+        theme::STATUS_BAR,
+    ));
+    spans.extend(right_spans);
+
+    let line = Line::from(spans);
+    f.render_widget(Paragraph::new(line), area);
+}
+
+/// What one status bar segment shows now; nothing when it has nothing to say.
+fn segment_spans(app: &App, segment: StatusSegment) -> Vec<Span<'static>> {
+    let mut spans: Vec<Span> = Vec::new();
+    match segment {
+        StatusSegment::Errors => {
+            if let Some(ref err) = app.last_error {
+                spans.push(Span::styled(
+                    format!(" ERR: {} ", err),
+                    theme::ERROR_DISPLAY,
+                ));
+            }
+        }
+        StatusSegment::Follow => {
+            if app.active_tab == ActiveTab::Sessions && app.follow_mode {
+                spans.push(Span::styled(" FOLLOW ", theme::FOLLOW_ACTIVE));
+            }
+        }
+        // Transcript sharing (--share) and connected mirrors
+        StatusSegment::Sharing => {
+            if let Some(share) = &app.share {
+                spans.push(Span::styled(
+                    format!(" SHARING {} ({}) ", share.addr(), share.clients()),
+                    theme::SHARE_ACTIVE,
+                ));
+            }
+        }
+        // Low-power mode (on battery or --low-power)
+        StatusSegment::Power => {
+            if app.low_power {
+                spans.push(Span::styled(" LOW POWER ", theme::LOW_POWER));
+            }
+        }
+        StatusSegment::Modes => mode_badges(app, &mut spans),
+        // Pane send status
+        StatusSegment::Messages => {
+            if app.send_pending {
+                spans.push(Span::styled(" SENDING... ", theme::SEND_PENDING));
+            } else if let Some((ref msg, _)) = app.send_status {
+                spans.push(Span::styled(format!(" {} ", msg), theme::SEND_OK));
+            }
+        }
+        // Background poll countdown (PRs / Issues / Jira / Linear / Work Items)
+        StatusSegment::Polls => {
+            if let Some(countdown) = app.poll_countdown() {
+                spans.push(Span::styled(
+                    format!(" {} ", countdown),
+                    theme::POLL_COUNTDOWN,
+                ));
+            }
+        }
+        StatusSegment::Processes => {
+            let count =
+                |status: ProcessStatus| app.processes.iter().filter(|p| p.status == status).count();
+            let (running, queued) = (count(ProcessStatus::Running), count(ProcessStatus::Queued));
+            let text = match (running, queued) {
+                (0, 0) => None,
+                (running, 0) => Some(format!(" {} running ", running)),
+                (running, queued) => Some(format!(" {} running, {} queued ", running, queued)),
+            };
+            if let Some(text) = text {
+                spans.push(Span::styled(text, theme::STATUS_BAR));
+            }
+            if app.process_usage_high() {
+                let total = app.total_process_usage();
+                spans.push(Span::styled(
+                    format!(
+                        " HIGH USAGE {} {} ",
+                        total.cpu_label(),
+                        total.memory_label()
+                    ),
+                    theme::PROCESS_USAGE_HIGH,
+                ));
+            }
+        }
+        StatusSegment::Clock => spans.push(Span::styled(
+            format!(" {} ", chrono::Local::now().format("%H:%M")),
+            theme::STATUS_BAR,
+        )),
+        StatusSegment::Branch => {
+            if let Some(ref branch) = app.git_branch {
+                spans.push(Span::styled(format!(" {} ", branch), theme::STATUS_BAR));
+            }
+        }
+        StatusSegment::Agents => {
+            let statuses = app.all_agent_statuses();
+            if !statuses.is_empty() {
+                spans.push(Span::styled(
+                    format!(" agents: {} ", overview::status_summary(&statuses)),
+                    theme::STATUS_BAR,
+                ));
+            }
+        }
+        StatusSegment::Hints => {
+            spans.push(Span::styled(" ", theme::STATUS_BAR));
+            for (i, (key, desc)) in hint_text(app).into_iter().enumerate() {
+                if i > 0 {
+                    spans.push(Span::styled("  ", theme::STATUS_BAR));
+                }
+                spans.push(Span::styled(key, theme::HINT_KEY));
+                spans.push(Span::styled(":", theme::HINT_DESC));
+                spans.push(Span::styled(desc, theme::HINT_DESC));
+            }
+            spans.push(Span::styled(" ", theme::STATUS_BAR));
+        }
+    }
+    spans
+}
 
-    // Error display
-    if let Some(ref err) = app.last_error {
-        left_spans.push(Span::styled(
-            format!(" ERR: {} ", err),
-            theme::ERROR_DISPLAY,
+/// Badges for the active tab's modes: editing, browsing, merging, filtering.
+fn mode_badges(app: &App, spans: &mut Vec<Span<'static>>) {
+    // Unread transcript lines (Sessions tab)
+    if app.active_tab == ActiveTab::Sessions && app.transcript_unread_count() > 0 {
+        spans.push(Span::styled(
+            format!(" {} NEW ", app.transcript_unread_count()),
+            theme::MODE_BADGE_SEARCH,
         ));
     }
 
-    // Follow mode indicator (only on sessions tab)
-    if app.active_tab == ActiveTab::Sessions && app.follow_mode {
-        left_spans.push(Span::styled(" FOLLOW ", theme::FOLLOW_ACTIVE));
+    // Translate mode indicator (Sessions tab)
+    if app.active_tab == ActiveTab::Sessions && app.translate_mode {
+        spans.push(Span::styled(" TRANSLATED ", theme::MODE_BADGE_SEARCH));
     }
 
     // Browse mode indicator (Git tab)
     if app.active_tab == ActiveTab::Git && app.git_mode == GitMode::Browse {
-        left_spans.push(Span::styled(" BROWSE ", theme::MODE_BADGE_BROWSE));
+        spans.push(Span::styled(" BROWSE ", theme::MODE_BADGE_BROWSE));
         if app.fb_editing {
-            left_spans.push(Span::styled(" EDIT ", theme::MODE_BADGE_EDIT));
+            spans.push(Span::styled(" EDIT ", theme::MODE_BADGE_EDIT));
         }
     }
 
-    // Issues edit mode indicator
-    if app.active_tab == ActiveTab::GitHubIssues && app.gh_issues_editing {
-        left_spans.push(Span::styled(" EDIT ", theme::MODE_BADGE_BROWSE));
+    // Todo edit mode indicator
+    if app.active_tab == ActiveTab::Todos && app.todo_edit_mode.is_some() {
+        spans.push(Span::styled(" EDIT ", theme::MODE_BADGE_EDIT));
     }
 
-    // Pane send status
-    if app.send_pending {
-        left_spans.push(Span::styled(" SENDING... ", theme::SEND_PENDING));
-    } else if let Some((ref msg, _)) = app.send_status {
-        left_spans.push(Span::styled(format!(" {} ", msg), theme::SEND_OK));
+    // Plan edit / merge indicator
+    if app.active_tab == ActiveTab::Plans && app.plan_editing {
+        if app.plan_merge.is_some() {
+            spans.push(Span::styled(" MERGE ", theme::MODE_BADGE_SEARCH));
+        } else {
+            spans.push(Span::styled(" EDIT ", theme::MODE_BADGE_EDIT));
+        }
     }
 
-    // Jira search mode indicator
-    if app.active_tab == ActiveTab::Jira && app.jira_search_mode {
-        left_spans.push(Span::styled(" SEARCH ", theme::MODE_BADGE_SEARCH));
+    // Command edit indicator
+    if app.active_tab == ActiveTab::Commands && app.command_editing {
+        spans.push(Span::styled(" EDIT ", theme::MODE_BADGE_EDIT));
     }
 
-    // Build right-aligned hint spans
-    let hints = hint_text(app);
-    let mut hint_spans: Vec<Span> = Vec::new();
-    for (i, (key, desc)) in hints.iter().enumerate() {
-        if i > 0 {
-            hint_spans.push(Span::styled("  ", theme::STATUS_BAR));
+    // Issues edit mode indicator
+    if app.active_tab == ActiveTab::GitHubIssues && app.gh_issues_editing {
+        if app.gh_issues_conflict.is_some() {
+            spans.push(Span::styled(" CONFLICT ", theme::MODE_BADGE_SEARCH));
+        } else {
+            spans.push(Span::styled(" EDIT ", theme::MODE_BADGE_BROWSE));
         }
-        hint_spans.push(Span::styled(*key, theme::HINT_KEY));
-        hint_spans.push(Span::styled(":", theme::HINT_DESC));
-        hint_spans.push(Span::styled(*desc, theme::HINT_DESC));
     }
-    hint_spans.push(Span::styled(" ", theme::STATUS_BAR));
-
-    let left_width: usize = left_spans.iter().map(|s| s.width()).sum();
-    let hint_width: usize = hint_spans.iter().map(|s| s.width()).sum();
-    let total = area.width as usize;
-    let gap = total.saturating_sub(left_width + hint_width);
 
-    let mut spans = left_spans;
-    spans.push(Span::styled(" ".repeat(gap), theme::STATUS_BAR));
-    spans.extend(hint_spans);
-
-    let line = Line::from(spans);
-    f.render_widget(Paragraph::new(line), area);
+    // List filter indicator
+    if app.filter_input.is_some() {
+        spans.push(Span::styled(" FILTER ", theme::MODE_BADGE_SEARCH));
+    }
 }
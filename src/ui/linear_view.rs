@@ -4,17 +4,21 @@ use ratatui::text::{Line, Span};
 use ratatui::widgets::{Block, Borders, List, ListItem, ListState, Paragraph, Wrap};
 use ratatui::Frame;
 
-use super::theme;
+use super::util::{linked_sessions_lines, ticket_notes_lines};
+use super::{empty_state, filter_bar, theme, trend_chart};
 use crate::app::{App, LinearPane};
 use crate::model::linear::FlatLinearItem;
+use crate::model::process::TicketSource;
+use crate::model::snapshot;
 
 pub fn draw_linear(f: &mut Frame, area: Rect, app: &App) {
+    let body = trend_chart::draw_trend_header(f, area, app, snapshot::TRACKER_LINEAR);
     let chunks = Layout::default()
         .direction(Direction::Horizontal)
         .constraints([Constraint::Percentage(40), Constraint::Percentage(60)])
-        .split(area);
+        .split(body);
 
-    draw_issue_list(f, chunks[0], app);
+    filter_bar::with_filter_input(f, chunks[0], app, |f, area| draw_issue_list(f, area, app));
     draw_detail_pane(f, chunks[1], app);
 }
 
@@ -26,18 +30,29 @@ fn draw_issue_list(f: &mut Frame, area: Rect, app: &App) {
         theme::BORDER_INACTIVE
     };
 
-    let title = format!(" Linear [{}] ", app.linear_issues.len());
+    let shown = app
+        .linear_flat_list
+        .iter()
+        .filter(|item| matches!(item, FlatLinearItem::Issue(_)))
+        .count();
+    let label = match app.linear_scope() {
+        Some(scope) => format!("Linear {}", scope),
+        None => "Linear".to_string(),
+    };
+    let title = filter_bar::paged_list_title(
+        &label,
+        shown,
+        app.linear_issues.len(),
+        app.linear_next_cursor.is_some(),
+        app,
+    );
     let block = Block::default()
         .title(title)
         .borders(Borders::ALL)
         .border_style(border_style);
 
     if app.linear_flat_list.is_empty() {
-        let p = Paragraph::new("No issues found")
-            .style(theme::EMPTY_STATE)
-            .block(block)
-            .wrap(Wrap { trim: false });
-        f.render_widget(p, area);
+        empty_state::draw_empty_state(f, area, block, "No issues found", app);
     } else {
         let items: Vec<ListItem> = app
             .linear_flat_list
@@ -192,6 +207,20 @@ fn draw_detail_pane(f: &mut Frame, area: Rect, app: &App) {
         ]));
     }
 
+    if let Some(ref project) = issue.project {
+        lines.push(Line::from(vec![
+            Span::styled("Project: ", theme::LIST_NORMAL.add_modifier(Modifier::BOLD)),
+            Span::raw(project.name.clone()),
+        ]));
+    }
+
+    if let Some(ref cycle) = issue.cycle {
+        lines.push(Line::from(vec![
+            Span::styled("Cycle: ", theme::LIST_NORMAL.add_modifier(Modifier::BOLD)),
+            Span::raw(cycle.label()),
+        ]));
+    }
+
     // Labels
     if !issue.labels.nodes.is_empty() {
         let label_names: Vec<&str> = issue.labels.nodes.iter().map(|l| l.name.as_str()).collect();
@@ -224,6 +253,13 @@ fn draw_detail_pane(f: &mut Frame, area: Rect, app: &App) {
         }
     }
 
+    lines.extend(ticket_notes_lines(app, &issue.url));
+    lines.extend(linked_sessions_lines(
+        app,
+        &TicketSource::Linear,
+        &issue.identifier,
+    ));
+
     // URL
     if !issue.url.is_empty() {
         lines.push(Line::from(""));
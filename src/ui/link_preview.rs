@@ -0,0 +1,74 @@
+use ratatui::layout::{Constraint, Direction, Layout, Rect};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph, Wrap};
+use ratatui::Frame;
+
+use super::theme;
+use super::util::truncate_chars;
+use crate::app::App;
+
+/// Draw the links of the selected item with a preview of the highlighted one.
+pub fn draw_link_popup(f: &mut Frame, area: Rect, app: &App) {
+    let Some(ref popup) = app.link_popup else {
+        return;
+    };
+    let width = 90u16.min(area.width.saturating_sub(4));
+    let list_height = popup.urls.len().min(8) as u16;
+    let height = (list_height + 12).min(area.height.saturating_sub(2));
+    let popup_area = Rect {
+        x: area.x + area.width.saturating_sub(width) / 2,
+        y: area.y + area.height.saturating_sub(height) / 2,
+        width,
+        height,
+    };
+    f.render_widget(Clear, popup_area);
+
+    let block = Block::default()
+        .title(format!(" Links ({}) ", popup.urls.len()))
+        .title_bottom(" j/k select  o open in browser  Esc close ")
+        .borders(Borders::ALL)
+        .border_style(theme::PROMPT_MODAL_BORDER);
+    let inner = block.inner(popup_area);
+    f.render_widget(block, popup_area);
+
+    let parts = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(list_height + 1), Constraint::Min(1)])
+        .split(inner);
+
+    let url_width = inner.width.saturating_sub(1) as usize;
+    let items: Vec<ListItem> = popup
+        .urls
+        .iter()
+        .map(|url| {
+            ListItem::new(Span::styled(
+                truncate_chars(url, url_width),
+                theme::LINK_URL,
+            ))
+        })
+        .collect();
+    let list = List::new(items)
+        .block(Block::default().borders(Borders::BOTTOM))
+        .highlight_style(theme::LIST_SELECTED);
+    let mut state = ListState::default();
+    state.select(Some(popup.index));
+    f.render_stateful_widget(list, parts[0], &mut state);
+
+    let lines = match app
+        .selected_link()
+        .and_then(|url| app.link_previews.get(url))
+    {
+        None => vec![Line::from(Span::styled("Fetching...", theme::EMPTY_STATE))],
+        Some(Err(e)) => vec![Line::from(Span::styled(
+            format!("No preview: {}", e),
+            theme::CHECK_FAIL,
+        ))],
+        Some(Ok(preview)) => vec![
+            Line::from(Span::styled(preview.title.clone(), theme::LINK_TITLE)),
+            Line::from(""),
+            Line::from(Span::styled(preview.summary.clone(), theme::LIST_NORMAL)),
+        ],
+    };
+    let preview = Paragraph::new(lines).wrap(Wrap { trim: true });
+    f.render_widget(preview, parts[1]);
+}
@@ -0,0 +1,108 @@
+use ratatui::layout::{Constraint, Direction, Layout, Rect};
+use ratatui::style::Style;
+use ratatui::text::Line;
+use ratatui::widgets::{Block, Cell, Row, Table, TableState};
+use ratatui::Frame;
+
+use super::theme;
+use crate::model::list_table::{ListColumn, TableView};
+
+/// Share of the width the PRs and Issues lists take; the detail pane gets
+/// the rest.
+pub const LIST_PERCENT: u16 = 40;
+
+/// One row of a PR or issue table.
+pub enum TableRow<'a> {
+    /// Section label, shown in the title column.
+    Section(String, Style),
+    /// Cells for every column of the view, in order.
+    Item(Vec<Cell<'a>>),
+}
+
+/// Split `area` into the list and the detail pane.
+pub fn split(area: Rect) -> std::rc::Rc<[Rect]> {
+    Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([
+            Constraint::Percentage(LIST_PERCENT),
+            Constraint::Percentage(100 - LIST_PERCENT),
+        ])
+        .split(area)
+}
+
+/// Width the table gets inside the list's borders on a terminal `width`
+/// cells wide.
+pub fn inner_width(width: u16) -> u16 {
+    split(Rect::new(0, 0, width, 1))[0].width.saturating_sub(2)
+}
+
+/// Draw `rows` as a table of `view`'s columns. Columns that don't fit are
+/// left out, and `<` / `>` scroll them into view; the block's top-right
+/// corner shows which way more columns are.
+pub fn draw_table(
+    f: &mut Frame,
+    area: Rect,
+    block: Block,
+    view: &TableView,
+    rows: Vec<TableRow>,
+    selected: usize,
+) {
+    let width = area.width.saturating_sub(2);
+    let visible = view.visible_columns(width);
+    let columns = &view.columns[visible.clone()];
+    let more_left = visible.start > 0;
+    let more_right = visible.end < view.columns.len();
+
+    let header = Row::new(columns.iter().map(|column| {
+        let arrow = match view.sort {
+            Some(sorted) if sorted == *column && view.descending => "↓",
+            Some(sorted) if sorted == *column => "↑",
+            _ => "",
+        };
+        Cell::from(format!("{}{}", column.header(), arrow))
+    }))
+    .style(theme::TABLE_HEADER);
+
+    let section_column = columns
+        .iter()
+        .position(|c| *c == ListColumn::Title)
+        .unwrap_or(0);
+    let rows: Vec<Row> = rows
+        .into_iter()
+        .map(|row| match row {
+            TableRow::Section(label, style) => {
+                let mut cells = vec![Cell::from(""); columns.len()];
+                cells[section_column] = Cell::from(label);
+                Row::new(cells).style(style)
+            }
+            TableRow::Item(cells) => {
+                Row::new(cells.into_iter().skip(visible.start).take(visible.len()))
+            }
+        })
+        .collect();
+
+    let widths: Vec<Constraint> = columns
+        .iter()
+        .map(|column| match column {
+            ListColumn::Title => Constraint::Min(column.width()),
+            _ => Constraint::Length(column.width()),
+        })
+        .collect();
+
+    let arrows = match (more_left, more_right) {
+        (true, true) => " ◀ ▶ ",
+        (true, false) => " ◀ ",
+        (false, true) => " ▶ ",
+        (false, false) => "",
+    };
+    let block = block.title_top(Line::from(arrows).style(theme::EMPTY_STATE).right_aligned());
+
+    let table = Table::new(rows, widths)
+        .header(header)
+        .block(block)
+        .row_highlight_style(theme::LIST_SELECTED);
+
+    let mut state = TableState::default();
+    state.select(Some(selected));
+    f.render_stateful_widget(table, area, &mut state);
+}
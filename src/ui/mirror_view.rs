@@ -0,0 +1,97 @@
+use ratatui::layout::{Constraint, Direction, Layout, Rect};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, Paragraph};
+use ratatui::Frame;
+
+use super::sessions_view::kind_style;
+use super::theme;
+use super::util::truncate_chars;
+use crate::model::share::{MirrorState, MirrorStatus};
+
+/// Rows of the mirror screen that are not transcript: borders and the
+/// status bar.
+pub const MIRROR_CHROME_ROWS: u16 = 3;
+
+/// `assoc mirror`: the host's transcript, one item per line, and a status
+/// bar with the connection state.
+pub fn draw_mirror(f: &mut Frame, mirror: &MirrorState) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(3), Constraint::Length(1)])
+        .split(f.area());
+    draw_transcript(f, chunks[0], mirror);
+    draw_status(f, chunks[1], mirror);
+}
+
+fn draw_transcript(f: &mut Frame, area: Rect, mirror: &MirrorState) {
+    let title = if mirror.title.is_empty() {
+        format!(" Mirror: {} ", mirror.addr)
+    } else {
+        format!(
+            " Mirror: {} ({}) ",
+            truncate_chars(&mirror.title, 40),
+            mirror.project
+        )
+    };
+    let block = Block::default()
+        .title(title)
+        .borders(Borders::ALL)
+        .border_style(theme::BORDER_ACTIVE);
+    let inner = block.inner(area);
+    f.render_widget(block, area);
+
+    if mirror.items.is_empty() {
+        let msg = match mirror.status {
+            MirrorStatus::Connected => "Waiting for the host to open a session...",
+            _ => "Connecting to the host...",
+        };
+        f.render_widget(Paragraph::new(msg).style(theme::EMPTY_STATE), inner);
+        return;
+    }
+
+    let top = mirror.top();
+    let end = (top + inner.height as usize).min(mirror.items.len());
+    let width = inner.width.saturating_sub(11) as usize;
+    let lines: Vec<Line> = mirror.items[top..end]
+        .iter()
+        .map(|item| {
+            let time = item
+                .timestamp
+                .map(|ts| ts.format("%H:%M").to_string())
+                .unwrap_or_else(|| "     ".to_string());
+            let text = truncate_chars(&item.text, width)
+                .replace('\n', " ")
+                .replace('\r', "");
+            Line::from(vec![
+                Span::raw(format!("{} ", time)),
+                Span::styled(format!("{} ", item.kind.label()), kind_style(&item.kind)),
+                Span::raw(text),
+            ])
+        })
+        .collect();
+    f.render_widget(Paragraph::new(lines), inner);
+}
+
+fn draw_status(f: &mut Frame, area: Rect, mirror: &MirrorState) {
+    let mut spans = match &mirror.status {
+        MirrorStatus::Connecting => vec![Span::styled(" CONNECTING ", theme::MODE_BADGE_SEARCH)],
+        MirrorStatus::Connected => vec![Span::styled(" READ-ONLY ", theme::SHARE_ACTIVE)],
+        MirrorStatus::Disconnected(reason) => vec![Span::styled(
+            format!(" DISCONNECTED: {}, retrying ", reason),
+            theme::MIRROR_DISCONNECTED,
+        )],
+    };
+    if mirror.follow {
+        spans.push(Span::styled(" FOLLOW ", theme::FOLLOW_ACTIVE));
+    }
+    spans.push(Span::styled(
+        " j/k scroll  g/G top/bottom  f follow  q quit",
+        theme::STATUS_BAR,
+    ));
+    let used: usize = spans.iter().map(|s| s.width()).sum();
+    spans.push(Span::styled(
+        " ".repeat((area.width as usize).saturating_sub(used)),
+        theme::STATUS_BAR,
+    ));
+    f.render_widget(Paragraph::new(Line::from(spans)), area);
+}
@@ -1,26 +1,73 @@
+pub mod activity_feed;
+pub mod activity_heatmap;
+pub mod ansi;
+pub mod azure_view;
+pub mod branch_picker;
+pub mod commands_view;
+pub mod deps_view;
+pub mod empty_state;
 pub mod filebrowser_view;
+pub mod filter_bar;
 pub mod git_view;
 pub mod github_view;
+pub mod goto_anything;
 pub mod help_overlay;
+pub mod hooks_view;
 pub mod issues_view;
 pub mod jira_view;
 pub mod layout;
 pub mod linear_view;
+pub mod link_preview;
+pub mod list_table;
+pub mod mirror_view;
+pub mod notes_view;
+pub mod overview_view;
+pub mod pane_cache;
 pub mod plans_view;
+pub mod pr_draft;
 pub mod processes_view;
 pub mod prompt_modal;
+pub mod remote_picker;
 pub mod sessions_view;
+pub mod settings_view;
 pub mod tabs;
 pub mod teams_view;
 pub mod theme;
 pub mod todos_view;
+pub mod trash_view;
+pub mod trend_chart;
 pub mod util;
 
+use ratatui::layout::{Alignment, Rect};
+use ratatui::widgets::{Paragraph, Wrap};
 use ratatui::Frame;
 
 use crate::app::App;
 
-/// Main draw dispatcher.
-pub fn draw(f: &mut Frame, app: &App) {
-    layout::draw_layout(f, app);
+/// Smallest terminal the dashboard is drawn in; below this a notice asks
+/// for a bigger window.
+pub const MIN_WIDTH: u16 = 60;
+pub const MIN_HEIGHT: u16 = 12;
+
+/// Main draw dispatcher. Panes `app.dirty` leaves clean come from `cache`.
+pub fn draw(f: &mut Frame, app: &App, cache: &mut pane_cache::PaneCache) {
+    let area = f.area();
+    if area.width < MIN_WIDTH || area.height < MIN_HEIGHT {
+        draw_too_small(f, area);
+        return;
+    }
+    layout::draw_layout(f, app, cache);
+}
+
+fn draw_too_small(f: &mut Frame, area: Rect) {
+    let msg = format!(
+        "Terminal too small\n{}x{}, needs {}x{}",
+        area.width, area.height, MIN_WIDTH, MIN_HEIGHT
+    );
+    let popup_area = util::centered_rect(area, area.width, 2);
+    let p = Paragraph::new(msg)
+        .style(theme::EMPTY_STATE)
+        .alignment(Alignment::Center)
+        .wrap(Wrap { trim: true });
+    f.render_widget(p, popup_area);
 }
@@ -0,0 +1,93 @@
+use ratatui::layout::Rect;
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, Clear, Paragraph, Wrap};
+use ratatui::Frame;
+
+use super::plans_view::markdown_line;
+use super::theme;
+use super::util::centered_rect;
+use crate::app::App;
+
+pub fn draw_notes(f: &mut Frame, area: Rect, app: &App) {
+    let title = if app.notes_raw {
+        " .associate/notes.md (raw) "
+    } else {
+        " .associate/notes.md "
+    };
+
+    if app.notes_editing {
+        let block = Block::default()
+            .title(title)
+            .borders(Borders::ALL)
+            .border_style(theme::FB_EDIT_BORDER);
+        if let Some(ref editor) = app.notes_editor {
+            let inner = block.inner(area);
+            f.render_widget(block, area);
+            f.render_widget(editor, inner);
+        }
+        return;
+    }
+
+    let block = Block::default()
+        .title(title)
+        .borders(Borders::ALL)
+        .border_style(theme::BORDER_ACTIVE);
+
+    if app.notes_text.trim().is_empty() {
+        let p = Paragraph::new(
+            "No notes yet.\n\nPress e to write some, or Ctrl+N on any tab to jot one down.",
+        )
+        .style(theme::EMPTY_STATE)
+        .block(block)
+        .wrap(Wrap { trim: false });
+        f.render_widget(p, area);
+        return;
+    }
+
+    let inner = block.inner(area);
+    f.render_widget(block, area);
+
+    let inner_height = inner.height as usize;
+    let total = if app.notes_raw {
+        app.notes_text.lines().count()
+    } else {
+        app.notes_lines.len()
+    };
+    let scroll = app.notes_scroll.min(total.saturating_sub(inner_height));
+    let rendered: Vec<Line> = if app.notes_raw {
+        app.notes_text
+            .lines()
+            .skip(scroll)
+            .take(inner_height)
+            .map(Line::raw)
+            .collect()
+    } else {
+        app.notes_lines
+            .iter()
+            .skip(scroll)
+            .take(inner_height)
+            .map(|ml| markdown_line(ml, inner.width as usize))
+            .collect()
+    };
+    f.render_widget(Paragraph::new(rendered), inner);
+}
+
+/// One-line popup for a note jotted with `Ctrl+N`, over whichever tab is
+/// showing.
+pub fn draw_note_input(f: &mut Frame, area: Rect, app: &App) {
+    let Some(ref text) = app.note_input else {
+        return;
+    };
+    let popup_area = centered_rect(area, 72, 3);
+    f.render_widget(Clear, popup_area);
+    let block = Block::default()
+        .title(" Add to notes ")
+        .title_bottom(" Enter add  Esc cancel ")
+        .borders(Borders::ALL)
+        .border_style(theme::FB_EDIT_BORDER);
+    let line = Line::from(vec![
+        Span::styled(" Note: ", theme::HELP_DESC),
+        Span::styled(format!("{}_", text), theme::BRANCH_PICKER_INPUT),
+    ]);
+    f.render_widget(Paragraph::new(line).block(block), popup_area);
+}
@@ -0,0 +1,85 @@
+use ratatui::layout::{Constraint, Direction, Layout, Rect};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, Paragraph};
+use ratatui::Frame;
+
+use super::theme;
+use super::util::truncate_chars;
+use crate::app::{App, OverviewCard, OVERVIEW_COLUMNS};
+
+pub fn draw_overview(f: &mut Frame, area: Rect, app: &App) {
+    let cards = app.overview_cards();
+    if cards.is_empty() {
+        let block = Block::default()
+            .title(" Overview ")
+            .borders(Borders::ALL)
+            .border_style(theme::BORDER_ACTIVE);
+        let p = Paragraph::new("No other tabs are enabled.")
+            .style(theme::EMPTY_STATE)
+            .block(block);
+        f.render_widget(p, area);
+        return;
+    }
+
+    let row_count = cards.len().div_ceil(OVERVIEW_COLUMNS);
+    let rows = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints(vec![Constraint::Ratio(1, row_count as u32); row_count])
+        .split(area);
+
+    for (row, row_cards) in cards.chunks(OVERVIEW_COLUMNS).enumerate() {
+        let columns = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints(vec![
+                Constraint::Ratio(1, OVERVIEW_COLUMNS as u32);
+                OVERVIEW_COLUMNS
+            ])
+            .split(rows[row]);
+        for (col, card) in row_cards.iter().enumerate() {
+            let selected = row * OVERVIEW_COLUMNS + col == app.overview_index;
+            draw_card(f, columns[col], card, selected);
+        }
+    }
+}
+
+fn draw_card(f: &mut Frame, area: Rect, card: &OverviewCard, selected: bool) {
+    let border = if selected {
+        theme::BORDER_ACTIVE
+    } else {
+        theme::BORDER_INACTIVE
+    };
+    let block = Block::default()
+        .title(format!(" {} ", card.title))
+        .borders(Borders::ALL)
+        .border_style(border);
+
+    let width = area.width.saturating_sub(2) as usize;
+    let mut lines = vec![
+        Line::from(Span::styled(
+            truncate_chars(&card.headline, width).to_string(),
+            theme::OVERVIEW_HEADLINE,
+        )),
+        Line::from(""),
+    ];
+    let room = area.height.saturating_sub(4) as usize;
+    let hidden = card.details.len().saturating_sub(room);
+    let shown = if hidden > 0 {
+        room.saturating_sub(1)
+    } else {
+        room
+    };
+    for detail in card.details.iter().take(shown) {
+        lines.push(Line::from(Span::styled(
+            truncate_chars(detail, width).to_string(),
+            theme::OVERVIEW_DETAIL,
+        )));
+    }
+    if hidden > 0 {
+        lines.push(Line::from(Span::styled(
+            format!("+{} more", card.details.len() - shown),
+            theme::EMPTY_STATE,
+        )));
+    }
+
+    f.render_widget(Paragraph::new(lines).block(block), area);
+}
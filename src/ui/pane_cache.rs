@@ -0,0 +1,89 @@
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::Frame;
+
+use crate::app::ActiveTab;
+
+/// A part of the screen whose last drawing can be reused.
+#[derive(Debug, Clone, PartialEq)]
+pub enum CachedPane {
+    SessionList,
+    Transcript,
+    /// The body of any tab but Sessions, which caches its two panes apart.
+    Content(ActiveTab),
+}
+
+/// Cells of panes drawn in earlier frames. A pane whose data hasn't changed
+/// is copied back instead of having its widgets built again.
+#[derive(Default)]
+pub struct PaneCache {
+    panes: Vec<(CachedPane, Buffer)>,
+}
+
+impl PaneCache {
+    /// Draw `pane` with `draw` when it's `dirty` or was last drawn in another
+    /// area; otherwise copy its cells from the last frame.
+    pub fn draw(
+        &mut self,
+        f: &mut Frame,
+        pane: CachedPane,
+        area: Rect,
+        dirty: bool,
+        draw: impl FnOnce(&mut Frame),
+    ) {
+        let cached = self
+            .panes
+            .iter()
+            .find(|(p, cells)| *p == pane && cells.area == area);
+        if let (false, Some((_, cells))) = (dirty, cached) {
+            f.buffer_mut().merge(cells);
+            return;
+        }
+
+        draw(f);
+        let mut cells = Buffer::empty(area);
+        let buf = f.buffer_mut();
+        for y in area.top()..area.bottom() {
+            for x in area.left()..area.right() {
+                cells[(x, y)] = buf[(x, y)].clone();
+            }
+        }
+        self.panes.retain(|(p, _)| *p != pane);
+        self.panes.push((pane, cells));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ratatui::backend::TestBackend;
+    use ratatui::widgets::Paragraph;
+    use ratatui::Terminal;
+
+    #[test]
+    fn reuses_clean_panes() {
+        let mut terminal = Terminal::new(TestBackend::new(10, 2)).unwrap();
+        let mut cache = PaneCache::default();
+        let area = Rect::new(0, 0, 10, 1);
+        let mut draws = 0;
+        let mut frame = |text: &str, dirty: bool, draws: &mut u32| {
+            let buffer = terminal
+                .draw(|f| {
+                    cache.draw(f, CachedPane::Transcript, area, dirty, |f| {
+                        *draws += 1;
+                        f.render_widget(Paragraph::new(text.to_string()), area);
+                    })
+                })
+                .unwrap()
+                .buffer
+                .clone();
+            buffer[(0, 0)].symbol().to_string()
+        };
+
+        assert_eq!(frame("first", false, &mut draws), "f");
+        // Clean: the old cells stay even though the text changed
+        assert_eq!(frame("second", false, &mut draws), "f");
+        assert_eq!(frame("second", true, &mut draws), "s");
+        assert_eq!(draws, 2);
+    }
+}
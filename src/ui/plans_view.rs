@@ -1,12 +1,14 @@
 use ratatui::layout::{Constraint, Direction, Layout, Rect};
 use ratatui::text::{Line, Span};
-use ratatui::widgets::{Block, Borders, List, ListItem, ListState, Paragraph, Wrap};
+use ratatui::widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph, Wrap};
 use ratatui::Frame;
 
 use super::theme;
-use super::util::truncate_chars;
+use super::util::{centered_rect, truncate_chars};
 use crate::app::{App, PlansPane};
-use crate::model::plan::MarkdownLineKind;
+use crate::data::plans;
+use crate::model::merge::MergeChunk;
+use crate::model::plan::{MarkdownLine, MarkdownLineKind};
 
 pub fn draw_plans(f: &mut Frame, area: Rect, app: &App) {
     let chunks = Layout::default()
@@ -14,8 +16,29 @@ pub fn draw_plans(f: &mut Frame, area: Rect, app: &App) {
         .constraints([Constraint::Percentage(35), Constraint::Percentage(65)])
         .split(area);
 
+    let right = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(30), Constraint::Percentage(70)])
+        .split(chunks[1]);
+
+    // While editing, the outline and counts follow the unsaved text
+    let edited = app
+        .plan_editor
+        .as_ref()
+        .filter(|_| app.plan_editing)
+        .map(|editor| plans::parse_markdown_lines(&editor.lines().join("\n")));
+    let lines = edited.as_deref().unwrap_or(app.current_plan_lines());
+
     draw_plan_list(f, chunks[0], app);
-    draw_plan_content(f, chunks[1], app);
+    draw_plan_outline(f, right[0], app, lines);
+    draw_plan_content(f, right[1], app, lines);
+
+    if app.plan_merge.is_some() {
+        draw_merge_popup(f, area, app);
+    }
+    if app.plan_tasks.is_some() {
+        draw_plan_tasks_popup(f, area, app);
+    }
 }
 
 fn draw_plan_list(f: &mut Frame, area: Rect, app: &App) {
@@ -67,7 +90,59 @@ fn draw_plan_list(f: &mut Frame, area: Rect, app: &App) {
     f.render_stateful_widget(list, area, &mut state);
 }
 
-fn draw_plan_content(f: &mut Frame, area: Rect, app: &App) {
+fn draw_plan_outline(f: &mut Frame, area: Rect, app: &App, lines: &[MarkdownLine]) {
+    let is_active = app.plans_pane == PlansPane::Outline;
+    let border_style = if is_active {
+        theme::BORDER_ACTIVE
+    } else {
+        theme::BORDER_INACTIVE
+    };
+    let block = Block::default()
+        .title(" Outline ")
+        .borders(Borders::ALL)
+        .border_style(border_style);
+
+    let outline = plans::outline(lines);
+    if outline.is_empty() {
+        let p = Paragraph::new("No headings")
+            .style(theme::EMPTY_STATE)
+            .block(block);
+        f.render_widget(p, area);
+        return;
+    }
+
+    let width = area.width.saturating_sub(2) as usize;
+    let items: Vec<ListItem> = outline
+        .iter()
+        .map(|entry| {
+            let indent = "  ".repeat(entry.level.saturating_sub(1));
+            let text = format!("{}{}", indent, entry.title);
+            ListItem::new(Line::from(Span::styled(
+                truncate_chars(&text, width).to_string(),
+                theme::MD_HEADING,
+            )))
+        })
+        .collect();
+
+    // Outside the outline pane, highlight the section being read
+    let selected = if is_active {
+        app.plan_outline_index
+    } else {
+        outline
+            .iter()
+            .rposition(|entry| entry.line <= app.plan_content_scroll)
+            .unwrap_or(0)
+    };
+    let mut state = ListState::default();
+    state.select(Some(selected.min(outline.len() - 1)));
+
+    let list = List::new(items)
+        .block(block)
+        .highlight_style(theme::LIST_SELECTED);
+    f.render_stateful_widget(list, area, &mut state);
+}
+
+fn draw_plan_content(f: &mut Frame, area: Rect, app: &App, lines: &[MarkdownLine]) {
     let is_active = app.plans_pane == PlansPane::Content;
     let border_style = if is_active {
         theme::BORDER_ACTIVE
@@ -82,12 +157,29 @@ fn draw_plan_content(f: &mut Frame, area: Rect, app: &App) {
         " Content ".to_string()
     };
 
+    let stats = plans::plan_stats(lines);
+    let footer = format!(" {} words | {} paragraphs ", stats.words, stats.paragraphs);
+
+    // Edit mode: render the TextArea widget
+    if app.plan_editing {
+        let block = Block::default()
+            .title(title)
+            .title_bottom(Line::from(footer).right_aligned())
+            .borders(Borders::ALL)
+            .border_style(theme::FB_EDIT_BORDER);
+        if let Some(ref editor) = app.plan_editor {
+            let inner = block.inner(area);
+            f.render_widget(block, area);
+            f.render_widget(editor, inner);
+        }
+        return;
+    }
+
     let block = Block::default()
         .title(title)
         .borders(Borders::ALL)
         .border_style(border_style);
 
-    let lines = app.current_plan_lines();
     if lines.is_empty() {
         let p = Paragraph::new("Select a plan to view")
             .style(theme::EMPTY_STATE)
@@ -96,6 +188,7 @@ fn draw_plan_content(f: &mut Frame, area: Rect, app: &App) {
         return;
     }
 
+    let block = block.title_bottom(Line::from(footer).right_aligned());
     let inner = block.inner(area);
     f.render_widget(block, area);
 
@@ -109,19 +202,153 @@ fn draw_plan_content(f: &mut Frame, area: Rect, app: &App) {
 
     let rendered: Vec<Line> = lines[scroll_offset..visible_end]
         .iter()
-        .map(|ml| {
-            let style = match ml.kind {
-                MarkdownLineKind::Heading => theme::MD_HEADING,
-                MarkdownLineKind::CodeFence => theme::MD_CODE_FENCE,
-                MarkdownLineKind::CodeBlock => theme::MD_CODE_BLOCK,
-                MarkdownLineKind::Normal => theme::MD_NORMAL,
-            };
-            let available = inner.width as usize;
-            let text = truncate_chars(&ml.text, available);
-            Line::from(Span::styled(text, style))
-        })
+        .map(|ml| markdown_line(ml, inner.width as usize))
         .collect();
 
     let paragraph = Paragraph::new(rendered);
     f.render_widget(paragraph, inner);
 }
+
+/// Style one classified markdown line, truncated to `width` characters.
+pub(super) fn markdown_line(ml: &MarkdownLine, width: usize) -> Line<'_> {
+    let style = match ml.kind {
+        MarkdownLineKind::Heading => theme::MD_HEADING,
+        MarkdownLineKind::CodeFence => theme::MD_CODE_FENCE,
+        MarkdownLineKind::CodeBlock => theme::MD_CODE_BLOCK,
+        MarkdownLineKind::Normal => theme::MD_NORMAL,
+    };
+    Line::from(Span::styled(truncate_chars(&ml.text, width), style))
+}
+
+/// Per-hunk review of an agent's concurrent edits to the plan being edited.
+fn draw_merge_popup(f: &mut Frame, area: Rect, app: &App) {
+    let Some(ref merge) = app.plan_merge else {
+        return;
+    };
+
+    let width = (area.width * 4 / 5).max(20);
+    let height = (area.height * 4 / 5).max(8);
+    let popup_area = centered_rect(area, width, height);
+    f.render_widget(Clear, popup_area);
+
+    let hunks = merge.hunk_indices();
+    let title = format!(
+        " Plan changed on disk — hunk {}/{} ",
+        merge.selected + 1,
+        hunks.len()
+    );
+    let block = Block::default()
+        .title(title)
+        .borders(Borders::ALL)
+        .border_style(theme::FB_EDIT_BORDER);
+
+    let mut lines: Vec<Line> = Vec::new();
+    let mut selected_line = 0;
+    for (n, &idx) in hunks.iter().enumerate() {
+        let MergeChunk::Changed {
+            ref mine,
+            ref theirs,
+            ..
+        } = merge.chunks[idx]
+        else {
+            continue;
+        };
+        if n == merge.selected {
+            selected_line = lines.len();
+        }
+        let marker = if n == merge.selected { ">" } else { " " };
+        let (decision, decision_style) = if merge.accepted[idx] {
+            ("ACCEPT", theme::MERGE_ACCEPT)
+        } else {
+            ("REJECT", theme::MERGE_REJECT)
+        };
+        let mut header = vec![
+            Span::styled(format!("{} Hunk {} ", marker, n + 1), theme::DIFF_HUNK),
+            Span::styled(format!("[{}]", decision), decision_style),
+        ];
+        if merge.chunks[idx].is_conflict() {
+            header.push(Span::styled(" conflict", theme::MERGE_REJECT));
+        }
+        lines.push(Line::from(header));
+        for l in mine {
+            lines.push(Line::from(Span::styled(
+                format!("  - {}", l),
+                theme::DIFF_REMOVE,
+            )));
+        }
+        for l in theirs {
+            lines.push(Line::from(Span::styled(
+                format!("  + {}", l),
+                theme::DIFF_ADD,
+            )));
+        }
+        lines.push(Line::from(""));
+    }
+    lines.push(Line::from(Span::styled(
+        "j/k hunk  a accept  r reject  Enter apply  Esc keep mine",
+        theme::DIFF_HEADER,
+    )));
+
+    let paragraph = Paragraph::new(lines)
+        .block(block)
+        .scroll((selected_line as u16, 0));
+    f.render_widget(paragraph, popup_area);
+}
+
+/// Tasks proposed from the selected plan, picked before they're created.
+fn draw_plan_tasks_popup(f: &mut Frame, area: Rect, app: &App) {
+    let Some(ref preview) = app.plan_tasks else {
+        return;
+    };
+
+    let width = (area.width * 4 / 5).max(20);
+    let height = (area.height * 4 / 5).max(8);
+    let popup_area = centered_rect(area, width, height);
+    f.render_widget(Clear, popup_area);
+
+    let team = app
+        .teams
+        .get(preview.team)
+        .map(|t| t.display_name())
+        .unwrap_or_default();
+    let selected = preview.tasks.iter().filter(|t| t.selected).count();
+    let title = format!(
+        " Tasks from {} → {} [{}/{}] ",
+        preview.plan,
+        team,
+        selected,
+        preview.tasks.len()
+    );
+    let block = Block::default()
+        .title(title)
+        .borders(Borders::ALL)
+        .border_style(theme::FB_EDIT_BORDER);
+
+    let inner_width = popup_area.width.saturating_sub(12) as usize;
+    let items: Vec<ListItem> = preview
+        .tasks
+        .iter()
+        .map(|task| {
+            let check = if task.selected { "[x] " } else { "[ ] " };
+            let mut spans = vec![
+                Span::raw(check),
+                Span::styled(
+                    truncate_chars(&task.draft.subject, inner_width),
+                    theme::LIST_NORMAL,
+                ),
+            ];
+            if task.duplicate {
+                spans.push(Span::styled(" duplicate", theme::MERGE_REJECT));
+            }
+            ListItem::new(Line::from(spans))
+        })
+        .collect();
+
+    let mut state = ListState::default();
+    state.select(Some(preview.index));
+
+    let list = List::new(items)
+        .block(block)
+        .highlight_style(theme::LIST_SELECTED);
+    f.render_stateful_widget(list, popup_area, &mut state);
+}
@@ -0,0 +1,71 @@
+use ratatui::layout::{Constraint, Direction, Layout, Rect};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, Clear, Paragraph};
+use ratatui::Frame;
+
+use super::theme;
+use super::util::centered_rect;
+use crate::app::{App, IssueEditField};
+
+/// Draw the PR description Claude Code drafted, title over body, for editing
+/// before `gh pr create`.
+pub fn draw_pr_draft(f: &mut Frame, area: Rect, app: &App) {
+    let Some(ref draft) = app.pr_draft else {
+        return;
+    };
+    let width = 80u16.min(area.width.saturating_sub(6));
+    let height = 28u16.min(area.height.saturating_sub(4));
+    let popup_area = centered_rect(area, width, height);
+    f.render_widget(Clear, popup_area);
+
+    let block = Block::default()
+        .title(format!(
+            " New PR: {} → {} ",
+            draft.target.head, draft.target.base
+        ))
+        .borders(Borders::ALL)
+        .border_style(theme::BORDER_ACTIVE);
+    let inner = block.inner(popup_area);
+    f.render_widget(block, popup_area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(3),
+            Constraint::Min(1),
+            Constraint::Length(1),
+        ])
+        .split(inner);
+
+    let border = |field: IssueEditField| {
+        if draft.field == field {
+            theme::BORDER_ACTIVE
+        } else {
+            theme::BORDER_INACTIVE
+        }
+    };
+    let title_block = Block::default()
+        .title(" Title ")
+        .borders(Borders::ALL)
+        .border_style(border(IssueEditField::Title));
+    let title_inner = title_block.inner(chunks[0]);
+    f.render_widget(title_block, chunks[0]);
+    f.render_widget(&draft.title, title_inner);
+
+    let body_block = Block::default()
+        .title(" Description ")
+        .borders(Borders::ALL)
+        .border_style(border(IssueEditField::Body));
+    let body_inner = body_block.inner(chunks[1]);
+    f.render_widget(body_block, chunks[1]);
+    f.render_widget(&draft.body, body_inner);
+
+    let hint = Line::from(Span::styled(
+        format!(
+            " Tab: switch field  Ctrl+S: create on {}  Esc: discard",
+            draft.target.repo
+        ),
+        theme::HINT_DESC,
+    ));
+    f.render_widget(Paragraph::new(hint), chunks[2]);
+}
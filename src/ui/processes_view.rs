@@ -1,12 +1,14 @@
 use ratatui::layout::{Constraint, Direction, Layout, Rect};
 use ratatui::style::Modifier;
 use ratatui::text::{Line, Span};
-use ratatui::widgets::{Block, Borders, List, ListItem, ListState, Paragraph, Wrap};
+use ratatui::widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph, Wrap};
 use ratatui::Frame;
 
+use super::ansi::ansi_line;
 use super::theme;
 use crate::app::{App, ProcessesPane};
-use crate::model::process::{ProcessStatus, TicketSource};
+use crate::data::run_report;
+use crate::model::process::{ProcessStatus, TicketSource, STDERR_HEADER};
 
 pub fn draw_processes(f: &mut Frame, area: Rect, app: &App) {
     let chunks = Layout::default()
@@ -15,7 +17,66 @@ pub fn draw_processes(f: &mut Frame, area: Rect, app: &App) {
         .split(area);
 
     draw_process_list(f, chunks[0], app);
-    draw_process_output(f, chunks[1], app);
+
+    let artifacts = app.selected_artifacts().len();
+    if artifacts == 0 {
+        draw_process_output(f, chunks[1], app);
+    } else {
+        let right = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(artifacts.min(MAX_ARTIFACT_ROWS) as u16 + 2),
+                Constraint::Min(3),
+            ])
+            .split(chunks[1]);
+        draw_artifacts(f, right[0], app);
+        draw_process_output(f, right[1], app);
+    }
+
+    if app.process_report_prompt {
+        draw_report_prompt(f, area, app);
+    }
+}
+
+/// Artifact rows shown before the list scrolls.
+const MAX_ARTIFACT_ROWS: usize = 5;
+
+/// PRs, issues and commits the selected process linked; `c` moves the
+/// selection and `o` opens it.
+fn draw_artifacts(f: &mut Frame, area: Rect, app: &App) {
+    let artifacts = app.selected_artifacts();
+    let items: Vec<ListItem> = artifacts
+        .iter()
+        .map(|artifact| {
+            ListItem::new(Line::from(vec![
+                Span::styled(
+                    format!("{:<7}", artifact.kind.label()),
+                    theme::LIST_NORMAL.add_modifier(Modifier::DIM),
+                ),
+                Span::styled(
+                    &artifact.reference,
+                    theme::LIST_NORMAL.add_modifier(Modifier::BOLD),
+                ),
+                Span::raw("  "),
+                Span::styled(&artifact.url, theme::EMPTY_STATE),
+            ]))
+        })
+        .collect();
+
+    let mut state = ListState::default();
+    state.select(Some(
+        app.process_artifact_index
+            .min(artifacts.len().saturating_sub(1)),
+    ));
+
+    let block = Block::default()
+        .title(format!(" Artifacts [{}] ", artifacts.len()))
+        .borders(Borders::ALL)
+        .border_style(theme::BORDER_INACTIVE);
+    let list = List::new(items)
+        .block(block)
+        .highlight_style(theme::LIST_SELECTED);
+    f.render_stateful_widget(list, area, &mut state);
 }
 
 fn draw_process_list(f: &mut Frame, area: Rect, app: &App) {
@@ -31,7 +92,14 @@ fn draw_process_list(f: &mut Frame, area: Rect, app: &App) {
         .iter()
         .filter(|p| p.status == ProcessStatus::Running)
         .count();
-    let title = format!(" Processes [{}/{}] ", running_count, app.processes.len());
+    let mut title = format!(" Processes [{}/{}] ", running_count, app.processes.len());
+    if let Some(pane) = app.pane_usage {
+        title.push_str(&format!(
+            "pane {} {} ",
+            pane.cpu_label(),
+            pane.memory_label()
+        ));
+    }
 
     let block = Block::default()
         .title(title)
@@ -40,7 +108,7 @@ fn draw_process_list(f: &mut Frame, area: Rect, app: &App) {
 
     if app.processes.is_empty() {
         let p = Paragraph::new(
-            "No processes running\n\nPress 'c' on a GitHub PR or Jira issue to launch",
+            "No processes running\n\nPress 'p' on a PR, issue, Jira, Linear or work item ticket to launch",
         )
         .style(theme::EMPTY_STATE)
         .block(block)
@@ -54,7 +122,9 @@ fn draw_process_list(f: &mut Frame, area: Rect, app: &App) {
         .iter()
         .map(|proc| {
             let status_icon = match proc.status {
+                ProcessStatus::Queued => Span::styled(" . ", theme::PROCESS_QUEUED),
                 ProcessStatus::Running => Span::styled(" * ", theme::PROCESS_RUNNING),
+                ProcessStatus::Terminating => Span::styled(" ~ ", theme::PROCESS_TERMINATING),
                 ProcessStatus::Completed => Span::styled(" + ", theme::PROCESS_COMPLETED),
                 ProcessStatus::Failed => Span::styled(" x ", theme::PROCESS_FAILED),
             };
@@ -64,18 +134,36 @@ fn draw_process_list(f: &mut Frame, area: Rect, app: &App) {
                 TicketSource::GitHubIssue => "GH",
                 TicketSource::Linear => "LN",
                 TicketSource::Jira => "JR",
+                TicketSource::Azure => "AZ",
+                TicketSource::Dependency => "DP",
             };
 
-            let line = Line::from(vec![
+            let mut spans = vec![
                 status_icon,
                 Span::styled(
                     format!("[{}] ", source_icon),
                     theme::LIST_NORMAL.add_modifier(Modifier::DIM),
                 ),
-                Span::styled(&proc.label, theme::LIST_NORMAL.add_modifier(Modifier::BOLD)),
-                Span::raw(" "),
-                Span::styled(truncate(&proc.title, 30), theme::LIST_NORMAL),
-            ]);
+            ];
+            // Usage column, blank for processes that aren't running
+            if !app.process_usage.is_empty() {
+                let usage = match app.process_usage.get(&proc.id) {
+                    Some(usage) => format!("{:>4} {:>5} ", usage.cpu_label(), usage.memory_label()),
+                    None => " ".repeat(11),
+                };
+                spans.push(Span::styled(usage, theme::PROCESS_USAGE));
+            }
+            spans.push(Span::styled(
+                &proc.label,
+                theme::LIST_NORMAL.add_modifier(Modifier::BOLD),
+            ));
+            spans.push(Span::raw(" "));
+            // Tells parallel runs on different models apart
+            if let Some(ref model) = proc.model {
+                spans.push(Span::styled(format!("{} ", model), theme::PROCESS_MODEL));
+            }
+            spans.push(Span::styled(truncate(&proc.title, 30), theme::LIST_NORMAL));
+            let line = Line::from(spans);
 
             ListItem::new(line)
         })
@@ -103,7 +191,9 @@ fn draw_process_output(f: &mut Frame, area: Rect, app: &App) {
 
     let title = if let Some(p) = proc {
         let status_str = match p.status {
+            ProcessStatus::Queued => "QUEUED",
             ProcessStatus::Running => "RUNNING",
+            ProcessStatus::Terminating => "TERMINATING...",
             ProcessStatus::Completed => "DONE",
             ProcessStatus::Failed => "FAILED",
         };
@@ -112,10 +202,15 @@ fn draw_process_output(f: &mut Frame, area: Rect, app: &App) {
             .as_deref()
             .map(|s| format!(" [sid:{}]", &s[..8.min(s.len())]))
             .unwrap_or_default();
-        let follow_indicator = if app.process_follow { " [FOLLOW]" } else { "" };
+        let follow_indicator = if p.follow { " [FOLLOW]" } else { "" };
+        let search = match (&app.process_search_input, &app.process_search) {
+            (Some(input), _) => format!(" /{}_", input),
+            (None, Some(query)) => format!(" [/{}]", query),
+            (None, None) => String::new(),
+        };
         format!(
-            " {} {} [{}]{}{} ",
-            p.label, p.title, status_str, sid_suffix, follow_indicator
+            " {} {} [{}]{}{}{} ",
+            p.label, p.title, status_str, sid_suffix, follow_indicator, search
         )
     } else {
         " Output ".to_string()
@@ -152,45 +247,59 @@ fn draw_process_output(f: &mut Frame, area: Rect, app: &App) {
             } else {
                 theme::PROCESS_STDOUT
             };
-            lines.push(Line::from(Span::styled(line.as_str(), style)));
+            lines.push(ansi_line(line, style));
         }
     } else {
         // Fall back to raw output lines dimly if no parsed progress yet
         for line in &proc.output_lines {
-            lines.push(Line::from(Span::styled(
-                line.as_str(),
+            lines.push(ansi_line(
+                line,
                 theme::PROCESS_STDOUT.add_modifier(Modifier::DIM),
-            )));
+            ));
         }
     }
 
     if !proc.error_lines.is_empty() {
         lines.push(Line::from(""));
         lines.push(Line::from(Span::styled(
-            "--- stderr ---",
+            STDERR_HEADER,
             theme::PROCESS_STDERR_HEADER,
         )));
         for line in &proc.error_lines {
-            lines.push(Line::from(Span::styled(
-                line.as_str(),
-                theme::PROCESS_STDERR,
-            )));
+            lines.push(ansi_line(line, theme::PROCESS_STDERR));
         }
     }
 
     if lines.is_empty() {
-        lines.push(Line::from(Span::styled(
-            "Waiting for output...",
-            theme::EMPTY_STATE,
-        )));
+        let waiting = if proc.status == ProcessStatus::Queued {
+            "Queued: starts when a running process finishes (x cancels)"
+        } else {
+            "Waiting for output..."
+        };
+        lines.push(Line::from(Span::styled(waiting, theme::EMPTY_STATE)));
+    }
+
+    if let Some(ref query) = app.process_search {
+        for i in proc.output_matches(query) {
+            lines[i] = std::mem::take(&mut lines[i]).patch_style(theme::PROCESS_SEARCH_MATCH);
+        }
+    }
+    if let Some(line) = app.process_output_mark.filter(|&i| i < lines.len()) {
+        lines[line] = std::mem::take(&mut lines[line]).patch_style(theme::PROCESS_OUTPUT_MARK);
     }
 
-    // Apply scroll offset
+    // Scroll is counted up from the bottom; follow pins it there, and a
+    // search or error jump pins a line to the top
     let inner_height = inner.height as usize;
     let total = lines.len();
-    let scroll_offset = app
-        .process_output_scroll
-        .min(total.saturating_sub(inner_height));
+    let bottom = total.saturating_sub(inner_height);
+    let scroll_offset = if proc.follow {
+        bottom
+    } else if let Some(top) = app.process_output_top {
+        top.min(bottom)
+    } else {
+        bottom.saturating_sub(app.process_output_scroll)
+    };
     let visible_end = (scroll_offset + inner_height).min(total);
 
     let visible_lines: Vec<Line> = lines[scroll_offset..visible_end].to_vec();
@@ -198,6 +307,51 @@ fn draw_process_output(f: &mut Frame, area: Rect, app: &App) {
     f.render_widget(paragraph, inner);
 }
 
+/// Popup asking where `E` exports the selected run's report.
+fn draw_report_prompt(f: &mut Frame, area: Rect, app: &App) {
+    let Some(proc) = app.selected_process() else {
+        return;
+    };
+    let width = 64u16.min(area.width.saturating_sub(4));
+    let height = 6u16.min(area.height);
+    let popup_area = Rect {
+        x: area.x + (area.width.saturating_sub(width)) / 2,
+        y: area.y + (area.height.saturating_sub(height)) / 2,
+        width,
+        height,
+    };
+    f.render_widget(Clear, popup_area);
+
+    let dir = app.project_config.process_report_dir();
+    let mut lines = vec![
+        Line::from(vec![
+            Span::styled(" f ", theme::HELP_KEY),
+            Span::raw(format!("Save as Markdown in {}/", dir.display())),
+        ]),
+        match run_report::comment_target(&proc.source) {
+            Some(target) => Line::from(vec![
+                Span::styled(" c ", theme::HELP_KEY),
+                Span::raw(format!("Post as a comment on {} ({})", target, proc.label)),
+            ]),
+            None => Line::from(Span::styled(
+                "   This ticket can't take comments",
+                theme::EMPTY_STATE,
+            )),
+        },
+    ];
+    lines.push(Line::from(""));
+    lines.push(Line::from(vec![
+        Span::styled(" Esc ", theme::HELP_KEY),
+        Span::raw("cancel"),
+    ]));
+
+    let block = Block::default()
+        .title(format!(" Export run report: {} ", proc.label))
+        .borders(Borders::ALL)
+        .border_style(theme::BORDER_ACTIVE);
+    f.render_widget(Paragraph::new(lines).block(block), popup_area);
+}
+
 fn truncate(s: &str, max: usize) -> String {
     let char_count = s.chars().count();
     if char_count <= max {
@@ -1,11 +1,13 @@
 use ratatui::layout::{Constraint, Direction, Layout, Rect};
 use ratatui::style::{Modifier, Style};
 use ratatui::text::{Line, Span};
-use ratatui::widgets::{Block, Borders, Clear, Paragraph};
+use ratatui::widgets::{Block, Borders, Clear, Paragraph, Wrap};
 use ratatui::Frame;
 
 use super::theme;
 use crate::app::App;
+use crate::model::prompt::PromptPreview;
+use crate::model::transcript::format_tokens;
 
 /// Draw the prompt editor modal overlay.
 pub fn draw_prompt_modal(f: &mut Frame, area: Rect, app: &App) {
@@ -57,15 +59,29 @@ pub fn draw_prompt_modal(f: &mut Frame, area: Rect, app: &App) {
         "Prompt Editor".to_string()
     };
 
+    let heading = if app.prompt_preview.is_some() {
+        "Dry run"
+    } else {
+        "Launch Claude"
+    };
     let title_block = Block::default()
-        .title(format!(" Launch Claude: {} ", ticket_label))
+        .title(format!(" {}: {} ", heading, ticket_label))
         .borders(Borders::TOP | Borders::LEFT | Borders::RIGHT)
         .border_style(theme::PROMPT_MODAL_BORDER);
     let title_text = Paragraph::new("").block(title_block);
     f.render_widget(title_text, title_area);
 
-    // Editor
-    if let Some(ref editor) = app.prompt_editor {
+    // Dry run preview, or the editor
+    if let Some(ref preview) = app.prompt_preview {
+        let preview_block = Block::default()
+            .borders(Borders::LEFT | Borders::RIGHT)
+            .border_style(theme::PROMPT_MODAL_BORDER);
+        let paragraph = Paragraph::new(preview_lines(app, preview))
+            .block(preview_block)
+            .wrap(Wrap { trim: false })
+            .scroll((preview.scroll.min(u16::MAX as usize) as u16, 0));
+        f.render_widget(paragraph, editor_area);
+    } else if let Some(ref editor) = app.prompt_editor {
         let editor_block = Block::default()
             .borders(Borders::LEFT | Borders::RIGHT)
             .border_style(theme::PROMPT_MODAL_BORDER);
@@ -79,12 +95,42 @@ pub fn draw_prompt_modal(f: &mut Frame, area: Rect, app: &App) {
     }
 
     // Hints at bottom
-    let hints = Line::from(vec![
-        Span::styled(" Ctrl+Enter", theme::HELP_KEY),
-        Span::styled(": Launch  ", theme::HELP_DESC),
-        Span::styled("Esc", theme::HELP_KEY),
-        Span::styled(": Cancel ", theme::HELP_DESC),
-    ]);
+    let hints = if app.prompt_args_input.is_some() || app.prompt_turns_input.is_some() {
+        Line::from(vec![
+            Span::styled(" Enter", theme::HELP_KEY),
+            Span::styled(": Use  ", theme::HELP_DESC),
+            Span::styled("Esc", theme::HELP_KEY),
+            Span::styled(": Cancel ", theme::HELP_DESC),
+        ])
+    } else if app.prompt_preview.is_some() {
+        Line::from(vec![
+            Span::styled(" Ctrl+Enter", theme::HELP_KEY),
+            Span::styled(": Launch  ", theme::HELP_DESC),
+            Span::styled("m", theme::HELP_KEY),
+            Span::styled(": Model  ", theme::HELP_DESC),
+            Span::styled("g", theme::HELP_KEY),
+            Span::styled(": Agent  ", theme::HELP_DESC),
+            Span::styled("t", theme::HELP_KEY),
+            Span::styled(": Max turns  ", theme::HELP_DESC),
+            Span::styled("a", theme::HELP_KEY),
+            Span::styled(": Args  ", theme::HELP_DESC),
+            Span::styled("p", theme::HELP_KEY),
+            Span::styled(": Permissions  ", theme::HELP_DESC),
+            Span::styled("j/k", theme::HELP_KEY),
+            Span::styled(": Scroll  ", theme::HELP_DESC),
+            Span::styled("Ctrl+T/Esc", theme::HELP_KEY),
+            Span::styled(": Back to editor ", theme::HELP_DESC),
+        ])
+    } else {
+        Line::from(vec![
+            Span::styled(" Ctrl+Enter", theme::HELP_KEY),
+            Span::styled(": Review & launch  ", theme::HELP_DESC),
+            Span::styled("Ctrl+T", theme::HELP_KEY),
+            Span::styled(": Dry run  ", theme::HELP_DESC),
+            Span::styled("Esc", theme::HELP_KEY),
+            Span::styled(": Cancel ", theme::HELP_DESC),
+        ])
+    };
     let hint_block = Block::default()
         .borders(Borders::BOTTOM | Borders::LEFT | Borders::RIGHT)
         .border_style(theme::PROMPT_MODAL_BORDER);
@@ -92,6 +138,120 @@ pub fn draw_prompt_modal(f: &mut Frame, area: Rect, app: &App) {
     f.render_widget(hint_paragraph, hint_area);
 }
 
+/// The size of the prompt, the exact command it would run and where, and
+/// any unfilled placeholders above the prompt text.
+fn preview_lines<'a>(app: &App, preview: &'a PromptPreview) -> Vec<Line<'a>> {
+    let mut command = String::from("claude -p <prompt>");
+    let args = app.prompt_command_args();
+    for (i, arg) in args.iter().enumerate() {
+        command.push(' ');
+        // The agent's definition is its whole file; name it instead
+        if i > 0 && args[i - 1] == "--agents" {
+            command.push_str("<agent definition>");
+        } else {
+            command.push_str(arg);
+        }
+    }
+    let permissions = if app.prompt_skip_permissions {
+        Span::styled(
+            "Permissions: skipped (--dangerously-skip-permissions)",
+            theme::PROMPT_PREVIEW_UNRESOLVED,
+        )
+    } else {
+        Span::styled(
+            "Permissions: prompted (headless runs stop at the first ask)",
+            theme::PROMPT_PREVIEW_INFO,
+        )
+    };
+    let args_line = match app.prompt_args_input {
+        Some(ref input) => Line::from(vec![
+            Span::styled("Extra args: ", theme::PROMPT_PREVIEW_INFO),
+            Span::styled(format!("{}_", input), theme::PROMPT_EDITOR_TEXT),
+        ]),
+        None => Line::from(Span::styled(
+            format!(
+                "Extra args: {}",
+                if app.prompt_launch_args.is_empty() {
+                    "(none)".to_string()
+                } else {
+                    app.prompt_launch_args.join(" ")
+                }
+            ),
+            theme::PROMPT_PREVIEW_INFO,
+        )),
+    };
+    let turns_line = match app.prompt_turns_input {
+        Some(ref input) => Line::from(vec![
+            Span::styled("Max turns: ", theme::PROMPT_PREVIEW_INFO),
+            Span::styled(format!("{}_", input), theme::PROMPT_EDITOR_TEXT),
+        ]),
+        None => Line::from(Span::styled(
+            format!(
+                "Max turns: {}",
+                app.prompt_max_turns
+                    .map(|turns| turns.to_string())
+                    .unwrap_or_else(|| "unlimited".to_string())
+            ),
+            theme::PROMPT_PREVIEW_INFO,
+        )),
+    };
+    let mut lines = vec![
+        Line::from(Span::styled(
+            format!(
+                "~{} tokens, {} characters, {} lines",
+                format_tokens(preview.tokens),
+                preview.text.chars().count(),
+                preview.text.lines().count()
+            ),
+            theme::PROMPT_PREVIEW_INFO,
+        )),
+        Line::from(Span::styled(command, theme::PROMPT_PREVIEW_INFO)),
+        Line::from(Span::styled(
+            format!("in {}", app.project_cwd.display()),
+            theme::PROMPT_PREVIEW_INFO,
+        )),
+        Line::from(Span::styled(
+            format!(
+                "Model: {}",
+                app.prompt_model.as_deref().unwrap_or("default")
+            ),
+            theme::PROMPT_PREVIEW_INFO,
+        )),
+        Line::from(Span::styled(
+            format!(
+                "Agent: {}",
+                app.prompt_agent
+                    .as_ref()
+                    .map(|(name, _)| name.as_str())
+                    .unwrap_or("none")
+            ),
+            theme::PROMPT_PREVIEW_INFO,
+        )),
+        turns_line,
+        args_line,
+        Line::from(permissions),
+    ];
+    if !preview.unresolved.is_empty() {
+        let names: Vec<String> = preview
+            .unresolved
+            .iter()
+            .map(|name| format!("{{{{{}}}}}", name))
+            .collect();
+        lines.push(Line::from(Span::styled(
+            format!("Unfilled variables: {}", names.join(", ")),
+            theme::PROMPT_PREVIEW_UNRESOLVED,
+        )));
+    }
+    lines.push(Line::from(""));
+    lines.extend(
+        preview
+            .text
+            .lines()
+            .map(|l| Line::from(Span::styled(l, theme::PROMPT_EDITOR_TEXT))),
+    );
+    lines
+}
+
 /// Draw the prompt picker overlay — a small list of available prompts.
 pub fn draw_prompt_picker(f: &mut Frame, area: Rect, app: &App) {
     let item_count = app.prompt_picker_len();
@@ -149,6 +309,32 @@ pub fn draw_prompt_picker(f: &mut Frame, area: Rect, app: &App) {
         )));
     }
 
+    let offset = 1 + app.project_config.prompts.len();
+    for (i, template) in app.prompt_library.iter().enumerate() {
+        let idx = i + offset;
+        let style = if app.prompt_picker_index == idx {
+            Style::new()
+                .fg(ratatui::style::Color::White)
+                .bg(ratatui::style::Color::DarkGray)
+                .add_modifier(Modifier::BOLD)
+        } else {
+            Style::new().fg(ratatui::style::Color::White)
+        };
+        let pfx = if app.prompt_picker_index == idx {
+            "> "
+        } else {
+            "  "
+        };
+        let mut spans = vec![Span::styled(format!("{}{}", pfx, template.title), style)];
+        if let Some(ref model) = template.model {
+            spans.push(Span::styled(
+                format!("  [{}]", model),
+                theme::PROMPT_TEMPLATE_MODEL,
+            ));
+        }
+        lines.push(Line::from(spans));
+    }
+
     // Split popup into title, list, hint
     let inner = Layout::default()
         .direction(Direction::Vertical)
@@ -170,7 +356,11 @@ pub fn draw_prompt_picker(f: &mut Frame, area: Rect, app: &App) {
     let list_block = Block::default()
         .borders(Borders::LEFT | Borders::RIGHT)
         .border_style(theme::PROMPT_MODAL_BORDER);
-    f.render_widget(Paragraph::new(lines).block(list_block), inner[1]);
+    let scroll = (app.prompt_picker_index as u16).saturating_sub(content_height.saturating_sub(1));
+    f.render_widget(
+        Paragraph::new(lines).block(list_block).scroll((scroll, 0)),
+        inner[1],
+    );
 
     // Hints
     let hints = Line::from(vec![
@@ -0,0 +1,50 @@
+use ratatui::layout::Rect;
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, Clear, List, ListItem, ListState};
+use ratatui::Frame;
+
+use super::theme;
+use crate::app::App;
+
+/// Draw the git remote picker as a dropdown under the tab bar's right edge.
+pub fn draw_remote_picker(f: &mut Frame, area: Rect, app: &App) {
+    let width = 56u16.min(area.width.saturating_sub(2));
+    let height = (app.gh_remotes.len() as u16 + 2).min(area.height.saturating_sub(2));
+    let popup_area = Rect {
+        x: area.x + area.width.saturating_sub(width + 1),
+        y: area.y + 1,
+        width,
+        height,
+    };
+    f.render_widget(Clear, popup_area);
+
+    let block = Block::default()
+        .title(" GitHub remote (Enter use, Esc close) ")
+        .borders(Borders::ALL)
+        .border_style(theme::PROMPT_MODAL_BORDER);
+
+    let items: Vec<ListItem> = app
+        .gh_remotes
+        .iter()
+        .map(|remote| {
+            let current = app.gh_repo.as_ref() == Some(&remote.repo);
+            let (marker, name_style) = if current {
+                ("* ", theme::BRANCH_CURRENT)
+            } else {
+                ("  ", theme::FB_FILE)
+            };
+            ListItem::new(Line::from(vec![
+                Span::styled(marker, theme::BRANCH_CURRENT),
+                Span::styled(format!("{:<12}", remote.name), name_style),
+                Span::styled(remote.repo.clone(), theme::BRANCH_DETAIL),
+            ]))
+        })
+        .collect();
+
+    let list = List::new(items)
+        .block(block)
+        .highlight_style(theme::LIST_SELECTED);
+    let mut state = ListState::default();
+    state.select(Some(app.remote_picker_index));
+    f.render_stateful_widget(list, popup_area, &mut state);
+}
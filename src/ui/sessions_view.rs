@@ -1,21 +1,84 @@
 use ratatui::layout::{Constraint, Direction, Layout, Rect};
+use ratatui::style::Style;
 use ratatui::text::{Line, Span};
-use ratatui::widgets::{Block, Borders, List, ListItem, ListState, Paragraph, Wrap};
+use ratatui::widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph, Wrap};
 use ratatui::Frame;
 
-use super::theme;
-use super::util::truncate_chars;
+use super::pane_cache::{CachedPane, PaneCache};
+use super::util::{centered_rect, home_span, truncate_chars};
+use super::{activity_heatmap, filter_bar, plans_view, theme};
 use crate::app::{App, SessionsPane};
-use crate::model::transcript::TranscriptItemKind;
-
-pub fn draw_sessions(f: &mut Frame, area: Rect, app: &App) {
-    let chunks = Layout::default()
+use crate::model::transcript::{
+    format_elapsed, format_tokens, prefix_width, TranscriptItem, TranscriptItemKind, TurnUsage,
+    USAGE_COLUMN_WIDTH,
+};
+
+/// Split `area` into the session list and the transcript.
+fn split(area: Rect) -> std::rc::Rc<[Rect]> {
+    Layout::default()
         .direction(Direction::Horizontal)
         .constraints([Constraint::Percentage(40), Constraint::Percentage(60)])
-        .split(area);
+        .split(area)
+}
+
+/// Width inside the transcript pane's borders on a terminal `width` cells
+/// wide.
+pub fn transcript_width(width: u16) -> u16 {
+    split(Rect::new(0, 0, width, 1))[1].width.saturating_sub(2)
+}
+
+/// Draw the session list and the transcript, copying whichever of them
+/// hasn't changed from the last frame.
+pub fn draw_sessions(f: &mut Frame, area: Rect, app: &App, cache: &mut PaneCache) {
+    let chunks = split(area);
+    let mut list = |f: &mut Frame, area: Rect| {
+        cache.draw(
+            f,
+            CachedPane::SessionList,
+            area,
+            app.dirty.session_list,
+            |f| draw_session_list(f, area, app),
+        )
+    };
 
-    draw_session_list(f, chunks[0], app);
-    draw_transcript(f, chunks[1], app);
+    if app.session_rename_input.is_some() {
+        let left = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Min(3), Constraint::Length(3)])
+            .split(chunks[0]);
+        list(f, left[0]);
+        draw_rename_input(f, left[1], app);
+    } else {
+        filter_bar::with_filter_input(f, chunks[0], app, list);
+    }
+    cache.draw(
+        f,
+        CachedPane::Transcript,
+        chunks[1],
+        app.dirty.transcript,
+        |f| {
+            if app.show_session_stats {
+                draw_session_stats(f, chunks[1], app);
+            } else {
+                draw_transcript(f, chunks[1], app);
+            }
+        },
+    );
+}
+
+fn draw_rename_input(f: &mut Frame, area: Rect, app: &App) {
+    let Some(ref title) = app.session_rename_input else {
+        return;
+    };
+    let block = Block::default()
+        .title(" Rename session (empty restores the generated title) ")
+        .borders(Borders::ALL)
+        .border_style(theme::FB_EDIT_BORDER);
+    let line = Line::from(vec![
+        Span::styled(" Title: ", theme::HELP_DESC),
+        Span::styled(format!("{}_", title), theme::BRANCH_PICKER_INPUT),
+    ]);
+    f.render_widget(Paragraph::new(line).block(block), area);
 }
 
 fn draw_session_list(f: &mut Frame, area: Rect, app: &App) {
@@ -26,7 +89,7 @@ fn draw_session_list(f: &mut Frame, area: Rect, app: &App) {
         theme::BORDER_INACTIVE
     };
 
-    let title = format!(" Sessions [{}] ", app.sessions.len());
+    let title = filter_bar::list_title("Sessions", app.sessions.len(), app.all_sessions.len(), app);
     let block = Block::default()
         .title(title)
         .borders(Borders::ALL)
@@ -34,8 +97,11 @@ fn draw_session_list(f: &mut Frame, area: Rect, app: &App) {
 
     if app.sessions.is_empty() {
         let msg = Paragraph::new(format!(
-            "No sessions found.\nLooking in: ~/.claude/projects/{}/",
-            app.encoded_project
+            "No sessions found.\nLooking in: {}/",
+            app.claude_home
+                .join("projects")
+                .join(&app.encoded_project)
+                .display()
         ))
         .style(theme::EMPTY_STATE)
         .block(block)
@@ -44,50 +110,72 @@ fn draw_session_list(f: &mut Frame, area: Rect, app: &App) {
         return;
     }
 
-    let items: Vec<ListItem> = app
-        .sessions
-        .iter()
-        .enumerate()
-        .map(|(i, s)| {
-            let prefix = if i == app.session_list_index {
-                ">"
-            } else {
-                " "
-            };
-            let branch = s.branch();
-            let branch_span = if branch.is_empty() {
-                Span::raw("")
-            } else {
-                Span::styled(format!("  {}", branch), theme::BRANCH_LABEL)
+    let mut items: Vec<ListItem> = Vec::with_capacity(app.sessions.len());
+    let mut selected_row = 0;
+    for (i, s) in app.sessions.iter().enumerate() {
+        if app.sessions_by_ticket
+            && (i == 0 || app.sessions[i - 1].link.as_ref() != s.link.as_ref())
+        {
+            let header = match s.link {
+                Some(ref link) => format!("{}  {}", link.label(), link.title),
+                None => "Not linked".to_string(),
             };
+            items.push(ListItem::new(Line::from(Span::styled(
+                truncate_chars(&header, 50).to_string(),
+                theme::SESSION_GROUP_HEADER,
+            ))));
+        }
+        if i == app.session_list_index {
+            selected_row = items.len();
+        }
+        let prefix = if i == app.session_list_index {
+            ">"
+        } else {
+            " "
+        };
+        let branch = s.branch();
+        let branch_span = if branch.is_empty() {
+            Span::raw("")
+        } else {
+            Span::styled(format!("  {}", branch), theme::BRANCH_LABEL)
+        };
 
-            let title_raw = s.display_title();
-            let title_text = truncate_chars(&title_raw, 30).to_string();
-
-            // Subagent indicator: check if this is the loaded session and has subagents
-            let subagent_span = if app.loaded_session_id.as_deref() == Some(&s.session_id)
-                && !app.subagents.is_empty()
-            {
-                Span::styled(
-                    format!(" [{} agents]", app.subagents.len()),
-                    theme::SUBAGENT_BADGE,
-                )
-            } else {
-                Span::raw("")
-            };
+        let title_raw = s.display_title();
+        let title_text = truncate_chars(&title_raw, 30).to_string();
+
+        // Subagent indicator: check if this is the loaded session and has subagents
+        let subagent_span = if app.loaded_session_id.as_deref() == Some(&s.session_id)
+            && !app.subagents.is_empty()
+        {
+            Span::styled(
+                format!(" [{} agents]", app.subagents.len()),
+                theme::SUBAGENT_BADGE,
+            )
+        } else {
+            Span::raw("")
+        };
 
-            let line = Line::from(vec![
-                Span::raw(format!("{} ", prefix)),
-                Span::raw(title_text),
-                branch_span,
-                subagent_span,
-            ]);
-            ListItem::new(line)
-        })
-        .collect();
+        // In the grouped list the header already names the ticket
+        let link_span = match s.link {
+            Some(ref link) if !app.sessions_by_ticket => {
+                Span::styled(format!("  [{}]", link.label()), theme::SESSION_LINK)
+            }
+            _ => Span::raw(""),
+        };
+
+        let line = Line::from(vec![
+            Span::raw(format!("{} ", prefix)),
+            Span::raw(title_text),
+            branch_span,
+            link_span,
+            subagent_span,
+            home_span(app, s.home),
+        ]);
+        items.push(ListItem::new(line));
+    }
 
     let mut state = ListState::default();
-    state.select(Some(app.session_list_index));
+    state.select(Some(selected_row));
 
     let list = List::new(items)
         .block(block)
@@ -203,12 +291,119 @@ fn draw_transcript(f: &mut Frame, area: Rect, app: &App) {
     }
 }
 
-fn draw_transcript_content(
-    f: &mut Frame,
-    area: Rect,
-    items: &[crate::model::transcript::TranscriptItem],
-    app: &App,
-) {
+/// Stats pane for the selected session: duration, messages by role, tokens,
+/// subagents, tool calls per tool and the files it edited.
+fn draw_session_stats(f: &mut Frame, area: Rect, app: &App) {
+    let border_style = if app.sessions_pane == SessionsPane::Transcript {
+        theme::BORDER_ACTIVE
+    } else {
+        theme::BORDER_INACTIVE
+    };
+    let session = app.sessions.get(app.session_list_index);
+    let title = session
+        .map(|s| truncate_chars(&s.display_title(), 30).to_string())
+        .unwrap_or_default();
+    let block = Block::default()
+        .title(format!(" Stats: {} ", title))
+        .borders(Borders::ALL)
+        .border_style(border_style);
+    let inner = block.inner(area);
+    f.render_widget(block, area);
+    let area = activity_heatmap::draw_activity_heatmap(f, inner, app);
+
+    let Some(stats) = app.selected_session_stats() else {
+        let msg = if session.is_none() {
+            "Select a session to view its stats"
+        } else {
+            "Scanning transcript..."
+        };
+        let p = Paragraph::new(msg).style(theme::EMPTY_STATE);
+        f.render_widget(p, area);
+        return;
+    };
+
+    let label = Style::new().fg(ratatui::style::Color::Yellow);
+    let mut lines = Vec::new();
+    let duration = match (stats.duration_ms(), stats.started, stats.ended) {
+        (Some(ms), Some(start), Some(end)) => format!(
+            "{} ({} - {})",
+            format_elapsed(ms),
+            start.with_timezone(&chrono::Local).format("%Y-%m-%d %H:%M"),
+            end.with_timezone(&chrono::Local).format("%H:%M")
+        ),
+        _ => "-".to_string(),
+    };
+    let rows = [
+        ("Duration:     ", duration),
+        (
+            "Messages:     ",
+            format!(
+                "{} user, {} assistant, {} tool results",
+                stats.user_messages, stats.assistant_messages, stats.tool_results
+            ),
+        ),
+        (
+            "Tokens:       ",
+            format!(
+                "{} ({} in, {} out, {} cache writes)",
+                format_tokens(stats.tokens()),
+                format_tokens(stats.input_tokens),
+                format_tokens(stats.output_tokens),
+                format_tokens(stats.cache_creation_tokens)
+            ),
+        ),
+        ("Subagents:    ", stats.subagents.to_string()),
+    ];
+    for (name, value) in rows {
+        lines.push(Line::from(vec![
+            Span::styled(name, label),
+            Span::raw(value),
+        ]));
+    }
+
+    lines.push(Line::from(""));
+    lines.push(Line::from(Span::styled(
+        format!("Tool calls ({}):", stats.tool_calls()),
+        label,
+    )));
+    let mut tools: Vec<(&String, &usize)> = stats.tools.iter().collect();
+    tools.sort_by(|a, b| b.1.cmp(a.1).then_with(|| a.0.cmp(b.0)));
+    for (tool, count) in tools {
+        lines.push(Line::from(format!(
+            "  {:<20} {:>5}",
+            truncate_chars(tool, 20),
+            count
+        )));
+    }
+
+    lines.push(Line::from(""));
+    lines.push(Line::from(Span::styled(
+        format!("Files touched ({}):", stats.files_touched.len()),
+        label,
+    )));
+    for file in &stats.files_touched {
+        lines.push(Line::from(format!("  {}", file)));
+    }
+
+    let p = Paragraph::new(lines).wrap(Wrap { trim: false });
+    f.render_widget(p, area);
+}
+
+/// Turns at or above these are highlighted as expensive or slow.
+const HEAVY_TURN_TOKENS: u64 = 20_000;
+const SLOW_TURN_MS: u64 = 60_000;
+
+fn usage_style(usage: &TurnUsage) -> Style {
+    let heavy = usage.tokens.is_some_and(|t| t >= HEAVY_TURN_TOKENS)
+        || usage.elapsed_ms.is_some_and(|ms| ms >= SLOW_TURN_MS);
+    if heavy {
+        theme::TX_USAGE_HEAVY
+    } else {
+        theme::TX_USAGE
+    }
+}
+
+fn draw_transcript_content(f: &mut Frame, area: Rect, items: &[TranscriptItem], app: &App) {
     if items.is_empty() {
         let p = Paragraph::new("(empty transcript)").style(theme::EMPTY_STATE);
         f.render_widget(p, area);
@@ -217,52 +412,261 @@ fn draw_transcript_content(
 
     let inner_height = area.height as usize;
     let total = items.len();
-
-    // Calculate visible range
-    let scroll_offset = if app.follow_mode && !app.viewing_subagent {
-        total.saturating_sub(inner_height)
+    let follow = app.follow_mode && !app.viewing_subagent;
+
+    // First item shown and, in wrapped mode, how many of its rows are
+    // scrolled past
+    let (scroll_offset, skip_rows) = if app.transcript_wrap {
+        if follow {
+            // Fill the pane from the bottom up
+            let mut rows = 0;
+            let mut first = total;
+            while first > 0 && rows < inner_height {
+                first -= 1;
+                rows += app.transcript_rows(&items[first]).len();
+            }
+            (first, rows.saturating_sub(inner_height))
+        } else {
+            let scroll = if app.viewing_subagent {
+                app.subagent_scroll
+            } else {
+                app.transcript_scroll
+            };
+            let first = scroll.min(total - 1);
+            let rows = app.transcript_rows(&items[first]).len();
+            (first, app.transcript_wrap_row.min(rows - 1))
+        }
+    } else if follow {
+        (total.saturating_sub(inner_height), 0)
     } else if app.viewing_subagent {
-        app.subagent_scroll
-            .min(total.saturating_sub(inner_height))
+        (
+            app.subagent_scroll.min(total.saturating_sub(inner_height)),
+            0,
+        )
+    } else {
+        (
+            app.transcript_scroll
+                .min(total.saturating_sub(inner_height)),
+            0,
+        )
+    };
+
+    // "New" divider only applies to the main transcript
+    let unread_from = if app.viewing_subagent {
+        None
     } else {
-        app.transcript_scroll
-            .min(total.saturating_sub(inner_height))
+        app.transcript_unread_from
     };
 
-    let visible_end = (scroll_offset + inner_height).min(total);
+    // Enter opens the item under the cursor in full
+    let selected = if app.sessions_pane == SessionsPane::Transcript {
+        app.selected_transcript_index()
+    } else {
+        None
+    };
 
-    let lines: Vec<Line> = items[scroll_offset..visible_end]
-        .iter()
-        .map(|item| {
-            let time_str = item
-                .timestamp
-                .map(|ts| ts.format("%H:%M").to_string())
-                .unwrap_or_else(|| "     ".to_string());
-
-            let kind_style = match item.kind {
-                TranscriptItemKind::User => theme::TX_USER,
-                TranscriptItemKind::Assistant => theme::TX_ASSISTANT,
-                TranscriptItemKind::ToolUse => theme::TX_TOOL,
-                TranscriptItemKind::ToolResult => theme::TX_RESULT,
-                TranscriptItemKind::System => theme::TX_SYSTEM,
-                TranscriptItemKind::Progress => theme::TX_PROGRESS,
-                TranscriptItemKind::Other => theme::TX_PROGRESS,
+    let indent = " ".repeat(prefix_width(app.show_turn_usage));
+    let mut lines: Vec<Line> = Vec::with_capacity(inner_height + 1);
+    for (offset, item) in items[scroll_offset..].iter().enumerate() {
+        if lines.len() >= inner_height + skip_rows {
+            break;
+        }
+        let index = scroll_offset + offset;
+        // Scrolled into a wrapped item, the divider above it is out of view
+        if unread_from == Some(index) && !(offset == 0 && skip_rows > 0) {
+            let label = format!(" new ({}) ", total - index);
+            let rule = "─".repeat((area.width as usize).saturating_sub(label.len() + 2) / 2);
+            lines.push(Line::from(Span::styled(
+                format!("{}{}{}", rule, label, rule),
+                theme::TX_UNREAD_DIVIDER,
+            )));
+        }
+        let time_str = item
+            .timestamp
+            .map(|ts| ts.format("%H:%M").to_string())
+            .unwrap_or_else(|| "     ".to_string());
+
+        let kind_style = kind_style(&item.kind);
+
+        let mut spans = vec![
+            Span::raw(format!("{} ", time_str)),
+            Span::styled(format!("{} ", item.kind.label()), kind_style),
+        ];
+        if app.show_turn_usage {
+            let (summary, style) = match item.usage {
+                Some(usage) => (usage.summary(), usage_style(&usage)),
+                None => (String::new(), theme::TX_USAGE),
             };
+            spans.push(Span::styled(
+                format!("{:>w$} ", summary, w = USAGE_COLUMN_WIDTH),
+                style,
+            ));
+        }
 
-            // Truncate text to fit
-            let available_width = area.width.saturating_sub(14) as usize;
-            let text = truncate_chars(&item.text, available_width);
-            // Replace newlines with spaces for single-line display
-            let text = text.replace('\n', " ").replace('\r', "");
-
-            Line::from(vec![
-                Span::raw(format!("{} ", time_str)),
-                Span::styled(format!("{} ", item.kind.label()), kind_style),
-                Span::raw(text),
-            ])
+        // One row per line in wrapped mode, else the text truncated to fit
+        let available_width =
+            (area.width as usize).saturating_sub(prefix_width(app.show_turn_usage));
+        let mut rows = app.transcript_rows(item).into_iter();
+        let first = rows.next().unwrap_or_default();
+        spans.push(Span::raw(
+            truncate_chars(&first, available_width).to_string(),
+        ));
+
+        let row_style = if selected == Some(index) {
+            theme::TX_SELECTED
+        } else {
+            Style::default()
+        };
+        lines.push(Line::from(spans).style(row_style));
+        for row in rows {
+            lines.push(Line::from(format!("{}{}", indent, row)).style(row_style));
+        }
+    }
+
+    // Rows of the first item above the pane, in wrapped mode
+    let skip = skip_rows.min(lines.len());
+    let paragraph = Paragraph::new(lines.split_off(skip));
+    f.render_widget(paragraph, area);
+}
+
+/// Colour of a transcript item's kind label.
+pub fn kind_style(kind: &TranscriptItemKind) -> Style {
+    match kind {
+        TranscriptItemKind::User => theme::TX_USER,
+        TranscriptItemKind::Assistant => theme::TX_ASSISTANT,
+        TranscriptItemKind::ToolUse => theme::TX_TOOL,
+        TranscriptItemKind::Plan => theme::TX_PLAN,
+        TranscriptItemKind::ToolResult => theme::TX_RESULT,
+        TranscriptItemKind::System => theme::TX_SYSTEM,
+        TranscriptItemKind::Progress => theme::TX_PROGRESS,
+        TranscriptItemKind::Other => theme::TX_PROGRESS,
+    }
+}
+
+/// Popup listing loaded tickets to link the selected session to.
+pub fn draw_session_link_picker(f: &mut Frame, area: Rect, app: &App) {
+    let Some(ref picker) = app.session_link_picker else {
+        return;
+    };
+    let matches = picker.matches();
+    let width = 76u16.min(area.width.saturating_sub(4));
+    let height = (matches.len() as u16).clamp(1, 16) + 5;
+    let popup_area = centered_rect(area, width, height);
+    f.render_widget(Clear, popup_area);
+
+    let block = Block::default()
+        .title(" Link session to ticket ")
+        .title_bottom(" type to narrow  ↑/↓ move  Enter link  Del unlink  Esc cancel ")
+        .borders(Borders::ALL)
+        .border_style(theme::PROMPT_MODAL_BORDER);
+    let inner = block.inner(popup_area);
+    f.render_widget(block, popup_area);
+
+    let parts = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(2), Constraint::Min(1)])
+        .split(inner);
+
+    let query = Line::from(vec![
+        Span::styled(" Find: ", theme::HELP_DESC),
+        Span::styled(format!("{}_", picker.query), theme::BRANCH_PICKER_INPUT),
+    ]);
+    f.render_widget(Paragraph::new(query), parts[0]);
+
+    if matches.is_empty() {
+        f.render_widget(
+            Paragraph::new(" No matching tickets").style(theme::EMPTY_STATE),
+            parts[1],
+        );
+        return;
+    }
+    let items: Vec<ListItem> = matches
+        .iter()
+        .map(|link| {
+            ListItem::new(Line::from(vec![
+                Span::styled(format!(" {:<14}", link.label()), theme::SESSION_LINK),
+                Span::raw(truncate_chars(&link.title, 56).to_string()),
+            ]))
         })
         .collect();
+    let mut state = ListState::default();
+    state.select(Some(picker.index));
+    let list = List::new(items).highlight_style(theme::LIST_SELECTED);
+    f.render_stateful_widget(list, parts[1], &mut state);
+}
+
+/// Overlay for reviewing a plan the agent proposed with ExitPlanMode.
+pub fn draw_plan_review(f: &mut Frame, area: Rect, app: &App) {
+    let Some(ref review) = app.plan_review else {
+        return;
+    };
+    let width = (area.width * 4 / 5).max(20);
+    let height = (area.height * 4 / 5).max(8);
+    let popup_area = centered_rect(area, width, height);
+    f.render_widget(Clear, popup_area);
+
+    let status = match app.plan_decision(&review.session_id, review.proposed) {
+        Some(d) if d.accepted => " — accepted",
+        Some(_) => " — rejected",
+        None => "",
+    };
+    let when = review
+        .proposed
+        .map(|ts| {
+            ts.with_timezone(&chrono::Local)
+                .format(" %H:%M")
+                .to_string()
+        })
+        .unwrap_or_default();
+    let block = Block::default()
+        .title(format!(" Proposed plan{}{} ", when, status))
+        .borders(Borders::ALL)
+        .border_style(theme::BORDER_ACTIVE);
+    let inner = block.inner(popup_area);
+    f.render_widget(block, popup_area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Fill(1), Constraint::Length(1)])
+        .split(inner);
+
+    let text_width = chunks[0].width as usize;
+    let visible: Vec<Line> = review
+        .lines
+        .iter()
+        .skip(review.scroll)
+        .take(chunks[0].height as usize)
+        .map(|ml| plans_view::markdown_line(ml, text_width))
+        .collect();
+    f.render_widget(Paragraph::new(visible), chunks[0]);
 
-    let paragraph = Paragraph::new(lines);
+    let answer = if app.two_pane {
+        "sent to Claude pane"
+    } else {
+        "recorded"
+    };
+    let footer = format!("j/k scroll  a accept  r reject ({})  Esc later", answer);
+    f.render_widget(
+        Paragraph::new(Span::styled(footer, theme::DIFF_HEADER)),
+        chunks[1],
+    );
+}
+
+/// Full-screen popup showing one transcript item without truncation.
+pub fn draw_transcript_detail(f: &mut Frame, area: Rect, app: &App) {
+    let Some(ref detail) = app.transcript_detail else {
+        return;
+    };
+    f.render_widget(Clear, area);
+
+    let block = Block::default()
+        .title(format!(" {} ", detail.title))
+        .title_bottom(" j/k scroll  g top  Esc close ")
+        .borders(Borders::ALL)
+        .border_style(theme::BORDER_ACTIVE);
+    let paragraph = Paragraph::new(detail.text.as_str())
+        .block(block)
+        .wrap(Wrap { trim: false })
+        .scroll((detail.scroll.min(u16::MAX as usize) as u16, 0));
     f.render_widget(paragraph, area);
 }
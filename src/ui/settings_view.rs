@@ -0,0 +1,219 @@
+use ratatui::layout::{Constraint, Direction, Layout, Rect};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, List, ListItem, ListState, Paragraph, Wrap};
+use ratatui::Frame;
+
+use super::theme;
+use crate::app::{App, SettingsPane};
+use crate::model::claude_settings::{FlatSettingsItem, SettingsSection};
+use crate::model::hook::SettingsScope;
+
+pub fn draw_settings(f: &mut Frame, area: Rect, app: &App) {
+    let chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(45), Constraint::Percentage(55)])
+        .split(area);
+
+    draw_settings_list(f, chunks[0], app);
+    draw_settings_detail(f, chunks[1], app);
+}
+
+fn draw_settings_list(f: &mut Frame, area: Rect, app: &App) {
+    let border_style = if app.settings_pane == SettingsPane::List {
+        theme::BORDER_ACTIVE
+    } else {
+        theme::BORDER_INACTIVE
+    };
+
+    let values = app.claude_settings.entries.len();
+    let title = match app.claude_settings.problem_count() {
+        0 => format!(" Settings [{}] ", values),
+        1 => format!(" Settings [{}] 1 problem ", values),
+        n => format!(" Settings [{}] {} problems ", values, n),
+    };
+    let block = Block::default()
+        .title(title)
+        .borders(Borders::ALL)
+        .border_style(border_style);
+
+    if app.settings_flat_list.is_empty() {
+        let files: Vec<String> = app
+            .claude_settings
+            .layers
+            .iter()
+            .map(|layer| layer.path.display().to_string())
+            .collect();
+        let msg = format!(
+            "No model, permissions or hooks set in\n{}",
+            files.join("\n")
+        );
+        let p = Paragraph::new(msg)
+            .style(theme::EMPTY_STATE)
+            .block(block)
+            .wrap(Wrap { trim: false });
+        f.render_widget(p, area);
+        return;
+    }
+
+    let items: Vec<ListItem> = app.settings_flat_list.iter().map(list_item).collect();
+
+    let mut state = ListState::default();
+    state.select(Some(app.settings_index));
+
+    let list = List::new(items)
+        .block(block)
+        .highlight_style(theme::LIST_SELECTED);
+    f.render_stateful_widget(list, area, &mut state);
+}
+
+fn list_item(item: &FlatSettingsItem) -> ListItem<'static> {
+    let entry = match item {
+        FlatSettingsItem::ProblemHeader => {
+            return ListItem::new(Line::from(Span::styled("Problems", theme::CHECK_FAIL)));
+        }
+        FlatSettingsItem::Problem(scope, problem) => {
+            return ListItem::new(Line::from(vec![
+                Span::styled(format!("  {} ", scope.tag()), theme::COMMAND_SCOPE),
+                Span::styled(problem.clone(), theme::CHECK_FAIL),
+            ]));
+        }
+        FlatSettingsItem::SectionHeader(section) => {
+            return ListItem::new(Line::from(Span::styled(
+                section.label(),
+                theme::SETTINGS_SECTION,
+            )));
+        }
+        FlatSettingsItem::Entry(entry) => entry,
+    };
+
+    let mut spans = vec![Span::styled(
+        format!("  {} ", entry.scope.tag()),
+        theme::COMMAND_SCOPE,
+    )];
+    match entry.overridden_by {
+        Some(winner) => {
+            spans.push(Span::styled(
+                entry.value.clone(),
+                theme::SETTINGS_OVERRIDDEN,
+            ));
+            spans.push(Span::styled(
+                format!("  overridden by {}", winner.tag()),
+                theme::EMPTY_STATE,
+            ));
+        }
+        None if entry.section == SettingsSection::Hooks => {
+            spans.push(Span::styled(
+                format!("{} ", entry.value),
+                theme::HOOK_MATCHER,
+            ));
+            spans.push(Span::raw(
+                entry.detail.lines().next().unwrap_or("").to_string(),
+            ));
+        }
+        None => spans.push(Span::raw(entry.value.clone())),
+    }
+    ListItem::new(Line::from(spans))
+}
+
+fn draw_settings_detail(f: &mut Frame, area: Rect, app: &App) {
+    let border_style = if app.settings_pane == SettingsPane::Detail {
+        theme::BORDER_ACTIVE
+    } else {
+        theme::BORDER_INACTIVE
+    };
+    let field = |name: &str, value: String| {
+        Line::from(vec![
+            Span::styled(format!("{}: ", name), theme::HELP_DESC),
+            Span::raw(value),
+        ])
+    };
+    let file = |scope: SettingsScope| match app.claude_settings.layer(scope) {
+        Some(layer) => format!("{} ({})", scope.label(), layer.path.display()),
+        None => scope.label().to_string(),
+    };
+
+    let (title, mut lines) = match app.selected_settings_item() {
+        Some(FlatSettingsItem::Entry(entry)) => {
+            let mut lines = vec![
+                field("Setting", entry.section.label().to_string()),
+                field(
+                    if entry.section == SettingsSection::Hooks {
+                        "Event"
+                    } else {
+                        "Value"
+                    },
+                    entry.value.clone(),
+                ),
+                field("File", file(entry.scope)),
+            ];
+            if let Some(winner) = entry.overridden_by {
+                lines.push(Line::from(Span::styled(
+                    format!("Overridden by {}", file(winner)),
+                    theme::CHECK_PENDING,
+                )));
+            }
+            lines.push(Line::from(""));
+            lines.push(Line::from(Span::styled(
+                entry.section.merge_note(),
+                theme::EMPTY_STATE,
+            )));
+            if !entry.detail.is_empty() {
+                lines.push(Line::from(""));
+                lines.push(Line::from(Span::styled("Command", theme::HELP_DESC)));
+                lines.extend(entry.detail.lines().map(|l| Line::from(l.to_string())));
+            }
+            (format!(" {} ", entry.section.label()), lines)
+        }
+        Some(FlatSettingsItem::Problem(scope, problem)) => {
+            let lines = vec![
+                field("File", file(*scope)),
+                Line::from(""),
+                Line::from(Span::styled(problem.clone(), theme::CHECK_FAIL)),
+                Line::from(""),
+                Line::from(Span::styled(
+                    "Claude Code ignores values it can't read. The tab refreshes once the file is fixed.",
+                    theme::EMPTY_STATE,
+                )),
+            ];
+            (" Problem ".to_string(), lines)
+        }
+        _ => (" Detail ".to_string(), Vec::new()),
+    };
+
+    // Where every value could come from, most specific first
+    if !lines.is_empty() {
+        lines.push(Line::from(""));
+    }
+    lines.push(Line::from(Span::styled("Files", theme::HELP_DESC)));
+    for layer in &app.claude_settings.layers {
+        let state = if !layer.exists {
+            Span::styled("not found", theme::EMPTY_STATE)
+        } else if layer.problems.is_empty() {
+            Span::styled("ok", theme::CHECK_PASS)
+        } else {
+            Span::styled(
+                format!("{} problem(s)", layer.problems.len()),
+                theme::CHECK_FAIL,
+            )
+        };
+        lines.push(Line::from(vec![
+            Span::styled(format!("  {} ", layer.scope.tag()), theme::COMMAND_SCOPE),
+            Span::raw(format!("{}  ", layer.path.display())),
+            state,
+        ]));
+    }
+
+    let block = Block::default()
+        .title(title)
+        .borders(Borders::ALL)
+        .border_style(border_style);
+    let inner_height = area.height.saturating_sub(2) as usize;
+    let scroll = app
+        .settings_detail_scroll
+        .min(lines.len().saturating_sub(inner_height));
+    let p = Paragraph::new(lines)
+        .block(block)
+        .wrap(Wrap { trim: false })
+        .scroll((scroll.min(u16::MAX as usize) as u16, 0));
+    f.render_widget(p, area);
+}
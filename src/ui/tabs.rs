@@ -1,10 +1,14 @@
 use ratatui::layout::Rect;
+use ratatui::style::Style;
 use ratatui::text::{Line, Span};
 use ratatui::widgets::Paragraph;
 use ratatui::Frame;
 
 use super::theme;
 use crate::app::{ActiveTab, App};
+use crate::model::agent_status::AgentStatus;
+use crate::model::backoff::PollHealth;
+use crate::model::process::ProcessStatus;
 
 pub fn draw_tab_bar(f: &mut Frame, area: Rect, app: &App) {
     let visible = app.visible_tabs();
@@ -12,57 +16,93 @@ pub fn draw_tab_bar(f: &mut Frame, area: Rect, app: &App) {
     let mut spans = Vec::new();
     for (i, tab) in visible.iter().enumerate() {
         let num = i + 1;
-        let label = match tab {
-            ActiveTab::Sessions => format!("{}:Sessions", num),
-            ActiveTab::Teams => format!("{}:Teams", num),
-            ActiveTab::Todos => format!("{}:Todos", num),
-            ActiveTab::Git => format!("{}:Git", num),
-            ActiveTab::Plans => format!("{}:Plans", num),
-            ActiveTab::GitHubPRs => {
-                if app.gh_new_activity {
-                    format!("{}:PRs*", num)
-                } else {
-                    format!("{}:PRs", num)
-                }
-            }
-            ActiveTab::GitHubIssues => format!("{}:Issues", num),
-            ActiveTab::Jira => format!("{}:Jira", num),
-            ActiveTab::Linear => format!("{}:Linear", num),
-            ActiveTab::Processes => {
-                let running = app
-                    .processes
-                    .iter()
-                    .filter(|p| p.status == crate::model::process::ProcessStatus::Running)
-                    .count();
-                if running > 0 {
-                    format!("{}:Procs({})", num, running)
-                } else {
-                    format!("{}:Procs", num)
-                }
-            }
-        };
+        let mut label = format!("{}:{}", num, tab.title());
+        // Background polls failing: retrying later, or given up on for now
+        let health = app.poll_health(tab);
+        match health {
+            PollHealth::Ok => {}
+            PollHealth::Retrying => label.push('!'),
+            PollHealth::Down => label.push_str("!!"),
+        }
 
-        let style = if *tab == app.active_tab {
+        let active = *tab == app.active_tab;
+        let style = if active {
             theme::TAB_ACTIVE
-        } else if *tab == ActiveTab::GitHubPRs && app.gh_new_activity {
-            theme::PR_BADGE
+        } else if health == PollHealth::Down {
+            theme::POLL_DOWN
+        } else if health == PollHealth::Retrying {
+            theme::POLL_RETRYING
         } else {
             theme::TAB_INACTIVE
         };
-        spans.push(Span::styled(format!(" {} ", label), style));
+        spans.push(Span::styled(format!(" {}", label), style));
+        for (badge, badge_style) in tab_badges(app, tab) {
+            let badge_style = if active {
+                style
+            } else {
+                style.patch(badge_style)
+            };
+            spans.push(Span::styled(badge, badge_style));
+        }
+        spans.push(Span::styled(" ", style));
         spans.push(Span::raw(" "));
     }
 
-    // Version on the right
+    // Unread activity count and version on the right
+    let unread = app.unread_activity();
+    let activity = if unread > 0 {
+        format!(" F:{} new ", unread)
+    } else {
+        String::new()
+    };
     let version = format!("The Associate v{}", env!("CARGO_PKG_VERSION"));
     let tabs_width: usize = spans.iter().map(|s| s.width()).sum();
-    let total_used = tabs_width + version.len();
+    let total_used = tabs_width + activity.len() + 1 + version.len();
     let pad = (area.width as usize).saturating_sub(total_used);
     if pad > 0 {
         spans.push(Span::raw(" ".repeat(pad)));
     }
+    if unread > 0 {
+        spans.push(Span::styled(activity, theme::PR_BADGE));
+        spans.push(Span::raw(" "));
+    }
     spans.push(Span::styled(version, theme::STATUS_BAR));
 
     let line = Line::from(spans);
     f.render_widget(Paragraph::new(line), area);
 }
+
+/// Live badges after a tab's title, e.g. the running process count on
+/// Processes; empty when there is nothing to show.
+fn tab_badges(app: &App, tab: &ActiveTab) -> Vec<(String, Style)> {
+    let mut badges = Vec::new();
+    let count = match tab {
+        ActiveTab::Processes => Some((
+            app.processes
+                .iter()
+                .filter(|p| p.status == ProcessStatus::Running)
+                .count(),
+            theme::TAB_BADGE_PROCESSES,
+        )),
+        ActiveTab::Teams => Some((
+            app.all_agent_statuses()
+                .iter()
+                .filter(|s| **s == AgentStatus::Working)
+                .count(),
+            theme::TAB_BADGE_AGENTS,
+        )),
+        ActiveTab::Git => Some((app.git_status.total_files(), theme::TAB_BADGE_DIRTY)),
+        _ => None,
+    };
+    if let Some((count, style)) = count.filter(|(count, _)| *count > 0) {
+        badges.push((format!("({})", count), style));
+    }
+    if *tab == ActiveTab::Sessions && app.follow_mode {
+        badges.push((" \u{2193}".to_string(), theme::TAB_BADGE_FOLLOW));
+    }
+    // Unread activity feed items from this tab's polls
+    if app.tab_has_activity(tab) {
+        badges.push((" \u{25cf}".to_string(), theme::TAB_BADGE_UNREAD));
+    }
+    badges
+}
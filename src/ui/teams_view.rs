@@ -1,13 +1,16 @@
 use ratatui::layout::{Constraint, Direction, Layout, Rect};
 use ratatui::text::{Line, Span};
-use ratatui::widgets::{Block, Borders, List, ListItem, ListState, Paragraph, Wrap};
+use ratatui::widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph, Wrap};
 use ratatui::Frame;
 
-use super::theme;
-use super::util::truncate_chars;
-use crate::app::{App, TeamsPane};
-use crate::model::agent_status::AgentStatus;
-use crate::model::task::TaskStatus;
+use super::util::{home_span, truncate_chars};
+use super::{empty_state, sessions_view, theme};
+use crate::app::{App, BroadcastComposer, TaskEditField, TaskEditor, TeamsPane};
+use crate::model::agent_status::{AgentStatus, StatusHistory};
+use crate::model::inbox::BROADCAST_TEMPLATES;
+use crate::model::task::{Task, TaskGraph, TaskStatus};
+use crate::model::team::Team;
+use crate::model::transcript::{format_elapsed, format_tokens};
 
 pub fn draw_teams(f: &mut Frame, area: Rect, app: &App) {
     // Layout: Teams (fixed) | Members/Tasks (fixed) | Detail (fills remaining)
@@ -35,6 +38,297 @@ pub fn draw_teams(f: &mut Frame, area: Rect, app: &App) {
 
     // Right column: context-sensitive detail panel
     draw_detail_panel(f, chunks[2], app);
+
+    if let Some(ref editor) = app.task_editor {
+        draw_task_editor(f, area, app, editor);
+    }
+    if let Some(ref composer) = app.broadcast {
+        draw_broadcast(f, area, app, composer);
+    }
+}
+
+/// Popup for a message to every member of the team: a template picker and
+/// editor, then the recipients to confirm.
+/// Full-screen popup showing a member's transcript, one item per row,
+/// following the newest unless scrolled back.
+pub fn draw_member_transcript(f: &mut Frame, area: Rect, app: &App) {
+    let Some(ref view) = app.member_transcript else {
+        return;
+    };
+    f.render_widget(Clear, area);
+
+    let file = view
+        .path
+        .file_name()
+        .map(|n| n.to_string_lossy().to_string())
+        .unwrap_or_default();
+    let follow = if view.follow { " following" } else { "" };
+    let block = Block::default()
+        .title(format!(" {} · {}{} ", view.member, file, follow))
+        .title_bottom(" j/k scroll  g/G top/follow  Esc close ")
+        .borders(Borders::ALL)
+        .border_style(theme::BORDER_ACTIVE);
+    let inner = block.inner(area);
+    f.render_widget(block, area);
+
+    let items = &view.reader.items;
+    if items.is_empty() {
+        let p = Paragraph::new("(empty transcript)").style(theme::EMPTY_STATE);
+        f.render_widget(p, inner);
+        return;
+    }
+    let height = inner.height as usize;
+    let last = if view.follow {
+        items.len() - 1
+    } else {
+        view.scroll.min(items.len() - 1)
+    };
+    let first = (last + 1).saturating_sub(height);
+    let width = (inner.width as usize).saturating_sub(11);
+    let lines: Vec<Line> = items[first..]
+        .iter()
+        .take(height)
+        .map(|item| {
+            let time = item
+                .timestamp
+                .map(|ts| ts.format("%H:%M").to_string())
+                .unwrap_or_else(|| "     ".to_string());
+            let text = app.transcript_display_text(item).replace(['\n', '\r'], " ");
+            Line::from(vec![
+                Span::raw(format!("{} ", time)),
+                Span::styled(
+                    format!("{} ", item.kind.label()),
+                    sessions_view::kind_style(&item.kind),
+                ),
+                Span::raw(truncate_chars(&text, width).to_string()),
+            ])
+        })
+        .collect();
+    f.render_widget(Paragraph::new(lines), inner);
+}
+
+fn draw_broadcast(f: &mut Frame, area: Rect, app: &App, composer: &BroadcastComposer) {
+    let recipients = app.broadcast_recipients();
+    let width = 70u16.min(area.width.saturating_sub(6));
+    let height = if composer.confirming {
+        recipients.len() as u16 + composer.editor.lines().len() as u16 + 7
+    } else {
+        16
+    }
+    .min(area.height.saturating_sub(2));
+    let popup_area = Rect {
+        x: area.x + (area.width.saturating_sub(width)) / 2,
+        y: area.y + (area.height.saturating_sub(height)) / 2,
+        width,
+        height,
+    };
+    f.render_widget(Clear, popup_area);
+
+    let team = current_team(app).map(|t| t.display_name()).unwrap_or("");
+    let block = Block::default()
+        .title(format!(" Broadcast to {} ", team))
+        .borders(Borders::ALL)
+        .border_style(theme::BORDER_ACTIVE)
+        .style(
+            ratatui::style::Style::new()
+                .fg(ratatui::style::Color::White)
+                .bg(ratatui::style::Color::Black),
+        );
+    let inner = block.inner(popup_area);
+    f.render_widget(block, popup_area);
+
+    let label_style = ratatui::style::Style::new().fg(ratatui::style::Color::Yellow);
+
+    if composer.confirming {
+        let mut lines = vec![
+            Line::from(Span::styled(
+                format!(
+                    "Send this message to {} inbox{}?",
+                    recipients.len(),
+                    if recipients.len() == 1 { "" } else { "es" }
+                ),
+                label_style,
+            )),
+            Line::from(""),
+        ];
+        lines.extend(
+            recipients
+                .iter()
+                .map(|name| Line::from(format!("  {}", name))),
+        );
+        lines.push(Line::from(""));
+        lines.extend(
+            composer
+                .editor
+                .lines()
+                .iter()
+                .map(|l| Line::from(Span::styled(format!("> {}", l), theme::EMPTY_STATE))),
+        );
+        lines.push(Line::from(""));
+        lines.push(Line::from(vec![
+            Span::styled("y", theme::HELP_KEY),
+            Span::styled(": send  ", theme::HELP_DESC),
+            Span::styled("n", theme::HELP_KEY),
+            Span::styled(": back", theme::HELP_DESC),
+        ]));
+        f.render_widget(Paragraph::new(lines).wrap(Wrap { trim: false }), inner);
+        return;
+    }
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(1), // Template
+            Constraint::Min(3),    // Message
+            Constraint::Length(1), // Recipients
+        ])
+        .split(inner);
+
+    let template = match composer.template {
+        Some(i) => Span::raw(BROADCAST_TEMPLATES[i].0),
+        None => Span::styled("(none)", theme::EMPTY_STATE),
+    };
+    f.render_widget(
+        Paragraph::new(Line::from(vec![
+            Span::styled("Template: ", label_style),
+            Span::raw("< "),
+            template,
+            Span::raw(" >"),
+        ])),
+        chunks[0],
+    );
+
+    let editor_block = Block::default()
+        .title(" Message ")
+        .borders(Borders::ALL)
+        .border_style(theme::BORDER_ACTIVE);
+    f.render_widget(&composer.editor, editor_block.inner(chunks[1]));
+    f.render_widget(editor_block, chunks[1]);
+
+    f.render_widget(
+        Paragraph::new(Line::from(vec![
+            Span::styled("To: ", label_style),
+            Span::raw(recipients.join(", ")),
+        ])),
+        chunks[2],
+    );
+}
+
+/// Popup for creating or editing a task: subject, description, owner and
+/// the tasks it is blocked by.
+fn draw_task_editor(f: &mut Frame, area: Rect, app: &App, editor: &TaskEditor) {
+    let blocker_rows = editor.candidates.len().clamp(1, 6) as u16;
+    let width = 70u16.min(area.width.saturating_sub(6));
+    let height = (3 + 3 + 8 + blocker_rows + 2 + 1 + 2).min(area.height.saturating_sub(2));
+    let popup_area = Rect {
+        x: area.x + (area.width.saturating_sub(width)) / 2,
+        y: area.y + (area.height.saturating_sub(height)) / 2,
+        width,
+        height,
+    };
+    f.render_widget(Clear, popup_area);
+
+    let title = match editor.task_id {
+        Some(ref id) => format!(" Edit Task #{} ", id),
+        None => " New Task ".to_string(),
+    };
+    let block = Block::default()
+        .title(title)
+        .borders(Borders::ALL)
+        .border_style(theme::BORDER_ACTIVE)
+        .style(
+            ratatui::style::Style::new()
+                .fg(ratatui::style::Color::White)
+                .bg(ratatui::style::Color::Black),
+        );
+    let inner = block.inner(popup_area);
+    f.render_widget(block, popup_area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(3),                // Subject
+            Constraint::Min(3),                   // Description
+            Constraint::Length(3),                // Owner
+            Constraint::Length(blocker_rows + 2), // Blocked by
+            Constraint::Length(1),                // Hint
+        ])
+        .split(inner);
+
+    let field_block = |label: &'static str, field: TaskEditField| {
+        let border = if editor.field == field {
+            theme::BORDER_ACTIVE
+        } else {
+            theme::BORDER_INACTIVE
+        };
+        Block::default()
+            .title(label)
+            .borders(Borders::ALL)
+            .border_style(border)
+    };
+
+    let subject_block = field_block(" Subject ", TaskEditField::Subject);
+    f.render_widget(&editor.subject, subject_block.inner(chunks[0]));
+    f.render_widget(subject_block, chunks[0]);
+
+    let description_block = field_block(" Description ", TaskEditField::Description);
+    f.render_widget(&editor.description, description_block.inner(chunks[1]));
+    f.render_widget(description_block, chunks[1]);
+
+    let owner = match editor.owner {
+        Some(ref name) => Span::raw(name.clone()),
+        None => Span::styled("(unassigned)", theme::EMPTY_STATE),
+    };
+    let owner_line = Line::from(vec![Span::raw("< "), owner, Span::raw(" >")]);
+    f.render_widget(
+        Paragraph::new(owner_line).block(field_block(" Owner ", TaskEditField::Owner)),
+        chunks[2],
+    );
+
+    let blocked_block = field_block(" Blocked by ", TaskEditField::BlockedBy);
+    if editor.candidates.is_empty() {
+        let msg = Paragraph::new("No other tasks")
+            .style(theme::EMPTY_STATE)
+            .block(blocked_block);
+        f.render_widget(msg, chunks[3]);
+    } else {
+        let items: Vec<ListItem> = editor
+            .candidates
+            .iter()
+            .map(|id| {
+                let mark = if editor.blocked_by.contains(id) {
+                    "[x]"
+                } else {
+                    "[ ]"
+                };
+                let title = app
+                    .tasks
+                    .iter()
+                    .find(|t| t.id == *id)
+                    .map(|t| t.display_title())
+                    .unwrap_or_default();
+                ListItem::new(Line::from(format!(" {} #{} {}", mark, id, title)))
+            })
+            .collect();
+        let mut state = ListState::default();
+        if editor.field == TaskEditField::BlockedBy {
+            state.select(Some(editor.blocked_index));
+        }
+        let list = List::new(items)
+            .block(blocked_block)
+            .highlight_style(theme::LIST_SELECTED);
+        f.render_stateful_widget(list, chunks[3], &mut state);
+    }
+
+    let hint = match editor.field {
+        TaskEditField::Owner => " h/l: pick owner  Tab: next field  Ctrl+S: save  Esc: cancel",
+        TaskEditField::BlockedBy => " Space: toggle  Tab: next field  Ctrl+S: save  Esc: cancel",
+        _ => " Tab: next field  Ctrl+S: save  Esc: cancel",
+    };
+    f.render_widget(
+        Paragraph::new(Line::from(Span::styled(hint, theme::HINT_DESC))),
+        chunks[4],
+    );
 }
 
 fn draw_team_list(f: &mut Frame, area: Rect, app: &App) {
@@ -52,11 +346,7 @@ fn draw_team_list(f: &mut Frame, area: Rect, app: &App) {
         .border_style(border_style);
 
     if app.teams.is_empty() {
-        let msg = Paragraph::new("No teams found.")
-            .style(theme::EMPTY_STATE)
-            .block(block)
-            .wrap(Wrap { trim: false });
-        f.render_widget(msg, area);
+        empty_state::draw_empty_state(f, area, block, "No teams found.", app);
         return;
     }
 
@@ -67,7 +357,17 @@ fn draw_team_list(f: &mut Frame, area: Rect, app: &App) {
         .map(|(i, team)| {
             let prefix = if i == app.team_list_index { ">" } else { " " };
             let name = team.display_name();
-            ListItem::new(format!("{} {}", prefix, name))
+            let mut spans = vec![
+                Span::raw(format!("{} {}", prefix, name)),
+                home_span(app, team.home),
+            ];
+            if let Some(resources) = app.resources_for(team) {
+                spans.push(Span::styled(
+                    format!(" {}", format_tokens(resources.tokens)),
+                    theme::TX_USAGE,
+                ));
+            }
+            ListItem::new(Line::from(spans))
         })
         .collect();
 
@@ -228,6 +528,9 @@ fn draw_detail_panel(f: &mut Frame, area: Rect, app: &App) {
     match app.teams_pane {
         TeamsPane::Teams => draw_team_detail(f, area, app, border_style),
         TeamsPane::Members => draw_member_detail(f, area, app, border_style),
+        TeamsPane::Tasks | TeamsPane::Detail if app.task_graph => {
+            draw_task_graph(f, area, app, border_style)
+        }
         TeamsPane::Tasks | TeamsPane::Detail => draw_task_detail(f, area, app, border_style),
     }
 }
@@ -350,13 +653,186 @@ fn draw_team_detail(f: &mut Frame, area: Rect, app: &App, border_style: ratatui:
         ]));
     }
 
+    lines.push(Line::from(""));
+    push_resource_lines(&mut lines, app, team, label_style);
+
     let paragraph = Paragraph::new(lines)
         .block(block)
         .wrap(Wrap { trim: false });
     f.render_widget(paragraph, area);
 }
 
+/// Resource panel for `team`: tokens, tasks, tool calls and files touched by
+/// its sessions, its share of all teams' tokens, and a per-member breakdown.
+fn push_resource_lines(
+    lines: &mut Vec<Line<'_>>,
+    app: &App,
+    team: &Team,
+    label_style: ratatui::style::Style,
+) {
+    lines.push(Line::from(Span::styled("Resources:", label_style)));
+    let Some(resources) = app.resources_for(team) else {
+        lines.push(Line::from(Span::styled(
+            "  Scanning transcripts...",
+            theme::EMPTY_STATE,
+        )));
+        return;
+    };
+
+    let per_task = resources
+        .tokens_per_task()
+        .map(|t| format!(" ({} per done task)", format_tokens(t)))
+        .unwrap_or_default();
+    let all_tokens: u64 = app.team_resources.values().map(|r| r.tokens).sum();
+    let share = if app.team_resources.len() > 1 && all_tokens > 0 {
+        format!(", {}% of all teams", resources.tokens * 100 / all_tokens)
+    } else {
+        String::new()
+    };
+    let live = team
+        .config
+        .members
+        .iter()
+        .filter(|m| {
+            app.agent_statuses
+                .get(&m.name)
+                .is_some_and(|s| *s != AgentStatus::ShutDown)
+        })
+        .count();
+
+    let rows = [
+        (
+            "Tokens:      ",
+            format!("{}{}{}", format_tokens(resources.tokens), per_task, share),
+        ),
+        (
+            "Tasks done:  ",
+            format!("{}/{}", resources.tasks_completed, resources.tasks_total),
+        ),
+        (
+            "Agents:      ",
+            format!("{} live / {}", live, team.config.members.len()),
+        ),
+        ("Transcripts: ", resources.transcripts.to_string()),
+        ("Tool calls:  ", resources.tool_calls.to_string()),
+        ("Files:       ", resources.files_touched.len().to_string()),
+    ];
+    for (label, value) in rows {
+        lines.push(Line::from(vec![
+            Span::styled(format!("  {}", label), label_style),
+            Span::raw(value),
+        ]));
+    }
+
+    for member in &team.config.members {
+        let Some(usage) = resources.members.get(&member.name) else {
+            continue;
+        };
+        lines.push(Line::from(vec![
+            Span::raw(format!("  {:<16}", truncate_chars(&member.name, 16))),
+            Span::styled(
+                format!(
+                    "{:>7} tok {:>4} tools {:>3} files",
+                    format_tokens(usage.tokens),
+                    usage.tool_calls,
+                    usage.files_touched
+                ),
+                theme::TX_USAGE,
+            ),
+        ]));
+    }
+}
+
 /// Show member info header + inbox messages for the selected member.
+/// Label in front of the member timeline strip.
+const TIMELINE_LABEL: &str = "Timeline: ";
+
+/// The timeline never reaches further back than this.
+const TIMELINE_MAX_HOURS: i64 = 24;
+
+const TIMELINE_MAX_CELLS: usize = 48;
+
+/// Transitions listed under the timeline, newest first.
+const TIMELINE_RECENT: usize = 4;
+
+fn current_team(app: &App) -> Option<&Team> {
+    if app.teams.is_empty() {
+        return None;
+    }
+    Some(&app.teams[app.team_list_index.min(app.teams.len() - 1)])
+}
+
+fn agent_status_style(status: &AgentStatus) -> ratatui::style::Style {
+    match status {
+        AgentStatus::Starting => theme::AGENT_STARTING,
+        AgentStatus::Working => theme::AGENT_WORKING,
+        AgentStatus::Idle => theme::AGENT_IDLE,
+        AgentStatus::ShutDown => theme::AGENT_SHUTDOWN,
+    }
+}
+
+/// A strip of `width` cells coloured by the status at each point from the
+/// first recorded change (at most a day back) to now, with its time range
+/// and the most recent transitions below.
+fn timeline_lines(
+    history: &StatusHistory,
+    member: &str,
+    width: usize,
+    label_style: ratatui::style::Style,
+) -> Vec<Line<'static>> {
+    let changes = history.changes(member);
+    let Some(first) = changes.first() else {
+        return Vec::new();
+    };
+    let now = chrono::Utc::now();
+    let start = first
+        .at
+        .max(now - chrono::Duration::hours(TIMELINE_MAX_HOURS));
+    let span_ms = (now - start).num_milliseconds();
+    if width < 2 || span_ms <= 0 {
+        return Vec::new();
+    }
+
+    let mut strip = vec![Span::styled(TIMELINE_LABEL, label_style)];
+    for cell in 0..width {
+        let offset = span_ms * (2 * cell as i64 + 1) / (2 * width as i64);
+        let at = start + chrono::Duration::milliseconds(offset);
+        strip.push(match history.status_at(member, at) {
+            Some(status) => Span::styled("█", agent_status_style(status)),
+            None => Span::styled("·", theme::AGENT_TIMELINE),
+        });
+    }
+
+    let start_label = start
+        .with_timezone(&chrono::Local)
+        .format("%H:%M")
+        .to_string();
+    let gap = width.saturating_sub(start_label.len() + "now".len());
+    let mut lines = vec![
+        Line::from(strip),
+        Line::from(Span::styled(
+            format!(
+                "{}{}{}now",
+                " ".repeat(TIMELINE_LABEL.len()),
+                start_label,
+                " ".repeat(gap)
+            ),
+            theme::AGENT_TIMELINE,
+        )),
+    ];
+    for change in changes.iter().rev().take(TIMELINE_RECENT) {
+        let at = change
+            .at
+            .with_timezone(&chrono::Local)
+            .format("%m-%d %H:%M");
+        lines.push(Line::from(vec![
+            Span::styled(format!("  {} ", at), theme::AGENT_TIMELINE),
+            Span::styled(change.status.label(), agent_status_style(&change.status)),
+        ]));
+    }
+    lines
+}
+
 fn draw_member_detail(f: &mut Frame, area: Rect, app: &App, border_style: ratatui::style::Style) {
     let members = app.current_team_members();
     let member = if !members.is_empty() {
@@ -393,16 +869,36 @@ fn draw_member_detail(f: &mut Frame, area: Rect, app: &App, border_style: ratatu
     // Status badge
     let status = app.agent_statuses.get(&member.name);
     if let Some(status) = status {
-        let (icon, style) = match status {
-            AgentStatus::Starting => ("[~] Starting", theme::AGENT_STARTING),
-            AgentStatus::Working => ("[>] Working", theme::AGENT_WORKING),
-            AgentStatus::Idle => ("[z] Idle", theme::AGENT_IDLE),
-            AgentStatus::ShutDown => ("[x] Shut down", theme::AGENT_SHUTDOWN),
+        let icon = match status {
+            AgentStatus::Starting => "[~] Starting",
+            AgentStatus::Working => "[>] Working",
+            AgentStatus::Idle => "[z] Idle",
+            AgentStatus::ShutDown => "[x] Shut down",
         };
-        lines.push(Line::from(vec![
+        let style = agent_status_style(status);
+        let mut spans = vec![
             Span::styled("Status: ", label_style),
             Span::styled(icon, style),
-        ]));
+        ];
+        let history = current_team(app).and_then(|team| app.history_for(team));
+        let changes = history.map_or(&[][..], |h| h.changes(&member.name));
+        if let Some(last) = changes.last() {
+            let elapsed = (chrono::Utc::now() - last.at).num_milliseconds().max(0) as u64;
+            spans.push(Span::styled(
+                format!("  for {}", format_elapsed(elapsed)),
+                theme::AGENT_TIMELINE,
+            ));
+        }
+        lines.push(Line::from(spans));
+        let width = area.width.saturating_sub(2 + TIMELINE_LABEL.len() as u16) as usize;
+        if let Some(history) = history {
+            lines.extend(timeline_lines(
+                history,
+                &member.name,
+                width.min(TIMELINE_MAX_CELLS),
+                label_style,
+            ));
+        }
     }
 
     // Agent type + model
@@ -450,11 +946,8 @@ fn draw_member_detail(f: &mut Frame, area: Rect, app: &App, border_style: ratatu
     }
 
     // Lead indicator
-    if !app.teams.is_empty() {
-        let team_idx = app.team_list_index.min(app.teams.len() - 1);
-        if member.is_lead(&app.teams[team_idx].config) {
-            lines.push(Line::from(Span::styled("Team Lead", theme::AGENT_LEAD)));
-        }
+    if current_team(app).is_some_and(|team| member.is_lead(&team.config)) {
+        lines.push(Line::from(Span::styled("Team Lead", theme::AGENT_LEAD)));
     }
 
     // Separator before inbox
@@ -522,6 +1015,129 @@ fn draw_member_detail(f: &mut Frame, area: Rect, app: &App, border_style: ratatu
 }
 
 /// Show full task description, status, owner, and dependencies.
+/// The team's tasks in dependency order: one lane per step, each task with
+/// its owner and blockers, then any cycles and missing blockers.
+fn draw_task_graph(f: &mut Frame, area: Rect, app: &App, border_style: ratatui::style::Style) {
+    let tasks = &app.tasks;
+    let graph = TaskGraph::build(tasks);
+    let problems = graph.cycle.len() + graph.behind_cycle.len() + graph.orphans.len();
+    let title = match problems {
+        0 => " Dependencies ".to_string(),
+        1 => " Dependencies (1 problem) ".to_string(),
+        n => format!(" Dependencies ({} problems) ", n),
+    };
+    let block = Block::default()
+        .title(title)
+        .borders(Borders::ALL)
+        .border_style(border_style);
+
+    if tasks.is_empty() {
+        let msg = Paragraph::new("No tasks found.")
+            .style(theme::EMPTY_STATE)
+            .block(block);
+        f.render_widget(msg, area);
+        return;
+    }
+
+    let label_style = ratatui::style::Style::new().fg(ratatui::style::Color::Yellow);
+    let task_line = |i: usize| {
+        let task = &tasks[i];
+        let selected = i == app.task_list_index;
+        let mut spans = vec![
+            Span::raw(if selected { "> " } else { "  " }),
+            Span::styled(format!("{} ", task.status.icon()), task_status_style(task)),
+            Span::styled(
+                format!("#{} {}", task.id, task.display_title()),
+                if selected {
+                    theme::LIST_SELECTED
+                } else {
+                    theme::LIST_NORMAL
+                },
+            ),
+        ];
+        if let Some(ref owner) = task.owner {
+            spans.push(Span::styled(format!("  @{}", owner), theme::COMMAND_SCOPE));
+        }
+        if !graph.blockers[i].is_empty() {
+            let ids: Vec<String> = graph.blockers[i]
+                .iter()
+                .map(|&b| format!("#{}", tasks[b].id))
+                .collect();
+            let style = if graph.is_blocked(tasks, i) {
+                theme::CHECK_FAIL
+            } else {
+                theme::EMPTY_STATE
+            };
+            spans.push(Span::styled(format!("  ← {}", ids.join(" ")), style));
+        }
+        Line::from(spans)
+    };
+
+    let mut lines = Vec::new();
+    for (n, lane) in graph.lanes.iter().enumerate() {
+        if n > 0 {
+            lines.push(Line::from(""));
+        }
+        let heading = if n == 0 {
+            "Lane 1: no blockers".to_string()
+        } else {
+            format!("Lane {}: after lane {}", n + 1, n)
+        };
+        lines.push(Line::from(Span::styled(heading, label_style)));
+        lines.extend(lane.iter().map(|&i| task_line(i)));
+    }
+    if !graph.cycle.is_empty() {
+        lines.push(Line::from(""));
+        lines.push(Line::from(Span::styled(
+            "Cycle: these tasks block each other",
+            theme::CHECK_FAIL,
+        )));
+        lines.extend(graph.cycle.iter().map(|&i| task_line(i)));
+    }
+    if !graph.behind_cycle.is_empty() {
+        lines.push(Line::from(""));
+        lines.push(Line::from(Span::styled(
+            "Waiting on the cycle",
+            theme::CHECK_FAIL,
+        )));
+        lines.extend(graph.behind_cycle.iter().map(|&i| task_line(i)));
+    }
+    if !graph.orphans.is_empty() {
+        lines.push(Line::from(""));
+        lines.push(Line::from(Span::styled(
+            "Missing blockers",
+            theme::CHECK_FAIL,
+        )));
+        for (i, id) in &graph.orphans {
+            let gone = if tasks.iter().any(|t| t.id == *id) {
+                "deleted"
+            } else {
+                "not found"
+            };
+            lines.push(Line::from(vec![
+                Span::raw(format!("  #{} waits on ", tasks[*i].id)),
+                Span::styled(format!("#{}", id), theme::CHECK_FAIL),
+                Span::styled(format!(" ({})", gone), theme::EMPTY_STATE),
+            ]));
+        }
+    }
+
+    let paragraph = Paragraph::new(lines)
+        .block(block)
+        .wrap(Wrap { trim: false })
+        .scroll((app.detail_scroll.min(u16::MAX as usize) as u16, 0));
+    f.render_widget(paragraph, area);
+}
+
+fn task_status_style(task: &Task) -> ratatui::style::Style {
+    match task.status {
+        TaskStatus::Pending => theme::TASK_PENDING,
+        TaskStatus::InProgress => theme::TASK_IN_PROGRESS,
+        TaskStatus::Completed => theme::TASK_COMPLETED,
+        TaskStatus::Deleted => theme::TASK_COMPLETED,
+    }
+}
+
 fn draw_task_detail(f: &mut Frame, area: Rect, app: &App, border_style: ratatui::style::Style) {
     let block = Block::default()
         .title(" Task Detail ")
@@ -3,6 +3,12 @@ use ratatui::style::{Color, Modifier, Style};
 // Tab bar
 pub const TAB_ACTIVE: Style = Style::new().fg(Color::Black).bg(Color::Cyan);
 pub const TAB_INACTIVE: Style = Style::new().fg(Color::Gray).bg(Color::DarkGray);
+// Tab badges, drawn over the inactive tab style; the active tab keeps its own
+pub const TAB_BADGE_UNREAD: Style = Style::new().fg(Color::Yellow).add_modifier(Modifier::BOLD);
+pub const TAB_BADGE_PROCESSES: Style = Style::new().fg(Color::Yellow).add_modifier(Modifier::BOLD);
+pub const TAB_BADGE_AGENTS: Style = Style::new().fg(Color::Green).add_modifier(Modifier::BOLD);
+pub const TAB_BADGE_DIRTY: Style = Style::new().fg(Color::LightRed);
+pub const TAB_BADGE_FOLLOW: Style = Style::new().fg(Color::Green).add_modifier(Modifier::BOLD);
 
 // Status bar
 pub const STATUS_BAR: Style = Style::new().fg(Color::White).bg(Color::DarkGray);
@@ -18,14 +24,24 @@ pub const LIST_NORMAL: Style = Style::new().fg(Color::White);
 pub const TX_USER: Style = Style::new().fg(Color::Green).add_modifier(Modifier::BOLD);
 pub const TX_ASSISTANT: Style = Style::new().fg(Color::Cyan);
 pub const TX_TOOL: Style = Style::new().fg(Color::Yellow);
+pub const TX_PLAN: Style = Style::new()
+    .fg(Color::LightBlue)
+    .add_modifier(Modifier::BOLD);
 pub const TX_RESULT: Style = Style::new().fg(Color::DarkGray);
 pub const TX_SYSTEM: Style = Style::new().fg(Color::Magenta);
 pub const TX_PROGRESS: Style = Style::new().fg(Color::DarkGray);
+pub const TX_USAGE: Style = Style::new().fg(Color::DarkGray);
+pub const TX_USAGE_HEAVY: Style = Style::new()
+    .fg(Color::LightRed)
+    .add_modifier(Modifier::BOLD);
+pub const TX_SELECTED: Style = Style::new().bg(Color::DarkGray);
+pub const TX_UNREAD_DIVIDER: Style = Style::new().fg(Color::Yellow).add_modifier(Modifier::BOLD);
 
 // Task status
 pub const TASK_PENDING: Style = Style::new().fg(Color::Yellow);
 pub const TASK_IN_PROGRESS: Style = Style::new().fg(Color::Cyan);
 pub const TASK_COMPLETED: Style = Style::new().fg(Color::Green);
+pub const TODO_EDIT_INPUT: Style = Style::new().fg(Color::Yellow);
 
 // Borders
 pub const BORDER_ACTIVE: Style = Style::new().fg(Color::Cyan);
@@ -42,11 +58,27 @@ pub const FOLLOW_ACTIVE: Style = Style::new()
     .bg(Color::Green)
     .add_modifier(Modifier::BOLD);
 
+// Transcript sharing indicator (--share) and mirror connection state
+pub const SHARE_ACTIVE: Style = Style::new()
+    .fg(Color::Black)
+    .bg(Color::Magenta)
+    .add_modifier(Modifier::BOLD);
+pub const LOW_POWER: Style = Style::new()
+    .fg(Color::Black)
+    .bg(Color::Yellow)
+    .add_modifier(Modifier::BOLD);
+pub const MIRROR_DISCONNECTED: Style = Style::new()
+    .fg(Color::White)
+    .bg(Color::Red)
+    .add_modifier(Modifier::BOLD);
+
 // Agent status
 pub const AGENT_STARTING: Style = Style::new().fg(Color::Yellow);
 pub const AGENT_WORKING: Style = Style::new().fg(Color::Green);
 pub const AGENT_IDLE: Style = Style::new().fg(Color::DarkGray);
 pub const AGENT_SHUTDOWN: Style = Style::new().fg(Color::Red);
+/// Captions and unrecorded stretches of the member status timeline.
+pub const AGENT_TIMELINE: Style = Style::new().fg(Color::DarkGray);
 pub const AGENT_LEAD: Style = Style::new().fg(Color::Cyan).add_modifier(Modifier::BOLD);
 
 // Subagent indicator
@@ -63,15 +95,30 @@ pub const HINT_DESC: Style = Style::new().fg(Color::Gray).bg(Color::DarkGray);
 
 // Empty state
 pub const EMPTY_STATE: Style = Style::new().fg(Color::DarkGray);
+pub const SKELETON: Style = Style::new().fg(Color::DarkGray);
 
 // Branch label
 pub const BRANCH_LABEL: Style = Style::new().fg(Color::Yellow);
+/// Ticket a session is linked to, on both the session and the ticket.
+pub const SESSION_LINK: Style = Style::new().fg(Color::Cyan);
+pub const SESSION_GROUP_HEADER: Style = Style::new().fg(Color::Cyan).add_modifier(Modifier::BOLD);
+/// Source label on items from an extra Claude home.
+pub const HOME_LABEL: Style = Style::new().fg(Color::Blue);
 
 // Git diff
 pub const DIFF_ADD: Style = Style::new().fg(Color::Green);
 pub const DIFF_REMOVE: Style = Style::new().fg(Color::Red);
 pub const DIFF_HUNK: Style = Style::new().fg(Color::Cyan);
 pub const DIFF_HEADER: Style = Style::new().fg(Color::White).add_modifier(Modifier::DIM);
+pub const DIFF_ADD_WORD: Style = Style::new()
+    .fg(Color::Black)
+    .bg(Color::Green)
+    .add_modifier(Modifier::BOLD);
+pub const DIFF_REMOVE_WORD: Style = Style::new()
+    .fg(Color::Black)
+    .bg(Color::Red)
+    .add_modifier(Modifier::BOLD);
+pub const DIFF_SPLIT_DIVIDER: Style = Style::new().fg(Color::DarkGray);
 
 // Markdown styles
 pub const MD_HEADING: Style = Style::new().fg(Color::Cyan).add_modifier(Modifier::BOLD);
@@ -79,18 +126,50 @@ pub const MD_CODE_FENCE: Style = Style::new().fg(Color::DarkGray);
 pub const MD_CODE_BLOCK: Style = Style::new().fg(Color::Yellow);
 pub const MD_NORMAL: Style = Style::new().fg(Color::White);
 
+// Plan merge review
+pub const MERGE_ACCEPT: Style = Style::new().fg(Color::Green).add_modifier(Modifier::BOLD);
+pub const MERGE_REJECT: Style = Style::new().fg(Color::Red).add_modifier(Modifier::BOLD);
+
+// Overview tab
+pub const OVERVIEW_HEADLINE: Style = Style::new().fg(Color::White).add_modifier(Modifier::BOLD);
+pub const OVERVIEW_DETAIL: Style = Style::new().fg(Color::Gray);
+
+// Commands tab
+pub const COMMAND_SCOPE: Style = Style::new().fg(Color::Magenta);
+pub const COMMAND_DESCRIPTION: Style = Style::new().fg(Color::DarkGray);
+
+// Hooks tab
+pub const HOOK_EVENT: Style = Style::new().fg(Color::Cyan).add_modifier(Modifier::BOLD);
+pub const HOOK_MATCHER: Style = Style::new().fg(Color::Yellow);
+
+// Settings tab
+pub const SETTINGS_SECTION: Style = Style::new().fg(Color::Cyan).add_modifier(Modifier::BOLD);
+pub const SETTINGS_OVERRIDDEN: Style = Style::new()
+    .fg(Color::DarkGray)
+    .add_modifier(Modifier::CROSSED_OUT);
+
 // Git section headers
 pub const GIT_STAGED: Style = Style::new().fg(Color::Green).add_modifier(Modifier::BOLD);
 pub const GIT_UNSTAGED: Style = Style::new().fg(Color::Yellow).add_modifier(Modifier::BOLD);
 pub const GIT_UNTRACKED: Style = Style::new()
     .fg(Color::DarkGray)
     .add_modifier(Modifier::BOLD);
+pub const GIT_STASH: Style = Style::new().fg(Color::Magenta).add_modifier(Modifier::BOLD);
+
+// Branch picker
+pub const BRANCH_PICKER_SECTION: Style = Style::new().fg(Color::Cyan).add_modifier(Modifier::BOLD);
+pub const BRANCH_CURRENT: Style = Style::new().fg(Color::Green).add_modifier(Modifier::BOLD);
+pub const BRANCH_DETAIL: Style = Style::new().fg(Color::DarkGray);
+pub const BRANCH_PICKER_INPUT: Style = Style::new().fg(Color::Yellow);
 
 // File browser
 pub const FB_DIR: Style = Style::new().fg(Color::Cyan).add_modifier(Modifier::BOLD);
 pub const FB_FILE: Style = Style::new().fg(Color::White);
 pub const FB_LINE_NUMBER: Style = Style::new().fg(Color::DarkGray);
 pub const FB_EDIT_BORDER: Style = Style::new().fg(Color::Yellow);
+pub const FB_HEX_ASCII: Style = Style::new().fg(Color::Cyan);
+pub const FB_GREP_MATCH: Style = Style::new().fg(Color::Black).bg(Color::Yellow);
+pub const FB_GREP_LINE_NUMBER: Style = Style::new().fg(Color::Yellow);
 
 // GitHub PRs
 pub const PR_APPROVED: Style = Style::new().fg(Color::Green);
@@ -98,8 +177,19 @@ pub const PR_CHANGES_REQUESTED: Style = Style::new().fg(Color::Red);
 pub const PR_PENDING_REVIEW: Style = Style::new().fg(Color::Yellow);
 pub const PR_DRAFT: Style = Style::new().fg(Color::DarkGray);
 pub const PR_SIZE: Style = Style::new().fg(Color::Magenta);
+pub const PR_AUTHOR: Style = Style::new().fg(Color::Blue);
+pub const PR_AGE: Style = Style::new().fg(Color::DarkGray);
 pub const PR_SECTION: Style = Style::new().fg(Color::Cyan).add_modifier(Modifier::BOLD);
+pub const TABLE_HEADER: Style = Style::new()
+    .fg(Color::White)
+    .add_modifier(Modifier::BOLD.union(Modifier::UNDERLINED));
+pub const REVIEW_THREAD_FILE: Style = Style::new().fg(Color::Cyan);
+pub const REVIEW_THREAD_OPEN: Style = Style::new().fg(Color::Yellow);
+pub const REVIEW_THREAD_RESOLVED: Style = Style::new().fg(Color::DarkGray);
 pub const PR_BADGE: Style = Style::new().fg(Color::Yellow).add_modifier(Modifier::BOLD);
+pub const CHECK_PASS: Style = Style::new().fg(Color::Green);
+pub const CHECK_FAIL: Style = Style::new().fg(Color::Red).add_modifier(Modifier::BOLD);
+pub const CHECK_PENDING: Style = Style::new().fg(Color::Yellow);
 
 // GitHub Issues
 pub const ISSUE_OPEN: Style = Style::new().fg(Color::Green);
@@ -144,21 +234,67 @@ pub const JIRA_DONE: Style = Style::new().fg(Color::Green).add_modifier(Modifier
 pub const JIRA_BUG: Style = Style::new().fg(Color::Red);
 pub const JIRA_STORY: Style = Style::new().fg(Color::Green);
 pub const JIRA_TASK: Style = Style::new().fg(Color::Blue);
-pub const JIRA_SEARCH_INPUT: Style = Style::new().fg(Color::Yellow);
+pub const JIRA_BOARD_STATUS: Style = Style::new().fg(Color::DarkGray);
 pub const JIRA_TRANSITION_POPUP: Style = Style::new().fg(Color::White).bg(Color::DarkGray);
 
+// Azure DevOps Work Items tab
+pub const AZURE_ACTIVE: Style = Style::new().fg(Color::Cyan).add_modifier(Modifier::BOLD);
+pub const AZURE_NEW: Style = Style::new().fg(Color::Yellow).add_modifier(Modifier::BOLD);
+pub const AZURE_RESOLVED: Style = Style::new().fg(Color::Green).add_modifier(Modifier::BOLD);
+pub const AZURE_BUG: Style = Style::new().fg(Color::Red);
+pub const AZURE_STORY: Style = Style::new().fg(Color::Blue);
+pub const AZURE_TASK: Style = Style::new().fg(Color::Yellow);
+pub const AZURE_COMMENT_AUTHOR: Style =
+    Style::new().fg(Color::Magenta).add_modifier(Modifier::BOLD);
+
+// Deps tab
+pub const DEP_ECOSYSTEM: Style = Style::new().fg(Color::DarkGray);
+pub const DEP_COMPATIBLE: Style = Style::new().fg(Color::Green);
+pub const DEP_BREAKING: Style = Style::new().fg(Color::Yellow).add_modifier(Modifier::BOLD);
+
+// Tracker trend header
+pub const TREND_LABEL: Style = Style::new().fg(Color::DarkGray);
+pub const TREND_OPEN: Style = Style::new().fg(Color::Cyan);
+pub const TREND_CLOSED: Style = Style::new().fg(Color::Green);
+pub const TREND_VALUE: Style = Style::new().fg(Color::White);
+
+// Session activity heatmap, from no messages to the busiest days
+pub const HEATMAP_LABEL: Style = Style::new().fg(Color::DarkGray);
+pub const HEATMAP_LEVELS: [Style; 5] = [
+    Style::new().fg(Color::DarkGray),
+    Style::new().fg(Color::Indexed(22)),
+    Style::new().fg(Color::Indexed(28)),
+    Style::new().fg(Color::Indexed(34)),
+    Style::new().fg(Color::Indexed(46)),
+];
+
 // Processes tab
+pub const PROCESS_QUEUED: Style = Style::new()
+    .fg(Color::DarkGray)
+    .add_modifier(Modifier::BOLD);
 pub const PROCESS_RUNNING: Style = Style::new().fg(Color::Yellow).add_modifier(Modifier::BOLD);
+pub const PROCESS_TERMINATING: Style = Style::new().fg(Color::Magenta).add_modifier(Modifier::BOLD);
 pub const PROCESS_COMPLETED: Style = Style::new().fg(Color::Green).add_modifier(Modifier::BOLD);
 pub const PROCESS_FAILED: Style = Style::new().fg(Color::Red).add_modifier(Modifier::BOLD);
+pub const PROCESS_MODEL: Style = Style::new().fg(Color::Cyan);
+pub const PROCESS_USAGE: Style = Style::new().fg(Color::DarkGray);
+pub const PROCESS_USAGE_HIGH: Style = Style::new()
+    .fg(Color::White)
+    .bg(Color::Red)
+    .add_modifier(Modifier::BOLD);
 pub const PROCESS_STDOUT: Style = Style::new().fg(Color::White);
 pub const PROCESS_STDERR: Style = Style::new().fg(Color::Red);
 pub const PROCESS_STDERR_HEADER: Style = Style::new().fg(Color::Red).add_modifier(Modifier::BOLD);
+pub const PROCESS_SEARCH_MATCH: Style = Style::new().bg(Color::DarkGray);
+pub const PROCESS_OUTPUT_MARK: Style = Style::new().fg(Color::Black).bg(Color::Yellow);
 
 // Prompt modal
 pub const PROMPT_MODAL_BORDER: Style = Style::new().fg(Color::Cyan);
 pub const PROMPT_EDITOR_TEXT: Style = Style::new().fg(Color::White);
 pub const PROMPT_CURSOR_LINE: Style = Style::new().bg(Color::DarkGray);
+pub const PROMPT_TEMPLATE_MODEL: Style = Style::new().fg(Color::DarkGray);
+pub const PROMPT_PREVIEW_INFO: Style = Style::new().fg(Color::DarkGray);
+pub const PROMPT_PREVIEW_UNRESOLVED: Style = Style::new().fg(Color::Yellow);
 
 // Current issue highlight
 pub const CURRENT_ISSUE: Style = Style::new()
@@ -183,7 +319,22 @@ pub const MODE_BADGE_EDIT: Style = Style::new()
     .fg(Color::Black)
     .bg(Color::Red)
     .add_modifier(Modifier::BOLD);
+pub const POLL_COUNTDOWN: Style = Style::new().fg(Color::DarkGray);
+pub const POLL_RETRYING: Style = Style::new().fg(Color::Yellow).bg(Color::DarkGray);
+pub const POLL_DOWN: Style = Style::new().fg(Color::Red).bg(Color::DarkGray);
 pub const MODE_BADGE_SEARCH: Style = Style::new()
     .fg(Color::Black)
     .bg(Color::Yellow)
     .add_modifier(Modifier::BOLD);
+
+// List filter input (Sessions, PRs, Issues, Jira, Linear)
+pub const FILTER_INPUT: Style = Style::new().fg(Color::Yellow);
+
+// Activity feed dropdown
+pub const ACTIVITY_TIME: Style = Style::new().fg(Color::DarkGray);
+pub const ACTIVITY_SOURCE: Style = Style::new().fg(Color::Cyan);
+pub const ACTIVITY_SUMMARY: Style = Style::new().fg(Color::Yellow);
+
+// Link preview popup
+pub const LINK_URL: Style = Style::new().fg(Color::Blue);
+pub const LINK_TITLE: Style = Style::new().fg(Color::White).add_modifier(Modifier::BOLD);
@@ -1,11 +1,11 @@
 use ratatui::layout::{Constraint, Direction, Layout, Rect};
 use ratatui::text::{Line, Span};
-use ratatui::widgets::{Block, Borders, List, ListItem, ListState, Paragraph, Wrap};
+use ratatui::widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph, Wrap};
 use ratatui::Frame;
 
 use super::theme;
-use super::util::truncate_chars;
-use crate::app::App;
+use super::util::{centered_rect, home_span, truncate_chars};
+use crate::app::{App, TodoEditMode};
 
 pub fn draw_todos(f: &mut Frame, area: Rect, app: &App) {
     let chunks = Layout::default()
@@ -15,6 +15,55 @@ pub fn draw_todos(f: &mut Frame, area: Rect, app: &App) {
 
     draw_todo_file_list(f, chunks[0], app);
     draw_todo_items(f, chunks[1], app);
+
+    if app.todo_promote.is_some() {
+        draw_promote_popup(f, area, app);
+    }
+}
+
+/// The tracker picker for turning a todo into a ticket, with the editable
+/// title and a preview of the body.
+fn draw_promote_popup(f: &mut Frame, area: Rect, app: &App) {
+    let Some(ref promote) = app.todo_promote else {
+        return;
+    };
+    let height = promote.targets.len() as u16 + 12;
+    let popup = centered_rect(area, 72, height);
+    f.render_widget(Clear, popup);
+
+    let mut lines = vec![
+        Line::from(vec![
+            Span::styled("Title: ", theme::HELP_KEY),
+            Span::styled(format!("{}_", promote.title), theme::TODO_EDIT_INPUT),
+        ]),
+        Line::raw(""),
+    ];
+    for (i, target) in promote.targets.iter().enumerate() {
+        let (marker, style) = if i == promote.index {
+            ("> ", theme::LIST_SELECTED)
+        } else {
+            ("  ", theme::LIST_NORMAL)
+        };
+        lines.push(Line::styled(format!("{}{}", marker, target.label()), style));
+    }
+    lines.push(Line::raw(""));
+    for line in promote.body.lines() {
+        lines.push(Line::styled(line.to_string(), theme::EMPTY_STATE));
+    }
+
+    let title = if promote.creating {
+        " New ticket from todo (creating...) "
+    } else {
+        " New ticket from todo (Enter create, Esc cancel) "
+    };
+    let block = Block::default()
+        .title(title)
+        .borders(Borders::ALL)
+        .border_style(theme::PROMPT_MODAL_BORDER);
+    let p = Paragraph::new(lines)
+        .block(block)
+        .wrap(Wrap { trim: false });
+    f.render_widget(p, popup);
 }
 
 fn draw_todo_file_list(f: &mut Frame, area: Rect, app: &App) {
@@ -52,7 +101,7 @@ fn draw_todo_file_list(f: &mut Frame, area: Rect, app: &App) {
                 tf.display_name(),
                 tf.items.len()
             );
-            ListItem::new(text)
+            ListItem::new(Line::from(vec![Span::raw(text), home_span(app, tf.home)]))
         })
         .collect();
 
@@ -74,6 +123,32 @@ fn draw_todo_items(f: &mut Frame, area: Rect, app: &App) {
         theme::BORDER_INACTIVE
     };
 
+    // If editing, split vertically to show the input line at bottom
+    let (area, input_area) = if app.todo_edit_mode.is_some() {
+        let parts = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Min(1), Constraint::Length(3)])
+            .split(area);
+        (parts[0], Some(parts[1]))
+    } else {
+        (area, None)
+    };
+
+    if let Some(input_area) = input_area {
+        let title = match app.todo_edit_mode {
+            Some(TodoEditMode::New) => " New item (Enter to save, Esc to cancel) ",
+            _ => " Edit item (Enter to save, Esc to cancel) ",
+        };
+        let input_block = Block::default()
+            .title(title)
+            .borders(Borders::ALL)
+            .border_style(theme::TODO_EDIT_INPUT);
+        let p = Paragraph::new(format!("> {}_", app.todo_edit_input))
+            .style(theme::TODO_EDIT_INPUT)
+            .block(input_block);
+        f.render_widget(p, input_area);
+    }
+
     let items = app.current_todo_items();
     let title = format!(" Items [{}] ", items.len());
     let block = Block::default()
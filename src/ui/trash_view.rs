@@ -0,0 +1,90 @@
+use ratatui::layout::Rect;
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph};
+use ratatui::Frame;
+
+use super::theme;
+use super::util::{centered_rect, truncate_chars};
+use crate::app::{App, TrashPurge};
+use crate::model::overview::relative_age;
+
+/// Draw the trash: deleted items, newest first, with where they came from.
+pub fn draw_trash(f: &mut Frame, area: Rect, app: &App) {
+    let Some(ref view) = app.trash else {
+        return;
+    };
+    let width = 100u16.min(area.width.saturating_sub(4));
+    let height = (view.entries.len().max(1) as u16 + 2)
+        .max(8)
+        .min(area.height.saturating_sub(4));
+    let popup_area = centered_rect(area, width, height);
+    f.render_widget(Clear, popup_area);
+
+    let footer = match (view.confirm, app.selected_trash_entry()) {
+        (Some(TrashPurge::All), _) => format!(
+            " Delete all {} items for good? y yes  n no ",
+            view.entries.len()
+        ),
+        (Some(TrashPurge::Selected), Some(entry)) => {
+            format!(" Delete {} for good? y yes  n no ", entry.name)
+        }
+        _ => " Enter restore  d purge  D empty trash  Esc close ".to_string(),
+    };
+    let footer_style = if view.confirm.is_some() {
+        theme::DELETE_CONFIRM
+    } else {
+        theme::HELP_DESC
+    };
+    let block = Block::default()
+        .title(format!(" Trash [{}] ", view.entries.len()))
+        .title_bottom(Span::styled(footer, footer_style))
+        .borders(Borders::ALL)
+        .border_style(theme::PROMPT_MODAL_BORDER);
+
+    if view.entries.is_empty() {
+        let p = Paragraph::new(Span::styled(
+            "Nothing deleted. Deleted sessions, plans, todos, commands and teams land here.",
+            theme::EMPTY_STATE,
+        ))
+        .block(block);
+        f.render_widget(p, popup_area);
+        return;
+    }
+
+    let now = chrono::Utc::now();
+    // Room for the name after the age and kind columns, the rest for the path
+    let name_width = 32;
+    let path_width = (width as usize).saturating_sub(2 + 10 + 9 + name_width + 2);
+    let items: Vec<ListItem> = view
+        .entries
+        .iter()
+        .map(|entry| {
+            let original = entry.original.display().to_string();
+            // Keep the end of long paths, where the file name is
+            let skip = original.chars().count().saturating_sub(path_width);
+            let original: String = original.chars().skip(skip).collect();
+            ListItem::new(Line::from(vec![
+                Span::styled(
+                    format!(" {:<9}", relative_age(entry.deleted_at, now)),
+                    theme::ACTIVITY_TIME,
+                ),
+                Span::styled(
+                    format!("{:<9}", entry.source.label()),
+                    theme::ACTIVITY_SOURCE,
+                ),
+                Span::raw(format!(
+                    "{:<w$}  ",
+                    truncate_chars(&entry.name, name_width),
+                    w = name_width
+                )),
+                Span::styled(original, theme::ACTIVITY_TIME),
+            ]))
+        })
+        .collect();
+    let list = List::new(items)
+        .block(block)
+        .highlight_style(theme::LIST_SELECTED);
+    let mut state = ListState::default();
+    state.select(Some(view.index));
+    f.render_stateful_widget(list, popup_area, &mut state);
+}
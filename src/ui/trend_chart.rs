@@ -0,0 +1,64 @@
+use ratatui::layout::{Constraint, Direction, Layout, Rect};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::Paragraph;
+use ratatui::Frame;
+
+use super::theme;
+use crate::app::App;
+
+/// Number of days shown in the trend header.
+const TREND_DAYS: usize = 14;
+
+const BARS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+
+/// Draw a one-line open/closed trend header for `tracker` at the top of
+/// `area` and return the space left below it. Nothing is drawn until at
+/// least one snapshot has been recorded.
+pub fn draw_trend_header(f: &mut Frame, area: Rect, app: &App, tracker: &str) -> Rect {
+    let history = app.snapshots.recent(tracker, TREND_DAYS);
+    let Some(today) = history.last() else {
+        return area;
+    };
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(1), Constraint::Min(0)])
+        .split(area);
+
+    let open: Vec<usize> = history.iter().map(|s| s.open).collect();
+    let closed: Vec<usize> = history.iter().map(|s| s.closed).collect();
+    let total_closed: usize = closed.iter().sum();
+
+    let line = Line::from(vec![
+        Span::styled(" Open ", theme::TREND_LABEL),
+        Span::styled(sparkline(&open), theme::TREND_OPEN),
+        Span::styled(format!(" {}", today.open), theme::TREND_VALUE),
+        Span::styled("   Closed/day ", theme::TREND_LABEL),
+        Span::styled(sparkline(&closed), theme::TREND_CLOSED),
+        Span::styled(
+            format!(
+                " {} today, {} in {}d",
+                today.closed,
+                total_closed,
+                history.len()
+            ),
+            theme::TREND_VALUE,
+        ),
+    ]);
+    f.render_widget(Paragraph::new(line), chunks[0]);
+
+    chunks[1]
+}
+
+/// Render values as a row of block characters scaled to the largest value.
+fn sparkline(values: &[usize]) -> String {
+    let max = values.iter().copied().max().unwrap_or(0);
+    values
+        .iter()
+        .map(|&v| {
+            (v * (BARS.len() - 1))
+                .checked_div(max)
+                .map_or(BARS[0], |i| BARS[i])
+        })
+        .collect()
+}
@@ -1,3 +1,11 @@
+use ratatui::layout::Rect;
+use ratatui::style::Modifier;
+use ratatui::text::{Line, Span};
+
+use super::theme;
+use crate::app::App;
+use crate::model::process::TicketSource;
+
 /// Truncate a string to at most `max_chars` Unicode scalar values.
 /// Returns a borrowed slice if possible; no allocation when not truncated.
 pub fn truncate_chars(s: &str, max_chars: usize) -> &str {
@@ -6,3 +14,121 @@ pub fn truncate_chars(s: &str, max_chars: usize) -> &str {
         None => s,
     }
 }
+
+/// A byte count as `512 B`, `1.5 KB` or `2.0 MB`.
+pub fn format_size(bytes: u64) -> String {
+    if bytes < 1024 {
+        format!("{} B", bytes)
+    } else if bytes < 1024 * 1024 {
+        format!("{:.1} KB", bytes as f64 / 1024.0)
+    } else {
+        format!("{:.1} MB", bytes as f64 / (1024.0 * 1024.0))
+    }
+}
+
+/// A `width` x `height` popup centered in `area`, shrunk to fit when the
+/// terminal is smaller than that.
+pub fn centered_rect(area: Rect, width: u16, height: u16) -> Rect {
+    let width = width.min(area.width);
+    let height = height.min(area.height);
+    Rect {
+        x: area.x + (area.width - width) / 2,
+        y: area.y + (area.height - height) / 2,
+        width,
+        height,
+    }
+}
+
+/// ` [label]` for items from an extra Claude home, nothing for the primary.
+pub fn home_span(app: &App, home: usize) -> Span<'static> {
+    match app.home_label(home) {
+        "" => Span::raw(""),
+        label => Span::styled(format!(" [{}]", label), theme::HOME_LABEL),
+    }
+}
+
+/// "Run artifacts" section of a ticket's detail pane: the PRs, issues and
+/// commits processes launched from it linked. Empty when there are none.
+pub fn ticket_notes_lines(app: &App, ticket_url: &str) -> Vec<Line<'static>> {
+    let notes = app.ticket_notes.for_ticket(ticket_url);
+    if notes.is_empty() {
+        return Vec::new();
+    }
+    let mut lines = vec![
+        Line::from(""),
+        Line::from(Span::styled(
+            "Run artifacts:",
+            theme::LIST_NORMAL.add_modifier(Modifier::BOLD),
+        )),
+    ];
+    for note in notes {
+        let at = note
+            .at
+            .with_timezone(&chrono::Local)
+            .format("%Y-%m-%d %H:%M");
+        lines.push(Line::from(vec![
+            Span::raw(format!("  {} ", note.artifact.kind.label())),
+            Span::styled(
+                note.artifact.reference.clone(),
+                theme::LIST_NORMAL.add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(format!("  {}", at), theme::EMPTY_STATE),
+        ]));
+        lines.push(Line::from(Span::styled(
+            format!("    {}", note.artifact.url),
+            theme::EMPTY_STATE,
+        )));
+    }
+    lines
+}
+
+/// "Sessions" section of a ticket's detail pane: the sessions linked to it
+/// with `L` on the Sessions tab. Empty when there are none.
+pub fn linked_sessions_lines(app: &App, source: &TicketSource, key: &str) -> Vec<Line<'static>> {
+    let sessions = app.sessions_linked_to(source, key);
+    if sessions.is_empty() {
+        return Vec::new();
+    }
+    let mut lines = vec![
+        Line::from(""),
+        Line::from(Span::styled(
+            "Sessions:",
+            theme::LIST_NORMAL.add_modifier(Modifier::BOLD),
+        )),
+    ];
+    for session in sessions {
+        let when = session
+            .modified
+            .map(|t| {
+                t.with_timezone(&chrono::Local)
+                    .format("  %Y-%m-%d %H:%M")
+                    .to_string()
+            })
+            .unwrap_or_default();
+        lines.push(Line::from(vec![
+            Span::styled(
+                format!("  {}", truncate_chars(&session.display_title(), 60)),
+                theme::SESSION_LINK,
+            ),
+            Span::styled(when, theme::EMPTY_STATE),
+        ]));
+    }
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn centers_popups_and_shrinks_them_to_fit() {
+        let area = Rect::new(2, 1, 80, 24);
+        assert_eq!(centered_rect(area, 40, 10), Rect::new(22, 8, 40, 10));
+        // Larger than the terminal: fills it instead of underflowing
+        assert_eq!(centered_rect(area, 120, 30), area);
+        assert_eq!(
+            centered_rect(Rect::new(0, 0, 0, 0), 10, 5),
+            Rect::new(0, 0, 0, 0)
+        );
+    }
+}
@@ -1,17 +1,20 @@
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::sync::mpsc;
 use std::time::Duration;
 
 use anyhow::Result;
 use notify_debouncer_mini::{new_debouncer, DebouncedEventKind};
 
-use crate::config::{TabsConfig, DEBOUNCE_MS};
+use crate::config::{TabsConfig, DEBOUNCE_MS, WORKTREE_WATCH_MAX_DIRS};
+use crate::data::git;
 use crate::event::{AppEvent, FileChange};
 
 /// Start the file watcher, sending FileChanged events to the given sender.
-/// Directories for disabled tabs are not watched.
+/// Directories for disabled tabs are not watched. Sessions, teams, tasks and
+/// todos are watched in every Claude home; plans and the user's settings
+/// only in the first (primary).
 pub fn start_watcher(
-    claude_home: PathBuf,
+    claude_homes: Vec<PathBuf>,
     encoded_project: String,
     project_cwd: PathBuf,
     tx: mpsc::Sender<AppEvent>,
@@ -22,14 +25,13 @@ pub fn start_watcher(
     let todos_enabled = tabs_config.todos();
     let git_enabled = tabs_config.git();
     let plans_enabled = tabs_config.plans();
-
-    let project_dir = claude_home.join("projects").join(&encoded_project);
-    let teams_dir = claude_home.join("teams");
-    let tasks_dir = claude_home.join("tasks");
-    let todos_dir = claude_home.join("todos");
+    // The Hooks tab reads the settings files too
+    let settings_enabled = tabs_config.settings() || tabs_config.hooks();
 
     let tx_clone = tx.clone();
     let encoded_clone = encoded_project.clone();
+    let cwd_clone = project_cwd.clone();
+    let homes_clone = claude_homes.clone();
 
     let mut debouncer = new_debouncer(
         Duration::from_millis(DEBOUNCE_MS),
@@ -47,6 +49,25 @@ pub fn start_watcher(
                 let path = &event.path;
                 let path_str = path.to_string_lossy().to_string();
 
+                // Config edits are applied in place rather than reloading data
+                if is_project_config(path) {
+                    let _ = tx_clone.send(AppEvent::ConfigChanged);
+                    continue;
+                }
+
+                // Claude Code settings; checked before the working tree,
+                // which holds the project's
+                if settings_enabled && is_claude_settings(path, &homes_clone) {
+                    let _ = tx_clone.send(AppEvent::FileChanged(FileChange::ClaudeSettings));
+                    continue;
+                }
+
+                // Edits in the working tree, for the Git tab's diff
+                if git_enabled && is_worktree_file(path, &cwd_clone, &homes_clone) {
+                    let _ = tx_clone.send(AppEvent::FileChanged(FileChange::Worktree));
+                    continue;
+                }
+
                 // Determine what kind of file changed
                 let change = classify_change(&path_str, &encoded_clone, path);
                 if let Some(change) = change {
@@ -58,41 +79,106 @@ pub fn start_watcher(
 
     let watcher = debouncer.watcher();
 
-    // Watch project directory (recursive to catch subagent transcripts)
-    if sessions_enabled && project_dir.exists() {
-        let _ = watcher.watch(&project_dir, notify::RecursiveMode::Recursive);
-    }
+    for claude_home in &claude_homes {
+        let project_dir = claude_home.join("projects").join(&encoded_project);
+        let teams_dir = claude_home.join("teams");
+        let tasks_dir = claude_home.join("tasks");
+        let todos_dir = claude_home.join("todos");
 
-    // Watch teams directory
-    if teams_enabled && teams_dir.exists() {
-        let _ = watcher.watch(&teams_dir, notify::RecursiveMode::Recursive);
-    }
+        // Watch project directory (recursive to catch subagent transcripts)
+        if sessions_enabled && project_dir.exists() {
+            let _ = watcher.watch(&project_dir, notify::RecursiveMode::Recursive);
+        }
 
-    // Watch tasks directory
-    if teams_enabled && tasks_dir.exists() {
-        let _ = watcher.watch(&tasks_dir, notify::RecursiveMode::Recursive);
-    }
+        // Watch teams directory
+        if teams_enabled && teams_dir.exists() {
+            let _ = watcher.watch(&teams_dir, notify::RecursiveMode::Recursive);
+        }
+
+        // Watch tasks directory
+        if teams_enabled && tasks_dir.exists() {
+            let _ = watcher.watch(&tasks_dir, notify::RecursiveMode::Recursive);
+        }
 
-    // Watch todos directory
-    if todos_enabled && todos_dir.exists() {
-        let _ = watcher.watch(&todos_dir, notify::RecursiveMode::Recursive);
+        // Watch todos directory
+        if todos_enabled && todos_dir.exists() {
+            let _ = watcher.watch(&todos_dir, notify::RecursiveMode::Recursive);
+        }
     }
 
     // Watch plans directory
-    let plans_dir = claude_home.join("plans");
+    let plans_dir = claude_homes
+        .first()
+        .map(|home| home.join("plans"))
+        .unwrap_or_default();
     if plans_enabled && plans_dir.exists() {
         let _ = watcher.watch(&plans_dir, notify::RecursiveMode::NonRecursive);
     }
 
+    // Watch the project's and the user's settings files
+    if settings_enabled {
+        let dirs = [
+            Some(project_cwd.join(".claude")),
+            claude_homes.first().cloned(),
+        ];
+        for dir in dirs.into_iter().flatten().filter(|d| d.exists()) {
+            let _ = watcher.watch(&dir, notify::RecursiveMode::NonRecursive);
+        }
+    }
+
     // Watch .git directory for git status changes
     let git_dir = project_cwd.join(".git");
     if git_enabled && git_dir.exists() {
         let _ = watcher.watch(&git_dir, notify::RecursiveMode::NonRecursive);
     }
 
+    // Watch the tracked directories of the working tree, so the diff can
+    // follow edits; untracked trees such as build output stay unwatched
+    if git_enabled {
+        let dirs = git::tracked_dirs(&project_cwd).unwrap_or_default();
+        for dir in dirs.iter().take(WORKTREE_WATCH_MAX_DIRS) {
+            let _ = watcher.watch(&project_cwd.join(dir), notify::RecursiveMode::NonRecursive);
+        }
+    }
+
+    // Watch the project root (non-recursive) for .assoc.toml edits
+    if project_cwd.exists() {
+        let _ = watcher.watch(&project_cwd, notify::RecursiveMode::NonRecursive);
+    }
+
     Ok(debouncer)
 }
 
+/// Whether `path` is the project's `.assoc.toml`. Only the project root is
+/// watched outside the Claude homes, so the file name is enough (and avoids
+/// comparing canonicalized paths against the watcher's).
+fn is_project_config(path: &Path) -> bool {
+    path.file_name().is_some_and(|name| name == ".assoc.toml")
+}
+
+/// Whether `path` is a Claude Code settings file: `settings.json` or
+/// `settings.local.json` in a `.claude` directory or a Claude home.
+fn is_claude_settings(path: &Path, claude_homes: &[PathBuf]) -> bool {
+    let is_settings = path
+        .file_name()
+        .is_some_and(|name| name == "settings.json" || name == "settings.local.json");
+    let Some(dir) = path.parent() else {
+        return false;
+    };
+    is_settings
+        && (dir.file_name().is_some_and(|name| name == ".claude")
+            || claude_homes.iter().any(|home| dir == home))
+}
+
+/// Whether `path` is a file in the project's working tree, outside `.git`
+/// and any Claude home inside the project.
+fn is_worktree_file(path: &Path, project_cwd: &Path, claude_homes: &[PathBuf]) -> bool {
+    path != project_cwd
+        && path.starts_with(project_cwd)
+        && !path.components().any(|c| c.as_os_str() == ".git")
+        && !claude_homes.iter().any(|home| path.starts_with(home))
+}
+
 fn classify_change(
     path_str: &str,
     encoded_project: &str,